@@ -0,0 +1,250 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    consolidation_settlement::helper_functions::calc_rectangle_stress,
+    enums::PressureConvention,
+    models::{
+        adjacent_structure::AdjacentStructure, foundation::Foundation, soil_profile::SoilProfile,
+    },
+    validation::{validate_field, ValidationError},
+};
+
+/// Allowable increments on an existing foundation caused by a new foundation.
+///
+/// # Fields
+/// * `stress_ratio` - Allowable ratio of the stress increase to the existing foundation pressure.
+/// * `settlement` - Allowable additional settlement of the existing foundation (cm).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct AllowableIncrement {
+    pub stress_ratio: f64,
+    pub settlement: f64,
+}
+
+impl Default for AllowableIncrement {
+    fn default() -> Self {
+        Self {
+            stress_ratio: 0.1,
+            settlement: 1.0,
+        }
+    }
+}
+
+/// Stress increase and settlement of a layer below the existing foundation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdjacentInfluenceLayer {
+    /// Depth of the top of the layer, or of the existing foundation base within the layer (m).
+    pub top: f64,
+    /// Depth of the bottom of the layer (m).
+    pub bottom: f64,
+    /// Stress increase at the layer center below the existing foundation center (t/m²).
+    pub center_stress_increase: f64,
+    /// Stress increase at the layer center below the nearest point of the existing foundation (t/m²).
+    pub edge_stress_increase: f64,
+    /// Additional settlement of the layer below the existing foundation center (cm).
+    pub center_settlement: f64,
+    /// Additional settlement of the layer below the nearest point of the existing foundation (cm).
+    pub edge_settlement: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdjacentInfluenceResult {
+    /// Net pressure of the new foundation (t/m²).
+    pub net_pressure: f64,
+    /// Results of the layers below the existing foundation base.
+    pub layers: Vec<AdjacentInfluenceLayer>,
+    /// Largest stress increase below the existing foundation (t/m²).
+    pub max_stress_increase: f64,
+    /// Ratio of the largest stress increase to the existing foundation pressure.
+    pub stress_increment_ratio: f64,
+    /// Additional settlement of the existing foundation center (cm).
+    pub center_settlement: f64,
+    /// Additional settlement of the point of the existing foundation nearest to the new one (cm).
+    pub edge_settlement: f64,
+    /// Tilt of the existing foundation between its nearest point and its center (rad).
+    pub tilt: f64,
+    /// Allowable increments of the check.
+    pub allowable: AllowableIncrement,
+    /// Indicates whether both increments are within the allowable values.
+    pub is_safe: bool,
+    /// Convention of the new foundation pressure given to the check.
+    pub pressure_convention: PressureConvention,
+}
+
+/// Validates the input data for the adjacent structure influence check.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `foundation` - The new foundation.
+/// * `foundation_pressure` - Pressure of the new foundation (t/m²).
+/// * `adjacent` - The existing adjacent foundation.
+/// * `allowable` - Allowable increments.
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    foundation_pressure: f64,
+    adjacent: &AdjacentStructure,
+    allowable: &AllowableIncrement,
+) -> Result<(), ValidationError> {
+    soil_profile.validate(&["thickness"])?;
+    soil_profile.validate_soil_layers(&["mv"])?;
+    foundation.validate(&["foundation_depth", "foundation_width", "foundation_length"])?;
+    validate_field(
+        "foundation_pressure",
+        Some(foundation_pressure),
+        Some(0.0),
+        None,
+        "loads",
+    )?;
+    adjacent.validate(&[
+        "width",
+        "length",
+        "depth",
+        "offset_x",
+        "offset_y",
+        "foundation_pressure",
+    ])?;
+    validate_field(
+        "stress_ratio",
+        Some(allowable.stress_ratio),
+        Some(0.0),
+        None,
+        "adjacent_influence",
+    )?;
+    validate_field(
+        "settlement",
+        Some(allowable.settlement),
+        Some(0.0),
+        None,
+        "adjacent_influence",
+    )?;
+
+    Ok(())
+}
+
+/// Returns the point of a rectangle nearest to the origin along one axis.
+fn nearest_coordinate(center: f64, half_size: f64) -> f64 {
+    0.0_f64.clamp(center - half_size, center + half_size)
+}
+
+/// Checks the influence of a new foundation on an existing adjacent foundation.
+///
+/// The net pressure of the new foundation is spread by the Boussinesq solution for a uniformly
+/// loaded rectangle (superposition of Newmark corner factors), and the stress increase Δσ is
+/// taken at the center of each layer below the existing foundation base, both below its center
+/// and below its point nearest to the new foundation. The additional settlement of each layer is
+/// mv · H · Δσ, rock layers are taken as incompressible. The check passes when
+///
+/// * Δσmax / q_existing ≤ allowable stress ratio
+/// * additional settlement ≤ allowable settlement
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `foundation` - The new foundation.
+/// * `foundation_pressure` - Pressure of the new foundation (t/m²).
+/// * `pressure_convention` - Whether `foundation_pressure` is gross or net.
+/// * `adjacent` - The existing adjacent foundation.
+/// * `allowable` - Allowable increments.
+///
+/// # Returns
+/// * `AdjacentInfluenceResult` with the stress increases and additional settlements.
+pub fn calc_adjacent_influence(
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    foundation_pressure: f64,
+    pressure_convention: PressureConvention,
+    adjacent: &AdjacentStructure,
+    allowable: &AllowableIncrement,
+) -> Result<AdjacentInfluenceResult, ValidationError> {
+    validate_input(
+        soil_profile,
+        foundation,
+        foundation_pressure,
+        adjacent,
+        allowable,
+    )?;
+    let mut soil_profile = soil_profile.clone();
+    soil_profile.calc_layer_depths();
+
+    let df = foundation.foundation_depth.unwrap();
+    let half_width = foundation.foundation_width.unwrap() / 2.0;
+    let half_length = foundation.foundation_length.unwrap() / 2.0;
+    let net_pressure = foundation
+        .calc_net_pressure(&soil_profile, foundation_pressure, pressure_convention)
+        .max(0.0);
+
+    let adjacent_depth = adjacent.depth.unwrap();
+    let center = (adjacent.offset_x.unwrap(), adjacent.offset_y.unwrap());
+    let edge = (
+        nearest_coordinate(center.0, adjacent.width.unwrap() / 2.0),
+        nearest_coordinate(center.1, adjacent.length.unwrap() / 2.0),
+    );
+
+    let stress_at = |point: (f64, f64), depth: f64| {
+        if depth <= df {
+            return 0.0;
+        }
+        calc_rectangle_stress(
+            net_pressure,
+            -half_width - point.0,
+            half_width - point.0,
+            -half_length - point.1,
+            half_length - point.1,
+            depth - df,
+        )
+    };
+
+    let mut layers = vec![];
+    for layer in soil_profile.layers.iter() {
+        let bottom = layer.depth.unwrap();
+        let top = (bottom - layer.thickness.unwrap()).max(adjacent_depth);
+        if bottom <= top || layer.is_rock() {
+            continue;
+        }
+
+        let mid = (top + bottom) / 2.0;
+        let center_stress_increase = stress_at(center, mid);
+        let edge_stress_increase = stress_at(edge, mid);
+        let compressibility = 100.0 * layer.mv.unwrap() * (bottom - top);
+
+        layers.push(AdjacentInfluenceLayer {
+            top,
+            bottom,
+            center_stress_increase,
+            edge_stress_increase,
+            center_settlement: compressibility * center_stress_increase,
+            edge_settlement: compressibility * edge_stress_increase,
+        });
+    }
+
+    let max_stress_increase = layers
+        .iter()
+        .map(|l| l.center_stress_increase.max(l.edge_stress_increase))
+        .fold(0.0, f64::max);
+    let stress_increment_ratio = max_stress_increase / adjacent.foundation_pressure.unwrap();
+    let center_settlement: f64 = layers.iter().map(|l| l.center_settlement).sum();
+    let edge_settlement: f64 = layers.iter().map(|l| l.edge_settlement).sum();
+
+    let distance = (center.0 - edge.0).hypot(center.1 - edge.1);
+    let tilt = if distance > 0.0 {
+        (edge_settlement - center_settlement) / 100.0 / distance
+    } else {
+        0.0
+    };
+
+    Ok(AdjacentInfluenceResult {
+        net_pressure,
+        layers,
+        max_stress_increase,
+        stress_increment_ratio,
+        center_settlement,
+        edge_settlement,
+        tilt,
+        allowable: *allowable,
+        is_safe: stress_increment_ratio <= allowable.stress_ratio
+            && center_settlement.max(edge_settlement) <= allowable.settlement,
+        pressure_convention,
+    })
+}
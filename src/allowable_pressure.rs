@@ -0,0 +1,190 @@
+use serde::Serialize;
+
+use crate::{
+    bearing_capacity::depth_profile::get_bearing_capacity_fn,
+    consolidation_settlement::{by_compression_index, by_mv},
+    elastic_settlement::boussinesq::calc_elastic_settlement,
+    enums::{AnalysisTerm, GoverningCriterion, MethodId, PressureConvention},
+    models::{foundation::Foundation, loads::Loads, soil_profile::SoilProfile},
+    validation::{validate_field, ValidationError},
+};
+
+/// Factor of safety applied to the bearing capacity.
+const FACTOR_OF_SAFETY: f64 = 3.0;
+
+/// Pressure tolerance of the iteration (t/m²).
+const PRESSURE_TOLERANCE: f64 = 1e-4;
+
+/// Maximum number of bisection steps.
+const MAX_ITERATIONS: usize = 100;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SettlementLimitedPressure {
+    /// Governing allowable foundation pressure (t/m²).
+    pub allowable_pressure: f64,
+    /// Allowable bearing capacity, the lower of the short and long term values (t/m²).
+    pub allowable_bearing_capacity: f64,
+    /// Pressure giving the settlement limit, if below the allowable bearing capacity (t/m²).
+    pub settlement_pressure: Option<f64>,
+    /// Elastic plus consolidation settlement under the allowable pressure (cm).
+    pub settlement: f64,
+    /// Criterion limiting the allowable pressure.
+    pub governing: GoverningCriterion,
+    /// Consolidation settlement method used, `None` if the profile has no consolidation data.
+    pub consolidation_method: Option<MethodId>,
+}
+
+/// Returns the consolidation settlement method the soil layers have the parameters for: the
+/// compression index method, otherwise the volume compressibility method.
+pub(crate) fn select_consolidation_method(
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+) -> Option<MethodId> {
+    if by_compression_index::validate_input(soil_profile, foundation, 0.0).is_ok() {
+        Some(MethodId::CompressionIndex)
+    } else if by_mv::validate_input(soil_profile, foundation, 0.0).is_ok() {
+        Some(MethodId::VolumeCompressibility)
+    } else {
+        None
+    }
+}
+
+/// Calculates the elastic plus consolidation settlement under a foundation pressure.
+pub(crate) fn calc_total_settlement(
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    pressure: f64,
+    pressure_convention: PressureConvention,
+    consolidation_method: Option<MethodId>,
+) -> Result<f64, ValidationError> {
+    let elastic = calc_elastic_settlement(
+        &mut soil_profile.clone(),
+        foundation,
+        pressure,
+        pressure_convention,
+    )?;
+    let consolidation = match consolidation_method {
+        Some(MethodId::CompressionIndex) => {
+            by_compression_index::calc_settlement(
+                &mut soil_profile.clone(),
+                foundation,
+                pressure,
+                pressure_convention,
+            )?
+            .total_settlement
+        }
+        Some(MethodId::VolumeCompressibility) => {
+            by_mv::calc_settlement(
+                &mut soil_profile.clone(),
+                foundation,
+                pressure,
+                pressure_convention,
+            )?
+            .total_settlement
+        }
+        _ => 0.0,
+    };
+
+    Ok(elastic.total_settlement + consolidation)
+}
+
+/// Calculates the allowable foundation pressure limited by both bearing capacity and settlement.
+///
+/// The settlement is the Boussinesq elastic settlement plus the consolidation settlement. The
+/// consolidation method is the compression index method when the soil layers have its
+/// parameters, otherwise the volume compressibility method when they have mv. The pressure is
+/// found by bisection between zero and the allowable bearing capacity, since the settlement
+/// increases with the pressure.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `foundation` - The foundation data.
+/// * `limit_cm` - Allowable settlement (cm).
+/// * `method` - Bearing capacity method (`Vesic`, `Meyerhof` or `Hansen`).
+///
+/// # Returns
+/// * `SettlementLimitedPressure` with the governing pressure and criterion.
+pub fn calc_allowable_pressure_for_settlement(
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    limit_cm: f64,
+    method: MethodId,
+) -> Result<SettlementLimitedPressure, ValidationError> {
+    validate_field(
+        "limit_cm",
+        Some(limit_cm),
+        Some(0.0001),
+        None,
+        "allowable_pressure",
+    )?;
+    let calc = get_bearing_capacity_fn(method, "allowable_pressure")?;
+
+    // Unit concentric vertical load; without horizontal loads or moments the capacity does not
+    // depend on its magnitude
+    let loading = Loads {
+        vertical_load: Some(1.0),
+        ..Default::default()
+    };
+
+    let mut allowable_bearing_capacity = f64::INFINITY;
+    for term in [AnalysisTerm::Short, AnalysisTerm::Long] {
+        let result = calc(
+            &mut soil_profile.clone(),
+            &mut foundation.clone(),
+            &loading,
+            0.0,
+            PressureConvention::Gross,
+            FACTOR_OF_SAFETY,
+            term,
+        )?;
+        allowable_bearing_capacity =
+            allowable_bearing_capacity.min(result.allowable_bearing_capacity);
+    }
+
+    let consolidation_method = select_consolidation_method(soil_profile, foundation);
+
+    let settlement = |pressure: f64| {
+        calc_total_settlement(
+            soil_profile,
+            foundation,
+            pressure,
+            PressureConvention::Gross,
+            consolidation_method,
+        )
+    };
+
+    let capacity_settlement = settlement(allowable_bearing_capacity)?;
+    if capacity_settlement <= limit_cm {
+        return Ok(SettlementLimitedPressure {
+            allowable_pressure: allowable_bearing_capacity,
+            allowable_bearing_capacity,
+            settlement_pressure: None,
+            settlement: capacity_settlement,
+            governing: GoverningCriterion::BearingCapacity,
+            consolidation_method,
+        });
+    }
+
+    let mut low = 0.0;
+    let mut high = allowable_bearing_capacity;
+    for _ in 0..MAX_ITERATIONS {
+        if high - low < PRESSURE_TOLERANCE {
+            break;
+        }
+        let mid = 0.5 * (low + high);
+        if settlement(mid)? > limit_cm {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+
+    Ok(SettlementLimitedPressure {
+        allowable_pressure: low,
+        allowable_bearing_capacity,
+        settlement_pressure: Some(low),
+        settlement: settlement(low)?,
+        governing: GoverningCriterion::Settlement,
+        consolidation_method,
+    })
+}
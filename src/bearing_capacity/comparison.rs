@@ -0,0 +1,277 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::{AnalysisTerm, BearingCapacityMethod, MethodId, PressureConvention},
+    models::{
+        foundation::Foundation, loads::Loads, masw::Masw, point_load_test::PointLoadTest,
+        soil_profile::SoilProfile, spt::SPT,
+    },
+    validation::{validate_field, ValidationError},
+};
+
+use super::{depth_profile::get_bearing_capacity_fn, point_load_test, spt, tezcan_ozdemir};
+
+/// Methods run by `compare_methods`, in the order of the comparison table.
+const ALL_METHODS: &[BearingCapacityMethod] = &[
+    BearingCapacityMethod::Vesic,
+    BearingCapacityMethod::Meyerhof,
+    BearingCapacityMethod::Hansen,
+    BearingCapacityMethod::TezcanOzdemir,
+    BearingCapacityMethod::PointLoad,
+    BearingCapacityMethod::SptBased,
+];
+
+/// Site data available to the bearing capacity methods.
+///
+/// # Fields
+/// * `soil_profile` - Soil profile for the general bearing capacity equations and Vs methods.
+/// * `spt` - SPT data for the SPT-based method.
+/// * `masw` - MASW data for the Vs-based method.
+/// * `point_load_test` - Point load test data for rock.
+/// * `allowable_settlement` - Allowable settlement of the SPT-based method (cm).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BearingCapacityData {
+    pub soil_profile: Option<SoilProfile>,
+    pub spt: Option<SPT>,
+    pub masw: Option<Masw>,
+    pub point_load_test: Option<PointLoadTest>,
+    pub allowable_settlement: Option<f64>,
+}
+
+/// Bearing capacity of a single method, one row of the comparison table.
+///
+/// # Fields
+/// * `method` - Method of the row.
+/// * `ultimate_bearing_capacity` - Ultimate bearing capacity (t/m²), if the method provides one.
+/// * `allowable_bearing_capacity` - Allowable bearing capacity (t/m²).
+/// * `is_governing` - Whether the row has the lowest allowable bearing capacity of the comparison.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MethodCapacity {
+    pub method: BearingCapacityMethod,
+    pub ultimate_bearing_capacity: Option<f64>,
+    pub allowable_bearing_capacity: f64,
+    pub is_governing: bool,
+}
+
+/// Method left out of the comparison table.
+///
+/// # Fields
+/// * `method` - Method that was not run.
+/// * `reason` - Error code explaining why the method is not applicable.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkippedMethod {
+    pub method: BearingCapacityMethod,
+    pub reason: String,
+}
+
+/// Comparison of the bearing capacity methods applicable to the site data.
+///
+/// # Fields
+/// * `rows` - Results of the applicable methods.
+/// * `skipped` - Methods that were not applicable to the given data.
+/// * `governing_bearing_capacity` - Governing (minimum) allowable bearing capacity (t/m²).
+/// * `governing_method` - Method with the governing bearing capacity.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MethodComparison {
+    pub rows: Vec<MethodCapacity>,
+    pub skipped: Vec<SkippedMethod>,
+    pub governing_bearing_capacity: f64,
+    pub governing_method: BearingCapacityMethod,
+}
+
+/// Returns the data field of a method, or a `missing` error if it was not provided.
+fn require<'a, T>(value: &'a Option<T>, field: &str) -> Result<&'a T, ValidationError> {
+    value.as_ref().ok_or_else(|| ValidationError {
+        code: format!("bearing_capacity_data.{}.missing", field),
+        message: format!("{} is required for the method.", field),
+    })
+}
+
+/// Calculates the bearing capacity of a foundation with any of the implemented methods.
+///
+/// Terzaghi and CPT-based methods are not implemented and are rejected before any data is read.
+///
+/// # Arguments
+/// * `method` - The bearing capacity method.
+/// * `data` - The site data.
+/// * `foundation` - The foundation data.
+/// * `loading` - The applied loads (used by the general bearing capacity equations).
+/// * `foundation_pressure` - The pressure exerted by the foundation (t/m²).
+/// * `factor_of_safety` - The safety factor of the methods that take one.
+/// * `term` - Short or long-term condition.
+///
+/// # Returns
+/// * `MethodCapacity` with the ultimate and allowable bearing capacity of the method.
+pub fn calc_bearing_capacity(
+    method: BearingCapacityMethod,
+    data: &BearingCapacityData,
+    foundation: &Foundation,
+    loading: &Loads,
+    foundation_pressure: f64,
+    factor_of_safety: f64,
+    term: AnalysisTerm,
+) -> Result<MethodCapacity, ValidationError> {
+    if matches!(
+        method,
+        BearingCapacityMethod::Terzaghi | BearingCapacityMethod::CptBased
+    ) {
+        return Err(ValidationError {
+            code: "bearing_capacity_data.method.unsupported".to_string(),
+            message: format!("{:?} is not implemented.", method),
+        });
+    }
+
+    let (ultimate_bearing_capacity, allowable_bearing_capacity) = match method {
+        BearingCapacityMethod::Vesic
+        | BearingCapacityMethod::Meyerhof
+        | BearingCapacityMethod::Hansen => {
+            let method_id = match method {
+                BearingCapacityMethod::Vesic => MethodId::Vesic,
+                BearingCapacityMethod::Meyerhof => MethodId::Meyerhof,
+                _ => MethodId::Hansen,
+            };
+            let calc = get_bearing_capacity_fn(method_id, "bearing_capacity_data")?;
+            let mut soil_profile = require(&data.soil_profile, "soil_profile")?.clone();
+            let result = calc(
+                &mut soil_profile,
+                &mut foundation.clone(),
+                loading,
+                foundation_pressure,
+                PressureConvention::Gross,
+                factor_of_safety,
+                term,
+            )?;
+            (
+                Some(result.ultimate_bearing_capacity),
+                result.allowable_bearing_capacity,
+            )
+        }
+        BearingCapacityMethod::TezcanOzdemir => {
+            let soil_profile = require(&data.soil_profile, "soil_profile")?.clone();
+            let mut masw = require(&data.masw, "masw")?.clone();
+            let result = tezcan_ozdemir::calc_bearing_capacity(
+                soil_profile,
+                &mut masw,
+                foundation.clone(),
+                foundation_pressure,
+                PressureConvention::Gross,
+            )?;
+            (
+                Some(result.allowable_bearing_capacity * result.safety_factor),
+                result.allowable_bearing_capacity,
+            )
+        }
+        BearingCapacityMethod::PointLoad => {
+            let point_load_test = require(&data.point_load_test, "point_load_test")?.clone();
+            // A gross pressure needs no soil profile to be converted
+            let result = point_load_test::calc_bearing_capacity(
+                point_load_test,
+                &SoilProfile::default(),
+                foundation.clone(),
+                foundation_pressure,
+                PressureConvention::Gross,
+                factor_of_safety,
+            )?;
+            (
+                Some(result.allowable_bearing_capacity * result.safety_factor),
+                result.allowable_bearing_capacity,
+            )
+        }
+        BearingCapacityMethod::SptBased => {
+            let spt_data = require(&data.spt, "spt")?;
+            let allowable_settlement =
+                *require(&data.allowable_settlement, "allowable_settlement")?;
+            let result = spt::calc_bearing_capacity(
+                spt_data,
+                &SoilProfile::default(),
+                foundation,
+                foundation_pressure,
+                PressureConvention::Gross,
+                allowable_settlement,
+            )?;
+            (None, result.allowable_bearing_capacity)
+        }
+        BearingCapacityMethod::Terzaghi | BearingCapacityMethod::CptBased => unreachable!(),
+    };
+
+    Ok(MethodCapacity {
+        method,
+        ultimate_bearing_capacity,
+        allowable_bearing_capacity,
+        is_governing: false,
+    })
+}
+
+/// Runs every bearing capacity method applicable to the given data and tabulates the results.
+///
+/// Methods whose data is missing or invalid are listed as skipped with the error code. The row
+/// with the lowest allowable bearing capacity is flagged as governing.
+///
+/// # Arguments
+/// * `data` - The site data.
+/// * `foundation` - The foundation data.
+/// * `loading` - The applied loads (used by the general bearing capacity equations).
+/// * `foundation_pressure` - The pressure exerted by the foundation (t/m²).
+/// * `factor_of_safety` - The safety factor of the methods that take one.
+/// * `term` - Short or long-term condition.
+///
+/// # Returns
+/// * `MethodComparison` with a row per applicable method.
+pub fn compare_methods(
+    data: &BearingCapacityData,
+    foundation: &Foundation,
+    loading: &Loads,
+    foundation_pressure: f64,
+    factor_of_safety: f64,
+    term: AnalysisTerm,
+) -> Result<MethodComparison, ValidationError> {
+    validate_field(
+        "factor_of_safety",
+        Some(factor_of_safety),
+        Some(1.0),
+        None,
+        "method_comparison",
+    )?;
+
+    let mut rows = Vec::new();
+    let mut skipped = Vec::new();
+    for &method in ALL_METHODS {
+        match calc_bearing_capacity(
+            method,
+            data,
+            foundation,
+            loading,
+            foundation_pressure,
+            factor_of_safety,
+            term,
+        ) {
+            Ok(row) => rows.push(row),
+            Err(err) => skipped.push(SkippedMethod {
+                method,
+                reason: err.code,
+            }),
+        }
+    }
+
+    let governing = rows
+        .iter_mut()
+        .min_by(|a, b| {
+            a.allowable_bearing_capacity
+                .total_cmp(&b.allowable_bearing_capacity)
+        })
+        .ok_or_else(|| ValidationError {
+            code: "method_comparison.no_applicable_method".to_string(),
+            message: "None of the bearing capacity methods is applicable to the given data."
+                .to_string(),
+        })?;
+    governing.is_governing = true;
+    let governing_bearing_capacity = governing.allowable_bearing_capacity;
+    let governing_method = governing.method;
+
+    Ok(MethodComparison {
+        rows,
+        skipped,
+        governing_bearing_capacity,
+        governing_method,
+    })
+}
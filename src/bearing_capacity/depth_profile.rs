@@ -0,0 +1,158 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::{AnalysisTerm, MethodId, PressureConvention},
+    models::{foundation::Foundation, loads::Loads, soil_profile::SoilProfile},
+    validation::{validate_field, ValidationError},
+};
+
+use super::{hansen, meyerhof, model::BearingCapacityResult, vesic};
+
+/// Signature shared by the general bearing capacity methods.
+pub(crate) type BearingCapacityFn = fn(
+    &mut SoilProfile,
+    &mut Foundation,
+    &Loads,
+    f64,
+    PressureConvention,
+    f64,
+    AnalysisTerm,
+) -> Result<BearingCapacityResult, ValidationError>;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DepthBearingCapacity {
+    /// Candidate foundation depth (m).
+    pub foundation_depth: f64,
+    /// Ultimate bearing capacity at the depth (t/m²).
+    pub ultimate_bearing_capacity: f64,
+    /// Allowable bearing capacity at the depth (t/m²).
+    pub allowable_bearing_capacity: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BearingCapacityProfile {
+    /// Bearing capacity at each candidate depth, in the order of the depths.
+    pub points: Vec<DepthBearingCapacity>,
+    /// Method used for the analysis.
+    pub method: MethodId,
+}
+
+/// Returns the general bearing capacity method for a method id.
+///
+/// # Arguments
+/// * `method` - Bearing capacity method (`Vesic`, `Meyerhof` or `Hansen`).
+/// * `prefix` - Prefix of the error code for unsupported methods.
+pub(crate) fn get_bearing_capacity_fn(
+    method: MethodId,
+    prefix: &str,
+) -> Result<BearingCapacityFn, ValidationError> {
+    match method {
+        MethodId::Vesic => Ok(vesic::calc_bearing_capacity),
+        MethodId::Meyerhof => Ok(meyerhof::calc_bearing_capacity),
+        MethodId::Hansen => Ok(hansen::calc_bearing_capacity),
+        _ => Err(ValidationError {
+            code: format!("{}.method.unsupported", prefix),
+            message: format!("{:?} is not a supported bearing capacity method.", method),
+        }),
+    }
+}
+
+/// Generates candidate foundation depths from `start` to `end` (inclusive) at `step` intervals.
+///
+/// # Arguments
+/// * `start` - First candidate depth (m).
+/// * `end` - Last candidate depth (m).
+/// * `step` - Depth increment (m).
+///
+/// # Returns
+/// * `Vec<f64>`: Candidate depths.
+pub fn generate_candidate_depths(
+    start: f64,
+    end: f64,
+    step: f64,
+) -> Result<Vec<f64>, ValidationError> {
+    validate_field(
+        "start",
+        Some(start),
+        Some(0.0),
+        None,
+        "bearing_capacity_profile",
+    )?;
+    validate_field(
+        "end",
+        Some(end),
+        Some(start),
+        None,
+        "bearing_capacity_profile",
+    )?;
+    validate_field(
+        "step",
+        Some(step),
+        Some(0.0001),
+        None,
+        "bearing_capacity_profile",
+    )?;
+
+    let count = ((end - start) / step + 1e-9).floor() as usize;
+    Ok((0..=count).map(|i| start + i as f64 * step).collect())
+}
+
+/// Calculates the bearing capacity at a series of candidate foundation depths.
+///
+/// The foundation is re-analysed at each depth with the selected method, keeping its plan
+/// dimensions and the loads, so that the design depth can be picked from the qa–Df curve.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `foundation` - The foundation data (its depth is replaced by each candidate depth).
+/// * `loading` - The applied loads.
+/// * `depths` - Candidate foundation depths (m).
+/// * `factor_of_safety` - The safety factor of the bearing capacity.
+/// * `term` - Short or long-term condition.
+/// * `method` - Bearing capacity method (`Vesic`, `Meyerhof` or `Hansen`).
+///
+/// # Returns
+/// * `BearingCapacityProfile` with the bearing capacity at each depth.
+pub fn calc_bearing_capacity_profile(
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    loading: &Loads,
+    depths: &[f64],
+    factor_of_safety: f64,
+    term: AnalysisTerm,
+    method: MethodId,
+) -> Result<BearingCapacityProfile, ValidationError> {
+    if depths.is_empty() {
+        return Err(ValidationError {
+            code: "bearing_capacity_profile.depths.empty".to_string(),
+            message: "At least one candidate depth is required.".to_string(),
+        });
+    }
+
+    let calc = get_bearing_capacity_fn(method, "bearing_capacity_profile")?;
+
+    let mut points = Vec::with_capacity(depths.len());
+    for &depth in depths {
+        let mut profile = soil_profile.clone();
+        let mut candidate = foundation.clone();
+        candidate.foundation_depth = Some(depth);
+
+        let result = calc(
+            &mut profile,
+            &mut candidate,
+            loading,
+            0.0,
+            PressureConvention::Gross,
+            factor_of_safety,
+            term,
+        )?;
+
+        points.push(DepthBearingCapacity {
+            foundation_depth: depth,
+            ultimate_bearing_capacity: result.ultimate_bearing_capacity,
+            allowable_bearing_capacity: result.allowable_bearing_capacity,
+        });
+    }
+
+    Ok(BearingCapacityProfile { points, method })
+}
@@ -0,0 +1,226 @@
+use serde::Serialize;
+
+use crate::{
+    enums::PressureConvention,
+    models::{engineered_fill::EngineeredFill, foundation::Foundation, soil_profile::SoilProfile},
+    validation::ValidationError,
+};
+
+use super::{
+    model::{BearingCapacityFactors, ShapeFactors},
+    vesic::{calc_bearing_capacity_factors, calc_shape_factors},
+};
+
+/// Represents the bearing capacity result of a footing founded on an engineered fill.
+#[derive(Debug, Clone, Serialize)]
+pub struct FillBearingCapacityResult {
+    /// Thickness of fill between the footing base and the natural ground (m).
+    pub fill_thickness_below_base: f64,
+
+    /// Width of the loaded area at the fill/natural ground interface (m).
+    pub spread_width: f64,
+
+    /// Length of the loaded area at the fill/natural ground interface (m).
+    pub spread_length: f64,
+
+    /// Net stress increase at the fill/natural ground interface (t/m²).
+    pub interface_stress: f64,
+
+    /// Net ultimate capacity of the natural soil under the spread area (t/m²).
+    pub interface_capacity: f64,
+
+    /// Indicates whether the natural soil can carry the spread load.
+    pub is_interface_safe: bool,
+
+    /// Width of the fill at its base, including side slopes (m).
+    pub fill_base_width: f64,
+
+    /// Length of the fill at its base, including side slopes (m).
+    pub fill_base_length: f64,
+
+    /// Indicates whether the fill footprint covers the spread load area.
+    pub is_footprint_sufficient: bool,
+
+    /// Punching shear coefficient (Ks) used in the calculation.
+    pub punching_shear_coefficient: f64,
+
+    /// Ultimate capacity governed by punching of the fill into the soft soil (t/m²).
+    pub punching_capacity: f64,
+
+    /// Ultimate capacity of the footing assuming the fill extends to great depth (t/m²).
+    pub fill_capacity: f64,
+
+    /// Bearing capacity factors of the fill.
+    pub bearing_capacity_factors: BearingCapacityFactors,
+
+    /// Shape factors of the fill.
+    pub shape_factors: ShapeFactors,
+
+    /// Governing ultimate bearing capacity (t/m²).
+    pub ultimate_bearing_capacity: f64,
+
+    /// Allowable bearing capacity (t/m²).
+    pub allowable_bearing_capacity: f64,
+
+    /// The gross pressure exerted by the foundation (t/m²).
+    pub qmax: f64,
+
+    /// Indicates whether all checks are satisfied.
+    pub is_safe: bool,
+
+    /// Convention of the foundation pressure given to the method; `qmax` is always gross.
+    pub pressure_convention: PressureConvention,
+}
+
+/// Validates the input data for bearing capacity calculations on engineered fill.
+///
+/// # Arguments
+/// * `soil_profile` - The natural soil profile below the fill.
+/// * `fill` - The engineered fill data.
+/// * `foundation` - The foundation data. Depth is measured from the top of the fill.
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(
+    soil_profile: &SoilProfile,
+    fill: &EngineeredFill,
+    foundation: &Foundation,
+) -> Result<(), ValidationError> {
+    fill.validate(&[
+        "thickness",
+        "unit_weight",
+        "friction_angle",
+        "cohesion",
+        "side_slope",
+        "crest_offset",
+        "load_spread_angle",
+    ])?;
+    if fill.punching_shear_coefficient.is_some() {
+        fill.validate(&["punching_shear_coefficient"])?;
+    }
+    foundation.validate(&["foundation_depth", "foundation_width", "foundation_length"])?;
+    soil_profile.validate(&["thickness"])?;
+    soil_profile.layers[0].validate_fields(&["cu"])?;
+
+    if foundation.foundation_depth.unwrap() >= fill.thickness.unwrap() {
+        return Err(ValidationError {
+            code: "engineered_fill.thickness.smaller_than_foundation_depth".to_string(),
+            message: "Fill thickness must be greater than the foundation depth.".to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Calculates the bearing capacity of a footing resting on a compacted fill over soft soil.
+///
+/// Three checks are performed:
+/// * Punching of the fill into the underlying clay, after Meyerhof & Hanna (1978), capped by
+///   the capacity of the fill alone. Adhesion along the punching perimeter is neglected.
+/// * Load spread through the fill, comparing the net stress at the interface with the
+///   undrained capacity of the natural soil under the spread area.
+/// * Footprint of the fill, which must cover the spread area at its base.
+///
+/// The fill is assumed to be above the groundwater level and the natural soil is checked
+/// in undrained condition using the `cu` of its uppermost layer.
+///
+/// # Arguments
+/// * `soil_profile` - The natural soil profile below the fill.
+/// * `fill` - The engineered fill data.
+/// * `foundation` - The foundation data. Depth is measured from the top of the fill.
+/// * `foundation_pressure` - The pressure on the foundation (t/m²).
+/// * `pressure_convention` - Whether `foundation_pressure` is gross or net.
+/// * `factor_of_safety` - The safety factor to apply.
+///
+/// # Returns
+/// * `FillBearingCapacityResult` with the results of each check.
+pub fn calc_bearing_capacity(
+    soil_profile: &SoilProfile,
+    fill: &EngineeredFill,
+    foundation: &Foundation,
+    foundation_pressure: f64,
+    pressure_convention: PressureConvention,
+    factor_of_safety: f64,
+) -> Result<FillBearingCapacityResult, ValidationError> {
+    let mut foundation = foundation.clone();
+    foundation.apply_shape()?;
+    validate_input(soil_profile, fill, &foundation)?;
+
+    let df = foundation.foundation_depth.unwrap();
+    let width = foundation.foundation_width.unwrap();
+    let length = foundation.foundation_length.unwrap();
+    let w_l = foundation.calc_shape_ratio(width, length);
+
+    let thickness = fill.thickness.unwrap();
+    let gamma = fill.unit_weight.unwrap();
+    let phi = fill.friction_angle.unwrap();
+    let cohesion = fill.cohesion.unwrap();
+    let cu = soil_profile.layers[0].cu.unwrap();
+
+    let h = thickness - df;
+
+    // Load spread through the fill
+    let spread = 2.0 * h * fill.load_spread_angle.unwrap().to_radians().tan();
+    let spread_width = width + spread;
+    let spread_length = length + spread;
+    let (foundation_pressure, net_pressure) = match pressure_convention {
+        PressureConvention::Gross => (foundation_pressure, foundation_pressure - gamma * df),
+        PressureConvention::Net => (foundation_pressure + gamma * df, foundation_pressure),
+    };
+    let interface_stress = net_pressure.max(0.0) * width * length / (spread_width * spread_length);
+    let interface_capacity = 5.14 * cu * (1.0 + 0.2 * spread_width / spread_length);
+    let is_interface_safe = interface_stress <= interface_capacity / factor_of_safety;
+
+    // Fill footprint
+    let side_extension = 2.0 * (fill.crest_offset.unwrap() + thickness * fill.side_slope.unwrap());
+    let fill_base_width = width + side_extension;
+    let fill_base_length = length + side_extension;
+    let is_footprint_sufficient =
+        fill_base_width >= spread_width && fill_base_length >= spread_length;
+
+    // Capacity of the fill alone
+    let bearing_capacity_factors = calc_bearing_capacity_factors(phi);
+    let shape_factors = calc_shape_factors(&foundation, bearing_capacity_factors, phi);
+    let fill_capacity = if phi == 0. {
+        5.14 * cohesion * (1. + shape_factors.sc) + gamma * df
+    } else {
+        cohesion * bearing_capacity_factors.nc * shape_factors.sc
+            + gamma * df * bearing_capacity_factors.nq * shape_factors.sq
+            + 0.5 * gamma * width * bearing_capacity_factors.ng * shape_factors.sg
+    };
+
+    // Punching of the fill into the soft soil
+    let ks = fill.get_punching_shear_coefficient();
+    let qb = (1.0 + 0.2 * w_l) * 5.14 * cu + gamma * thickness;
+    let punching_capacity = qb
+        + gamma * h.powi(2) * (1.0 + w_l) * (1.0 + 2.0 * df / h) * ks * phi.to_radians().tan()
+            / width
+        - gamma * h;
+
+    let q_ult = punching_capacity.min(fill_capacity);
+    let q_allow = q_ult / factor_of_safety;
+
+    let is_safe = foundation_pressure <= q_allow && is_interface_safe && is_footprint_sufficient;
+
+    Ok(FillBearingCapacityResult {
+        fill_thickness_below_base: h,
+        spread_width,
+        spread_length,
+        interface_stress,
+        interface_capacity,
+        is_interface_safe,
+        fill_base_width,
+        fill_base_length,
+        is_footprint_sufficient,
+        punching_shear_coefficient: ks,
+        punching_capacity,
+        fill_capacity,
+        bearing_capacity_factors,
+        shape_factors,
+        ultimate_bearing_capacity: q_ult,
+        allowable_bearing_capacity: q_allow,
+        qmax: foundation_pressure,
+        is_safe,
+        pressure_convention,
+    })
+}
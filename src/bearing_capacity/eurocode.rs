@@ -0,0 +1,207 @@
+use serde::Serialize;
+
+use crate::{
+    enums::{AnalysisTerm, DesignApproach, MethodId, PressureConvention},
+    models::{foundation::Foundation, loads::Loads, soil_profile::SoilProfile},
+    validation::{validate_field, ValidationError},
+};
+
+use super::{depth_profile::get_bearing_capacity_fn, model::BearingCapacityResult};
+
+/// Characteristic vertical actions on the foundation.
+///
+/// # Fields
+/// * `permanent` - Characteristic permanent pressure Gk (t/m²).
+/// * `variable` - Characteristic variable pressure Qk (t/m²).
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct CharacteristicActions {
+    pub permanent: f64,
+    pub variable: f64,
+}
+
+/// Partial factors of EN 1997-1 Annex A for a design approach.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct PartialFactors {
+    /// Partial factor on unfavourable permanent actions γG.
+    pub permanent_action: f64,
+    /// Partial factor on unfavourable variable actions γQ.
+    pub variable_action: f64,
+    /// Partial factor on the tangent of the friction angle γφ'.
+    pub friction_angle: f64,
+    /// Partial factor on the effective cohesion γc'.
+    pub effective_cohesion: f64,
+    /// Partial factor on the undrained shear strength γcu.
+    pub undrained_strength: f64,
+    /// Partial factor on the bearing resistance γR;v.
+    pub resistance: f64,
+}
+
+impl PartialFactors {
+    /// Returns the recommended partial factors of a design approach.
+    ///
+    /// * A1: γG = 1.35, γQ = 1.5; A2: γG = 1.0, γQ = 1.3
+    /// * M1: γφ' = γc' = γcu = 1.0; M2: γφ' = γc' = 1.25, γcu = 1.4
+    /// * R1: γR;v = 1.0; R2: γR;v = 1.4; R3: γR;v = 1.0
+    pub fn for_approach(approach: DesignApproach) -> Self {
+        let (permanent_action, variable_action) = match approach {
+            DesignApproach::Da1Combination2 => (1.0, 1.3),
+            _ => (1.35, 1.5),
+        };
+        let (friction_angle, effective_cohesion, undrained_strength) = match approach {
+            DesignApproach::Da1Combination2 | DesignApproach::Da3 => (1.25, 1.25, 1.4),
+            _ => (1.0, 1.0, 1.0),
+        };
+        let resistance = match approach {
+            DesignApproach::Da2 => 1.4,
+            _ => 1.0,
+        };
+
+        Self {
+            permanent_action,
+            variable_action,
+            friction_angle,
+            effective_cohesion,
+            undrained_strength,
+            resistance,
+        }
+    }
+}
+
+/// Represents the Eurocode 7 bearing resistance of a foundation.
+#[derive(Debug, Serialize)]
+pub struct Ec7BearingCapacity {
+    /// Design approach used.
+    pub approach: DesignApproach,
+    /// Partial factors of the design approach.
+    pub partial_factors: PartialFactors,
+    /// Design pressure Ed = γG · Gk + γQ · Qk (t/m²).
+    pub design_pressure: f64,
+    /// Design vertical load Vd = Ed · A (ton).
+    pub design_load: f64,
+    /// Design bearing resistance Rd on the effective area (ton).
+    pub design_resistance: f64,
+    /// Design bearing resistance per unit effective area Rd / A' (t/m²).
+    pub design_resistance_pressure: f64,
+    /// Utilization ratio Vd / Rd.
+    pub utilization: f64,
+    /// Indicates whether Vd ≤ Rd.
+    pub is_safe: bool,
+    /// Bearing capacity with the design soil parameters and loads.
+    pub bearing_capacity: BearingCapacityResult,
+}
+
+pub fn validate_input(actions: &CharacteristicActions) -> Result<(), ValidationError> {
+    validate_field("permanent", Some(actions.permanent), Some(0.0), None, "ec7")?;
+    validate_field("variable", Some(actions.variable), Some(0.0), None, "ec7")?;
+    Ok(())
+}
+
+/// Returns the soil profile with the material partial factors applied to the strength of every
+/// layer: tan φd = tan φk / γφ', c'd = c'k / γc', cu,d = cu,k / γcu.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile with characteristic parameters.
+/// * `factors` - The partial factors.
+///
+/// # Returns
+/// * `SoilProfile` - The soil profile with design parameters.
+pub fn apply_material_factors(soil_profile: &SoilProfile, factors: &PartialFactors) -> SoilProfile {
+    let factor_angle = |phi: f64| {
+        (phi.to_radians().tan() / factors.friction_angle)
+            .atan()
+            .to_degrees()
+    };
+
+    let mut design_profile = soil_profile.clone();
+    for layer in &mut design_profile.layers {
+        layer.phi_prime = layer.phi_prime.map(factor_angle);
+        layer.phi_u = layer.phi_u.map(factor_angle);
+        layer.c_prime = layer.c_prime.map(|c| c / factors.effective_cohesion);
+        layer.cu = layer.cu.map(|cu| cu / factors.undrained_strength);
+    }
+    design_profile
+}
+
+/// Calculates the design bearing resistance of a foundation by Eurocode 7 (EN 1997-1).
+///
+/// The partial factors of the design approach are applied to the actions, the soil strength and
+/// the resistance instead of a global factor of safety. The horizontal loads and moments of
+/// `loading` are factored in the same ratio as the vertical actions, Ed / (Gk + Qk).
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile with characteristic parameters.
+/// * `foundation` - The foundation data.
+/// * `loading` - The characteristic loads.
+/// * `actions` - The characteristic vertical actions.
+/// * `approach` - The design approach.
+/// * `term` - Short or long-term condition.
+/// * `method` - Bearing capacity method (`Vesic`, `Meyerhof` or `Hansen`).
+///
+/// # Returns
+/// * `Ec7BearingCapacity` with the design resistance and utilization ratio.
+pub fn calc_bearing_capacity(
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    loading: &Loads,
+    actions: &CharacteristicActions,
+    approach: DesignApproach,
+    term: AnalysisTerm,
+    method: MethodId,
+) -> Result<Ec7BearingCapacity, ValidationError> {
+    validate_input(actions)?;
+    let calc = get_bearing_capacity_fn(method, "ec7")?;
+
+    let partial_factors = PartialFactors::for_approach(approach);
+    let characteristic_pressure = actions.permanent + actions.variable;
+    let design_pressure = partial_factors.permanent_action * actions.permanent
+        + partial_factors.variable_action * actions.variable;
+    let action_ratio = if characteristic_pressure > 0.0 {
+        design_pressure / characteristic_pressure
+    } else {
+        partial_factors.permanent_action
+    };
+
+    let factor = |value: Option<f64>| value.map(|v| v * action_ratio);
+    let design_loading = Loads {
+        vertical_load: factor(loading.vertical_load),
+        horizontal_load_x: factor(loading.horizontal_load_x),
+        horizontal_load_y: factor(loading.horizontal_load_y),
+        moment_x: factor(loading.moment_x),
+        moment_y: factor(loading.moment_y),
+        ..loading.clone()
+    };
+
+    let mut design_profile = apply_material_factors(soil_profile, &partial_factors);
+    let mut design_foundation = foundation.clone();
+    let bearing_capacity = calc(
+        &mut design_profile,
+        &mut design_foundation,
+        &design_loading,
+        design_pressure,
+        PressureConvention::Gross,
+        1.0,
+        term,
+    )?;
+
+    let effective_area = bearing_capacity.effective_area.effective_area;
+    let design_resistance_pressure =
+        bearing_capacity.ultimate_bearing_capacity / partial_factors.resistance;
+    let design_resistance = design_resistance_pressure * effective_area;
+    let foundation_area = design_foundation.foundation_area.unwrap_or(
+        design_foundation.foundation_width.unwrap() * design_foundation.foundation_length.unwrap(),
+    );
+    let design_load = design_pressure * foundation_area;
+    let utilization = design_load / design_resistance;
+
+    Ok(Ec7BearingCapacity {
+        approach,
+        partial_factors,
+        design_pressure,
+        design_load,
+        design_resistance,
+        design_resistance_pressure,
+        utilization,
+        is_safe: utilization <= 1.0,
+        bearing_capacity,
+    })
+}
@@ -0,0 +1,351 @@
+use std::f64::consts::PI;
+
+use crate::{
+    enums::{AnalysisTerm, MethodId, PressureConvention},
+    models::{foundation::Foundation, loads::Loads, soil_profile::SoilProfile},
+    validation::ValidationError,
+};
+
+use super::{helper_functions::*, model::*, vesic};
+
+/// Exponent of the iq inclination factor.
+const ALPHA_1: i32 = 5;
+
+/// Exponent of the iγ inclination factor.
+const ALPHA_2: i32 = 5;
+
+/// Validates the input data for Hansen bearing capacity calculations.
+///
+/// The same inputs as the Vesic method are required.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `foundation` - The foundation data.
+/// * `loading` - The applied loads.
+/// * `term` - Short or long-term condition.
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    loading: &Loads,
+    term: AnalysisTerm,
+) -> Result<(), ValidationError> {
+    vesic::validate_input(soil_profile, foundation, loading, term)
+}
+
+/// Computes the Hansen (1970) bearing capacity factors based on the friction angle φ (degrees).
+///
+/// * Nq = e^(π tan φ) · tan²(45 + φ/2)
+/// * Nc = (Nq − 1) · cot φ, 5.14 for φ = 0
+/// * Nγ = 1.5 · (Nq − 1) · tan φ
+///
+/// # Arguments
+/// * `phi` - Friction angle in degrees.
+///
+/// # Returns
+/// * `BearingCapacityFactors` containing Nc, Nq, and Ng.
+pub fn calc_bearing_capacity_factors(phi: f64) -> BearingCapacityFactors {
+    let tan_phi = phi.to_radians().tan();
+    let nq = (PI * tan_phi).exp() * (45.0 + phi / 2.0).to_radians().tan().powi(2);
+
+    let nc = if phi == 0.0 {
+        5.14
+    } else {
+        (nq - 1.0) / tan_phi
+    };
+
+    let ng = 1.5 * (nq - 1.0) * tan_phi;
+
+    BearingCapacityFactors { nc, nq, ng }
+}
+
+/// Calculates the Hansen shape factors from the effective foundation dimensions.
+///
+/// * Sc = 1 + (Nq/Nc) · B'/L', S'c = 0.2 · B'/L' for φ = 0
+/// * Sq = 1 + B'/L' · sin φ
+/// * Sγ = 1 − 0.4 · B'/L' ≥ 0.6
+///
+/// # Arguments
+/// * `foundation` - Foundation data with effective width and length
+/// * `bearing_capacity_factors` - Nc, Nq, Ng
+/// * `phi` - Friction angle in degrees
+///
+/// # Returns
+/// * `ShapeFactors`: shape coefficients for Sc, Sq, and Sg
+pub fn calc_shape_factors(
+    foundation: &Foundation,
+    bearing_capacity_factors: BearingCapacityFactors,
+    phi: f64,
+) -> ShapeFactors {
+    let w_l = foundation.calc_shape_ratio(
+        foundation.effective_width.unwrap(),
+        foundation.effective_length.unwrap(),
+    );
+
+    let sc = if phi == 0.0 {
+        0.2 * w_l
+    } else {
+        1.0 + w_l * bearing_capacity_factors.nq / bearing_capacity_factors.nc
+    };
+    let sq = 1.0 + w_l * phi.to_radians().sin();
+    let sg = (1.0 - 0.4 * w_l).max(0.6);
+
+    ShapeFactors { sc, sq, sg }
+}
+
+/// Calculates the Hansen depth factors.
+///
+/// k = Df/B for Df/B ≤ 1 and k = atan(Df/B) (radians) for Df/B > 1.
+///
+/// * dc = 1 + 0.4k, d'c = 0.4k for φ = 0
+/// * dq = 1 + 2 · tan φ · (1 − sin φ)² · k
+/// * dγ = 1
+///
+/// # Arguments
+/// * `foundation` - Foundation data
+/// * `phi` - Friction angle in degrees
+///
+/// # Returns
+/// * `DepthFactors`: dc, dq, dg coefficients
+pub fn calc_depth_factors(foundation: &Foundation, phi: f64) -> DepthFactors {
+    let d_b = foundation.calc_downslope_embedment() / foundation.foundation_width.unwrap();
+    let k = if d_b <= 1.0 { d_b } else { d_b.atan() };
+
+    let phi_rad = phi.to_radians();
+    let dc = if phi == 0.0 { 0.4 * k } else { 1.0 + 0.4 * k };
+    let dq = 1.0 + 2.0 * phi_rad.tan() * (1.0 - phi_rad.sin()).powi(2) * k;
+
+    DepthFactors { dc, dq, dg: 1.0 }
+}
+
+/// Calculates the Hansen inclination factors.
+///
+/// * iq = [1 − 0.5H / (V + A' · ca · cot φ)]^α1
+/// * iγ = [1 − (0.7 − η/450°) · H / (V + A' · ca · cot φ)]^α2
+/// * ic = iq − (1 − iq) / (Nq − 1), i'c = 0.5 − 0.5 · √(1 − H / (A' · ca)) for φ = 0
+///
+/// The base adhesion is taken as ca = 0.75c and α1 = α2 = 5.
+///
+/// # Arguments
+/// * `phi` - Friction angle in degrees
+/// * `cohesion` - Cohesion (t/m²)
+/// * `bearing_capacity_factors` - Nc, Nq, Ng
+/// * `foundation` - Foundation data with effective dimensions and base tilt angle
+/// * `loading` - Loads with vertical and horizontal components
+///
+/// # Returns
+/// * `InclinationFactors`: ic, iq, ig coefficients
+pub fn calc_inclination_factors(
+    phi: f64,
+    cohesion: f64,
+    bearing_capacity_factors: BearingCapacityFactors,
+    foundation: &Foundation,
+    loading: &Loads,
+) -> InclinationFactors {
+    let vertical_load = loading.vertical_load.unwrap();
+    let horizontal_load = loading
+        .horizontal_load_x
+        .unwrap_or(0.0)
+        .hypot(loading.horizontal_load_y.unwrap_or(0.0));
+    let area = foundation.effective_width.unwrap() * foundation.effective_length.unwrap();
+    let ca = 0.75 * cohesion;
+    let eta = foundation.base_tilt_angle.unwrap_or(0.0);
+
+    if phi == 0.0 {
+        let ic = if horizontal_load == 0.0 {
+            0.0
+        } else {
+            0.5 - 0.5 * (1.0 - horizontal_load / (area * ca)).max(0.0).sqrt()
+        };
+        return InclinationFactors {
+            ic,
+            iq: 1.0,
+            ig: 1.0,
+        };
+    }
+
+    let resisting = vertical_load + area * ca / phi.to_radians().tan();
+    let iq = (1.0 - 0.5 * horizontal_load / resisting)
+        .max(0.0)
+        .powi(ALPHA_1);
+    let ig = (1.0 - (0.7 - eta / 450.0) * horizontal_load / resisting)
+        .max(0.0)
+        .powi(ALPHA_2);
+    let ic = iq - (1.0 - iq) / (bearing_capacity_factors.nq - 1.0);
+
+    InclinationFactors { ic, iq, ig }
+}
+
+/// Calculates the Hansen ground (slope) factors for a ground slope β below the foundation.
+///
+/// * gc = 1 − β/147°, g'c = β/147° for φ = 0
+/// * gq = gγ = (1 − 0.5 · tan β)^5
+///
+/// # Arguments
+/// * `slope_angle` - Slope angle in degrees
+/// * `phi` - Friction angle in degrees
+///
+/// # Returns
+/// * `GroundFactors` with gc, gq, and gg
+pub fn calc_ground_factors(slope_angle: f64, phi: f64) -> GroundFactors {
+    let gc = if phi == 0.0 {
+        slope_angle / 147.0
+    } else {
+        1.0 - slope_angle / 147.0
+    };
+    let gq = (1.0 - 0.5 * slope_angle.to_radians().tan()).powi(5);
+
+    GroundFactors { gc, gq, gg: gq }
+}
+
+/// Calculates the Hansen base (tilt) factors for a base tilted by η from the horizontal.
+///
+/// * bc = 1 − η/147°, b'c = η/147° for φ = 0
+/// * bq = e^(−2η · tan φ)
+/// * bγ = e^(−2.7η · tan φ), with η in radians
+///
+/// # Arguments
+/// * `phi` - Friction angle in degrees
+/// * `foundation` - Foundation with the base tilt angle
+///
+/// # Returns
+/// * `BaseFactors`: The base inclination factors
+pub fn calc_base_factors(phi: f64, foundation: &Foundation) -> BaseFactors {
+    let eta = foundation.base_tilt_angle.unwrap_or(0.0);
+    let tan_phi = phi.to_radians().tan();
+
+    let bc = if phi == 0.0 {
+        eta / 147.0
+    } else {
+        1.0 - eta / 147.0
+    };
+    let bq = (-2.0 * eta.to_radians() * tan_phi).exp();
+    let bg = (-2.7 * eta.to_radians() * tan_phi).exp();
+
+    BaseFactors { bc, bq, bg }
+}
+
+/// Calculates the ultimate and allowable bearing capacity of a foundation by the Brinch Hansen
+/// (1970) method.
+///
+/// * φ > 0: q_ult = c · Nc · Sc · dc · ic · gc · bc + q · Nq · Sq · dq · iq · gq · bq
+///   + 0.5 · γ · B' · Nγ · Sγ · dγ · iγ · gγ · bγ
+/// * φ = 0: q_ult = 5.14 · su · (1 + S'c + d'c − i'c − b'c − g'c) + q
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `foundation` - The foundation data.
+/// * `loading` - The applied loads.
+/// * `foundation_pressure` - The pressure on the foundation.
+/// * `pressure_convention` - Whether `foundation_pressure` is gross or net.
+/// * `factor_of_safety` - The safety factor to apply.
+/// * `term` - Short or long-term condition.
+///
+/// # Returns
+/// * `BearingCapacityResult` with detailed components and safety check.
+pub fn calc_bearing_capacity(
+    soil_profile: &mut SoilProfile,
+    foundation: &mut Foundation,
+    loading: &Loads,
+    foundation_pressure: f64,
+    pressure_convention: PressureConvention,
+    factor_of_safety: f64,
+    term: AnalysisTerm,
+) -> Result<BearingCapacityResult, ValidationError> {
+    foundation.apply_shape()?;
+    validate_input(soil_profile, foundation, loading, term)?;
+    soil_profile.calc_layer_depths();
+    let foundation_pressure =
+        foundation.calc_gross_pressure(soil_profile, foundation_pressure, pressure_convention);
+    let (ex, ey) = loading.calc_eccentricity();
+    let effective_area = foundation.calc_effective_area(ex, ey);
+
+    let soil_params = get_soil_params(soil_profile, foundation, term);
+    let phi = soil_params.friction_angle;
+    let cohesion = soil_params.cohesion;
+    let effective_unit_weight = soil_params.unit_weight;
+
+    let effective_surcharge = calc_effective_surcharge(soil_profile, foundation, term)
+        + loading.surface_surcharge.unwrap_or(0.0);
+
+    let bearing_capacity_factors = calc_bearing_capacity_factors(phi);
+    let shape_factors = calc_shape_factors(foundation, bearing_capacity_factors, phi);
+    let depth_factors = calc_depth_factors(foundation, phi);
+    let inclination_factors =
+        calc_inclination_factors(phi, cohesion, bearing_capacity_factors, foundation, loading);
+    let ground_factors = calc_ground_factors(foundation.slope_angle.unwrap_or(0.0), phi);
+    let base_factors = calc_base_factors(phi, foundation);
+
+    let effective_width = foundation.effective_width.unwrap();
+    let (part_1, part_2, part_3) = if phi == 0. {
+        let part_1 = 5.14
+            * cohesion
+            * (1. + shape_factors.sc + depth_factors.dc
+                - inclination_factors.ic
+                - base_factors.bc
+                - ground_factors.gc);
+        (part_1, effective_surcharge, 0.0)
+    } else {
+        let part_1 = cohesion
+            * bearing_capacity_factors.nc
+            * shape_factors.sc
+            * depth_factors.dc
+            * inclination_factors.ic
+            * ground_factors.gc
+            * base_factors.bc;
+
+        let part_2 = effective_surcharge
+            * bearing_capacity_factors.nq
+            * shape_factors.sq
+            * depth_factors.dq
+            * inclination_factors.iq
+            * ground_factors.gq
+            * base_factors.bq;
+
+        let part_3 = 0.5
+            * effective_unit_weight
+            * effective_width
+            * bearing_capacity_factors.ng
+            * shape_factors.sg
+            * depth_factors.dg
+            * inclination_factors.ig
+            * ground_factors.gg
+            * base_factors.bg;
+
+        (part_1, part_2, part_3)
+    };
+    let q_ult = part_1 + part_2 + part_3;
+
+    let q_allow = q_ult / factor_of_safety;
+
+    Ok(BearingCapacityResult {
+        bearing_capacity_factors,
+        shape_factors,
+        depth_factors,
+        load_inclination_factors: inclination_factors,
+        soil_params,
+        terms: BearingCapacityTerms {
+            part_1,
+            part_2,
+            part_3,
+            friction_angle: phi,
+            cohesion,
+            unit_weight: effective_unit_weight,
+            surcharge: effective_surcharge,
+            effective_width,
+        },
+        ultimate_bearing_capacity: q_ult,
+        allowable_bearing_capacity: q_allow,
+        is_safe: foundation_pressure <= q_allow,
+        ground_factors,
+        base_factors,
+        qmax: foundation_pressure,
+        method: MethodId::Hansen,
+        effective_area,
+        compressibility_factors: None,
+        seismic: None,
+        pressure_convention,
+    })
+}
@@ -1,6 +1,10 @@
-use crate::enums::AnalysisTerm;
+use crate::enums::{
+    AnalysisTerm, EmpiricalGeometryAdjustment, FoundationShape, GroundwaterCase,
+    PressureConvention, ShearFailureMode,
+};
 use crate::models::foundation::Foundation;
-use crate::models::soil_profile::SoilProfile;
+use crate::models::soil_profile::{SoilLayer, SoilProfile};
+use crate::validation::ValidationError;
 
 use super::model::SoilParams;
 
@@ -34,8 +38,61 @@ pub fn compute_equivalent_unit_weights(profile: &SoilProfile, depth_limit: f64)
     (gamma_dry, gamma_saturated)
 }
 
+/// Returns the groundwater level used in the bearing capacity equation.
+///
+/// For drained conditions the groundwater is taken below the influence zone Df + B.
+fn get_design_groundwater_level(
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    term: AnalysisTerm,
+) -> f64 {
+    let df = foundation.foundation_depth.unwrap();
+    let width = foundation.effective_width.unwrap();
+
+    if soil_profile.get_layer_at_depth(df).is_undrained(term) {
+        soil_profile.ground_water_level.unwrap()
+    } else {
+        df + width
+    }
+}
+
+/// Classifies the groundwater correction case of Das from the groundwater level.
+///
+/// * Case I: groundwater at or above the foundation base (0 ≤ D1 ≤ Df)
+/// * Case II: groundwater within B below the base (Df < d ≤ Df + B)
+/// * Case III: groundwater below the influence zone (d > Df + B)
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile with layers and water level.
+/// * `foundation` - The foundation depth and width.
+/// * `term` - Short-term or long-term condition.
+///
+/// # Returns
+/// * `GroundwaterCase`: The groundwater correction case.
+pub fn classify_groundwater_case(
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    term: AnalysisTerm,
+) -> GroundwaterCase {
+    let df = foundation.foundation_depth.unwrap();
+    let width = foundation.effective_width.unwrap();
+    let gwt = get_design_groundwater_level(soil_profile, foundation, term);
+
+    if gwt <= df {
+        GroundwaterCase::CaseI
+    } else if gwt < df + width {
+        GroundwaterCase::CaseII
+    } else {
+        GroundwaterCase::CaseIII
+    }
+}
+
 /// Calculates the effective surcharge (overburden pressure) at the foundation level.
 ///
+/// In Case I, q = D1 · γ + D2 · γ', where D1 is the groundwater depth and D2 = Df − D1.
+/// Otherwise q = γ · Df. When a downslope embedment is set on the foundation, the reduced soil
+/// cover on the downslope side is used in place of Df.
+///
 /// # Arguments
 /// * `soil_profile` - SoilProfile with unit weights and groundwater depth.
 /// * `foundation_data` - Foundation data containing foundation depth and width.
@@ -49,25 +106,30 @@ pub fn calc_effective_surcharge(
     term: AnalysisTerm,
 ) -> f64 {
     let df = foundation_data.foundation_depth.unwrap();
-    let width = foundation_data.effective_width.unwrap();
+    // Soil cover on the downslope side, measured from its lowered ground surface
+    let embedment = foundation_data.calc_downslope_embedment();
 
     let (gamma_dry, gamma_saturated) = compute_equivalent_unit_weights(soil_profile, df);
-    let gamma_effective = gamma_saturated - 0.981; // γ_w assumed as 0.981 tf/m³ (≈ 9.81 kN/m³)
-
-    let gwt = match term {
-        AnalysisTerm::Short => soil_profile.ground_water_level.unwrap(),
-        AnalysisTerm::Long => df + width,
-    };
+    let gamma_effective = soil_profile
+        .get_environment()
+        .submerged_unit_weight(gamma_saturated);
 
-    if gwt <= df {
-        gamma_dry * gwt + gamma_effective * (df - gwt)
-    } else {
-        gamma_dry * df
+    match classify_groundwater_case(soil_profile, foundation_data, term) {
+        GroundwaterCase::CaseI => {
+            let gwt = get_design_groundwater_level(soil_profile, foundation_data, term);
+            let dry_depth = (gwt - (df - embedment)).max(0.0);
+            gamma_dry * dry_depth + gamma_effective * (embedment - dry_depth)
+        }
+        GroundwaterCase::CaseII | GroundwaterCase::CaseIII => gamma_dry * embedment,
     }
 }
 
-/// Calculates the effective unit weight between the surface and Df + B,
-/// depending on groundwater position and load duration.
+/// Calculates the effective unit weight of the γ-term from the groundwater correction case of
+/// Das.
+///
+/// * Case I: γ' = γsat − γw
+/// * Case II: γ̄ = γ' + d / B · (γ − γ'), where d is the groundwater depth below the base
+/// * Case III: γ
 ///
 /// # Arguments
 /// * `soil_profile` - The soil profile with layers and water level.
@@ -85,23 +147,17 @@ pub fn calc_effective_unit_weight(
     let width = foundation.effective_width.unwrap();
 
     let (gamma_dry, gamma_saturated) = compute_equivalent_unit_weights(soil_profile, df);
-    let gamma_effective = gamma_saturated - 0.981; // Subtract unit weight of water (kN/m³)
+    let gamma_effective = soil_profile
+        .get_environment()
+        .submerged_unit_weight(gamma_saturated);
 
-    let gwt = match term {
-        AnalysisTerm::Short => soil_profile.ground_water_level.unwrap(),
-        AnalysisTerm::Long => df + width,
-    };
-
-    if gwt <= df {
-        // Entire zone is below groundwater
-        gamma_effective
-    } else if gwt < df + width {
-        // Partially submerged zone
-        let d = df + width - gwt;
-        gamma_effective + d * (gamma_dry - gamma_effective) / width
-    } else {
-        // Entire zone is above groundwater
-        gamma_dry
+    match classify_groundwater_case(soil_profile, foundation, term) {
+        GroundwaterCase::CaseI => gamma_effective,
+        GroundwaterCase::CaseII => {
+            let d = get_design_groundwater_level(soil_profile, foundation, term) - df;
+            gamma_effective + d / width * (gamma_dry - gamma_effective)
+        }
+        GroundwaterCase::CaseIII => gamma_dry,
     }
 }
 
@@ -116,6 +172,9 @@ pub fn calc_effective_unit_weight(
 /// * `foundation` - The foundation depth and width.
 /// * `term` - Short-term or long-term condition.
 ///
+/// For local and punching shear failure the strength is reduced as in Terzaghi (1943):
+/// c* = 2/3 · c and tan φ* = 2/3 · tan φ.
+///
 /// # Returns
 /// * `SoilParams`: Soil parameters (φ, c, γ') for the foundation depth and term.
 pub fn get_soil_params(
@@ -126,16 +185,146 @@ pub fn get_soil_params(
     let depth = foundation.foundation_depth.unwrap();
     let layer = soil_profile.get_layer_at_depth(depth);
 
-    let (friction_angle, cohesion) = match term {
-        AnalysisTerm::Short => (layer.phi_u.unwrap(), layer.cu.unwrap()),
-        AnalysisTerm::Long => (layer.phi_prime.unwrap(), layer.c_prime.unwrap()),
+    let (mut friction_angle, mut cohesion) = if layer.is_undrained(term) {
+        (layer.phi_u.unwrap(), layer.cu.unwrap())
+    } else {
+        (layer.phi_prime.unwrap(), layer.c_prime.unwrap())
     };
 
+    let failure_mode = match foundation.failure_mode {
+        Some(ShearFailureMode::Auto) => classify_failure_mode(layer, term),
+        Some(mode) => mode,
+        None => ShearFailureMode::General,
+    };
+    if failure_mode != ShearFailureMode::General {
+        cohesion *= 2.0 / 3.0;
+        friction_angle = (2.0 / 3.0 * friction_angle.to_radians().tan())
+            .atan()
+            .to_degrees();
+    }
+
     let unit_weight = calc_effective_unit_weight(soil_profile, foundation, term);
+    let groundwater_case = classify_groundwater_case(soil_profile, foundation, term);
 
     SoilParams {
         friction_angle,
         cohesion,
         unit_weight,
+        failure_mode,
+        groundwater_case,
+    }
+}
+
+/// Classifies the shear failure mode from the state of the soil below the foundation
+/// (Vesic, 1973).
+///
+/// * Granular soil: general for Dr ≥ 67%, local for 35% ≤ Dr < 67%, punching for Dr < 35%
+/// * Cohesive soil: general for cu ≥ 5 t/m² (stiff), local for 2.5 ≤ cu < 5 t/m² (firm),
+///   punching for cu < 2.5 t/m² (soft)
+///
+/// If the relative density or undrained shear strength is not known, general shear is assumed.
+///
+/// # Arguments
+/// * `layer` - The soil layer at the foundation depth.
+/// * `term` - Short-term or long-term condition.
+///
+/// # Returns
+/// * `ShearFailureMode`: The governing failure mode.
+pub fn classify_failure_mode(layer: &SoilLayer, term: AnalysisTerm) -> ShearFailureMode {
+    let (value, general, local) = if layer.is_cohesive() || layer.is_undrained(term) {
+        (layer.cu, 5.0, 2.5)
+    } else {
+        (layer.relative_density, 67.0, 35.0)
+    };
+
+    match value {
+        Some(v) if v >= general => ShearFailureMode::General,
+        Some(v) if v >= local => ShearFailureMode::Local,
+        Some(_) => ShearFailureMode::Punching,
+        None => ShearFailureMode::General,
+    }
+}
+
+/// Calculates the shape factor of Skempton (1951) used by the empirical bearing capacity methods.
+///
+/// s = 1 + 0.2 · B / L, which gives 1.2 for square and circular footings and 1.0 for strip footings.
+/// Circular and ring footings use the square value regardless of the given dimensions. For other
+/// shapes, if the foundation width or length is not provided, the factor is 1.0.
+///
+/// # Arguments
+/// * `foundation` - The foundation data.
+///
+/// # Returns
+/// * `f64`: Shape factor.
+pub fn calc_empirical_shape_factor(foundation: &Foundation) -> f64 {
+    if let FoundationShape::Circular { .. } | FoundationShape::Ring { .. } = foundation.shape {
+        return 1.2;
     }
+
+    match (foundation.foundation_width, foundation.foundation_length) {
+        (Some(width), Some(length)) if width > 0.0 && length > 0.0 => {
+            1.0 + 0.2 * foundation.calc_shape_ratio(width.min(length), width.max(length))
+        }
+        _ => 1.0,
+    }
+}
+
+/// Returns the shape and depth factors of the empirical bearing capacity methods for the
+/// geometry adjustment of the foundation.
+///
+/// Without an adjustment both factors are 1.0, as the methods are published. The Terzaghi (1943)
+/// shape factor is 1 + 0.3 · B / L; if the width or length is not provided it is 1.0.
+///
+/// # Arguments
+/// * `foundation` - The foundation data.
+///
+/// # Returns
+/// * `(f64, f64)`: Shape factor and depth factor.
+pub fn calc_empirical_geometry_factors(foundation: &Foundation) -> (f64, f64) {
+    match foundation.geometry_adjustment {
+        None => (1.0, 1.0),
+        Some(EmpiricalGeometryAdjustment::Terzaghi) => {
+            match (foundation.foundation_width, foundation.foundation_length) {
+                (Some(width), Some(length)) if width > 0.0 && length > 0.0 => {
+                    (1.0 + 0.3 * width.min(length) / width.max(length), 1.0)
+                }
+                _ => (1.0, 1.0),
+            }
+        }
+        Some(EmpiricalGeometryAdjustment::Custom {
+            shape_factor,
+            depth_factor,
+        }) => (shape_factor, depth_factor),
+    }
+}
+
+/// Returns the gross foundation pressure checked by the empirical bearing capacity methods.
+///
+/// A gross pressure is returned as given, so the soil profile is only validated and used to add
+/// the overburden σv(Df) to a net pressure.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `foundation` - The foundation data.
+/// * `foundation_pressure` - Foundation pressure (t/m²).
+/// * `pressure_convention` - Whether `foundation_pressure` is gross or net.
+///
+/// # Returns
+/// * `Result<f64, ValidationError>`: Gross foundation pressure (t/m²).
+pub fn calc_checked_gross_pressure(
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    foundation_pressure: f64,
+    pressure_convention: PressureConvention,
+) -> Result<f64, ValidationError> {
+    if pressure_convention == PressureConvention::Gross {
+        return Ok(foundation_pressure);
+    }
+
+    soil_profile.validate(&["thickness", "dry_unit_weight", "saturated_unit_weight"])?;
+    foundation.validate(&["foundation_depth"])?;
+    let mut soil_profile = soil_profile.clone();
+    soil_profile.calc_layer_depths();
+
+    Ok(foundation.calc_gross_pressure(&soil_profile, foundation_pressure, pressure_convention))
 }
@@ -0,0 +1,153 @@
+use serde::Serialize;
+
+use crate::{
+    constants::MPA_TO_TON,
+    diagnostics::{check_limits, Warning},
+    enums::{MethodId, PressureConvention},
+    models::{foundation::Foundation, rock_mass::RockMassHoekBrown, soil_profile::SoilProfile},
+    validation::{validate_field, ValidationError},
+};
+
+use super::helper_functions::{calc_checked_gross_pressure, calc_empirical_shape_factor};
+
+/// Represents the Hoek-Brown bearing capacity result for a footing on rock.
+#[derive(Debug, Serialize)]
+pub struct Output {
+    /// Reduced material constant mb.
+    pub mb: f64,
+
+    /// Rock mass constant s.
+    pub s: f64,
+
+    /// Rock mass constant a.
+    pub a: f64,
+
+    /// Shape factor of the foundation.
+    pub shape_factor: f64,
+
+    /// Ultimate bearing capacity in ton/m2.
+    pub ultimate_bearing_capacity: f64,
+
+    /// Allowable bearing capacity in ton/m2.
+    pub allowable_bearing_capacity: f64,
+
+    /// The gross pressure exerted by the foundation in ton/m2.
+    pub qmax: f64,
+
+    /// Indicates whether the bearing capacity is safe.
+    pub is_safe: bool,
+
+    /// Safety factor used in the design.
+    pub safety_factor: f64,
+
+    /// Method used for the analysis.
+    pub method: MethodId,
+
+    /// Warnings for inputs outside the calibration range of the method.
+    pub warnings: Vec<Warning>,
+
+    /// Convention of the foundation pressure given to the method; `qmax` is always gross.
+    pub pressure_convention: PressureConvention,
+}
+
+pub fn validate_input(
+    rock_mass: &RockMassHoekBrown,
+    foundation_pressure: f64,
+    safety_factor: f64,
+) -> Result<(), ValidationError> {
+    rock_mass.validate(&["gsi", "mi", "disturbance_factor", "ucs"])?;
+    validate_field(
+        "foundation_pressure",
+        Some(foundation_pressure),
+        Some(0.0),
+        None,
+        "loads",
+    )?;
+    validate_field(
+        "safety_factor",
+        Some(safety_factor),
+        Some(1.),
+        None,
+        "safety_factor",
+    )?;
+    Ok(())
+}
+
+/// Calculates the ultimate bearing capacity of a strip footing on a Hoek-Brown rock mass
+/// (Wyllie, 1999).
+///
+/// Following Bell's wedge solution, the active zone below the footing is confined by the
+/// passive zone, whose major principal stress at failure under zero confinement is
+/// σ1,II = σci · s^a:
+///
+/// qult = σci · s^a + σci · (mb · s^a + s)^a
+///
+/// # Arguments
+/// * `ucs` - Uniaxial compressive strength of the intact rock σci (MPa).
+/// * `mb` - Reduced material constant.
+/// * `s` - Rock mass constant s.
+/// * `a` - Rock mass constant a.
+///
+/// # Returns
+/// * `f64` - Ultimate bearing capacity (t/m²).
+pub fn calc_strip_bearing_capacity(ucs: f64, mb: f64, s: f64, a: f64) -> f64 {
+    let confinement = s.powf(a);
+    let q_ult = ucs * confinement + ucs * (mb * confinement + s).powf(a);
+
+    q_ult * MPA_TO_TON
+}
+
+/// Calculates the bearing capacity of a footing on rock from the Hoek-Brown failure criterion.
+/// The strip footing capacity is adjusted for footing shape with the shape factor of Skempton
+/// (1951).
+///
+/// # Arguments
+/// * `rock_mass` - The rock mass data.
+/// * `soil_profile` - The soil profile data, used to convert a net foundation pressure.
+/// * `foundation` - The foundation data.
+/// * `foundation_pressure` - The pressure exerted by the foundation (t/m²).
+/// * `pressure_convention` - Whether `foundation_pressure` is gross or net.
+/// * `safety_factor` - The safety factor for the design.
+///
+/// # Returns
+/// * `Output` - The bearing capacity result.
+pub fn calc_bearing_capacity(
+    rock_mass: &RockMassHoekBrown,
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    foundation_pressure: f64,
+    pressure_convention: PressureConvention,
+    safety_factor: f64,
+) -> Result<Output, ValidationError> {
+    validate_input(rock_mass, foundation_pressure, safety_factor)?;
+    let foundation_pressure = calc_checked_gross_pressure(
+        soil_profile,
+        foundation,
+        foundation_pressure,
+        pressure_convention,
+    )?;
+
+    let mb = rock_mass.calc_mb();
+    let s = rock_mass.calc_s();
+    let a = rock_mass.calc_a();
+
+    let shape_factor = calc_empirical_shape_factor(foundation);
+    let ultimate_bearing_capacity =
+        calc_strip_bearing_capacity(rock_mass.ucs.unwrap(), mb, s, a) * shape_factor;
+    let allowable_bearing_capacity = ultimate_bearing_capacity / safety_factor;
+
+    Ok(Output {
+        mb,
+        s,
+        a,
+        shape_factor,
+        ultimate_bearing_capacity,
+        allowable_bearing_capacity,
+        qmax: foundation_pressure,
+        is_safe: foundation_pressure <= allowable_bearing_capacity,
+        safety_factor,
+        method: MethodId::HoekBrown,
+        warnings: check_limits(MethodId::HoekBrown, &[("gsi", rock_mass.gsi.unwrap())]),
+        pressure_convention,
+    })
+}
@@ -0,0 +1,282 @@
+use serde::Serialize;
+
+use crate::{
+    enums::{MethodId, PmtSoilCategory, PressureConvention},
+    models::{
+        foundation::Foundation,
+        pressuremeter::{PressuremeterExp, PressuremeterTest},
+        soil_profile::SoilProfile,
+    },
+    validation::{validate_field, ValidationError},
+};
+
+/// Coefficient of earth pressure at rest used to estimate the horizontal stress p0.
+const EARTH_PRESSURE_AT_REST: f64 = 0.5;
+
+/// Represents the pressuremeter bearing capacity result for a given soil and foundation setup.
+#[derive(Debug, Serialize)]
+pub struct Output {
+    /// Equivalent net limit pressure ple* in ton/m2.
+    pub equivalent_net_limit_pressure: f64,
+
+    /// Equivalent embedment depth De in meters.
+    pub equivalent_embedment: f64,
+
+    /// Bearing factor kp.
+    pub bearing_factor: f64,
+
+    /// Total vertical stress at the foundation base q0 in ton/m2.
+    pub overburden_pressure: f64,
+
+    /// Ultimate bearing capacity in ton/m2.
+    pub ultimate_bearing_capacity: f64,
+
+    /// Allowable bearing capacity in ton/m2.
+    pub allowable_bearing_capacity: f64,
+
+    /// The gross pressure exerted by the foundation in ton/m2.
+    pub qmax: f64,
+
+    /// Indicates whether the bearing capacity is safe.
+    pub is_safe: bool,
+
+    /// Safety factor used in the design.
+    pub safety_factor: f64,
+
+    /// Method used for the analysis.
+    pub method: MethodId,
+
+    /// Convention of the foundation pressure given to the method; `qmax` is always gross.
+    pub pressure_convention: PressureConvention,
+}
+
+pub fn validate_input(
+    pressuremeter_test: &PressuremeterTest,
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    foundation_pressure: f64,
+    safety_factor: f64,
+) -> Result<(), ValidationError> {
+    pressuremeter_test.validate(&["depth", "limit_pressure", "menard_modulus"])?;
+    soil_profile.validate(&["thickness", "dry_unit_weight", "saturated_unit_weight"])?;
+    foundation.validate(&["foundation_depth", "foundation_width", "foundation_length"])?;
+    validate_field(
+        "foundation_pressure",
+        Some(foundation_pressure),
+        Some(0.0),
+        None,
+        "loads",
+    )?;
+    validate_field(
+        "safety_factor",
+        Some(safety_factor),
+        Some(1.),
+        None,
+        "safety_factor",
+    )?;
+    Ok(())
+}
+
+/// Calculates the net limit pressure pl* = pl − p0 at a depth.
+///
+/// The horizontal stress at rest is estimated as p0 = K0 · σ'v + u with K0 = 0.5.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `depth` - Depth of the test point (m).
+/// * `limit_pressure` - Limit pressure pl (t/m²).
+///
+/// # Returns
+/// * `f64` - Net limit pressure (t/m²), not less than 0.
+pub fn calc_net_limit_pressure(soil_profile: &SoilProfile, depth: f64, limit_pressure: f64) -> f64 {
+    let gwl = soil_profile.ground_water_level.unwrap();
+    let pore_pressure = soil_profile
+        .get_environment()
+        .hydrostatic_pressure(depth - gwl);
+    let p0 = EARTH_PRESSURE_AT_REST * soil_profile.calc_effective_stress(depth) + pore_pressure;
+
+    (limit_pressure - p0).max(0.0)
+}
+
+/// Calculates the equivalent net limit pressure ple* as the geometric mean of the net limit
+/// pressures between the foundation base and 1.5B below it.
+///
+/// If no test point lies in this zone, the first test point below the base is used.
+///
+/// # Arguments
+/// * `pmt_exp` - The idealized pressuremeter experiment.
+/// * `soil_profile` - The soil profile data.
+/// * `foundation_depth` - Depth of the foundation base (m).
+/// * `width` - Width of the foundation (m).
+///
+/// # Returns
+/// * `f64` - Equivalent net limit pressure (t/m²).
+pub fn calc_equivalent_net_limit_pressure(
+    pmt_exp: &PressuremeterExp,
+    soil_profile: &SoilProfile,
+    foundation_depth: f64,
+    width: f64,
+) -> f64 {
+    let bottom = foundation_depth + 1.5 * width;
+    let values: Vec<f64> = pmt_exp
+        .samples
+        .iter()
+        .filter(|s| s.depth.unwrap() >= foundation_depth && s.depth.unwrap() <= bottom)
+        .map(|s| calc_net_limit_pressure(soil_profile, s.depth.unwrap(), s.limit_pressure.unwrap()))
+        .collect();
+
+    if values.is_empty() {
+        let sample = pmt_exp.get_sample_at_depth(foundation_depth);
+        return calc_net_limit_pressure(
+            soil_profile,
+            sample.depth.unwrap(),
+            sample.limit_pressure.unwrap(),
+        );
+    }
+
+    let log_sum: f64 = values.iter().map(|pl| pl.ln()).sum();
+    (log_sum / values.len() as f64).exp()
+}
+
+/// Calculates the equivalent embedment depth De = (1 / ple*) · ∫ pl*(z) dz from the surface to
+/// the foundation base.
+///
+/// The net limit pressure of a test point applies from the previous test point down to it.
+///
+/// # Arguments
+/// * `pmt_exp` - The idealized pressuremeter experiment.
+/// * `soil_profile` - The soil profile data.
+/// * `foundation_depth` - Depth of the foundation base (m).
+/// * `equivalent_net_limit_pressure` - Equivalent net limit pressure ple* (t/m²).
+///
+/// # Returns
+/// * `f64` - Equivalent embedment depth (m).
+pub fn calc_equivalent_embedment(
+    pmt_exp: &PressuremeterExp,
+    soil_profile: &SoilProfile,
+    foundation_depth: f64,
+    equivalent_net_limit_pressure: f64,
+) -> f64 {
+    if equivalent_net_limit_pressure <= 0.0 {
+        return 0.0;
+    }
+
+    let mut integral = 0.0;
+    let mut top = 0.0;
+
+    while top < foundation_depth {
+        let sample = pmt_exp.get_sample_at_depth(top + 1e-9);
+        let sample_depth = sample.depth.unwrap();
+        let bottom = if sample_depth > top {
+            sample_depth.min(foundation_depth)
+        } else {
+            foundation_depth
+        };
+        let pl_net =
+            calc_net_limit_pressure(soil_profile, sample_depth, sample.limit_pressure.unwrap());
+
+        integral += pl_net * (bottom - top);
+        top = bottom;
+    }
+
+    integral / equivalent_net_limit_pressure
+}
+
+/// Calculates the bearing factor kp = k0 · [1 + a · (0.6 + 0.4 · B / L) · De / B] of
+/// Fascicule 62-V (1993).
+///
+/// # Arguments
+/// * `category` - Soil category.
+/// * `foundation` - The foundation data.
+/// * `equivalent_embedment` - Equivalent embedment depth De (m).
+///
+/// # Returns
+/// * `f64` - Bearing factor kp.
+pub fn calc_bearing_factor(
+    category: PmtSoilCategory,
+    foundation: &Foundation,
+    equivalent_embedment: f64,
+) -> f64 {
+    let width = foundation.foundation_width.unwrap();
+    let length = foundation.foundation_length.unwrap();
+
+    let (k0, a) = match category {
+        PmtSoilCategory::ClayA => (0.8, 0.25),
+        PmtSoilCategory::ClayB => (0.8, 0.35),
+        PmtSoilCategory::ClayC => (0.8, 0.50),
+        PmtSoilCategory::SandA => (1.0, 0.35),
+        PmtSoilCategory::SandB => (1.0, 0.50),
+        PmtSoilCategory::SandC => (1.0, 0.80),
+        PmtSoilCategory::Chalk => (1.3, 0.27),
+        PmtSoilCategory::Marl | PmtSoilCategory::WeatheredRock => (1.0, 0.27),
+    };
+
+    let shape_term = 0.6 + 0.4 * width.min(length) / width.max(length);
+    k0 * (1.0 + a * shape_term * equivalent_embedment / width)
+}
+
+/// Calculates the bearing capacity of a foundation from Menard pressuremeter test results.
+///
+/// qu = kp · ple* + q0 and qa = kp · ple* / FS + q0, where q0 is the total vertical stress at the
+/// foundation base.
+///
+/// # Arguments
+/// * `pressuremeter_test` - The pressuremeter test data.
+/// * `soil_profile` - The soil profile data.
+/// * `foundation` - The foundation data.
+/// * `category` - Soil category below the foundation.
+/// * `foundation_pressure` - The pressure exerted by the foundation (t/m²).
+/// * `pressure_convention` - Whether `foundation_pressure` is gross or net.
+/// * `safety_factor` - The safety factor for the design.
+///
+/// # Returns
+/// * `Output` - The bearing capacity result.
+pub fn calc_bearing_capacity(
+    pressuremeter_test: &PressuremeterTest,
+    soil_profile: &mut SoilProfile,
+    foundation: &Foundation,
+    category: PmtSoilCategory,
+    foundation_pressure: f64,
+    pressure_convention: PressureConvention,
+    safety_factor: f64,
+) -> Result<Output, ValidationError> {
+    validate_input(
+        pressuremeter_test,
+        soil_profile,
+        foundation,
+        foundation_pressure,
+        safety_factor,
+    )?;
+    soil_profile.calc_layer_depths();
+    let foundation_pressure =
+        foundation.calc_gross_pressure(soil_profile, foundation_pressure, pressure_convention);
+
+    let pmt_exp = pressuremeter_test.get_idealized_exp("idealized".to_string());
+    let df = foundation.foundation_depth.unwrap();
+    let width = foundation.foundation_width.unwrap();
+
+    let equivalent_net_limit_pressure =
+        calc_equivalent_net_limit_pressure(&pmt_exp, soil_profile, df, width);
+    let equivalent_embedment =
+        calc_equivalent_embedment(&pmt_exp, soil_profile, df, equivalent_net_limit_pressure);
+    let bearing_factor = calc_bearing_factor(category, foundation, equivalent_embedment);
+    let overburden_pressure = soil_profile.calc_normal_stress(df);
+
+    let net_capacity = bearing_factor * equivalent_net_limit_pressure;
+    let ultimate_bearing_capacity = net_capacity + overburden_pressure;
+    let allowable_bearing_capacity = net_capacity / safety_factor + overburden_pressure;
+
+    Ok(Output {
+        equivalent_net_limit_pressure,
+        equivalent_embedment,
+        bearing_factor,
+        overburden_pressure,
+        ultimate_bearing_capacity,
+        allowable_bearing_capacity,
+        qmax: foundation_pressure,
+        is_safe: foundation_pressure <= allowable_bearing_capacity,
+        safety_factor,
+        method: MethodId::Menard,
+        pressure_convention,
+    })
+}
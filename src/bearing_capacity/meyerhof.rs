@@ -0,0 +1,259 @@
+use std::f64::consts::PI;
+
+use crate::{
+    enums::{AnalysisTerm, MethodId, PressureConvention},
+    models::{foundation::Foundation, loads::Loads, soil_profile::SoilProfile},
+    validation::ValidationError,
+};
+
+use super::{helper_functions::*, model::*, vesic};
+
+/// Friction angle (degrees) below which the shape and depth factors of the q and γ terms are 1.
+const MIN_FRICTION_ANGLE_FOR_SQ: f64 = 10.0;
+
+/// Validates the input data for Meyerhof bearing capacity calculations.
+///
+/// The same inputs as the Vesic method are required.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `foundation` - The foundation data.
+/// * `loading` - The applied loads.
+/// * `term` - Short or long-term condition.
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    loading: &Loads,
+    term: AnalysisTerm,
+) -> Result<(), ValidationError> {
+    vesic::validate_input(soil_profile, foundation, loading, term)
+}
+
+/// Returns the Rankine passive earth pressure coefficient Kp = tan²(45 + φ/2).
+fn calc_kp(phi: f64) -> f64 {
+    (45.0 + phi / 2.0).to_radians().tan().powi(2)
+}
+
+/// Computes the Meyerhof (1963) bearing capacity factors based on the friction angle φ (degrees).
+///
+/// * Nq = e^(π tan φ) · tan²(45 + φ/2)
+/// * Nc = (Nq − 1) · cot φ, 5.14 for φ = 0
+/// * Nγ = (Nq − 1) · tan(1.4φ)
+///
+/// # Arguments
+/// * `phi` - Friction angle in degrees.
+///
+/// # Returns
+/// * `BearingCapacityFactors` containing Nc, Nq, and Ng.
+pub fn calc_bearing_capacity_factors(phi: f64) -> BearingCapacityFactors {
+    let tan_phi = phi.to_radians().tan();
+    let nq = (PI * tan_phi).exp() * calc_kp(phi);
+
+    let nc = if phi == 0.0 {
+        5.14
+    } else {
+        (nq - 1.0) / tan_phi
+    };
+
+    let ng = (nq - 1.0) * (1.4 * phi).to_radians().tan();
+
+    BearingCapacityFactors { nc, nq, ng }
+}
+
+/// Calculates the Meyerhof shape factors.
+///
+/// * Sc = 1 + 0.2 · Kp · B/L
+/// * Sq = Sγ = 1 + 0.1 · Kp · B/L for φ ≥ 10°, 1 otherwise
+///
+/// # Arguments
+/// * `foundation` - Foundation data (width and length)
+/// * `phi` - Friction angle in degrees
+///
+/// # Returns
+/// * `ShapeFactors`: shape coefficients for Sc, Sq, and Sg
+pub fn calc_shape_factors(foundation: &Foundation, phi: f64) -> ShapeFactors {
+    let w_l = foundation.calc_shape_ratio(
+        foundation.foundation_width.unwrap(),
+        foundation.foundation_length.unwrap(),
+    );
+    let kp = calc_kp(phi);
+
+    let sc = 1.0 + 0.2 * kp * w_l;
+    let sq = if phi >= MIN_FRICTION_ANGLE_FOR_SQ {
+        1.0 + 0.1 * kp * w_l
+    } else {
+        1.0
+    };
+
+    ShapeFactors { sc, sq, sg: sq }
+}
+
+/// Calculates the Meyerhof depth factors.
+///
+/// * dc = 1 + 0.2 · √Kp · Df/B
+/// * dq = dγ = 1 + 0.1 · √Kp · Df/B for φ ≥ 10°, 1 otherwise
+///
+/// # Arguments
+/// * `foundation` - Foundation data
+/// * `phi` - Friction angle in degrees
+///
+/// # Returns
+/// * `DepthFactors`: dc, dq, dg coefficients
+pub fn calc_depth_factors(foundation: &Foundation, phi: f64) -> DepthFactors {
+    let d_b = foundation.calc_downslope_embedment() / foundation.foundation_width.unwrap();
+    let sqrt_kp = calc_kp(phi).sqrt();
+
+    let dc = 1.0 + 0.2 * sqrt_kp * d_b;
+    let dq = if phi >= MIN_FRICTION_ANGLE_FOR_SQ {
+        1.0 + 0.1 * sqrt_kp * d_b
+    } else {
+        1.0
+    };
+
+    DepthFactors { dc, dq, dg: dq }
+}
+
+/// Calculates the Meyerhof inclination factors from the inclination θ of the resultant load
+/// from the vertical.
+///
+/// * ic = iq = (1 − θ/90)²
+/// * iγ = (1 − θ/φ)², 0 when θ ≥ φ
+///
+/// # Arguments
+/// * `phi` - Friction angle in degrees
+/// * `loading` - Loads with vertical and horizontal components
+///
+/// # Returns
+/// * `InclinationFactors`: ic, iq, ig coefficients
+pub fn calc_inclination_factors(phi: f64, loading: &Loads) -> InclinationFactors {
+    let vertical_load = loading.vertical_load.unwrap();
+    let horizontal_load = loading
+        .horizontal_load_x
+        .unwrap_or(0.0)
+        .hypot(loading.horizontal_load_y.unwrap_or(0.0));
+    let theta = horizontal_load.atan2(vertical_load).to_degrees();
+
+    let ic = (1.0 - theta / 90.0).powi(2);
+    let ig = if phi > 0.0 {
+        (1.0 - theta / phi).max(0.0).powi(2)
+    } else {
+        1.0
+    };
+
+    InclinationFactors { ic, iq: ic, ig }
+}
+
+/// Calculates the ultimate and allowable bearing capacity of a foundation by the Meyerhof (1963)
+/// general bearing capacity equation.
+///
+/// q_ult = c · Nc · Sc · dc · ic + q · Nq · Sq · dq · iq + 0.5 · γ · B' · Nγ · Sγ · dγ · iγ
+///
+/// The Meyerhof method has no base or ground inclination factors; they are reported as 1.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `foundation` - The foundation data.
+/// * `loading` - The applied loads.
+/// * `foundation_pressure` - The pressure on the foundation.
+/// * `pressure_convention` - Whether `foundation_pressure` is gross or net.
+/// * `factor_of_safety` - The safety factor to apply.
+/// * `term` - Short or long-term condition.
+///
+/// # Returns
+/// * `BearingCapacityResult` with detailed components and safety check.
+pub fn calc_bearing_capacity(
+    soil_profile: &mut SoilProfile,
+    foundation: &mut Foundation,
+    loading: &Loads,
+    foundation_pressure: f64,
+    pressure_convention: PressureConvention,
+    factor_of_safety: f64,
+    term: AnalysisTerm,
+) -> Result<BearingCapacityResult, ValidationError> {
+    foundation.apply_shape()?;
+    validate_input(soil_profile, foundation, loading, term)?;
+    soil_profile.calc_layer_depths();
+    let foundation_pressure =
+        foundation.calc_gross_pressure(soil_profile, foundation_pressure, pressure_convention);
+    let (ex, ey) = loading.calc_eccentricity();
+    let effective_area = foundation.calc_effective_area(ex, ey);
+
+    let soil_params = get_soil_params(soil_profile, foundation, term);
+    let phi = soil_params.friction_angle;
+    let cohesion = soil_params.cohesion;
+    let effective_unit_weight = soil_params.unit_weight;
+
+    let effective_surcharge = calc_effective_surcharge(soil_profile, foundation, term)
+        + loading.surface_surcharge.unwrap_or(0.0);
+
+    let bearing_capacity_factors = calc_bearing_capacity_factors(phi);
+    let shape_factors = calc_shape_factors(foundation, phi);
+    let depth_factors = calc_depth_factors(foundation, phi);
+    let inclination_factors = calc_inclination_factors(phi, loading);
+    let base_factors = BaseFactors {
+        bc: 1.0,
+        bq: 1.0,
+        bg: 1.0,
+    };
+    let ground_factors = GroundFactors {
+        gc: 1.0,
+        gq: 1.0,
+        gg: 1.0,
+    };
+
+    let effective_width = foundation.effective_width.unwrap();
+    let part_1 = cohesion
+        * bearing_capacity_factors.nc
+        * shape_factors.sc
+        * depth_factors.dc
+        * inclination_factors.ic;
+
+    let part_2 = effective_surcharge
+        * bearing_capacity_factors.nq
+        * shape_factors.sq
+        * depth_factors.dq
+        * inclination_factors.iq;
+
+    let part_3 = 0.5
+        * effective_unit_weight
+        * effective_width
+        * bearing_capacity_factors.ng
+        * shape_factors.sg
+        * depth_factors.dg
+        * inclination_factors.ig;
+
+    let q_ult = part_1 + part_2 + part_3;
+    let q_allow = q_ult / factor_of_safety;
+
+    Ok(BearingCapacityResult {
+        bearing_capacity_factors,
+        shape_factors,
+        depth_factors,
+        load_inclination_factors: inclination_factors,
+        soil_params,
+        terms: BearingCapacityTerms {
+            part_1,
+            part_2,
+            part_3,
+            friction_angle: phi,
+            cohesion,
+            unit_weight: effective_unit_weight,
+            surcharge: effective_surcharge,
+            effective_width,
+        },
+        ultimate_bearing_capacity: q_ult,
+        allowable_bearing_capacity: q_allow,
+        is_safe: foundation_pressure <= q_allow,
+        ground_factors,
+        base_factors,
+        qmax: foundation_pressure,
+        method: MethodId::Meyerhof,
+        effective_area,
+        compressibility_factors: None,
+        seismic: None,
+        pressure_convention,
+    })
+}
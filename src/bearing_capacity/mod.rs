@@ -1,5 +1,19 @@
+pub mod comparison;
+pub mod depth_profile;
+pub mod engineered_fill;
+pub mod eurocode;
+pub mod hansen;
 pub mod helper_functions;
+pub mod hoek_brown;
+pub mod menard;
+pub mod meyerhof;
 pub mod model;
 pub mod point_load_test;
+pub mod seismic;
+pub mod skirted_foundation;
+pub mod slope;
+pub mod spt;
+pub mod sweep;
 pub mod tezcan_ozdemir;
 pub mod vesic;
+pub mod vhm_envelope;
@@ -1,4 +1,11 @@
 use serde::Serialize;
+
+use crate::{
+    diagnostics::Warning,
+    enums::{GroundwaterCase, MethodId, PressureConvention, ShearFailureMode},
+    models::foundation::EffectiveArea,
+};
+
 /// Bearing capacity factors according to Terzaghi, Meyerhof, Hansen, etc.
 #[derive(Debug, Clone, Copy, Serialize)]
 pub struct BearingCapacityFactors {
@@ -47,12 +54,66 @@ pub struct DepthFactors {
     pub dg: f64,
 }
 
+/// Soil compressibility factors of Vesic (1973).
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct CompressibilityFactors {
+    pub rigidity_index: f64,
+    pub critical_rigidity_index: f64,
+    pub zc: f64,
+    pub zq: f64,
+    pub zg: f64,
+}
+
 /// Soil parameters used in bearing capacity calculations.
 #[derive(Debug, Clone, Copy, Serialize)]
 pub struct SoilParams {
     pub friction_angle: f64,
     pub cohesion: f64,
     pub unit_weight: f64,
+    pub failure_mode: ShearFailureMode,
+    pub groundwater_case: GroundwaterCase,
+}
+
+/// Seismic reduction factors applied to Nc, Nq and Nγ.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct SeismicFactors {
+    pub ec: f64,
+    pub eq: f64,
+    pub eg: f64,
+}
+
+/// Seismic (pseudo-static) bearing capacity.
+#[derive(Debug, Serialize)]
+pub struct SeismicBearingCapacity {
+    pub horizontal_coefficient: f64,
+    pub vertical_coefficient: f64,
+    pub seismic_factors: SeismicFactors,
+    pub ultimate_bearing_capacity: f64,
+    pub allowable_bearing_capacity: f64,
+    pub is_safe: bool,
+    pub method: MethodId,
+    pub warnings: Vec<Warning>,
+}
+
+/// Term-by-term breakdown of the ultimate bearing capacity with the inputs of each term.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct BearingCapacityTerms {
+    /// Cohesion term c · Nc · (factors) (t/m²).
+    pub part_1: f64,
+    /// Surcharge term q · Nq · (factors) (t/m²).
+    pub part_2: f64,
+    /// Unit weight term 0.5 · γ' · B' · Nγ · (factors) (t/m²).
+    pub part_3: f64,
+    /// Friction angle φ (degrees).
+    pub friction_angle: f64,
+    /// Cohesion c (t/m²).
+    pub cohesion: f64,
+    /// Effective unit weight γ' (t/m³).
+    pub unit_weight: f64,
+    /// Effective surcharge q at the foundation base (t/m²).
+    pub surcharge: f64,
+    /// Effective foundation width B' (m).
+    pub effective_width: f64,
 }
 
 #[derive(Debug, Serialize)]
@@ -64,8 +125,15 @@ pub struct BearingCapacityResult {
     pub ground_factors: GroundFactors,
     pub base_factors: BaseFactors,
     pub soil_params: SoilParams,
+    pub terms: BearingCapacityTerms,
     pub ultimate_bearing_capacity: f64,
     pub allowable_bearing_capacity: f64,
     pub is_safe: bool,
     pub qmax: f64,
+    pub method: MethodId,
+    pub effective_area: EffectiveArea,
+    pub compressibility_factors: Option<CompressibilityFactors>,
+    pub seismic: Option<SeismicBearingCapacity>,
+    /// Convention of the foundation pressure given to the method; `qmax` is always gross.
+    pub pressure_convention: PressureConvention,
 }
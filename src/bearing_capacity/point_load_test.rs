@@ -1,10 +1,15 @@
 use serde::Serialize;
 
 use crate::{
-    models::{foundation::Foundation, point_load_test::PointLoadTest},
+    constants::MPA_TO_TON,
+    enums::{MethodId, PressureConvention, SelectionMethod, SelectionRule},
+    models::{foundation::Foundation, point_load_test::PointLoadTest, soil_profile::SoilProfile},
+    parameter_selection::apply_selection_rule,
     validation::{validate_field, ValidationError},
 };
 
+use super::helper_functions::{calc_checked_gross_pressure, calc_empirical_geometry_factors};
+
 /// Represents the bearing capacity result for a given soil and foundation setup.
 #[derive(Debug, Serialize)]
 pub struct Output {
@@ -20,10 +25,16 @@ pub struct Output {
     /// Equivalent core diameter in mm.
     pub d: f64,
 
+    /// Shape factor of the foundation.
+    pub shape_factor: f64,
+
+    /// Depth (embedment) factor of the foundation.
+    pub depth_factor: f64,
+
     /// Allowable bearing capacity in ton/m2.
     pub allowable_bearing_capacity: f64,
 
-    /// The pressure exerted by the foundation in ton/m2.
+    /// The gross pressure exerted by the foundation in ton/m2.
     pub qmax: f64,
 
     /// Indicates the depth at which the bearing capacity is calculated in meters.
@@ -34,6 +45,53 @@ pub struct Output {
 
     /// Safety factor used in the design.
     pub safety_factor: f64,
+
+    /// Method used for the analysis.
+    pub method: MethodId,
+
+    /// Convention of the foundation pressure given to the method; `qmax` is always gross.
+    pub pressure_convention: PressureConvention,
+}
+
+/// Statistics of the Is50 samples inside the influence zone of the foundation.
+#[derive(Debug, Serialize)]
+pub struct ZoneStatistics {
+    /// Top of the influence zone (m), equal to the foundation depth.
+    pub zone_top: f64,
+
+    /// Bottom of the influence zone (m), Df + 2B.
+    pub zone_bottom: f64,
+
+    /// Number of samples used in the statistics.
+    pub sample_count: usize,
+
+    /// Smallest Is50 value in MPa.
+    pub min_is50: f64,
+
+    /// Largest Is50 value in MPa.
+    pub max_is50: f64,
+
+    /// Mean Is50 value in MPa.
+    pub mean_is50: f64,
+
+    /// Sample standard deviation of Is50 in MPa.
+    pub std_dev_is50: f64,
+
+    /// Coefficient of variation of Is50 (std_dev / mean).
+    pub coefficient_of_variation: f64,
+
+    /// True if no sample lay inside the zone and the sample at Df was used instead.
+    pub is_fallback: bool,
+}
+
+/// Represents the point load test bearing capacity from the samples in the influence zone.
+#[derive(Debug, Serialize)]
+pub struct ZoneOutput {
+    /// Statistics of the samples in the influence zone.
+    pub statistics: ZoneStatistics,
+
+    /// Bearing capacity computed from the design Is50 of the zone.
+    pub result: Output,
 }
 
 pub fn validate_input(
@@ -43,7 +101,7 @@ pub fn validate_input(
     safety_factor: f64,
 ) -> Result<(), ValidationError> {
     point_load_test.validate(&["is50", "d"])?;
-    foundation.validate(&["foundation_depth"])?;
+    foundation.validate(&["foundation_depth", "geometry_adjustment"])?;
     validate_field(
         "foundation_pressure",
         Some(foundation_pressure),
@@ -102,19 +160,24 @@ pub fn get_generalized_c_value(d: f64) -> f64 {
 }
 
 /// Calculates the bearing capacity of a foundation based on point load test results.
+/// The capacity is adjusted for footing geometry only if the foundation has a geometry adjustment.
 ///
 /// # Arguments
 /// * `point_load_test` - The point load test data.
-/// * `df` - Depth at which to calculate the bearing capacity.
+/// * `soil_profile` - The soil profile data, used to convert a net foundation pressure.
+/// * `foundation` - The foundation data; the capacity is calculated at its depth.
 /// * `foundation_pressure` - The pressure exerted by the foundation.
+/// * `pressure_convention` - Whether `foundation_pressure` is gross or net.
 /// * `safety_factor` - The safety factor for the design.
 ///
 /// # Returns
 /// * `Output` - The bearing capacity result containing various parameters.
 pub fn calc_bearing_capacity(
     point_load_test: PointLoadTest,
+    soil_profile: &SoilProfile,
     foundation: Foundation,
     foundation_pressure: f64,
+    pressure_convention: PressureConvention,
     safety_factor: f64,
 ) -> Result<Output, ValidationError> {
     validate_input(
@@ -123,9 +186,14 @@ pub fn calc_bearing_capacity(
         foundation_pressure,
         safety_factor,
     )?;
+    let foundation_pressure = calc_checked_gross_pressure(
+        soil_profile,
+        &foundation,
+        foundation_pressure,
+        pressure_convention,
+    )?;
     let df = foundation.foundation_depth.unwrap();
     let point_load_test_exp = point_load_test.get_idealized_exp("idealized".to_string());
-    const MPA_TO_TON: f64 = 101.97162; // Conversion factor from MPa to ton/m2
     let sample = point_load_test_exp.get_sample_at_depth(df);
 
     let is50 = sample.is50.unwrap();
@@ -134,7 +202,8 @@ pub fn calc_bearing_capacity(
 
     let ucs = is50 * c * MPA_TO_TON;
 
-    let allowable_bearing_capacity = ucs / safety_factor;
+    let (shape_factor, depth_factor) = calc_empirical_geometry_factors(&foundation);
+    let allowable_bearing_capacity = ucs * shape_factor * depth_factor / safety_factor;
     let is_safe = allowable_bearing_capacity >= foundation_pressure;
 
     Ok(Output {
@@ -142,10 +211,134 @@ pub fn calc_bearing_capacity(
         ucs,
         c,
         d,
+        shape_factor,
+        depth_factor,
         allowable_bearing_capacity,
         is_safe,
         safety_factor,
         qmax: foundation_pressure,
         df,
+        method: MethodId::PointLoadTest,
+        pressure_convention,
+    })
+}
+
+/// Calculates the bearing capacity of a foundation from all point load test samples between
+/// Df and Df + 2B.
+///
+/// Samples of every borehole inside the influence zone are pooled, so that a single outlier
+/// core does not control the design. The design Is50 is selected from the pooled samples with
+/// the idealization method of the test (minimum, mean or maximum), together with the diameter
+/// of the selected sample (or the mean diameter for `Avg`). The spread of the samples is
+/// reported alongside the result. If no sample lies in the zone, the sample at Df is used.
+///
+/// # Arguments
+/// * `point_load_test` - The point load test data.
+/// * `soil_profile` - The soil profile data, used to convert a net foundation pressure.
+/// * `foundation` - The foundation data (depth and width are required).
+/// * `foundation_pressure` - The pressure exerted by the foundation (t/m²).
+/// * `pressure_convention` - Whether `foundation_pressure` is gross or net.
+/// * `safety_factor` - The safety factor for the design.
+///
+/// # Returns
+/// * `ZoneOutput` - The zone statistics and the bearing capacity result.
+pub fn calc_zone_bearing_capacity(
+    point_load_test: &PointLoadTest,
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    foundation_pressure: f64,
+    pressure_convention: PressureConvention,
+    safety_factor: f64,
+) -> Result<ZoneOutput, ValidationError> {
+    validate_input(
+        point_load_test,
+        foundation,
+        foundation_pressure,
+        safety_factor,
+    )?;
+    foundation.validate(&["foundation_width"])?;
+    let foundation_pressure = calc_checked_gross_pressure(
+        soil_profile,
+        foundation,
+        foundation_pressure,
+        pressure_convention,
+    )?;
+
+    let df = foundation.foundation_depth.unwrap();
+    let zone_bottom = df + 2.0 * foundation.foundation_width.unwrap();
+
+    let mut samples: Vec<(f64, f64)> = point_load_test
+        .exps
+        .iter()
+        .flat_map(|exp| exp.samples.iter())
+        .filter(|sample| {
+            let depth = sample.depth.unwrap();
+            depth >= df && depth <= zone_bottom
+        })
+        .map(|sample| (sample.is50.unwrap(), sample.d.unwrap()))
+        .collect();
+
+    let is_fallback = samples.is_empty();
+    if is_fallback {
+        let idealized = point_load_test.get_idealized_exp("idealized".to_string());
+        let sample = idealized.get_sample_at_depth(df);
+        samples.push((sample.is50.unwrap(), sample.d.unwrap()));
+    }
+
+    let values: Vec<f64> = samples.iter().map(|&(is50, _)| is50).collect();
+    let (mean_is50, std_dev_is50, _) = apply_selection_rule(&values, SelectionRule::Mean);
+    let min = samples
+        .iter()
+        .cloned()
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+        .unwrap();
+    let max = samples
+        .iter()
+        .cloned()
+        .max_by(|a, b| a.0.total_cmp(&b.0))
+        .unwrap();
+
+    let (is50, d) = match point_load_test.idealization_method {
+        SelectionMethod::Min => min,
+        SelectionMethod::Max => max,
+        SelectionMethod::Avg => {
+            let mean_d = samples.iter().map(|&(_, d)| d).sum::<f64>() / samples.len() as f64;
+            (mean_is50, mean_d)
+        }
+    };
+
+    let c = get_generalized_c_value(d);
+    let ucs = is50 * c * MPA_TO_TON;
+
+    let (shape_factor, depth_factor) = calc_empirical_geometry_factors(foundation);
+    let allowable_bearing_capacity = ucs * shape_factor * depth_factor / safety_factor;
+
+    Ok(ZoneOutput {
+        statistics: ZoneStatistics {
+            zone_top: df,
+            zone_bottom,
+            sample_count: samples.len(),
+            min_is50: min.0,
+            max_is50: max.0,
+            mean_is50,
+            std_dev_is50,
+            coefficient_of_variation: std_dev_is50 / mean_is50,
+            is_fallback,
+        },
+        result: Output {
+            is50,
+            ucs,
+            c,
+            d,
+            shape_factor,
+            depth_factor,
+            allowable_bearing_capacity,
+            is_safe: allowable_bearing_capacity >= foundation_pressure,
+            safety_factor,
+            qmax: foundation_pressure,
+            df,
+            method: MethodId::PointLoadTest,
+            pressure_convention,
+        },
     })
 }
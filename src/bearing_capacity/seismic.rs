@@ -0,0 +1,173 @@
+use crate::{
+    diagnostics::check_limits,
+    enums::{AnalysisTerm, MethodId, PressureConvention},
+    models::{foundation::Foundation, loads::Loads, soil_profile::SoilProfile},
+    validation::ValidationError,
+};
+
+use super::{helper_functions::*, model::*, vesic};
+
+/// Validates the input data for seismic bearing capacity calculations.
+///
+/// The inputs of the Vesic method and the horizontal seismic coefficient are required. The
+/// vertical seismic coefficient is taken as 0 when it is not set.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `foundation` - The foundation data.
+/// * `loading` - The applied loads with the seismic coefficients.
+/// * `term` - Short or long-term condition.
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    loading: &Loads,
+    term: AnalysisTerm,
+) -> Result<(), ValidationError> {
+    vesic::validate_input(soil_profile, foundation, loading, term)?;
+    loading.validate(&["horizontal_seismic_coefficient"])?;
+
+    if loading.vertical_seismic_coefficient.is_some() {
+        loading.validate(&["vertical_seismic_coefficient"])?;
+    }
+
+    Ok(())
+}
+
+/// Calculates the seismic reduction factors of Budhu & Al-Karni (1993).
+///
+/// * ec = exp(−4.3 · kh^(1 + D)), with D = c / (γ · Df)
+/// * eq = (1 − kv) · exp(−5.3 · kh^1.2 / (1 − kv))
+/// * eγ = (1 − 2kv/3) · exp(−9 · kh^1.2 / (1 − kv))
+///
+/// D is taken as 0 for surface foundations.
+///
+/// # Arguments
+/// * `kh` - Horizontal seismic coefficient.
+/// * `kv` - Vertical seismic coefficient.
+/// * `cohesion` - Cohesion of the soil (t/m²).
+/// * `unit_weight` - Unit weight of the soil (t/m³).
+/// * `foundation_depth` - Depth of the foundation base (m).
+///
+/// # Returns
+/// * `SeismicFactors` containing ec, eq and eg.
+pub fn calc_seismic_factors(
+    kh: f64,
+    kv: f64,
+    cohesion: f64,
+    unit_weight: f64,
+    foundation_depth: f64,
+) -> SeismicFactors {
+    let d = if foundation_depth > 0.0 && unit_weight > 0.0 {
+        cohesion / (unit_weight * foundation_depth)
+    } else {
+        0.0
+    };
+
+    let ec = (-4.3 * kh.powf(1.0 + d)).exp();
+    let eq = (1.0 - kv) * (-5.3 * kh.powf(1.2) / (1.0 - kv)).exp();
+    let eg = (1.0 - 2.0 * kv / 3.0) * (-9.0 * kh.powf(1.2) / (1.0 - kv)).exp();
+
+    SeismicFactors { ec, eq, eg }
+}
+
+/// Calculates the static and seismic (pseudo-static) bearing capacity of a foundation.
+///
+/// The static bearing capacity is calculated by the Vesic method. The seismic bearing capacity
+/// is obtained by reducing Nc, Nq and Nγ with the factors of Budhu & Al-Karni (1993), the other
+/// modification factors being the same as in the static case:
+///
+/// qult,E = c · Nc · ec · (...) + q' · Nq · eq · (...) + 0.5 · γ · B' · Nγ · eγ · (...)
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `foundation` - The foundation data.
+/// * `loading` - The applied loads with the seismic coefficients.
+/// * `foundation_pressure` - The pressure on the foundation (t/m²).
+/// * `pressure_convention` - Whether `foundation_pressure` is gross or net.
+/// * `factor_of_safety` - The safety factor of the bearing capacity.
+/// * `term` - Short or long-term condition.
+///
+/// # Returns
+/// * `BearingCapacityResult` with the static result and the seismic bearing capacity.
+pub fn calc_bearing_capacity(
+    soil_profile: &mut SoilProfile,
+    foundation: &mut Foundation,
+    loading: &Loads,
+    foundation_pressure: f64,
+    pressure_convention: PressureConvention,
+    factor_of_safety: f64,
+    term: AnalysisTerm,
+) -> Result<BearingCapacityResult, ValidationError> {
+    validate_input(soil_profile, foundation, loading, term)?;
+
+    let mut result = vesic::calc_bearing_capacity(
+        soil_profile,
+        foundation,
+        loading,
+        foundation_pressure,
+        pressure_convention,
+        factor_of_safety,
+        term,
+    )?;
+
+    let kh = loading.horizontal_seismic_coefficient.unwrap();
+    let kv = loading.vertical_seismic_coefficient.unwrap_or(0.0);
+
+    let soil_params = result.soil_params;
+    let phi = soil_params.friction_angle;
+    let cohesion = soil_params.cohesion;
+    let effective_surcharge = calc_effective_surcharge(soil_profile, foundation, term)
+        + loading.surface_surcharge.unwrap_or(0.0);
+
+    let seismic_factors = calc_seismic_factors(
+        kh,
+        kv,
+        cohesion,
+        soil_params.unit_weight,
+        foundation.foundation_depth.unwrap(),
+    );
+
+    let bcf = result.bearing_capacity_factors;
+    let sf = result.shape_factors;
+    let df = result.depth_factors;
+    let inf = result.load_inclination_factors;
+    let bf = result.base_factors;
+    let gf = result.ground_factors;
+
+    let q_ult = if phi == 0. {
+        5.14 * cohesion * (1. + sf.sc + df.dc - inf.ic - bf.bc - gf.gc) * seismic_factors.ec
+            + effective_surcharge * seismic_factors.eq
+    } else {
+        let part_1 = cohesion * bcf.nc * sf.sc * df.dc * bf.bc * gf.gc * inf.ic;
+        let part_2 = effective_surcharge * bcf.nq * sf.sq * df.dq * bf.bq * gf.gq * inf.iq;
+        let part_3 = 0.5
+            * soil_params.unit_weight
+            * foundation.effective_width.unwrap()
+            * bcf.ng
+            * sf.sg
+            * df.dg
+            * bf.bg
+            * gf.gg
+            * inf.ig;
+
+        part_1 * seismic_factors.ec + part_2 * seismic_factors.eq + part_3 * seismic_factors.eg
+    };
+
+    let q_allow = q_ult / factor_of_safety;
+
+    result.seismic = Some(SeismicBearingCapacity {
+        horizontal_coefficient: kh,
+        vertical_coefficient: kv,
+        seismic_factors,
+        ultimate_bearing_capacity: q_ult,
+        allowable_bearing_capacity: q_allow,
+        is_safe: foundation_pressure <= q_allow,
+        method: MethodId::BudhuAlKarni,
+        warnings: check_limits(MethodId::BudhuAlKarni, &[("kh", kh)]),
+    });
+
+    Ok(result)
+}
@@ -0,0 +1,234 @@
+use serde::Serialize;
+use std::f64::consts::PI;
+
+use crate::{
+    models::{loads::Loads, skirted_foundation::SkirtedFoundation, soil_profile::SoilProfile},
+    validation::ValidationError,
+};
+
+/// Lateral bearing factor (Np) for the soil wedge in front of the skirt.
+const LATERAL_BEARING_FACTOR: f64 = 9.0;
+
+/// Normalized moment capacity Mult / (A · D · su) of a fully bonded circular base.
+const BASE_MOMENT_FACTOR: f64 = 0.67;
+
+/// Represents the capacity result of a skirted foundation in clay.
+#[derive(Debug, Clone, Serialize)]
+pub struct SkirtedFoundationResult {
+    /// Undrained shear strength at skirt tip level (t/m²).
+    pub su_tip: f64,
+    /// Average undrained shear strength along the skirt (t/m²).
+    pub su_avg: f64,
+    /// Depth corrected bearing factor Nc.
+    pub nc: f64,
+    /// Base resistance (t).
+    pub base_resistance: f64,
+    /// External skirt friction (t).
+    pub skirt_friction: f64,
+    /// Ultimate vertical capacity (t).
+    pub vertical_capacity: f64,
+    /// Ultimate horizontal capacity (t).
+    pub horizontal_capacity: f64,
+    /// Ultimate moment capacity (t·m).
+    pub moment_capacity: f64,
+    /// Exponent of the horizontal term in the H-M envelope.
+    pub exponent_h: f64,
+    /// Exponent of the moment term in the H-M envelope.
+    pub exponent_m: f64,
+    /// Utilization of the vertical capacity including the safety factor.
+    pub vertical_utilization: f64,
+    /// Utilization of the H-M envelope including the safety factor.
+    pub hm_utilization: f64,
+    /// Indicates whether the foundation is safe.
+    pub is_safe: bool,
+}
+
+/// Validates the input data for skirted foundation capacity calculations.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `caisson` - The skirted foundation data.
+/// * `loading` - The applied loads.
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(
+    soil_profile: &SoilProfile,
+    caisson: &SkirtedFoundation,
+    loading: &Loads,
+) -> Result<(), ValidationError> {
+    soil_profile.validate(&[
+        "thickness",
+        "dry_unit_weight",
+        "saturated_unit_weight",
+        "cu",
+    ])?;
+    caisson.validate(&["diameter", "skirt_length", "adhesion_factor"])?;
+    loading.validate(&["vertical_load", "horizontal_load_x", "moment_x"])?;
+
+    if soil_profile.get_total_depth() < caisson.skirt_length.unwrap() {
+        return Err(ValidationError {
+            code: "skirted_foundation.skirt_length.greater_than_soil_profile_depth".to_string(),
+            message: "Skirt length is greater than the soil profile depth.".to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Calculates the thickness-weighted average undrained shear strength between the surface and a given depth.
+fn get_average_cu(soil_profile: &SoilProfile, depth_limit: f64) -> f64 {
+    let mut prev_depth = 0.0;
+    let mut cu_sum = 0.0;
+
+    for layer in soil_profile.layers.iter() {
+        let bottom = layer.depth.unwrap().min(depth_limit);
+        cu_sum += layer.cu.unwrap() * (bottom - prev_depth);
+        prev_depth = bottom;
+
+        if layer.depth.unwrap() >= depth_limit {
+            break;
+        }
+    }
+
+    cu_sum / prev_depth
+}
+
+/// Calculates the exponents of the H-M interaction envelope (Supachawarote et al., 2004).
+///
+/// # Arguments
+/// * `l_d` - Embedment ratio L/D.
+///
+/// # Returns
+/// * `(f64, f64)`: Exponents of the horizontal and moment terms.
+pub fn calc_envelope_exponents(l_d: f64) -> (f64, f64) {
+    (l_d + 0.5, l_d / 3.0 + 4.5)
+}
+
+/// Generates points on the H-M interaction envelope (H/Hult)^a + (M/Mult)^b = 1.
+///
+/// # Arguments
+/// * `horizontal_capacity` - Ultimate horizontal capacity (t).
+/// * `moment_capacity` - Ultimate moment capacity (t·m).
+/// * `l_d` - Embedment ratio L/D.
+/// * `n_points` - Number of points on the envelope.
+///
+/// # Returns
+/// * `Vec<(f64, f64)>`: (H, M) pairs from pure moment to pure horizontal load.
+pub fn calc_interaction_envelope(
+    horizontal_capacity: f64,
+    moment_capacity: f64,
+    l_d: f64,
+    n_points: usize,
+) -> Vec<(f64, f64)> {
+    let (a, b) = calc_envelope_exponents(l_d);
+    let steps = n_points.max(2) - 1;
+
+    (0..=steps)
+        .map(|i| {
+            let h = i as f64 / steps as f64;
+            let m = (1.0 - h.powf(a)).max(0.0).powf(1.0 / b);
+            (h * horizontal_capacity, m * moment_capacity)
+        })
+        .collect()
+}
+
+/// Calculates the radial utilization of a normalized (h, m) load point on the H-M envelope.
+///
+/// The load point is scaled along its radial path until it reaches the envelope; the utilization
+/// is the inverse of that scale factor.
+fn calc_hm_utilization(h: f64, m: f64, a: f64, b: f64) -> f64 {
+    if h == 0.0 && m == 0.0 {
+        return 0.0;
+    }
+
+    let envelope = |scale: f64| (scale * h).powf(a) + (scale * m).powf(b) - 1.0;
+
+    let mut low = 0.0;
+    let mut high = 1.0;
+    while envelope(high) < 0.0 {
+        high *= 2.0;
+    }
+
+    for _ in 0..100 {
+        let mid = 0.5 * (low + high);
+        if envelope(mid) < 0.0 {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+
+    1.0 / high
+}
+
+/// Calculates the vertical, horizontal and moment capacity of a skirted foundation in clay.
+///
+/// * Vertical: V = A · (Nc · su,tip + σ'v,tip) + α · su,avg · π · D · L,
+///   with Nc = 6.2 · (1 + 0.34 · atan(L/D)).
+/// * Horizontal: H = Np · su,avg · L · D.
+/// * Moment: M = H · L / 2 + 0.67 · A · D · su,tip.
+/// * Combined H-M: (H/Hult)^a + (M/Mult)^b ≤ 1, with a = L/D + 0.5 and b = L/(3D) + 4.5.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data, depths measured from the mudline.
+/// * `caisson` - The skirted foundation data.
+/// * `loading` - The applied loads (`vertical_load`, `horizontal_load_x`, `moment_x`).
+/// * `factor_of_safety` - The safety factor to apply.
+///
+/// # Returns
+/// * `SkirtedFoundationResult` with capacities and utilization.
+pub fn calc_bearing_capacity(
+    soil_profile: &mut SoilProfile,
+    caisson: &SkirtedFoundation,
+    loading: &Loads,
+    factor_of_safety: f64,
+) -> Result<SkirtedFoundationResult, ValidationError> {
+    validate_input(soil_profile, caisson, loading)?;
+    soil_profile.calc_layer_depths();
+
+    let d = caisson.diameter.unwrap();
+    let l = caisson.skirt_length.unwrap();
+    let alpha = caisson.adhesion_factor.unwrap();
+    let area = caisson.area();
+    let l_d = l / d;
+
+    let su_tip = soil_profile.get_layer_at_depth(l).cu.unwrap();
+    let su_avg = get_average_cu(soil_profile, l);
+    let sigma_v = soil_profile.calc_effective_stress(l);
+
+    let nc = 6.2 * (1.0 + 0.34 * l_d.atan());
+    let base_resistance = area * (nc * su_tip + sigma_v);
+    let skirt_friction = alpha * su_avg * PI * d * l;
+    let vertical_capacity = base_resistance + skirt_friction;
+
+    let horizontal_capacity = LATERAL_BEARING_FACTOR * su_avg * l * d;
+    let moment_capacity = horizontal_capacity * l / 2.0 + BASE_MOMENT_FACTOR * area * d * su_tip;
+
+    let (exponent_h, exponent_m) = calc_envelope_exponents(l_d);
+
+    let vertical_utilization =
+        loading.vertical_load.unwrap() * factor_of_safety / vertical_capacity;
+    let hm_utilization = calc_hm_utilization(
+        loading.horizontal_load_x.unwrap() * factor_of_safety / horizontal_capacity,
+        loading.moment_x.unwrap() * factor_of_safety / moment_capacity,
+        exponent_h,
+        exponent_m,
+    );
+
+    Ok(SkirtedFoundationResult {
+        su_tip,
+        su_avg,
+        nc,
+        base_resistance,
+        skirt_friction,
+        vertical_capacity,
+        horizontal_capacity,
+        moment_capacity,
+        exponent_h,
+        exponent_m,
+        vertical_utilization,
+        hm_utilization,
+        is_safe: vertical_utilization <= 1.0 && hm_utilization <= 1.0,
+    })
+}
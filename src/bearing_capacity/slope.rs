@@ -0,0 +1,129 @@
+use serde::Serialize;
+
+use crate::{
+    enums::{AnalysisTerm, PressureConvention},
+    models::{foundation::Foundation, loads::Loads, soil_profile::SoilProfile},
+    validation::ValidationError,
+};
+
+use super::{
+    model::BearingCapacityResult,
+    vesic::{self, calc_slope_influence},
+};
+
+/// Represents the bearing capacity of a footing near the crest of a slope.
+#[derive(Debug, Serialize)]
+pub struct SlopeBearingCapacityResult {
+    /// Setback ratio b / B of the footing from the slope crest.
+    pub setback_ratio: f64,
+
+    /// Slope height ratio H / B, if the slope height is given.
+    pub height_ratio: Option<f64>,
+
+    /// Share of the slope reduction acting on the footing (0: level ground, 1: at the crest).
+    pub slope_influence: f64,
+
+    /// Ultimate bearing capacity of the same footing on level ground (t/m²).
+    pub level_ground_bearing_capacity: f64,
+
+    /// Ratio of the ultimate bearing capacity near the slope to that on level ground.
+    pub reduction_ratio: f64,
+
+    /// Vesic bearing capacity of the footing near the slope.
+    pub bearing_capacity: BearingCapacityResult,
+}
+
+/// Validates the slope geometry of a footing near a slope.
+///
+/// # Arguments
+/// * `foundation` - The foundation data.
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(foundation: &Foundation) -> Result<(), ValidationError> {
+    foundation.validate(&["foundation_width", "slope_angle"])?;
+    let optional_fields = [
+        ("berm_width", foundation.berm_width),
+        ("slope_height", foundation.slope_height),
+        ("opposite_slope_angle", foundation.opposite_slope_angle),
+        ("opposite_berm_width", foundation.opposite_berm_width),
+        ("opposite_slope_height", foundation.opposite_slope_height),
+    ];
+    for (field, value) in optional_fields {
+        if value.is_some() {
+            foundation.validate(&[field])?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Calculates the bearing capacity of a footing near the crest of a slope.
+///
+/// The Vesic ground factors of the slope angle β are applied in proportion to the setback b / B
+/// and the slope height H / B (see `vesic::calc_slope_influence`), and the result is compared
+/// with the same footing on level ground to report the slope reduction.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `foundation` - The foundation data with `slope_angle` and optionally `berm_width` and
+///   `slope_height`.
+/// * `loading` - The applied loads.
+/// * `foundation_pressure` - The pressure on the foundation.
+/// * `pressure_convention` - Whether `foundation_pressure` is gross or net.
+/// * `factor_of_safety` - The safety factor to apply.
+/// * `term` - Short or long-term condition.
+///
+/// # Returns
+/// * `SlopeBearingCapacityResult` with the slope influence and the bearing capacity.
+pub fn calc_bearing_capacity(
+    soil_profile: &mut SoilProfile,
+    foundation: &mut Foundation,
+    loading: &Loads,
+    foundation_pressure: f64,
+    pressure_convention: PressureConvention,
+    factor_of_safety: f64,
+    term: AnalysisTerm,
+) -> Result<SlopeBearingCapacityResult, ValidationError> {
+    validate_input(foundation)?;
+
+    let mut level_foundation = Foundation {
+        slope_angle: None,
+        opposite_slope_angle: None,
+        ..foundation.clone()
+    };
+    let level = vesic::calc_bearing_capacity(
+        soil_profile,
+        &mut level_foundation,
+        loading,
+        foundation_pressure,
+        pressure_convention,
+        factor_of_safety,
+        term,
+    )?;
+    let bearing_capacity = vesic::calc_bearing_capacity(
+        soil_profile,
+        foundation,
+        loading,
+        foundation_pressure,
+        pressure_convention,
+        factor_of_safety,
+        term,
+    )?;
+
+    let width = foundation.foundation_width.unwrap();
+    let level_ground_bearing_capacity = level.ultimate_bearing_capacity;
+
+    Ok(SlopeBearingCapacityResult {
+        setback_ratio: foundation.berm_width.unwrap_or(0.0) / width,
+        height_ratio: foundation.slope_height.map(|h| h / width),
+        slope_influence: calc_slope_influence(
+            width,
+            foundation.berm_width,
+            foundation.slope_height,
+        ),
+        level_ground_bearing_capacity,
+        reduction_ratio: bearing_capacity.ultimate_bearing_capacity / level_ground_bearing_capacity,
+        bearing_capacity,
+    })
+}
@@ -0,0 +1,244 @@
+use serde::Serialize;
+
+use crate::{
+    constants::KPA_TO_TON,
+    diagnostics::{check_limits, Warning},
+    enums::{MethodId, PressureConvention},
+    helper::calc_window_average,
+    models::{
+        averaging_window::AveragingWindow,
+        foundation::Foundation,
+        soil_profile::SoilProfile,
+        spt::{SPTExp, SPT},
+    },
+    validation::{validate_field, ValidationError},
+};
+
+use super::helper_functions::calc_checked_gross_pressure;
+
+/// Settlement (cm) for which the Meyerhof relations are derived.
+const REFERENCE_SETTLEMENT: f64 = 2.5;
+
+/// Footing width (m) separating the narrow and wide footing relations.
+const WIDTH_LIMIT: f64 = 1.22;
+
+/// Represents the SPT based allowable bearing pressure for a given foundation setup.
+#[derive(Debug, Serialize)]
+pub struct Output {
+    /// Average N60 value within the averaging window.
+    pub n60: f64,
+
+    /// Depth window over which N60 is averaged.
+    pub averaging_window: AveragingWindow,
+
+    /// Depth factor Kd = 1 + 0.33 · Df / B ≤ 1.33.
+    pub depth_factor: f64,
+
+    /// Allowable bearing pressure for 25 mm settlement in ton/m2.
+    pub allowable_bearing_capacity: f64,
+
+    /// Allowable bearing pressure for the allowable settlement in ton/m2.
+    pub settlement_limited_bearing_capacity: f64,
+
+    /// Allowable settlement in cm.
+    pub allowable_settlement: f64,
+
+    /// The gross pressure exerted by the foundation in ton/m2.
+    pub qmax: f64,
+
+    /// Indicates whether the settlement limited bearing pressure is safe.
+    pub is_safe: bool,
+
+    /// Method used for the analysis.
+    pub method: MethodId,
+
+    /// Warnings for inputs outside the calibration range of the method.
+    pub warnings: Vec<Warning>,
+
+    /// Convention of the foundation pressure given to the method; `qmax` is always gross.
+    pub pressure_convention: PressureConvention,
+}
+
+pub fn validate_input(
+    spt: &SPT,
+    foundation: &Foundation,
+    foundation_pressure: f64,
+    allowable_settlement: f64,
+) -> Result<(), ValidationError> {
+    spt.validate(&["n", "depth"])?;
+    foundation.validate(&["foundation_depth", "foundation_width"])?;
+    validate_field(
+        "foundation_pressure",
+        Some(foundation_pressure),
+        Some(0.0),
+        None,
+        "loads",
+    )?;
+    validate_field(
+        "allowable_settlement",
+        Some(allowable_settlement),
+        Some(0.0001),
+        None,
+        "spt_bearing",
+    )?;
+    Ok(())
+}
+
+/// Calculates the average N60 value between two depths.
+///
+/// If no blow lies between the depths, the first blow below `top` is used, or the deepest blow
+/// when the test ends above `top`.
+///
+/// # Arguments
+/// * `spt_exp` - The SPT experiment with N60 values.
+/// * `top` - Top depth of the zone (m).
+/// * `bottom` - Bottom depth of the zone (m).
+///
+/// # Returns
+/// * `f64` - Average N60 value.
+pub fn calc_average_n60(spt_exp: &SPTExp, top: f64, bottom: f64) -> f64 {
+    let samples: Vec<(f64, f64)> = spt_exp
+        .blows
+        .iter()
+        .map(|blow| (blow.depth.unwrap(), blow.n60.unwrap().to_i32() as f64))
+        .collect();
+
+    calc_window_average(&samples, top, bottom).unwrap()
+}
+
+/// Calculates the depth factor Kd = 1 + 0.33 · Df / B ≤ 1.33.
+///
+/// # Arguments
+/// * `foundation` - The foundation data.
+///
+/// # Returns
+/// * `f64` - Depth factor.
+pub fn calc_depth_factor(foundation: &Foundation) -> f64 {
+    let df = foundation.foundation_depth.unwrap();
+    let width = foundation.foundation_width.unwrap();
+
+    (1.0 + 0.33 * df / width).min(1.33)
+}
+
+/// Calculates the allowable net bearing pressure from N60 (Meyerhof, 1956 increased by 50% by
+/// Bowles, 1977).
+///
+/// * B ≤ 1.22 m: qa = 19.16 · N60 · Kd · (S / 25) kPa
+/// * B > 1.22 m: qa = 11.98 · N60 · ((3.28B + 1) / 3.28B)² · Kd · (S / 25) kPa
+///
+/// # Arguments
+/// * `n60` - N60 value below the foundation.
+/// * `width` - Width of the foundation (m).
+/// * `depth_factor` - Depth factor Kd.
+/// * `settlement` - Allowable settlement (cm).
+///
+/// # Returns
+/// * `f64` - Allowable bearing pressure (t/m²).
+pub fn calc_allowable_pressure(n60: f64, width: f64, depth_factor: f64, settlement: f64) -> f64 {
+    let settlement_ratio = settlement / REFERENCE_SETTLEMENT;
+
+    let pressure = if width <= WIDTH_LIMIT {
+        19.16 * n60
+    } else {
+        11.98 * n60 * ((3.28 * width + 1.0) / (3.28 * width)).powi(2)
+    };
+
+    pressure * depth_factor * settlement_ratio * KPA_TO_TON
+}
+
+/// Calculates the allowable bearing pressure of a foundation on granular soil from SPT results.
+///
+/// N60 is averaged between the foundation base and a depth B below it. The allowable pressure is
+/// given for the reference settlement of 25 mm and for the allowable settlement, the pressure
+/// being proportional to the settlement.
+///
+/// # Arguments
+/// * `spt` - The SPT data.
+/// * `soil_profile` - The soil profile data, used to convert a net foundation pressure.
+/// * `foundation` - The foundation data.
+/// * `foundation_pressure` - The pressure exerted by the foundation (t/m²).
+/// * `pressure_convention` - Whether `foundation_pressure` is gross or net.
+/// * `allowable_settlement` - Allowable settlement (cm).
+///
+/// # Returns
+/// * `Output` - The bearing pressure result.
+pub fn calc_bearing_capacity(
+    spt: &SPT,
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    foundation_pressure: f64,
+    pressure_convention: PressureConvention,
+    allowable_settlement: f64,
+) -> Result<Output, ValidationError> {
+    calc_bearing_capacity_with_window(
+        spt,
+        soil_profile,
+        foundation,
+        foundation_pressure,
+        pressure_convention,
+        allowable_settlement,
+        AveragingWindow::shallow_foundation(),
+    )
+}
+
+/// Calculates the allowable bearing pressure of a foundation on granular soil from SPT results,
+/// averaging N60 over a configurable window tied to the foundation width.
+///
+/// # Arguments
+/// * `spt` - The SPT data.
+/// * `soil_profile` - The soil profile data, used to convert a net foundation pressure.
+/// * `foundation` - The foundation data.
+/// * `foundation_pressure` - The pressure exerted by the foundation (t/m²).
+/// * `pressure_convention` - Whether `foundation_pressure` is gross or net.
+/// * `allowable_settlement` - Allowable settlement (cm).
+/// * `averaging_window` - Depth window around the foundation base over which N60 is averaged.
+///
+/// # Returns
+/// * `Output` - The bearing pressure result.
+pub fn calc_bearing_capacity_with_window(
+    spt: &SPT,
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    foundation_pressure: f64,
+    pressure_convention: PressureConvention,
+    allowable_settlement: f64,
+    averaging_window: AveragingWindow,
+) -> Result<Output, ValidationError> {
+    validate_input(spt, foundation, foundation_pressure, allowable_settlement)?;
+    averaging_window.validate()?;
+    let foundation_pressure = calc_checked_gross_pressure(
+        soil_profile,
+        foundation,
+        foundation_pressure,
+        pressure_convention,
+    )?;
+
+    let mut spt_exp = spt.get_idealized_exp("idealized".to_string());
+    spt_exp.apply_energy_correction(spt.energy_correction_factor.unwrap());
+
+    let df = foundation.foundation_depth.unwrap();
+    let width = foundation.foundation_width.unwrap();
+
+    let (top, bottom) = averaging_window.calc_bounds(df, width);
+    let n60 = calc_average_n60(&spt_exp, top, bottom);
+    let depth_factor = calc_depth_factor(foundation);
+
+    let allowable_bearing_capacity =
+        calc_allowable_pressure(n60, width, depth_factor, REFERENCE_SETTLEMENT);
+    let settlement_limited_bearing_capacity =
+        calc_allowable_pressure(n60, width, depth_factor, allowable_settlement);
+
+    Ok(Output {
+        n60,
+        averaging_window,
+        depth_factor,
+        allowable_bearing_capacity,
+        settlement_limited_bearing_capacity,
+        allowable_settlement,
+        qmax: foundation_pressure,
+        is_safe: foundation_pressure <= settlement_limited_bearing_capacity,
+        method: MethodId::MeyerhofSpt,
+        warnings: check_limits(MethodId::MeyerhofSpt, &[("n60", n60)]),
+        pressure_convention,
+    })
+}
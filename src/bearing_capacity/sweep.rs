@@ -0,0 +1,133 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::{AnalysisTerm, MethodId, PressureConvention},
+    models::{foundation::Foundation, loads::Loads, soil_profile::SoilProfile},
+    progress::ProgressHandle,
+    validation::ValidationError,
+};
+
+use super::depth_profile::get_bearing_capacity_fn;
+
+/// Foundation widths and depths evaluated in a bearing capacity sweep.
+///
+/// # Fields
+/// * `widths` - Foundation widths (m).
+/// * `depths` - Foundation depths (m).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SweepGrid {
+    pub widths: Vec<f64>,
+    pub depths: Vec<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BearingCapacitySweep {
+    /// Foundation widths of the grid columns (m).
+    pub widths: Vec<f64>,
+    /// Foundation depths of the grid rows (m).
+    pub depths: Vec<f64>,
+    /// Ultimate bearing capacity, `[depth][width]` (t/m²).
+    pub ultimate_bearing_capacity: Vec<Vec<f64>>,
+    /// Allowable bearing capacity, `[depth][width]` (t/m²).
+    pub allowable_bearing_capacity: Vec<Vec<f64>>,
+    /// Method used for the analysis.
+    pub method: MethodId,
+}
+
+/// Calculates the allowable bearing capacity over a grid of foundation widths and depths for
+/// design charts.
+///
+/// The soil profile is validated once and reused for every grid point. The length of the
+/// foundation is scaled with the width to keep the L/B ratio of the given foundation.
+///
+/// With a progress handle, each grid point is reported as a step and the sweep stops with an
+/// `analysis.cancelled` error once cancellation is requested.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `foundation` - The foundation data (its width, length and depth are replaced).
+/// * `loading` - The applied loads.
+/// * `grid` - Foundation widths and depths to evaluate.
+/// * `factor_of_safety` - The safety factor of the bearing capacity.
+/// * `term` - Short or long-term condition.
+/// * `method` - Bearing capacity method (`Vesic`, `Meyerhof` or `Hansen`).
+/// * `progress` - Optional handle for progress reporting and cancellation.
+///
+/// # Returns
+/// * `BearingCapacitySweep` with the results at each width and depth.
+#[allow(clippy::too_many_arguments)]
+pub fn calc_bearing_capacity_sweep(
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    loading: &Loads,
+    grid: &SweepGrid,
+    factor_of_safety: f64,
+    term: AnalysisTerm,
+    method: MethodId,
+    progress: Option<&ProgressHandle>,
+) -> Result<BearingCapacitySweep, ValidationError> {
+    if grid.widths.is_empty() || grid.depths.is_empty() {
+        return Err(ValidationError {
+            code: "bearing_capacity_sweep.grid.empty".to_string(),
+            message: "At least one width and one depth are required.".to_string(),
+        });
+    }
+    let calc = get_bearing_capacity_fn(method, "bearing_capacity_sweep")?;
+
+    let mut profile = soil_profile.clone();
+    profile.validate(&["thickness", "dry_unit_weight", "saturated_unit_weight"])?;
+    profile.calc_layer_depths();
+
+    let aspect_ratio = match (foundation.foundation_width, foundation.foundation_length) {
+        (Some(width), Some(length)) if width > 0.0 => length / width,
+        _ => 1.0,
+    };
+
+    if let Some(progress) = progress {
+        progress.set_total(grid.depths.len() * grid.widths.len());
+    }
+
+    let mut ultimate_bearing_capacity = Vec::with_capacity(grid.depths.len());
+    let mut allowable_bearing_capacity = Vec::with_capacity(grid.depths.len());
+    for &depth in grid.depths.iter() {
+        let mut ultimate_row = Vec::with_capacity(grid.widths.len());
+        let mut allowable_row = Vec::with_capacity(grid.widths.len());
+
+        for &width in grid.widths.iter() {
+            if let Some(progress) = progress {
+                progress.check_cancelled()?;
+            }
+
+            let mut candidate = foundation.clone();
+            candidate.foundation_depth = Some(depth);
+            candidate.foundation_width = Some(width);
+            candidate.foundation_length = Some(width * aspect_ratio);
+
+            let result = calc(
+                &mut profile,
+                &mut candidate,
+                loading,
+                0.0,
+                PressureConvention::Gross,
+                factor_of_safety,
+                term,
+            )?;
+            ultimate_row.push(result.ultimate_bearing_capacity);
+            allowable_row.push(result.allowable_bearing_capacity);
+            if let Some(progress) = progress {
+                progress.advance(1);
+            }
+        }
+
+        ultimate_bearing_capacity.push(ultimate_row);
+        allowable_bearing_capacity.push(allowable_row);
+    }
+
+    Ok(BearingCapacitySweep {
+        widths: grid.widths.clone(),
+        depths: grid.depths.clone(),
+        ultimate_bearing_capacity,
+        allowable_bearing_capacity,
+        method,
+    })
+}
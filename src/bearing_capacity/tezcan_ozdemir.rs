@@ -1,9 +1,21 @@
 use crate::{
-    models::{foundation::Foundation, masw::Masw, soil_profile::SoilProfile},
+    diagnostics::{check_limit, Warning},
+    enums::{MethodId, PressureConvention, VsBearingMethod},
+    helper::calc_thickness_weighted_average,
+    models::{
+        foundation::Foundation,
+        masw::{Masw, MaswExp},
+        soil_profile::SoilProfile,
+    },
     validation::ValidationError,
 };
+
+/// Depth of the influence zone below the foundation base, in multiples of B.
+const INFLUENCE_DEPTH_RATIO: f64 = 2.0;
 use serde::Serialize;
 
+use super::helper_functions::{calc_checked_gross_pressure, calc_empirical_geometry_factors};
+
 /// Represents the bearing capacity result for a given soil and foundation setup.
 #[derive(Debug, Serialize)]
 pub struct Output {
@@ -13,7 +25,13 @@ pub struct Output {
     /// Unit weight of the soil in t/m³.
     pub unit_weight: f64,
 
-    /// The pressure exerted by the foundation in ton/m2.
+    /// Shape factor of the foundation.
+    pub shape_factor: f64,
+
+    /// Depth (embedment) factor of the foundation.
+    pub depth_factor: f64,
+
+    /// The gross pressure exerted by the foundation in ton/m2.
     pub qmax: f64,
 
     /// Allowable bearing capacity in ton/m2.
@@ -24,6 +42,32 @@ pub struct Output {
 
     /// Safety factor used in the design.
     pub safety_factor: f64,
+
+    /// Method used for the analysis.
+    pub method: MethodId,
+
+    /// Warnings for inputs outside the calibration range of the method.
+    pub warnings: Vec<Warning>,
+
+    /// Convention of the foundation pressure given to the method; `qmax` is always gross.
+    pub pressure_convention: PressureConvention,
+}
+
+/// Represents the bearing capacity from the point values at Df and from the values averaged over
+/// the influence zone.
+#[derive(Debug, Serialize)]
+pub struct AveragedOutput {
+    /// Top of the influence zone, the foundation depth (m).
+    pub zone_top: f64,
+
+    /// Bottom of the influence zone Df + 2B (m).
+    pub zone_bottom: f64,
+
+    /// Result with the Vs and unit weight of the layers at Df.
+    pub point: Output,
+
+    /// Result with the thickness-weighted Vs and unit weight of the influence zone.
+    pub averaged: Output,
 }
 
 /// Validates the input data for Tezcan & Ozdemir bearing capacity calculations.
@@ -42,7 +86,7 @@ pub fn validate_input(
 ) -> Result<(), ValidationError> {
     masw.validate(&["thickness", "vs"])?;
     soil_profile.validate(&["thickness", "dry_unit_weight", "saturated_unit_weight"])?;
-    foundation.validate(&["foundation_depth"])?;
+    foundation.validate(&["foundation_depth", "geometry_adjustment"])?;
 
     Ok(())
 }
@@ -68,15 +112,110 @@ fn get_unit_weight(df: f64, soil_profile: SoilProfile) -> f64 {
     unit_weight
 }
 
+/// Calculates the safety factor and allowable bearing pressure of Tezcan & Ozdemir (2007).
+///
+/// # Arguments
+/// - `vs`: Shear wave velocity (m/s).
+/// - `unit_weight`: Unit weight of the soil (t/m³).
+///
+/// # Returns
+/// - `(f64, f64)`: The safety factor and the allowable bearing pressure (t/m²).
+fn calc_tezcan_ozdemir(vs: f64, unit_weight: f64) -> (f64, f64) {
+    match vs {
+        vs if vs < 750.0 => {
+            let sf = 4.0;
+            let q = 0.025 * unit_weight * vs;
+            (sf, q)
+        }
+        vs if vs < 4000.0 => {
+            let sf = 4.6 - vs * 8.0e-4;
+            let q = 0.1 * unit_weight * vs / sf;
+            (sf, q)
+        }
+        _ => {
+            let sf = 1.4;
+            let q = 0.071 * unit_weight * vs;
+            (sf, q)
+        }
+    }
+}
+
+/// Calculates the safety factor and allowable bearing pressure of Keçeli (2012).
+///
+/// The ultimate bearing pressure qf = 0.1 · γ · Vs is divided by a constant safety factor of 4,
+/// without the reduction for stiff rock of Tezcan & Ozdemir (2007).
+///
+/// # Arguments
+/// - `vs`: Shear wave velocity (m/s).
+/// - `unit_weight`: Unit weight of the soil (t/m³).
+///
+/// # Returns
+/// - `(f64, f64)`: The safety factor and the allowable bearing pressure (t/m²).
+fn calc_keceli(vs: f64, unit_weight: f64) -> (f64, f64) {
+    let sf = 4.0;
+    (sf, 0.1 * unit_weight * vs / sf)
+}
+
+/// Calculates the allowable bearing capacity from a shear wave velocity and unit weight.
+///
+/// # Arguments
+/// - `vs`: Shear wave velocity (m/s).
+/// - `unit_weight`: Unit weight of the soil (t/m³).
+/// - `foundation`: The foundation data.
+/// - `foundation_pressure`: The gross pressure applied by the foundation in t/m2.
+/// - `pressure_convention`: Convention in which the foundation pressure was given.
+/// - `method`: The Vs-based formulation.
+///
+/// # Returns
+/// - `Output`: The bearing capacity result.
+fn calc_output(
+    vs: f64,
+    unit_weight: f64,
+    foundation: &Foundation,
+    foundation_pressure: f64,
+    pressure_convention: PressureConvention,
+    method: VsBearingMethod,
+) -> Output {
+    let method_id = match method {
+        VsBearingMethod::TezcanOzdemir => MethodId::TezcanOzdemir,
+        VsBearingMethod::Keceli => MethodId::Keceli,
+    };
+    let warnings = check_limit(method_id, "vs", vs).into_iter().collect();
+
+    let (safety_factor, bearing_capacity) = match method {
+        VsBearingMethod::TezcanOzdemir => calc_tezcan_ozdemir(vs, unit_weight),
+        VsBearingMethod::Keceli => calc_keceli(vs, unit_weight),
+    };
+
+    let (shape_factor, depth_factor) = calc_empirical_geometry_factors(foundation);
+    let bearing_capacity = bearing_capacity * shape_factor * depth_factor;
+
+    Output {
+        vs,
+        unit_weight,
+        shape_factor,
+        depth_factor,
+        allowable_bearing_capacity: bearing_capacity,
+        is_safe: bearing_capacity >= foundation_pressure,
+        safety_factor,
+        qmax: foundation_pressure,
+        method: method_id,
+        warnings,
+        pressure_convention,
+    }
+}
+
 /// Calculates the ultimate bearing capacity of a foundation based on
 /// shear wave velocity (Vs), soil unit weight, and empirical relationships.
 /// It uses the method proposed by Tezcan and Ozdemir (2007).
+/// The capacity is adjusted for footing geometry only if the foundation has a geometry adjustment.
 ///
 /// # Arguments
 /// - `soil_profile`: A struct containing the soil layers and properties.
 /// - `masw`: A struct representing the MASW data.
 /// - `foundation`: A struct representing the foundation geometry (e.g., depth).
 /// - `foundation_pressure`: The pressure applied by the foundation in t/m2.
+/// - `pressure_convention`: Whether `foundation_pressure` is gross or net.
 ///
 /// # Returns
 /// - `f64`: The calculated bearing capacity in kPa.
@@ -85,9 +224,48 @@ pub fn calc_bearing_capacity(
     masw: &mut Masw,
     foundation: Foundation,
     foundation_pressure: f64,
+    pressure_convention: PressureConvention,
+) -> Result<Output, ValidationError> {
+    calc_bearing_capacity_with_method(
+        soil_profile,
+        masw,
+        foundation,
+        foundation_pressure,
+        pressure_convention,
+        VsBearingMethod::TezcanOzdemir,
+    )
+}
+
+/// Calculates the allowable bearing capacity of a foundation from the shear wave velocity at Df
+/// with the selected Vs-based formulation.
+/// The capacity is adjusted for footing geometry only if the foundation has a geometry adjustment.
+///
+/// # Arguments
+/// - `soil_profile`: A struct containing the soil layers and properties.
+/// - `masw`: A struct representing the MASW data.
+/// - `foundation`: A struct representing the foundation geometry (e.g., depth).
+/// - `foundation_pressure`: The pressure applied by the foundation in t/m2.
+/// - `pressure_convention`: Whether `foundation_pressure` is gross or net.
+/// - `method`: The Vs-based formulation.
+///
+/// # Returns
+/// - `Output`: The bearing capacity result.
+pub fn calc_bearing_capacity_with_method(
+    soil_profile: SoilProfile,
+    masw: &mut Masw,
+    foundation: Foundation,
+    foundation_pressure: f64,
+    pressure_convention: PressureConvention,
+    method: VsBearingMethod,
 ) -> Result<Output, ValidationError> {
     // Validate the input parameters
     validate_input(masw, &soil_profile, &foundation)?;
+    let foundation_pressure = calc_checked_gross_pressure(
+        &soil_profile,
+        &foundation,
+        foundation_pressure,
+        pressure_convention,
+    )?;
 
     let df = foundation.foundation_depth.unwrap();
     let masw_exp = masw.get_idealized_exp("idealized".to_string());
@@ -96,30 +274,124 @@ pub fn calc_bearing_capacity(
     let vs = masw_layer.vs.unwrap();
     let unit_weight = get_unit_weight(df, soil_profile);
 
-    let (safety_factor, bearing_capacity): (f64, f64) = match vs {
-        vs if vs < 750.0 => {
-            let sf = 4.0;
-            let q = 0.025 * unit_weight * vs;
-            (sf, q)
-        }
-        vs if vs < 4000.0 => {
-            let sf = 4.6 - vs * 8.0e-4;
-            let q = 0.1 * unit_weight * vs / sf;
-            (sf, q)
+    Ok(calc_output(
+        vs,
+        unit_weight,
+        &foundation,
+        foundation_pressure,
+        pressure_convention,
+        method,
+    ))
+}
+
+/// Calculates the thickness-weighted shear wave velocity between two depths.
+fn calc_average_vs(masw_exp: &MaswExp, top: f64, bottom: f64) -> f64 {
+    let mut intervals = Vec::new();
+    let mut layer_top = 0.0;
+    for layer in &masw_exp.layers {
+        let layer_bottom = layer_top + layer.thickness.unwrap();
+        intervals.push((layer_top, layer_bottom, layer.vs.unwrap()));
+        layer_top = layer_bottom;
+    }
+
+    // Below the deepest layer the velocity of the deepest layer applies
+    if let Some(last) = masw_exp.layers.last()
+        && layer_top < bottom
+    {
+        intervals.push((layer_top, bottom, last.vs.unwrap()));
+    }
+
+    calc_thickness_weighted_average(&intervals, top, bottom).unwrap()
+}
+
+/// Calculates the thickness-weighted unit weight between two depths, using the dry unit weight
+/// above and the saturated unit weight below the groundwater level.
+fn calc_average_unit_weight(soil_profile: &SoilProfile, top: f64, bottom: f64) -> f64 {
+    let gwt = soil_profile.ground_water_level.unwrap();
+
+    let mut intervals = Vec::new();
+    let mut layer_top = 0.0;
+    for (i, layer) in soil_profile.layers.iter().enumerate() {
+        let mut layer_bottom = layer_top + layer.thickness.unwrap();
+        // The deepest layer extends to the bottom of the zone
+        if i == soil_profile.layers.len() - 1 {
+            layer_bottom = layer_bottom.max(bottom);
         }
-        _ => {
-            let sf = 1.4;
-            let q = 0.071 * unit_weight * vs;
-            (sf, q)
+
+        let dry = layer.dry_unit_weight.unwrap();
+        let saturated = layer.saturated_unit_weight.unwrap();
+        if gwt <= layer_top {
+            intervals.push((layer_top, layer_bottom, saturated));
+        } else if gwt >= layer_bottom {
+            intervals.push((layer_top, layer_bottom, dry));
+        } else {
+            intervals.push((layer_top, gwt, dry));
+            intervals.push((gwt, layer_bottom, saturated));
         }
-    };
+        layer_top = layer_bottom;
+    }
 
-    Ok(Output {
-        vs,
-        unit_weight,
-        allowable_bearing_capacity: bearing_capacity,
-        is_safe: bearing_capacity >= foundation_pressure,
-        safety_factor,
-        qmax: foundation_pressure,
+    calc_thickness_weighted_average(&intervals, top, bottom).unwrap()
+}
+
+/// Calculates the Tezcan & Ozdemir (2007) bearing capacity with the shear wave velocity and unit
+/// weight averaged over the influence zone Df to Df + 2B, together with the point result at Df.
+///
+/// Both the shear wave velocity and the unit weight are weighted by the thickness of each layer
+/// within the zone.
+///
+/// # Arguments
+/// - `soil_profile`: A struct containing the soil layers and properties.
+/// - `masw`: A struct representing the MASW data.
+/// - `foundation`: A struct representing the foundation geometry (depth and width).
+/// - `foundation_pressure`: The pressure applied by the foundation in t/m2.
+/// - `pressure_convention`: Whether `foundation_pressure` is gross or net.
+///
+/// # Returns
+/// - `AveragedOutput`: The point and averaged results.
+pub fn calc_averaged_bearing_capacity(
+    soil_profile: SoilProfile,
+    masw: &mut Masw,
+    foundation: Foundation,
+    foundation_pressure: f64,
+    pressure_convention: PressureConvention,
+) -> Result<AveragedOutput, ValidationError> {
+    validate_input(masw, &soil_profile, &foundation)?;
+    foundation.validate(&["foundation_width"])?;
+    let foundation_pressure = calc_checked_gross_pressure(
+        &soil_profile,
+        &foundation,
+        foundation_pressure,
+        pressure_convention,
+    )?;
+
+    let df = foundation.foundation_depth.unwrap();
+    let zone_bottom = df + INFLUENCE_DEPTH_RATIO * foundation.foundation_width.unwrap();
+    let masw_exp = masw.get_idealized_exp("idealized".to_string());
+
+    let point_vs = masw_exp.get_layer_at_depth(df).vs.unwrap();
+    let point_unit_weight = get_unit_weight(df, soil_profile.clone());
+    let averaged_vs = calc_average_vs(&masw_exp, df, zone_bottom);
+    let averaged_unit_weight = calc_average_unit_weight(&soil_profile, df, zone_bottom);
+
+    Ok(AveragedOutput {
+        zone_top: df,
+        zone_bottom,
+        point: calc_output(
+            point_vs,
+            point_unit_weight,
+            &foundation,
+            foundation_pressure,
+            pressure_convention,
+            VsBearingMethod::TezcanOzdemir,
+        ),
+        averaged: calc_output(
+            averaged_vs,
+            averaged_unit_weight,
+            &foundation,
+            foundation_pressure,
+            pressure_convention,
+            VsBearingMethod::TezcanOzdemir,
+        ),
     })
 }
@@ -2,13 +2,19 @@ use super::helper_functions::*;
 use std::f64::consts::PI;
 
 use crate::{
-    enums::AnalysisTerm,
+    enums::{AnalysisTerm, MethodId, PressureConvention},
     models::{foundation::Foundation, loads::Loads, soil_profile::SoilProfile},
     validation::ValidationError,
 };
 
 use super::{helper_functions::get_soil_params, model::*};
 
+/// Berm width (as a multiple of the foundation width) beyond which a slope has no effect.
+const BERM_INFLUENCE_RATIO: f64 = 2.0;
+
+/// Slope height (as a multiple of the foundation width) beyond which the full slope reduction applies.
+const SLOPE_HEIGHT_INFLUENCE_RATIO: f64 = 2.0;
+
 /// Validates the input data for vesics bearing capacity calculations.
 ///
 /// # Arguments
@@ -27,7 +33,13 @@ pub fn validate_input(
 ) -> Result<(), ValidationError> {
     soil_profile.validate(&["thickness", "dry_unit_weight", "saturated_unit_weight"])?;
     foundation.validate(&["foundation_depth", "foundation_width", "foundation_length"])?;
+    if foundation.downslope_embedment.is_some() {
+        foundation.validate(&["downslope_embedment"])?;
+    }
     loading.validate(&["vertical_load"])?;
+    if loading.surface_surcharge.is_some() {
+        loading.validate(&["surface_surcharge"])?;
+    }
 
     if soil_profile.layers.last().unwrap().depth.unwrap() < foundation.foundation_depth.unwrap() {
         return Err(ValidationError {
@@ -36,33 +48,49 @@ pub fn validate_input(
         });
     }
 
-    for layer in soil_profile.layers.iter() {
-        match term {
-            AnalysisTerm::Short => {
-                let fields_to_validate = ["cu", "phi_u"];
-                layer.validate_fields(&fields_to_validate).unwrap();
-
-                if layer.cu.unwrap() == 0. && layer.phi_u.unwrap() == 0. {
-                    return Err(
-                        ValidationError{
-                            code: "soil_profile.layer.cu_or_phi_u_zero".to_string(),
-                            message: "Either undrained shear strength (cu) or undrained friction angle (phi_u) must be greater than zero.".to_string(),
-                        }
-                    );
-                }
+    if foundation.apply_compressibility == Some(true) {
+        let depth =
+            foundation.foundation_depth.unwrap() + foundation.foundation_width.unwrap() / 2.0;
+        soil_profile
+            .get_layer_at_depth(depth)
+            .validate_fields(&["elastic_modulus", "poissons_ratio"])?;
+    }
+
+    if soil_profile
+        .get_layer_at_depth(foundation.foundation_depth.unwrap())
+        .is_rock()
+    {
+        return Err(ValidationError {
+            code: "soil_profile.layer.rock_at_foundation_level".to_string(),
+            message: "Foundation rests on rock; use a rock bearing capacity method instead."
+                .to_string(),
+        });
+    }
+
+    for layer in soil_profile.layers.iter().filter(|layer| !layer.is_rock()) {
+        if layer.is_undrained(term) {
+            let fields_to_validate = ["cu", "phi_u"];
+            layer.validate_fields(&fields_to_validate).unwrap();
+
+            if layer.cu.unwrap() == 0. && layer.phi_u.unwrap() == 0. {
+                return Err(
+                    ValidationError{
+                        code: "soil_profile.layer.cu_or_phi_u_zero".to_string(),
+                        message: "Either undrained shear strength (cu) or undrained friction angle (phi_u) must be greater than zero.".to_string(),
+                    }
+                );
             }
-            AnalysisTerm::Long => {
-                let fields_to_validate = ["c_prime", "phi_prime"];
-                layer.validate_fields(&fields_to_validate).unwrap();
-
-                if layer.c_prime.unwrap() == 0. && layer.phi_prime.unwrap() == 0. {
-                    return Err(
-                        ValidationError{
-                            code: "soil_profile.layer.c_prime_or_phi_prime_zero".to_string(),
-                            message: "Either effective cohesion (c') or effective friction angle (phi') must be greater than zero.".to_string(),
-                        }
-                    );
-                }
+        } else {
+            let fields_to_validate = ["c_prime", "phi_prime"];
+            layer.validate_fields(&fields_to_validate).unwrap();
+
+            if layer.c_prime.unwrap() == 0. && layer.phi_prime.unwrap() == 0. {
+                return Err(
+                    ValidationError{
+                        code: "soil_profile.layer.c_prime_or_phi_prime_zero".to_string(),
+                        message: "Either effective cohesion (c') or effective friction angle (phi') must be greater than zero.".to_string(),
+                    }
+                );
             }
         }
     }
@@ -109,7 +137,7 @@ pub fn calc_shape_factors(
 ) -> ShapeFactors {
     let width = foundation.foundation_width.unwrap();
     let length = foundation.foundation_length.unwrap();
-    let w_l = width / length;
+    let w_l = foundation.calc_shape_ratio(width, length);
 
     let nc = bearing_capacity_factors.nc;
     let nq = bearing_capacity_factors.nq;
@@ -234,7 +262,7 @@ pub fn calc_inclination_factors(
 /// # Returns
 /// * `DepthFactors`: dc, dq, dg coefficients
 pub fn calc_depth_factors(foundation: &Foundation, phi: f64) -> DepthFactors {
-    let df = foundation.foundation_depth.unwrap();
+    let df = foundation.calc_downslope_embedment();
     let w = foundation.foundation_width.unwrap();
 
     let db = if df / w <= 1.0 {
@@ -280,6 +308,161 @@ pub fn calc_ground_factors(iq: f64, slope_angle: f64, phi: f64) -> GroundFactors
     GroundFactors { gc, gq, gg }
 }
 
+/// Calculates the share of the slope reduction acting on a foundation near a slope.
+///
+/// The influence decreases linearly with the setback b and vanishes at b = 2B, and a slope of
+/// height H < 2B only carries H / 2B of the reduction:
+///
+/// w = max(1 − b / 2B, 0) · min(H / 2B, 1)
+///
+/// # Arguments
+/// * `width` - Foundation width B (m)
+/// * `berm_width` - Setback b from the foundation edge to the slope crest (m), 0 if not set
+/// * `slope_height` - Slope height H (m), unlimited if not set
+///
+/// # Returns
+/// * `f64`: Slope influence between 0 (level ground) and 1 (full slope reduction)
+pub fn calc_slope_influence(width: f64, berm_width: Option<f64>, slope_height: Option<f64>) -> f64 {
+    let setback_weight =
+        (1.0 - berm_width.unwrap_or(0.0) / (BERM_INFLUENCE_RATIO * width)).max(0.0);
+    let height_weight = slope_height.map_or(1.0, |h| {
+        (h / (SLOPE_HEIGHT_INFLUENCE_RATIO * width)).min(1.0)
+    });
+
+    setback_weight * height_weight
+}
+
+/// Calculates the ground factors for a foundation between two slopes set back by berms.
+///
+/// The ground factors of each side are interpolated linearly between the value for a foundation
+/// at the slope crest and the value for level ground, which is reached at a berm width of
+/// 2B. A slope lower than 2B only cuts into the upper part of the failure zone, so its
+/// reduction is further scaled by H / 2B. The side with the larger reduction governs. Without
+/// an opposite slope, berms or slope heights, the result equals `calc_ground_factors` with the
+/// foundation slope angle.
+///
+/// # Arguments
+/// * `iq` - Load inclination factor (between 0 and 1)
+/// * `foundation` - Foundation with slope angles and berm widths
+/// * `phi` - Soil friction angle in degrees
+///
+/// # Returns
+/// * `GroundFactors` of the governing side
+pub fn calc_benched_ground_factors(iq: f64, foundation: &Foundation, phi: f64) -> GroundFactors {
+    let width = foundation.foundation_width.unwrap();
+    let level = calc_ground_factors(iq, 0.0, phi);
+
+    let sides = [
+        (
+            foundation.slope_angle,
+            foundation.berm_width,
+            foundation.slope_height,
+        ),
+        (
+            foundation.opposite_slope_angle,
+            foundation.opposite_berm_width,
+            foundation.opposite_slope_height,
+        ),
+    ];
+
+    sides
+        .iter()
+        .map(|&(slope_angle, berm_width, slope_height)| {
+            let slope = calc_ground_factors(iq, slope_angle.unwrap_or(0.0), phi);
+            let weight = calc_slope_influence(width, berm_width, slope_height);
+            GroundFactors {
+                gc: level.gc + weight * (slope.gc - level.gc),
+                gq: level.gq + weight * (slope.gq - level.gq),
+                gg: level.gg + weight * (slope.gg - level.gg),
+            }
+        })
+        .min_by(|a, b| a.gq.total_cmp(&b.gq))
+        .unwrap()
+}
+
+/// Calculates the rigidity index Ir = G / (c + q' · tan φ) (Vesic, 1973).
+///
+/// # Arguments
+/// * `shear_modulus` - Shear modulus of the soil G (t/m²)
+/// * `cohesion` - Cohesion c (t/m²)
+/// * `effective_stress` - Effective overburden pressure q' at a depth B/2 below the base (t/m²)
+/// * `phi` - Friction angle in degrees
+///
+/// # Returns
+/// * `f64`: Rigidity index Ir
+pub fn calc_rigidity_index(
+    shear_modulus: f64,
+    cohesion: f64,
+    effective_stress: f64,
+    phi: f64,
+) -> f64 {
+    shear_modulus / (cohesion + effective_stress * phi.to_radians().tan())
+}
+
+/// Calculates the critical rigidity index
+/// Ir,cr = 0.5 · exp[(3.30 − 0.45 · B/L) · cot(45° − φ/2)] (Vesic, 1973).
+///
+/// # Arguments
+/// * `phi` - Friction angle in degrees
+/// * `shape_ratio` - Width to length ratio B/L
+///
+/// # Returns
+/// * `f64`: Critical rigidity index Ir,cr
+pub fn calc_critical_rigidity_index(phi: f64, shape_ratio: f64) -> f64 {
+    let angle = (45.0 - phi / 2.0).to_radians();
+    0.5 * ((3.30 - 0.45 * shape_ratio) / angle.tan()).exp()
+}
+
+/// Calculates the soil compressibility factors (ζc, ζq, ζγ) of Vesic (1973).
+///
+/// The factors are 1 when Ir ≥ Ir,cr. Otherwise:
+/// * ζq = ζγ = exp[(−4.4 + 0.6 · B/L) · tan φ + 3.07 · sin φ · log10(2 · Ir) / (1 + sin φ)]
+/// * φ = 0: ζc = 0.32 + 0.12 · B/L + 0.60 · log10(Ir)
+/// * φ > 0: ζc = ζq − (1 − ζq) / (Nq · tan φ)
+///
+/// # Arguments
+/// * `rigidity_index` - Rigidity index Ir
+/// * `phi` - Friction angle in degrees
+/// * `shape_ratio` - Width to length ratio B/L
+/// * `nq` - Bearing capacity factor Nq
+///
+/// # Returns
+/// * `CompressibilityFactors` with the rigidity indices and ζc, ζq, ζγ
+pub fn calc_compressibility_factors(
+    rigidity_index: f64,
+    phi: f64,
+    shape_ratio: f64,
+    nq: f64,
+) -> CompressibilityFactors {
+    let critical_rigidity_index = calc_critical_rigidity_index(phi, shape_ratio);
+
+    let (zc, zq) = if rigidity_index >= critical_rigidity_index {
+        (1.0, 1.0)
+    } else {
+        let phi_rad = phi.to_radians();
+        let sin_phi = phi_rad.sin();
+        let tan_phi = phi_rad.tan();
+
+        let zq = ((-4.4 + 0.6 * shape_ratio) * tan_phi
+            + 3.07 * sin_phi * (2.0 * rigidity_index).log10() / (1.0 + sin_phi))
+            .exp();
+        let zc = if phi == 0.0 {
+            0.32 + 0.12 * shape_ratio + 0.60 * rigidity_index.log10()
+        } else {
+            zq - (1.0 - zq) / (nq * tan_phi)
+        };
+        (zc.min(1.0), zq.min(1.0))
+    };
+
+    CompressibilityFactors {
+        rigidity_index,
+        critical_rigidity_index,
+        zc,
+        zq,
+        zg: zq,
+    }
+}
+
 /// Calculates the ultimate and allowable bearing capacity of a foundation.
 ///
 /// # Arguments
@@ -287,6 +470,7 @@ pub fn calc_ground_factors(iq: f64, slope_angle: f64, phi: f64) -> GroundFactors
 /// * `foundation` - The foundation data.
 /// * `loading` - The applied loads.
 /// * `foundation_pressure` - The pressure on the foundation.
+/// * `pressure_convention` - Whether `foundation_pressure` is gross or net.
 /// * `factor_of_safety` - The safety factor to apply.
 /// * `term` - Short or long-term condition.
 ///
@@ -297,24 +481,27 @@ pub fn calc_bearing_capacity(
     foundation: &mut Foundation,
     loading: &Loads,
     foundation_pressure: f64,
+    pressure_convention: PressureConvention,
     factor_of_safety: f64,
     term: AnalysisTerm,
 ) -> Result<BearingCapacityResult, ValidationError> {
+    foundation.apply_shape()?;
     // Validate input data
     validate_input(soil_profile, foundation, loading, term)?;
     soil_profile.calc_layer_depths();
+    let foundation_pressure =
+        foundation.calc_gross_pressure(soil_profile, foundation_pressure, pressure_convention);
     // Calculate effective foundation dimensions
-    foundation.calc_effective_lengths(
-        loading.moment_x.unwrap_or(0.),
-        loading.moment_y.unwrap_or(0.),
-    );
+    let (ex, ey) = loading.calc_eccentricity();
+    let effective_area = foundation.calc_effective_area(ex, ey);
 
     let soil_params = get_soil_params(soil_profile, foundation, term);
     let phi = soil_params.friction_angle;
     let cohesion = soil_params.cohesion;
     let effective_unit_weight = soil_params.unit_weight;
 
-    let effective_surcharge = calc_effective_surcharge(soil_profile, foundation, term);
+    let effective_surcharge = calc_effective_surcharge(soil_profile, foundation, term)
+        + loading.surface_surcharge.unwrap_or(0.0);
 
     let bearing_capacity_factors = calc_bearing_capacity_factors(phi);
     let shape_factors = calc_shape_factors(foundation, bearing_capacity_factors, phi);
@@ -322,19 +509,39 @@ pub fn calc_bearing_capacity(
         calc_inclination_factors(phi, cohesion, bearing_capacity_factors, foundation, loading);
     let depth_factors = calc_depth_factors(foundation, phi);
     let base_factors = calc_base_factors(phi, foundation);
-    let ground_factors = calc_ground_factors(
-        inclination_factors.iq,
-        foundation.slope_angle.unwrap_or(0.0),
-        phi,
-    );
-
-    let q_ult = if phi == 0. {
-        5.14 * cohesion
+    let ground_factors = calc_benched_ground_factors(inclination_factors.iq, foundation, phi);
+
+    let compressibility_factors = (foundation.apply_compressibility == Some(true)).then(|| {
+        let width = foundation.foundation_width.unwrap();
+        let depth = foundation.foundation_depth.unwrap() + width / 2.0;
+        let layer = soil_profile.get_layer_at_depth(depth);
+        let shear_modulus =
+            layer.elastic_modulus.unwrap() / (2.0 * (1.0 + layer.poissons_ratio.unwrap()));
+        let rigidity_index = calc_rigidity_index(
+            shear_modulus,
+            cohesion,
+            soil_profile.calc_effective_stress(depth),
+            phi,
+        );
+        let shape_ratio = foundation.calc_shape_ratio(width, foundation.foundation_length.unwrap());
+        calc_compressibility_factors(
+            rigidity_index,
+            phi,
+            shape_ratio,
+            bearing_capacity_factors.nq,
+        )
+    });
+    let (zc, zq, zg) = compressibility_factors.map_or((1.0, 1.0, 1.0), |f| (f.zc, f.zq, f.zg));
+
+    let effective_width = foundation.effective_width.unwrap();
+    let (part_1, part_2, part_3) = if phi == 0. {
+        let part_1 = 5.14
+            * cohesion
             * (1. + shape_factors.sc + depth_factors.dc
                 - inclination_factors.ic
                 - base_factors.bc
-                - ground_factors.gc)
-            + effective_surcharge
+                - ground_factors.gc);
+        (part_1 * zc, effective_surcharge * zq, 0.0)
     } else {
         let part_1 = cohesion
             * bearing_capacity_factors.nc
@@ -342,7 +549,8 @@ pub fn calc_bearing_capacity(
             * depth_factors.dc
             * base_factors.bc
             * ground_factors.gc
-            * inclination_factors.ic;
+            * inclination_factors.ic
+            * zc;
 
         let part_2 = effective_surcharge
             * bearing_capacity_factors.nq
@@ -350,20 +558,23 @@ pub fn calc_bearing_capacity(
             * depth_factors.dq
             * base_factors.bq
             * ground_factors.gq
-            * inclination_factors.iq;
+            * inclination_factors.iq
+            * zq;
 
         let part_3 = 0.5
             * effective_unit_weight
-            * foundation.effective_width.unwrap()
+            * effective_width
             * bearing_capacity_factors.ng
             * shape_factors.sg
             * depth_factors.dg
             * base_factors.bg
             * ground_factors.gg
-            * inclination_factors.ig;
+            * inclination_factors.ig
+            * zg;
 
-        part_1 + part_2 + part_3
+        (part_1, part_2, part_3)
     };
+    let q_ult = part_1 + part_2 + part_3;
 
     let q_allow = q_ult / factor_of_safety;
 
@@ -375,11 +586,26 @@ pub fn calc_bearing_capacity(
         depth_factors,
         load_inclination_factors: inclination_factors,
         soil_params,
+        terms: BearingCapacityTerms {
+            part_1,
+            part_2,
+            part_3,
+            friction_angle: phi,
+            cohesion,
+            unit_weight: effective_unit_weight,
+            surcharge: effective_surcharge,
+            effective_width,
+        },
         ultimate_bearing_capacity: q_ult,
         allowable_bearing_capacity: q_allow,
         is_safe,
         ground_factors,
         base_factors,
         qmax: foundation_pressure,
+        method: MethodId::Vesic,
+        effective_area,
+        compressibility_factors,
+        seismic: None,
+        pressure_convention,
     })
 }
@@ -0,0 +1,224 @@
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+use crate::{
+    models::{foundation::Foundation, loads::Loads},
+    validation::{validate_field, ValidationError},
+};
+
+/// Shape parameters of the V-H-M failure envelope.
+///
+/// Default values are those of Gottardi, Houlsby & Butterfield (1999) for footings on dense sand.
+///
+/// # Fields
+/// * `h0` - Peak normalized horizontal capacity H / V0.
+/// * `m0` - Peak normalized moment capacity M / (B · V0).
+/// * `a` - Eccentricity (rotation) of the elliptical H-M cross section.
+/// * `beta1` - Curvature of the envelope at low vertical load.
+/// * `beta2` - Curvature of the envelope at high vertical load.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct VhmEnvelopeParams {
+    pub h0: f64,
+    pub m0: f64,
+    pub a: f64,
+    pub beta1: f64,
+    pub beta2: f64,
+}
+
+impl Default for VhmEnvelopeParams {
+    fn default() -> Self {
+        Self {
+            h0: 0.121,
+            m0: 0.09,
+            a: -0.223,
+            beta1: 0.95,
+            beta2: 0.95,
+        }
+    }
+}
+
+/// A point on (or a load point relative to) the V-H-M envelope.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct VhmPoint {
+    /// Vertical load (t).
+    pub v: f64,
+    /// Horizontal load (t).
+    pub h: f64,
+    /// Moment (t·m).
+    pub m: f64,
+}
+
+/// Represents the utilization of a load point with respect to the V-H-M envelope.
+#[derive(Debug, Clone, Serialize)]
+pub struct VhmUtilizationResult {
+    /// Normalized vertical load V / V0.
+    pub v: f64,
+    /// Normalized horizontal load H / V0.
+    pub h: f64,
+    /// Normalized moment M / (B · V0).
+    pub m: f64,
+    /// Value of the yield function at the load point (negative inside the envelope).
+    pub yield_function: f64,
+    /// Radial utilization: 1.0 means the load point lies on the envelope.
+    pub utilization: f64,
+    /// Indicates whether the load point is inside the envelope.
+    pub is_safe: bool,
+}
+
+/// Calculates the β12 scaling factor of the envelope.
+fn calc_beta12(params: &VhmEnvelopeParams) -> f64 {
+    let (b1, b2) = (params.beta1, params.beta2);
+    (b1 + b2).powf(b1 + b2) / (b1.powf(b1) * b2.powf(b2))
+}
+
+/// Calculates the radius of the H-M cross section at a normalized vertical load v.
+fn calc_section_radius(v: f64, params: &VhmEnvelopeParams) -> f64 {
+    if v <= 0.0 || v >= 1.0 {
+        return 0.0;
+    }
+    calc_beta12(params) * v.powf(params.beta1) * (1.0 - v).powf(params.beta2)
+}
+
+/// Evaluates the yield function of the envelope for a normalized load point.
+///
+/// f = (h/h0)² + (m/m0)² − 2a(h/h0)(m/m0) − [β12 · v^β1 · (1 − v)^β2]²
+///
+/// # Arguments
+/// * `v` - Normalized vertical load V / V0.
+/// * `h` - Normalized horizontal load H / V0.
+/// * `m` - Normalized moment M / (B · V0).
+/// * `params` - Envelope shape parameters.
+///
+/// # Returns
+/// * `f64`: Negative inside, zero on and positive outside the envelope.
+pub fn calc_yield_function(v: f64, h: f64, m: f64, params: &VhmEnvelopeParams) -> f64 {
+    let x = h / params.h0;
+    let y = m / params.m0;
+    x.powi(2) + y.powi(2) - 2.0 * params.a * x * y - calc_section_radius(v, params).powi(2)
+}
+
+/// Generates points on the V-H-M envelope surface.
+///
+/// # Arguments
+/// * `vertical_capacity` - Ultimate vertical capacity under pure vertical load, V0 (t).
+/// * `width` - Foundation width B (m).
+/// * `params` - Envelope shape parameters.
+/// * `n_v` - Number of vertical load levels between 0 and V0.
+/// * `n_theta` - Number of points around each H-M cross section.
+///
+/// # Returns
+/// * `Vec<VhmPoint>`: Envelope points in dimensional form.
+pub fn calc_envelope_surface(
+    vertical_capacity: f64,
+    width: f64,
+    params: &VhmEnvelopeParams,
+    n_v: usize,
+    n_theta: usize,
+) -> Vec<VhmPoint> {
+    let mut points = Vec::with_capacity(n_v * n_theta);
+
+    for i in 1..=n_v {
+        let v = i as f64 / (n_v + 1) as f64;
+        let radius = calc_section_radius(v, params);
+
+        for j in 0..n_theta {
+            let theta = 2.0 * PI * j as f64 / n_theta as f64;
+            let (x, y) = (theta.cos(), theta.sin());
+            let scale = radius / (x.powi(2) + y.powi(2) - 2.0 * params.a * x * y).sqrt();
+
+            points.push(VhmPoint {
+                v: v * vertical_capacity,
+                h: scale * x * params.h0 * vertical_capacity,
+                m: scale * y * params.m0 * vertical_capacity * width,
+            });
+        }
+    }
+
+    points
+}
+
+/// Validates the input data for V-H-M envelope calculations.
+///
+/// # Arguments
+/// * `vertical_capacity` - Ultimate vertical capacity, V0 (t).
+/// * `foundation` - The foundation data.
+/// * `loading` - The applied loads.
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(
+    vertical_capacity: f64,
+    foundation: &Foundation,
+    loading: &Loads,
+) -> Result<(), ValidationError> {
+    validate_field(
+        "vertical_capacity",
+        Some(vertical_capacity),
+        Some(0.0001),
+        None,
+        "vhm_envelope",
+    )?;
+    foundation.validate(&["foundation_width"])?;
+    loading.validate(&["vertical_load", "horizontal_load_x", "moment_x"])?;
+
+    Ok(())
+}
+
+/// Calculates the utilization of a load point with respect to the V-H-M envelope.
+///
+/// The load point is scaled radially from the origin until it reaches the envelope;
+/// the utilization is the inverse of that scale factor.
+///
+/// # Arguments
+/// * `vertical_capacity` - Ultimate vertical capacity under pure vertical load, V0 (t).
+/// * `foundation` - The foundation data (`foundation_width`).
+/// * `loading` - The applied loads (`vertical_load`, `horizontal_load_x`, `moment_x`).
+/// * `params` - Envelope shape parameters.
+///
+/// # Returns
+/// * `VhmUtilizationResult` with normalized loads and utilization.
+pub fn calc_utilization(
+    vertical_capacity: f64,
+    foundation: &Foundation,
+    loading: &Loads,
+    params: &VhmEnvelopeParams,
+) -> Result<VhmUtilizationResult, ValidationError> {
+    validate_input(vertical_capacity, foundation, loading)?;
+
+    let width = foundation.foundation_width.unwrap();
+    let v = loading.vertical_load.unwrap() / vertical_capacity;
+    let h = loading.horizontal_load_x.unwrap() / vertical_capacity;
+    let m = loading.moment_x.unwrap() / (vertical_capacity * width);
+
+    let yield_function = calc_yield_function(v, h, m, params);
+
+    let utilization = if v <= 0.0 {
+        if h == 0.0 && m == 0.0 {
+            0.0
+        } else {
+            f64::INFINITY
+        }
+    } else {
+        // Inside the envelope for small scale factors, outside when v reaches 1.0
+        let mut low = 0.0;
+        let mut high = 1.0 / v;
+        for _ in 0..100 {
+            let mid = 0.5 * (low + high);
+            if calc_yield_function(mid * v, mid * h, mid * m, params) < 0.0 {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        1.0 / high
+    };
+
+    Ok(VhmUtilizationResult {
+        v,
+        h,
+        m,
+        yield_function,
+        utilization,
+        is_safe: utilization <= 1.0,
+    })
+}
@@ -0,0 +1,194 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::{CompactionLevel, PipeBackfillType},
+    helper::interp1d,
+    models::{buried_pipe::BuriedPipe, soil_profile::SoilProfile},
+    validation::ValidationError,
+};
+
+/// Conversion factor from psi to t/m².
+const PSI_TO_TON: f64 = 0.70307;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuriedPipeResult {
+    /// Average total unit weight of the backfill over the cover depth (t/m³).
+    pub unit_weight: f64,
+    /// Prism load on the pipe, γ · H · Bc (t/m).
+    pub prism_load: f64,
+    /// Marston load coefficient Cd.
+    pub load_coefficient: f64,
+    /// Marston trench load on the flexible pipe, Cd · γ · Bc · Bd (t/m).
+    pub marston_load: f64,
+    /// Load used in the deflection calculation (t/m).
+    pub design_load: f64,
+    /// Modulus of soil reaction E' (t/m²).
+    pub modulus_of_soil_reaction: f64,
+    /// Bedding constant K.
+    pub bedding_constant: f64,
+    /// Horizontal deflection of the pipe (m).
+    pub deflection: f64,
+    /// Deflection as a ratio of the pipe diameter.
+    pub deflection_ratio: f64,
+    /// Indicates whether the deflection is within the allowable limit.
+    pub is_safe: bool,
+}
+
+/// Returns the modulus of soil reaction E' of the pipe zone backfill (Howard, 1977).
+///
+/// # Arguments
+/// * `backfill_type` - Pipe zone backfill soil type.
+/// * `compaction_level` - Compaction level of the backfill.
+///
+/// # Returns
+/// * `f64`: E' in t/m².
+pub fn get_modulus_of_soil_reaction(
+    backfill_type: PipeBackfillType,
+    compaction_level: CompactionLevel,
+) -> f64 {
+    let values_psi = match backfill_type {
+        PipeBackfillType::FineGrainedLowPlasticity => [50.0, 200.0, 400.0, 1000.0],
+        PipeBackfillType::FineGrainedWithCoarse => [100.0, 400.0, 1000.0, 2000.0],
+        PipeBackfillType::CoarseGrainedClean => [200.0, 1000.0, 2000.0, 3000.0],
+        PipeBackfillType::CrushedRock => [1000.0, 3000.0, 3000.0, 3000.0],
+    };
+
+    let index = match compaction_level {
+        CompactionLevel::Dumped => 0,
+        CompactionLevel::Slight => 1,
+        CompactionLevel::Moderate => 2,
+        CompactionLevel::High => 3,
+    };
+
+    values_psi[index] * PSI_TO_TON
+}
+
+/// Returns the bedding constant K of the Iowa formula for a given bedding angle.
+///
+/// # Arguments
+/// * `bedding_angle` - Bedding angle in degrees.
+///
+/// # Returns
+/// * `f64`: Bedding constant K.
+pub fn get_bedding_constant(bedding_angle: f64) -> f64 {
+    let angles = [0.0, 30.0, 45.0, 60.0, 90.0, 120.0, 180.0];
+    let constants = [0.110, 0.108, 0.105, 0.102, 0.096, 0.090, 0.083];
+
+    interp1d(&angles, &constants, bedding_angle)
+}
+
+/// Calculates Marston's load coefficient for a trench condition.
+///
+/// Cd = (1 − e^(−2Kμ'·H/Bd)) / (2Kμ')
+///
+/// # Arguments
+/// * `cover_depth` - Depth of cover above the pipe crown H (m).
+/// * `trench_width` - Trench width Bd (m).
+/// * `friction_factor` - Kμ' of the backfill.
+///
+/// # Returns
+/// * `f64`: Load coefficient Cd.
+pub fn calc_load_coefficient(cover_depth: f64, trench_width: f64, friction_factor: f64) -> f64 {
+    (1.0 - (-2.0 * friction_factor * cover_depth / trench_width).exp()) / (2.0 * friction_factor)
+}
+
+/// Validates the input data for buried pipe calculations.
+///
+/// # Arguments
+/// * `pipe` - The buried pipe data.
+/// * `soil_profile` - The soil profile of the trench backfill.
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(
+    pipe: &BuriedPipe,
+    soil_profile: &SoilProfile,
+) -> Result<(), ValidationError> {
+    pipe.validate(&[
+        "outer_diameter",
+        "pipe_stiffness",
+        "cover_depth",
+        "trench_width",
+        "bedding_angle",
+        "deflection_lag_factor",
+        "allowable_deflection",
+        "backfill_friction_factor",
+    ])?;
+    soil_profile.validate(&["thickness", "dry_unit_weight", "saturated_unit_weight"])?;
+
+    if soil_profile.layers.last().unwrap().depth.unwrap() < pipe.cover_depth.unwrap() {
+        return Err(ValidationError {
+            code: "buried_pipe.cover_depth.greater_than_soil_profile_depth".to_string(),
+            message: "Cover depth is greater than the soil profile depth.".to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Calculates the backfill load and deflection of a flexible pipe buried in a trench.
+///
+/// The deflection is obtained from the Modified Iowa formula (Spangler, 1941; Watkins & Spangler, 1958):
+///
+/// Δy = DL · K · W / (0.149 · PS + 0.061 · E')
+///
+/// When `include_arching` is true, the Marston trench load Cd · γ · Bc · Bd is used, which accounts
+/// for the shear resisting the settlement of the backfill along the trench walls. Otherwise the
+/// prism load γ · H · Bc is used, as recommended for long-term design of flexible pipes.
+///
+/// # Arguments
+/// * `pipe` - The buried pipe data.
+/// * `soil_profile` - The soil profile of the trench backfill.
+/// * `include_arching` - Whether to use the Marston load instead of the prism load.
+///
+/// # Returns
+/// * `BuriedPipeResult` with loads and deflection.
+pub fn calc_pipe_deflection(
+    pipe: &BuriedPipe,
+    soil_profile: &mut SoilProfile,
+    include_arching: bool,
+) -> Result<BuriedPipeResult, ValidationError> {
+    soil_profile.calc_layer_depths();
+    validate_input(pipe, soil_profile)?;
+
+    let diameter = pipe.outer_diameter.unwrap();
+    let cover_depth = pipe.cover_depth.unwrap();
+    let trench_width = pipe.trench_width.unwrap();
+
+    let unit_weight = soil_profile.calc_normal_stress(cover_depth) / cover_depth;
+    let prism_load = unit_weight * cover_depth * diameter;
+
+    let load_coefficient = calc_load_coefficient(
+        cover_depth,
+        trench_width,
+        pipe.backfill_friction_factor.unwrap(),
+    );
+    let marston_load = load_coefficient * unit_weight * diameter * trench_width;
+
+    let design_load = if include_arching {
+        marston_load
+    } else {
+        prism_load
+    };
+
+    let modulus_of_soil_reaction =
+        get_modulus_of_soil_reaction(pipe.backfill_type, pipe.compaction_level);
+    let bedding_constant = get_bedding_constant(pipe.bedding_angle.unwrap());
+
+    let deflection = pipe.deflection_lag_factor.unwrap() * bedding_constant * design_load
+        / (0.149 * pipe.pipe_stiffness.unwrap() + 0.061 * modulus_of_soil_reaction);
+    let deflection_ratio = deflection / diameter;
+
+    Ok(BuriedPipeResult {
+        unit_weight,
+        prism_load,
+        load_coefficient,
+        marston_load,
+        design_load,
+        modulus_of_soil_reaction,
+        bedding_constant,
+        deflection,
+        deflection_ratio,
+        is_safe: deflection_ratio <= pipe.allowable_deflection.unwrap(),
+    })
+}
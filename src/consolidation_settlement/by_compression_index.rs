@@ -1,11 +1,14 @@
 use crate::{
+    enums::{MethodId, PressureConvention},
     models::{foundation::Foundation, soil_profile::SoilProfile},
     validation::{validate_field, ValidationError},
 };
 
 use super::{
-    helper_functions::{calc_delta_stress, get_center_and_thickness},
-    model::SettlementResult,
+    helper_functions::{
+        calc_delta_stress, calc_excavation_stress_relief, get_center_and_thickness,
+    },
+    model::{ConsolidationOptions, SettlementResult},
 };
 
 pub fn validate_input(
@@ -13,8 +16,8 @@ pub fn validate_input(
     foundation: &Foundation,
     foundation_pressure: f64,
 ) -> Result<(), ValidationError> {
-    soil_profile.validate(&[
-        "thickness",
+    soil_profile.validate(&["thickness"])?;
+    soil_profile.validate_soil_layers(&[
         "compression_index",
         "recompression_index",
         "void_ratio",
@@ -72,6 +75,7 @@ pub fn calc_single_layer_settlement(
 /// * `soil_profile` - The soil profile containing the layers.
 /// * `foundation` - The foundation parameters.
 /// * `foundation_pressure` - The foundation pressure (q) [t/m²].
+/// * `pressure_convention` - Whether `foundation_pressure` is gross or net.
 ///
 /// # Returns
 /// * A vector of settlements for each layer in the soil profile.
@@ -79,25 +83,78 @@ pub fn calc_settlement(
     soil_profile: &mut SoilProfile,
     foundation: &Foundation,
     foundation_pressure: f64,
+    pressure_convention: PressureConvention,
+) -> Result<SettlementResult, ValidationError> {
+    calc_settlement_with_options(
+        soil_profile,
+        foundation,
+        foundation_pressure,
+        pressure_convention,
+        &ConsolidationOptions::default(),
+    )
+}
+
+/// Calculates the consolidation settlement of a foundation with the given options.
+///
+/// The stress increase of each layer is taken from the stress distribution method of the
+/// options, and the stress relief caused by a nearby excavation is subtracted from it.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile containing the layers.
+/// * `foundation` - The foundation parameters.
+/// * `foundation_pressure` - The foundation pressure (q) [t/m²].
+/// * `pressure_convention` - Whether `foundation_pressure` is gross or net.
+/// * `options` - Stress distribution and nearby excavation.
+///
+/// # Returns
+/// * A vector of settlements for each layer in the soil profile.
+pub fn calc_settlement_with_options(
+    soil_profile: &mut SoilProfile,
+    foundation: &Foundation,
+    foundation_pressure: f64,
+    pressure_convention: PressureConvention,
+    options: &ConsolidationOptions,
 ) -> Result<SettlementResult, ValidationError> {
     validate_input(soil_profile, foundation, foundation_pressure)?;
+    if let Some(excavation) = &options.excavation {
+        excavation.validate(&["width", "length", "depth"])?;
+    }
     soil_profile.calc_layer_depths();
 
     let mut settlements = vec![];
     let df = foundation.foundation_depth.unwrap();
     let width = foundation.foundation_width.unwrap();
     let length = foundation.foundation_length.unwrap();
-    let q_net = foundation_pressure - soil_profile.calc_normal_stress(df);
+    let q_net =
+        foundation.calc_net_pressure(soil_profile, foundation_pressure, pressure_convention);
     let gwt = soil_profile.ground_water_level.unwrap();
 
+    if q_net <= 0.0 {
+        return Ok(SettlementResult::compensated(
+            soil_profile.layers.len(),
+            q_net,
+            MethodId::CompressionIndex,
+            Vec::new(),
+            pressure_convention,
+        ));
+    }
+
     for i in 0..soil_profile.layers.len() {
-        if soil_profile.get_layer_index(gwt) > i || soil_profile.get_layer_index(df) > i {
+        if soil_profile.get_layer_index(gwt) > i
+            || soil_profile.get_layer_index(df) > i
+            || soil_profile.layers[i].is_rock()
+        {
             settlements.push(0.0);
             continue;
         }
         let layer = &soil_profile.layers[i];
         let (center, thickness) = get_center_and_thickness(soil_profile, df, i);
-        let delta_stress = calc_delta_stress(q_net, width, length, center);
+        let relief = options.excavation.as_ref().map_or(0.0, |excavation| {
+            calc_excavation_stress_relief(soil_profile, excavation, center)
+        });
+        let delta_stress =
+            (calc_delta_stress(q_net, width, length, center - df, options.distribution) - relief)
+                .max(0.0);
         let g0 = soil_profile.calc_effective_stress(center);
         let cc = layer.compression_index.unwrap();
         let cr = layer.recompression_index.unwrap();
@@ -110,5 +167,9 @@ pub fn calc_settlement(
         settlement_per_layer: settlements.clone(),
         total_settlement: settlements.iter().sum(),
         qnet: q_net,
+        method: MethodId::CompressionIndex,
+        warnings: Vec::new(),
+        fully_compensated: false,
+        pressure_convention,
     })
 }
@@ -0,0 +1,109 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    models::soil_profile::SoilProfile,
+    validation::{validate_field, ValidationError},
+};
+
+use super::by_compression_index::calc_single_layer_settlement;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DewateringSettlementResult {
+    /// Consolidation settlement of each layer (cm).
+    pub settlement_per_layer: Vec<f64>,
+    /// Increase in effective stress at the center of each layer (t/m²).
+    pub delta_stress_per_layer: Vec<f64>,
+    /// Total settlement of the ground surface (cm).
+    pub total_settlement: f64,
+    /// Lowering of the groundwater level (m).
+    pub drawdown: f64,
+}
+
+pub fn validate_input(
+    soil_profile: &SoilProfile,
+    lowered_water_level: f64,
+) -> Result<(), ValidationError> {
+    soil_profile.validate(&["thickness", "dry_unit_weight", "saturated_unit_weight"])?;
+    soil_profile.validate_soil_layers(&[
+        "compression_index",
+        "recompression_index",
+        "void_ratio",
+        "preconsolidation_pressure",
+    ])?;
+    validate_field(
+        "lowered_water_level",
+        Some(lowered_water_level),
+        soil_profile.ground_water_level,
+        None,
+        "dewatering",
+    )?;
+    Ok(())
+}
+
+/// Calculates the consolidation settlement of the ground caused by lowering the groundwater level.
+///
+/// The pore pressure profile is recomputed with the lowered groundwater level and the increase
+/// in effective stress Δσ' = σ'(lowered) − σ'(initial) at the center of each layer below the
+/// initial groundwater level is used as the stress increment of the Cc-Cr method. Layers above
+/// the initial groundwater level and rock layers do not settle.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile with the initial groundwater level.
+/// * `lowered_water_level` - Depth of the groundwater level after dewatering [m].
+///
+/// # Returns
+/// * `DewateringSettlementResult` with the settlement of each layer.
+pub fn calc_settlement(
+    soil_profile: &mut SoilProfile,
+    lowered_water_level: f64,
+) -> Result<DewateringSettlementResult, ValidationError> {
+    validate_input(soil_profile, lowered_water_level)?;
+    soil_profile.calc_layer_depths();
+
+    let gwt = soil_profile.ground_water_level.unwrap();
+    let mut lowered_profile = soil_profile.clone();
+    lowered_profile.ground_water_level = Some(lowered_water_level);
+
+    let mut settlements = vec![];
+    let mut delta_stresses = vec![];
+    let mut top = 0.0;
+
+    for layer in &soil_profile.layers {
+        let bottom = layer.depth.unwrap();
+        let layer_top = top;
+        top = bottom;
+
+        if bottom <= gwt || layer.is_rock() {
+            settlements.push(0.0);
+            delta_stresses.push(0.0);
+            continue;
+        }
+
+        let thickness = bottom - layer_top.max(gwt);
+        let center = bottom - thickness / 2.0;
+        let g0 = soil_profile.calc_effective_stress(center);
+        let delta_stress = (lowered_profile.calc_effective_stress(center) - g0).max(0.0);
+
+        let cc = layer.compression_index.unwrap();
+        let cr = layer.recompression_index.unwrap();
+        let e0 = layer.void_ratio.unwrap();
+        let gp = layer.preconsolidation_pressure.unwrap();
+        settlements.push(calc_single_layer_settlement(
+            thickness,
+            cc,
+            cr,
+            e0,
+            gp,
+            g0,
+            delta_stress,
+        ));
+        delta_stresses.push(delta_stress);
+    }
+
+    Ok(DewateringSettlementResult {
+        total_settlement: settlements.iter().sum(),
+        settlement_per_layer: settlements,
+        delta_stress_per_layer: delta_stresses,
+        drawdown: lowered_water_level - gwt,
+    })
+}
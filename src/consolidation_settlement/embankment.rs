@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    models::{embankment::Embankment, soil_profile::SoilProfile},
+    validation::ValidationError,
+};
+
+use super::by_compression_index::calc_single_layer_settlement;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmbankmentSettlementResult {
+    /// Consolidation settlement of each layer below the embankment centerline (cm).
+    pub settlement_per_layer: Vec<f64>,
+    /// Increase in vertical stress at the center of each layer (t/m²).
+    pub delta_stress_per_layer: Vec<f64>,
+    /// Total settlement of the embankment centerline (cm).
+    pub total_settlement: f64,
+    /// Pressure of the fill on the ground surface, γ · H (t/m²).
+    pub embankment_pressure: f64,
+}
+
+pub fn validate_input(
+    soil_profile: &SoilProfile,
+    embankment: &Embankment,
+) -> Result<(), ValidationError> {
+    soil_profile.validate(&["thickness", "dry_unit_weight", "saturated_unit_weight"])?;
+    soil_profile.validate_soil_layers(&[
+        "compression_index",
+        "recompression_index",
+        "void_ratio",
+        "preconsolidation_pressure",
+    ])?;
+    embankment.validate(&["height", "crest_width", "side_slope", "unit_weight"])?;
+    Ok(())
+}
+
+/// Calculates the consolidation settlement below the centerline of an embankment.
+///
+/// The stress increase at the center of each layer is found from the Osterberg influence factors
+/// of the two halves of the trapezoidal fill and used as the stress increment of the Cc-Cr
+/// method. Layers above the groundwater level and rock layers do not settle.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile below the embankment.
+/// * `embankment` - The embankment geometry and fill unit weight.
+///
+/// # Returns
+/// * `EmbankmentSettlementResult` with the settlement of each layer.
+pub fn calc_settlement(
+    soil_profile: &mut SoilProfile,
+    embankment: &Embankment,
+) -> Result<EmbankmentSettlementResult, ValidationError> {
+    validate_input(soil_profile, embankment)?;
+    soil_profile.calc_layer_depths();
+
+    let gwt = soil_profile.ground_water_level.unwrap();
+
+    let mut settlements = vec![];
+    let mut delta_stresses = vec![];
+    let mut top = 0.0;
+
+    for layer in &soil_profile.layers {
+        let bottom = layer.depth.unwrap();
+        let layer_top = top;
+        top = bottom;
+
+        if bottom <= gwt || layer.is_rock() {
+            settlements.push(0.0);
+            delta_stresses.push(0.0);
+            continue;
+        }
+
+        let thickness = bottom - layer_top.max(gwt);
+        let center = bottom - thickness / 2.0;
+        let g0 = soil_profile.calc_effective_stress(center);
+        let delta_stress = embankment.calc_center_stress(center);
+
+        let cc = layer.compression_index.unwrap();
+        let cr = layer.recompression_index.unwrap();
+        let e0 = layer.void_ratio.unwrap();
+        let gp = layer.preconsolidation_pressure.unwrap();
+        settlements.push(calc_single_layer_settlement(
+            thickness,
+            cc,
+            cr,
+            e0,
+            gp,
+            g0,
+            delta_stress,
+        ));
+        delta_stresses.push(delta_stress);
+    }
+
+    Ok(EmbankmentSettlementResult {
+        total_settlement: settlements.iter().sum(),
+        settlement_per_layer: settlements,
+        delta_stress_per_layer: delta_stresses,
+        embankment_pressure: embankment.calc_pressure(),
+    })
+}
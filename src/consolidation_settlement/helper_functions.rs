@@ -1,4 +1,9 @@
-use crate::models::soil_profile::SoilProfile;
+use std::f64::consts::PI;
+
+use crate::{
+    enums::StressDistribution,
+    models::{excavation::Excavation, soil_profile::SoilProfile},
+};
 
 /// Calculates the center and thickness of a soil layer based on the ground water table (GWT) and the depth of the foundation (df).
 ///
@@ -39,17 +44,161 @@ pub fn get_center_and_thickness(
     (center, thickness)
 }
 
-/// Calculates the change in effective stress (delta_stress) based on the foundation pressure (q),
-/// width, length, and center of the layer.
+/// Calculates the change in effective stress (delta_stress) below the center of the foundation
+/// based on the foundation pressure (q), width, length and depth below the foundation base.
+///
+/// * `TwoToOne`: Δσ = q · B · L / ((B + z) · (L + z))
+/// * `BoussinesqRectangular`: Δσ = 4 · q · I(B / 2, L / 2, z)
+/// * `Westergaard`: Δσ = 4 · q · Iw(B / 2, L / 2, z)
 ///
 /// # Arguments
 /// * `q` - Foundation pressure [t/m²].
 /// * `width` - Width of the foundation [m].
 /// * `length` - Length of the foundation [m].
-/// * `center` - Center of the layer [m].
+/// * `depth` - Depth below the foundation base [m].
+/// * `distribution` - Stress distribution method.
 ///
 /// # Returns
 /// * Change in effective stress [t/m²].
-pub fn calc_delta_stress(q: f64, width: f64, length: f64, center: f64) -> f64 {
-    q * width * length / (width + center) * (length + center)
+pub fn calc_delta_stress(
+    q: f64,
+    width: f64,
+    length: f64,
+    depth: f64,
+    distribution: StressDistribution,
+) -> f64 {
+    match distribution {
+        StressDistribution::TwoToOne => q * width * length / ((width + depth) * (length + depth)),
+        StressDistribution::BoussinesqRectangular => {
+            4.0 * q * calc_corner_influence_factor(width / 2.0, length / 2.0, depth)
+        }
+        StressDistribution::Westergaard => {
+            4.0 * q * calc_westergaard_corner_influence_factor(width / 2.0, length / 2.0, depth)
+        }
+    }
+}
+
+/// Calculates the influence factor for the vertical stress beneath the corner of a uniformly
+/// loaded rectangle (Newmark, 1935).
+///
+/// # Arguments
+/// * `width` - Width of the rectangle [m].
+/// * `length` - Length of the rectangle [m].
+/// * `depth` - Depth below the loaded surface [m].
+///
+/// # Returns
+/// * Influence factor I, with Δσ = q · I.
+pub fn calc_corner_influence_factor(width: f64, length: f64, depth: f64) -> f64 {
+    if width <= 0.0 || length <= 0.0 {
+        return 0.0;
+    }
+    if depth <= 0.0 {
+        return 0.25;
+    }
+
+    let m = width / depth;
+    let n = length / depth;
+    let v = m.powi(2) + n.powi(2) + 1.0;
+    let mn = m * n;
+
+    let term1 = 2.0 * mn * v.sqrt() / (v + mn.powi(2)) * (v + 1.0) / v;
+    let term2 = (2.0 * mn * v.sqrt()).atan2(v - mn.powi(2));
+
+    (term1 + term2) / (4.0 * PI)
+}
+
+/// Calculates the influence factor for the vertical stress beneath the corner of a uniformly
+/// loaded rectangle on soil reinforced by rigid horizontal sheets (Westergaard, 1938).
+///
+/// With Poisson's ratio taken as 0, η² = (1 − 2ν) / (2 − 2ν) = 1/2 and
+///
+/// * I = 1 / (2π) · cot⁻¹ √(η² · (1 / m² + 1 / n²) + η⁴ / (m² · n²)), with m = B / z, n = L / z
+///
+/// # Arguments
+/// * `width` - Width of the rectangle [m].
+/// * `length` - Length of the rectangle [m].
+/// * `depth` - Depth below the loaded surface [m].
+///
+/// # Returns
+/// * Influence factor I, with Δσ = q · I.
+pub fn calc_westergaard_corner_influence_factor(width: f64, length: f64, depth: f64) -> f64 {
+    if width <= 0.0 || length <= 0.0 {
+        return 0.0;
+    }
+    if depth <= 0.0 {
+        return 0.25;
+    }
+
+    let eta2 = 0.5;
+    let m = width / depth;
+    let n = length / depth;
+    let x = (eta2 * (1.0 / m.powi(2) + 1.0 / n.powi(2)) + eta2.powi(2) / (m * n).powi(2)).sqrt();
+
+    1.0_f64.atan2(x) / (2.0 * PI)
+}
+
+/// Calculates the vertical stress at depth below a point caused by a uniformly loaded rectangle
+/// located anywhere in plan, by superposition of corner influence factors.
+///
+/// The point is at the origin and the rectangle spans `x_min..x_max` and `y_min..y_max`.
+///
+/// # Arguments
+/// * `q` - Pressure on the rectangle [t/m²].
+/// * `x_min`, `x_max` - Extent of the rectangle along x [m].
+/// * `y_min`, `y_max` - Extent of the rectangle along y [m].
+/// * `depth` - Depth below the loaded surface [m].
+///
+/// # Returns
+/// * Vertical stress [t/m²].
+pub fn calc_rectangle_stress(
+    q: f64,
+    x_min: f64,
+    x_max: f64,
+    y_min: f64,
+    y_max: f64,
+    depth: f64,
+) -> f64 {
+    let corner = |x: f64, y: f64| {
+        x.signum() * y.signum() * calc_corner_influence_factor(x.abs(), y.abs(), depth)
+    };
+
+    q * (corner(x_max, y_max) - corner(x_min, y_max) - corner(x_max, y_min) + corner(x_min, y_min))
+}
+
+/// Calculates the stress relief beneath the foundation center caused by a nearby excavation.
+///
+/// The removed overburden σv(D) acts as an unloading rectangle at the excavation base, so only
+/// points below the base are affected.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile containing the layers.
+/// * `excavation` - The excavation data.
+/// * `depth` - Depth of the point below the ground surface [m].
+///
+/// # Returns
+/// * Decrease in vertical stress [t/m²].
+pub fn calc_excavation_stress_relief(
+    soil_profile: &SoilProfile,
+    excavation: &Excavation,
+    depth: f64,
+) -> f64 {
+    let excavation_depth = excavation.depth.unwrap();
+    if depth <= excavation_depth {
+        return 0.0;
+    }
+
+    let half_width = excavation.width.unwrap() / 2.0;
+    let half_length = excavation.length.unwrap() / 2.0;
+    let offset_x = excavation.offset_x.unwrap_or(0.0);
+    let offset_y = excavation.offset_y.unwrap_or(0.0);
+    let unloading = soil_profile.calc_normal_stress(excavation_depth);
+
+    calc_rectangle_stress(
+        unloading,
+        offset_x - half_width,
+        offset_x + half_width,
+        offset_y - half_length,
+        offset_y + half_length,
+        depth - excavation_depth,
+    )
 }
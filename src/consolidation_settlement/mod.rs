@@ -1,4 +1,7 @@
 pub mod by_compression_index;
 pub mod by_mv;
+pub mod dewatering;
+pub mod embankment;
 pub mod helper_functions;
 pub mod model;
+pub mod time_rate;
@@ -1,8 +1,62 @@
 use serde::{Deserialize, Serialize};
 
+use crate::{
+    diagnostics::Warning,
+    enums::{MethodId, PressureConvention, StressDistribution},
+    models::excavation::Excavation,
+};
+
+/// Options of the consolidation settlement calculation.
+///
+/// # Fields
+/// * `distribution` - Stress distribution method below the foundation center.
+/// * `excavation` - Nearby excavation whose stress relief is subtracted from the stress increase.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ConsolidationOptions {
+    pub distribution: StressDistribution,
+    pub excavation: Option<Excavation>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SettlementResult {
     pub settlement_per_layer: Vec<f64>,
     pub total_settlement: f64,
     pub qnet: f64,
+    pub method: MethodId,
+    pub warnings: Vec<Warning>,
+    /// Indicates that the foundation pressure does not exceed the removed overburden (q_net ≤ 0),
+    /// so no settlement is produced by the foundation.
+    pub fully_compensated: bool,
+    /// Convention of the foundation pressure given to the method; `qnet` is always net.
+    #[serde(default)]
+    pub pressure_convention: PressureConvention,
+}
+
+impl SettlementResult {
+    /// Creates the result of a fully compensated foundation (q_net ≤ 0) with zero settlement in
+    /// every layer.
+    ///
+    /// # Arguments
+    /// * `layer_count` - Number of layers in the soil profile.
+    /// * `q_net` - Net foundation pressure [t/m²].
+    /// * `method` - Method used for the settlement calculation.
+    /// * `warnings` - Applicability warnings of the method.
+    /// * `pressure_convention` - Convention of the foundation pressure given to the method.
+    pub fn compensated(
+        layer_count: usize,
+        q_net: f64,
+        method: MethodId,
+        warnings: Vec<Warning>,
+        pressure_convention: PressureConvention,
+    ) -> Self {
+        SettlementResult {
+            settlement_per_layer: vec![0.0; layer_count],
+            total_settlement: 0.0,
+            qnet: q_net,
+            method,
+            warnings,
+            fully_compensated: true,
+            pressure_convention,
+        }
+    }
 }
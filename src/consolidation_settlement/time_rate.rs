@@ -0,0 +1,272 @@
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+use crate::{
+    enums::DrainageCondition,
+    models::soil_profile::SoilProfile,
+    validation::{validate_field, ValidationError},
+};
+
+/// Time factor at 90% average degree of consolidation.
+pub const T90: f64 = 0.848;
+/// Time factor at 95% average degree of consolidation.
+pub const T95: f64 = 1.129;
+
+/// Settlement of the profile at a given time after loading.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct TimeRatePoint {
+    /// Time after loading (years).
+    pub time: f64,
+    /// Average degree of consolidation of the profile, S(t) / S (0 to 1).
+    pub degree_of_consolidation: f64,
+    /// Consolidation settlement reached at the time (cm).
+    pub settlement: f64,
+}
+
+/// Time-rate parameters of a consolidating layer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeRateLayer {
+    /// Index of the layer in the soil profile.
+    pub layer_index: usize,
+    /// Coefficient of consolidation (m²/year).
+    pub cv: f64,
+    /// Length of the drainage path (m).
+    pub drainage_path: f64,
+    /// Primary consolidation settlement of the layer (cm).
+    pub primary_settlement: f64,
+    /// Time to reach 90% consolidation of the layer (years).
+    pub t90: f64,
+    /// Time to reach 95% consolidation of the layer (years).
+    pub t95: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeRateResult {
+    /// Consolidating layers of the profile.
+    pub layers: Vec<TimeRateLayer>,
+    /// Settlement curve at the requested times.
+    pub points: Vec<TimeRatePoint>,
+    /// Total primary consolidation settlement (cm).
+    pub total_settlement: f64,
+    /// Time for the profile to reach 90% of the total settlement (years).
+    pub t90: f64,
+    /// Time for the profile to reach 95% of the total settlement (years).
+    pub t95: f64,
+}
+
+/// Calculates the length of the drainage path of a layer.
+///
+/// # Arguments
+/// * `thickness` - Layer thickness (m).
+/// * `drainage` - Drainage condition of the layer.
+///
+/// # Returns
+/// * `f64`: Drainage path Hdr (m).
+pub fn calc_drainage_path(thickness: f64, drainage: DrainageCondition) -> f64 {
+    match drainage {
+        DrainageCondition::Single => thickness,
+        DrainageCondition::Double => thickness / 2.0,
+    }
+}
+
+/// Calculates the time factor Tv = cv · t / Hdr².
+///
+/// # Arguments
+/// * `cv` - Coefficient of consolidation (m²/year).
+/// * `time` - Time after loading (years).
+/// * `drainage_path` - Drainage path Hdr (m).
+pub fn calc_time_factor(cv: f64, time: f64, drainage_path: f64) -> f64 {
+    cv * time / drainage_path.powi(2)
+}
+
+/// Calculates the average degree of consolidation for a time factor (Terzaghi, 1925).
+///
+/// U = 1 − Σ 2 / M² · exp(−M² · Tv), with M = π (2m + 1) / 2, for a uniform initial excess
+/// pore pressure.
+///
+/// # Arguments
+/// * `time_factor` - Time factor Tv.
+///
+/// # Returns
+/// * `f64`: Average degree of consolidation U (0 to 1).
+pub fn calc_degree_of_consolidation(time_factor: f64) -> f64 {
+    if time_factor <= 0.0 {
+        return 0.0;
+    }
+
+    let mut remaining = 0.0;
+    for m in 0..200 {
+        let big_m = PI * (2 * m + 1) as f64 / 2.0;
+        let term = 2.0 / big_m.powi(2) * (-big_m.powi(2) * time_factor).exp();
+        remaining += term;
+        if term < 1e-12 {
+            break;
+        }
+    }
+
+    (1.0 - remaining).clamp(0.0, 1.0)
+}
+
+/// Calculates the time factor needed for an average degree of consolidation.
+///
+/// Tv = π / 4 · U² for U < 0.6 and Tv = 1.781 − 0.933 · log10(100 (1 − U)) otherwise
+/// (Terzaghi, 1943).
+///
+/// # Arguments
+/// * `degree_of_consolidation` - Average degree of consolidation U (0 to 1).
+///
+/// # Returns
+/// * `f64`: Time factor Tv, infinite for U = 1.
+pub fn calc_required_time_factor(degree_of_consolidation: f64) -> f64 {
+    if degree_of_consolidation >= 1.0 {
+        f64::INFINITY
+    } else if degree_of_consolidation < 0.6 {
+        PI / 4.0 * degree_of_consolidation.powi(2)
+    } else {
+        1.781 - 0.933 * (100.0 * (1.0 - degree_of_consolidation)).log10()
+    }
+}
+
+/// Calculates the consolidation settlement of the profile at a given time.
+fn calc_settlement_at(layers: &[TimeRateLayer], time: f64) -> f64 {
+    layers
+        .iter()
+        .map(|layer| {
+            let tv = calc_time_factor(layer.cv, time, layer.drainage_path);
+            calc_degree_of_consolidation(tv) * layer.primary_settlement
+        })
+        .sum()
+}
+
+/// Finds the time at which the profile reaches a degree of consolidation by bisection.
+fn calc_time_to_degree(layers: &[TimeRateLayer], total_settlement: f64, degree: f64) -> f64 {
+    if total_settlement <= 0.0 {
+        return 0.0;
+    }
+
+    // The slowest layer governs the upper bound
+    let mut upper = layers
+        .iter()
+        .map(|layer| calc_required_time_factor(degree) * layer.drainage_path.powi(2) / layer.cv)
+        .fold(0.0, f64::max);
+    let mut lower = 0.0;
+    while calc_settlement_at(layers, upper) < degree * total_settlement {
+        upper *= 2.0;
+    }
+
+    for _ in 0..100 {
+        let mid = (lower + upper) / 2.0;
+        if calc_settlement_at(layers, mid) < degree * total_settlement {
+            lower = mid;
+        } else {
+            upper = mid;
+        }
+    }
+
+    upper
+}
+
+/// Validates the input data for the time rate of consolidation.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `settlement_per_layer` - Primary consolidation settlement of each layer (cm).
+/// * `times` - Times after loading (years).
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(
+    soil_profile: &SoilProfile,
+    settlement_per_layer: &[f64],
+    times: &[f64],
+) -> Result<(), ValidationError> {
+    soil_profile.validate(&["thickness"])?;
+    if settlement_per_layer.len() != soil_profile.layers.len() {
+        return Err(ValidationError {
+            code: "time_rate.settlement_per_layer.invalid_length".into(),
+            message: "A settlement must be given for each layer of the soil profile.".into(),
+        });
+    }
+    for (layer, &settlement) in soil_profile.layers.iter().zip(settlement_per_layer) {
+        validate_field("settlement", Some(settlement), Some(0.0), None, "time_rate")?;
+        if settlement > 0.0 {
+            layer.validate_fields(&["cv"])?;
+        }
+    }
+    for &time in times {
+        validate_field("time", Some(time), Some(0.0), None, "time_rate")?;
+    }
+
+    Ok(())
+}
+
+/// Calculates the time rate of consolidation settlement of a soil profile.
+///
+/// Each layer with a primary settlement consolidates on its own with its coefficient of
+/// consolidation cv and the drainage path of the drainage condition, so the settlement of the
+/// profile at time t is S(t) = Σ U(Tv) · S, with Tv = cv · t / Hdr². The times to reach 90% and
+/// 95% of the total settlement are found from the combined curve.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile with `cv` for the consolidating layers.
+/// * `settlement_per_layer` - Primary consolidation settlement of each layer (cm), e.g. from
+///   `SettlementResult::settlement_per_layer`.
+/// * `drainage` - Drainage condition of the consolidating layers.
+/// * `times` - Times after loading of the settlement curve (years).
+///
+/// # Returns
+/// * `TimeRateResult` with the settlement curve and the times to 90% and 95% consolidation.
+pub fn calc_time_rate(
+    soil_profile: &SoilProfile,
+    settlement_per_layer: &[f64],
+    drainage: DrainageCondition,
+    times: &[f64],
+) -> Result<TimeRateResult, ValidationError> {
+    validate_input(soil_profile, settlement_per_layer, times)?;
+
+    let layers: Vec<TimeRateLayer> = soil_profile
+        .layers
+        .iter()
+        .zip(settlement_per_layer)
+        .enumerate()
+        .filter(|(_, (_, settlement))| **settlement > 0.0)
+        .map(|(layer_index, (layer, &primary_settlement))| {
+            let cv = layer.cv.unwrap();
+            let drainage_path = calc_drainage_path(layer.thickness.unwrap(), drainage);
+            TimeRateLayer {
+                layer_index,
+                cv,
+                drainage_path,
+                primary_settlement,
+                t90: T90 * drainage_path.powi(2) / cv,
+                t95: T95 * drainage_path.powi(2) / cv,
+            }
+        })
+        .collect();
+
+    let total_settlement: f64 = layers.iter().map(|layer| layer.primary_settlement).sum();
+
+    let points = times
+        .iter()
+        .map(|&time| {
+            let settlement = calc_settlement_at(&layers, time);
+            TimeRatePoint {
+                time,
+                degree_of_consolidation: if total_settlement > 0.0 {
+                    settlement / total_settlement
+                } else {
+                    0.0
+                },
+                settlement,
+            }
+        })
+        .collect();
+
+    Ok(TimeRateResult {
+        t90: calc_time_to_degree(&layers, total_settlement, 0.9),
+        t95: calc_time_to_degree(&layers, total_settlement, 0.95),
+        layers,
+        points,
+        total_settlement,
+    })
+}
@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+
+use crate::validation::{validate_field, ValidationError};
+
+/// Gravitational acceleration (m/s²).
+pub const GRAVITY: f64 = 9.81;
+
+/// Unit weight of fresh water (t/m³).
+///
+/// Water pressures use the conventional design value γw = 0.981 t/m³. It is a design
+/// convention, not a unit conversion: unit conversions use `TON_TO_KN`.
+pub const WATER_UNIT_WEIGHT: f64 = 0.981;
+
+/// Unit weight of sea water (t/m³), for a density 1.025 times that of fresh water.
+pub const SEA_WATER_UNIT_WEIGHT: f64 = 1.025 * WATER_UNIT_WEIGHT;
+
+/// Force of 1 t (tonne-force) in kN, which is also the pressure of 1 t/m² in kPa.
+///
+/// This is the only ton definition of the unit conversions; the other factors are derived from it.
+pub const TON_TO_KN: f64 = 9.80665;
+
+/// Conversion factor from kPa to t/m².
+pub const KPA_TO_TON: f64 = 1.0 / TON_TO_KN;
+
+/// Conversion factor from MPa to t/m².
+pub const MPA_TO_TON: f64 = 1000.0 / TON_TO_KN;
+
+/// Physical constants used by the analyses.
+///
+/// The default environment uses fresh water. Use `Environment::sea_water` for offshore and
+/// coastal structures, where the heavier sea water increases pore pressures and uplift.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Environment {
+    /// Gravitational acceleration (m/s²).
+    pub gravity: f64,
+    /// Unit weight of the groundwater, in the units of `WATER_UNIT_WEIGHT`.
+    pub water_unit_weight: f64,
+}
+
+impl Default for Environment {
+    fn default() -> Self {
+        Self {
+            gravity: GRAVITY,
+            water_unit_weight: WATER_UNIT_WEIGHT,
+        }
+    }
+}
+
+impl Environment {
+    /// Creates an environment with sea water as the groundwater.
+    pub fn sea_water() -> Self {
+        Self {
+            water_unit_weight: SEA_WATER_UNIT_WEIGHT,
+            ..Default::default()
+        }
+    }
+
+    /// Calculates the submerged unit weight γ' = γsat − γw (t/m³).
+    ///
+    /// # Arguments
+    /// * `saturated_unit_weight` - Saturated unit weight of the soil (t/m³).
+    pub fn submerged_unit_weight(&self, saturated_unit_weight: f64) -> f64 {
+        saturated_unit_weight - self.water_unit_weight
+    }
+
+    /// Calculates the hydrostatic pressure u = γw · hw (t/m²).
+    ///
+    /// # Arguments
+    /// * `head` - Pressure head (m). Negative heads give zero pressure.
+    pub fn hydrostatic_pressure(&self, head: f64) -> f64 {
+        self.water_unit_weight * head.max(0.0)
+    }
+
+    /// Validates the environment constants.
+    ///
+    /// # Returns
+    /// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        validate_field(
+            "gravity",
+            Some(self.gravity),
+            Some(0.0001),
+            None,
+            "environment",
+        )?;
+        validate_field(
+            "water_unit_weight",
+            Some(self.water_unit_weight),
+            Some(0.0001),
+            None,
+            "environment",
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,174 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bearing_capacity::vesic::calc_bearing_capacity_factors,
+    consolidation_settlement::model::SettlementResult,
+    enums::{AnalysisTerm, CriticalLayerCriterion},
+    liquefaction::models::CommonLiquefactionLayerResult,
+    models::{foundation::Foundation, soil_profile::SoilProfile},
+    validation::ValidationError,
+};
+
+/// Depth of the bearing influence zone below the foundation base, as a multiple of its width.
+const BEARING_INFLUENCE_RATIO: f64 = 2.0;
+
+/// Critical layer of an analysis.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CriticalLayer {
+    /// Analysis the layer is critical for.
+    pub criterion: CriticalLayerCriterion,
+    /// Index of the layer in the soil profile, or of the sample in the liquefaction results.
+    pub layer_index: usize,
+    /// Depth of the top of the layer (m).
+    pub top: f64,
+    /// Depth of the bottom of the layer (m).
+    pub bottom: f64,
+    /// Governing value: settlement (cm), unit bearing resistance (t/m²) or safety factor.
+    pub value: f64,
+    /// Share of the total settlement (settlement criterion only).
+    pub share: Option<f64>,
+}
+
+/// Returns the top and bottom depths of each layer of the soil profile.
+fn get_layer_bounds(soil_profile: &SoilProfile) -> Vec<(f64, f64)> {
+    let mut top = 0.0;
+    soil_profile
+        .layers
+        .iter()
+        .map(|layer| {
+            let bottom = top + layer.thickness.unwrap();
+            let bounds = (top, bottom);
+            top = bottom;
+            bounds
+        })
+        .collect()
+}
+
+/// Finds the layer with the largest contribution to the settlement.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile the settlement was calculated for.
+/// * `settlement` - Settlement result with one settlement per layer.
+///
+/// # Returns
+/// * `Option<CriticalLayer>`: None when no layer settles.
+pub fn find_critical_settlement_layer(
+    soil_profile: &SoilProfile,
+    settlement: &SettlementResult,
+) -> Result<Option<CriticalLayer>, ValidationError> {
+    soil_profile.validate(&["thickness"])?;
+
+    let bounds = get_layer_bounds(soil_profile);
+    let critical = settlement
+        .settlement_per_layer
+        .iter()
+        .enumerate()
+        .take(bounds.len())
+        .filter(|(_, s)| **s > 0.0)
+        .max_by(|a, b| a.1.total_cmp(b.1));
+
+    Ok(critical.map(|(i, &value)| CriticalLayer {
+        criterion: CriticalLayerCriterion::Settlement,
+        layer_index: i,
+        top: bounds[i].0,
+        bottom: bounds[i].1,
+        value,
+        share: Some(value / settlement.total_settlement),
+    }))
+}
+
+/// Finds the weakest layer within the bearing influence zone of a foundation.
+///
+/// Layers between the foundation base and 2B below it are ranked by the unit bearing resistance
+/// of a surface footing of the same width resting on them,
+///
+/// qr = c · Nc + 0.5 · γ' · B · Nγ
+///
+/// with the undrained or drained parameters of the analysis term and the Vesic factors. The
+/// layer with the lowest resistance contributes the lowest safety to the bearing capacity.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `foundation` - The foundation data.
+/// * `term` - Short or long-term condition.
+///
+/// # Returns
+/// * `Option<CriticalLayer>`: None when no soil layer lies in the influence zone.
+pub fn find_critical_bearing_layer(
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    term: AnalysisTerm,
+) -> Result<Option<CriticalLayer>, ValidationError> {
+    soil_profile.validate(&["thickness", "dry_unit_weight", "saturated_unit_weight"])?;
+    foundation.validate(&["foundation_depth", "foundation_width"])?;
+
+    let df = foundation.foundation_depth.unwrap();
+    let width = foundation.foundation_width.unwrap();
+    let zone_bottom = df + BEARING_INFLUENCE_RATIO * width;
+    let gwt = soil_profile.ground_water_level.unwrap();
+
+    let mut critical: Option<CriticalLayer> = None;
+    for (i, (top, bottom)) in get_layer_bounds(soil_profile).into_iter().enumerate() {
+        let layer = &soil_profile.layers[i];
+        if bottom <= df || top >= zone_bottom || layer.is_rock() {
+            continue;
+        }
+
+        let (phi, cohesion) = if layer.is_undrained(term) {
+            layer.validate_fields(&["cu", "phi_u"])?;
+            (layer.phi_u.unwrap(), layer.cu.unwrap())
+        } else {
+            layer.validate_fields(&["c_prime", "phi_prime"])?;
+            (layer.phi_prime.unwrap(), layer.c_prime.unwrap())
+        };
+        let unit_weight = if gwt >= (top + bottom) / 2.0 {
+            layer.dry_unit_weight.unwrap()
+        } else {
+            soil_profile
+                .get_environment()
+                .submerged_unit_weight(layer.saturated_unit_weight.unwrap())
+        };
+        let factors = calc_bearing_capacity_factors(phi);
+        let value = cohesion * factors.nc + 0.5 * unit_weight * width * factors.ng;
+
+        if critical.as_ref().is_none_or(|c| value < c.value) {
+            critical = Some(CriticalLayer {
+                criterion: CriticalLayerCriterion::BearingCapacity,
+                layer_index: i,
+                top,
+                bottom,
+                value,
+                share: None,
+            });
+        }
+    }
+
+    Ok(critical)
+}
+
+/// Finds the sample with the lowest liquefaction safety factor.
+///
+/// Each sample represents the interval from the previous sample depth to its own depth.
+///
+/// # Arguments
+/// * `layers` - Layer results of an SPT or Vs based liquefaction analysis, ordered by depth.
+///
+/// # Returns
+/// * `Option<CriticalLayer>`: None when no sample has a safety factor.
+pub fn find_critical_liquefaction_layer(
+    layers: &[CommonLiquefactionLayerResult],
+) -> Option<CriticalLayer> {
+    layers
+        .iter()
+        .enumerate()
+        .filter_map(|(i, layer)| layer.safety_factor.map(|fs| (i, fs)))
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(i, value)| CriticalLayer {
+            criterion: CriticalLayerCriterion::Liquefaction,
+            layer_index: i,
+            top: if i == 0 { 0.0 } else { layers[i - 1].depth },
+            bottom: layers[i].depth,
+            value,
+            share: None,
+        })
+}
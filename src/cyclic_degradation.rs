@@ -0,0 +1,185 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    models::soil_profile::SoilProfile,
+    validation::{validate_field, ValidationError},
+};
+
+/// Coefficients of the degradation parameter t = s · (CSR − CSRt)^r.
+///
+/// The default values are indicative for normally to lightly overconsolidated clays and should be
+/// calibrated against cyclic laboratory tests where available.
+///
+/// # Fields
+/// * `s` - Multiplier of the degradation parameter.
+/// * `r` - Exponent of the degradation parameter.
+/// * `threshold_csr` - Threshold cyclic stress ratio below which no degradation occurs.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct CyclicDegradationParams {
+    pub s: f64,
+    pub r: f64,
+    pub threshold_csr: f64,
+}
+
+impl Default for CyclicDegradationParams {
+    fn default() -> Self {
+        Self {
+            s: 0.3,
+            r: 1.0,
+            threshold_csr: 0.2,
+        }
+    }
+}
+
+/// Degradation result of a single soil layer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DegradedLayer {
+    /// Bottom depth of the layer (m).
+    pub depth: f64,
+    /// Static undrained shear strength (t/m²).
+    pub cu: f64,
+    /// Cyclic stress ratio τcyc / su.
+    pub csr: f64,
+    /// Degradation parameter t.
+    pub t: f64,
+    /// Degradation index δ = N^(−t).
+    pub degradation_index: f64,
+    /// Degraded undrained shear strength (t/m²).
+    pub degraded_cu: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CyclicDegradationResult {
+    /// Number of equivalent loading cycles.
+    pub number_of_cycles: f64,
+    /// Per-layer degradation results.
+    pub layers: Vec<DegradedLayer>,
+    /// Copy of the soil profile with degraded `cu` values.
+    pub degraded_profile: SoilProfile,
+}
+
+/// Calculates the degradation parameter t for a given cyclic stress ratio.
+///
+/// # Arguments
+/// * `csr` - Cyclic stress ratio τcyc / su.
+/// * `params` - Degradation coefficients.
+///
+/// # Returns
+/// * `f64`: Degradation parameter t (zero below the threshold).
+pub fn calc_degradation_parameter(csr: f64, params: &CyclicDegradationParams) -> f64 {
+    if csr <= params.threshold_csr {
+        return 0.0;
+    }
+    params.s * (csr - params.threshold_csr).powf(params.r)
+}
+
+/// Calculates the degradation index δ = N^(−t) (Idriss et al., 1978).
+///
+/// # Arguments
+/// * `number_of_cycles` - Number of equivalent loading cycles (N ≥ 1).
+/// * `t` - Degradation parameter.
+///
+/// # Returns
+/// * `f64`: Degradation index between 0 and 1.
+pub fn calc_degradation_index(number_of_cycles: f64, t: f64) -> f64 {
+    number_of_cycles.powf(-t)
+}
+
+/// Validates the input data for cyclic degradation calculations.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `cyclic_stress_ratios` - Cyclic stress ratio of each layer.
+/// * `number_of_cycles` - Number of equivalent loading cycles.
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(
+    soil_profile: &SoilProfile,
+    cyclic_stress_ratios: &[f64],
+    number_of_cycles: f64,
+) -> Result<(), ValidationError> {
+    soil_profile.validate(&["thickness", "cu"])?;
+    validate_field(
+        "number_of_cycles",
+        Some(number_of_cycles),
+        Some(1.0),
+        None,
+        "cyclic_degradation",
+    )?;
+
+    if cyclic_stress_ratios.len() != soil_profile.layers.len() {
+        return Err(ValidationError {
+            code: "cyclic_degradation.cyclic_stress_ratios.length_mismatch".to_string(),
+            message: "A cyclic stress ratio must be provided for each soil layer.".to_string(),
+        });
+    }
+
+    for &csr in cyclic_stress_ratios {
+        validate_field(
+            "cyclic_stress_ratio",
+            Some(csr),
+            Some(0.0),
+            None,
+            "cyclic_degradation",
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Calculates the degraded undrained shear strength profile under cyclic (storm or earthquake) loading.
+///
+/// Each layer's `cu` is multiplied by the degradation index δ = N^(−t), where t grows with the
+/// cyclic stress ratio of the layer. The returned profile can be passed directly to the bearing
+/// capacity and sliding checks in short term condition.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `cyclic_stress_ratios` - Cyclic stress ratio τcyc / su of each layer.
+/// * `number_of_cycles` - Number of equivalent loading cycles.
+/// * `params` - Degradation coefficients.
+///
+/// # Returns
+/// * `CyclicDegradationResult` with per-layer results and the degraded profile.
+pub fn calc_cyclic_degradation(
+    soil_profile: &SoilProfile,
+    cyclic_stress_ratios: &[f64],
+    number_of_cycles: f64,
+    params: &CyclicDegradationParams,
+) -> Result<CyclicDegradationResult, ValidationError> {
+    validate_input(soil_profile, cyclic_stress_ratios, number_of_cycles)?;
+
+    let mut degraded_profile = soil_profile.clone();
+    degraded_profile.calc_layer_depths();
+
+    let mut layers = Vec::with_capacity(soil_profile.layers.len());
+
+    for (layer, &csr) in degraded_profile
+        .layers
+        .iter_mut()
+        .zip(cyclic_stress_ratios.iter())
+    {
+        let cu = layer.cu.unwrap();
+        let t = calc_degradation_parameter(csr, params);
+        let degradation_index = calc_degradation_index(number_of_cycles, t);
+        let degraded_cu = cu * degradation_index;
+
+        layer.cu = Some(degraded_cu);
+
+        layers.push(DegradedLayer {
+            depth: layer.depth.unwrap(),
+            cu,
+            csr,
+            t,
+            degradation_index,
+            degraded_cu,
+        });
+    }
+
+    Ok(CyclicDegradationResult {
+        number_of_cycles,
+        layers,
+        degraded_profile,
+    })
+}
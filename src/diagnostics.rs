@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{enums::MethodId, method_registry::get_method_info};
+
+/// Warning raised when an input is outside the calibration range of a method.
+///
+/// Unlike a `ValidationError`, a warning does not stop the analysis; the method is still
+/// evaluated (by extrapolation or clamping) and the warning is reported with the result.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Warning {
+    /// Warning code (e.g. "applicability.vs.out_of_range").
+    pub code: String,
+    /// Human readable description of the warning.
+    pub message: String,
+    /// Method whose calibration range is exceeded.
+    pub method: MethodId,
+    /// Name of the input parameter.
+    pub parameter: String,
+    /// Value of the input parameter.
+    pub value: f64,
+}
+
+/// Checks an input value against the calibration range of a method in the registry.
+///
+/// # Arguments
+/// * `method` - The method being used.
+/// * `parameter` - Name of the input parameter.
+/// * `value` - Value of the input parameter.
+///
+/// # Returns
+/// * `Option<Warning>`: A warning if the value is outside the range, `None` otherwise or if the
+///   method has no range for the parameter.
+pub fn check_limit(method: MethodId, parameter: &str, value: f64) -> Option<Warning> {
+    let info = get_method_info(method);
+    let limit = info.get_limit(parameter)?;
+    if limit.contains(value) {
+        return None;
+    }
+
+    let range = match (limit.min, limit.max) {
+        (Some(min), Some(max)) => format!("{} – {}", min, max),
+        (Some(min), None) => format!("≥ {}", min),
+        (None, Some(max)) => format!("≤ {}", max),
+        (None, None) => String::new(),
+    };
+
+    Some(Warning {
+        code: format!("applicability.{}.out_of_range", parameter),
+        message: format!(
+            "{} = {} is outside the calibration range ({}) of {}.",
+            parameter, value, range, info.name
+        ),
+        method,
+        parameter: parameter.to_string(),
+        value,
+    })
+}
+
+/// Checks several input values against the calibration ranges of a method.
+///
+/// # Arguments
+/// * `method` - The method being used.
+/// * `inputs` - (parameter name, value) pairs.
+///
+/// # Returns
+/// * `Vec<Warning>`: Warnings for the values outside their ranges.
+pub fn check_limits(method: MethodId, inputs: &[(&str, f64)]) -> Vec<Warning> {
+    inputs
+        .iter()
+        .filter_map(|&(parameter, value)| check_limit(method, parameter, value))
+        .collect()
+}
@@ -0,0 +1,309 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    allowable_pressure::{calc_total_settlement, select_consolidation_method},
+    elastic_settlement::boussinesq::calc_point_settlement,
+    enums::{PressureConvention, SettlementPoint},
+    models::{foundation::Foundation, soil_profile::SoilProfile},
+    validation::{validate_field, ValidationError},
+};
+
+/// Limits of the differential settlement checks.
+///
+/// # Fields
+/// * `angular_distortion` - Allowable angular distortion Δ / L (e.g. 1/500).
+/// * `differential_settlement` - Allowable differential settlement (cm), not checked if not set.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct DistortionLimits {
+    pub angular_distortion: f64,
+    pub differential_settlement: Option<f64>,
+}
+
+impl Default for DistortionLimits {
+    fn default() -> Self {
+        Self {
+            angular_distortion: 1.0 / 500.0,
+            differential_settlement: None,
+        }
+    }
+}
+
+/// A foundation at a plan location with its pressure.
+///
+/// # Fields
+/// * `id` - Identifier of the location, e.g. the column of the footing.
+/// * `x`, `y` - Plan coordinates of the foundation center (m).
+/// * `foundation` - The foundation data.
+/// * `foundation_pressure` - The foundation pressure (t/m²).
+/// * `pressure_convention` - Whether `foundation_pressure` is gross or net (gross if not set).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocatedFoundation {
+    pub id: String,
+    pub x: f64,
+    pub y: f64,
+    pub foundation: Foundation,
+    pub foundation_pressure: f64,
+    #[serde(default)]
+    pub pressure_convention: PressureConvention,
+}
+
+/// Settlement at a plan location.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocationSettlement {
+    /// Identifier of the location.
+    pub id: String,
+    /// x coordinate of the location (m).
+    pub x: f64,
+    /// y coordinate of the location (m).
+    pub y: f64,
+    /// Settlement at the location (cm).
+    pub settlement: f64,
+}
+
+impl LocationSettlement {
+    /// Creates a new `LocationSettlement` instance.
+    pub fn new(id: &str, x: f64, y: f64, settlement: f64) -> Self {
+        Self {
+            id: id.to_string(),
+            x,
+            y,
+            settlement,
+        }
+    }
+}
+
+/// Differential settlement between two locations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SettlementPair {
+    /// Identifier of the first location.
+    pub first: String,
+    /// Identifier of the second location.
+    pub second: String,
+    /// Horizontal distance between the locations (m).
+    pub distance: f64,
+    /// Absolute difference of the settlements (cm).
+    pub differential_settlement: f64,
+    /// Angular distortion Δ / L.
+    pub angular_distortion: f64,
+    /// Indicates whether the pair is within the limits.
+    pub is_safe: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DifferentialSettlementResult {
+    /// Settlement at each location.
+    pub locations: Vec<LocationSettlement>,
+    /// Results of every pair of locations.
+    pub pairs: Vec<SettlementPair>,
+    /// Largest differential settlement of all pairs (cm).
+    pub max_differential_settlement: f64,
+    /// Largest angular distortion of all pairs.
+    pub max_angular_distortion: f64,
+    /// Limits of the check.
+    pub limits: DistortionLimits,
+    /// Indicates whether all pairs are within the limits.
+    pub is_safe: bool,
+}
+
+/// Validates the input data for differential settlement checks.
+///
+/// # Arguments
+/// * `locations` - Settlements at the locations.
+/// * `limits` - Limits of the check.
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(
+    locations: &[LocationSettlement],
+    limits: &DistortionLimits,
+) -> Result<(), ValidationError> {
+    if locations.len() < 2 {
+        return Err(ValidationError {
+            code: "differential_settlement.too_few_locations".into(),
+            message: "At least two locations must be provided.".into(),
+        });
+    }
+    for (i, a) in locations.iter().enumerate() {
+        for b in &locations[i + 1..] {
+            if a.x == b.x && a.y == b.y {
+                return Err(ValidationError {
+                    code: "differential_settlement.coincident_locations".into(),
+                    message: format!("Locations '{}' and '{}' coincide.", a.id, b.id),
+                });
+            }
+        }
+    }
+    validate_field(
+        "angular_distortion",
+        Some(limits.angular_distortion),
+        Some(0.0),
+        None,
+        "differential_settlement",
+    )?;
+    if limits.differential_settlement.is_some() {
+        validate_field(
+            "differential_settlement",
+            limits.differential_settlement,
+            Some(0.0),
+            None,
+            "differential_settlement",
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Calculates the differential settlement and angular distortion between every pair of
+/// locations and checks them against the limits.
+///
+/// The angular distortion of a pair is β = |s1 − s2| / L, with L the horizontal distance
+/// between the locations.
+///
+/// # Arguments
+/// * `locations` - Settlements at the locations.
+/// * `limits` - Limits of the check.
+///
+/// # Returns
+/// * `DifferentialSettlementResult` with the result of every pair.
+pub fn calc_differential_settlements(
+    locations: Vec<LocationSettlement>,
+    limits: &DistortionLimits,
+) -> Result<DifferentialSettlementResult, ValidationError> {
+    validate_input(&locations, limits)?;
+
+    let mut pairs = Vec::new();
+    for (i, a) in locations.iter().enumerate() {
+        for b in &locations[i + 1..] {
+            let distance = (a.x - b.x).hypot(a.y - b.y);
+            let differential_settlement = (a.settlement - b.settlement).abs();
+            let angular_distortion = differential_settlement / 100.0 / distance;
+            let is_safe = angular_distortion <= limits.angular_distortion
+                && limits
+                    .differential_settlement
+                    .is_none_or(|limit| differential_settlement <= limit);
+
+            pairs.push(SettlementPair {
+                first: a.id.clone(),
+                second: b.id.clone(),
+                distance,
+                differential_settlement,
+                angular_distortion,
+                is_safe,
+            });
+        }
+    }
+
+    Ok(DifferentialSettlementResult {
+        max_differential_settlement: pairs
+            .iter()
+            .map(|p| p.differential_settlement)
+            .fold(0.0, f64::max),
+        max_angular_distortion: pairs
+            .iter()
+            .map(|p| p.angular_distortion)
+            .fold(0.0, f64::max),
+        is_safe: pairs.iter().all(|p| p.is_safe),
+        locations,
+        pairs,
+        limits: *limits,
+    })
+}
+
+/// Calculates the settlement of each footing and checks the differential settlements between
+/// every pair of footings.
+///
+/// The settlement of a footing is the Boussinesq elastic settlement of its center plus the
+/// consolidation settlement, with the compression index method when the soil layers have its
+/// parameters and the volume compressibility method when they have mv. The footings are treated
+/// independently, without the stress overlap of neighboring footings.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `footings` - The footings at their plan locations.
+/// * `limits` - Limits of the check.
+///
+/// # Returns
+/// * `DifferentialSettlementResult` with the result of every pair of footings.
+pub fn calc_footing_differential_settlements(
+    soil_profile: &SoilProfile,
+    footings: &[LocatedFoundation],
+    limits: &DistortionLimits,
+) -> Result<DifferentialSettlementResult, ValidationError> {
+    let locations = footings
+        .iter()
+        .map(|footing| {
+            let consolidation_method =
+                select_consolidation_method(soil_profile, &footing.foundation);
+            let settlement = calc_total_settlement(
+                soil_profile,
+                &footing.foundation,
+                footing.foundation_pressure,
+                footing.pressure_convention,
+                consolidation_method,
+            )?;
+            Ok(LocationSettlement::new(
+                &footing.id,
+                footing.x,
+                footing.y,
+                settlement,
+            ))
+        })
+        .collect::<Result<Vec<_>, ValidationError>>()?;
+
+    calc_differential_settlements(locations, limits)
+}
+
+/// Calculates the elastic settlement at the center and corners of a flexible mat and checks the
+/// differential settlements between them.
+///
+/// The mat center is at the origin with the width along x and the length along y. By symmetry
+/// the four corners settle equally, so the governing pairs are between the center and a corner.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `foundation` - The mat foundation data.
+/// * `foundation_pressure` - The foundation pressure (t/m²).
+/// * `pressure_convention` - Whether `foundation_pressure` is gross or net.
+/// * `limits` - Limits of the check.
+///
+/// # Returns
+/// * `DifferentialSettlementResult` with the center listed first and the corners after it.
+pub fn calc_mat_differential_settlements(
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    foundation_pressure: f64,
+    pressure_convention: PressureConvention,
+    limits: &DistortionLimits,
+) -> Result<DifferentialSettlementResult, ValidationError> {
+    let result = calc_point_settlement(
+        &mut soil_profile.clone(),
+        foundation,
+        foundation_pressure,
+        pressure_convention,
+        SettlementPoint::CenterFlexible,
+    )?;
+    let half_width = foundation.foundation_width.unwrap() / 2.0;
+    let half_length = foundation.foundation_length.unwrap() / 2.0;
+
+    let mut locations = vec![LocationSettlement::new(
+        "center",
+        0.0,
+        0.0,
+        result.center_flexible,
+    )];
+    for (id, sx, sy) in [
+        ("corner_1", -1.0, -1.0),
+        ("corner_2", 1.0, -1.0),
+        ("corner_3", 1.0, 1.0),
+        ("corner_4", -1.0, 1.0),
+    ] {
+        locations.push(LocationSettlement::new(
+            id,
+            sx * half_width,
+            sy * half_length,
+            result.corner_flexible,
+        ));
+    }
+
+    calc_differential_settlements(locations, limits)
+}
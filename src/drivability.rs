@@ -0,0 +1,275 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bearing_capacity::vesic::calc_bearing_capacity_factors,
+    constants::TON_TO_KN,
+    models::{
+        driven_pile::DrivenPile,
+        soil_profile::{SoilLayer, SoilProfile},
+    },
+    validation::{validate_field, ValidationError},
+};
+
+/// Empirical set allowance of the ENR formula, 0.1 in (m).
+const ENR_SET_CONSTANT: f64 = 0.00254;
+
+/// Drivability result at a single pile toe depth.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DrivabilityPoint {
+    /// Pile toe depth (m).
+    pub depth: f64,
+    /// Long-term shaft resistance (t).
+    pub shaft_resistance: f64,
+    /// Toe resistance (t).
+    pub toe_resistance: f64,
+    /// Soil resistance to driving, with the shaft resistance reduced by the setup factors (t).
+    pub driving_resistance: f64,
+    /// Long-term static capacity after setup (t).
+    pub static_capacity: f64,
+    /// Blow count per 0.25 m from the ENR formula.
+    pub enr_blow_count: f64,
+    /// Blow count per 0.25 m from the FHWA modified Gates formula.
+    pub gates_blow_count: f64,
+    /// Indicates whether the Gates blow count exceeds practical refusal.
+    pub is_refusal: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DrivabilityResult {
+    /// Results at each toe depth.
+    pub points: Vec<DrivabilityPoint>,
+    /// Shallowest depth at which practical refusal is predicted, if any (m).
+    pub refusal_depth: Option<f64>,
+}
+
+/// Returns whether a layer is treated as cohesive in drivability calculations.
+fn is_cohesive(layer: &SoilLayer) -> bool {
+    layer.cu.is_some_and(|cu| cu > 0.0)
+}
+
+/// Returns the soil setup factor of a layer (Rausche et al., 1996).
+///
+/// The setup factor is the ratio of the long-term shaft resistance to the shaft resistance
+/// during driving: 2.0 for clays, 1.2 for granular soils with more than 12% fines and 1.0 for
+/// clean granular soils.
+///
+/// # Arguments
+/// * `layer` - The soil layer.
+///
+/// # Returns
+/// * `f64`: Setup factor.
+pub fn get_setup_factor(layer: &SoilLayer) -> f64 {
+    if is_cohesive(layer) {
+        2.0
+    } else if layer.get_fine_content().is_some_and(|fc| fc > 12.0) {
+        1.2
+    } else {
+        1.0
+    }
+}
+
+/// Calculates the unit shaft friction of a layer at a given effective vertical stress.
+///
+/// Cohesive layers use the API α method, granular layers the β method with K = 1.0 and
+/// δ = φ' − 5°.
+///
+/// # Arguments
+/// * `layer` - The soil layer.
+/// * `effective_stress` - Effective vertical stress (t/m²).
+///
+/// # Returns
+/// * `f64`: Unit shaft friction (t/m²).
+pub fn calc_unit_shaft_friction(layer: &SoilLayer, effective_stress: f64) -> f64 {
+    if is_cohesive(layer) {
+        let cu = layer.cu.unwrap();
+        let psi = cu / effective_stress.max(1e-6);
+        let alpha = if psi <= 1.0 {
+            0.5 * psi.powf(-0.5)
+        } else {
+            0.5 * psi.powf(-0.25)
+        };
+        alpha.min(1.0) * cu
+    } else {
+        let delta = (layer.phi_prime.unwrap() - 5.0).max(0.0);
+        effective_stress * delta.to_radians().tan()
+    }
+}
+
+/// Calculates the unit toe resistance of a layer at a given effective vertical stress.
+///
+/// # Arguments
+/// * `layer` - The soil layer at the pile toe.
+/// * `effective_stress` - Effective vertical stress at the toe (t/m²).
+///
+/// # Returns
+/// * `f64`: Unit toe resistance (t/m²).
+pub fn calc_unit_toe_resistance(layer: &SoilLayer, effective_stress: f64) -> f64 {
+    if is_cohesive(layer) {
+        9.0 * layer.cu.unwrap()
+    } else {
+        calc_bearing_capacity_factors(layer.phi_prime.unwrap()).nq * effective_stress
+    }
+}
+
+/// Calculates the blow count per 0.25 m from the Engineering News Record formula.
+///
+/// Ru = e · E / (s + 0.254 cm)
+///
+/// # Arguments
+/// * `resistance` - Soil resistance to driving (t).
+/// * `hammer_energy` - Rated hammer energy (t·m).
+/// * `hammer_efficiency` - Hammer efficiency.
+///
+/// # Returns
+/// * `f64`: Blow count per 0.25 m (infinite if the resistance cannot be overcome).
+pub fn calc_enr_blow_count(resistance: f64, hammer_energy: f64, hammer_efficiency: f64) -> f64 {
+    let set = hammer_efficiency * hammer_energy / resistance - ENR_SET_CONSTANT;
+    if set <= 0.0 {
+        f64::INFINITY
+    } else {
+        0.25 / set
+    }
+}
+
+/// Calculates the blow count per 0.25 m from the FHWA modified Gates formula.
+///
+/// Ru = 6.7 · √(e · E) · log10(10 · Nb) − 445, with Ru in kN, E in J and Nb in blows per 25 mm.
+///
+/// # Arguments
+/// * `resistance` - Soil resistance to driving (t).
+/// * `hammer_energy` - Rated hammer energy (t·m).
+/// * `hammer_efficiency` - Hammer efficiency.
+///
+/// # Returns
+/// * `f64`: Blow count per 0.25 m.
+pub fn calc_gates_blow_count(resistance: f64, hammer_energy: f64, hammer_efficiency: f64) -> f64 {
+    let resistance_kn = resistance * TON_TO_KN;
+    let energy_j = hammer_efficiency * hammer_energy * TON_TO_KN * 1000.0;
+    let blows_per_25mm = 10f64.powf((resistance_kn + 445.0) / (6.7 * energy_j.sqrt())) / 10.0;
+    blows_per_25mm * 10.0
+}
+
+/// Validates the input data for drivability calculations.
+///
+/// # Arguments
+/// * `pile` - The driven pile and hammer data.
+/// * `soil_profile` - The soil profile data.
+/// * `depth_step` - Depth increment of the calculation (m).
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(
+    pile: &DrivenPile,
+    soil_profile: &SoilProfile,
+    depth_step: f64,
+) -> Result<(), ValidationError> {
+    pile.validate(&[
+        "diameter",
+        "length",
+        "hammer_energy",
+        "hammer_efficiency",
+        "refusal_blow_count",
+    ])?;
+    soil_profile.validate(&["thickness", "dry_unit_weight", "saturated_unit_weight"])?;
+    for layer in soil_profile.layers.iter().filter(|l| !is_cohesive(l)) {
+        layer.validate_fields(&["phi_prime"])?;
+    }
+    validate_field(
+        "depth_step",
+        Some(depth_step),
+        Some(0.0001),
+        None,
+        "drivability",
+    )?;
+
+    if soil_profile.layers.last().unwrap().depth.unwrap() < pile.length.unwrap() {
+        return Err(ValidationError {
+            code: "driven_pile.length.greater_than_soil_profile_depth".to_string(),
+            message: "Pile length is greater than the soil profile depth.".to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Screens the drivability of a pile by estimating blow counts with depth.
+///
+/// The static resistance is calculated at each toe depth. The shaft resistance mobilized during
+/// driving is the long-term value divided by the setup factor of each layer, so cohesive layers
+/// are easier to drive through than their long-term capacity suggests. The blow counts are
+/// then obtained by inverting the ENR and FHWA modified Gates dynamic formulas. This is a
+/// screening tool only and does not replace a wave equation analysis.
+///
+/// # Arguments
+/// * `pile` - The driven pile and hammer data.
+/// * `soil_profile` - The soil profile data.
+/// * `depth_step` - Depth increment of the calculation (m).
+///
+/// # Returns
+/// * `DrivabilityResult` with blow counts at each depth and the refusal depth.
+pub fn calc_drivability(
+    pile: &DrivenPile,
+    soil_profile: &mut SoilProfile,
+    depth_step: f64,
+) -> Result<DrivabilityResult, ValidationError> {
+    soil_profile.calc_layer_depths();
+    validate_input(pile, soil_profile, depth_step)?;
+
+    let diameter = pile.diameter.unwrap();
+    let length = pile.length.unwrap();
+    let energy = pile.hammer_energy.unwrap();
+    let efficiency = pile.hammer_efficiency.unwrap();
+    let refusal_blow_count = pile.refusal_blow_count.unwrap();
+
+    let perimeter = std::f64::consts::PI * diameter;
+    let toe_area = std::f64::consts::PI * diameter.powi(2) / 4.0;
+
+    let mut points = Vec::new();
+    let mut refusal_depth = None;
+    let mut shaft_resistance = 0.0;
+    let mut driving_shaft_resistance = 0.0;
+    let mut top = 0.0;
+
+    while top < length - 1e-9 {
+        let depth = (top + depth_step).min(length);
+        let center = (top + depth) / 2.0;
+
+        let layer = soil_profile.get_layer_at_depth(center);
+        let friction = calc_unit_shaft_friction(layer, soil_profile.calc_effective_stress(center))
+            * perimeter
+            * (depth - top);
+        shaft_resistance += friction;
+        driving_shaft_resistance += friction / get_setup_factor(layer);
+
+        let toe_layer = soil_profile.get_layer_at_depth(depth);
+        let toe_resistance =
+            calc_unit_toe_resistance(toe_layer, soil_profile.calc_effective_stress(depth))
+                * toe_area;
+
+        let driving_resistance = driving_shaft_resistance + toe_resistance;
+        let gates_blow_count = calc_gates_blow_count(driving_resistance, energy, efficiency);
+        let is_refusal = gates_blow_count > refusal_blow_count;
+
+        if is_refusal && refusal_depth.is_none() {
+            refusal_depth = Some(depth);
+        }
+
+        points.push(DrivabilityPoint {
+            depth,
+            shaft_resistance,
+            toe_resistance,
+            driving_resistance,
+            static_capacity: shaft_resistance + toe_resistance,
+            enr_blow_count: calc_enr_blow_count(driving_resistance, energy, efficiency),
+            gates_blow_count,
+            is_refusal,
+        });
+
+        top = depth;
+    }
+
+    Ok(DrivabilityResult {
+        points,
+        refusal_depth,
+    })
+}
@@ -1,4 +1,5 @@
 use crate::{
+    enums::PressureConvention,
     models::{foundation::Foundation, soil_profile::SoilProfile},
     validation::{validate_field, ValidationError},
 };
@@ -75,13 +76,16 @@ fn find_effective_depth(f: f64, b: f64, df: f64, l: f64, sp: &SoilProfile) -> f6
 /// * `soil_profile` - A reference to a `SoilProfile` object.
 /// * `foundation_data` - A reference to a `Foundation` object.
 /// * `foundation_pressure` - The pressure applied by the foundation in ton/m2.
+/// * `pressure_convention` - Whether `foundation_pressure` is gross or net.
 ///
 /// # Returns
-/// * The effective depth as a `f64` value in meters.
+/// * The effective depth as a `f64` value in meters. The foundation depth is returned for a fully
+///   compensated foundation (q_net ≤ 0).
 pub fn calc_effective_depth(
     soil_profile: &SoilProfile,
     foundation_data: &Foundation,
     foundation_pressure: f64,
+    pressure_convention: PressureConvention,
 ) -> Result<f64, ValidationError> {
     validate_input(soil_profile, foundation_data, foundation_pressure)?;
 
@@ -89,7 +93,12 @@ pub fn calc_effective_depth(
     let b = foundation_data.foundation_width.unwrap();
     let l = foundation_data.foundation_length.unwrap();
 
-    let q_net = foundation_pressure - soil_profile.calc_normal_stress(df);
+    let q_net =
+        foundation_data.calc_net_pressure(soil_profile, foundation_pressure, pressure_convention);
+    if q_net <= 0.0 {
+        // Fully compensated foundation, no stress increment below the base
+        return Ok(df);
+    }
     let f = q_net * b * l;
 
     let result = find_effective_depth(f, b, df, l, soil_profile);
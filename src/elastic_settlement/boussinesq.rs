@@ -1,11 +1,47 @@
+use serde::Serialize;
+
 use crate::{
     consolidation_settlement::model::SettlementResult,
-    models::{foundation::Foundation, soil_profile::SoilProfile},
+    diagnostics::check_limits,
+    enums::{FoundationShape, MethodId, PressureConvention, SettlementPoint},
+    models::{
+        foundation::Foundation,
+        soil_profile::{SoilLayer, SoilProfile},
+    },
     validation::{validate_field, ValidationError},
 };
 
 use super::reduction_factors::interpolate_if;
 
+/// Number of sublayers a layer with a modulus gradient is divided into.
+const GIBSON_SUBLAYER_COUNT: usize = 10;
+
+/// Ratio of the average to the center settlement of a flexible foundation.
+pub const AVERAGE_FLEXIBLE_RATIO: f64 = 0.85;
+/// Ratio of the settlement of a rigid foundation to the center settlement of a flexible one.
+pub const RIGID_RATIO: f64 = 0.93;
+
+/// Settlement of a layer from the foundation base to depth h, with the arguments of
+/// `single_layer_settlement`.
+type LayerSettlementFn = fn(f64, f64, f64, f64, f64, f64, f64) -> f64;
+
+/// Represents the elastic settlement of a foundation at the settlement points of Bowles (1996).
+#[derive(Debug, Clone, Serialize)]
+pub struct PointSettlementResult {
+    /// Settlement point of `settlement`.
+    pub point: SettlementPoint,
+    /// Settlement per layer at the selected point.
+    pub settlement: SettlementResult,
+    /// Settlement at the center of a flexible foundation (cm).
+    pub center_flexible: f64,
+    /// Settlement at a corner of a flexible foundation (cm).
+    pub corner_flexible: f64,
+    /// Average settlement of a flexible foundation (cm).
+    pub average_flexible: f64,
+    /// Settlement of a rigid foundation (cm).
+    pub rigid: f64,
+}
+
 /// Validates the input data for elastic settlement calculations.
 ///
 /// # Arguments
@@ -24,9 +60,18 @@ pub fn validate_input(
         "thickness",
         "dry_unit_weight",
         "saturated_unit_weight",
-        "elastic_modulus",
         "poissons_ratio",
     ])?;
+    for layer in soil_profile.layers.iter() {
+        if layer.is_rock() && layer.rock_mass_modulus.is_some() {
+            layer.validate_fields(&["rock_mass_modulus"])?;
+        } else {
+            layer.validate_fields(&["elastic_modulus"])?;
+        }
+        if layer.elastic_modulus_gradient.is_some() {
+            layer.validate_fields(&["elastic_modulus_gradient"])?;
+        }
+    }
     foundation.validate(&["foundation_depth", "foundation_width", "foundation_length"])?;
 
     validate_field(
@@ -99,12 +144,120 @@ pub fn single_layer_settlement(h: f64, u: f64, e: f64, l: f64, b: f64, df: f64,
     100.0 * q_net * 4.0 * b * if_value * ip * (1.0 - u.powi(2)) * 0.5 / e
 }
 
+/// Calculates the settlement (S) at a corner of a rectangular foundation for a single soil layer.
+///
+/// The arguments are those of `single_layer_settlement`. At the corner, B' = B, m = 1 and the
+/// influence factor Ip uses N = H / B.
+///
+/// # Returns
+/// * `S` - Settlement in centimeters [cm]
+///
+/// # Formula
+/// S = 100 * qNet * B * If * Ip * (1 - u²) / E
+///
+/// Reference: Bowles, J.E. (1996)
+pub fn corner_layer_settlement(h: f64, u: f64, e: f64, l: f64, b: f64, df: f64, q_net: f64) -> f64 {
+    let lb = l / b;
+    let db = df / b;
+    let ip = calc_ip(h / 2.0, b, l, u);
+    let if_value = interpolate_if(u, db, lb);
+
+    100.0 * q_net * b * if_value * ip * (1.0 - u.powi(2)) / e
+}
+
+/// Calculates the settlement of a soil layer whose modulus increases linearly with depth
+/// (Gibson soil).
+///
+/// The part of the layer below the foundation is divided into sublayers, each with the modulus at
+/// its center, and the sublayer settlements are obtained from the difference of the Steinbrenner
+/// influence factors at the sublayer bottom and top.
+///
+/// # Arguments
+/// * `layer` - The soil layer.
+/// * `layer_top` - Depth of the top of the layer [m].
+/// * `foundation` - The foundation parameters.
+/// * `q_net` - Net foundation pressure (qNet) [t/m²].
+///
+/// # Returns
+/// * `S` - Settlement in centimeters [cm]
+pub fn gibson_layer_settlement(
+    layer: &SoilLayer,
+    layer_top: f64,
+    foundation: &Foundation,
+    q_net: f64,
+) -> f64 {
+    calc_gibson_layer_settlement(layer, layer_top, foundation, q_net, single_layer_settlement)
+}
+
+/// Calculates the Gibson layer settlement with the given layer settlement function.
+fn calc_gibson_layer_settlement(
+    layer: &SoilLayer,
+    layer_top: f64,
+    foundation: &Foundation,
+    q_net: f64,
+    layer_settlement: LayerSettlementFn,
+) -> f64 {
+    let df = foundation.foundation_depth.unwrap();
+    let width = foundation.foundation_width.unwrap();
+    let length = foundation.foundation_length.unwrap();
+    let u = layer.poissons_ratio.unwrap();
+
+    let top = layer_top.max(df);
+    let bottom = layer.depth.unwrap();
+    if bottom <= top {
+        return 0.0;
+    }
+
+    let sublayer_thickness = (bottom - top) / GIBSON_SUBLAYER_COUNT as f64;
+
+    (0..GIBSON_SUBLAYER_COUNT)
+        .map(|j| {
+            let z1 = top + j as f64 * sublayer_thickness;
+            let z2 = z1 + sublayer_thickness;
+            let e = layer
+                .get_elastic_modulus_at(0.5 * (z1 + z2) - layer_top)
+                .unwrap();
+
+            let s2 = layer_settlement(z2 - df, u, e, length, width, df, q_net);
+            let s1 = if z1 > df {
+                layer_settlement(z1 - df, u, e, length, width, df, q_net)
+            } else {
+                0.0
+            };
+            (s2 - s1).max(0.0)
+        })
+        .sum()
+}
+
+/// Returns a copy of the foundation with the plan dimensions used by the rectangular influence
+/// factors. Circular and ring footings are replaced by a square of equal area, B = L = √A.
+///
+/// # Arguments
+/// * `foundation` - The foundation parameters.
+pub fn calc_equivalent_rectangle(foundation: &Foundation) -> Result<Foundation, ValidationError> {
+    let mut equivalent = foundation.clone();
+    equivalent.apply_shape()?;
+
+    if let FoundationShape::Circular { .. } | FoundationShape::Ring { .. } = equivalent.shape {
+        let side = equivalent.foundation_area.unwrap().sqrt();
+        equivalent.foundation_width = Some(side);
+        equivalent.foundation_length = Some(side);
+    }
+
+    Ok(equivalent)
+}
+
 /// Calculates the elastic settlement of a foundation based on the soil profile and foundation parameters.
+/// The rock mass modulus is used for rock layers when it is provided. Layers with an elastic
+/// modulus gradient are treated as Gibson soil (see `gibson_layer_settlement`).
+/// The settlement is at the center of a flexible foundation; see `calc_point_settlement` for the
+/// corner, average and rigid settlements.
 ///
 /// # Arguments
 /// * `soil_profile` - The soil profile containing the layers of soil.
 /// * `foundation` - The foundation parameters.
 /// * `foundation_pressure` - The foundation pressure (q) [t/m²].
+/// * `pressure_convention` - Whether `foundation_pressure` is gross or net.
 ///
 /// # Returns
 /// * A vector of settlements for each layer in the soil profile.
@@ -114,7 +267,119 @@ pub fn calc_elastic_settlement(
     soil_profile: &mut SoilProfile,
     foundation: &Foundation,
     foundation_pressure: f64,
+    pressure_convention: PressureConvention,
 ) -> Result<SettlementResult, ValidationError> {
+    calc_settlement_with(
+        soil_profile,
+        foundation,
+        foundation_pressure,
+        pressure_convention,
+        single_layer_settlement,
+    )
+}
+
+/// Calculates the elastic settlement at a corner of a flexible foundation.
+///
+/// Same as `calc_elastic_settlement` with the corner influence factors (see
+/// `corner_layer_settlement`).
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile containing the layers of soil.
+/// * `foundation` - The foundation parameters.
+/// * `foundation_pressure` - The foundation pressure (q) [t/m²].
+/// * `pressure_convention` - Whether `foundation_pressure` is gross or net.
+///
+/// # Returns
+/// * `SettlementResult` with the corner settlement of each layer.
+pub fn calc_corner_settlement(
+    soil_profile: &mut SoilProfile,
+    foundation: &Foundation,
+    foundation_pressure: f64,
+    pressure_convention: PressureConvention,
+) -> Result<SettlementResult, ValidationError> {
+    calc_settlement_with(
+        soil_profile,
+        foundation,
+        foundation_pressure,
+        pressure_convention,
+        corner_layer_settlement,
+    )
+}
+
+/// Calculates the elastic settlement of a foundation at a settlement point.
+///
+/// The center and corner settlements of a flexible foundation come from their influence factors,
+/// while the average settlement of a flexible foundation and the settlement of a rigid one are
+/// taken as 0.85 and 0.93 times the center settlement (Bowles, 1996). All four settlements are
+/// reported, and the per-layer result is given for the selected point.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile containing the layers of soil.
+/// * `foundation` - The foundation parameters.
+/// * `foundation_pressure` - The foundation pressure (q) [t/m²].
+/// * `pressure_convention` - Whether `foundation_pressure` is gross or net.
+/// * `point` - Settlement point of the per-layer result.
+///
+/// # Returns
+/// * `PointSettlementResult` with the settlements at all points.
+pub fn calc_point_settlement(
+    soil_profile: &mut SoilProfile,
+    foundation: &Foundation,
+    foundation_pressure: f64,
+    pressure_convention: PressureConvention,
+    point: SettlementPoint,
+) -> Result<PointSettlementResult, ValidationError> {
+    let center = calc_elastic_settlement(
+        soil_profile,
+        foundation,
+        foundation_pressure,
+        pressure_convention,
+    )?;
+    let corner = calc_corner_settlement(
+        soil_profile,
+        foundation,
+        foundation_pressure,
+        pressure_convention,
+    )?;
+
+    let center_flexible = center.total_settlement;
+    let corner_flexible = corner.total_settlement;
+
+    let settlement = match point {
+        SettlementPoint::CenterFlexible => center,
+        SettlementPoint::CornerFlexible => corner,
+        SettlementPoint::AverageFlexible => scale_settlement(center, AVERAGE_FLEXIBLE_RATIO),
+        SettlementPoint::Rigid => scale_settlement(center, RIGID_RATIO),
+    };
+
+    Ok(PointSettlementResult {
+        point,
+        settlement,
+        center_flexible,
+        corner_flexible,
+        average_flexible: AVERAGE_FLEXIBLE_RATIO * center_flexible,
+        rigid: RIGID_RATIO * center_flexible,
+    })
+}
+
+/// Scales the layer and total settlements of a result by a ratio.
+fn scale_settlement(mut result: SettlementResult, ratio: f64) -> SettlementResult {
+    for settlement in result.settlement_per_layer.iter_mut() {
+        *settlement *= ratio;
+    }
+    result.total_settlement *= ratio;
+    result
+}
+
+/// Calculates the elastic settlement of a foundation with the given layer settlement function.
+fn calc_settlement_with(
+    soil_profile: &mut SoilProfile,
+    foundation: &Foundation,
+    foundation_pressure: f64,
+    pressure_convention: PressureConvention,
+    layer_settlement: LayerSettlementFn,
+) -> Result<SettlementResult, ValidationError> {
+    let foundation = &calc_equivalent_rectangle(foundation)?;
     validate_input(soil_profile, foundation, foundation_pressure)?;
     soil_profile.calc_layer_depths();
 
@@ -123,25 +388,52 @@ pub fn calc_elastic_settlement(
     let width = foundation.foundation_width.unwrap();
     let length = foundation.foundation_length.unwrap();
 
-    let q_net = foundation_pressure - soil_profile.calc_normal_stress(df);
+    let q_net =
+        foundation.calc_net_pressure(soil_profile, foundation_pressure, pressure_convention);
     let df_index = soil_profile.get_layer_index(df);
 
+    let warnings = check_limits(
+        MethodId::Boussinesq,
+        &[
+            ("depth_ratio", df / width),
+            ("length_ratio", length / width),
+        ],
+    );
+
+    if q_net <= 0.0 {
+        return Ok(SettlementResult::compensated(
+            soil_profile.layers.len(),
+            q_net,
+            MethodId::Boussinesq,
+            warnings,
+            pressure_convention,
+        ));
+    }
+
     for i in 0..soil_profile.layers.len() {
         let layer = &soil_profile.layers[i];
         let h = layer.depth.unwrap() - df;
         let u = layer.poissons_ratio.unwrap();
-        let e = layer.elastic_modulus.unwrap();
+        let e = layer.get_elastic_modulus().unwrap();
 
         if i < df_index {
             settlements.push(0.0);
+        } else if layer.elastic_modulus_gradient.is_some_and(|k| k > 0.0) {
+            let layer_top = layer.depth.unwrap() - layer.thickness.unwrap();
+            settlements.push(calc_gibson_layer_settlement(
+                layer,
+                layer_top,
+                foundation,
+                q_net,
+                layer_settlement,
+            ));
         } else {
-            let settlement_all = single_layer_settlement(h, u, e, length, width, df, q_net);
+            let settlement_all = layer_settlement(h, u, e, length, width, df, q_net);
             if i == 0 {
                 settlements.push(settlement_all.max(0.));
             } else {
                 let h0 = soil_profile.layers[i - 1].depth.unwrap() - df;
-                let settlement_prevlayer =
-                    single_layer_settlement(h0, u, e, length, width, df, q_net);
+                let settlement_prevlayer = layer_settlement(h0, u, e, length, width, df, q_net);
                 settlements.push((settlement_all - settlement_prevlayer).max(0.));
             }
         }
@@ -151,5 +443,9 @@ pub fn calc_elastic_settlement(
         settlement_per_layer: settlements.clone(),
         total_settlement: settlements.iter().sum(),
         qnet: q_net,
+        method: MethodId::Boussinesq,
+        warnings,
+        fully_compensated: false,
+        pressure_convention,
     })
 }
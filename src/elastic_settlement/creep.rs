@@ -0,0 +1,127 @@
+use serde::Serialize;
+
+use crate::{
+    consolidation_settlement::model::SettlementResult,
+    validation::{validate_field, ValidationError},
+};
+
+/// Creep coefficient per log cycle of time of Schmertmann et al. (1978).
+pub const SCHMERTMANN_CREEP_COEFFICIENT: f64 = 0.2;
+
+/// Time after which creep starts (years).
+const REFERENCE_TIME: f64 = 0.1;
+
+/// Represents the time-dependent creep settlement of granular soil.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreepSettlement {
+    /// Time after loading (years).
+    pub time: f64,
+    /// Creep factor C2 = 1 + Ct · log10(t / 0.1).
+    pub creep_factor: f64,
+    /// Immediate settlement (cm).
+    pub immediate_settlement: f64,
+    /// Settlement added by creep (cm).
+    pub creep_settlement: f64,
+    /// Immediate plus creep settlement (cm).
+    pub total_settlement: f64,
+}
+
+pub fn validate_input(
+    immediate_settlement: f64,
+    time: f64,
+    creep_coefficient: f64,
+) -> Result<(), ValidationError> {
+    validate_field(
+        "immediate_settlement",
+        Some(immediate_settlement),
+        Some(0.0),
+        None,
+        "creep",
+    )?;
+    validate_field("time", Some(time), Some(0.0), None, "creep")?;
+    validate_field(
+        "creep_coefficient",
+        Some(creep_coefficient),
+        Some(0.0),
+        None,
+        "creep",
+    )?;
+    Ok(())
+}
+
+/// Calculates the creep factor C2 = 1 + Ct · log10(t / 0.1) (Schmertmann et al., 1978).
+///
+/// Creep is taken to start 0.1 year after loading, so C2 = 1 for earlier times.
+///
+/// # Arguments
+/// * `time` - Time after loading (years).
+/// * `creep_coefficient` - Creep coefficient Ct per log cycle of time (0.2 for Schmertmann).
+///
+/// # Returns
+/// * `f64` - Creep factor C2.
+pub fn calc_creep_factor(time: f64, creep_coefficient: f64) -> f64 {
+    if time <= REFERENCE_TIME {
+        return 1.0;
+    }
+    1.0 + creep_coefficient * (time / REFERENCE_TIME).log10()
+}
+
+/// Calculates the long-term settlement of granular soil from an immediate settlement.
+///
+/// The immediate settlement may come from any method; it is multiplied by the creep factor C2.
+///
+/// # Arguments
+/// * `immediate_settlement` - Immediate settlement (cm).
+/// * `time` - Time after loading (years).
+/// * `creep_coefficient` - Creep coefficient Ct per log cycle of time.
+///
+/// # Returns
+/// * `CreepSettlement` - The creep and total settlement.
+pub fn calc_creep_settlement(
+    immediate_settlement: f64,
+    time: f64,
+    creep_coefficient: f64,
+) -> Result<CreepSettlement, ValidationError> {
+    validate_input(immediate_settlement, time, creep_coefficient)?;
+
+    let creep_factor = calc_creep_factor(time, creep_coefficient);
+    let total_settlement = immediate_settlement * creep_factor;
+
+    Ok(CreepSettlement {
+        time,
+        creep_factor,
+        immediate_settlement,
+        creep_settlement: total_settlement - immediate_settlement,
+        total_settlement,
+    })
+}
+
+/// Applies creep to every layer of an immediate settlement result.
+///
+/// # Arguments
+/// * `result` - Immediate settlement result.
+/// * `time` - Time after loading (years).
+/// * `creep_coefficient` - Creep coefficient Ct per log cycle of time.
+///
+/// # Returns
+/// * `SettlementResult` - The long-term settlement result.
+pub fn apply_creep(
+    result: &SettlementResult,
+    time: f64,
+    creep_coefficient: f64,
+) -> Result<SettlementResult, ValidationError> {
+    validate_input(result.total_settlement.max(0.0), time, creep_coefficient)?;
+
+    let creep_factor = calc_creep_factor(time, creep_coefficient);
+    let settlement_per_layer: Vec<f64> = result
+        .settlement_per_layer
+        .iter()
+        .map(|settlement| settlement * creep_factor)
+        .collect();
+
+    Ok(SettlementResult {
+        total_settlement: settlement_per_layer.iter().sum(),
+        settlement_per_layer,
+        ..result.clone()
+    })
+}
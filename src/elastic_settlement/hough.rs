@@ -0,0 +1,286 @@
+use serde::Serialize;
+
+use crate::{
+    consolidation_settlement::helper_functions::calc_rectangle_stress,
+    diagnostics::{check_limits, Warning},
+    enums::{HoughSoilType, MethodId, PressureConvention},
+    helper::interp1d,
+    liquefaction::spt::seed_idriss::prepare_spt_exp,
+    models::{
+        foundation::Foundation,
+        soil_profile::{SoilLayer, SoilProfile},
+        spt::SPT,
+    },
+    validation::{validate_field, ValidationError},
+};
+
+use super::boussinesq::calc_equivalent_rectangle;
+
+/// N1,60 values of the digitized Hough (1959) chart.
+const CHART_N1_60: [f64; 7] = [0.0, 10.0, 20.0, 30.0, 40.0, 50.0, 60.0];
+
+/// Fines content (%) above which a non-plastic soil is taken as silt.
+const SILT_FINES_CONTENT: f64 = 50.0;
+
+/// Fines content (%) above which a sand is taken as silty sand.
+const SILTY_SAND_FINES_CONTENT: f64 = 12.0;
+
+/// Grain size (mm) separating sand from gravel.
+const GRAVEL_SIZE: f64 = 4.75;
+
+/// Grain size (mm) separating fine sand from medium sand.
+const MEDIUM_SAND_SIZE: f64 = 0.425;
+
+/// Settlement of a sub-layer by the Hough method.
+#[derive(Debug, Clone, Serialize)]
+pub struct HoughLayer {
+    /// Depth of the top of the sub-layer (m).
+    pub top: f64,
+    /// Depth of the bottom of the sub-layer (m).
+    pub bottom: f64,
+    /// Corrected blow count N1,60 of the sub-layer.
+    pub n1_60: f64,
+    /// Soil type of the bearing capacity index chart.
+    pub soil_type: HoughSoilType,
+    /// Bearing capacity index C'.
+    pub bearing_capacity_index: f64,
+    /// Initial effective stress at the sub-layer center (t/m²).
+    pub effective_stress: f64,
+    /// Stress increase at the sub-layer center (t/m²).
+    pub delta_stress: f64,
+    /// Settlement of the sub-layer (cm).
+    pub settlement: f64,
+}
+
+/// Represents the Hough settlement of a foundation on granular soil.
+#[derive(Debug, Clone, Serialize)]
+pub struct HoughResult {
+    /// Net foundation pressure (t/m²).
+    pub net_pressure: f64,
+    /// Granular sub-layers below the foundation base.
+    pub layers: Vec<HoughLayer>,
+    /// Total settlement (cm).
+    pub total_settlement: f64,
+    /// Method used for the analysis.
+    pub method: MethodId,
+    /// Warnings for inputs outside the calibration range of the method.
+    pub warnings: Vec<Warning>,
+    /// Indicates that the foundation pressure does not exceed the removed overburden (q_net ≤ 0),
+    /// so no settlement is produced by the foundation.
+    pub fully_compensated: bool,
+    /// Convention of the foundation pressure given to the method; `net_pressure` is always net.
+    pub pressure_convention: PressureConvention,
+}
+
+/// Validates the input data for Hough settlement calculations.
+///
+/// # Arguments
+/// * `spt` - The SPT data.
+/// * `soil_profile` - The soil profile data.
+/// * `foundation` - The foundation data.
+/// * `foundation_pressure` - The foundation pressure (t/m²).
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(
+    spt: &SPT,
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    foundation_pressure: f64,
+) -> Result<(), ValidationError> {
+    spt.validate(&["n", "depth"])?;
+    soil_profile.validate(&["thickness", "dry_unit_weight", "saturated_unit_weight"])?;
+    foundation.validate(&["foundation_depth", "foundation_width", "foundation_length"])?;
+    validate_field(
+        "foundation_pressure",
+        Some(foundation_pressure),
+        Some(0.0),
+        None,
+        "loads",
+    )?;
+    Ok(())
+}
+
+/// Returns the chart soil type of a granular layer.
+///
+/// Non-plastic layers with at least 50% fines are inorganic silt and layers with at least 12%
+/// fines are silty sand. Clean layers are split by their gradation curve into well-graded sand
+/// and gravel (D60 ≥ 4.75 mm), well-graded sand, uniform medium sand and uniform fine sand
+/// (D60 < 0.425 mm). Layers without fines content are taken as silty sand and clean layers
+/// without a gradation curve as uniform fine sand, the lower curves of the chart.
+///
+/// # Arguments
+/// * `layer` - The soil layer.
+pub fn classify_soil_type(layer: &SoilLayer) -> HoughSoilType {
+    let Some(fines_content) = layer.get_fine_content() else {
+        return HoughSoilType::SiltySand;
+    };
+    if fines_content >= SILT_FINES_CONTENT {
+        return HoughSoilType::InorganicSilt;
+    }
+    if fines_content >= SILTY_SAND_FINES_CONTENT {
+        return HoughSoilType::SiltySand;
+    }
+
+    let Some(gradation) = layer.gradation.as_ref() else {
+        return HoughSoilType::UniformFineSand;
+    };
+    let d60 = gradation.d60().unwrap_or(0.0);
+    match gradation.is_well_graded() {
+        Some(true) if d60 >= GRAVEL_SIZE => HoughSoilType::WellGradedSandAndGravel,
+        Some(true) => HoughSoilType::WellGradedSand,
+        _ if d60 >= MEDIUM_SAND_SIZE => HoughSoilType::UniformMediumSand,
+        _ => HoughSoilType::UniformFineSand,
+    }
+}
+
+/// Returns the bearing capacity index C' of the Hough chart (AASHTO LRFD Figure 10.6.2.4.2-1).
+///
+/// The curves are digitized at N1,60 = 0, 10, ..., 60 and interpolated linearly. Blow counts
+/// outside the chart are clamped to it.
+///
+/// # Arguments
+/// * `n1_60` - Corrected blow count N1,60.
+/// * `soil_type` - Soil type of the chart.
+pub fn calc_bearing_capacity_index(n1_60: f64, soil_type: HoughSoilType) -> f64 {
+    let curve: [f64; 7] = match soil_type {
+        HoughSoilType::WellGradedSandAndGravel => [45.0, 75.0, 110.0, 150.0, 190.0, 230.0, 270.0],
+        HoughSoilType::WellGradedSand => [35.0, 60.0, 90.0, 120.0, 155.0, 190.0, 225.0],
+        HoughSoilType::UniformMediumSand => [30.0, 50.0, 75.0, 100.0, 130.0, 160.0, 190.0],
+        HoughSoilType::UniformFineSand => [25.0, 42.0, 62.0, 85.0, 108.0, 132.0, 155.0],
+        HoughSoilType::SiltySand => [20.0, 35.0, 52.0, 70.0, 90.0, 110.0, 130.0],
+        HoughSoilType::InorganicSilt => [15.0, 27.0, 40.0, 55.0, 70.0, 85.0, 100.0],
+    };
+    let n1_60 = n1_60.clamp(CHART_N1_60[0], CHART_N1_60[CHART_N1_60.len() - 1]);
+
+    interp1d(&CHART_N1_60, &curve, n1_60)
+}
+
+/// Calculates the settlement of a single granular sub-layer by the Hough method.
+///
+/// ΔH = H · (1 / C') · log10((σ'0 + Δσ) / σ'0)
+///
+/// # Arguments
+/// * `thickness` - Thickness of the sub-layer H (m).
+/// * `bearing_capacity_index` - Bearing capacity index C'.
+/// * `effective_stress` - Initial effective stress at the sub-layer center σ'0 (t/m²).
+/// * `delta_stress` - Stress increase at the sub-layer center Δσ (t/m²).
+///
+/// # Returns
+/// * `f64`: Settlement (cm).
+pub fn calc_layer_settlement(
+    thickness: f64,
+    bearing_capacity_index: f64,
+    effective_stress: f64,
+    delta_stress: f64,
+) -> f64 {
+    if effective_stress <= 0.0 || delta_stress <= 0.0 {
+        return 0.0;
+    }
+    100.0 * thickness / bearing_capacity_index
+        * ((effective_stress + delta_stress) / effective_stress).log10()
+}
+
+/// Calculates the settlement of a foundation on granular soil by the Hough (1959) bearing
+/// capacity index method (AASHTO LRFD 10.6.2.4.2).
+///
+/// The SPT blows are corrected to N1,60 and each blow represents the interval from the previous
+/// blow to its own depth. The intervals below the foundation base are the sub-layers, whose
+/// stress increase is the Boussinesq stress below the foundation center. Plastic and rock layers
+/// are not granular and are skipped. Circular and ring footings are replaced by a square of
+/// equal area.
+///
+/// # Arguments
+/// * `spt` - The SPT data.
+/// * `soil_profile` - The soil profile data.
+/// * `foundation` - The foundation data.
+/// * `foundation_pressure` - The foundation pressure (q) (t/m²).
+/// * `pressure_convention` - Whether `foundation_pressure` is gross or net.
+///
+/// # Returns
+/// * `HoughResult` with the settlement of each sub-layer and the total settlement.
+pub fn calc_settlement(
+    spt: &SPT,
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    foundation_pressure: f64,
+    pressure_convention: PressureConvention,
+) -> Result<HoughResult, ValidationError> {
+    validate_input(spt, soil_profile, foundation, foundation_pressure)?;
+    let foundation = &calc_equivalent_rectangle(foundation)?;
+    let mut soil_profile = soil_profile.clone();
+    soil_profile.calc_layer_depths();
+
+    let spt_exp = prepare_spt_exp(&mut spt.clone(), &soil_profile);
+
+    let df = foundation.foundation_depth.unwrap();
+    let half_width = foundation.foundation_width.unwrap() / 2.0;
+    let half_length = foundation.foundation_length.unwrap() / 2.0;
+    let net_pressure = foundation
+        .calc_net_pressure(&soil_profile, foundation_pressure, pressure_convention)
+        .max(0.0);
+
+    let mut layers = vec![];
+    let mut max_n1_60 = f64::NEG_INFINITY;
+
+    for blow in &spt_exp.blows {
+        let bottom = blow.depth.unwrap();
+        let top = (bottom - blow.thickness.unwrap()).max(df);
+        if bottom <= top {
+            continue;
+        }
+
+        let center = (top + bottom) / 2.0;
+        let layer = soil_profile.get_layer_at_depth(center);
+        if layer.is_rock() || layer.plasticity_index.is_some_and(|pi| pi > 0.0) {
+            continue;
+        }
+
+        let n1_60 = blow.n1_60.unwrap().to_i32() as f64;
+        max_n1_60 = max_n1_60.max(n1_60);
+
+        let soil_type = classify_soil_type(layer);
+        let bearing_capacity_index = calc_bearing_capacity_index(n1_60, soil_type);
+        let effective_stress = soil_profile.calc_effective_stress(center);
+        let delta_stress = calc_rectangle_stress(
+            net_pressure,
+            -half_width,
+            half_width,
+            -half_length,
+            half_length,
+            center - df,
+        );
+
+        layers.push(HoughLayer {
+            top,
+            bottom,
+            n1_60,
+            soil_type,
+            bearing_capacity_index,
+            effective_stress,
+            delta_stress,
+            settlement: calc_layer_settlement(
+                bottom - top,
+                bearing_capacity_index,
+                effective_stress,
+                delta_stress,
+            ),
+        });
+    }
+
+    let warnings = if layers.is_empty() {
+        vec![]
+    } else {
+        check_limits(MethodId::Hough, &[("n1_60", max_n1_60)])
+    };
+
+    Ok(HoughResult {
+        net_pressure,
+        total_settlement: layers.iter().map(|l| l.settlement).sum(),
+        layers,
+        method: MethodId::Hough,
+        warnings,
+        fully_compensated: net_pressure <= 0.0,
+        pressure_convention,
+    })
+}
@@ -0,0 +1,211 @@
+use crate::{
+    consolidation_settlement::model::SettlementResult,
+    diagnostics::check_limits,
+    enums::{FoundationShape, MethodId, PressureConvention},
+    models::{foundation::Foundation, soil_profile::SoilProfile},
+    validation::{validate_field, ValidationError},
+};
+
+use super::{boussinesq::calc_equivalent_rectangle, reduction_factors::find_bounds};
+
+// Representation of the μ0 chart of Christian & Carrier (1978)
+// 1st dimension: Df/B [0,2,4,6,8,10,12,14,16,18,20]
+const DF_B_VALUES: [f64; 11] = [0.0, 2.0, 4.0, 6.0, 8.0, 10.0, 12.0, 14.0, 16.0, 18.0, 20.0];
+const MU0_VALUES: [f64; 11] = [
+    1.0, 0.9, 0.88, 0.875, 0.87, 0.865, 0.863, 0.86, 0.86, 0.857, 0.855,
+];
+
+// Representation of the μ1 chart of Christian & Carrier (1978)
+// 1st dimension: H/B [0,1,2,4,6,8,10,20,30]
+// 2nd dimension: B/L [0 (strip),0.1,0.2,0.5,1]
+const H_B_VALUES: [f64; 9] = [0.0, 1.0, 2.0, 4.0, 6.0, 8.0, 10.0, 20.0, 30.0];
+const B_L_VALUES: [f64; 5] = [0.0, 0.1, 0.2, 0.5, 1.0];
+const MU1_TABLE: [[f64; 5]; 9] = [
+    [0.0, 0.0, 0.0, 0.0, 0.0],
+    [0.36, 0.36, 0.36, 0.36, 0.36],
+    [0.64, 0.64, 0.64, 0.63, 0.53],
+    [0.94, 0.94, 0.94, 0.82, 0.63],
+    [1.16, 1.14, 1.08, 0.88, 0.67],
+    [1.26, 1.22, 1.13, 0.90, 0.68],
+    [1.42, 1.30, 1.18, 0.92, 0.70],
+    [1.74, 1.47, 1.26, 0.93, 0.71],
+    [1.84, 1.54, 1.29, 0.95, 0.73],
+];
+const MU1_CIRCLE: [f64; 9] = [0.0, 0.36, 0.47, 0.58, 0.61, 0.62, 0.63, 0.64, 0.66];
+
+/// Interpolates the depth factor μ0 for the given embedment ratio
+///
+/// # Arguments
+///
+/// * `df_b` - Df/B ratio
+///
+/// # Returns
+///
+/// The interpolated μ0 value
+pub fn interpolate_mu0(df_b: f64) -> f64 {
+    let df_b = df_b.clamp(0.0, 20.0);
+    let (i0, i1) = find_bounds(&DF_B_VALUES, df_b);
+    let t = (df_b - DF_B_VALUES[i0]) / (DF_B_VALUES[i1] - DF_B_VALUES[i0]);
+
+    MU0_VALUES[i0] * (1.0 - t) + MU0_VALUES[i1] * t
+}
+
+/// Interpolates the layer thickness factor μ1 for the given ratios
+///
+/// # Arguments
+///
+/// * `h_b` - H/B ratio, with H the thickness of the clay below the foundation base
+/// * `b_l` - B/L ratio, 0 for strip foundations
+/// * `circular` - Uses the chart of circular foundations (B is the diameter)
+///
+/// # Returns
+///
+/// The interpolated μ1 value
+pub fn interpolate_mu1(h_b: f64, b_l: f64, circular: bool) -> f64 {
+    let h_b = h_b.clamp(0.0, 30.0);
+    let (h_i0, h_i1) = find_bounds(&H_B_VALUES, h_b);
+    let ty = (h_b - H_B_VALUES[h_i0]) / (H_B_VALUES[h_i1] - H_B_VALUES[h_i0]);
+
+    // Linear interp function
+    let lerp = |x0: f64, x1: f64, t: f64| x0 * (1.0 - t) + x1 * t;
+
+    if circular {
+        return lerp(MU1_CIRCLE[h_i0], MU1_CIRCLE[h_i1], ty);
+    }
+
+    let b_l = b_l.clamp(0.0, 1.0);
+    let (b_i0, b_i1) = find_bounds(&B_L_VALUES, b_l);
+    let tx = (b_l - B_L_VALUES[b_i0]) / (B_L_VALUES[b_i1] - B_L_VALUES[b_i0]);
+
+    let c0 = lerp(MU1_TABLE[h_i0][b_i0], MU1_TABLE[h_i0][b_i1], tx);
+    let c1 = lerp(MU1_TABLE[h_i1][b_i0], MU1_TABLE[h_i1][b_i1], tx);
+
+    lerp(c0, c1, ty)
+}
+
+/// Validates the input data for the Janbu immediate settlement.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `foundation` - The foundation data.
+/// * `foundation_pressure` - The foundation pressure (q) [t/m²].
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    foundation_pressure: f64,
+) -> Result<(), ValidationError> {
+    soil_profile.validate(&["thickness", "dry_unit_weight", "saturated_unit_weight"])?;
+    for layer in soil_profile.layers.iter() {
+        if layer.is_rock() && layer.rock_mass_modulus.is_some() {
+            layer.validate_fields(&["rock_mass_modulus"])?;
+        } else {
+            layer.validate_fields(&["elastic_modulus"])?;
+        }
+    }
+    foundation.validate(&["foundation_depth", "foundation_width", "foundation_length"])?;
+
+    validate_field(
+        "foundation_pressure",
+        Some(foundation_pressure),
+        Some(0.0),
+        None,
+        "loads",
+    )?;
+
+    Ok(())
+}
+
+/// Calculates the immediate (undrained) settlement of a foundation on saturated clay by the
+/// method of Janbu et al. (1956) as revised by Christian & Carrier (1978).
+///
+/// Si = μ0 · μ1 · qNet · B / Eu
+///
+/// Layers with different undrained moduli are superposed: each layer settles by
+/// μ0 · qNet · B · (μ1(H_bottom / B) − μ1(H_top / B)) / Eu, with H measured from the foundation
+/// base. Circular footings use the chart of circles with B as the diameter; other shapes use
+/// their equivalent rectangle.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile with the undrained elastic moduli of the layers.
+/// * `foundation` - The foundation parameters.
+/// * `foundation_pressure` - The foundation pressure (q) [t/m²].
+/// * `pressure_convention` - Whether `foundation_pressure` is gross or net.
+///
+/// # Returns
+/// * `SettlementResult` with the settlement of each layer [cm].
+pub fn calc_settlement(
+    soil_profile: &mut SoilProfile,
+    foundation: &Foundation,
+    foundation_pressure: f64,
+    pressure_convention: PressureConvention,
+) -> Result<SettlementResult, ValidationError> {
+    let foundation = &calc_equivalent_rectangle(foundation)?;
+    validate_input(soil_profile, foundation, foundation_pressure)?;
+    soil_profile.calc_layer_depths();
+
+    let circular = matches!(foundation.shape, FoundationShape::Circular { .. });
+    let df = foundation.foundation_depth.unwrap();
+    let (width, length) = match foundation.shape {
+        FoundationShape::Circular { diameter } => (diameter, diameter),
+        _ => (
+            foundation.foundation_width.unwrap(),
+            foundation.foundation_length.unwrap(),
+        ),
+    };
+
+    let q_net =
+        foundation.calc_net_pressure(soil_profile, foundation_pressure, pressure_convention);
+    let total_thickness = soil_profile.get_total_depth() - df;
+
+    let warnings = check_limits(
+        MethodId::Janbu,
+        &[
+            ("depth_ratio", df / width),
+            ("thickness_ratio", total_thickness / width),
+        ],
+    );
+
+    if q_net <= 0.0 {
+        return Ok(SettlementResult::compensated(
+            soil_profile.layers.len(),
+            q_net,
+            MethodId::Janbu,
+            warnings,
+            pressure_convention,
+        ));
+    }
+
+    let mu0 = interpolate_mu0(df / width);
+    let b_l = width / length;
+
+    let settlements: Vec<f64> = soil_profile
+        .layers
+        .iter()
+        .map(|layer| {
+            let bottom = layer.depth.unwrap();
+            let top = bottom - layer.thickness.unwrap();
+            if bottom <= df {
+                return 0.0;
+            }
+            let h_top = (top - df).max(0.0);
+            let h_bottom = bottom - df;
+            let mu1 = interpolate_mu1(h_bottom / width, b_l, circular)
+                - interpolate_mu1(h_top / width, b_l, circular);
+
+            100.0 * mu0 * mu1 * q_net * width / layer.get_elastic_modulus().unwrap()
+        })
+        .collect();
+
+    Ok(SettlementResult {
+        total_settlement: settlements.iter().sum(),
+        settlement_per_layer: settlements,
+        qnet: q_net,
+        method: MethodId::Janbu,
+        warnings,
+        fully_compensated: false,
+        pressure_convention,
+    })
+}
@@ -0,0 +1,159 @@
+use serde::Serialize;
+
+use crate::{
+    bearing_capacity::spt::{calc_allowable_pressure, calc_average_n60, calc_depth_factor},
+    diagnostics::{check_limits, Warning},
+    enums::{MethodId, PressureConvention},
+    models::{
+        averaging_window::AveragingWindow, foundation::Foundation, soil_profile::SoilProfile,
+        spt::SPT,
+    },
+    validation::{validate_field, ValidationError},
+};
+
+/// Represents the immediate settlement of a footing on sand from SPT results.
+#[derive(Debug, Serialize)]
+pub struct Output {
+    /// Average N60 value within the averaging window.
+    pub n60: f64,
+
+    /// Depth window over which N60 is averaged.
+    pub averaging_window: AveragingWindow,
+
+    /// Depth factor Kd = 1 + 0.33 · Df / B ≤ 1.33.
+    pub depth_factor: f64,
+
+    /// Net foundation pressure in ton/m2.
+    pub net_pressure: f64,
+
+    /// Immediate settlement in cm.
+    pub settlement: f64,
+
+    /// Method used for the analysis.
+    pub method: MethodId,
+
+    /// Warnings for inputs outside the calibration range of the method.
+    pub warnings: Vec<Warning>,
+
+    /// Indicates that the foundation pressure does not exceed the removed overburden (q_net ≤ 0),
+    /// so no settlement is produced by the foundation.
+    pub fully_compensated: bool,
+
+    /// Convention of the foundation pressure given to the method; `net_pressure` is always net.
+    pub pressure_convention: PressureConvention,
+}
+
+pub fn validate_input(
+    spt: &SPT,
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    foundation_pressure: f64,
+) -> Result<(), ValidationError> {
+    spt.validate(&["n", "depth"])?;
+    soil_profile.validate(&["thickness", "dry_unit_weight", "saturated_unit_weight"])?;
+    foundation.validate(&["foundation_depth", "foundation_width"])?;
+    validate_field(
+        "foundation_pressure",
+        Some(foundation_pressure),
+        Some(0.0),
+        None,
+        "loads",
+    )?;
+    Ok(())
+}
+
+/// Calculates the immediate settlement of a footing on sand from SPT results (Meyerhof, 1965).
+///
+/// N60 is averaged between the foundation base and a depth B below it. The Meyerhof relations of
+/// `bearing_capacity::spt::calc_allowable_pressure` are inverted for the settlement:
+///
+/// * B ≤ 1.22 m: S = 25 · q / (19.16 · N60 · Kd) mm
+/// * B > 1.22 m: S = 25 · q / (11.98 · N60 · ((3.28B + 1) / 3.28B)² · Kd) mm
+///
+/// with q the net foundation pressure in kPa, so no elastic modulus is needed.
+///
+/// # Arguments
+/// * `spt` - The SPT data.
+/// * `soil_profile` - The soil profile, for the net foundation pressure.
+/// * `foundation` - The foundation data.
+/// * `foundation_pressure` - The foundation pressure (q) (t/m²).
+/// * `pressure_convention` - Whether `foundation_pressure` is gross or net.
+///
+/// # Returns
+/// * `Output` - The settlement result.
+pub fn calc_settlement(
+    spt: &SPT,
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    foundation_pressure: f64,
+    pressure_convention: PressureConvention,
+) -> Result<Output, ValidationError> {
+    calc_settlement_with_window(
+        spt,
+        soil_profile,
+        foundation,
+        foundation_pressure,
+        pressure_convention,
+        AveragingWindow::shallow_foundation(),
+    )
+}
+
+/// Calculates the immediate settlement of a footing on sand from SPT results, averaging N60 over
+/// a configurable window tied to the foundation width.
+///
+/// # Arguments
+/// * `spt` - The SPT data.
+/// * `soil_profile` - The soil profile, for the net foundation pressure.
+/// * `foundation` - The foundation data.
+/// * `foundation_pressure` - The foundation pressure (q) (t/m²).
+/// * `pressure_convention` - Whether `foundation_pressure` is gross or net.
+/// * `averaging_window` - Depth window around the foundation base over which N60 is averaged.
+///
+/// # Returns
+/// * `Output` - The settlement result.
+pub fn calc_settlement_with_window(
+    spt: &SPT,
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    foundation_pressure: f64,
+    pressure_convention: PressureConvention,
+    averaging_window: AveragingWindow,
+) -> Result<Output, ValidationError> {
+    validate_input(spt, soil_profile, foundation, foundation_pressure)?;
+    averaging_window.validate()?;
+
+    let mut spt_exp = spt.get_idealized_exp("idealized".to_string());
+    spt_exp.apply_energy_correction(spt.energy_correction_factor.unwrap());
+
+    let df = foundation.foundation_depth.unwrap();
+    let width = foundation.foundation_width.unwrap();
+
+    let (top, bottom) = averaging_window.calc_bounds(df, width);
+    let n60 = calc_average_n60(&spt_exp, top, bottom);
+    let depth_factor = calc_depth_factor(foundation);
+    let net_pressure = foundation
+        .calc_net_pressure(soil_profile, foundation_pressure, pressure_convention)
+        .max(0.0);
+
+    // Pressure giving 1 cm of settlement, the settlement being proportional to the pressure.
+    let unit_pressure = calc_allowable_pressure(n60, width, depth_factor, 1.0);
+    let settlement = if net_pressure == 0.0 {
+        0.0
+    } else if unit_pressure > 0.0 {
+        net_pressure / unit_pressure
+    } else {
+        f64::INFINITY
+    };
+
+    Ok(Output {
+        n60,
+        averaging_window,
+        depth_factor,
+        net_pressure,
+        settlement,
+        method: MethodId::MeyerhofSpt,
+        warnings: check_limits(MethodId::MeyerhofSpt, &[("n60", n60)]),
+        fully_compensated: net_pressure <= 0.0,
+        pressure_convention,
+    })
+}
@@ -1,2 +1,8 @@
 pub mod boussinesq;
+pub mod creep;
+pub mod hough;
+pub mod janbu;
+pub mod meyerhof_spt;
 pub mod reduction_factors;
+pub mod schmertmann;
+pub mod stress_bulb;
@@ -66,7 +66,7 @@ const NU_VALUES: [f64; 5] = [0.0, 0.1, 0.3, 0.4, 0.5];
 const D_B_VALUES: [f64; 8] = [0.05, 0.1, 0.2, 0.4, 0.6, 0.8, 1.0, 2.0];
 const L_B_VALUES: [f64; 7] = [1.0, 1.2, 1.4, 1.6, 1.8, 2.0, 5.0];
 
-fn find_bounds(values: &[f64], target: f64) -> (usize, usize) {
+pub(crate) fn find_bounds(values: &[f64], target: f64) -> (usize, usize) {
     for i in 0..values.len() - 1 {
         if target >= values[i] && target <= values[i + 1] {
             return (i, i + 1);
@@ -0,0 +1,344 @@
+use serde::Serialize;
+
+use crate::{
+    constants::MPA_TO_TON,
+    enums::{PressureConvention, SoilType},
+    models::{
+        cpt::{CPTExp, CPTLayer},
+        foundation::Foundation,
+        soil_profile::SoilProfile,
+    },
+    validation::{validate_field, ValidationError},
+};
+
+use super::{
+    boussinesq::calc_equivalent_rectangle,
+    creep::{calc_creep_factor, SCHMERTMANN_CREEP_COEFFICIENT},
+};
+
+/// Friction ratio (%) above which a CPT reading is taken as cohesive soil.
+const COHESIVE_FRICTION_RATIO: f64 = 2.0;
+
+/// E / qc of granular soil under axisymmetric (square and circular) footings.
+const GRANULAR_AXISYMMETRIC_MODULUS_RATIO: f64 = 2.5;
+
+/// E / qc of granular soil under plane strain (strip) footings.
+const GRANULAR_PLANE_STRAIN_MODULUS_RATIO: f64 = 3.5;
+
+/// E / qc of cohesive soil, the middle of the 3 to 8 range of Bowles (1996).
+const COHESIVE_MODULUS_RATIO: f64 = 5.0;
+
+/// Strain influence diagram of Schmertmann et al. (1978).
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct StrainInfluenceDiagram {
+    /// Influence factor at the foundation base.
+    pub base_influence_factor: f64,
+    /// Peak influence factor Izp.
+    pub peak_influence_factor: f64,
+    /// Depth of the peak below the foundation base (m).
+    pub peak_depth: f64,
+    /// Depth below the foundation base where the influence factor vanishes (m).
+    pub influence_depth: f64,
+}
+
+impl StrainInfluenceDiagram {
+    /// Returns the strain influence factor Iz at a depth below the foundation base.
+    ///
+    /// # Arguments
+    /// * `z` - Depth below the foundation base (m).
+    pub fn calc_influence_factor(&self, z: f64) -> f64 {
+        if z < 0.0 || z >= self.influence_depth {
+            return 0.0;
+        }
+        if z <= self.peak_depth {
+            self.base_influence_factor
+                + (self.peak_influence_factor - self.base_influence_factor) * z / self.peak_depth
+        } else {
+            self.peak_influence_factor * (self.influence_depth - z)
+                / (self.influence_depth - self.peak_depth)
+        }
+    }
+}
+
+/// Settlement of a sub-layer below the foundation.
+#[derive(Debug, Clone, Serialize)]
+pub struct SchmertmannSublayer {
+    /// Depth of the top of the sub-layer (m).
+    pub top: f64,
+    /// Depth of the bottom of the sub-layer (m).
+    pub bottom: f64,
+    /// Cone resistance qc of the sub-layer (MPa).
+    pub cone_resistance: f64,
+    /// Soil type inferred from the friction ratio.
+    pub soil_type: SoilType,
+    /// Elastic modulus from the cone resistance (t/m²).
+    pub elastic_modulus: f64,
+    /// Strain influence factor at the sub-layer center.
+    pub influence_factor: f64,
+    /// Settlement of the sub-layer (cm).
+    pub settlement: f64,
+}
+
+/// Represents the Schmertmann settlement of a foundation.
+#[derive(Debug, Clone, Serialize)]
+pub struct SchmertmannResult {
+    /// Net foundation pressure Δp (t/m²).
+    pub net_pressure: f64,
+    /// Depth correction factor C1.
+    pub depth_factor: f64,
+    /// Creep correction factor C2.
+    pub creep_factor: f64,
+    /// Strain influence diagram of the foundation.
+    pub diagram: StrainInfluenceDiagram,
+    /// Sub-layers within the influence depth.
+    pub sublayers: Vec<SchmertmannSublayer>,
+    /// Total settlement (cm).
+    pub total_settlement: f64,
+    /// Indicates that the foundation pressure does not exceed the removed overburden (q_net ≤ 0),
+    /// so no settlement is produced by the foundation.
+    pub fully_compensated: bool,
+    /// Convention of the foundation pressure given to the method; `net_pressure` is always net.
+    pub pressure_convention: PressureConvention,
+}
+
+/// Validates the input data for Schmertmann settlement calculations.
+///
+/// # Arguments
+/// * `cpt` - The CPT sounding.
+/// * `soil_profile` - The soil profile data.
+/// * `foundation` - The foundation data.
+/// * `foundation_pressure` - The foundation pressure (t/m²).
+/// * `time` - Time after loading (years).
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(
+    cpt: &CPTExp,
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    foundation_pressure: f64,
+    time: f64,
+) -> Result<(), ValidationError> {
+    cpt.validate(&["depth", "cone_resistance", "sleeve_friction"])?;
+    soil_profile.validate(&["thickness", "dry_unit_weight", "saturated_unit_weight"])?;
+    foundation.validate(&["foundation_depth", "foundation_width", "foundation_length"])?;
+    validate_field(
+        "foundation_pressure",
+        Some(foundation_pressure),
+        Some(0.0),
+        None,
+        "loads",
+    )?;
+    validate_field("time", Some(time), Some(0.0), None, "schmertmann")?;
+
+    Ok(())
+}
+
+/// Infers the soil type of a CPT reading from its friction ratio Rf = fs / qc · 100.
+///
+/// # Arguments
+/// * `layer` - The CPT reading.
+///
+/// # Returns
+/// * `SoilType`: Cohesive when Rf ≥ 2 %, granular otherwise.
+pub fn classify_soil_type(layer: &CPTLayer) -> SoilType {
+    let qc = layer.cone_resistance.unwrap();
+    let friction_ratio = layer.friction_ratio.unwrap_or(if qc > 0.0 {
+        layer.sleeve_friction.unwrap() / qc * 100.0
+    } else {
+        0.0
+    });
+
+    if friction_ratio >= COHESIVE_FRICTION_RATIO {
+        SoilType::Cohesive
+    } else {
+        SoilType::Granular
+    }
+}
+
+/// Returns the share of plane strain behaviour of a footing, 0 for L / B = 1 and 1 for
+/// L / B ≥ 10.
+fn calc_plane_strain_share(width: f64, length: f64) -> f64 {
+    ((length / width - 1.0) / 9.0).clamp(0.0, 1.0)
+}
+
+/// Calculates the elastic modulus from the cone resistance.
+///
+/// Granular soil uses E = 2.5 · qc under axisymmetric and E = 3.5 · qc under plane strain
+/// footings (Schmertmann et al., 1978), interpolated linearly in L / B. Cohesive soil uses
+/// E = 5 · qc.
+///
+/// # Arguments
+/// * `cone_resistance` - Cone resistance qc (MPa).
+/// * `soil_type` - Soil type of the reading.
+/// * `width` - Foundation width B (m).
+/// * `length` - Foundation length L (m).
+///
+/// # Returns
+/// * `f64`: Elastic modulus (t/m²).
+pub fn calc_elastic_modulus(
+    cone_resistance: f64,
+    soil_type: SoilType,
+    width: f64,
+    length: f64,
+) -> f64 {
+    let ratio = match soil_type {
+        SoilType::Cohesive => COHESIVE_MODULUS_RATIO,
+        _ => {
+            let share = calc_plane_strain_share(width, length);
+            GRANULAR_AXISYMMETRIC_MODULUS_RATIO
+                + share
+                    * (GRANULAR_PLANE_STRAIN_MODULUS_RATIO - GRANULAR_AXISYMMETRIC_MODULUS_RATIO)
+        }
+    };
+    ratio * cone_resistance * MPA_TO_TON
+}
+
+/// Builds the strain influence diagram of a footing (Schmertmann et al., 1978).
+///
+/// For L / B = 1 the factor grows from 0.1 at the base to Izp at B / 2 and vanishes at 2B. For
+/// L / B ≥ 10 it grows from 0.2 to Izp at B and vanishes at 4B. Intermediate shapes are
+/// interpolated linearly. The peak is Izp = 0.5 + 0.1 · √(Δp / σ'vp), with σ'vp the effective
+/// stress at the peak depth.
+///
+/// # Arguments
+/// * `width` - Foundation width B (m).
+/// * `length` - Foundation length L (m).
+/// * `net_pressure` - Net foundation pressure Δp (t/m²).
+/// * `peak_stress` - Function returning σ'vp for the peak depth below the base (t/m²).
+pub fn calc_strain_influence_diagram(
+    width: f64,
+    length: f64,
+    net_pressure: f64,
+    peak_stress: impl Fn(f64) -> f64,
+) -> StrainInfluenceDiagram {
+    let share = calc_plane_strain_share(width, length);
+    let peak_depth = width * (0.5 + 0.5 * share);
+    let sigma_vp = peak_stress(peak_depth);
+    let peak_influence_factor = if sigma_vp > 0.0 {
+        0.5 + 0.1 * (net_pressure.max(0.0) / sigma_vp).sqrt()
+    } else {
+        0.5
+    };
+
+    StrainInfluenceDiagram {
+        base_influence_factor: 0.1 + 0.1 * share,
+        peak_influence_factor,
+        peak_depth,
+        influence_depth: width * (2.0 + 2.0 * share),
+    }
+}
+
+/// Calculates the depth correction factor C1 = 1 − 0.5 · σ'v0 / Δp ≥ 0.5.
+///
+/// # Arguments
+/// * `effective_stress` - Effective overburden stress at the foundation base σ'v0 (t/m²).
+/// * `net_pressure` - Net foundation pressure Δp (t/m²).
+pub fn calc_depth_factor(effective_stress: f64, net_pressure: f64) -> f64 {
+    if net_pressure <= 0.0 {
+        return 1.0;
+    }
+    (1.0 - 0.5 * effective_stress / net_pressure).max(0.5)
+}
+
+/// Calculates the settlement of a shallow foundation from a CPT sounding by the strain influence
+/// factor method (Schmertmann et al., 1978).
+///
+/// S = C1 · C2 · Δp · Σ (Iz / E) · Δz
+///
+/// Each CPT reading represents the interval from the previous reading to its own depth, and the
+/// deepest reading continues to the bottom of the influence zone. The intervals are clipped to
+/// the influence zone and Iz is taken at their centers. Circular and ring footings are replaced
+/// by a square of equal area.
+///
+/// # Arguments
+/// * `cpt` - The CPT sounding, with readings ordered by depth.
+/// * `soil_profile` - The soil profile, for the overburden stresses.
+/// * `foundation` - The foundation data.
+/// * `foundation_pressure` - The foundation pressure (q) (t/m²).
+/// * `pressure_convention` - Whether `foundation_pressure` is gross or net.
+/// * `time` - Time after loading for the creep factor (years).
+///
+/// # Returns
+/// * `SchmertmannResult` with the settlement of each sub-layer and the total settlement.
+pub fn calc_settlement(
+    cpt: &CPTExp,
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    foundation_pressure: f64,
+    pressure_convention: PressureConvention,
+    time: f64,
+) -> Result<SchmertmannResult, ValidationError> {
+    validate_input(cpt, soil_profile, foundation, foundation_pressure, time)?;
+    let foundation = &calc_equivalent_rectangle(foundation)?;
+
+    let df = foundation.foundation_depth.unwrap();
+    let width = foundation.foundation_width.unwrap();
+    let length = foundation.foundation_length.unwrap();
+
+    let net_pressure =
+        foundation.calc_net_pressure(soil_profile, foundation_pressure, pressure_convention);
+    let diagram = calc_strain_influence_diagram(width, length, net_pressure, |z| {
+        soil_profile.calc_effective_stress(df + z)
+    });
+    let depth_factor = calc_depth_factor(soil_profile.calc_effective_stress(df), net_pressure);
+    let creep_factor = calc_creep_factor(time, SCHMERTMANN_CREEP_COEFFICIENT);
+
+    let zone_bottom = df + diagram.influence_depth;
+    let mut sublayers = vec![];
+    let mut previous_depth: f64 = 0.0;
+
+    for (i, layer) in cpt.layers.iter().enumerate() {
+        let depth = layer.depth.unwrap();
+        let top = previous_depth.max(df);
+        let bottom = if i == cpt.layers.len() - 1 {
+            zone_bottom
+        } else {
+            depth.min(zone_bottom)
+        };
+        previous_depth = depth;
+        if bottom <= top {
+            continue;
+        }
+
+        let cone_resistance = layer.cone_resistance.unwrap();
+        validate_field(
+            "cone_resistance",
+            Some(cone_resistance),
+            Some(0.001),
+            None,
+            "schmertmann",
+        )?;
+        let soil_type = classify_soil_type(layer);
+        let elastic_modulus = calc_elastic_modulus(cone_resistance, soil_type, width, length);
+        let influence_factor = diagram.calc_influence_factor(0.5 * (top + bottom) - df);
+        let settlement = 100.0
+            * depth_factor
+            * creep_factor
+            * net_pressure.max(0.0)
+            * influence_factor
+            * (bottom - top)
+            / elastic_modulus;
+
+        sublayers.push(SchmertmannSublayer {
+            top,
+            bottom,
+            cone_resistance,
+            soil_type,
+            elastic_modulus,
+            influence_factor,
+            settlement,
+        });
+    }
+
+    Ok(SchmertmannResult {
+        net_pressure,
+        depth_factor,
+        creep_factor,
+        diagram,
+        total_settlement: sublayers.iter().map(|s| s.settlement).sum(),
+        sublayers,
+        fully_compensated: net_pressure <= 0.0,
+        pressure_convention,
+    })
+}
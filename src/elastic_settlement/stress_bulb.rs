@@ -0,0 +1,147 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    consolidation_settlement::helper_functions::calc_rectangle_stress,
+    models::foundation::Foundation,
+    validation::{validate_field, ValidationError},
+};
+
+use super::boussinesq::calc_equivalent_rectangle;
+
+/// Vertical stress increase on a vertical section through the foundation center.
+///
+/// The grid is stored row by row: `delta_stress[i][j]` is the stress at depth `depths[i]` and
+/// horizontal offset `offsets[j]`, which can be passed directly to contouring tools.
+///
+/// # Fields
+/// * `offsets` - Horizontal offsets from the foundation center along the width (m).
+/// * `depths` - Depths below the foundation base (m).
+/// * `delta_stress` - Vertical stress increase Δσ (t/m²).
+/// * `influence_factors` - Δσ / q (unitless).
+/// * `net_pressure` - Net foundation pressure q (t/m²).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StressBulb {
+    pub offsets: Vec<f64>,
+    pub depths: Vec<f64>,
+    pub delta_stress: Vec<Vec<f64>>,
+    pub influence_factors: Vec<Vec<f64>>,
+    pub net_pressure: f64,
+}
+
+/// Validates the input data for the stress bulb calculation.
+///
+/// # Arguments
+/// * `foundation` - The foundation data.
+/// * `net_pressure` - Net foundation pressure (q) [t/m²].
+/// * `horizontal_extent` - Horizontal distance from the center covered by the grid [m].
+/// * `max_depth` - Depth below the foundation base covered by the grid [m].
+/// * `spacing` - Grid spacing [m].
+pub fn validate_input(
+    foundation: &Foundation,
+    net_pressure: f64,
+    horizontal_extent: f64,
+    max_depth: f64,
+    spacing: f64,
+) -> Result<(), ValidationError> {
+    foundation.validate(&["foundation_width", "foundation_length"])?;
+    validate_field(
+        "net_pressure",
+        Some(net_pressure),
+        Some(0.0),
+        None,
+        "stress_bulb",
+    )?;
+    validate_field(
+        "horizontal_extent",
+        Some(horizontal_extent),
+        Some(0.0),
+        None,
+        "stress_bulb",
+    )?;
+    validate_field(
+        "max_depth",
+        Some(max_depth),
+        Some(0.0001),
+        None,
+        "stress_bulb",
+    )?;
+    validate_field("spacing", Some(spacing), Some(0.0001), None, "stress_bulb")?;
+    Ok(())
+}
+
+/// Generates grid coordinates from `start` to `end` (inclusive) at `spacing` intervals.
+fn grid_points(start: f64, end: f64, spacing: f64) -> Vec<f64> {
+    let count = ((end - start) / spacing + 1e-9).floor() as usize;
+    (0..=count).map(|i| start + i as f64 * spacing).collect()
+}
+
+/// Calculates the Boussinesq vertical stress increase on a grid beneath and around the
+/// foundation, for plotting the stress bulb and checking the influence on adjacent structures.
+///
+/// The section passes through the foundation center along its width. The stress at each point is
+/// obtained by superposing the corner influence factors of the loaded rectangle (Newmark, 1935).
+/// Circular and ring foundations are replaced by the square of equal area.
+///
+/// # Arguments
+/// * `foundation` - The foundation data.
+/// * `net_pressure` - Net foundation pressure (q) [t/m²].
+/// * `horizontal_extent` - Horizontal distance from the center covered by the grid [m].
+/// * `max_depth` - Depth below the foundation base covered by the grid [m].
+/// * `spacing` - Grid spacing [m].
+///
+/// # Returns
+/// * `StressBulb` with the grid coordinates and stresses.
+pub fn calc_stress_bulb(
+    foundation: &Foundation,
+    net_pressure: f64,
+    horizontal_extent: f64,
+    max_depth: f64,
+    spacing: f64,
+) -> Result<StressBulb, ValidationError> {
+    let foundation = &calc_equivalent_rectangle(foundation)?;
+    validate_input(
+        foundation,
+        net_pressure,
+        horizontal_extent,
+        max_depth,
+        spacing,
+    )?;
+
+    let half_width = foundation.foundation_width.unwrap() / 2.0;
+    let half_length = foundation.foundation_length.unwrap() / 2.0;
+
+    let offsets = grid_points(-horizontal_extent, horizontal_extent, spacing);
+    let depths = grid_points(0.0, max_depth, spacing);
+
+    let influence_factors: Vec<Vec<f64>> = depths
+        .iter()
+        .map(|&z| {
+            offsets
+                .iter()
+                .map(|&x| {
+                    calc_rectangle_stress(
+                        1.0,
+                        -half_width - x,
+                        half_width - x,
+                        -half_length,
+                        half_length,
+                        z,
+                    )
+                })
+                .collect()
+        })
+        .collect();
+
+    let delta_stress = influence_factors
+        .iter()
+        .map(|row| row.iter().map(|i| i * net_pressure).collect())
+        .collect();
+
+    Ok(StressBulb {
+        offsets,
+        depths,
+        delta_stress,
+        influence_factors,
+        net_pressure,
+    })
+}
@@ -18,7 +18,7 @@ pub enum SelectionMethod {
 /// * `G` - Dead load
 /// * `Q` - Live load
 /// * `E` - Earthquake load
-#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
 pub enum LoadCase {
     ServiceLoad,
     UltimateLoad,
@@ -30,8 +30,755 @@ pub enum LoadCase {
 /// # Variants
 /// * `Short` - Short term analysis
 /// * `Long` - Long term analysis
+/// * `Auto` - Short or long term selected for each layer from its soil type and the loading rate
 #[derive(Debug, Clone, Copy, Deserialize, Serialize)]
 pub enum AnalysisTerm {
     Short,
     Long,
+    Auto(LoadingRate),
+}
+
+/// Loading rate used for the automatic selection of the analysis term
+///
+/// # Variants
+/// * `Rapid` - Loading faster than consolidation (undrained behaviour in cohesive soils)
+/// * `Slow` - Loading slow enough for drainage in all soils
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub enum LoadingRate {
+    Rapid,
+    Slow,
+}
+
+/// Ground type around the bonded (fixed) length of a ground anchor.
+///
+/// Ultimate bond stress ranges are taken from FHWA-IF-99-015 (Sabatini et al., 1999).
+///
+/// # Variants
+/// * `SiltClayMixture` - Stiff to very stiff silt-clay mixture (30–70 kPa)
+/// * `StiffHighPlasticityClay` - Stiff to very stiff high plasticity clay (30–100 kPa)
+/// * `StiffMediumPlasticityClay` - Stiff to very stiff medium plasticity clay (100–250 kPa)
+/// * `VeryStiffSandySilt` - Very stiff medium plasticity sandy silt (280–380 kPa)
+/// * `FineMediumSand` - Medium dense to dense fine to medium sand (80–380 kPa)
+/// * `MediumCoarseSandWithGravel` - Medium dense to very dense medium to coarse sand with gravel (110–670 kPa)
+/// * `SiltySand` - Silty sand (170–400 kPa)
+/// * `SandyGravel` - Medium dense to dense sandy gravel (215–950 kPa)
+/// * `GlacialTill` - Dense glacial till (300–520 kPa)
+/// * `SoftShale` - Soft shale (200–800 kPa)
+/// * `Sandstone` - Sandstone (800–1700 kPa)
+/// * `SoftLimestone` - Soft limestone (1000–1400 kPa)
+/// * `GraniteBasalt` - Granite and basalt (1700–3100 kPa)
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub enum AnchorGroundType {
+    SiltClayMixture,
+    StiffHighPlasticityClay,
+    StiffMediumPlasticityClay,
+    VeryStiffSandySilt,
+    FineMediumSand,
+    MediumCoarseSandWithGravel,
+    SiltySand,
+    SandyGravel,
+    GlacialTill,
+    SoftShale,
+    Sandstone,
+    SoftLimestone,
+    GraniteBasalt,
+}
+
+/// Grouting method of a ground anchor.
+///
+/// # Variants
+/// * `Gravity` - Tremie (gravity) grouted, lower bound of the bond stress range
+/// * `LowPressure` - Low pressure grouted, middle of the bond stress range
+/// * `HighPressure` - High pressure (post) grouted, upper bound of the bond stress range
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub enum GroutingMethod {
+    Gravity,
+    LowPressure,
+    HighPressure,
+}
+
+/// Marginal probability distribution of a random variable.
+///
+/// # Variants
+/// * `Normal` - Normal distribution
+/// * `Lognormal` - Lognormal distribution (strictly positive values)
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub enum Distribution {
+    Normal,
+    Lognormal,
+}
+
+/// Material type of a layer
+///
+/// # Variants
+/// * `Soil` - Soil layer
+/// * `Rock` - Rock layer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum MaterialType {
+    Soil,
+    Rock,
+}
+
+/// Weathering grade of a rock mass (ISRM, 1981)
+///
+/// # Variants
+/// * `Fresh` - Grade I, no visible sign of weathering
+/// * `SlightlyWeathered` - Grade II, discoloration on discontinuity surfaces
+/// * `ModeratelyWeathered` - Grade III, less than half of the rock decomposed to soil
+/// * `HighlyWeathered` - Grade IV, more than half of the rock decomposed to soil
+/// * `CompletelyWeathered` - Grade V, all rock decomposed to soil, original structure intact
+/// * `ResidualSoil` - Grade VI, all rock converted to soil, structure destroyed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum WeatheringGrade {
+    Fresh,
+    SlightlyWeathered,
+    ModeratelyWeathered,
+    HighlyWeathered,
+    CompletelyWeathered,
+    ResidualSoil,
+}
+
+/// Pipe zone backfill soil type for the modulus of soil reaction E' (Howard, 1977)
+///
+/// # Variants
+/// * `FineGrainedLowPlasticity` - Fine grained soils (LL < 50) with little or no coarse grains (CL, ML)
+/// * `FineGrainedWithCoarse` - Fine grained soils (LL < 50) with more than 25% coarse grains, or
+///   coarse grained soils with more than 12% fines
+/// * `CoarseGrainedClean` - Coarse grained soils with little or no fines (GW, GP, SW, SP)
+/// * `CrushedRock` - Crushed rock
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub enum PipeBackfillType {
+    FineGrainedLowPlasticity,
+    FineGrainedWithCoarse,
+    CoarseGrainedClean,
+    CrushedRock,
+}
+
+/// Compaction level of the pipe zone backfill
+///
+/// # Variants
+/// * `Dumped` - Dumped, not compacted
+/// * `Slight` - Slight compaction (< 85% Standard Proctor)
+/// * `Moderate` - Moderate compaction (85–95% Standard Proctor)
+/// * `High` - High compaction (> 95% Standard Proctor)
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub enum CompactionLevel {
+    Dumped,
+    Slight,
+    Moderate,
+    High,
+}
+
+/// Wave type governing the geometric attenuation of ground vibrations
+///
+/// # Variants
+/// * `Surface` - Rayleigh (surface) waves, geometric exponent n = 0.5
+/// * `Body` - Body waves from a point source at the surface, geometric exponent n = 2.0
+/// * `BodyAtDepth` - Body waves from a source at depth (e.g. pile toe), geometric exponent n = 1.0
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub enum WaveType {
+    Surface,
+    Body,
+    BodyAtDepth,
+}
+
+/// Structure category for the vibration limits of DIN 4150-3
+///
+/// # Variants
+/// * `Commercial` - Commercial, industrial and similar buildings (line 1)
+/// * `Residential` - Dwellings and buildings of similar design (line 2)
+/// * `Sensitive` - Structures particularly sensitive to vibration, e.g. listed buildings (line 3)
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub enum StructureCategory {
+    Commercial,
+    Residential,
+    Sensitive,
+}
+
+/// Position of a screening trench relative to the vibration source
+///
+/// # Variants
+/// * `Active` - Trench surrounding the source (near-field isolation)
+/// * `Passive` - Trench close to the structure to be protected (far-field isolation)
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub enum IsolationType {
+    Active,
+    Passive,
+}
+
+/// Pier nose shape for the local scour correction factor K1 (HEC-18)
+///
+/// # Variants
+/// * `Square` - Square nose
+/// * `Round` - Round nose
+/// * `Circular` - Circular cylinder
+/// * `GroupOfCylinders` - Group of cylinders
+/// * `Sharp` - Sharp (triangular) nose
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub enum PierNoseShape {
+    Square,
+    Round,
+    Circular,
+    GroupOfCylinders,
+    Sharp,
+}
+
+/// Bed condition for the local scour correction factor K3 (HEC-18)
+///
+/// # Variants
+/// * `ClearWater` - Clear-water scour
+/// * `PlaneBed` - Plane bed and antidune flow
+/// * `SmallDunes` - Small dunes (0.6–3 m high)
+/// * `MediumDunes` - Medium dunes (3–9 m high)
+/// * `LargeDunes` - Large dunes (higher than 9 m)
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub enum BedCondition {
+    ClearWater,
+    PlaneBed,
+    SmallDunes,
+    MediumDunes,
+    LargeDunes,
+}
+
+/// Granular filter design criterion
+///
+/// # Variants
+/// * `TerzaghiRetention` - D15F / D85B ≤ 4 (Terzaghi)
+/// * `TerzaghiPermeability` - D15F / D15B ≥ 4 (Terzaghi)
+/// * `UsaceRetention` - D15F / D85B ≤ 5 (USACE)
+/// * `UsaceD50Ratio` - D50F / D50B ≤ 25 (USACE)
+/// * `UsacePermeability` - D15F / D15B ≥ 3 (USACE)
+/// * `FilterFines` - Filter fines passing 0.075 mm ≤ 5% (USACE)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum FilterCriterion {
+    TerzaghiRetention,
+    TerzaghiPermeability,
+    UsaceRetention,
+    UsaceD50Ratio,
+    UsacePermeability,
+    FilterFines,
+}
+
+/// Design parameter selected from site investigation data
+///
+/// # Variants
+/// * `UndrainedShearStrength` - Undrained shear strength cu (t/m²)
+/// * `FrictionAngle` - Effective friction angle φ' (degrees)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum DesignParameter {
+    UndrainedShearStrength,
+    FrictionAngle,
+}
+
+/// Source of a parameter measurement
+///
+/// # Variants
+/// * `Lab` - Laboratory test result
+/// * `Spt` - Derived from SPT blow counts
+/// * `Cpt` - Derived from CPT cone resistance
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum MeasurementSource {
+    Lab,
+    Spt,
+    Cpt,
+}
+
+/// Rule for selecting a design value from a set of measurements
+///
+/// # Variants
+/// * `Mean` - Arithmetic mean
+/// * `CautiousMean` - Mean minus half a standard deviation (Schneider, 1999)
+/// * `Characteristic` - 95% confidence lower bound of the mean (EC7)
+/// * `Minimum` - Smallest measured value
+/// * `Median` - Median value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum SelectionRule {
+    Mean,
+    CautiousMean,
+    Characteristic,
+    Minimum,
+    Median,
+}
+
+/// Implemented analysis method
+///
+/// # Variants
+/// * `Vesic` - Bearing capacity (Vesic, 1973)
+/// * `Meyerhof` - Bearing capacity (Meyerhof, 1963)
+/// * `Hansen` - Bearing capacity (Brinch Hansen, 1970)
+/// * `BudhuAlKarni` - Seismic bearing capacity reduction (Budhu & Al-Karni, 1993)
+/// * `MeyerhofSpt` - Allowable bearing pressure from SPT (Meyerhof, 1956; Bowles, 1977)
+/// * `TezcanOzdemir` - Bearing capacity from shear wave velocity (Tezcan & Ozdemir, 2007)
+/// * `Menard` - Bearing capacity from pressuremeter tests (Menard, 1963; Fascicule 62-V, 1993)
+/// * `PointLoadTest` - Bearing capacity of rock from point load tests (ISRM, 1985)
+/// * `HoekBrown` - Bearing capacity of rock from the Hoek-Brown criterion (Wyllie, 1999)
+/// * `SkirtedFoundation` - Capacity of skirted foundations in clay (Supachawarote et al., 2004)
+/// * `VhmEnvelope` - V-H-M failure envelope (Gottardi et al., 1999)
+/// * `EngineeredFill` - Bearing capacity of fill over soft clay (Meyerhof & Hanna, 1978)
+/// * `Boussinesq` - Elastic settlement (Bowles, 1996)
+/// * `CompressionIndex` - Consolidation settlement from Cc and Cr
+/// * `VolumeCompressibility` - Consolidation settlement from mv
+/// * `SeedIdriss` - SPT-based liquefaction (Youd et al., 2001)
+/// * `TokimatsuSeed` - Dry sand seismic settlement (Tokimatsu & Seed, 1987)
+/// * `AndrusStokoe` - Vs-based liquefaction (Andrus & Stokoe, 2000)
+/// * `LocalSoilClassByCu` - Local soil class from cu (TBDY, 2018)
+/// * `LocalSoilClassBySpt` - Local soil class from SPT (TBDY, 2018)
+/// * `LocalSoilClassByVs` - Local soil class from Vs (TBDY, 2018)
+/// * `KayabaliYaldiz` - Swelling pressure (Kayabalı & Yaldız, 2014)
+/// * `HorizontalSliding` - Horizontal sliding of foundations
+/// * `GroundAnchor` - Ground anchor design (Sabatini et al., 1999)
+/// * `PiledRaft` - Piled raft load sharing (Randolph, 1994)
+/// * `CyclicDegradation` - Cyclic degradation of clays (Idriss et al., 1978)
+/// * `ModifiedIowa` - Buried flexible pipe deflection (Spangler, 1941)
+/// * `Bornitz` - Ground vibration attenuation (Bornitz, 1931)
+/// * `EnrDrivability` - Engineering News Record driving formula
+/// * `GatesDrivability` - Gates (1957) driving formula
+/// * `Hec18Scour` - Bridge pier scour (Arneson et al., 2012)
+/// * `KenneyLau` - Internal stability of granular soils (Kenney & Lau, 1986)
+/// * `Hazen` - Permeability from D10 (Hazen, 1911)
+/// * `FilterCriteria` - Granular filter criteria (Terzaghi; USACE, 2004)
+/// * `ParameterSelection` - Design parameter selection (Schneider, 1999; EC7)
+/// * `SptAnchor` - Tieback and screw anchor capacity from SPT (Stroud, 1974; Meyerhof, 1976)
+/// * `Keceli` - Bearing capacity from shear wave velocity (Keçeli, 2012)
+/// * `Schmertmann` - Settlement from CPT by the strain influence factor (Schmertmann et al., 1978)
+/// * `Hough` - Settlement of granular soil from SPT (Hough, 1959)
+/// * `Janbu` - Immediate settlement of saturated clay (Christian & Carrier, 1978)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum MethodId {
+    Vesic,
+    Meyerhof,
+    Hansen,
+    BudhuAlKarni,
+    MeyerhofSpt,
+    TezcanOzdemir,
+    Menard,
+    PointLoadTest,
+    HoekBrown,
+    SkirtedFoundation,
+    VhmEnvelope,
+    EngineeredFill,
+    Boussinesq,
+    CompressionIndex,
+    VolumeCompressibility,
+    SeedIdriss,
+    TokimatsuSeed,
+    AndrusStokoe,
+    LocalSoilClassByCu,
+    LocalSoilClassBySpt,
+    LocalSoilClassByVs,
+    KayabaliYaldiz,
+    HorizontalSliding,
+    GroundAnchor,
+    PiledRaft,
+    CyclicDegradation,
+    ModifiedIowa,
+    Bornitz,
+    EnrDrivability,
+    GatesDrivability,
+    Hec18Scour,
+    KenneyLau,
+    Hazen,
+    FilterCriteria,
+    ParameterSelection,
+    SptAnchor,
+    Keceli,
+    Schmertmann,
+    Hough,
+    Janbu,
+}
+
+/// Bearing capacity method of the unified dispatcher
+///
+/// # Variants
+/// * `Vesic` - General bearing capacity equation (Vesic, 1973)
+/// * `Meyerhof` - General bearing capacity equation (Meyerhof, 1963)
+/// * `Terzaghi` - General bearing capacity equation (Terzaghi, 1943)
+/// * `Hansen` - General bearing capacity equation (Brinch Hansen, 1970)
+/// * `TezcanOzdemir` - Allowable bearing pressure from shear wave velocity
+/// * `PointLoad` - Bearing capacity of rock from point load tests
+/// * `SptBased` - Allowable bearing pressure from SPT
+/// * `CptBased` - Bearing capacity from CPT
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum BearingCapacityMethod {
+    Vesic,
+    Meyerhof,
+    Terzaghi,
+    Hansen,
+    TezcanOzdemir,
+    PointLoad,
+    SptBased,
+    CptBased,
+}
+
+/// Allowable bearing pressure formulation based on shear wave velocity
+///
+/// # Variants
+/// * `TezcanOzdemir` - Safety factor reducing from 4 in soils to 1.4 in rock (Tezcan & Ozdemir, 2007)
+/// * `Keceli` - Constant safety factor of 4 over the full velocity range (Keçeli, 2012)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum VsBearingMethod {
+    TezcanOzdemir,
+    Keceli,
+}
+
+/// Soil type a method is applicable to
+///
+/// # Variants
+/// * `Cohesive` - Clays and silts
+/// * `Granular` - Sands and gravels
+/// * `Rock` - Rock
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum SoilType {
+    Cohesive,
+    Granular,
+    Rock,
+}
+
+/// Style of a generated example soil profile
+///
+/// # Variants
+/// * `SoftClayOverSand` - Normally consolidated soft clay over medium dense sand
+/// * `Alluvium` - Interbedded sand and clay layers with a shallow groundwater level
+/// * `StiffClay` - Overconsolidated stiff clay
+/// * `DenseSand` - Medium dense to dense sand
+/// * `ClayOverRock` - Stiff clay over weathered rock
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum ProfileStyle {
+    SoftClayOverSand,
+    Alluvium,
+    StiffClay,
+    DenseSand,
+    ClayOverRock,
+}
+
+/// Granular soil type of the Hough (1959) bearing capacity index chart
+///
+/// # Variants
+/// * `WellGradedSandAndGravel` - Well-graded silty sand and gravel
+/// * `WellGradedSand` - Clean, well-graded fine to coarse sand
+/// * `UniformMediumSand` - Uniform medium sand
+/// * `UniformFineSand` - Uniform fine sand
+/// * `SiltySand` - Silty sand
+/// * `InorganicSilt` - Inorganic silt
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum HoughSoilType {
+    WellGradedSandAndGravel,
+    WellGradedSand,
+    UniformMediumSand,
+    UniformFineSand,
+    SiltySand,
+    InorganicSilt,
+}
+
+/// Analysis for which a critical layer is identified.
+///
+/// # Variants
+/// * `Settlement` - Layer with the largest settlement contribution
+/// * `BearingCapacity` - Weakest layer within the bearing influence zone
+/// * `Liquefaction` - Layer with the lowest liquefaction safety factor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum CriticalLayerCriterion {
+    Settlement,
+    BearingCapacity,
+    Liquefaction,
+}
+
+/// Parameter a local soil class is based on.
+///
+/// # Variants
+/// * `Vs30` - Average shear wave velocity of the upper 30 m
+/// * `N30` - Average SPT blow count of the upper 30 m
+/// * `Cu30` - Average undrained shear strength of the upper 30 m
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum SoilClassBasis {
+    Vs30,
+    N30,
+    Cu30,
+}
+
+/// Soil category of the pressuremeter bearing capacity method (Fascicule 62-V, 1993).
+///
+/// # Variants
+/// * `ClayA` - Soft clay and silt
+/// * `ClayB` - Firm clay and silt
+/// * `ClayC` - Stiff to very stiff clay
+/// * `SandA` - Loose sand and gravel
+/// * `SandB` - Medium dense sand and gravel
+/// * `SandC` - Dense sand and gravel
+/// * `Chalk` - Chalk
+/// * `Marl` - Marl and marly limestone
+/// * `WeatheredRock` - Weathered rock
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum PmtSoilCategory {
+    ClayA,
+    ClayB,
+    ClayC,
+    SandA,
+    SandB,
+    SandC,
+    Chalk,
+    Marl,
+    WeatheredRock,
+}
+
+/// Convention of a foundation pressure passed to the capacity and settlement methods.
+///
+/// # Variants
+/// * `Gross` - Total contact pressure at the foundation base, q (default)
+/// * `Net` - Pressure in excess of the total overburden at the base, q_net = q − σv(Df)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum PressureConvention {
+    #[default]
+    Gross,
+    Net,
+}
+
+/// Plan shape of a foundation.
+///
+/// # Variants
+/// * `Rectangular` - Rectangular footing with the foundation width and length (default)
+/// * `Circular` - Circular footing with the given diameter (m)
+/// * `Strip` - Strip footing of the foundation width and infinite length
+/// * `Ring` - Ring footing with the given outer and inner diameters (m)
+#[derive(Debug, Clone, Copy, PartialEq, Default, Deserialize, Serialize)]
+pub enum FoundationShape {
+    #[default]
+    Rectangular,
+    Circular {
+        diameter: f64,
+    },
+    Strip,
+    Ring {
+        outer_diameter: f64,
+        inner_diameter: f64,
+    },
+}
+
+/// Shape of the effective area of an eccentrically loaded rectangular foundation
+/// (Highter & Anders, 1985).
+///
+/// # Variants
+/// * `Concentric` - No eccentricity, the full area is effective
+/// * `OneWay` - Eccentricity in one direction, B' = B − 2e (Meyerhof, 1953)
+/// * `Pentagonal` - Two-way eccentricity with a corner cut off (Case IV)
+/// * `TrapezoidalLength` - Trapezoid spanning the full width (Case II)
+/// * `TrapezoidalWidth` - Trapezoid spanning the full length (Case III)
+/// * `Triangular` - Triangle at the loaded corner (Case I)
+/// * `NoContact` - The resultant is outside the foundation, no effective area
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum EccentricityCase {
+    Concentric,
+    OneWay,
+    Pentagonal,
+    TrapezoidalLength,
+    TrapezoidalWidth,
+    Triangular,
+    NoContact,
+}
+
+/// Interface between the foundation base and the soil, for base friction and adhesion.
+///
+/// # Variants
+/// * `CastInPlaceConcrete` - Concrete cast directly against the soil
+/// * `PrecastConcreteOnSand` - Smooth precast concrete placed on sand
+/// * `Geomembrane` - Smooth geomembrane between the base and the soil
+/// * `LeanConcrete` - Footing cast on a lean concrete blinding layer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum InterfaceType {
+    CastInPlaceConcrete,
+    PrecastConcreteOnSand,
+    Geomembrane,
+    LeanConcrete,
+}
+
+/// Shear failure mode of a shallow foundation.
+///
+/// # Variants
+/// * `General` - General shear failure with the full soil strength
+/// * `Local` - Local shear failure with the reduced parameters of Terzaghi (1943)
+/// * `Punching` - Punching shear failure with the reduced parameters of Terzaghi (1943)
+/// * `Auto` - Failure mode classified from the relative density or consistency of the soil
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum ShearFailureMode {
+    General,
+    Local,
+    Punching,
+    Auto,
+}
+
+/// Criterion limiting the allowable foundation pressure.
+///
+/// # Variants
+/// * `BearingCapacity` - The allowable bearing capacity governs
+/// * `Settlement` - The allowable settlement governs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum GoverningCriterion {
+    BearingCapacity,
+    Settlement,
+}
+
+/// Groundwater correction case of the bearing capacity equation (Das).
+///
+/// # Variants
+/// * `CaseI` - Groundwater above the foundation base; the surcharge and the γ-term use γ' below it
+/// * `CaseII` - Groundwater within B below the base; the γ-term uses an interpolated unit weight
+/// * `CaseIII` - Groundwater below the influence zone Df + B; no correction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum GroundwaterCase {
+    CaseI,
+    CaseII,
+    CaseIII,
+}
+
+/// Design approach of Eurocode 7 (EN 1997-1) for the ultimate limit state.
+///
+/// # Variants
+/// * `Da1Combination1` - Design Approach 1, Combination 1 (A1 + M1 + R1)
+/// * `Da1Combination2` - Design Approach 1, Combination 2 (A2 + M2 + R1)
+/// * `Da2` - Design Approach 2 (A1 + M1 + R2)
+/// * `Da3` - Design Approach 3 (A1 structural actions + M2 + R3)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum DesignApproach {
+    Da1Combination1,
+    Da1Combination2,
+    Da2,
+    Da3,
+}
+
+/// Exposure class of concrete to chemical attack from groundwater (EN 206, Table 2).
+///
+/// # Variants
+/// * `None` - Not aggressive
+/// * `Xa1` - Slightly aggressive chemical environment
+/// * `Xa2` - Moderately aggressive chemical environment
+/// * `Xa3` - Highly aggressive chemical environment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+pub enum ChemicalExposureClass {
+    None,
+    Xa1,
+    Xa2,
+    Xa3,
+}
+
+/// Drainage condition of a consolidating layer.
+///
+/// # Variants
+/// * `Single` - Drained at one boundary only; the drainage path is the layer thickness
+/// * `Double` - Drained at the top and the bottom; the drainage path is half the thickness
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum DrainageCondition {
+    Single,
+    Double,
+}
+
+/// Point of a foundation at which the elastic settlement is calculated.
+///
+/// # Variants
+/// * `CenterFlexible` - Center of a flexible foundation
+/// * `CornerFlexible` - Corner of a flexible foundation
+/// * `AverageFlexible` - Average over a flexible foundation
+/// * `Rigid` - Uniform settlement of a rigid foundation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum SettlementPoint {
+    CenterFlexible,
+    CornerFlexible,
+    AverageFlexible,
+    Rigid,
+}
+
+/// Method used to distribute a foundation pressure to the vertical stress increase below the
+/// foundation center.
+///
+/// # Variants
+/// * `TwoToOne` - 2:1 load spreading, Δσ = q · B · L / ((B + z) · (L + z)) (default)
+/// * `BoussinesqRectangular` - Boussinesq solution for a uniformly loaded rectangle
+/// * `Westergaard` - Westergaard solution for a uniformly loaded rectangle on layered soil
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum StressDistribution {
+    #[default]
+    TwoToOne,
+    BoussinesqRectangular,
+    Westergaard,
+}
+
+/// Type of sedimentary deposit used in liquefaction susceptibility screening
+/// (Youd & Perkins, 1978).
+///
+/// # Variants
+/// * `RiverChannel`, `FloodPlain`, `AlluvialFan`, `MarineTerrace`, `Delta`, `Lacustrine`,
+///   `Colluvium`, `Talus`, `Dunes`, `Loess`, `GlacialTill`, `Tuff`, `Tephra`, `ResidualSoil`,
+///   `Sebka` - Continental deposits
+/// * `CoastalDelta`, `Estuarine`, `BeachHighWaveEnergy`, `BeachLowWaveEnergy`, `Lagoonal`,
+///   `Foreshore` - Coastal zone deposits
+/// * `UncompactedFill`, `CompactedFill` - Artificial deposits
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum DepositType {
+    RiverChannel,
+    FloodPlain,
+    AlluvialFan,
+    MarineTerrace,
+    Delta,
+    Lacustrine,
+    Colluvium,
+    Talus,
+    Dunes,
+    Loess,
+    GlacialTill,
+    Tuff,
+    Tephra,
+    ResidualSoil,
+    Sebka,
+    CoastalDelta,
+    Estuarine,
+    BeachHighWaveEnergy,
+    BeachLowWaveEnergy,
+    Lagoonal,
+    Foreshore,
+    UncompactedFill,
+    CompactedFill,
+}
+
+/// Geologic age of a deposit.
+///
+/// # Variants
+/// * `LatestHolocene` - Younger than 500 years
+/// * `Holocene` - Younger than 11,000 years
+/// * `Pleistocene` - Younger than 2 million years
+/// * `PrePleistocene` - Older than 2 million years
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum GeologicAge {
+    LatestHolocene,
+    Holocene,
+    Pleistocene,
+    PrePleistocene,
+}
+
+/// Liquefaction susceptibility class, ordered from the least to the most susceptible.
+///
+/// # Variants
+/// * `VeryLow`, `Low`, `Moderate`, `High`, `VeryHigh`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+pub enum LiquefactionSusceptibility {
+    VeryLow,
+    Low,
+    Moderate,
+    High,
+    VeryHigh,
+}
+
+/// Adjustment of the empirical bearing capacities (shear wave velocity and point load test) for
+/// the footing geometry. Without an adjustment the capacity of the method is independent of the
+/// footing size, as published.
+///
+/// # Variants
+/// * `Terzaghi` - Shape factor 1 + 0.3 · B / L of the cohesion term (1.3 for square and circular
+///   footings; Terzaghi, 1943), recommended for footings on rock by Bowles (1996)
+/// * `Custom` - Shape and depth factors of the governing design code
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+pub enum EmpiricalGeometryAdjustment {
+    Terzaghi,
+    Custom {
+        shape_factor: f64,
+        depth_factor: f64,
+    },
 }
@@ -0,0 +1,155 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::SelectionMethod,
+    models::soil_profile::SoilProfile,
+    validation::{validate_field, ValidationError},
+};
+
+/// Range of the undrained shear strength readings within a soil layer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayerStrengthRange {
+    /// Index of the layer in the soil profile.
+    pub layer_index: usize,
+    /// Top depth of the layer (m).
+    pub top: f64,
+    /// Bottom depth of the layer (m).
+    pub bottom: f64,
+    /// Number of readings within the layer.
+    pub count: usize,
+    /// Lowest undrained shear strength reading (t/m²).
+    pub min: f64,
+    /// Average undrained shear strength of the readings (t/m²).
+    pub average: f64,
+    /// Highest undrained shear strength reading (t/m²).
+    pub max: f64,
+    /// Undrained shear strength assigned to the layer by the selection method (t/m²).
+    pub selected: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldStrengthResult {
+    /// Strength ranges of the layers with at least one reading.
+    pub ranges: Vec<LayerStrengthRange>,
+    /// Copy of the soil profile with `cu` set for the layers with readings.
+    pub populated_profile: SoilProfile,
+}
+
+/// Validates the input data for populating layer strengths from field readings.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `readings` - (depth, su) pairs of the readings (m, t/m²).
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(
+    soil_profile: &SoilProfile,
+    readings: &[(f64, f64)],
+) -> Result<(), ValidationError> {
+    soil_profile.validate(&["thickness"])?;
+    if readings.is_empty() {
+        return Err(ValidationError {
+            code: "field_strength.empty_readings".into(),
+            message: "No readings provided for field strength tests.".into(),
+        });
+    }
+    for &(depth, su) in readings {
+        validate_field("depth", Some(depth), Some(0.0), None, "field_strength")?;
+        validate_field("su", Some(su), Some(0.0), None, "field_strength")?;
+    }
+
+    Ok(())
+}
+
+/// Calculates the range of the undrained shear strength readings within each soil layer.
+///
+/// Readings are assigned to the layer containing their depth, readings below the profile go to
+/// the last layer. Rock layers and layers without readings are skipped.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `readings` - (depth, su) pairs of the readings (m, t/m²), e.g. from
+///   `PocketPenetrometerTest::get_undrained_strengths` or `TorvaneTest::get_undrained_strengths`.
+/// * `method` - Selection method of the layer strength from the readings.
+///
+/// # Returns
+/// * `Vec<LayerStrengthRange>` of the layers with readings, ordered by depth.
+pub fn calc_layer_strength_ranges(
+    soil_profile: &SoilProfile,
+    readings: &[(f64, f64)],
+    method: SelectionMethod,
+) -> Vec<LayerStrengthRange> {
+    let mut profile = soil_profile.clone();
+    profile.calc_layer_depths();
+
+    let mut grouped: Vec<Vec<f64>> = vec![Vec::new(); profile.layers.len()];
+    for &(depth, su) in readings {
+        grouped[profile.get_layer_index(depth)].push(su);
+    }
+
+    let mut ranges = Vec::new();
+    let mut top = 0.0;
+
+    for (layer_index, (layer, values)) in profile.layers.iter().zip(grouped).enumerate() {
+        let bottom = layer.depth.unwrap();
+        if !layer.is_rock() && !values.is_empty() {
+            let min = values.iter().cloned().fold(f64::INFINITY, f64::min);
+            let max = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+            let average = values.iter().sum::<f64>() / values.len() as f64;
+            let selected = match method {
+                SelectionMethod::Min => min,
+                SelectionMethod::Avg => average,
+                SelectionMethod::Max => max,
+            };
+
+            ranges.push(LayerStrengthRange {
+                layer_index,
+                top,
+                bottom,
+                count: values.len(),
+                min,
+                average,
+                max,
+                selected,
+            });
+        }
+        top = bottom;
+    }
+
+    ranges
+}
+
+/// Populates the undrained shear strength of the soil layers from field strength readings.
+///
+/// Cheap index tests such as the pocket penetrometer and the torvane are logged at many depths;
+/// the readings within each layer are reduced to a single `cu` by the selection method, and the
+/// range of the readings is reported so the scatter can be judged.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `readings` - (depth, su) pairs of the readings (m, t/m²).
+/// * `method` - Selection method of the layer strength from the readings.
+///
+/// # Returns
+/// * `FieldStrengthResult` with the layer ranges and the populated profile.
+pub fn populate_layer_cu(
+    soil_profile: &SoilProfile,
+    readings: &[(f64, f64)],
+    method: SelectionMethod,
+) -> Result<FieldStrengthResult, ValidationError> {
+    validate_input(soil_profile, readings)?;
+
+    let ranges = calc_layer_strength_ranges(soil_profile, readings, method);
+
+    let mut populated_profile = soil_profile.clone();
+    populated_profile.calc_layer_depths();
+    for range in &ranges {
+        populated_profile.layers[range.layer_index].cu = Some(range.selected);
+    }
+
+    Ok(FieldStrengthResult {
+        ranges,
+        populated_profile,
+    })
+}
@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::FilterCriterion, models::gradation::GradationCurve, validation::ValidationError,
+};
+
+/// Result of a single filter criterion.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FilterCheck {
+    /// The checked criterion.
+    pub criterion: FilterCriterion,
+    /// Calculated ratio (or fines content in % for `FilterFines`).
+    pub value: f64,
+    /// Limiting value of the criterion.
+    pub limit: f64,
+    /// Indicates whether the criterion is satisfied.
+    pub is_satisfied: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FilterCheckResult {
+    /// Results of each criterion.
+    pub checks: Vec<FilterCheck>,
+    /// Indicates whether all criteria are satisfied.
+    pub is_compatible: bool,
+}
+
+/// Reads a characteristic diameter from a gradation curve or returns an error.
+fn get_diameter(
+    gradation: &GradationCurve,
+    percent: f64,
+    prefix: &str,
+) -> Result<f64, ValidationError> {
+    gradation.diameter_at(percent).ok_or(ValidationError {
+        code: format!("filter_design.{}.d{}_unavailable", prefix, percent),
+        message: format!(
+            "D{} cannot be read from the {} gradation curve.",
+            percent, prefix
+        ),
+    })
+}
+
+/// Creates a check for a ratio that must not exceed a limit.
+fn max_check(criterion: FilterCriterion, value: f64, limit: f64) -> FilterCheck {
+    FilterCheck {
+        criterion,
+        value,
+        limit,
+        is_satisfied: value <= limit,
+    }
+}
+
+/// Creates a check for a ratio that must not fall below a limit.
+fn min_check(criterion: FilterCriterion, value: f64, limit: f64) -> FilterCheck {
+    FilterCheck {
+        criterion,
+        value,
+        limit,
+        is_satisfied: value >= limit,
+    }
+}
+
+/// Checks the compatibility of a granular filter with a base soil.
+///
+/// The retention criteria prevent the base soil from migrating into the filter, while the
+/// permeability criteria ensure the filter is sufficiently more pervious than the base soil.
+///
+/// # Arguments
+/// * `base` - Gradation curve of the base soil.
+/// * `filter` - Gradation curve of the candidate filter.
+///
+/// # Returns
+/// * `FilterCheckResult` with the result of each criterion.
+pub fn check_filter(
+    base: &GradationCurve,
+    filter: &GradationCurve,
+) -> Result<FilterCheckResult, ValidationError> {
+    base.validate()?;
+    filter.validate()?;
+
+    let d15b = get_diameter(base, 15.0, "base")?;
+    let d50b = get_diameter(base, 50.0, "base")?;
+    let d85b = get_diameter(base, 85.0, "base")?;
+    let d15f = get_diameter(filter, 15.0, "filter")?;
+    let d50f = get_diameter(filter, 50.0, "filter")?;
+
+    let checks = vec![
+        max_check(FilterCriterion::TerzaghiRetention, d15f / d85b, 4.0),
+        min_check(FilterCriterion::TerzaghiPermeability, d15f / d15b, 4.0),
+        max_check(FilterCriterion::UsaceRetention, d15f / d85b, 5.0),
+        max_check(FilterCriterion::UsaceD50Ratio, d50f / d50b, 25.0),
+        min_check(FilterCriterion::UsacePermeability, d15f / d15b, 3.0),
+        max_check(FilterCriterion::FilterFines, filter.fines_content(), 5.0),
+    ];
+    let is_compatible = checks.iter().all(|check| check.is_satisfied);
+
+    Ok(FilterCheckResult {
+        checks,
+        is_compatible,
+    })
+}
+
+/// Checks a set of candidate filters against a base soil.
+///
+/// # Arguments
+/// * `base` - Gradation curve of the base soil.
+/// * `candidates` - Gradation curves of the candidate filters.
+///
+/// # Returns
+/// * `Vec<FilterCheckResult>`: Result for each candidate, in the given order.
+pub fn check_filter_candidates(
+    base: &GradationCurve,
+    candidates: &[GradationCurve],
+) -> Result<Vec<FilterCheckResult>, ValidationError> {
+    candidates
+        .iter()
+        .map(|filter| check_filter(base, filter))
+        .collect()
+}
@@ -0,0 +1,182 @@
+use serde::Serialize;
+
+use crate::{
+    bearing_capacity::{model::BearingCapacityResult, vesic},
+    constants::Environment,
+    enums::{AnalysisTerm, PressureConvention},
+    horizontal_sliding::{calc_horizontal_sliding_by_term, HorizontalSlidingResult},
+    models::{foundation::Foundation, loads::Loads, soil_profile::SoilProfile},
+    validation::ValidationError,
+};
+
+/// Required safety factor against flotation.
+const FLOTATION_SAFETY_FACTOR: f64 = 1.1;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FlotationResult {
+    /// Hydrostatic uplift pressure on the foundation base (t/m²).
+    pub uplift_pressure: f64,
+    /// Total uplift force on the foundation base (t).
+    pub uplift_force: f64,
+    /// Vertical load resisting the uplift (t).
+    pub resisting_force: f64,
+    /// Safety factor against flotation.
+    pub factor_of_safety: f64,
+    /// Indicates whether the safety factor is at least the required value of 1.1.
+    pub is_safe: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FloodResult {
+    /// Soil profile with the groundwater level raised to the ground surface.
+    pub flooded_profile: SoilProfile,
+    /// Foundation pressure reduced by the uplift pressure (t/m²).
+    pub effective_foundation_pressure: f64,
+    /// Bearing capacity (Vesic) under flooding.
+    pub bearing_capacity: BearingCapacityResult,
+    /// Horizontal sliding check under flooding.
+    pub horizontal_sliding: HorizontalSlidingResult,
+    /// Flotation check.
+    pub flotation: FlotationResult,
+}
+
+/// Calculates the hydrostatic uplift pressure on a foundation base during flooding.
+///
+/// # Arguments
+/// * `foundation_depth` - Depth of the foundation base below the ground surface (m).
+/// * `flood_height` - Height of flood water above the ground surface (m).
+/// * `environment` - Physical constants with the unit weight of the water.
+///
+/// # Returns
+/// * `f64`: Uplift pressure (t/m²).
+pub fn calc_uplift_pressure(
+    foundation_depth: f64,
+    flood_height: f64,
+    environment: &Environment,
+) -> f64 {
+    environment.hydrostatic_pressure(foundation_depth + flood_height)
+}
+
+/// Validates the input data for flood loading checks.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `foundation` - The foundation data.
+/// * `loading` - The applied loads.
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    loading: &Loads,
+) -> Result<(), ValidationError> {
+    soil_profile.validate(&["thickness"])?;
+    foundation.validate(&["foundation_depth", "foundation_width", "foundation_length"])?;
+    loading.validate(&["vertical_load", "flood_height"])?;
+
+    Ok(())
+}
+
+/// Checks a foundation against flotation under flooding.
+///
+/// The uplift force u · B · L is resisted by the vertical load of the structure.
+///
+/// # Arguments
+/// * `foundation` - The foundation data.
+/// * `loading` - The applied loads with the flood height.
+/// * `environment` - Physical constants with the unit weight of the water.
+///
+/// # Returns
+/// * `FlotationResult` with the uplift force and safety factor.
+pub fn calc_flotation(
+    foundation: &Foundation,
+    loading: &Loads,
+    environment: &Environment,
+) -> Result<FlotationResult, ValidationError> {
+    foundation.validate(&["foundation_depth", "foundation_width", "foundation_length"])?;
+    loading.validate(&["vertical_load", "flood_height"])?;
+
+    let area = foundation.foundation_width.unwrap() * foundation.foundation_length.unwrap();
+    let uplift_pressure = calc_uplift_pressure(
+        foundation.foundation_depth.unwrap(),
+        loading.flood_height.unwrap(),
+        environment,
+    );
+    let uplift_force = uplift_pressure * area;
+    let resisting_force = loading.vertical_load.unwrap();
+    let factor_of_safety = if uplift_force > 0.0 {
+        resisting_force / uplift_force
+    } else {
+        f64::INFINITY
+    };
+
+    Ok(FlotationResult {
+        uplift_pressure,
+        uplift_force,
+        resisting_force,
+        factor_of_safety,
+        is_safe: factor_of_safety >= FLOTATION_SAFETY_FACTOR,
+    })
+}
+
+/// Re-runs the bearing capacity (Vesic), horizontal sliding and flotation checks under flooding.
+///
+/// Flood water above the ground surface saturates the whole profile, so the groundwater level
+/// is raised to the surface. The water above the ground adds the same amount to total stress
+/// and pore pressure, leaving effective stresses in the soil unchanged, while the hydrostatic
+/// uplift on the base reduces the effective foundation pressure to q − γw · (Df + hw). The unit
+/// weight of the water is taken from the environment of the soil profile.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `foundation` - The foundation data.
+/// * `loading` - The applied loads with the flood height.
+/// * `foundation_pressure` - The pressure on the foundation (t/m²).
+/// * `factor_of_safety` - The safety factor of the bearing capacity.
+/// * `term` - Short, long or automatically selected term.
+///
+/// # Returns
+/// * `FloodResult` with the re-run analyses.
+pub fn calc_flood_stability(
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    loading: &Loads,
+    foundation_pressure: f64,
+    factor_of_safety: f64,
+    term: AnalysisTerm,
+) -> Result<FloodResult, ValidationError> {
+    validate_input(soil_profile, foundation, loading)?;
+
+    let mut flooded_profile = soil_profile.clone();
+    flooded_profile.ground_water_level = Some(0.0);
+    flooded_profile.calc_layer_depths();
+
+    let flotation = calc_flotation(foundation, loading, &soil_profile.get_environment())?;
+    let effective_foundation_pressure = (foundation_pressure - flotation.uplift_pressure).max(0.0);
+
+    let bearing_capacity = vesic::calc_bearing_capacity(
+        &mut flooded_profile,
+        &mut foundation.clone(),
+        loading,
+        effective_foundation_pressure,
+        PressureConvention::Gross,
+        factor_of_safety,
+        term,
+    )?;
+    let horizontal_sliding = calc_horizontal_sliding_by_term(
+        &flooded_profile,
+        foundation,
+        loading,
+        effective_foundation_pressure,
+        term,
+    )?;
+
+    Ok(FloodResult {
+        flooded_profile,
+        effective_foundation_pressure,
+        bearing_capacity,
+        horizontal_sliding,
+        flotation,
+    })
+}
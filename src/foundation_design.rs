@@ -0,0 +1,661 @@
+use serde::Serialize;
+
+use crate::{
+    enums::LoadCase,
+    models::{
+        column_load::{ColumnLoad, MatColumnLoad},
+        column_schedule::ColumnSchedule,
+        foundation::Foundation,
+    },
+    validation::{validate_field, ValidationError},
+};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CombinedFootingResult {
+    /// Sum of the column loads (t).
+    pub total_load: f64,
+    /// Distance of the load resultant from the left edge of the footing (m).
+    pub resultant_position: f64,
+    /// Eccentricity of the resultant from the footing centre, positive towards the right (m).
+    pub eccentricity: f64,
+    /// Contact pressure at the left edge of the footing (t/m²).
+    pub left_edge_pressure: f64,
+    /// Contact pressure at the right edge of the footing (t/m²).
+    pub right_edge_pressure: f64,
+    /// Maximum contact pressure (t/m²).
+    pub max_pressure: f64,
+    /// Minimum contact pressure (t/m²).
+    pub min_pressure: f64,
+    /// Length of the footing in contact with the soil (m).
+    pub contact_length: f64,
+    /// Indicates whether the resultant falls outside the kern, lifting part of the base.
+    pub has_tension: bool,
+    /// Allowable bearing capacity (t/m²).
+    pub allowable_bearing_capacity: f64,
+    /// Indicates whether the maximum pressure does not exceed the allowable bearing capacity.
+    pub is_safe: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StrapFootingResult {
+    /// Soil reaction under the exterior footing (t).
+    pub exterior_reaction: f64,
+    /// Soil reaction under the interior footing (t).
+    pub interior_reaction: f64,
+    /// Contact pressure under the exterior footing (t/m²).
+    pub exterior_pressure: f64,
+    /// Contact pressure under the interior footing (t/m²).
+    pub interior_pressure: f64,
+    /// Shear force carried by the strap beam (t).
+    pub strap_shear: f64,
+    /// Indicates whether the interior footing is lifted by the strap (negative reaction).
+    pub has_tension: bool,
+    /// Allowable bearing capacity (t/m²).
+    pub allowable_bearing_capacity: f64,
+    /// Indicates whether both contact pressures do not exceed the allowable bearing capacity.
+    pub is_safe: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MatPressureResult {
+    /// Sum of the column loads (t).
+    pub total_load: f64,
+    /// Eccentricity of the resultant from the mat centre along the width (m).
+    pub eccentricity_x: f64,
+    /// Eccentricity of the resultant from the mat centre along the length (m).
+    pub eccentricity_y: f64,
+    /// Contact pressure at the centre of each zone, rows along the length (t/m²).
+    pub zone_pressures: Vec<Vec<f64>>,
+    /// Settlement of each zone under its contact pressure (cm).
+    pub zone_settlements: Vec<Vec<f64>>,
+    /// Ratio of the contact pressure to the allowable bearing capacity of each zone.
+    pub zone_utilizations: Vec<Vec<f64>>,
+    /// Maximum zone pressure (t/m²).
+    pub max_pressure: f64,
+    /// Minimum zone pressure (t/m²).
+    pub min_pressure: f64,
+    /// Maximum zone utilization.
+    pub max_utilization: f64,
+    /// Indicates whether any zone is under tension, i.e. the rigid method does not apply there.
+    pub has_tension: bool,
+    /// Allowable bearing capacity (t/m²).
+    pub allowable_bearing_capacity: f64,
+    /// Indicates whether no zone exceeds the allowable bearing capacity.
+    pub is_safe: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FootingPressureResult {
+    /// Identifier of the column on the footing.
+    pub column_id: String,
+    /// Vertical column load (t).
+    pub vertical_load: f64,
+    /// Eccentricity of the load along the width (m).
+    pub eccentricity_x: f64,
+    /// Eccentricity of the load along the length (m).
+    pub eccentricity_y: f64,
+    /// Average contact pressure V / (B · L) (t/m²).
+    pub average_pressure: f64,
+    /// Maximum corner pressure of the linear pressure distribution (t/m²).
+    pub max_pressure: f64,
+    /// Minimum corner pressure of the linear pressure distribution (t/m²).
+    pub min_pressure: f64,
+    /// Pressure on the effective area V / A' (t/m²), for the bearing capacity check.
+    pub effective_pressure: f64,
+    /// Indicates whether the linear distribution gives tension, i.e. the load is outside the kern.
+    pub has_tension: bool,
+}
+
+/// Validates the column loads and the allowable bearing capacity.
+fn validate_loads(
+    columns: &[ColumnLoad; 2],
+    allowable_bearing_capacity: f64,
+) -> Result<(), ValidationError> {
+    for column in columns {
+        column.validate(&["position", "load"])?;
+        if column.moment.is_some() {
+            column.validate(&["moment"])?;
+        }
+    }
+    validate_field(
+        "allowable_bearing_capacity",
+        Some(allowable_bearing_capacity),
+        Some(0.0001),
+        None,
+        "foundation_design",
+    )?;
+    if columns.iter().all(|column| column.load.unwrap() == 0.0) {
+        return Err(ValidationError {
+            code: "foundation_design.total_load.zero".to_string(),
+            message: "Total column load must be greater than zero.".to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Returns the total load and the position of the resultant of the column loads.
+///
+/// Column moments shift the resultant by ΣM / ΣP towards the right.
+fn calc_resultant(columns: &[ColumnLoad; 2]) -> (f64, f64) {
+    let total_load: f64 = columns.iter().map(|c| c.load.unwrap()).sum();
+    let first_moment: f64 = columns
+        .iter()
+        .map(|c| c.load.unwrap() * c.position.unwrap() + c.moment.unwrap_or(0.0))
+        .sum();
+
+    (total_load, first_moment / total_load)
+}
+
+/// Validates the input data for combined footing contact pressure.
+///
+/// # Arguments
+/// * `foundation` - The combined footing, with its length along the column line.
+/// * `columns` - The two column loads.
+/// * `allowable_bearing_capacity` - Allowable bearing capacity (t/m²).
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_combined_input(
+    foundation: &Foundation,
+    columns: &[ColumnLoad; 2],
+    allowable_bearing_capacity: f64,
+) -> Result<(), ValidationError> {
+    foundation.validate(&["foundation_width", "foundation_length"])?;
+    validate_loads(columns, allowable_bearing_capacity)?;
+
+    let length = foundation.foundation_length.unwrap();
+    for column in columns {
+        validate_field(
+            "position",
+            column.position,
+            Some(0.0),
+            Some(length),
+            "column_load",
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Calculates the linear contact pressure under a rigid combined footing carrying two columns.
+///
+/// With the resultant at an eccentricity e from the footing centre, the edge pressures are
+///
+/// q = V / (B · L) · (1 ± 6 · e / L)
+///
+/// When |e| > L / 6 the soil cannot take tension and the pressure is redistributed over a
+/// triangular contact length of 3 · (L / 2 − |e|):
+///
+/// qmax = 2 · V / (3 · B · (L / 2 − |e|)), qmin = 0
+///
+/// # Arguments
+/// * `foundation` - The combined footing, with its length along the column line.
+/// * `columns` - The two column loads, positioned from the left edge of the footing.
+/// * `allowable_bearing_capacity` - Allowable bearing capacity (t/m²).
+///
+/// # Returns
+/// * `CombinedFootingResult` with the pressure distribution and the bearing check.
+pub fn calc_combined_footing_pressure(
+    foundation: &Foundation,
+    columns: &[ColumnLoad; 2],
+    allowable_bearing_capacity: f64,
+) -> Result<CombinedFootingResult, ValidationError> {
+    validate_combined_input(foundation, columns, allowable_bearing_capacity)?;
+
+    let b = foundation.foundation_width.unwrap();
+    let l = foundation.foundation_length.unwrap();
+    let (total_load, resultant_position) = calc_resultant(columns);
+    let eccentricity = resultant_position - l / 2.0;
+    let abs_e = eccentricity.abs();
+
+    if total_load < 0.0 || abs_e >= l / 2.0 {
+        return Err(ValidationError {
+            code: "foundation_design.resultant_outside_footing".to_string(),
+            message: "Load resultant falls outside the footing.".to_string(),
+        });
+    }
+
+    let has_tension = abs_e > l / 6.0;
+    let (max_pressure, min_pressure, contact_length) = if has_tension {
+        let contact_length = 3.0 * (l / 2.0 - abs_e);
+        (
+            2.0 * total_load / (3.0 * b * (l / 2.0 - abs_e)),
+            0.0,
+            contact_length,
+        )
+    } else {
+        let q_avg = total_load / (b * l);
+        (
+            q_avg * (1.0 + 6.0 * abs_e / l),
+            q_avg * (1.0 - 6.0 * abs_e / l),
+            l,
+        )
+    };
+    let (left_edge_pressure, right_edge_pressure) = if eccentricity >= 0.0 {
+        (min_pressure, max_pressure)
+    } else {
+        (max_pressure, min_pressure)
+    };
+
+    Ok(CombinedFootingResult {
+        total_load,
+        resultant_position,
+        eccentricity,
+        left_edge_pressure,
+        right_edge_pressure,
+        max_pressure,
+        min_pressure,
+        contact_length,
+        has_tension,
+        allowable_bearing_capacity,
+        is_safe: max_pressure <= allowable_bearing_capacity,
+    })
+}
+
+/// Validates the input data for strap footing contact pressure.
+///
+/// # Arguments
+/// * `exterior` - The exterior footing, with its width along the strap.
+/// * `interior` - The interior footing, with its width along the strap.
+/// * `columns` - The exterior and interior column loads.
+/// * `allowable_bearing_capacity` - Allowable bearing capacity (t/m²).
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_strap_input(
+    exterior: &Foundation,
+    interior: &Foundation,
+    columns: &[ColumnLoad; 2],
+    allowable_bearing_capacity: f64,
+) -> Result<(), ValidationError> {
+    exterior.validate(&["foundation_width", "foundation_length"])?;
+    interior.validate(&["foundation_width", "foundation_length"])?;
+    validate_loads(columns, allowable_bearing_capacity)?;
+
+    if columns[1].position.unwrap() <= exterior.foundation_width.unwrap() / 2.0 {
+        return Err(ValidationError {
+            code: "foundation_design.interior_column.inside_exterior_footing".to_string(),
+            message: "Interior column must lie beyond the centre of the exterior footing."
+                .to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Calculates the contact pressures under a strap footing.
+///
+/// The exterior footing starts at the property line (x = 0) with its centre at B1 / 2, and the
+/// interior footing is centred under the interior column at x2. The rigid strap balances the
+/// eccentric exterior column, so the footing reactions follow from statics:
+///
+/// * R1 = V · (x2 − xR) / (x2 − B1 / 2)
+/// * R2 = V − R1
+///
+/// where xR is the position of the load resultant. Each footing carries a uniform pressure
+/// R / (B · L). A negative R2 means the interior column cannot hold the strap down.
+///
+/// # Arguments
+/// * `exterior` - The exterior footing, with its width along the strap.
+/// * `interior` - The interior footing, with its width along the strap.
+/// * `columns` - The exterior and interior column loads, positioned from the property line.
+/// * `allowable_bearing_capacity` - Allowable bearing capacity (t/m²).
+///
+/// # Returns
+/// * `StrapFootingResult` with the footing reactions, pressures and the bearing check.
+pub fn calc_strap_footing_pressure(
+    exterior: &Foundation,
+    interior: &Foundation,
+    columns: &[ColumnLoad; 2],
+    allowable_bearing_capacity: f64,
+) -> Result<StrapFootingResult, ValidationError> {
+    validate_strap_input(exterior, interior, columns, allowable_bearing_capacity)?;
+
+    let b1 = exterior.foundation_width.unwrap();
+    let l1 = exterior.foundation_length.unwrap();
+    let b2 = interior.foundation_width.unwrap();
+    let l2 = interior.foundation_length.unwrap();
+    let x2 = columns[1].position.unwrap();
+
+    let (total_load, resultant_position) = calc_resultant(columns);
+    let exterior_reaction = total_load * (x2 - resultant_position) / (x2 - b1 / 2.0);
+    let interior_reaction = total_load - exterior_reaction;
+    let exterior_pressure = exterior_reaction / (b1 * l1);
+    let interior_pressure = interior_reaction / (b2 * l2);
+
+    Ok(StrapFootingResult {
+        exterior_reaction,
+        interior_reaction,
+        exterior_pressure,
+        interior_pressure,
+        strap_shear: exterior_reaction - columns[0].load.unwrap(),
+        has_tension: exterior_reaction < 0.0 || interior_reaction < 0.0,
+        allowable_bearing_capacity,
+        is_safe: exterior_pressure.max(interior_pressure) <= allowable_bearing_capacity,
+    })
+}
+
+/// Validates the input data for mat foundation contact pressure.
+///
+/// # Arguments
+/// * `foundation` - The mat, with its width along x and length along y.
+/// * `columns` - The column loads on the mat.
+/// * `subgrade_moduli` - Modulus of subgrade reaction of each zone, rows along the length (t/m³).
+/// * `allowable_bearing_capacity` - Allowable bearing capacity (t/m²).
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_mat_input(
+    foundation: &Foundation,
+    columns: &[MatColumnLoad],
+    subgrade_moduli: &[Vec<f64>],
+    allowable_bearing_capacity: f64,
+) -> Result<(), ValidationError> {
+    foundation.validate(&["foundation_width", "foundation_length"])?;
+    let width = foundation.foundation_width.unwrap();
+    let length = foundation.foundation_length.unwrap();
+
+    for column in columns {
+        column.validate(&["load"])?;
+        if column.moment_x.is_some() {
+            column.validate(&["moment_x"])?;
+        }
+        if column.moment_y.is_some() {
+            column.validate(&["moment_y"])?;
+        }
+        validate_field("x", column.x, Some(0.0), Some(width), "mat_column_load")?;
+        validate_field("y", column.y, Some(0.0), Some(length), "mat_column_load")?;
+    }
+    if columns
+        .iter()
+        .map(|column| column.load.unwrap())
+        .sum::<f64>()
+        <= 0.0
+    {
+        return Err(ValidationError {
+            code: "foundation_design.total_load.zero".to_string(),
+            message: "Total column load must be greater than zero.".to_string(),
+        });
+    }
+
+    let zone_count_x = subgrade_moduli.first().map_or(0, |row| row.len());
+    if zone_count_x == 0 || subgrade_moduli.iter().any(|row| row.len() != zone_count_x) {
+        return Err(ValidationError {
+            code: "foundation_design.subgrade_moduli.invalid_grid".to_string(),
+            message: "Subgrade moduli must form a non-empty rectangular grid of zones.".to_string(),
+        });
+    }
+    for &ks in subgrade_moduli.iter().flatten() {
+        validate_field(
+            "subgrade_modulus",
+            Some(ks),
+            Some(0.0001),
+            None,
+            "foundation_design",
+        )?;
+    }
+    validate_field(
+        "allowable_bearing_capacity",
+        Some(allowable_bearing_capacity),
+        Some(0.0001),
+        None,
+        "foundation_design",
+    )?;
+
+    Ok(())
+}
+
+/// Calculates the zone contact pressures of a mat foundation by the conventional rigid method.
+///
+/// The mat is divided into the zone grid of the subgrade moduli, and the pressure at each zone
+/// centre (x, y), measured from the mat centre, is
+///
+/// q = Q / A + Q · ey · y / Ix + Q · ex · x / Iy
+///
+/// with Ix = B · L³ / 12 and Iy = L · B³ / 12. The eccentricities of the resultant include the
+/// column moments, e.g. ex = (Σ P · x + Σ Mx) / Σ P − B / 2. Each zone settles by q / ks and is checked against
+/// the allowable bearing capacity. Negative pressures flag zones where the rigid method does not
+/// hold and the mat lifts off.
+///
+/// # Arguments
+/// * `foundation` - The mat, with its width along x and length along y.
+/// * `columns` - The column loads on the mat.
+/// * `subgrade_moduli` - Modulus of subgrade reaction of each zone, rows along the length (t/m³).
+/// * `allowable_bearing_capacity` - Allowable bearing capacity (t/m²).
+///
+/// # Returns
+/// * `MatPressureResult` with the zone pressure, settlement and utilization maps.
+pub fn calc_mat_pressure(
+    foundation: &Foundation,
+    columns: &[MatColumnLoad],
+    subgrade_moduli: &[Vec<f64>],
+    allowable_bearing_capacity: f64,
+) -> Result<MatPressureResult, ValidationError> {
+    validate_mat_input(
+        foundation,
+        columns,
+        subgrade_moduli,
+        allowable_bearing_capacity,
+    )?;
+
+    let b = foundation.foundation_width.unwrap();
+    let l = foundation.foundation_length.unwrap();
+    let total_load: f64 = columns.iter().map(|c| c.load.unwrap()).sum();
+    let eccentricity_x = columns
+        .iter()
+        .map(|c| c.load.unwrap() * c.x.unwrap() + c.moment_x.unwrap_or(0.0))
+        .sum::<f64>()
+        / total_load
+        - b / 2.0;
+    let eccentricity_y = columns
+        .iter()
+        .map(|c| c.load.unwrap() * c.y.unwrap() + c.moment_y.unwrap_or(0.0))
+        .sum::<f64>()
+        / total_load
+        - l / 2.0;
+
+    let area = b * l;
+    let ix = b * l.powi(3) / 12.0;
+    let iy = l * b.powi(3) / 12.0;
+    let ny = subgrade_moduli.len();
+    let nx = subgrade_moduli[0].len();
+
+    let zone_pressures: Vec<Vec<f64>> = (0..ny)
+        .map(|j| {
+            let y = (j as f64 + 0.5) * l / ny as f64 - l / 2.0;
+            (0..nx)
+                .map(|i| {
+                    let x = (i as f64 + 0.5) * b / nx as f64 - b / 2.0;
+                    total_load / area
+                        + total_load * eccentricity_y * y / ix
+                        + total_load * eccentricity_x * x / iy
+                })
+                .collect()
+        })
+        .collect();
+    let zone_settlements = zone_pressures
+        .iter()
+        .zip(subgrade_moduli)
+        .map(|(row, ks_row)| {
+            row.iter()
+                .zip(ks_row)
+                .map(|(q, ks)| 100.0 * q / ks)
+                .collect()
+        })
+        .collect();
+    let zone_utilizations: Vec<Vec<f64>> = zone_pressures
+        .iter()
+        .map(|row| row.iter().map(|q| q / allowable_bearing_capacity).collect())
+        .collect();
+
+    let pressures = zone_pressures.iter().flatten();
+    let max_pressure = pressures.clone().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let min_pressure = pressures.cloned().fold(f64::INFINITY, f64::min);
+    let max_utilization = max_pressure / allowable_bearing_capacity;
+
+    Ok(MatPressureResult {
+        total_load,
+        eccentricity_x,
+        eccentricity_y,
+        zone_pressures,
+        zone_settlements,
+        zone_utilizations,
+        max_pressure,
+        min_pressure,
+        max_utilization,
+        has_tension: min_pressure < 0.0,
+        allowable_bearing_capacity,
+        is_safe: max_utilization <= 1.0,
+    })
+}
+
+/// Validates the input data for footing pressures from a column schedule.
+///
+/// # Arguments
+/// * `schedule` - The column schedule.
+/// * `footings` - The footing under each column, in the order of the schedule.
+/// * `load_case` - Load case of the column forces.
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_schedule_input(
+    schedule: &ColumnSchedule,
+    footings: &[Foundation],
+    load_case: LoadCase,
+) -> Result<(), ValidationError> {
+    schedule.validate(load_case)?;
+    if footings.len() != schedule.columns.len() {
+        return Err(ValidationError {
+            code: "foundation_design.footings.count_mismatch".to_string(),
+            message: format!(
+                "Expected {} footings for the column schedule, got {}.",
+                schedule.columns.len(),
+                footings.len()
+            ),
+        });
+    }
+    for footing in footings {
+        footing.validate(&["foundation_width", "foundation_length"])?;
+    }
+
+    Ok(())
+}
+
+/// Calculates the contact pressures of the isolated footings of a column schedule.
+///
+/// Each footing is centred on its column and loaded by the column forces of the load case, with
+/// ex = Mx / V and ey = My / V. The corner pressures of the linear distribution are
+///
+/// q = V / (B · L) · (1 ± 6 · ex / B ± 6 · ey / L)
+///
+/// and the effective pressure V / A' uses the effective area of the footing (see
+/// `Foundation::calc_effective_area`). The self weight of the footing and the backfill is not
+/// included.
+///
+/// # Arguments
+/// * `schedule` - The column schedule.
+/// * `footings` - The footing under each column, in the order of the schedule.
+/// * `load_case` - Load case of the column forces.
+///
+/// # Returns
+/// * `Vec<FootingPressureResult>`: One result per column, in the order of the schedule.
+pub fn calc_footing_pressures(
+    schedule: &ColumnSchedule,
+    footings: &[Foundation],
+    load_case: LoadCase,
+) -> Result<Vec<FootingPressureResult>, ValidationError> {
+    validate_schedule_input(schedule, footings, load_case)?;
+
+    let results = schedule
+        .columns
+        .iter()
+        .zip(footings)
+        .map(|(column, footing)| {
+            let loads = column.get_loads(load_case).unwrap();
+            let vertical_load = loads.vertical_load.unwrap();
+            let (eccentricity_x, eccentricity_y) = loads.calc_eccentricity();
+
+            let b = footing.foundation_width.unwrap();
+            let l = footing.foundation_length.unwrap();
+            let average_pressure = vertical_load / (b * l);
+            let spread = 6.0 * eccentricity_x.abs() / b + 6.0 * eccentricity_y.abs() / l;
+            let min_pressure = average_pressure * (1.0 - spread);
+
+            let effective_area = footing
+                .clone()
+                .calc_effective_area(eccentricity_x, eccentricity_y)
+                .effective_area;
+            let effective_pressure = if effective_area > 0.0 {
+                vertical_load / effective_area
+            } else {
+                f64::INFINITY
+            };
+
+            FootingPressureResult {
+                column_id: column.id.clone(),
+                vertical_load,
+                eccentricity_x,
+                eccentricity_y,
+                average_pressure,
+                max_pressure: average_pressure * (1.0 + spread),
+                min_pressure,
+                effective_pressure,
+                has_tension: min_pressure < 0.0,
+            }
+        })
+        .collect();
+
+    Ok(results)
+}
+
+/// Calculates the zone contact pressures of a mat foundation under the columns of a schedule.
+///
+/// The column coordinates are converted to the mat axes by subtracting the coordinates of the
+/// lower left corner of the mat, and the axial loads and moments of the load case are passed to
+/// `calc_mat_pressure`, which adds the moments to the eccentricities of the resultant.
+///
+/// # Arguments
+/// * `schedule` - The column schedule.
+/// * `load_case` - Load case of the column forces.
+/// * `foundation` - The mat, with its width along x and length along y.
+/// * `origin` - Coordinates (x, y) of the lower left corner of the mat in the schedule (m).
+/// * `subgrade_moduli` - Modulus of subgrade reaction of each zone, rows along the length (t/m³).
+/// * `allowable_bearing_capacity` - Allowable bearing capacity (t/m²).
+///
+/// # Returns
+/// * `MatPressureResult` with the zone pressure, settlement and utilization maps.
+pub fn calc_schedule_mat_pressure(
+    schedule: &ColumnSchedule,
+    load_case: LoadCase,
+    foundation: &Foundation,
+    origin: (f64, f64),
+    subgrade_moduli: &[Vec<f64>],
+    allowable_bearing_capacity: f64,
+) -> Result<MatPressureResult, ValidationError> {
+    schedule.validate(load_case)?;
+
+    let columns: Vec<MatColumnLoad> = schedule
+        .columns
+        .iter()
+        .map(|column| {
+            let forces = column.get_load_case(load_case).unwrap();
+            MatColumnLoad {
+                moment_x: forces.moment_x,
+                moment_y: forces.moment_y,
+                ..MatColumnLoad::new(
+                    column.x.unwrap() - origin.0,
+                    column.y.unwrap() - origin.1,
+                    forces.axial_load.unwrap(),
+                )
+            }
+        })
+        .collect();
+
+    calc_mat_pressure(
+        foundation,
+        &columns,
+        subgrade_moduli,
+        allowable_bearing_capacity,
+    )
+}
@@ -0,0 +1,357 @@
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+use crate::{
+    constants::KPA_TO_TON,
+    enums::{AnchorGroundType, GroutingMethod},
+    models::{deadman::Deadman, ground_anchor::GroundAnchor, soil_profile::SoilProfile},
+    validation::{validate_field, ValidationError},
+};
+
+/// Minimum free (unbonded) length of a strand anchor (m).
+const MIN_FREE_LENGTH: f64 = 4.5;
+
+/// Minimum fixed (bonded) length of an anchor in soil (m).
+const MIN_FIXED_LENGTH: f64 = 4.5;
+
+/// Maximum fixed (bonded) length beyond which bond is not mobilized effectively (m).
+const MAX_FIXED_LENGTH: f64 = 12.0;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroundAnchorResult {
+    /// Ultimate bond stress at the grout/ground interface (t/m²).
+    pub bond_stress: f64,
+    /// Weighted friction angle of the retained soil (degrees).
+    pub friction_angle: f64,
+    /// Fixed length required by the bond capacity (m).
+    pub required_fixed_length: f64,
+    /// Adopted fixed length, not less than the minimum (m).
+    pub fixed_length: f64,
+    /// Indicates whether the required fixed length exceeds the practical maximum.
+    pub exceeds_max_fixed_length: bool,
+    /// Distance along the anchor from the head to the active failure plane (m).
+    pub wedge_length: f64,
+    /// Adopted free length (m).
+    pub free_length: f64,
+    /// Total anchor length (m).
+    pub total_length: f64,
+    /// Lower bound of the recommended lock-off load (t).
+    pub lock_off_load_min: f64,
+    /// Upper bound of the recommended lock-off load (t).
+    pub lock_off_load_max: f64,
+    /// Proof test load (t).
+    pub proof_test_load: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeadmanResult {
+    /// Weighted friction angle of the soil (degrees).
+    pub friction_angle: f64,
+    /// Rankine active earth pressure coefficient.
+    pub ka: f64,
+    /// Rankine passive earth pressure coefficient.
+    pub kp: f64,
+    /// Passive force in front of the deadman per unit length (t/m).
+    pub passive_force: f64,
+    /// Active force behind the deadman per unit length (t/m).
+    pub active_force: f64,
+    /// Resistance from the end effects of a deadman of finite length (t).
+    pub end_resistance: f64,
+    /// Ultimate capacity of the deadman (t).
+    pub ultimate_capacity: f64,
+    /// Allowable capacity of the deadman (t).
+    pub allowable_capacity: f64,
+    /// Indicates whether the deadman extends close enough to the surface (h ≥ H/2) to be
+    /// treated as extending to the ground surface.
+    pub is_near_surface: bool,
+    /// Minimum distance from the wall for the passive wedge of the deadman to stay beyond the
+    /// active wedge of the wall (m).
+    pub required_distance: f64,
+    /// Indicates whether the deadman is located beyond the active wedge of the wall.
+    pub is_beyond_failure_plane: bool,
+}
+
+/// Returns the range of ultimate bond stress (kPa) for a given ground type.
+///
+/// Values are from FHWA-IF-99-015 (Sabatini et al., 1999).
+///
+/// # Arguments
+/// * `ground_type` - Ground type around the fixed length.
+///
+/// # Returns
+/// * `(f64, f64)`: Lower and upper bound of the bond stress in kPa.
+pub fn get_bond_stress_range(ground_type: AnchorGroundType) -> (f64, f64) {
+    match ground_type {
+        AnchorGroundType::SiltClayMixture => (30.0, 70.0),
+        AnchorGroundType::StiffHighPlasticityClay => (30.0, 100.0),
+        AnchorGroundType::StiffMediumPlasticityClay => (100.0, 250.0),
+        AnchorGroundType::VeryStiffSandySilt => (280.0, 380.0),
+        AnchorGroundType::FineMediumSand => (80.0, 380.0),
+        AnchorGroundType::MediumCoarseSandWithGravel => (110.0, 670.0),
+        AnchorGroundType::SiltySand => (170.0, 400.0),
+        AnchorGroundType::SandyGravel => (215.0, 950.0),
+        AnchorGroundType::GlacialTill => (300.0, 520.0),
+        AnchorGroundType::SoftShale => (200.0, 800.0),
+        AnchorGroundType::Sandstone => (800.0, 1700.0),
+        AnchorGroundType::SoftLimestone => (1000.0, 1400.0),
+        AnchorGroundType::GraniteBasalt => (1700.0, 3100.0),
+    }
+}
+
+/// Returns the ultimate bond stress (t/m²) for a given ground type and grouting method.
+///
+/// Gravity grouting uses the lower bound of the range, low pressure grouting the mean
+/// and high pressure grouting the upper bound.
+///
+/// # Arguments
+/// * `ground_type` - Ground type around the fixed length.
+/// * `grouting_method` - Grouting method of the fixed length.
+///
+/// # Returns
+/// * `f64`: Ultimate bond stress in t/m².
+pub fn get_bond_stress(ground_type: AnchorGroundType, grouting_method: GroutingMethod) -> f64 {
+    let (lower, upper) = get_bond_stress_range(ground_type);
+
+    let bond_stress = match grouting_method {
+        GroutingMethod::Gravity => lower,
+        GroutingMethod::LowPressure => (lower + upper) / 2.0,
+        GroutingMethod::HighPressure => upper,
+    };
+
+    bond_stress * KPA_TO_TON
+}
+
+/// Calculates the thickness-weighted effective friction angle between the surface and a given depth.
+fn get_weighted_friction_angle(soil_profile: &SoilProfile, depth_limit: f64) -> f64 {
+    let mut prev_depth = 0.0;
+    let mut phi_sum = 0.0;
+
+    for layer in soil_profile.layers.iter() {
+        let bottom = layer.depth.unwrap().min(depth_limit);
+        phi_sum += layer.phi_prime.unwrap() * (bottom - prev_depth);
+        prev_depth = bottom;
+
+        if layer.depth.unwrap() >= depth_limit {
+            break;
+        }
+    }
+
+    phi_sum / prev_depth
+}
+
+/// Validates the input data for ground anchor design.
+///
+/// # Arguments
+/// * `anchor` - The ground anchor data.
+/// * `soil_profile` - The retained soil profile.
+/// * `wall_height` - Excavation (wall) height (m).
+/// * `factor_of_safety` - Safety factor on the bond capacity.
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(
+    anchor: &GroundAnchor,
+    soil_profile: &SoilProfile,
+    wall_height: f64,
+    factor_of_safety: f64,
+) -> Result<(), ValidationError> {
+    anchor.validate(&[
+        "design_load",
+        "drill_hole_diameter",
+        "inclination",
+        "head_depth",
+    ])?;
+    soil_profile.validate(&["thickness", "phi_prime"])?;
+    validate_field(
+        "wall_height",
+        Some(wall_height),
+        Some(0.0001),
+        None,
+        "ground_anchor",
+    )?;
+    validate_field(
+        "factor_of_safety",
+        Some(factor_of_safety),
+        Some(1.0),
+        None,
+        "ground_anchor",
+    )?;
+
+    if anchor.head_depth.unwrap() >= wall_height {
+        return Err(ValidationError {
+            code: "ground_anchor.head_depth.greater_than_wall_height".to_string(),
+            message: "Anchor head must be located above the bottom of the excavation.".to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Designs the free and fixed lengths of a ground anchor.
+///
+/// * The fixed length is obtained from the bond capacity: Lb = T · FS / (π · D · τ).
+/// * The free length reaches the active failure plane inclined at 45 + φ/2 from the horizontal
+///   through the bottom of the excavation, plus a margin of max(1.5 m, 0.2H), and is not less than 4.5 m.
+/// * Lock-off is recommended between 75% and 100% of the design load, and the proof test load is
+///   133% of the design load (FHWA-IF-99-015).
+///
+/// # Arguments
+/// * `anchor` - The ground anchor data.
+/// * `soil_profile` - The retained soil profile.
+/// * `wall_height` - Excavation (wall) height (m).
+/// * `factor_of_safety` - Safety factor on the bond capacity.
+///
+/// # Returns
+/// * `GroundAnchorResult` with the anchor geometry and loads.
+pub fn calc_anchor_design(
+    anchor: &GroundAnchor,
+    soil_profile: &mut SoilProfile,
+    wall_height: f64,
+    factor_of_safety: f64,
+) -> Result<GroundAnchorResult, ValidationError> {
+    validate_input(anchor, soil_profile, wall_height, factor_of_safety)?;
+    soil_profile.calc_layer_depths();
+
+    let design_load = anchor.design_load.unwrap();
+    let diameter = anchor.drill_hole_diameter.unwrap();
+    let inclination = anchor.inclination.unwrap().to_radians();
+    let head_depth = anchor.head_depth.unwrap();
+
+    // Fixed length from bond capacity
+    let bond_stress = get_bond_stress(anchor.ground_type, anchor.grouting_method);
+    let required_fixed_length = design_load * factor_of_safety / (PI * diameter * bond_stress);
+    let fixed_length = required_fixed_length.max(MIN_FIXED_LENGTH);
+
+    // Free length from the active wedge
+    let phi = get_weighted_friction_angle(soil_profile, wall_height);
+    let tan_wedge = (45.0 - phi / 2.0).to_radians().tan();
+    let wedge_length = (wall_height - head_depth) * tan_wedge
+        / (inclination.cos() + inclination.sin() * tan_wedge);
+    let free_length = (wedge_length + f64::max(1.5, 0.2 * wall_height)).max(MIN_FREE_LENGTH);
+
+    Ok(GroundAnchorResult {
+        bond_stress,
+        friction_angle: phi,
+        required_fixed_length,
+        fixed_length,
+        exceeds_max_fixed_length: required_fixed_length > MAX_FIXED_LENGTH,
+        wedge_length,
+        free_length,
+        total_length: free_length + fixed_length,
+        lock_off_load_min: 0.75 * design_load,
+        lock_off_load_max: design_load,
+        proof_test_load: 1.33 * design_load,
+    })
+}
+
+/// Validates the input data for deadman anchor design.
+///
+/// # Arguments
+/// * `deadman` - The deadman data.
+/// * `soil_profile` - The retained soil profile.
+/// * `wall_depth` - Depth of the wall toe below the retained ground surface (m).
+/// * `factor_of_safety` - Safety factor on the deadman capacity.
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_deadman_input(
+    deadman: &Deadman,
+    soil_profile: &SoilProfile,
+    wall_depth: f64,
+    factor_of_safety: f64,
+) -> Result<(), ValidationError> {
+    deadman.validate(&["height", "depth", "length", "distance"])?;
+    soil_profile.validate(&[
+        "thickness",
+        "dry_unit_weight",
+        "saturated_unit_weight",
+        "phi_prime",
+    ])?;
+    validate_field(
+        "wall_depth",
+        Some(wall_depth),
+        Some(0.0001),
+        None,
+        "ground_anchor",
+    )?;
+    validate_field(
+        "factor_of_safety",
+        Some(factor_of_safety),
+        Some(1.0),
+        None,
+        "ground_anchor",
+    )?;
+
+    if deadman.depth.unwrap() > soil_profile.get_total_depth() {
+        return Err(ValidationError {
+            code: "ground_anchor.deadman.depth_exceeds_soil_profile".to_string(),
+            message: "Deadman depth is greater than the soil profile depth.".to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Designs a deadman anchor of a tied-back sheet pile wall (Teng, 1962; as given by Das).
+///
+/// * The capacity per unit length is the difference of the Rankine passive and active forces over
+///   the depth H of the deadman bottom: Pp − Pa = ½ · σ'v(H) · H · (Kp − Ka).
+/// * For a deadman of finite length L, the end effects add
+///   (1/3) · K0 · σ'v(H) · (√Kp + √Ka) · H² · tan φ, with K0 = 1 − sin φ.
+/// * The deadman must be beyond the active wedge of the wall, inclined at 45 + φ/2 from the
+///   horizontal through the wall toe, so that its passive wedge does not intersect it:
+///   distance ≥ Hw · tan(45 − φ/2) + H · tan(45 + φ/2).
+///
+/// # Arguments
+/// * `deadman` - The deadman data.
+/// * `soil_profile` - The retained soil profile.
+/// * `wall_depth` - Depth of the wall toe below the retained ground surface (m).
+/// * `factor_of_safety` - Safety factor on the deadman capacity.
+///
+/// # Returns
+/// * `DeadmanResult` with the capacity and the location check.
+pub fn calc_deadman_capacity(
+    deadman: &Deadman,
+    soil_profile: &mut SoilProfile,
+    wall_depth: f64,
+    factor_of_safety: f64,
+) -> Result<DeadmanResult, ValidationError> {
+    validate_deadman_input(deadman, soil_profile, wall_depth, factor_of_safety)?;
+    soil_profile.calc_layer_depths();
+
+    let height = deadman.height.unwrap();
+    let depth = deadman.depth.unwrap();
+    let length = deadman.length.unwrap();
+
+    let phi = get_weighted_friction_angle(soil_profile, depth);
+    let phi_rad = phi.to_radians();
+    let ka = (45.0 - phi / 2.0).to_radians().tan().powi(2);
+    let kp = (45.0 + phi / 2.0).to_radians().tan().powi(2);
+    let k0 = 1.0 - phi_rad.sin();
+
+    let vertical_stress = soil_profile.calc_effective_stress(depth);
+    let passive_force = 0.5 * vertical_stress * depth * kp;
+    let active_force = 0.5 * vertical_stress * depth * ka;
+    let end_resistance =
+        k0 * vertical_stress * (kp.sqrt() + ka.sqrt()) * depth.powi(2) * phi_rad.tan() / 3.0;
+    let ultimate_capacity = length * (passive_force - active_force) + end_resistance;
+
+    let wall_phi =
+        get_weighted_friction_angle(soil_profile, wall_depth.min(soil_profile.get_total_depth()));
+    let required_distance = wall_depth * (45.0 - wall_phi / 2.0).to_radians().tan()
+        + depth * (45.0 + phi / 2.0).to_radians().tan();
+
+    Ok(DeadmanResult {
+        friction_angle: phi,
+        ka,
+        kp,
+        passive_force,
+        active_force,
+        end_resistance,
+        ultimate_capacity,
+        allowable_capacity: ultimate_capacity / factor_of_safety,
+        is_near_surface: height >= depth / 2.0,
+        required_distance,
+        is_beyond_failure_plane: deadman.distance.unwrap() >= required_distance,
+    })
+}
@@ -0,0 +1,178 @@
+use serde::Serialize;
+
+use crate::{
+    enums::ChemicalExposureClass,
+    models::{groundwater::GroundwaterSample, soil_profile::SoilProfile},
+    validation::ValidationError,
+};
+
+/// Sulfate content above which the groundwater is outside EN 206, Table 2 (mg/l).
+pub const MAX_TABULATED_SULFATE: f64 = 6000.0;
+/// pH below which the groundwater is outside EN 206, Table 2.
+pub const MIN_TABULATED_PH: f64 = 4.0;
+
+/// Chloride content at or above which groundwater is corrosive to steel (mg/l).
+pub const CORROSIVE_CHLORIDE: f64 = 500.0;
+/// Sulfate content at or above which groundwater is corrosive to steel (mg/l).
+pub const CORROSIVE_SULFATE: f64 = 1500.0;
+/// pH at or below which groundwater is corrosive to steel.
+pub const CORROSIVE_PH: f64 = 5.5;
+
+/// Aggressivity of a single groundwater sample.
+#[derive(Debug, Clone, Serialize)]
+pub struct GroundwaterSampleResult {
+    /// Sampling depth (m).
+    pub depth: f64,
+    /// Sampling date.
+    pub date: Option<String>,
+    /// Exposure class from the sulfate content.
+    pub sulfate_class: ChemicalExposureClass,
+    /// Exposure class from the pH.
+    pub ph_class: ChemicalExposureClass,
+    /// Governing exposure class of the sample.
+    pub exposure_class: ChemicalExposureClass,
+    /// Indicates whether the sample is corrosive to steel.
+    pub is_corrosive: bool,
+    /// Indicates whether the sample is beyond the limits of EN 206, Table 2, so that a special
+    /// study is required.
+    pub requires_special_study: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GroundwaterAggressivityResult {
+    /// Results of the samples, in the order of the soil profile.
+    pub samples: Vec<GroundwaterSampleResult>,
+    /// Governing (most severe) exposure class over the samples.
+    pub exposure_class: ChemicalExposureClass,
+    /// Indicates whether any sample is corrosive to steel.
+    pub is_corrosive: bool,
+    /// Indicates whether any sample requires a special study.
+    pub requires_special_study: bool,
+    /// Highest total dissolved solids of the samples, if measured (mg/l).
+    pub max_total_dissolved_solids: Option<f64>,
+}
+
+/// Classifies the sulfate content of groundwater (EN 206, Table 2).
+///
+/// # Arguments
+/// * `sulfate` - Sulfate (SO₄²⁻) content (mg/l).
+///
+/// # Returns
+/// * `ChemicalExposureClass`: XA1 from 200, XA2 above 600 and XA3 above 3000 mg/l.
+pub fn classify_sulfate(sulfate: f64) -> ChemicalExposureClass {
+    if sulfate > 3000.0 {
+        ChemicalExposureClass::Xa3
+    } else if sulfate > 600.0 {
+        ChemicalExposureClass::Xa2
+    } else if sulfate >= 200.0 {
+        ChemicalExposureClass::Xa1
+    } else {
+        ChemicalExposureClass::None
+    }
+}
+
+/// Classifies the pH of groundwater (EN 206, Table 2).
+///
+/// # Arguments
+/// * `ph` - pH of the water.
+///
+/// # Returns
+/// * `ChemicalExposureClass`: XA1 at or below 6.5, XA2 below 5.5 and XA3 below 4.5.
+pub fn classify_ph(ph: f64) -> ChemicalExposureClass {
+    if ph < 4.5 {
+        ChemicalExposureClass::Xa3
+    } else if ph < 5.5 {
+        ChemicalExposureClass::Xa2
+    } else if ph <= 6.5 {
+        ChemicalExposureClass::Xa1
+    } else {
+        ChemicalExposureClass::None
+    }
+}
+
+/// Returns whether a groundwater sample is corrosive to steel (Caltrans, 2021).
+///
+/// The water is corrosive when the chloride content is at least 500 mg/l, the sulfate content
+/// is at least 1500 mg/l or the pH is 5.5 or less.
+///
+/// # Arguments
+/// * `sample` - The groundwater sample.
+pub fn is_corrosive(sample: &GroundwaterSample) -> bool {
+    sample.chloride.is_some_and(|cl| cl >= CORROSIVE_CHLORIDE)
+        || sample.sulfate.is_some_and(|so4| so4 >= CORROSIVE_SULFATE)
+        || sample.ph.is_some_and(|ph| ph <= CORROSIVE_PH)
+}
+
+/// Validates the input data for groundwater aggressivity.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile with the groundwater samples.
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(soil_profile: &SoilProfile) -> Result<(), ValidationError> {
+    if soil_profile.groundwater_samples.is_empty() {
+        return Err(ValidationError {
+            code: "groundwater.empty_samples".into(),
+            message: "No groundwater samples provided for the soil profile.".into(),
+        });
+    }
+    for sample in &soil_profile.groundwater_samples {
+        sample.validate(&["depth", "ph", "sulfate", "chloride"])?;
+        if sample.total_dissolved_solids.is_some() {
+            sample.validate(&["total_dissolved_solids"])?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Classifies the chemical aggressivity of the groundwater samples of a soil profile.
+///
+/// Each sample is classified for concrete by its sulfate content and pH (EN 206, Table 2), the
+/// more severe class governing, and checked for corrosivity to steel. Samples with more than
+/// 6000 mg/l sulfate or a pH below 4.0 are outside the table and flagged for a special study.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile with the groundwater samples.
+///
+/// # Returns
+/// * `GroundwaterAggressivityResult` with the per-sample and governing classes.
+pub fn calc_groundwater_aggressivity(
+    soil_profile: &SoilProfile,
+) -> Result<GroundwaterAggressivityResult, ValidationError> {
+    validate_input(soil_profile)?;
+
+    let samples: Vec<GroundwaterSampleResult> = soil_profile
+        .groundwater_samples
+        .iter()
+        .map(|sample| {
+            let sulfate = sample.sulfate.unwrap();
+            let ph = sample.ph.unwrap();
+            let sulfate_class = classify_sulfate(sulfate);
+            let ph_class = classify_ph(ph);
+
+            GroundwaterSampleResult {
+                depth: sample.depth.unwrap(),
+                date: sample.date.clone(),
+                sulfate_class,
+                ph_class,
+                exposure_class: sulfate_class.max(ph_class),
+                is_corrosive: is_corrosive(sample),
+                requires_special_study: sulfate > MAX_TABULATED_SULFATE || ph < MIN_TABULATED_PH,
+            }
+        })
+        .collect();
+
+    Ok(GroundwaterAggressivityResult {
+        exposure_class: samples.iter().map(|s| s.exposure_class).max().unwrap(),
+        is_corrosive: samples.iter().any(|s| s.is_corrosive),
+        requires_special_study: samples.iter().any(|s| s.requires_special_study),
+        max_total_dissolved_solids: soil_profile
+            .groundwater_samples
+            .iter()
+            .filter_map(|s| s.total_dissolved_solids)
+            .reduce(f64::max),
+        samples,
+    })
+}
@@ -37,3 +37,61 @@ pub fn interp1d(x_values: &[f64], y_values: &[f64], x: f64) -> f64 {
 
     panic!("Interpolation error: x-value out of interpolation range");
 }
+
+/// Averages the values of depth samples between two depths.
+///
+/// If no sample lies between the depths, the first sample below `top` is used, or the deepest
+/// sample when the data ends above `top`.
+///
+/// # Arguments
+/// * `samples` - `(depth, value)` pairs sorted by depth
+/// * `top` - Top depth of the window
+/// * `bottom` - Bottom depth of the window
+///
+/// # Returns
+/// * Average value, or `None` if there are no samples
+pub fn calc_window_average(samples: &[(f64, f64)], top: f64, bottom: f64) -> Option<f64> {
+    let values: Vec<f64> = samples
+        .iter()
+        .filter(|(depth, _)| *depth >= top && *depth <= bottom)
+        .map(|(_, value)| *value)
+        .collect();
+
+    if !values.is_empty() {
+        return Some(values.iter().sum::<f64>() / values.len() as f64);
+    }
+
+    samples
+        .iter()
+        .find(|(depth, _)| *depth >= top)
+        .or(samples.last())
+        .map(|(_, value)| *value)
+}
+
+/// Averages interval values between two depths, weighting each interval by its thickness
+/// within the window.
+///
+/// # Arguments
+/// * `intervals` - `(top, bottom, value)` triples
+/// * `top` - Top depth of the window
+/// * `bottom` - Bottom depth of the window
+///
+/// # Returns
+/// * Weighted average value, or `None` if no interval overlaps the window
+pub fn calc_thickness_weighted_average(
+    intervals: &[(f64, f64, f64)],
+    top: f64,
+    bottom: f64,
+) -> Option<f64> {
+    let mut weighted_sum = 0.0;
+    let mut total_thickness = 0.0;
+    for &(interval_top, interval_bottom, value) in intervals {
+        let thickness = interval_bottom.min(bottom) - interval_top.max(top);
+        if thickness > 0.0 {
+            weighted_sum += value * thickness;
+            total_thickness += thickness;
+        }
+    }
+
+    (total_thickness > 0.0).then(|| weighted_sum / total_thickness)
+}
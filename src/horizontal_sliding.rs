@@ -1,4 +1,5 @@
 use crate::{
+    enums::AnalysisTerm,
     models::{foundation::Foundation, loads::Loads, soil_profile::SoilProfile},
     validation::{validate_field, ValidationError},
 };
@@ -20,6 +21,10 @@ pub struct HorizontalSlidingResult {
     pub ac: f64,
     pub vth_x: f64,
     pub vth_y: f64,
+    /// Base friction coefficient used for the drained resistance.
+    pub friction_coefficient: f64,
+    /// Base adhesion ratio ca/c used for the undrained resistance.
+    pub adhesion_factor: f64,
 }
 
 /// Validates the input data for horizontal sliding calculations.
@@ -47,13 +52,18 @@ pub fn validate_input(
         "phi_prime",
         "phi_u",
     ])?;
-    foundation.validate(&[
-        "foundation_depth",
-        "foundation_width",
-        "foundation_length",
-        "surface_friction_coefficient",
-    ])?;
+    foundation.validate(&["foundation_depth", "foundation_width", "foundation_length"])?;
+    if foundation.downslope_embedment.is_some() {
+        foundation.validate(&["downslope_embedment"])?;
+    }
+    // The interface type stands in for a missing friction coefficient
+    if foundation.interface_type.is_none() || foundation.surface_friction_coefficient.is_some() {
+        foundation.validate(&["surface_friction_coefficient"])?;
+    }
     loads.validate(&["horizontal_load_x", "horizontal_load_y"])?;
+    if loads.surface_surcharge.is_some() {
+        loads.validate(&["surface_surcharge"])?;
+    }
 
     validate_field(
         "foundation_pressure",
@@ -67,7 +77,9 @@ pub fn validate_input(
 }
 
 /// Extracts cohesion, friction angle, and unit weight based on groundwater level and soil properties.
-fn get_soil_params(soil_profile: &SoilProfile, df: f64) -> (f64, f64, f64) {
+///
+/// Undrained parameters (cu, φu) are used when `undrained` is true, drained parameters otherwise.
+fn get_soil_params(soil_profile: &SoilProfile, df: f64, undrained: bool) -> (f64, f64, f64) {
     let layer = soil_profile.get_layer_at_depth(df);
 
     let c_prime = layer.c_prime.unwrap();
@@ -77,18 +89,27 @@ fn get_soil_params(soil_profile: &SoilProfile, df: f64) -> (f64, f64, f64) {
     let dry_unit_weight = layer.dry_unit_weight.unwrap();
     let saturated_unit_weight = layer.saturated_unit_weight.unwrap();
 
-    let (selected_unit_weight, selected_cohesion, selected_phi) =
-        if soil_profile.ground_water_level.unwrap() <= df {
-            (saturated_unit_weight - 1.0, cu, phi_u)
-        } else {
-            (dry_unit_weight, c_prime, phi_prime)
-        };
+    let selected_unit_weight = if soil_profile.ground_water_level.unwrap() <= df {
+        soil_profile
+            .get_environment()
+            .submerged_unit_weight(saturated_unit_weight)
+    } else {
+        dry_unit_weight
+    };
+    let (selected_cohesion, selected_phi) = if undrained {
+        (cu, phi_u)
+    } else {
+        (c_prime, phi_prime)
+    };
 
     (selected_cohesion, selected_phi, selected_unit_weight)
 }
 
 /// Calculates horizontal sliding stability based on foundation and soil parameters.
 ///
+/// The base is treated as undrained when the groundwater level is at or above the foundation
+/// depth. Use `calc_horizontal_sliding_by_term` to select the parameters by analysis term.
+///
 /// # Arguments
 ///
 /// * `soil_profile` - The soil profile containing soil layers and properties.
@@ -105,28 +126,87 @@ pub fn calc_horizontal_sliding(
     foundation_pressure: f64,
 ) -> Result<HorizontalSlidingResult, ValidationError> {
     validate_input(soil_profile, foundation, loads, foundation_pressure)?;
+    let df = foundation.foundation_depth.unwrap();
+    let undrained = soil_profile.ground_water_level.unwrap() <= df;
+
+    Ok(calc_sliding(
+        soil_profile,
+        foundation,
+        loads,
+        foundation_pressure,
+        undrained,
+    ))
+}
+
+/// Calculates horizontal sliding stability with the drained/undrained parameters selected by
+/// analysis term.
+///
+/// With `AnalysisTerm::Auto`, the choice is made from the soil type of the layer at the
+/// foundation base and the loading rate.
+///
+/// # Arguments
+///
+/// * `soil_profile` - The soil profile containing soil layers and properties.
+/// * `foundation` - The foundation parameters including dimensions and friction coefficient.
+/// * `loads` - The loads acting on the foundation.
+/// * `foundation_pressure` - The pressure exerted by the foundation on the soil.
+/// * `term` - Short, long or automatically selected term.
+///
+/// # Returns
+/// A `HorizontalSlidingResult` struct containing the calculated values and safety checks.
+pub fn calc_horizontal_sliding_by_term(
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    loads: &Loads,
+    foundation_pressure: f64,
+    term: AnalysisTerm,
+) -> Result<HorizontalSlidingResult, ValidationError> {
+    validate_input(soil_profile, foundation, loads, foundation_pressure)?;
+    let df = foundation.foundation_depth.unwrap();
+    let undrained = soil_profile.get_layer_at_depth(df).is_undrained(term);
+
+    Ok(calc_sliding(
+        soil_profile,
+        foundation,
+        loads,
+        foundation_pressure,
+        undrained,
+    ))
+}
+
+fn calc_sliding(
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    loads: &Loads,
+    foundation_pressure: f64,
+    undrained: bool,
+) -> HorizontalSlidingResult {
     let df = foundation.foundation_depth.unwrap();
     let b = foundation.foundation_width.unwrap();
     let l = foundation.foundation_length.unwrap();
 
     let vx = loads.horizontal_load_x.unwrap();
     let vy = loads.horizontal_load_y.unwrap();
-    let surface_friction = foundation.surface_friction_coefficient.unwrap();
-
     let ptv = foundation_pressure * b * l;
 
-    let (cohesion, phi, unit_weight) = get_soil_params(soil_profile, df);
+    let (cohesion, phi, unit_weight) = get_soil_params(soil_profile, df, undrained);
+    let surface_friction = foundation.calc_base_friction_coefficient(phi).unwrap();
+    let adhesion_factor = foundation.get_base_adhesion_factor();
 
     let kp = (f64::tan((45.0 + phi / 2.0) * PI / 180.0)).powi(2);
 
-    let rth = if soil_profile.ground_water_level.unwrap() > df {
-        ptv * surface_friction / 1.1
+    let rth = if undrained {
+        l * b * adhesion_factor * cohesion / 1.1
     } else {
-        l * b * cohesion / 1.1
+        ptv * surface_friction / 1.1
     };
 
-    let rpk_x = b * 0.5 * df.powi(2) * unit_weight * kp;
-    let rpk_y = l * 0.5 * df.powi(2) * unit_weight * kp;
+    // Only the soil cover on the downslope side mobilizes passive resistance
+    let embedment = foundation.calc_downslope_embedment();
+    let surcharge = loads.surface_surcharge.unwrap_or(0.0);
+    let passive_force = (0.5 * embedment.powi(2) * unit_weight + surcharge * embedment) * kp;
+    let rpk_x = b * passive_force;
+    let rpk_y = l * passive_force;
 
     let rpt_x = rpk_x / 1.4;
     let rpt_y = rpk_y / 1.4;
@@ -134,7 +214,7 @@ pub fn calc_horizontal_sliding(
     let sum_x = rth + 0.3 * rpt_x;
     let sum_y = rth + 0.3 * rpt_y;
 
-    Ok(HorizontalSlidingResult {
+    HorizontalSlidingResult {
         rth,
         ptv,
         rpk_x,
@@ -148,5 +228,7 @@ pub fn calc_horizontal_sliding(
         ac: l * b,
         vth_x: vx,
         vth_y: vy,
-    })
+        friction_coefficient: surface_friction,
+        adhesion_factor,
+    }
 }
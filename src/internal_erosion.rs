@@ -0,0 +1,126 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    models::{gradation::GradationCurve, soil_profile::SoilProfile},
+    validation::ValidationError,
+};
+
+/// Number of logarithmically spaced particle sizes at which the gradation curve is evaluated.
+const EVALUATION_POINTS: usize = 100;
+
+/// Boundary of the stable zone H / F (Kenney & Lau, 1986).
+const STABILITY_BOUNDARY: f64 = 1.0;
+
+/// Kenney–Lau shape curve point.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KenneyLauPoint {
+    /// Particle size d (mm).
+    pub size: f64,
+    /// Percent finer than d, F (%).
+    pub f: f64,
+    /// Percent between d and 4d, H (%).
+    pub h: f64,
+    /// Ratio H / F.
+    pub ratio: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InternalStabilityResult {
+    /// Uniformity coefficient D60 / D10, if it can be read from the curve.
+    pub uniformity_coefficient: Option<f64>,
+    /// Upper limit of F over which the shape curve is checked (%).
+    pub f_limit: f64,
+    /// Evaluated points of the shape curve.
+    pub points: Vec<KenneyLauPoint>,
+    /// Minimum H / F ratio within the checked range.
+    pub min_ratio: f64,
+    /// Indicates whether the soil is internally stable (not susceptible to suffusion).
+    pub is_stable: bool,
+}
+
+/// Checks the internal stability of a soil with the Kenney–Lau method.
+///
+/// The shape curve H = F(4d) − F(d) is compared with the boundary H = 1.0 · F (Kenney & Lau, 1986)
+/// over the finer part of the gradation: F ≤ 20% for widely graded soils (Cu > 3) and F ≤ 30% for
+/// narrowly graded soils. A soil whose shape curve falls below the boundary lacks enough
+/// intermediate particles to hold its fines in place and is susceptible to suffusion.
+///
+/// # Arguments
+/// * `gradation` - The gradation curve of the soil.
+///
+/// # Returns
+/// * `InternalStabilityResult` with the shape curve and stability check.
+pub fn calc_internal_stability(
+    gradation: &GradationCurve,
+) -> Result<InternalStabilityResult, ValidationError> {
+    gradation.validate()?;
+
+    let uniformity_coefficient = gradation.uniformity_coefficient();
+    let f_limit = match uniformity_coefficient {
+        Some(cu) if cu <= 3.0 => 30.0,
+        _ => 20.0,
+    };
+
+    let min_size = gradation.sizes[0].log10();
+    let max_size = (gradation.sizes.last().unwrap() / 4.0).log10();
+
+    let mut points = Vec::new();
+    if max_size > min_size {
+        for i in 0..=EVALUATION_POINTS {
+            let size =
+                10f64.powf(min_size + (max_size - min_size) * i as f64 / EVALUATION_POINTS as f64);
+            let f = gradation.percent_finer(size);
+            if f <= 0.0 || f > f_limit {
+                continue;
+            }
+            let h = gradation.percent_finer(4.0 * size) - f;
+            points.push(KenneyLauPoint {
+                size,
+                f,
+                h,
+                ratio: h / f,
+            });
+        }
+    }
+
+    if points.is_empty() {
+        return Err(ValidationError {
+            code: "internal_erosion.gradation.insufficient_range".to_string(),
+            message: "Gradation curve does not cover the fine fraction of the Kenney–Lau check."
+                .to_string(),
+        });
+    }
+
+    let min_ratio = points.iter().map(|p| p.ratio).fold(f64::INFINITY, f64::min);
+
+    Ok(InternalStabilityResult {
+        uniformity_coefficient,
+        f_limit,
+        points,
+        min_ratio,
+        is_stable: min_ratio >= STABILITY_BOUNDARY,
+    })
+}
+
+/// Checks the internal stability of each soil layer that has a gradation curve.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+///
+/// # Returns
+/// * `Vec<Option<InternalStabilityResult>>`: Result for each layer, `None` for layers without gradation.
+pub fn calc_profile_internal_stability(
+    soil_profile: &SoilProfile,
+) -> Result<Vec<Option<InternalStabilityResult>>, ValidationError> {
+    soil_profile
+        .layers
+        .iter()
+        .map(|layer| {
+            layer
+                .gradation
+                .as_ref()
+                .map(calc_internal_stability)
+                .transpose()
+        })
+        .collect()
+}
@@ -0,0 +1,361 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bearing_capacity::model::BearingCapacityResult,
+    consolidation_settlement::model::SettlementResult,
+    critical_layer::CriticalLayer,
+    enums::{CriticalLayerCriterion, SoilClassBasis},
+    liquefaction::models::CommonLiquefactionLayerResult,
+    local_soil_class::{
+        by_cu::CuSoilClassificationResult, by_spt::SptSoilClassificationResult,
+        by_vs::VsSoilClassificationResult,
+    },
+    models::soil_profile::SoilProfile,
+};
+
+/// Coded engineering finding derived from analysis results, for automated report narratives.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "code", rename_all = "snake_case")]
+pub enum Finding {
+    /// The foundation pressure exceeds the allowable bearing capacity.
+    BearingCapacityExceeded {
+        /// Foundation pressure (t/m²).
+        foundation_pressure: f64,
+        /// Allowable bearing capacity (t/m²).
+        allowable_bearing_capacity: f64,
+    },
+    /// The settlement exceeds the allowable settlement.
+    SettlementExceeded {
+        /// Calculated settlement (cm).
+        settlement: f64,
+        /// Allowable settlement (cm).
+        allowable_settlement: f64,
+    },
+    /// The allowable pressure is limited by settlement rather than bearing capacity.
+    SettlementGoverns {
+        /// Pressure giving the allowable settlement (t/m²).
+        settlement_pressure: f64,
+        /// Allowable bearing capacity (t/m²).
+        allowable_bearing_capacity: f64,
+    },
+    /// The allowable pressure is limited by bearing capacity rather than settlement.
+    BearingGoverns {
+        /// Allowable bearing capacity (t/m²).
+        allowable_bearing_capacity: f64,
+        /// Pressure giving the allowable settlement (t/m²).
+        settlement_pressure: f64,
+    },
+    /// A continuous zone of liquefiable soil.
+    LiquefiableZone {
+        /// Depth of the top of the zone (m).
+        top: f64,
+        /// Depth of the bottom of the zone (m).
+        bottom: f64,
+        /// Minimum safety factor in the zone.
+        min_safety_factor: f64,
+    },
+    /// No liquefiable layer was found.
+    NoLiquefaction,
+    /// The local soil class and the average parameter it is based on.
+    SoilClass {
+        /// Local soil class (e.g., ZD).
+        soil_class: String,
+        /// Parameter the class is based on.
+        basis: SoilClassBasis,
+        /// Value of the parameter.
+        value: f64,
+    },
+    /// Lithology description of a layer from the borehole log.
+    Lithology {
+        /// Depth of the top of the layer (m).
+        top: f64,
+        /// Depth of the bottom of the layer (m).
+        bottom: f64,
+        /// Log description of the layer.
+        description: String,
+    },
+    /// The layer governing an analysis, where additional investigation is most useful.
+    CriticalLayer {
+        /// Analysis the layer is critical for.
+        criterion: CriticalLayerCriterion,
+        /// Depth of the top of the layer (m).
+        top: f64,
+        /// Depth of the bottom of the layer (m).
+        bottom: f64,
+        /// Governing value: settlement (cm), unit bearing resistance (t/m²) or safety factor.
+        value: f64,
+    },
+}
+
+impl Finding {
+    /// Returns the code of the finding.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Finding::BearingCapacityExceeded { .. } => "bearing_capacity_exceeded",
+            Finding::SettlementExceeded { .. } => "settlement_exceeded",
+            Finding::SettlementGoverns { .. } => "settlement_governs",
+            Finding::BearingGoverns { .. } => "bearing_governs",
+            Finding::LiquefiableZone { .. } => "liquefiable_zone",
+            Finding::NoLiquefaction => "no_liquefaction",
+            Finding::SoilClass { .. } => "soil_class",
+            Finding::Lithology { .. } => "lithology",
+            Finding::CriticalLayer { .. } => "critical_layer",
+        }
+    }
+
+    /// Returns a one sentence engineering commentary of the finding.
+    pub fn message(&self) -> String {
+        match self {
+            Finding::BearingCapacityExceeded {
+                foundation_pressure,
+                allowable_bearing_capacity,
+            } => format!(
+                "Foundation pressure of {:.2} t/m² exceeds the allowable bearing capacity of {:.2} t/m².",
+                foundation_pressure, allowable_bearing_capacity
+            ),
+            Finding::SettlementExceeded {
+                settlement,
+                allowable_settlement,
+            } => format!(
+                "Settlement of {:.2} cm exceeds the allowable settlement of {:.2} cm.",
+                settlement, allowable_settlement
+            ),
+            Finding::SettlementGoverns {
+                settlement_pressure,
+                allowable_bearing_capacity,
+            } => format!(
+                "Settlement governs over bearing: {:.2} t/m² gives the allowable settlement, below the allowable bearing capacity of {:.2} t/m².",
+                settlement_pressure, allowable_bearing_capacity
+            ),
+            Finding::BearingGoverns {
+                allowable_bearing_capacity,
+                settlement_pressure,
+            } => format!(
+                "Bearing governs over settlement: the allowable bearing capacity of {:.2} t/m² is below the {:.2} t/m² giving the allowable settlement.",
+                allowable_bearing_capacity, settlement_pressure
+            ),
+            Finding::LiquefiableZone {
+                top,
+                bottom,
+                min_safety_factor,
+            } => format!(
+                "Liquefiable layer between {:.1}–{:.1} m (minimum safety factor {:.2}).",
+                top, bottom, min_safety_factor
+            ),
+            Finding::NoLiquefaction => "No liquefiable layer was found.".to_string(),
+            Finding::SoilClass {
+                soil_class,
+                basis,
+                value,
+            } => {
+                let (name, unit) = match basis {
+                    SoilClassBasis::Vs30 => ("Vs30", "m/s"),
+                    SoilClassBasis::N30 => ("N30", "blows/30cm"),
+                    SoilClassBasis::Cu30 => ("Cu30", "t/m²"),
+                };
+                format!(
+                    "Soil class {} due to {}={:.0} {}.",
+                    soil_class, name, value, unit
+                )
+            }
+            Finding::Lithology {
+                top,
+                bottom,
+                description,
+            } => format!("{:.1}–{:.1} m: {}.", top, bottom, description),
+            Finding::CriticalLayer {
+                criterion,
+                top,
+                bottom,
+                value,
+            } => match criterion {
+                CriticalLayerCriterion::Settlement => format!(
+                    "Layer between {:.1}–{:.1} m governs settlement with {:.2} cm.",
+                    top, bottom, value
+                ),
+                CriticalLayerCriterion::BearingCapacity => format!(
+                    "Layer between {:.1}–{:.1} m is the weakest in the bearing zone with a unit resistance of {:.2} t/m².",
+                    top, bottom, value
+                ),
+                CriticalLayerCriterion::Liquefaction => format!(
+                    "Layer between {:.1}–{:.1} m governs liquefaction with a safety factor of {:.2}.",
+                    top, bottom, value
+                ),
+            },
+        }
+    }
+}
+
+/// Interprets bearing capacity and settlement results of a foundation.
+///
+/// Settlement is assumed to be proportional to the foundation pressure, so the pressure giving
+/// the allowable settlement is q · s_allow / s.
+///
+/// # Arguments
+/// * `bearing_capacity` - Bearing capacity result.
+/// * `settlement` - Settlement result under the same foundation pressure.
+/// * `allowable_settlement` - Allowable settlement (cm).
+///
+/// # Returns
+/// * `Vec<Finding>`: Exceedances followed by the governing criterion.
+pub fn interpret_foundation(
+    bearing_capacity: &BearingCapacityResult,
+    settlement: &SettlementResult,
+    allowable_settlement: f64,
+) -> Vec<Finding> {
+    let mut findings = vec![];
+    let foundation_pressure = bearing_capacity.qmax;
+    let allowable_bearing_capacity = bearing_capacity.allowable_bearing_capacity;
+
+    if !bearing_capacity.is_safe {
+        findings.push(Finding::BearingCapacityExceeded {
+            foundation_pressure,
+            allowable_bearing_capacity,
+        });
+    }
+    if settlement.total_settlement > allowable_settlement {
+        findings.push(Finding::SettlementExceeded {
+            settlement: settlement.total_settlement,
+            allowable_settlement,
+        });
+    }
+
+    if settlement.total_settlement > 0.0 {
+        let settlement_pressure =
+            foundation_pressure * allowable_settlement / settlement.total_settlement;
+        if settlement_pressure < allowable_bearing_capacity {
+            findings.push(Finding::SettlementGoverns {
+                settlement_pressure,
+                allowable_bearing_capacity,
+            });
+        } else {
+            findings.push(Finding::BearingGoverns {
+                allowable_bearing_capacity,
+                settlement_pressure,
+            });
+        }
+    }
+
+    findings
+}
+
+/// Interprets the layer results of a liquefaction analysis as continuous liquefiable zones.
+///
+/// # Arguments
+/// * `layers` - Layer results of an SPT or Vs based liquefaction analysis, ordered by depth.
+///
+/// # Returns
+/// * `Vec<Finding>`: One finding per liquefiable zone, or `NoLiquefaction`.
+pub fn interpret_liquefaction(layers: &[CommonLiquefactionLayerResult]) -> Vec<Finding> {
+    let mut findings = vec![];
+    let mut zone: Option<(f64, f64, f64)> = None;
+
+    for layer in layers {
+        if layer.is_safe {
+            if let Some((top, bottom, min_safety_factor)) = zone.take() {
+                findings.push(Finding::LiquefiableZone {
+                    top,
+                    bottom,
+                    min_safety_factor,
+                });
+            }
+            continue;
+        }
+
+        let safety_factor = layer.safety_factor.unwrap_or(0.0);
+        zone = Some(match zone {
+            Some((top, _, min_safety_factor)) => {
+                (top, layer.depth, min_safety_factor.min(safety_factor))
+            }
+            None => (layer.depth, layer.depth, safety_factor),
+        });
+    }
+
+    if let Some((top, bottom, min_safety_factor)) = zone {
+        findings.push(Finding::LiquefiableZone {
+            top,
+            bottom,
+            min_safety_factor,
+        });
+    }
+
+    if findings.is_empty() {
+        findings.push(Finding::NoLiquefaction);
+    }
+
+    findings
+}
+
+/// Interprets a local soil class result from shear wave velocities.
+pub fn interpret_soil_class_by_vs(result: &VsSoilClassificationResult) -> Finding {
+    Finding::SoilClass {
+        soil_class: result.soil_class.clone(),
+        basis: SoilClassBasis::Vs30,
+        value: result.vs_30,
+    }
+}
+
+/// Interprets a local soil class result from SPT blow counts.
+pub fn interpret_soil_class_by_spt(result: &SptSoilClassificationResult) -> Finding {
+    Finding::SoilClass {
+        soil_class: result.soil_class.clone(),
+        basis: SoilClassBasis::N30,
+        value: result.n_30,
+    }
+}
+
+/// Interprets a local soil class result from undrained shear strengths.
+pub fn interpret_soil_class_by_cu(result: &CuSoilClassificationResult) -> Finding {
+    Finding::SoilClass {
+        soil_class: result.soil_class.clone(),
+        basis: SoilClassBasis::Cu30,
+        value: result.cu_30,
+    }
+}
+
+/// Interprets the lithology descriptions of the soil profile as borehole log entries.
+///
+/// Layers without a lithology description are skipped.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile, with layers ordered from top to bottom.
+///
+/// # Returns
+/// * `Vec<Finding>`: One `Lithology` finding per described layer.
+pub fn interpret_lithology(soil_profile: &SoilProfile) -> Vec<Finding> {
+    let mut findings = vec![];
+    let mut top = 0.0;
+
+    for layer in soil_profile.layers.iter() {
+        let bottom = top + layer.thickness.unwrap_or(0.0);
+        if let Some(description) = layer.lithology.as_ref().and_then(|l| l.summary()) {
+            findings.push(Finding::Lithology {
+                top,
+                bottom,
+                description,
+            });
+        }
+        top = bottom;
+    }
+
+    findings
+}
+
+/// Interprets the critical layers of the analyses.
+///
+/// # Arguments
+/// * `critical_layers` - Critical layers found by the `critical_layer` scanners.
+///
+/// # Returns
+/// * `Vec<Finding>`: One `CriticalLayer` finding per critical layer.
+pub fn interpret_critical_layers(critical_layers: &[CriticalLayer]) -> Vec<Finding> {
+    critical_layers
+        .iter()
+        .map(|layer| Finding::CriticalLayer {
+            criterion: layer.criterion,
+            top: layer.top,
+            bottom: layer.bottom,
+            value: layer.value,
+        })
+        .collect()
+}
@@ -0,0 +1,197 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    effective_depth::calc_effective_depth,
+    enums::PressureConvention,
+    models::{borehole::Borehole, foundation::Foundation, soil_profile::SoilProfile},
+    validation::ValidationError,
+};
+
+/// Maximum spacing of boreholes for buildings (m), upper bound of the 15–40 m range of
+/// EN 1997-2 Annex B.3.
+pub const MAX_BOREHOLE_SPACING: f64 = 30.0;
+
+/// Footprint area above which at least three boreholes are required (m²).
+const SMALL_FOOTPRINT_AREA: f64 = 100.0;
+
+/// Deficiency of a site investigation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "code", rename_all = "snake_case")]
+pub enum InvestigationFlag {
+    /// A borehole does not reach the required depth.
+    InsufficientDepth {
+        /// Identifier of the borehole.
+        borehole_id: String,
+        /// Depth of the borehole (m).
+        depth: f64,
+        /// Required depth (m).
+        required_depth: f64,
+    },
+    /// Too few boreholes cover the footprint.
+    InsufficientCount {
+        /// Number of boreholes covering the footprint.
+        count: usize,
+        /// Required number of boreholes.
+        required_count: usize,
+    },
+    /// The boreholes are further apart than the maximum spacing.
+    ExcessiveSpacing {
+        /// Largest distance from a borehole to its nearest neighbour (m).
+        spacing: f64,
+        /// Maximum spacing (m).
+        max_spacing: f64,
+    },
+}
+
+/// Adequacy of a site investigation for a foundation.
+///
+/// # Fields
+/// * `effective_depth` - Depth of influence of the foundation (m).
+/// * `required_depth` - Depth the boreholes should reach (m).
+/// * `borehole_count` - Number of boreholes within or near the footprint.
+/// * `required_count` - Required number of boreholes.
+/// * `spacing` - Largest distance from a borehole to its nearest neighbour (m).
+/// * `score` - Adequacy score from 0 (no exploration) to 100 (all criteria met).
+/// * `flags` - Deficiencies of the investigation.
+/// * `is_adequate` - Whether all criteria are met.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InvestigationAdequacy {
+    pub effective_depth: f64,
+    pub required_depth: f64,
+    pub borehole_count: usize,
+    pub required_count: usize,
+    pub spacing: Option<f64>,
+    pub score: f64,
+    pub flags: Vec<InvestigationFlag>,
+    pub is_adequate: bool,
+}
+
+/// Validates the input data for the investigation adequacy check.
+///
+/// # Arguments
+/// * `boreholes` - The boreholes of the site investigation.
+pub fn validate_input(boreholes: &[Borehole]) -> Result<(), ValidationError> {
+    for borehole in boreholes {
+        borehole.validate(&["x", "y", "depth"])?;
+    }
+    Ok(())
+}
+
+/// Scores the adequacy of a site investigation against the footprint and depth of influence of a
+/// foundation.
+///
+/// Boreholes within half a foundation width of the footprint are counted. The criteria are:
+///
+/// * Depth: every borehole reaches the effective depth, and at least one foundation width below
+///   the base.
+/// * Number: one borehole per `MAX_BOREHOLE_SPACING`² of footprint, and at least three for
+///   footprints larger than 100 m².
+/// * Spacing: no borehole is further than `MAX_BOREHOLE_SPACING` from its nearest neighbour.
+///
+/// The score is the mean of the three criteria, each scored as the achieved fraction of the
+/// requirement.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `foundation` - The foundation data.
+/// * `foundation_pressure` - The pressure applied by the foundation (t/m²).
+/// * `boreholes` - The boreholes of the site investigation.
+///
+/// # Returns
+/// * `InvestigationAdequacy` with the score and the deficiencies.
+pub fn calc_investigation_adequacy(
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    foundation_pressure: f64,
+    boreholes: &[Borehole],
+) -> Result<InvestigationAdequacy, ValidationError> {
+    validate_input(boreholes)?;
+    let effective_depth = calc_effective_depth(
+        soil_profile,
+        foundation,
+        foundation_pressure,
+        PressureConvention::Gross,
+    )?;
+
+    let df = foundation.foundation_depth.unwrap();
+    let width = foundation.foundation_width.unwrap();
+    let length = foundation.foundation_length.unwrap();
+    let required_depth = effective_depth.max(df + width);
+
+    let margin = width / 2.0;
+    let covering: Vec<&Borehole> = boreholes
+        .iter()
+        .filter(|b| {
+            b.x.unwrap().abs() <= width / 2.0 + margin
+                && b.y.unwrap().abs() <= length / 2.0 + margin
+        })
+        .collect();
+
+    let area = width * length;
+    let min_count = if area > SMALL_FOOTPRINT_AREA { 3 } else { 1 };
+    let required_count = ((area / MAX_BOREHOLE_SPACING.powi(2)).ceil() as usize).max(min_count);
+
+    let spacing = (covering.len() > 1).then(|| {
+        covering
+            .iter()
+            .map(|a| {
+                covering
+                    .iter()
+                    .filter(|b| !std::ptr::eq(*a, **b))
+                    .map(|b| (a.x.unwrap() - b.x.unwrap()).hypot(a.y.unwrap() - b.y.unwrap()))
+                    .fold(f64::INFINITY, f64::min)
+            })
+            .fold(0.0, f64::max)
+    });
+
+    let mut flags = vec![];
+    for borehole in covering.iter() {
+        let depth = borehole.depth.unwrap();
+        if depth < required_depth {
+            flags.push(InvestigationFlag::InsufficientDepth {
+                borehole_id: borehole.borehole_id.clone(),
+                depth,
+                required_depth,
+            });
+        }
+    }
+    if covering.len() < required_count {
+        flags.push(InvestigationFlag::InsufficientCount {
+            count: covering.len(),
+            required_count,
+        });
+    }
+    if let Some(spacing) = spacing.filter(|&s| s > MAX_BOREHOLE_SPACING) {
+        flags.push(InvestigationFlag::ExcessiveSpacing {
+            spacing,
+            max_spacing: MAX_BOREHOLE_SPACING,
+        });
+    }
+
+    let depth_score = if covering.is_empty() {
+        0.0
+    } else {
+        covering
+            .iter()
+            .map(|b| (b.depth.unwrap() / required_depth).min(1.0))
+            .sum::<f64>()
+            / covering.len() as f64
+    };
+    let count_score = (covering.len() as f64 / required_count as f64).min(1.0);
+    let spacing_score = match spacing {
+        Some(s) => (MAX_BOREHOLE_SPACING / s).min(1.0),
+        None => count_score,
+    };
+    let score = 100.0 * (depth_score + count_score + spacing_score) / 3.0;
+
+    Ok(InvestigationAdequacy {
+        effective_depth,
+        required_depth,
+        borehole_count: covering.len(),
+        required_count,
+        spacing,
+        score,
+        is_adequate: flags.is_empty(),
+        flags,
+    })
+}
@@ -1,13 +1,49 @@
+pub mod adjacent_influence;
+pub mod allowable_pressure;
 pub mod bearing_capacity;
+pub mod buried_pipe;
 pub mod consolidation_settlement;
+pub mod constants;
+pub mod critical_layer;
+pub mod cyclic_degradation;
+pub mod diagnostics;
+pub mod differential_settlement;
+pub mod drivability;
 pub mod effective_depth;
 pub mod elastic_settlement;
 pub mod enums;
+pub mod field_strength;
+pub mod filter_design;
+pub mod flood;
+pub mod foundation_design;
+pub mod ground_anchor;
+pub mod groundwater_aggressivity;
 pub mod helper;
 pub mod horizontal_sliding;
+pub mod internal_erosion;
+pub mod interpretation;
+pub mod investigation_adequacy;
 pub mod liquefaction;
+pub mod load_settlement;
 pub mod local_soil_class;
+pub mod method_registry;
 pub mod models;
+pub mod parameter_selection;
+pub mod permeability;
+pub mod pile_kinematic;
+pub mod piled_raft;
+pub mod pore_pressure;
+pub mod probabilistic;
+pub mod progress;
+pub mod scour;
+pub mod shansep;
 pub mod soil_coefficient;
+pub mod spt_anchor;
+pub mod stability;
+pub mod stress_distribution;
+pub mod summary;
 pub mod swelling_potential;
+pub mod testkit;
+pub mod total_settlement;
 pub mod validation;
+pub mod vibration;
@@ -0,0 +1,265 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    liquefaction::{
+        helper_functions::calc_msf,
+        models::CyclicStressDemand,
+        spt::seed_idriss,
+        vs::andrus_stokoe::{self, calc_vs1c},
+    },
+    models::soil_profile::SoilProfile,
+    validation::{validate_field, ValidationError},
+};
+
+/// Largest clean-sand N1_60 covered by the Seed-Idriss CRR curve.
+const MAX_N1_60CS: i32 = 33;
+
+/// Largest clean-sand qc1N covered by the Robertson & Wride CRR curve.
+const MAX_QC1NCS: f64 = 160.0;
+
+/// Ground improvement target at a single depth.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MitigationTarget {
+    /// Depth of the target (m).
+    pub depth: f64,
+    /// Total vertical stress at the depth (t/m²).
+    pub normal_stress: f64,
+    /// Vertical effective stress at the depth (t/m²).
+    pub effective_stress: f64,
+    /// Stress reduction factor rd.
+    pub rd: f64,
+    /// Cyclic stress ratio CSR = 0.65 · PGA · (σv / σ'v) · rd.
+    pub csr: Option<f64>,
+    /// Cyclic resistance ratio for Mw = 7.5 needed to reach the target factor of safety.
+    pub required_crr75: Option<f64>,
+    /// Minimum clean-sand equivalent N1_60cs (Seed & Idriss).
+    pub required_n1_60cs: Option<i32>,
+    /// Minimum clean-sand equivalent qc1Ncs (Robertson & Wride).
+    pub required_qc1ncs: Option<f64>,
+    /// Minimum overburden-corrected shear wave velocity Vs1 (m/s, Andrus & Stokoe).
+    pub required_vs1: Option<f64>,
+    /// Indicates whether the depth is susceptible to liquefaction and needs a target.
+    pub is_susceptible: bool,
+}
+
+/// Ground improvement specification for liquefaction mitigation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MitigationTargetResult {
+    /// Factor of safety the improved ground must reach.
+    pub target_factor_of_safety: f64,
+    /// Magnitude scaling factor.
+    pub msf: f64,
+    /// Targets at the requested depths.
+    pub targets: Vec<MitigationTarget>,
+}
+
+/// Calculates the cyclic resistance ratio for Mw = 7.5 from the clean-sand equivalent CPT
+/// resistance (Robertson & Wride, 1998).
+///
+/// # Arguments
+/// * `qc1ncs` - Clean-sand equivalent normalized cone resistance qc1Ncs.
+///
+/// # Returns
+/// * `f64`: CRR7.5.
+pub fn calc_cpt_crr75(qc1ncs: f64) -> f64 {
+    if qc1ncs < 50.0 {
+        0.833 * qc1ncs / 1000.0 + 0.05
+    } else {
+        93.0 * (qc1ncs / 1000.0).powi(3) + 0.08
+    }
+}
+
+/// Calculates the minimum clean-sand N1_60 to reach a cyclic resistance ratio.
+///
+/// # Arguments
+/// * `crr75` - Required cyclic resistance ratio for Mw = 7.5.
+///
+/// # Returns
+/// * `Option<i32>`: Smallest N1_60cs with enough resistance, None if beyond the CRR curve.
+pub fn calc_required_n1_60cs(crr75: f64) -> Option<i32> {
+    (0..=MAX_N1_60CS).find(|&n| seed_idriss::calc_crr75(n, 1.0) >= crr75)
+}
+
+/// Calculates the minimum clean-sand qc1N to reach a cyclic resistance ratio.
+///
+/// # Arguments
+/// * `crr75` - Required cyclic resistance ratio for Mw = 7.5.
+///
+/// # Returns
+/// * `Option<f64>`: Required qc1Ncs, None if beyond the CRR curve.
+pub fn calc_required_qc1ncs(crr75: f64) -> Option<f64> {
+    let mut qc1ncs = (crr75 - 0.05) * 1000.0 / 0.833;
+    if qc1ncs >= 50.0 {
+        qc1ncs = 1000.0 * ((crr75 - 0.08) / 93.0).cbrt();
+    }
+
+    (qc1ncs < MAX_QC1NCS).then_some(qc1ncs.max(0.0))
+}
+
+/// Calculates the minimum overburden-corrected shear wave velocity to reach a cyclic
+/// resistance ratio by bisection on the Andrus & Stokoe CRR curve.
+///
+/// # Arguments
+/// * `crr75` - Required cyclic resistance ratio for Mw = 7.5.
+/// * `fine_content` - Fine content in percentage.
+///
+/// # Returns
+/// * `f64`: Required Vs1 (m/s), below the limiting Vs1c of the fine content.
+pub fn calc_required_vs1(crr75: f64, fine_content: f64) -> f64 {
+    let vs1c = calc_vs1c(fine_content);
+    let (mut low, mut high) = (0.0, vs1c);
+    for _ in 0..60 {
+        let mid = 0.5 * (low + high);
+        if andrus_stokoe::calc_crr75(mid, vs1c, 1.0) < crr75 {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+
+    high
+}
+
+/// Validates the input data for liquefaction mitigation targets.
+///
+/// # Arguments
+/// * `soil_profile` - Soil profile data.
+/// * `depths` - Depths of the targets (m).
+/// * `pga` - Peak Ground Acceleration.
+/// * `mw` - Moment magnitude.
+/// * `target_factor_of_safety` - Factor of safety the improved ground must reach.
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(
+    soil_profile: &SoilProfile,
+    depths: &[f64],
+    pga: f64,
+    mw: f64,
+    target_factor_of_safety: f64,
+) -> Result<(), ValidationError> {
+    soil_profile.validate(&["thickness", "dry_unit_weight", "saturated_unit_weight"])?;
+    soil_profile.validate_soil_layers(&["plasticity_index", "fine_content"])?;
+    if depths.is_empty() {
+        return Err(ValidationError {
+            code: "liquefaction_mitigation.empty_depths".into(),
+            message: "At least one depth must be provided.".into(),
+        });
+    }
+    for &depth in depths {
+        validate_field(
+            "depth",
+            Some(depth),
+            Some(0.0),
+            None,
+            "liquefaction_mitigation",
+        )?;
+    }
+    validate_field("pga", Some(pga), Some(0.0), None, "liquefaction_mitigation")?;
+    validate_field(
+        "mw",
+        Some(mw),
+        Some(4.0),
+        Some(10.0),
+        "liquefaction_mitigation",
+    )?;
+    validate_field(
+        "target_factor_of_safety",
+        Some(target_factor_of_safety),
+        Some(1.0),
+        Some(2.0),
+        "liquefaction_mitigation",
+    )?;
+
+    Ok(())
+}
+
+/// Back-calculates the in-situ test values the improved ground must reach so that the factor
+/// of safety against liquefaction equals the target.
+///
+/// At each depth the cyclic resistance ratio needed for Mw = 7.5 is
+///
+/// * CRR7.5 = FS · CSR / MSF, with CSR = 0.65 · PGA · (σv / σ'v) · rd
+///
+/// and it is converted into the minimum N1_60cs (Seed & Idriss), qc1Ncs (Robertson & Wride) and
+/// Vs1 (Andrus & Stokoe, with Vs1c from the fine content of the layer). Depths above the
+/// groundwater level, in rock or in plastic layers (PI ≥ 12) are reported as not susceptible.
+///
+/// # Arguments
+/// * `soil_profile` - Soil profile data.
+/// * `depths` - Depths of the targets (m).
+/// * `pga` - Peak Ground Acceleration.
+/// * `mw` - Moment magnitude.
+/// * `target_factor_of_safety` - Factor of safety the improved ground must reach, e.g. 1.1–1.3.
+/// * `demand` - Source of the cyclic stress demand.
+///
+/// # Returns
+/// * `MitigationTargetResult` with the ground improvement targets per depth.
+pub fn calc_mitigation_targets(
+    soil_profile: &SoilProfile,
+    depths: &[f64],
+    pga: f64,
+    mw: f64,
+    target_factor_of_safety: f64,
+    demand: &CyclicStressDemand,
+) -> Result<MitigationTargetResult, ValidationError> {
+    validate_input(soil_profile, depths, pga, mw, target_factor_of_safety)?;
+    demand.validate()?;
+
+    let msf = calc_msf(mw);
+    let ground_water_level = soil_profile.ground_water_level.unwrap();
+
+    let targets = depths
+        .iter()
+        .map(|&depth| {
+            let normal_stress = soil_profile.calc_normal_stress(depth);
+            let effective_stress = soil_profile.calc_effective_stress(depth);
+            let rd = demand.calc_rd(depth, pga, normal_stress);
+            let layer = soil_profile.get_layer_at_depth(depth);
+
+            let is_susceptible = !layer.is_rock()
+                && ground_water_level < depth
+                && layer.plasticity_index.unwrap_or(0.0) < 12.0
+                && effective_stress > 0.0;
+            if !is_susceptible {
+                return MitigationTarget {
+                    depth,
+                    normal_stress,
+                    effective_stress,
+                    rd,
+                    csr: None,
+                    required_crr75: None,
+                    required_n1_60cs: None,
+                    required_qc1ncs: None,
+                    required_vs1: None,
+                    is_susceptible,
+                };
+            }
+
+            let csr = 0.65 * pga * normal_stress / effective_stress * rd;
+            let required_crr75 = target_factor_of_safety * csr / msf;
+
+            MitigationTarget {
+                depth,
+                normal_stress,
+                effective_stress,
+                rd,
+                csr: Some(csr),
+                required_crr75: Some(required_crr75),
+                required_n1_60cs: calc_required_n1_60cs(required_crr75),
+                required_qc1ncs: calc_required_qc1ncs(required_crr75),
+                required_vs1: Some(calc_required_vs1(
+                    required_crr75,
+                    layer.fine_content.unwrap(),
+                )),
+                is_susceptible,
+            }
+        })
+        .collect();
+
+    Ok(MitigationTargetResult {
+        target_factor_of_safety,
+        msf,
+        targets,
+    })
+}
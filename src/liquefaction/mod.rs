@@ -1,4 +1,6 @@
 pub mod helper_functions;
+pub mod mitigation;
 pub mod models;
+pub mod screening;
 pub mod spt;
 pub mod vs;
@@ -1,4 +1,10 @@
-use crate::models::{soil_profile::SoilLayer, spt::SPTExp};
+use crate::{
+    enums::{DepositType, GeologicAge, LiquefactionSusceptibility, MethodId},
+    helper::interp1d,
+    liquefaction::helper_functions::calc_rd,
+    models::{soil_profile::SoilLayer, spt::SPTExp},
+    validation::{validate_field, ValidationError},
+};
 use serde::{Deserialize, Serialize};
 
 /// Result of liquefaction analysis for a single layer
@@ -44,6 +50,23 @@ pub struct VSLiquefactionLayerResult {
     pub cn: Option<f64>,
 }
 
+/// Verdict of the liquefaction susceptibility screening by geologic criteria.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct LiquefactionScreening {
+    /// Type of the deposit.
+    pub deposit_type: DepositType,
+    /// Geologic age of the deposit.
+    pub geologic_age: GeologicAge,
+    /// Depth of the groundwater table (m).
+    pub groundwater_depth: f64,
+    /// Susceptibility of the deposit type and age (Youd & Perkins, 1978).
+    pub geologic_susceptibility: LiquefactionSusceptibility,
+    /// Susceptibility after the reduction for the groundwater depth.
+    pub susceptibility: LiquefactionSusceptibility,
+    /// Indicates whether the triggering analysis must be performed.
+    pub requires_analysis: bool,
+}
+
 /// Result of liquefaction analysis for entire soil profile
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VSLiquefactionResult {
@@ -51,6 +74,9 @@ pub struct VSLiquefactionResult {
     pub vs_layers: Vec<VSLiquefactionLayerResult>,  // VS layer results
     pub total_settlement: f64,                      // Sum of settlements
     pub msf: f64,                                   // Magnitude Scaling Factor
+    pub method: MethodId,                           // Method used for the analysis
+    #[serde(default)]
+    pub screening: Option<LiquefactionScreening>, // Susceptibility screening, if performed
 }
 
 /// Result of liquefaction analysis for entire soil profile
@@ -60,4 +86,108 @@ pub struct SptLiquefactionResult {
     pub spt_exp: SPTExp,
     pub total_settlement: f64, // Sum of settlements
     pub msf: f64,              // Magnitude Scaling Factor
+    pub method: MethodId,      // Method used for the analysis
+    #[serde(default)]
+    pub screening: Option<LiquefactionScreening>, // Susceptibility screening, if performed
+}
+
+/// Maximum shear stress profile from a site response analysis.
+///
+/// # Fields
+/// * `depths` - Depths of the profile points in m, sorted from top to bottom
+/// * `max_shear_stress` - Maximum shear stress τmax at each depth in ton/m²
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ShearStressProfile {
+    pub depths: Vec<f64>,
+    pub max_shear_stress: Vec<f64>,
+}
+
+impl ShearStressProfile {
+    pub fn new(depths: Vec<f64>, max_shear_stress: Vec<f64>) -> Self {
+        Self {
+            depths,
+            max_shear_stress,
+        }
+    }
+
+    /// Returns the maximum shear stress at a depth by linear interpolation (t/m²).
+    ///
+    /// Depths outside the profile take the value of the nearest end.
+    pub fn get_max_shear_stress(&self, depth: f64) -> f64 {
+        interp1d(&self.depths, &self.max_shear_stress, depth)
+    }
+
+    /// Validates the shear stress profile.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if self.depths.is_empty() || self.depths.len() != self.max_shear_stress.len() {
+            return Err(ValidationError {
+                code: "shear_stress_profile.length_mismatch".into(),
+                message: "Depths and maximum shear stresses must be non-empty and of equal length."
+                    .into(),
+            });
+        }
+        if self.depths.windows(2).any(|w| w[1] <= w[0]) {
+            return Err(ValidationError {
+                code: "shear_stress_profile.depths.not_sorted".into(),
+                message: "Depths must be strictly increasing.".into(),
+            });
+        }
+        for (&depth, &tau) in self.depths.iter().zip(self.max_shear_stress.iter()) {
+            validate_field(
+                "depth",
+                Some(depth),
+                Some(0.0),
+                None,
+                "shear_stress_profile",
+            )?;
+            validate_field(
+                "max_shear_stress",
+                Some(tau),
+                Some(0.0),
+                None,
+                "shear_stress_profile",
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Source of the cyclic stress demand in liquefaction analyses.
+///
+/// # Variants
+/// * `Simplified` - Simplified procedure with the depth reduction factor rd
+/// * `SiteResponse` - Shear stress profile from a site response analysis, τav = 0.65 · τmax
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub enum CyclicStressDemand {
+    #[default]
+    Simplified,
+    SiteResponse(ShearStressProfile),
+}
+
+impl CyclicStressDemand {
+    /// Validates the cyclic stress demand.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            CyclicStressDemand::Simplified => Ok(()),
+            CyclicStressDemand::SiteResponse(profile) => profile.validate(),
+        }
+    }
+
+    /// Returns the stress reduction factor at a depth.
+    ///
+    /// For a site response profile the equivalent factor rd = τmax / (PGA · σv) is returned, so
+    /// that 0.65 · PGA · σv · rd equals 0.65 · τmax.
+    ///
+    /// # Arguments
+    /// * `depth` - Depth in meters
+    /// * `pga` - Peak Ground Acceleration
+    /// * `normal_stress` - Total vertical stress in ton/m²
+    pub fn calc_rd(&self, depth: f64, pga: f64, normal_stress: f64) -> f64 {
+        match self {
+            CyclicStressDemand::SiteResponse(profile) if pga * normal_stress > 0.0 => {
+                profile.get_max_shear_stress(depth) / (pga * normal_stress)
+            }
+            _ => calc_rd(depth),
+        }
+    }
 }
@@ -0,0 +1,268 @@
+use crate::{
+    enums::{DepositType, GeologicAge, LiquefactionSusceptibility, MethodId},
+    liquefaction::{
+        helper_functions::calc_msf,
+        models::{
+            CommonLiquefactionLayerResult, CyclicStressDemand, LiquefactionScreening,
+            SptLiquefactionResult, VSLiquefactionResult,
+        },
+        spt::seed_idriss,
+        vs::andrus_stokoe,
+    },
+    models::{masw::Masw, soil_profile::SoilProfile, spt::SPT},
+    validation::{validate_field, ValidationError},
+};
+
+/// Returns the susceptibility of a deposit to liquefaction from its type and geologic age
+/// (Youd & Perkins, 1978, Table 1).
+///
+/// Cells the table leaves unrated take the rating of the nearest rated age, so loess and tephra
+/// keep their high rating with age and fills keep the rating of a recent placement.
+///
+/// # Arguments
+/// * `deposit_type` - Type of the deposit.
+/// * `geologic_age` - Geologic age of the deposit.
+///
+/// # Returns
+/// * `LiquefactionSusceptibility` of the deposit with the groundwater table near the surface.
+pub fn calc_geologic_susceptibility(
+    deposit_type: DepositType,
+    geologic_age: GeologicAge,
+) -> LiquefactionSusceptibility {
+    use LiquefactionSusceptibility::*;
+
+    // Ratings for latest Holocene, Holocene, Pleistocene and pre-Pleistocene deposits
+    let ratings = match deposit_type {
+        DepositType::RiverChannel | DepositType::CoastalDelta => [VeryHigh, High, Low, VeryLow],
+        DepositType::FloodPlain
+        | DepositType::Delta
+        | DepositType::Lacustrine
+        | DepositType::Colluvium
+        | DepositType::Dunes
+        | DepositType::Sebka
+        | DepositType::Estuarine
+        | DepositType::BeachLowWaveEnergy
+        | DepositType::Lagoonal
+        | DepositType::Foreshore => [High, Moderate, Low, VeryLow],
+        DepositType::AlluvialFan => [Moderate, Low, Low, VeryLow],
+        DepositType::BeachHighWaveEnergy => [Moderate, Low, VeryLow, VeryLow],
+        DepositType::MarineTerrace
+        | DepositType::Talus
+        | DepositType::GlacialTill
+        | DepositType::Tuff
+        | DepositType::ResidualSoil => [Low, Low, VeryLow, VeryLow],
+        DepositType::Loess | DepositType::Tephra => [High, High, High, High],
+        DepositType::UncompactedFill => [VeryHigh; 4],
+        DepositType::CompactedFill => [Low; 4],
+    };
+
+    match geologic_age {
+        GeologicAge::LatestHolocene => ratings[0],
+        GeologicAge::Holocene => ratings[1],
+        GeologicAge::Pleistocene => ratings[2],
+        GeologicAge::PrePleistocene => ratings[3],
+    }
+}
+
+/// Reduces a susceptibility for the depth of the groundwater table.
+///
+/// The geologic ratings hold for a groundwater table within 3 m of the surface. Deeper water
+/// leaves fewer saturated granular soils within the depth where liquefaction is observed, so the
+/// rating drops one class for a depth of 3–10 m and two classes for 10–15 m, and is very low
+/// below 15 m.
+///
+/// # Arguments
+/// * `susceptibility` - Susceptibility of the deposit type and age.
+/// * `groundwater_depth` - Depth of the groundwater table (m).
+///
+/// # Returns
+/// * `LiquefactionSusceptibility` for the groundwater depth.
+pub fn reduce_for_groundwater(
+    susceptibility: LiquefactionSusceptibility,
+    groundwater_depth: f64,
+) -> LiquefactionSusceptibility {
+    use LiquefactionSusceptibility::*;
+
+    let classes = [VeryLow, Low, Moderate, High, VeryHigh];
+    let reduction = match groundwater_depth {
+        d if d <= 3.0 => 0,
+        d if d <= 10.0 => 1,
+        d if d <= 15.0 => 2,
+        _ => return VeryLow,
+    };
+
+    classes[(susceptibility as usize).saturating_sub(reduction)]
+}
+
+/// Screens a site for liquefaction susceptibility by its geology and groundwater depth.
+///
+/// The triggering analysis is required when the screened susceptibility is moderate or higher;
+/// sites of low or very low susceptibility can be excluded without it.
+///
+/// # Arguments
+/// * `deposit_type` - Type of the deposit.
+/// * `geologic_age` - Geologic age of the deposit.
+/// * `groundwater_depth` - Depth of the groundwater table (m).
+///
+/// # Returns
+/// * `LiquefactionScreening` with the susceptibility and the verdict.
+pub fn screen_susceptibility(
+    deposit_type: DepositType,
+    geologic_age: GeologicAge,
+    groundwater_depth: f64,
+) -> Result<LiquefactionScreening, ValidationError> {
+    validate_field(
+        "groundwater_depth",
+        Some(groundwater_depth),
+        Some(0.0),
+        None,
+        "liquefaction_screening",
+    )?;
+
+    let geologic_susceptibility = calc_geologic_susceptibility(deposit_type, geologic_age);
+    let susceptibility = reduce_for_groundwater(geologic_susceptibility, groundwater_depth);
+
+    Ok(LiquefactionScreening {
+        deposit_type,
+        geologic_age,
+        groundwater_depth,
+        geologic_susceptibility,
+        susceptibility,
+        requires_analysis: susceptibility >= LiquefactionSusceptibility::Moderate,
+    })
+}
+
+/// Returns the layer result of a depth that is screened out of the triggering analysis.
+fn screened_layer(
+    soil_profile: &SoilProfile,
+    depth: f64,
+    pga: f64,
+    demand: &CyclicStressDemand,
+) -> CommonLiquefactionLayerResult {
+    let normal_stress = soil_profile.calc_normal_stress(depth);
+
+    CommonLiquefactionLayerResult {
+        soil_layer: soil_profile.get_layer_at_depth(depth).clone(),
+        depth,
+        normal_stress,
+        effective_stress: soil_profile.calc_effective_stress(depth),
+        rd: demand.calc_rd(depth, pga, normal_stress),
+        ..Default::default()
+    }
+}
+
+/// Screens the site by geology and runs the SPT based triggering analysis (Seed & Idriss) only
+/// when the site is susceptible.
+///
+/// When the analysis is skipped, the layers are reported without a factor of safety and with
+/// no settlement, and the screening verdict in the result records the justification.
+///
+/// # Arguments
+/// * `soil_profile` - Soil profile data
+/// * `spt` - SPT data
+/// * `pga` - Peak Ground Acceleration
+/// * `mw` - Moment magnitude
+/// * `demand` - Source of the cyclic stress demand
+/// * `deposit_type` - Type of the deposit
+/// * `geologic_age` - Geologic age of the deposit
+///
+/// # Returns
+/// * `SptLiquefactionResult` - Result of liquefaction analysis with the screening verdict
+pub fn calc_spt_liquefaction_screened(
+    soil_profile: &SoilProfile,
+    spt: &mut SPT,
+    pga: f64,
+    mw: f64,
+    demand: &CyclicStressDemand,
+    deposit_type: DepositType,
+    geologic_age: GeologicAge,
+) -> Result<SptLiquefactionResult, ValidationError> {
+    seed_idriss::validate_input(soil_profile, spt)?;
+    demand.validate()?;
+    let screening = screen_susceptibility(
+        deposit_type,
+        geologic_age,
+        soil_profile.ground_water_level.unwrap(),
+    )?;
+
+    if screening.requires_analysis {
+        let mut result =
+            seed_idriss::calc_liquefacion_with_demand(soil_profile, spt, pga, mw, demand)?;
+        result.screening = Some(screening);
+        return Ok(result);
+    }
+
+    let spt_exp = seed_idriss::prepare_spt_exp(spt, soil_profile);
+    let layers = spt_exp
+        .blows
+        .iter()
+        .map(|blow| screened_layer(soil_profile, blow.depth.unwrap(), pga, demand))
+        .collect();
+
+    Ok(SptLiquefactionResult {
+        layers,
+        spt_exp,
+        total_settlement: 0.0,
+        msf: calc_msf(mw),
+        method: MethodId::SeedIdriss,
+        screening: Some(screening),
+    })
+}
+
+/// Screens the site by geology and runs the shear wave velocity based triggering analysis
+/// (Andrus & Stokoe) only when the site is susceptible.
+///
+/// When the analysis is skipped, the layers are reported without a factor of safety and with
+/// no settlement, and the screening verdict in the result records the justification.
+///
+/// # Arguments
+/// * `soil_profile` - Soil profile data
+/// * `masw` - MASW data
+/// * `pga` - Peak Ground Acceleration
+/// * `mw` - Moment magnitude
+/// * `demand` - Source of the cyclic stress demand
+/// * `deposit_type` - Type of the deposit
+/// * `geologic_age` - Geologic age of the deposit
+///
+/// # Returns
+/// * `VSLiquefactionResult` - Result of liquefaction analysis with the screening verdict
+pub fn calc_vs_liquefaction_screened(
+    soil_profile: &mut SoilProfile,
+    masw: &mut Masw,
+    pga: f64,
+    mw: f64,
+    demand: &CyclicStressDemand,
+    deposit_type: DepositType,
+    geologic_age: GeologicAge,
+) -> Result<VSLiquefactionResult, ValidationError> {
+    andrus_stokoe::validate_input(masw, soil_profile)?;
+    demand.validate()?;
+    let screening = screen_susceptibility(
+        deposit_type,
+        geologic_age,
+        soil_profile.ground_water_level.unwrap(),
+    )?;
+
+    if screening.requires_analysis {
+        let mut result =
+            andrus_stokoe::calc_liquefacion_with_demand(soil_profile, masw, pga, mw, demand)?;
+        result.screening = Some(screening);
+        return Ok(result);
+    }
+
+    soil_profile.calc_layer_depths();
+    let layers = soil_profile
+        .layers
+        .iter()
+        .map(|layer| screened_layer(soil_profile, layer.depth.unwrap(), pga, demand))
+        .collect();
+
+    Ok(VSLiquefactionResult {
+        layers,
+        vs_layers: Vec::new(),
+        total_settlement: 0.0,
+        msf: calc_msf(mw),
+        method: MethodId::AndrusStokoe,
+        screening: Some(screening),
+    })
+}
@@ -1 +1,2 @@
 pub mod seed_idriss;
+pub mod tokimatsu_seed;
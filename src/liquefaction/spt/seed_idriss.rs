@@ -1,8 +1,9 @@
 use crate::{
+    enums::MethodId,
     helper::interp1d,
     liquefaction::{
-        helper_functions::{calc_csr, calc_msf, calc_rd},
-        models::{CommonLiquefactionLayerResult, SptLiquefactionResult},
+        helper_functions::{calc_csr, calc_msf},
+        models::{CommonLiquefactionLayerResult, CyclicStressDemand, SptLiquefactionResult},
     },
     models::{
         soil_profile::SoilProfile,
@@ -21,18 +22,13 @@ use crate::{
 /// * `Result` - Ok if validation passes, Err if validation fails
 pub fn validate_input(soil_profile: &SoilProfile, spt: &SPT) -> Result<(), ValidationError> {
     spt.validate(&["n", "depth"])?;
-    soil_profile.validate(&[
-        "thickness",
-        "dry_unit_weight",
-        "saturated_unit_weight",
-        "plasticity_index",
-        "fine_content",
-    ])?;
+    soil_profile.validate(&["thickness", "dry_unit_weight", "saturated_unit_weight"])?;
+    soil_profile.validate_soil_layers(&["plasticity_index", "fine_content"])?;
 
     Ok(())
 }
 
-fn prepare_spt_exp(spt: &mut SPT, soil_profile: &SoilProfile) -> SPTExp {
+pub(crate) fn prepare_spt_exp(spt: &mut SPT, soil_profile: &SoilProfile) -> SPTExp {
     let cs = spt.sampler_correction_factor.unwrap();
     let cb = spt.diameter_correction_factor.unwrap();
     let ce = spt.energy_correction_factor.unwrap();
@@ -114,8 +110,31 @@ pub fn calc_liquefacion(
     spt: &mut SPT,
     pga: f64,
     mw: f64,
+) -> Result<SptLiquefactionResult, ValidationError> {
+    calc_liquefacion_with_demand(soil_profile, spt, pga, mw, &CyclicStressDemand::Simplified)
+}
+
+/// Calculates liquefaction potential with the cyclic stress demand taken from the simplified
+/// procedure or from the shear stress profile of a site response analysis.
+///
+/// # Arguments
+/// * `soil_profile` - Soil profile data
+/// * `spt` - SPT data
+/// * `pga` - Peak Ground Acceleration
+/// * `mw` - Moment magnitude
+/// * `demand` - Source of the cyclic stress demand
+///
+/// # Returns
+/// * `SptLiquefactionResult` - Result of liquefaction analysis
+pub fn calc_liquefacion_with_demand(
+    soil_profile: &SoilProfile,
+    spt: &mut SPT,
+    pga: f64,
+    mw: f64,
+    demand: &CyclicStressDemand,
 ) -> Result<SptLiquefactionResult, ValidationError> {
     validate_input(soil_profile, spt)?;
+    demand.validate()?;
 
     let spt_exp = prepare_spt_exp(spt, soil_profile);
 
@@ -125,13 +144,25 @@ pub fn calc_liquefacion(
     for blow in spt_exp.blows.iter() {
         let thickness = blow.thickness.unwrap();
         let depth = blow.depth.unwrap();
-        let rd = calc_rd(depth);
         let n60 = blow.n60.unwrap().to_i32();
         let n1_60 = blow.n1_60.unwrap().to_i32();
         let n1_60_f = blow.n1_60f.unwrap().to_i32();
         let effective_stress = soil_profile.calc_effective_stress(depth);
         let normal_stress = soil_profile.calc_normal_stress(depth);
+        let rd = demand.calc_rd(depth, pga, normal_stress);
         let soil_layer = soil_profile.get_layer_at_depth(depth);
+        if soil_layer.is_rock() {
+            // Rock layers are not susceptible to liquefaction
+            layer_results.push(CommonLiquefactionLayerResult {
+                soil_layer: soil_layer.clone(),
+                depth,
+                normal_stress,
+                effective_stress,
+                rd,
+                ..Default::default()
+            });
+            continue;
+        }
         let plasticity_index = soil_layer.plasticity_index.unwrap();
 
         let conditions = [
@@ -181,5 +212,7 @@ pub fn calc_liquefacion(
         spt_exp,
         total_settlement,
         msf,
+        method: MethodId::SeedIdriss,
+        screening: None,
     })
 }
@@ -0,0 +1,221 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::MethodId,
+    liquefaction::{
+        helper_functions::{calc_csr, calc_rd},
+        models::SptLiquefactionResult,
+        spt::seed_idriss::{calc_liquefacion, prepare_spt_exp},
+    },
+    models::{soil_profile::SoilProfile, spt::SPT},
+    validation::ValidationError,
+};
+
+/// Atmospheric pressure (t/m²).
+const ATMOSPHERIC_PRESSURE: f64 = 10.332;
+
+/// Result of dry sand settlement analysis for a single layer
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DrySandLayerResult {
+    pub depth: f64,
+    pub thickness: f64,
+    pub n1_60: i32,
+    pub max_shear_modulus: f64, // t/m²
+    pub shear_strain: f64,      // Effective shear strain (unitless)
+    pub volumetric_strain: f64, // Volumetric strain for the design magnitude (unitless)
+    pub settlement: f64,        // cm
+}
+
+/// Result of dry sand settlement analysis for entire soil profile
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DrySandSettlementResult {
+    pub layers: Vec<DrySandLayerResult>, // All layer results
+    pub total_settlement: f64,           // Sum of settlements in cm
+    pub method: MethodId,                // Method used for the analysis
+}
+
+/// Seismic settlement of a single layer combining saturated and dry sand settlements
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeismicSettlementLayer {
+    pub depth: f64,
+    pub liquefaction_settlement: f64, // cm
+    pub dry_sand_settlement: f64,     // cm
+    pub total_settlement: f64,        // cm
+}
+
+/// Result of total seismic settlement analysis
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeismicSettlementResult {
+    pub liquefaction: SptLiquefactionResult,
+    pub dry_sand: DrySandSettlementResult,
+    pub layers: Vec<SeismicSettlementLayer>,
+    pub total_settlement: f64, // cm
+}
+
+/// Validates the soil profile and SPT data
+///
+/// # Arguments
+/// * `soil_profile` - Soil profile data
+/// * `spt` - SPT data
+///
+/// # Returns
+/// * `Result` - Ok if validation passes, Err if validation fails
+pub fn validate_input(soil_profile: &SoilProfile, spt: &SPT) -> Result<(), ValidationError> {
+    spt.validate(&["n", "depth"])?;
+    soil_profile.validate(&["thickness", "dry_unit_weight", "saturated_unit_weight"])?;
+    soil_profile.validate_soil_layers(&["plasticity_index"])?;
+
+    Ok(())
+}
+
+/// Calculates the effective shear strain of a dry sand layer (Pradel, 1998)
+///
+/// # Arguments
+/// * `tau_av` - Average cyclic shear stress in ton/m²
+/// * `mean_stress` - Mean effective stress in ton/m²
+/// * `n1_60` - Corrected SPT value
+///
+/// # Returns
+/// * (Maximum shear modulus in ton/m², effective shear strain)
+pub fn calc_shear_strain(tau_av: f64, mean_stress: f64, n1_60: f64) -> (f64, f64) {
+    let p = mean_stress / ATMOSPHERIC_PRESSURE;
+    let gmax = 447.0 * ATMOSPHERIC_PRESSURE * n1_60.cbrt() * p.sqrt();
+
+    let a = 0.0389 * p + 0.124;
+    let b = 6400.0 * p.powf(-0.6);
+    let ratio = tau_av / gmax;
+    let shear_strain = (1.0 + a * (b * ratio).exp()) / (1.0 + a) * ratio;
+
+    (gmax, shear_strain)
+}
+
+/// Calculates the volumetric strain of a dry sand layer (Tokimatsu & Seed, 1987)
+///
+/// # Arguments
+/// * `shear_strain` - Effective shear strain
+/// * `n1_60` - Corrected SPT value
+/// * `mw` - Moment magnitude
+///
+/// # Returns
+/// * Volumetric strain for the design magnitude
+pub fn calc_volumetric_strain(shear_strain: f64, n1_60: f64, mw: f64) -> f64 {
+    let strain_15 = shear_strain * (n1_60 / 20.0).powf(-1.2);
+    let number_of_cycles = (mw - 4.0).powf(2.17);
+    strain_15 * (number_of_cycles / 15.0).powf(0.45)
+}
+
+/// Calculates earthquake-induced settlement of dry sands above the water table
+///
+/// The settlement of each layer is taken as twice the volumetric strain times the layer
+/// thickness to account for multi-directional shaking. Layers below the water table, rock
+/// layers and plastic layers (PI ≥ 12) are not settled.
+///
+/// # Arguments
+/// * `soil_profile` - Soil profile data
+/// * `spt` - SPT data
+/// * `pga` - Peak Ground Acceleration
+/// * `mw` - Moment magnitude
+///
+/// # Returns
+/// * `DrySandSettlementResult` - Result of dry sand settlement analysis
+pub fn calc_dry_sand_settlement(
+    soil_profile: &SoilProfile,
+    spt: &mut SPT,
+    pga: f64,
+    mw: f64,
+) -> Result<DrySandSettlementResult, ValidationError> {
+    validate_input(soil_profile, spt)?;
+
+    let spt_exp = prepare_spt_exp(spt, soil_profile);
+    let gwt = soil_profile.ground_water_level.unwrap();
+    let mut layer_results = Vec::new();
+
+    for blow in spt_exp.blows.iter() {
+        let depth = blow.depth.unwrap();
+        let thickness = blow.thickness.unwrap();
+        let n1_60 = blow.n1_60.unwrap().to_i32();
+        let soil_layer = soil_profile.get_layer_at_depth(depth);
+
+        let conditions = [
+            depth > gwt,
+            soil_layer.is_rock(),
+            soil_layer.plasticity_index.unwrap_or(0.0) >= 12.,
+        ];
+        if conditions.iter().any(|&x| x) {
+            layer_results.push(DrySandLayerResult {
+                depth,
+                thickness,
+                n1_60,
+                ..Default::default()
+            });
+            continue;
+        }
+
+        let normal_stress = soil_profile.calc_normal_stress(depth);
+        let effective_stress = soil_profile.calc_effective_stress(depth);
+        let tau_av = calc_csr(pga, normal_stress, calc_rd(depth));
+        let mean_stress = (1.0 + 2.0 * 0.5) / 3.0 * effective_stress;
+
+        let n1 = (n1_60 as f64).max(1.0);
+        let (max_shear_modulus, shear_strain) = calc_shear_strain(tau_av, mean_stress, n1);
+        let volumetric_strain = calc_volumetric_strain(shear_strain, n1, mw);
+
+        layer_results.push(DrySandLayerResult {
+            depth,
+            thickness,
+            n1_60,
+            max_shear_modulus,
+            shear_strain,
+            volumetric_strain,
+            settlement: 2.0 * volumetric_strain * thickness * 100.0,
+        });
+    }
+
+    let total_settlement = layer_results.iter().map(|x| x.settlement).sum();
+    Ok(DrySandSettlementResult {
+        layers: layer_results,
+        total_settlement,
+        method: MethodId::TokimatsuSeed,
+    })
+}
+
+/// Calculates total seismic settlement combining liquefaction and dry sand settlements
+///
+/// # Arguments
+/// * `soil_profile` - Soil profile data
+/// * `spt` - SPT data
+/// * `pga` - Peak Ground Acceleration
+/// * `mw` - Moment magnitude
+///
+/// # Returns
+/// * `SeismicSettlementResult` - Settlement profile and total settlement
+pub fn calc_seismic_settlement(
+    soil_profile: &SoilProfile,
+    spt: &mut SPT,
+    pga: f64,
+    mw: f64,
+) -> Result<SeismicSettlementResult, ValidationError> {
+    let liquefaction = calc_liquefacion(soil_profile, spt, pga, mw)?;
+    let dry_sand = calc_dry_sand_settlement(soil_profile, spt, pga, mw)?;
+
+    let layers: Vec<SeismicSettlementLayer> = liquefaction
+        .layers
+        .iter()
+        .zip(dry_sand.layers.iter())
+        .map(|(liq, dry)| SeismicSettlementLayer {
+            depth: dry.depth,
+            liquefaction_settlement: liq.settlement,
+            dry_sand_settlement: dry.settlement,
+            total_settlement: liq.settlement + dry.settlement,
+        })
+        .collect();
+
+    let total_settlement = liquefaction.total_settlement + dry_sand.total_settlement;
+
+    Ok(SeismicSettlementResult {
+        liquefaction,
+        dry_sand,
+        layers,
+        total_settlement,
+    })
+}
@@ -1,8 +1,12 @@
 use crate::{
+    enums::MethodId,
     helper::interp1d,
     liquefaction::{
-        helper_functions::{calc_csr, calc_msf, calc_rd},
-        models::{CommonLiquefactionLayerResult, VSLiquefactionLayerResult, VSLiquefactionResult},
+        helper_functions::{calc_csr, calc_msf},
+        models::{
+            CommonLiquefactionLayerResult, CyclicStressDemand, VSLiquefactionLayerResult,
+            VSLiquefactionResult,
+        },
     },
     models::{masw::Masw, soil_profile::SoilProfile},
     validation::ValidationError,
@@ -18,13 +22,8 @@ use crate::{
 /// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
 pub fn validate_input(masw: &Masw, soil_profile: &SoilProfile) -> Result<(), ValidationError> {
     masw.validate(&["thickness", "vs"])?;
-    soil_profile.validate(&[
-        "thickness",
-        "dry_unit_weight",
-        "saturated_unit_weight",
-        "plasticity_index",
-        "fine_content",
-    ])?;
+    soil_profile.validate(&["thickness", "dry_unit_weight", "saturated_unit_weight"])?;
+    soil_profile.validate_soil_layers(&["plasticity_index", "fine_content"])?;
 
     Ok(())
 }
@@ -122,8 +121,31 @@ pub fn calc_liquefacion(
     masw: &mut Masw,
     pga: f64,
     mw: f64,
+) -> Result<VSLiquefactionResult, ValidationError> {
+    calc_liquefacion_with_demand(soil_profile, masw, pga, mw, &CyclicStressDemand::Simplified)
+}
+
+/// Calculates liquefaction potential with the cyclic stress demand taken from the simplified
+/// procedure or from the shear stress profile of a site response analysis.
+///
+/// # Arguments
+/// * `soil_profile` - Soil profile data
+/// * `masw` - MASW data
+/// * `pga` - Peak Ground Acceleration
+/// * `mw` - Moment magnitude
+/// * `demand` - Source of the cyclic stress demand
+///
+/// # Returns
+/// * `VSLiquefactionResult` - Result of liquefaction analysis
+pub fn calc_liquefacion_with_demand(
+    soil_profile: &mut SoilProfile,
+    masw: &mut Masw,
+    pga: f64,
+    mw: f64,
+    demand: &CyclicStressDemand,
 ) -> Result<VSLiquefactionResult, ValidationError> {
     validate_input(masw, soil_profile)?;
+    demand.validate()?;
     soil_profile.calc_layer_depths();
 
     let mut masw_exp = masw.get_idealized_exp("idealized".to_string());
@@ -136,10 +158,22 @@ pub fn calc_liquefacion(
     for layer in soil_profile.layers.iter() {
         let thickness = layer.thickness.unwrap();
         let depth = layer.depth.unwrap();
-        let rd = calc_rd(depth);
         let effective_stress = soil_profile.calc_effective_stress(depth);
         let normal_stress = soil_profile.calc_normal_stress(depth);
+        let rd = demand.calc_rd(depth, pga, normal_stress);
         let soil_layer = soil_profile.get_layer_at_depth(depth);
+        if soil_layer.is_rock() {
+            // Rock layers are not susceptible to liquefaction
+            layer_results.push(CommonLiquefactionLayerResult {
+                soil_layer: soil_layer.clone(),
+                depth,
+                normal_stress,
+                effective_stress,
+                rd,
+                ..Default::default()
+            });
+            continue;
+        }
         let plasticity_index = soil_layer.plasticity_index.unwrap();
         let masw_layer = masw_exp.get_layer_at_depth(depth);
         let vs = masw_layer.vs.unwrap();
@@ -201,5 +235,7 @@ pub fn calc_liquefacion(
         vs_layers,
         total_settlement,
         msf,
+        method: MethodId::AndrusStokoe,
+        screening: None,
     })
 }
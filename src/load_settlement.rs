@@ -0,0 +1,157 @@
+use serde::Serialize;
+
+use crate::{
+    bearing_capacity::vesic,
+    elastic_settlement::boussinesq::calc_elastic_settlement,
+    enums::{AnalysisTerm, PressureConvention},
+    models::{foundation::Foundation, loads::Loads, soil_profile::SoilProfile},
+    validation::{validate_field, ValidationError},
+};
+
+/// Largest pressure of the generated curve as a ratio of the ultimate bearing capacity, since the
+/// hyperbola reaches qult only at infinite settlement.
+const MAX_LOAD_RATIO: f64 = 0.95;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LoadSettlementCurve {
+    /// Ultimate bearing capacity, the asymptote of the curve (t/m²).
+    pub ultimate_bearing_capacity: f64,
+    /// Initial tangent stiffness of the curve (t/m² per cm).
+    pub initial_stiffness: f64,
+    /// (Foundation pressure (t/m²), settlement (cm)) pairs.
+    pub points: Vec<(f64, f64)>,
+}
+
+/// Calculates the settlement of a footing from the hyperbolic load-settlement model.
+///
+/// q = s / (1 / k0 + s / qult)  →  s = q / (k0 · (1 − q / qult))
+///
+/// # Arguments
+/// * `pressure` - Foundation pressure q (t/m²).
+/// * `ultimate_bearing_capacity` - Ultimate bearing capacity qult (t/m²).
+/// * `initial_stiffness` - Initial tangent stiffness k0 (t/m² per cm).
+///
+/// # Returns
+/// * `f64`: Settlement (cm). Infinite when q ≥ qult.
+pub fn calc_hyperbolic_settlement(
+    pressure: f64,
+    ultimate_bearing_capacity: f64,
+    initial_stiffness: f64,
+) -> f64 {
+    if pressure >= ultimate_bearing_capacity {
+        return f64::INFINITY;
+    }
+    pressure / (initial_stiffness * (1.0 - pressure / ultimate_bearing_capacity))
+}
+
+/// Generates the points of a hyperbolic load-settlement curve.
+///
+/// The pressures are evenly spaced between zero and 0.95 · qult.
+///
+/// # Arguments
+/// * `ultimate_bearing_capacity` - Ultimate bearing capacity qult (t/m²).
+/// * `initial_stiffness` - Initial tangent stiffness k0 (t/m² per cm).
+/// * `point_count` - Number of intervals of the curve.
+///
+/// # Returns
+/// * `Vec<(f64, f64)>`: (pressure, settlement) pairs, starting at the origin.
+pub fn generate_hyperbolic_curve(
+    ultimate_bearing_capacity: f64,
+    initial_stiffness: f64,
+    point_count: usize,
+) -> Result<Vec<(f64, f64)>, ValidationError> {
+    validate_field(
+        "ultimate_bearing_capacity",
+        Some(ultimate_bearing_capacity),
+        Some(0.0001),
+        None,
+        "load_settlement",
+    )?;
+    validate_field(
+        "initial_stiffness",
+        Some(initial_stiffness),
+        Some(0.0001),
+        None,
+        "load_settlement",
+    )?;
+    validate_field(
+        "point_count",
+        Some(point_count as f64),
+        Some(1.0),
+        None,
+        "load_settlement",
+    )?;
+
+    let max_pressure = MAX_LOAD_RATIO * ultimate_bearing_capacity;
+    let points = (0..=point_count)
+        .map(|i| {
+            let pressure = max_pressure * i as f64 / point_count as f64;
+            let settlement =
+                calc_hyperbolic_settlement(pressure, ultimate_bearing_capacity, initial_stiffness);
+            (pressure, settlement)
+        })
+        .collect();
+
+    Ok(points)
+}
+
+/// Generates a nonlinear load-settlement curve of a footing for pushover or soil-structure
+/// interaction models.
+///
+/// The hyperbola is anchored at the ultimate bearing capacity from Vesic and at an initial
+/// stiffness equal to the secant stiffness of the elastic (Boussinesq) settlement under
+/// `foundation_pressure`.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `foundation` - The foundation data.
+/// * `loading` - The applied loads.
+/// * `foundation_pressure` - The service pressure on the foundation (t/m²).
+/// * `term` - Short, long or automatically selected term.
+/// * `point_count` - Number of intervals of the curve.
+///
+/// # Returns
+/// * `LoadSettlementCurve` with the curve points.
+pub fn calc_load_settlement_curve(
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    loading: &Loads,
+    foundation_pressure: f64,
+    term: AnalysisTerm,
+    point_count: usize,
+) -> Result<LoadSettlementCurve, ValidationError> {
+    let bearing_capacity = vesic::calc_bearing_capacity(
+        &mut soil_profile.clone(),
+        &mut foundation.clone(),
+        loading,
+        foundation_pressure,
+        PressureConvention::Gross,
+        1.0,
+        term,
+    )?;
+    let elastic_settlement = calc_elastic_settlement(
+        &mut soil_profile.clone(),
+        foundation,
+        foundation_pressure,
+        PressureConvention::Gross,
+    )?;
+
+    if elastic_settlement.total_settlement <= 0.0 {
+        return Err(ValidationError {
+            code: "load_settlement.initial_stiffness.undefined".to_string(),
+            message: "Elastic settlement under the foundation pressure must be positive."
+                .to_string(),
+        });
+    }
+
+    let ultimate_bearing_capacity = bearing_capacity.ultimate_bearing_capacity;
+    let initial_stiffness = foundation_pressure / elastic_settlement.total_settlement;
+    let points =
+        generate_hyperbolic_curve(ultimate_bearing_capacity, initial_stiffness, point_count)?;
+
+    Ok(LoadSettlementCurve {
+        ultimate_bearing_capacity,
+        initial_stiffness,
+        points,
+    })
+}
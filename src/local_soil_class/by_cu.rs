@@ -1,4 +1,4 @@
-use crate::{models::soil_profile::SoilProfile, validation::ValidationError};
+use crate::{enums::MethodId, models::soil_profile::SoilProfile, validation::ValidationError};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -21,6 +21,8 @@ pub struct CuSoilClassificationResult {
     pub cu_30: f64,
     /// Final local soil class (e.g., ZE, ZD, ZC)
     pub soil_class: String,
+    /// Method used for the analysis
+    pub method: MethodId,
 }
 
 /// Validates the input data for local soil classification calculations.
@@ -105,5 +107,6 @@ pub fn calc_lsc_by_cu(
         sum_h_over_cu,
         cu_30,
         soil_class,
+        method: MethodId::LocalSoilClassByCu,
     })
 }
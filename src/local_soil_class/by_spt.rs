@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    enums::MethodId,
     models::spt::{SPTExp, SPT},
     validation::ValidationError,
 };
@@ -25,6 +26,8 @@ pub struct SptSoilClassificationResult {
     pub n_30: f64,
     /// Final local soil class (e.g., ZE, ZD, ZC)
     pub soil_class: String,
+    /// Method used for the analysis
+    pub method: MethodId,
 }
 
 /// Validates the soil profile and SPT data
@@ -135,5 +138,6 @@ pub fn calc_lsc_by_spt(spt: &mut SPT) -> Result<SptSoilClassificationResult, Val
         sum_h_over_n,
         n_30,
         soil_class,
+        method: MethodId::LocalSoilClassBySpt,
     })
 }
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    enums::MethodId,
     models::masw::{Masw, MaswExp},
     validation::ValidationError,
 };
@@ -25,6 +26,8 @@ pub struct VsSoilClassificationResult {
     pub vs_30: f64,
     /// Final local soil class (e.g., ZE, ZD, ZC, ZB, ZA)
     pub soil_class: String,
+    /// Method used for the analysis
+    pub method: MethodId,
 }
 
 /// Validates the input data for local soil class calculations.
@@ -110,5 +113,6 @@ pub fn calc_lsc_by_vs(masw: &mut Masw) -> Result<VsSoilClassificationResult, Val
         sum_h_over_vs,
         vs_30,
         soil_class,
+        method: MethodId::LocalSoilClassByVs,
     })
 }
@@ -0,0 +1,401 @@
+use serde::Serialize;
+
+use crate::enums::{MethodId, SoilType};
+
+const ALL_SOILS: &[SoilType] = &[SoilType::Cohesive, SoilType::Granular, SoilType::Rock];
+const SOILS: &[SoilType] = &[SoilType::Cohesive, SoilType::Granular];
+const COHESIVE: &[SoilType] = &[SoilType::Cohesive];
+const GRANULAR: &[SoilType] = &[SoilType::Granular];
+const ROCK: &[SoilType] = &[SoilType::Rock];
+
+/// All registered methods.
+const ALL_METHODS: &[MethodId] = &[
+    MethodId::Vesic,
+    MethodId::Meyerhof,
+    MethodId::Hansen,
+    MethodId::BudhuAlKarni,
+    MethodId::MeyerhofSpt,
+    MethodId::TezcanOzdemir,
+    MethodId::Menard,
+    MethodId::PointLoadTest,
+    MethodId::HoekBrown,
+    MethodId::SkirtedFoundation,
+    MethodId::VhmEnvelope,
+    MethodId::EngineeredFill,
+    MethodId::Boussinesq,
+    MethodId::CompressionIndex,
+    MethodId::VolumeCompressibility,
+    MethodId::SeedIdriss,
+    MethodId::TokimatsuSeed,
+    MethodId::AndrusStokoe,
+    MethodId::LocalSoilClassByCu,
+    MethodId::LocalSoilClassBySpt,
+    MethodId::LocalSoilClassByVs,
+    MethodId::KayabaliYaldiz,
+    MethodId::HorizontalSliding,
+    MethodId::GroundAnchor,
+    MethodId::PiledRaft,
+    MethodId::CyclicDegradation,
+    MethodId::ModifiedIowa,
+    MethodId::Bornitz,
+    MethodId::EnrDrivability,
+    MethodId::GatesDrivability,
+    MethodId::Hec18Scour,
+    MethodId::KenneyLau,
+    MethodId::Hazen,
+    MethodId::FilterCriteria,
+    MethodId::ParameterSelection,
+    MethodId::SptAnchor,
+    MethodId::Keceli,
+    MethodId::Schmertmann,
+    MethodId::Hough,
+    MethodId::Janbu,
+];
+
+/// Range of an input parameter over which a method is calibrated.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct ParameterLimit {
+    /// Name of the input parameter.
+    pub parameter: &'static str,
+    /// Lower bound of the calibration range.
+    pub min: Option<f64>,
+    /// Upper bound of the calibration range.
+    pub max: Option<f64>,
+}
+
+impl ParameterLimit {
+    /// Returns whether a value is within the calibration range.
+    pub fn contains(&self, value: f64) -> bool {
+        self.min.is_none_or(|min| value >= min) && self.max.is_none_or(|max| value <= max)
+    }
+}
+
+/// Registry entry of an implemented method.
+#[derive(Debug, Clone, Serialize)]
+pub struct MethodInfo {
+    /// Identifier of the method.
+    pub id: MethodId,
+    /// Name of the method.
+    pub name: &'static str,
+    /// Literature reference of the method.
+    pub citation: &'static str,
+    /// Version of the implementation.
+    pub version: &'static str,
+    /// Soil types the method is applicable to.
+    pub soil_types: &'static [SoilType],
+    /// Calibration ranges of the input parameters.
+    pub limits: &'static [ParameterLimit],
+}
+
+impl MethodInfo {
+    /// Returns the calibration range of an input parameter, if the method has one.
+    ///
+    /// # Arguments
+    /// * `parameter` - Name of the input parameter.
+    pub fn get_limit(&self, parameter: &str) -> Option<&ParameterLimit> {
+        self.limits.iter().find(|l| l.parameter == parameter)
+    }
+
+    /// Returns whether the method is applicable to a soil type.
+    pub fn is_applicable_to(&self, soil_type: SoilType) -> bool {
+        self.soil_types.contains(&soil_type)
+    }
+}
+
+/// Returns the registry entry of a method.
+///
+/// # Arguments
+/// * `id` - Identifier of the method.
+///
+/// # Returns
+/// * `MethodInfo` with the citation, version and applicability limits.
+pub fn get_method_info(id: MethodId) -> MethodInfo {
+    let (name, citation, soil_types, limits): (_, _, _, &'static [ParameterLimit]) = match id {
+        MethodId::Vesic => (
+            "Vesic bearing capacity",
+            "Vesic, A.S. (1973). Analysis of ultimate loads of shallow foundations. JSMFD, ASCE, 99(1).",
+            SOILS,
+            &[ParameterLimit { parameter: "phi", min: Some(0.0), max: Some(50.0) }],
+        ),
+        MethodId::Meyerhof => (
+            "Meyerhof bearing capacity",
+            "Meyerhof, G.G. (1963). Some recent research on the bearing capacity of foundations. Canadian Geotechnical Journal, 1(1).",
+            SOILS,
+            &[ParameterLimit { parameter: "phi", min: Some(0.0), max: Some(45.0) }],
+        ),
+        MethodId::Hansen => (
+            "Brinch Hansen bearing capacity",
+            "Brinch Hansen, J. (1970). A revised and extended formula for bearing capacity. Danish Geotechnical Institute, Bulletin No. 28.",
+            SOILS,
+            &[ParameterLimit { parameter: "phi", min: Some(0.0), max: Some(50.0) }],
+        ),
+        MethodId::BudhuAlKarni => (
+            "Budhu & Al-Karni seismic bearing capacity",
+            "Budhu, M. & Al-Karni, A. (1993). Seismic bearing capacity of soils. Géotechnique, 43(1), 181-187.",
+            SOILS,
+            &[ParameterLimit { parameter: "kh", min: Some(0.0), max: Some(0.4) }],
+        ),
+        MethodId::MeyerhofSpt => (
+            "SPT allowable bearing pressure",
+            "Meyerhof, G.G. (1956). Penetration tests and bearing capacity of cohesionless soils. JSMFD, ASCE, 82(SM1); Bowles, J.E. (1977). Foundation Analysis and Design, 2nd ed. McGraw-Hill.",
+            GRANULAR,
+            &[ParameterLimit { parameter: "n60", min: Some(4.0), max: Some(50.0) }],
+        ),
+        MethodId::TezcanOzdemir => (
+            "Tezcan & Ozdemir bearing capacity",
+            "Tezcan, S.S. & Ozdemir, Z. (2007). A refined formula for the allowable bearing pressure based on shear wave velocity. Proc. 4th ICEGE.",
+            ALL_SOILS,
+            &[ParameterLimit { parameter: "vs", min: Some(150.0), max: None }],
+        ),
+        MethodId::Menard => (
+            "Menard pressuremeter bearing capacity",
+            "Menard, L. (1963). Calcul de la force portante des fondations sur la base des résultats des essais pressiométriques. Sols-Soils, 5-6; Fascicule 62 Titre V (1993). Règles techniques de conception et de calcul des fondations des ouvrages de génie civil.",
+            ALL_SOILS,
+            &[],
+        ),
+        MethodId::PointLoadTest => (
+            "Point load test bearing capacity",
+            "ISRM (1985). Suggested method for determining point load strength. IJRMMS, 22(2).",
+            ROCK,
+            &[ParameterLimit { parameter: "diameter", min: Some(20.0), max: Some(60.0) }],
+        ),
+        MethodId::HoekBrown => (
+            "Hoek-Brown rock bearing capacity",
+            "Hoek, E., Carranza-Torres, C. & Corkum, B. (2002). Hoek-Brown failure criterion – 2002 edition. Proc. NARMS-TAC; Wyllie, D.C. (1999). Foundations on Rock, 2nd ed. E & FN Spon.",
+            ROCK,
+            &[ParameterLimit { parameter: "gsi", min: Some(10.0), max: Some(100.0) }],
+        ),
+        MethodId::SkirtedFoundation => (
+            "Skirted foundation capacity",
+            "Supachawarote, C., Randolph, M.F. & Gourvenec, S. (2004). Inclined pull-out capacity of suction caissons. Proc. 14th ISOPE.",
+            COHESIVE,
+            &[ParameterLimit { parameter: "embedment_ratio", min: Some(0.0), max: Some(6.0) }],
+        ),
+        MethodId::VhmEnvelope => (
+            "V-H-M failure envelope",
+            "Gottardi, G., Houlsby, G.T. & Butterfield, R. (1999). Plastic response of circular footings on sand under general planar loading. Géotechnique, 49(4).",
+            GRANULAR,
+            &[],
+        ),
+        MethodId::EngineeredFill => (
+            "Bearing capacity of engineered fill over soft clay",
+            "Meyerhof, G.G. & Hanna, A.M. (1978). Ultimate bearing capacity of foundations on layered soils under inclined load. CGJ, 15(4).",
+            SOILS,
+            &[ParameterLimit { parameter: "phi", min: Some(25.0), max: Some(50.0) }],
+        ),
+        MethodId::Boussinesq => (
+            "Elastic settlement",
+            "Bowles, J.E. (1996). Foundation Analysis and Design, 5th Ed. McGraw-Hill.",
+            ALL_SOILS,
+            &[
+                ParameterLimit { parameter: "poisson_ratio", min: Some(0.0), max: Some(0.5) },
+                ParameterLimit { parameter: "depth_ratio", min: Some(0.05), max: Some(2.0) },
+                ParameterLimit { parameter: "length_ratio", min: Some(1.0), max: Some(5.0) },
+            ],
+        ),
+        MethodId::CompressionIndex => (
+            "Consolidation settlement by compression index",
+            "Terzaghi, K. & Peck, R.B. (1967). Soil Mechanics in Engineering Practice, 2nd Ed. Wiley.",
+            COHESIVE,
+            &[],
+        ),
+        MethodId::VolumeCompressibility => (
+            "Consolidation settlement by coefficient of volume compressibility",
+            "Terzaghi, K. & Peck, R.B. (1967). Soil Mechanics in Engineering Practice, 2nd Ed. Wiley.",
+            COHESIVE,
+            &[],
+        ),
+        MethodId::SeedIdriss => (
+            "SPT-based liquefaction",
+            "Youd, T.L. et al. (2001). Liquefaction resistance of soils: summary report from the 1996 NCEER and 1998 NCEER/NSF workshops. JGGE, ASCE, 127(10).",
+            GRANULAR,
+            &[
+                ParameterLimit { parameter: "n1_60f", min: None, max: Some(30.0) },
+                ParameterLimit { parameter: "mw", min: Some(5.25), max: Some(8.5) },
+            ],
+        ),
+        MethodId::TokimatsuSeed => (
+            "Dry sand seismic settlement",
+            "Tokimatsu, K. & Seed, H.B. (1987). Evaluation of settlements in sands due to earthquake shaking. JGE, ASCE, 113(8).",
+            GRANULAR,
+            &[ParameterLimit { parameter: "mw", min: Some(5.25), max: Some(8.5) }],
+        ),
+        MethodId::AndrusStokoe => (
+            "Vs-based liquefaction",
+            "Andrus, R.D. & Stokoe, K.H. (2000). Liquefaction resistance of soils from shear-wave velocity. JGGE, ASCE, 126(11).",
+            GRANULAR,
+            &[
+                ParameterLimit { parameter: "fine_content", min: Some(0.0), max: Some(35.0) },
+                ParameterLimit { parameter: "mw", min: Some(5.5), max: Some(8.0) },
+            ],
+        ),
+        MethodId::LocalSoilClassByCu => (
+            "Local soil class by undrained shear strength",
+            "TBDY (2018). Turkish Building Earthquake Code, Section 16.",
+            COHESIVE,
+            &[],
+        ),
+        MethodId::LocalSoilClassBySpt => (
+            "Local soil class by SPT",
+            "TBDY (2018). Turkish Building Earthquake Code, Section 16.",
+            SOILS,
+            &[],
+        ),
+        MethodId::LocalSoilClassByVs => (
+            "Local soil class by shear wave velocity",
+            "TBDY (2018). Turkish Building Earthquake Code, Section 16.",
+            ALL_SOILS,
+            &[],
+        ),
+        MethodId::KayabaliYaldiz => (
+            "Swelling pressure",
+            "Kayabalı, K. & Yaldız, Ö. (2014). Estimation of swelling pressure using simple soil indices. Bull. Eng. Geol. Environ., 73.",
+            COHESIVE,
+            &[
+                ParameterLimit { parameter: "water_content", min: Some(5.0), max: Some(50.0) },
+                ParameterLimit { parameter: "dry_unit_weight", min: Some(1.2), max: Some(2.0) },
+                ParameterLimit { parameter: "liquid_limit", min: Some(30.0), max: Some(110.0) },
+                ParameterLimit { parameter: "plastic_limit", min: Some(15.0), max: Some(45.0) },
+            ],
+        ),
+        MethodId::HorizontalSliding => (
+            "Horizontal sliding",
+            "TBDY (2018). Turkish Building Earthquake Code, Section 16.",
+            SOILS,
+            &[],
+        ),
+        MethodId::GroundAnchor => (
+            "Ground anchor design",
+            "Sabatini, P.J. et al. (1999). Ground Anchors and Anchored Systems. FHWA-IF-99-015.",
+            ALL_SOILS,
+            &[ParameterLimit { parameter: "fixed_length", min: Some(3.0), max: Some(12.0) }],
+        ),
+        MethodId::PiledRaft => (
+            "Piled raft load sharing",
+            "Randolph, M.F. (1994). Design methods for pile groups and piled rafts. Proc. 13th ICSMFE.",
+            SOILS,
+            &[],
+        ),
+        MethodId::CyclicDegradation => (
+            "Cyclic degradation of clays",
+            "Idriss, I.M., Dobry, R. & Singh, R.D. (1978). Nonlinear behavior of soft clays during cyclic loading. JGED, ASCE, 104(12).",
+            COHESIVE,
+            &[],
+        ),
+        MethodId::ModifiedIowa => (
+            "Buried flexible pipe deflection",
+            "Spangler, M.G. (1941). The structural design of flexible pipe culverts. Iowa Eng. Exp. Station Bull. 153.",
+            SOILS,
+            &[],
+        ),
+        MethodId::Bornitz => (
+            "Ground vibration attenuation",
+            "Bornitz, G. (1931). Über die Ausbreitung der von Großkolbenmaschinen erzeugten Bodenschwingungen in die Tiefe. Springer.",
+            ALL_SOILS,
+            &[ParameterLimit { parameter: "frequency", min: Some(1.0), max: Some(100.0) }],
+        ),
+        MethodId::EnrDrivability => (
+            "ENR pile driving formula",
+            "Engineering News Record (1888). Wellington, A.M. Piles and pile driving.",
+            SOILS,
+            &[],
+        ),
+        MethodId::GatesDrivability => (
+            "Gates pile driving formula",
+            "Gates, M. (1957). Empirical formula for predicting pile bearing capacity. Civil Engineering, ASCE, 27(3).",
+            SOILS,
+            &[],
+        ),
+        MethodId::Hec18Scour => (
+            "Bridge pier scour",
+            "Arneson, L.A. et al. (2012). Evaluating Scour at Bridges, HEC-18, 5th Ed. FHWA-HIF-12-003.",
+            SOILS,
+            &[],
+        ),
+        MethodId::KenneyLau => (
+            "Internal stability of granular soils",
+            "Kenney, T.C. & Lau, D. (1985, 1986). Internal stability of granular filters. CGJ, 22(2) and 23(3).",
+            GRANULAR,
+            &[],
+        ),
+        MethodId::Hazen => (
+            "Permeability from effective size",
+            "Hazen, A. (1911). Discussion of 'Dams on sand foundations'. Trans. ASCE, 73.",
+            GRANULAR,
+            &[
+                ParameterLimit { parameter: "d10", min: Some(0.1), max: Some(3.0) },
+                ParameterLimit { parameter: "uniformity_coefficient", min: None, max: Some(5.0) },
+            ],
+        ),
+        MethodId::FilterCriteria => (
+            "Granular filter criteria",
+            "USACE (2004). General Design and Construction Considerations for Earth and Rock-Fill Dams. EM 1110-2-2300.",
+            GRANULAR,
+            &[],
+        ),
+        MethodId::ParameterSelection => (
+            "Design parameter selection",
+            "Schneider, H.R. (1999). Determination of characteristic soil properties. Proc. 12th ECSMGE; EN 1997-1 (2004).",
+            ALL_SOILS,
+            &[],
+        ),
+        MethodId::SptAnchor => (
+            "Tieback and screw anchor capacity from SPT",
+            "Stroud, M.A. (1974). The standard penetration test in insensitive clays and soft rocks. Proc. ESOPT; Meyerhof, G.G. (1976). Bearing capacity and settlement of pile foundations. JGED, ASCE, 102(3).",
+            SOILS,
+            &[ParameterLimit { parameter: "n60", min: Some(2.0), max: Some(50.0) }],
+        ),
+        MethodId::Keceli => (
+            "Keçeli bearing capacity",
+            "Keçeli, A. (2012). Soil parameters which can be determined with seismic velocities. Jeofizik, 16, 17-29.",
+            ALL_SOILS,
+            &[ParameterLimit { parameter: "vs", min: Some(150.0), max: None }],
+        ),
+        MethodId::Schmertmann => (
+            "Strain influence factor settlement from CPT",
+            "Schmertmann, J.H., Hartman, J.P. & Brown, P.R. (1978). Improved strain influence factor diagrams. JGED, ASCE, 104(8).",
+            SOILS,
+            &[],
+        ),
+        MethodId::Hough => (
+            "Hough bearing capacity index settlement",
+            "Hough, B.K. (1959). Compressibility as the basis for soil bearing value. JSMFD, ASCE, 85(4); AASHTO LRFD (2020), 10.6.2.4.2.",
+            GRANULAR,
+            &[ParameterLimit { parameter: "n1_60", min: Some(0.0), max: Some(60.0) }],
+        ),
+        MethodId::Janbu => (
+            "Janbu immediate settlement",
+            "Christian, J.T. & Carrier, W.D. (1978). Janbu, Bjerrum and Kjaernsli's chart reinterpreted. Canadian Geotechnical Journal, 15(1).",
+            COHESIVE,
+            &[
+                ParameterLimit { parameter: "depth_ratio", min: Some(0.0), max: Some(20.0) },
+                ParameterLimit { parameter: "thickness_ratio", min: Some(0.0), max: Some(30.0) },
+            ],
+        ),
+    };
+
+    MethodInfo {
+        id,
+        name,
+        citation,
+        version: "1.0.0",
+        soil_types,
+        limits,
+    }
+}
+
+/// Returns the registry entries of all implemented methods.
+pub fn list_methods() -> Vec<MethodInfo> {
+    ALL_METHODS.iter().map(|&id| get_method_info(id)).collect()
+}
+
+/// Returns the registry entries of the methods applicable to a soil type.
+///
+/// # Arguments
+/// * `soil_type` - The soil type.
+pub fn find_methods(soil_type: SoilType) -> Vec<MethodInfo> {
+    list_methods()
+        .into_iter()
+        .filter(|m| m.is_applicable_to(soil_type))
+        .collect()
+}
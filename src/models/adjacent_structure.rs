@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+
+use crate::validation::{validate_field, ValidationError};
+
+/// Represents the foundation of an existing structure next to a new foundation.
+///
+/// The offsets locate the center of the existing foundation relative to the center of the new
+/// foundation in plan. Both foundations are rectangular with parallel sides.
+///
+/// # Fields
+/// * `width` - Width of the existing foundation along the new foundation width (m).
+/// * `length` - Length of the existing foundation along the new foundation length (m).
+/// * `depth` - Depth of the existing foundation base below the ground surface (m).
+/// * `offset_x` - Distance from the new foundation center to the existing foundation center along the width (m).
+/// * `offset_y` - Distance from the new foundation center to the existing foundation center along the length (m).
+/// * `foundation_pressure` - Pressure of the existing foundation on the soil (t/m²).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AdjacentStructure {
+    /// Width of the existing foundation (m).
+    pub width: Option<f64>,
+    /// Length of the existing foundation (m).
+    pub length: Option<f64>,
+    /// Depth of the existing foundation base (m).
+    pub depth: Option<f64>,
+    /// Offset of the existing foundation center along the width (m).
+    pub offset_x: Option<f64>,
+    /// Offset of the existing foundation center along the length (m).
+    pub offset_y: Option<f64>,
+    /// Pressure of the existing foundation (t/m²).
+    pub foundation_pressure: Option<f64>,
+}
+
+impl AdjacentStructure {
+    /// Validates specific fields of the AdjacentStructure using field names.
+    ///
+    /// # Arguments
+    /// * `fields` - A slice of field names to validate.
+    ///
+    /// # Returns
+    /// Ok(()) if all fields are valid, or an error if any field is invalid.
+    pub fn validate(&self, fields: &[&str]) -> Result<(), ValidationError> {
+        for &field in fields {
+            let result = match field {
+                "width" => validate_field(
+                    "width",
+                    self.width,
+                    Some(0.0001),
+                    None,
+                    "adjacent_structure",
+                ),
+                "length" => validate_field(
+                    "length",
+                    self.length,
+                    Some(0.0001),
+                    None,
+                    "adjacent_structure",
+                ),
+                "depth" => {
+                    validate_field("depth", self.depth, Some(0.0), None, "adjacent_structure")
+                }
+                "offset_x" => {
+                    validate_field("offset_x", self.offset_x, None, None, "adjacent_structure")
+                }
+                "offset_y" => {
+                    validate_field("offset_y", self.offset_y, None, None, "adjacent_structure")
+                }
+                "foundation_pressure" => validate_field(
+                    "foundation_pressure",
+                    self.foundation_pressure,
+                    Some(0.0001),
+                    None,
+                    "adjacent_structure",
+                ),
+                unknown => Err(ValidationError {
+                    code: "adjacent_structure.invalid_field".into(),
+                    message: format!("Field '{}' is not valid for AdjacentStructure.", unknown),
+                }),
+            };
+
+            result?; // propagate error if any field fails
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+
+use crate::validation::{validate_field, ValidationError};
+
+/// Depth window, tied to the foundation width B, over which field test values are averaged.
+///
+/// The window extends `above` · B above and `below` · B below a reference depth, which is the
+/// foundation base for shallow foundations and the tip for piles.
+///
+/// # Fields
+/// * `above` - Extent of the window above the reference depth, in multiples of B.
+/// * `below` - Extent of the window below the reference depth, in multiples of B.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AveragingWindow {
+    /// Extent above the reference depth, in multiples of B.
+    pub above: f64,
+    /// Extent below the reference depth, in multiples of B.
+    pub below: f64,
+}
+
+impl Default for AveragingWindow {
+    fn default() -> Self {
+        Self::shallow_foundation()
+    }
+}
+
+impl AveragingWindow {
+    pub fn new(above: f64, below: f64) -> Self {
+        Self { above, below }
+    }
+
+    /// Window from the foundation base Df to Df + B.
+    pub fn shallow_foundation() -> Self {
+        Self::new(0.0, 1.0)
+    }
+
+    /// Window from 0.5B above to 1.5B below a pile tip.
+    pub fn pile_tip() -> Self {
+        Self::new(0.5, 1.5)
+    }
+
+    /// Returns the top and bottom depths of the window, the top being limited to the ground
+    /// surface.
+    ///
+    /// # Arguments
+    /// * `reference_depth` - Foundation base or pile tip depth (m).
+    /// * `width` - Foundation width or pile diameter B (m).
+    ///
+    /// # Returns
+    /// * `(f64, f64)` - Top and bottom depths of the window (m).
+    pub fn calc_bounds(&self, reference_depth: f64, width: f64) -> (f64, f64) {
+        let top = (reference_depth - self.above * width).max(0.0);
+        let bottom = reference_depth + self.below * width;
+        (top, bottom)
+    }
+
+    /// Validates the window extents.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        validate_field(
+            "above",
+            Some(self.above),
+            Some(0.0),
+            None,
+            "averaging_window",
+        )?;
+        validate_field(
+            "below",
+            Some(self.below),
+            Some(0.0),
+            None,
+            "averaging_window",
+        )?;
+        if self.above + self.below <= 0.0 {
+            return Err(ValidationError {
+                code: "averaging_window.empty".to_string(),
+                message: "The averaging window must have a non-zero extent.".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
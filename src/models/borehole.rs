@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+use crate::validation::{validate_field, ValidationError};
+
+/// Represents an exploration borehole of a site investigation.
+///
+/// The coordinates locate the borehole relative to the center of the foundation in plan.
+///
+/// # Fields
+/// * `borehole_id` - Identifier for the borehole.
+/// * `x` - Distance from the foundation center along the width (m).
+/// * `y` - Distance from the foundation center along the length (m).
+/// * `depth` - Depth of the borehole below the ground surface (m).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Borehole {
+    /// Identifier for the borehole.
+    pub borehole_id: String,
+    /// Offset from the foundation center along the width (m).
+    pub x: Option<f64>,
+    /// Offset from the foundation center along the length (m).
+    pub y: Option<f64>,
+    /// Depth of the borehole (m).
+    pub depth: Option<f64>,
+}
+
+impl Borehole {
+    pub fn new(borehole_id: &str, x: f64, y: f64, depth: f64) -> Self {
+        Self {
+            borehole_id: borehole_id.to_string(),
+            x: Some(x),
+            y: Some(y),
+            depth: Some(depth),
+        }
+    }
+
+    /// Validates specific fields of the Borehole using field names.
+    ///
+    /// # Arguments
+    /// * `fields` - A slice of field names to validate.
+    ///
+    /// # Returns
+    /// Ok(()) if all fields are valid, or an error if any field is invalid.
+    pub fn validate(&self, fields: &[&str]) -> Result<(), ValidationError> {
+        for &field in fields {
+            let result = match field {
+                "x" => validate_field("x", self.x, None, None, "borehole"),
+                "y" => validate_field("y", self.y, None, None, "borehole"),
+                "depth" => validate_field("depth", self.depth, Some(0.0001), None, "borehole"),
+                unknown => Err(ValidationError {
+                    code: "borehole.invalid_field".into(),
+                    message: format!("Field '{}' is not valid for Borehole.", unknown),
+                }),
+            };
+
+            result?; // propagate error if any field fails
+        }
+
+        Ok(())
+    }
+}
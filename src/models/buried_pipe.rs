@@ -0,0 +1,123 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::{CompactionLevel, PipeBackfillType},
+    validation::{validate_field, ValidationError},
+};
+
+/// Represents a flexible pipe buried in a trench.
+///
+/// # Fields
+/// * `outer_diameter` - Outer diameter of the pipe (m).
+/// * `pipe_stiffness` - Pipe stiffness PS = F/Δy per unit length (t/m²).
+/// * `cover_depth` - Depth of cover above the pipe crown (m).
+/// * `trench_width` - Width of the trench at the pipe crown (m).
+/// * `bedding_angle` - Bedding angle (degrees).
+/// * `deflection_lag_factor` - Deflection lag factor DL (1.0–1.5).
+/// * `allowable_deflection` - Allowable deflection as a ratio of the diameter (e.g. 0.05).
+/// * `backfill_friction_factor` - Marston's Kμ' of the backfill (0.110 for saturated clay to 0.1924 for granular soil).
+/// * `backfill_type` - Pipe zone backfill soil type.
+/// * `compaction_level` - Compaction level of the pipe zone backfill.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuriedPipe {
+    /// Outer diameter of the pipe (m).
+    pub outer_diameter: Option<f64>,
+    /// Pipe stiffness PS (t/m²).
+    pub pipe_stiffness: Option<f64>,
+    /// Depth of cover above the pipe crown (m).
+    pub cover_depth: Option<f64>,
+    /// Width of the trench at the pipe crown (m).
+    pub trench_width: Option<f64>,
+    /// Bedding angle (degrees).
+    pub bedding_angle: Option<f64>,
+    /// Deflection lag factor DL.
+    pub deflection_lag_factor: Option<f64>,
+    /// Allowable deflection ratio Δy / D.
+    pub allowable_deflection: Option<f64>,
+    /// Marston's Kμ' of the backfill.
+    pub backfill_friction_factor: Option<f64>,
+    /// Pipe zone backfill soil type.
+    pub backfill_type: PipeBackfillType,
+    /// Compaction level of the pipe zone backfill.
+    pub compaction_level: CompactionLevel,
+}
+
+impl BuriedPipe {
+    /// Validates specific fields of the BuriedPipe using field names.
+    ///
+    /// # Arguments
+    /// * `fields` - A slice of field names to validate.
+    ///
+    /// # Returns
+    /// Ok(()) if all fields are valid, or an error if any field is invalid.
+    pub fn validate(&self, fields: &[&str]) -> Result<(), ValidationError> {
+        for &field in fields {
+            let result = match field {
+                "outer_diameter" => validate_field(
+                    "outer_diameter",
+                    self.outer_diameter,
+                    Some(0.0001),
+                    None,
+                    "buried_pipe",
+                ),
+                "pipe_stiffness" => validate_field(
+                    "pipe_stiffness",
+                    self.pipe_stiffness,
+                    Some(0.0),
+                    None,
+                    "buried_pipe",
+                ),
+                "cover_depth" => validate_field(
+                    "cover_depth",
+                    self.cover_depth,
+                    Some(0.0001),
+                    None,
+                    "buried_pipe",
+                ),
+                "trench_width" => validate_field(
+                    "trench_width",
+                    self.trench_width,
+                    self.outer_diameter,
+                    None,
+                    "buried_pipe",
+                ),
+                "bedding_angle" => validate_field(
+                    "bedding_angle",
+                    self.bedding_angle,
+                    Some(0.0),
+                    Some(180.0),
+                    "buried_pipe",
+                ),
+                "deflection_lag_factor" => validate_field(
+                    "deflection_lag_factor",
+                    self.deflection_lag_factor,
+                    Some(1.0),
+                    Some(2.5),
+                    "buried_pipe",
+                ),
+                "allowable_deflection" => validate_field(
+                    "allowable_deflection",
+                    self.allowable_deflection,
+                    Some(0.0001),
+                    Some(1.0),
+                    "buried_pipe",
+                ),
+                "backfill_friction_factor" => validate_field(
+                    "backfill_friction_factor",
+                    self.backfill_friction_factor,
+                    Some(0.0001),
+                    None,
+                    "buried_pipe",
+                ),
+                unknown => Err(ValidationError {
+                    code: "buried_pipe.invalid_field".into(),
+                    message: format!("Field '{}' is not valid for BuriedPipe.", unknown),
+                }),
+            };
+
+            result?; // propagate error if any field fails
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,133 @@
+use serde::{Deserialize, Serialize};
+
+use crate::validation::{validate_field, ValidationError};
+
+/// Represents a column load on a combined or strap footing.
+///
+/// # Fields
+/// * `position` - Distance of the column axis from the left edge of the footing (m).
+/// * `load` - Vertical column load (t).
+/// * `moment` - Column moment about the width axis, positive towards the right (t·m).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ColumnLoad {
+    /// Distance of the column axis from the left edge of the footing (m).
+    pub position: Option<f64>,
+    /// Vertical column load (t).
+    pub load: Option<f64>,
+    /// Column moment about the width axis, positive towards the right (t·m).
+    pub moment: Option<f64>,
+}
+
+impl ColumnLoad {
+    /// Creates a new `ColumnLoad` instance.
+    ///
+    /// # Arguments
+    /// * `position` - Distance of the column axis from the left edge of the footing (m).
+    /// * `load` - Vertical column load (t).
+    /// * `moment` - Column moment about the width axis, positive towards the right (t·m).
+    pub fn new(position: f64, load: f64, moment: f64) -> Self {
+        Self {
+            position: Some(position),
+            load: Some(load),
+            moment: Some(moment),
+        }
+    }
+
+    /// Validates specific fields of the ColumnLoad using field names.
+    ///
+    /// # Arguments
+    /// * `fields` - A slice of field names to validate.
+    ///
+    /// # Returns
+    /// Ok(()) if all fields are valid, or an error if any field is invalid.
+    pub fn validate(&self, fields: &[&str]) -> Result<(), ValidationError> {
+        for &field in fields {
+            let result = match field {
+                "position" => {
+                    validate_field("position", self.position, Some(0.0), None, "column_load")
+                }
+                "load" => validate_field("load", self.load, Some(0.0), None, "column_load"),
+                "moment" => validate_field("moment", self.moment, None, None, "column_load"),
+                unknown => Err(ValidationError {
+                    code: "column_load.invalid_field".into(),
+                    message: format!("Field '{}' is not valid for ColumnLoad.", unknown),
+                }),
+            };
+
+            result?; // propagate error if any field fails
+        }
+
+        Ok(())
+    }
+}
+
+/// Represents a column load on a mat foundation.
+///
+/// # Fields
+/// * `x` - Distance of the column axis from the left edge of the mat, along its width (m).
+/// * `y` - Distance of the column axis from the bottom edge of the mat, along its length (m).
+/// * `load` - Vertical column load (t).
+/// * `moment_x` - Column moment causing eccentricity along x, the mat width (t·m).
+/// * `moment_y` - Column moment causing eccentricity along y, the mat length (t·m).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MatColumnLoad {
+    /// Distance of the column axis from the left edge of the mat, along its width (m).
+    pub x: Option<f64>,
+    /// Distance of the column axis from the bottom edge of the mat, along its length (m).
+    pub y: Option<f64>,
+    /// Vertical column load (t).
+    pub load: Option<f64>,
+    /// Column moment causing eccentricity along x, the mat width (t·m).
+    pub moment_x: Option<f64>,
+    /// Column moment causing eccentricity along y, the mat length (t·m).
+    pub moment_y: Option<f64>,
+}
+
+impl MatColumnLoad {
+    /// Creates a new `MatColumnLoad` instance.
+    ///
+    /// # Arguments
+    /// * `x` - Distance of the column axis from the left edge of the mat (m).
+    /// * `y` - Distance of the column axis from the bottom edge of the mat (m).
+    /// * `load` - Vertical column load (t).
+    pub fn new(x: f64, y: f64, load: f64) -> Self {
+        Self {
+            x: Some(x),
+            y: Some(y),
+            load: Some(load),
+            moment_x: None,
+            moment_y: None,
+        }
+    }
+
+    /// Validates specific fields of the MatColumnLoad using field names.
+    ///
+    /// # Arguments
+    /// * `fields` - A slice of field names to validate.
+    ///
+    /// # Returns
+    /// Ok(()) if all fields are valid, or an error if any field is invalid.
+    pub fn validate(&self, fields: &[&str]) -> Result<(), ValidationError> {
+        for &field in fields {
+            let result = match field {
+                "x" => validate_field("x", self.x, Some(0.0), None, "mat_column_load"),
+                "y" => validate_field("y", self.y, Some(0.0), None, "mat_column_load"),
+                "load" => validate_field("load", self.load, Some(0.0), None, "mat_column_load"),
+                "moment_x" => {
+                    validate_field("moment_x", self.moment_x, None, None, "mat_column_load")
+                }
+                "moment_y" => {
+                    validate_field("moment_y", self.moment_y, None, None, "mat_column_load")
+                }
+                unknown => Err(ValidationError {
+                    code: "mat_column_load.invalid_field".into(),
+                    message: format!("Field '{}' is not valid for MatColumnLoad.", unknown),
+                }),
+            };
+
+            result?; // propagate error if any field fails
+        }
+
+        Ok(())
+    }
+}
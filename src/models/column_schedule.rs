@@ -0,0 +1,187 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::LoadCase,
+    models::loads::Loads,
+    validation::{validate_field, ValidationError},
+};
+
+/// Column forces of a single load case at the foundation level.
+///
+/// # Fields
+/// * `load_case` - Load case of the forces.
+/// * `axial_load` - Axial (vertical) column load in ton, positive in compression.
+/// * `moment_x` - Moment causing eccentricity along x, the footing width (t·m).
+/// * `moment_y` - Moment causing eccentricity along y, the footing length (t·m).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ColumnLoadCase {
+    pub load_case: LoadCase,
+    pub axial_load: Option<f64>,
+    pub moment_x: Option<f64>,
+    pub moment_y: Option<f64>,
+}
+
+impl ColumnLoadCase {
+    /// Creates a new `ColumnLoadCase` instance.
+    ///
+    /// # Arguments
+    /// * `load_case` - Load case of the forces.
+    /// * `axial_load` - Axial column load (t).
+    /// * `moment_x` - Moment causing eccentricity along x, the footing width (t·m).
+    /// * `moment_y` - Moment causing eccentricity along y, the footing length (t·m).
+    pub fn new(load_case: LoadCase, axial_load: f64, moment_x: f64, moment_y: f64) -> Self {
+        Self {
+            load_case,
+            axial_load: Some(axial_load),
+            moment_x: Some(moment_x),
+            moment_y: Some(moment_y),
+        }
+    }
+
+    /// Validates the forces of the load case.
+    ///
+    /// # Returns
+    /// Ok(()) if all fields are valid, or an error if any field is invalid.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        validate_field(
+            "axial_load",
+            self.axial_load,
+            Some(0.0),
+            None,
+            "column_schedule",
+        )?;
+        if self.moment_x.is_some() {
+            validate_field("moment_x", self.moment_x, None, None, "column_schedule")?;
+        }
+        if self.moment_y.is_some() {
+            validate_field("moment_y", self.moment_y, None, None, "column_schedule")?;
+        }
+        Ok(())
+    }
+}
+
+/// A column of the column schedule.
+///
+/// # Fields
+/// * `id` - Column identifier, e.g. "S12"
+/// * `x` - x coordinate of the column axis in m
+/// * `y` - y coordinate of the column axis in m
+/// * `load_cases` - Column forces per load case
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledColumn {
+    pub id: String,
+    pub x: Option<f64>,
+    pub y: Option<f64>,
+    pub load_cases: Vec<ColumnLoadCase>,
+}
+
+impl ScheduledColumn {
+    /// Creates a new `ScheduledColumn` instance.
+    ///
+    /// # Arguments
+    /// * `id` - Column identifier.
+    /// * `x` - x coordinate of the column axis (m).
+    /// * `y` - y coordinate of the column axis (m).
+    /// * `load_cases` - Column forces per load case.
+    pub fn new(id: String, x: f64, y: f64, load_cases: Vec<ColumnLoadCase>) -> Self {
+        Self {
+            id,
+            x: Some(x),
+            y: Some(y),
+            load_cases,
+        }
+    }
+
+    /// Returns the forces of a load case, if the column has them.
+    ///
+    /// # Arguments
+    /// * `load_case` - Load case.
+    pub fn get_load_case(&self, load_case: LoadCase) -> Option<&ColumnLoadCase> {
+        self.load_cases.iter().find(|c| c.load_case == load_case)
+    }
+
+    /// Returns the loads of a load case on the footing under the column, for the bearing capacity
+    /// and settlement analyses.
+    ///
+    /// # Arguments
+    /// * `load_case` - Load case.
+    ///
+    /// # Returns
+    /// * `Loads` with the vertical load and the moments, or None if the load case is missing.
+    pub fn get_loads(&self, load_case: LoadCase) -> Option<Loads> {
+        self.get_load_case(load_case).map(|forces| Loads {
+            vertical_load: forces.axial_load,
+            moment_x: forces.moment_x,
+            moment_y: forces.moment_y,
+            ..Default::default()
+        })
+    }
+
+    /// Validates the coordinates and the load cases of the column.
+    ///
+    /// # Returns
+    /// Ok(()) if all fields are valid, or an error if any field is invalid.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        validate_field("x", self.x, None, None, "column_schedule")?;
+        validate_field("y", self.y, None, None, "column_schedule")?;
+        for load_case in &self.load_cases {
+            load_case.validate()?;
+        }
+        Ok(())
+    }
+}
+
+/// Column schedule of a structure, as exported from the structural model.
+///
+/// The schedule can be imported from JSON with serde.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ColumnSchedule {
+    pub columns: Vec<ScheduledColumn>,
+}
+
+impl ColumnSchedule {
+    /// Creates a new `ColumnSchedule` instance.
+    ///
+    /// # Arguments
+    /// * `columns` - The columns of the schedule.
+    pub fn new(columns: Vec<ScheduledColumn>) -> Self {
+        Self { columns }
+    }
+
+    /// Returns the column with the given identifier.
+    ///
+    /// # Arguments
+    /// * `id` - Column identifier.
+    pub fn get_column(&self, id: &str) -> Option<&ScheduledColumn> {
+        self.columns.iter().find(|c| c.id == id)
+    }
+
+    /// Validates the columns of the schedule. Every column must have the given load case.
+    ///
+    /// # Arguments
+    /// * `load_case` - Load case the schedule is used for.
+    ///
+    /// # Returns
+    /// Ok(()) if the schedule is valid, or an error if it is not.
+    pub fn validate(&self, load_case: LoadCase) -> Result<(), ValidationError> {
+        if self.columns.is_empty() {
+            return Err(ValidationError {
+                code: "column_schedule.empty_columns".into(),
+                message: "No columns provided for the column schedule.".into(),
+            });
+        }
+        for column in &self.columns {
+            column.validate()?;
+            if column.get_load_case(load_case).is_none() {
+                return Err(ValidationError {
+                    code: "column_schedule.missing_load_case".into(),
+                    message: format!(
+                        "Column '{}' has no forces for the {:?} load case.",
+                        column.id, load_case
+                    ),
+                });
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,5 +1,7 @@
 use crate::{
     enums::SelectionMethod,
+    helper::calc_window_average,
+    models::averaging_window::AveragingWindow,
     validation::{validate_field, ValidationError},
 };
 use ordered_float::OrderedFloat;
@@ -159,6 +161,47 @@ impl CPTExp {
             .unwrap_or_else(|| self.layers.last().unwrap())
     }
 
+    /// Calculates the average cone resistance between two depths.
+    ///
+    /// If no reading lies between the depths, the first reading below `top` is used, or the
+    /// deepest reading when the sounding ends above `top`.
+    ///
+    /// # Arguments
+    /// * `top` - Top depth of the zone (m).
+    /// * `bottom` - Bottom depth of the zone (m).
+    ///
+    /// # Returns
+    /// * `f64` - Average cone resistance (MPa).
+    pub fn calc_average_cone_resistance(&self, top: f64, bottom: f64) -> f64 {
+        let samples: Vec<(f64, f64)> = self
+            .layers
+            .iter()
+            .map(|layer| (layer.depth.unwrap(), layer.cone_resistance.unwrap()))
+            .collect();
+
+        calc_window_average(&samples, top, bottom).unwrap()
+    }
+
+    /// Calculates the average cone resistance over a window tied to the foundation width, e.g.
+    /// from 0.5B above to 1.5B below a pile tip.
+    ///
+    /// # Arguments
+    /// * `window` - Averaging window.
+    /// * `reference_depth` - Foundation base or pile tip depth (m).
+    /// * `width` - Foundation width or pile diameter B (m).
+    ///
+    /// # Returns
+    /// * `f64` - Average cone resistance (MPa).
+    pub fn calc_window_cone_resistance(
+        &self,
+        window: &AveragingWindow,
+        reference_depth: f64,
+        width: f64,
+    ) -> f64 {
+        let (top, bottom) = window.calc_bounds(reference_depth, width);
+        self.calc_average_cone_resistance(top, bottom)
+    }
+
     /// Validates specific fields of the CPTExp using field names.
     ///
     /// # Arguments
@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+
+use crate::validation::{validate_field, ValidationError};
+
+/// Represents a deadman (plate) anchor of a tied-back sheet pile wall.
+///
+/// # Fields
+/// * `height` - Height of the deadman plate (m).
+/// * `depth` - Depth of the bottom of the deadman below the ground surface (m).
+/// * `length` - Length of the deadman parallel to the wall (m).
+/// * `distance` - Horizontal distance from the wall to the front face of the deadman (m).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Deadman {
+    /// Height of the deadman plate (m).
+    pub height: Option<f64>,
+    /// Depth of the bottom of the deadman (m).
+    pub depth: Option<f64>,
+    /// Length of the deadman parallel to the wall (m).
+    pub length: Option<f64>,
+    /// Distance from the wall to the deadman (m).
+    pub distance: Option<f64>,
+}
+
+impl Deadman {
+    /// Creates a new `Deadman` instance.
+    ///
+    /// # Arguments
+    /// * `height` - Height of the deadman plate (m).
+    /// * `depth` - Depth of the bottom of the deadman below the ground surface (m).
+    /// * `length` - Length of the deadman parallel to the wall (m).
+    /// * `distance` - Horizontal distance from the wall to the front face of the deadman (m).
+    pub fn new(height: f64, depth: f64, length: f64, distance: f64) -> Self {
+        Self {
+            height: Some(height),
+            depth: Some(depth),
+            length: Some(length),
+            distance: Some(distance),
+        }
+    }
+
+    /// Validates specific fields of the Deadman using field names.
+    ///
+    /// # Arguments
+    /// * `fields` - A slice of field names to validate.
+    ///
+    /// # Returns
+    /// Ok(()) if all fields are valid, or an error if any field is invalid.
+    pub fn validate(&self, fields: &[&str]) -> Result<(), ValidationError> {
+        for &field in fields {
+            let result = match field {
+                "height" => validate_field("height", self.height, Some(0.0001), None, "deadman"),
+                "depth" => validate_field("depth", self.depth, self.height, None, "deadman"),
+                "length" => validate_field("length", self.length, Some(0.0001), None, "deadman"),
+                "distance" => validate_field("distance", self.distance, Some(0.0), None, "deadman"),
+                unknown => Err(ValidationError {
+                    code: "deadman.invalid_field".into(),
+                    message: format!("Field '{}' is not valid for Deadman.", unknown),
+                }),
+            };
+
+            result?; // propagate error if any field fails
+        }
+
+        Ok(())
+    }
+}
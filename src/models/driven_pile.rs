@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+use crate::validation::{validate_field, ValidationError};
+
+/// Represents a closed-ended driven pile together with the hammer used to install it.
+///
+/// # Fields
+/// * `diameter` - Outer diameter (or width) of the pile (m).
+/// * `length` - Embedded length of the pile at the end of driving (m).
+/// * `hammer_energy` - Rated energy of the hammer per blow (t·m).
+/// * `hammer_efficiency` - Efficiency of the hammer (0–1, typically 0.7–0.85).
+/// * `refusal_blow_count` - Blow count per 0.25 m regarded as practical refusal (typically 100).
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct DrivenPile {
+    /// Outer diameter of the pile (m).
+    pub diameter: Option<f64>,
+    /// Embedded length of the pile (m).
+    pub length: Option<f64>,
+    /// Rated energy of the hammer per blow (t·m).
+    pub hammer_energy: Option<f64>,
+    /// Efficiency of the hammer.
+    pub hammer_efficiency: Option<f64>,
+    /// Blow count per 0.25 m regarded as practical refusal.
+    pub refusal_blow_count: Option<f64>,
+}
+
+impl DrivenPile {
+    /// Validates specific fields of the DrivenPile using field names.
+    ///
+    /// # Arguments
+    /// * `fields` - A slice of field names to validate.
+    ///
+    /// # Returns
+    /// Ok(()) if all fields are valid, or an error if any field is invalid.
+    pub fn validate(&self, fields: &[&str]) -> Result<(), ValidationError> {
+        for &field in fields {
+            let result = match field {
+                "diameter" => {
+                    validate_field("diameter", self.diameter, Some(0.0001), None, "driven_pile")
+                }
+                "length" => {
+                    validate_field("length", self.length, Some(0.0001), None, "driven_pile")
+                }
+                "hammer_energy" => validate_field(
+                    "hammer_energy",
+                    self.hammer_energy,
+                    Some(0.0001),
+                    None,
+                    "driven_pile",
+                ),
+                "hammer_efficiency" => validate_field(
+                    "hammer_efficiency",
+                    self.hammer_efficiency,
+                    Some(0.0001),
+                    Some(1.0),
+                    "driven_pile",
+                ),
+                "refusal_blow_count" => validate_field(
+                    "refusal_blow_count",
+                    self.refusal_blow_count,
+                    Some(1.0),
+                    None,
+                    "driven_pile",
+                ),
+                unknown => Err(ValidationError {
+                    code: "driven_pile.invalid_field".into(),
+                    message: format!("Field '{}' is not valid for DrivenPile.", unknown),
+                }),
+            };
+
+            result?; // propagate error if any field fails
+        }
+
+        Ok(())
+    }
+}
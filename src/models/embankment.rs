@@ -0,0 +1,125 @@
+use std::f64::consts::PI;
+
+use serde::{Deserialize, Serialize};
+
+use crate::validation::{validate_field, ValidationError};
+
+/// Represents a long embankment with a trapezoidal cross-section placed on the ground surface.
+///
+/// # Fields
+/// * `height` - Height of the fill (m).
+/// * `crest_width` - Width of the embankment crest (m).
+/// * `side_slope` - Horizontal run of the side slopes per unit rise, n for n H : 1 V.
+/// * `unit_weight` - Unit weight of the fill (t/m³).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Embankment {
+    /// Height of the fill (m).
+    pub height: Option<f64>,
+    /// Width of the crest (m).
+    pub crest_width: Option<f64>,
+    /// Horizontal run of the side slopes per unit rise.
+    pub side_slope: Option<f64>,
+    /// Unit weight of the fill (t/m³).
+    pub unit_weight: Option<f64>,
+}
+
+/// Calculates the Osterberg (1957) influence factor below the inner edge of a half embankment.
+///
+/// * I = 1 / π · [(a + b) / a · (α1 + α2) − b / a · α2]
+/// * α1 = tan⁻¹((a + b) / z) − tan⁻¹(b / z), α2 = tan⁻¹(b / z)
+///
+/// With vertical sides (a = 0) the limit I = 1 / π · [α2 + b · z / (b² + z²)] of a strip load is
+/// used.
+///
+/// # Arguments
+/// * `slope_width` - Horizontal width of the side slope, a (m).
+/// * `half_crest_width` - Half of the crest width, b (m).
+/// * `depth` - Depth below the ground surface, z (m).
+///
+/// # Returns
+/// * Influence factor I, with Δσ = q · I for the half embankment.
+pub fn calc_osterberg_influence_factor(slope_width: f64, half_crest_width: f64, depth: f64) -> f64 {
+    let (a, b) = (slope_width, half_crest_width);
+    if depth <= 0.0 {
+        return 0.5;
+    }
+
+    let alpha2 = (b / depth).atan();
+    if a <= 0.0 {
+        return (alpha2 + b * depth / (b.powi(2) + depth.powi(2))) / PI;
+    }
+    let alpha1 = ((a + b) / depth).atan() - alpha2;
+
+    ((a + b) / a * (alpha1 + alpha2) - b / a * alpha2) / PI
+}
+
+impl Embankment {
+    /// Returns the pressure of the fill on the ground surface, q = γ · H (t/m²).
+    pub fn calc_pressure(&self) -> f64 {
+        self.unit_weight.unwrap() * self.height.unwrap()
+    }
+
+    /// Returns the horizontal width of each side slope, n · H (m).
+    pub fn calc_slope_width(&self) -> f64 {
+        self.side_slope.unwrap() * self.height.unwrap()
+    }
+
+    /// Returns the width of the embankment at its base (m).
+    pub fn calc_base_width(&self) -> f64 {
+        self.crest_width.unwrap() + 2.0 * self.calc_slope_width()
+    }
+
+    /// Calculates the vertical stress increase below the embankment centerline by superposing the
+    /// two halves of the embankment.
+    ///
+    /// # Arguments
+    /// * `depth` - Depth below the ground surface (m).
+    ///
+    /// # Returns
+    /// * Vertical stress increase (t/m²).
+    pub fn calc_center_stress(&self, depth: f64) -> f64 {
+        let half_crest_width = self.crest_width.unwrap() / 2.0;
+        2.0 * self.calc_pressure()
+            * calc_osterberg_influence_factor(self.calc_slope_width(), half_crest_width, depth)
+    }
+
+    /// Validates specific fields of the Embankment using field names.
+    ///
+    /// # Arguments
+    /// * `fields` - A slice of field names to validate.
+    ///
+    /// # Returns
+    /// Ok(()) if all fields are valid, or an error if any field is invalid.
+    pub fn validate(&self, fields: &[&str]) -> Result<(), ValidationError> {
+        for &field in fields {
+            let result = match field {
+                "height" => validate_field("height", self.height, Some(0.0), None, "embankment"),
+                "crest_width" => validate_field(
+                    "crest_width",
+                    self.crest_width,
+                    Some(0.0),
+                    None,
+                    "embankment",
+                ),
+                "side_slope" => {
+                    validate_field("side_slope", self.side_slope, Some(0.0), None, "embankment")
+                }
+                "unit_weight" => validate_field(
+                    "unit_weight",
+                    self.unit_weight,
+                    Some(0.1),
+                    Some(3.0),
+                    "embankment",
+                ),
+                unknown => Err(ValidationError {
+                    code: "embankment.invalid_field".into(),
+                    message: format!("Field '{}' is not valid for Embankment.", unknown),
+                }),
+            };
+
+            result?; // propagate error if any field fails
+        }
+
+        Ok(())
+    }
+}
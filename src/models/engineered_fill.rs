@@ -0,0 +1,158 @@
+use serde::{Deserialize, Serialize};
+
+use crate::validation::{validate_field, ValidationError};
+
+/// Represents a compacted (engineered) fill placed above the natural ground.
+///
+/// The fill is idealized as a single homogeneous layer whose top is the working platform
+/// on which the footing is founded. Depths of the foundation are measured from the top of the fill,
+/// while the natural soil profile below keeps its own depths starting at the fill/ground interface.
+///
+/// # Fields
+/// * `thickness` - Total thickness of the fill (m).
+/// * `unit_weight` - Compacted unit weight of the fill (t/m³).
+/// * `friction_angle` - Internal friction angle of the fill (degrees).
+/// * `cohesion` - Cohesion of the fill (t/m²).
+/// * `side_slope` - Side slope of the fill as horizontal run per unit height (e.g. 1.5 for 1.5H:1V).
+/// * `crest_offset` - Horizontal distance between the footing edge and the fill crest (m).
+/// * `load_spread_angle` - Load spread angle through the fill measured from vertical (degrees).
+/// * `punching_shear_coefficient` - Punching shear coefficient (Ks) of Meyerhof & Hanna (1978).
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct EngineeredFill {
+    /// Total thickness of the fill (m).
+    pub thickness: Option<f64>,
+    /// Compacted unit weight of the fill (t/m³).
+    pub unit_weight: Option<f64>,
+    /// Internal friction angle of the fill (degrees).
+    pub friction_angle: Option<f64>,
+    /// Cohesion of the fill (t/m²).
+    pub cohesion: Option<f64>,
+    /// Side slope of the fill (horizontal / vertical).
+    pub side_slope: Option<f64>,
+    /// Horizontal distance between the footing edge and the fill crest (m).
+    pub crest_offset: Option<f64>,
+    /// Load spread angle through the fill measured from vertical (degrees).
+    pub load_spread_angle: Option<f64>,
+    /// Punching shear coefficient (Ks). When omitted, K0 = 1 - sin(φ) is used as a lower bound.
+    pub punching_shear_coefficient: Option<f64>,
+}
+
+impl EngineeredFill {
+    /// Creates a new `EngineeredFill` instance.
+    ///
+    /// # Arguments
+    /// * `thickness` - Total thickness of the fill (m).
+    /// * `unit_weight` - Compacted unit weight of the fill (t/m³).
+    /// * `friction_angle` - Internal friction angle of the fill (degrees).
+    /// * `cohesion` - Cohesion of the fill (t/m²).
+    /// * `side_slope` - Side slope of the fill (horizontal / vertical).
+    /// * `crest_offset` - Horizontal distance between the footing edge and the fill crest (m).
+    /// * `load_spread_angle` - Load spread angle through the fill (degrees).
+    pub fn new(
+        thickness: f64,
+        unit_weight: f64,
+        friction_angle: f64,
+        cohesion: f64,
+        side_slope: f64,
+        crest_offset: f64,
+        load_spread_angle: f64,
+    ) -> Self {
+        Self {
+            thickness: Some(thickness),
+            unit_weight: Some(unit_weight),
+            friction_angle: Some(friction_angle),
+            cohesion: Some(cohesion),
+            side_slope: Some(side_slope),
+            crest_offset: Some(crest_offset),
+            load_spread_angle: Some(load_spread_angle),
+            punching_shear_coefficient: None,
+        }
+    }
+
+    /// Returns the punching shear coefficient (Ks).
+    ///
+    /// If no value is provided, the at-rest coefficient K0 = 1 - sin(φ) is returned,
+    /// which is a conservative lower bound of the Meyerhof & Hanna (1978) chart values.
+    pub fn get_punching_shear_coefficient(&self) -> f64 {
+        self.punching_shear_coefficient
+            .unwrap_or_else(|| 1.0 - self.friction_angle.unwrap().to_radians().sin())
+    }
+
+    /// Validates specific fields of the EngineeredFill using field names.
+    ///
+    /// # Arguments
+    /// * `fields` - A slice of field names to validate.
+    ///
+    /// # Returns
+    /// Ok(()) if all fields are valid, or an error if any field is invalid.
+    pub fn validate(&self, fields: &[&str]) -> Result<(), ValidationError> {
+        for &field in fields {
+            let result = match field {
+                "thickness" => validate_field(
+                    "thickness",
+                    self.thickness,
+                    Some(0.0001),
+                    None,
+                    "engineered_fill",
+                ),
+                "unit_weight" => validate_field(
+                    "unit_weight",
+                    self.unit_weight,
+                    Some(0.1),
+                    Some(10.0),
+                    "engineered_fill",
+                ),
+                "friction_angle" => validate_field(
+                    "friction_angle",
+                    self.friction_angle,
+                    Some(0.0),
+                    Some(50.0),
+                    "engineered_fill",
+                ),
+                "cohesion" => validate_field(
+                    "cohesion",
+                    self.cohesion,
+                    Some(0.0),
+                    None,
+                    "engineered_fill",
+                ),
+                "side_slope" => validate_field(
+                    "side_slope",
+                    self.side_slope,
+                    Some(0.0),
+                    None,
+                    "engineered_fill",
+                ),
+                "crest_offset" => validate_field(
+                    "crest_offset",
+                    self.crest_offset,
+                    Some(0.0),
+                    None,
+                    "engineered_fill",
+                ),
+                "load_spread_angle" => validate_field(
+                    "load_spread_angle",
+                    self.load_spread_angle,
+                    Some(0.0),
+                    Some(60.0),
+                    "engineered_fill",
+                ),
+                "punching_shear_coefficient" => validate_field(
+                    "punching_shear_coefficient",
+                    self.punching_shear_coefficient,
+                    Some(0.0),
+                    None,
+                    "engineered_fill",
+                ),
+                unknown => Err(ValidationError {
+                    code: "engineered_fill.invalid_field".into(),
+                    message: format!("Field '{}' is not valid for EngineeredFill.", unknown),
+                }),
+            };
+
+            result?; // propagate error if any field fails
+        }
+
+        Ok(())
+    }
+}
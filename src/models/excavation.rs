@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+use crate::validation::{validate_field, ValidationError};
+
+/// Represents an existing excavation (or shallow tunnel) near a foundation.
+///
+/// The excavation is modelled as a rectangular unloading area at its base, with the removed
+/// overburden acting as a negative vertical pressure. The offsets locate the center of the
+/// excavation relative to the center of the foundation in plan.
+///
+/// # Fields
+/// * `width` - Width of the excavation along the foundation width (m).
+/// * `length` - Length of the excavation along the foundation length (m).
+/// * `depth` - Depth of the excavation base below the ground surface (m).
+/// * `offset_x` - Distance from the foundation center to the excavation center along the width (m).
+/// * `offset_y` - Distance from the foundation center to the excavation center along the length (m).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Excavation {
+    /// Width of the excavation (m).
+    pub width: Option<f64>,
+    /// Length of the excavation (m).
+    pub length: Option<f64>,
+    /// Depth of the excavation base (m).
+    pub depth: Option<f64>,
+    /// Offset of the excavation center along the width (m).
+    pub offset_x: Option<f64>,
+    /// Offset of the excavation center along the length (m).
+    pub offset_y: Option<f64>,
+}
+
+impl Excavation {
+    /// Validates specific fields of the Excavation using field names.
+    ///
+    /// # Arguments
+    /// * `fields` - A slice of field names to validate.
+    ///
+    /// # Returns
+    /// Ok(()) if all fields are valid, or an error if any field is invalid.
+    pub fn validate(&self, fields: &[&str]) -> Result<(), ValidationError> {
+        for &field in fields {
+            let result = match field {
+                "width" => validate_field("width", self.width, Some(0.0001), None, "excavation"),
+                "length" => validate_field("length", self.length, Some(0.0001), None, "excavation"),
+                "depth" => validate_field("depth", self.depth, Some(0.0), None, "excavation"),
+                "offset_x" => validate_field("offset_x", self.offset_x, None, None, "excavation"),
+                "offset_y" => validate_field("offset_y", self.offset_y, None, None, "excavation"),
+                unknown => Err(ValidationError {
+                    code: "excavation.invalid_field".into(),
+                    message: format!("Field '{}' is not valid for Excavation.", unknown),
+                }),
+            };
+
+            result?; // propagate error if any field fails
+        }
+
+        Ok(())
+    }
+}
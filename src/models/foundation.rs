@@ -1,6 +1,51 @@
 use serde::{Deserialize, Serialize};
 
-use crate::validation::{validate_field, ValidationError};
+use crate::{
+    enums::{
+        EccentricityCase, EmpiricalGeometryAdjustment, FoundationShape, InterfaceType,
+        PressureConvention, ShearFailureMode,
+    },
+    models::soil_profile::SoilProfile,
+    validation::{validate_field, ValidationError},
+};
+
+/// Effective area of an eccentrically loaded foundation.
+///
+/// # Fields
+/// * `effective_width` - Effective width B' = A' / L' (m).
+/// * `effective_length` - Effective length L' (m).
+/// * `effective_area` - Effective area A' whose centroid coincides with the load resultant (m²).
+/// * `case` - Shape of the effective area.
+/// * `outside_kern` - Whether the resultant falls outside the kern (e > B/6 or e > L/6), i.e.
+///   part of the base is not in contact with the soil.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct EffectiveArea {
+    pub effective_width: f64,
+    pub effective_length: f64,
+    pub effective_area: f64,
+    pub case: EccentricityCase,
+    pub outside_kern: bool,
+}
+
+/// Length to width ratio assigned to strip footings given without a length.
+const STRIP_LENGTH_RATIO: f64 = 10.0;
+
+/// Returns the interface friction ratio δ/φ and adhesion ratio ca/c of a base interface
+/// (EC7 6.5.3; Kulhawy, 1991; Koerner, 2005).
+///
+/// # Arguments
+/// * `interface_type` - Interface between the foundation base and the soil.
+///
+/// # Returns
+/// * `(f64, f64)` - Friction ratio δ/φ and adhesion ratio ca/c.
+pub fn get_interface_factors(interface_type: InterfaceType) -> (f64, f64) {
+    match interface_type {
+        InterfaceType::CastInPlaceConcrete => (1.0, 1.0),
+        InterfaceType::PrecastConcreteOnSand => (2.0 / 3.0, 0.0),
+        InterfaceType::Geomembrane => (0.6, 0.0),
+        InterfaceType::LeanConcrete => (0.8, 0.8),
+    }
+}
 
 /// Represents a foundation with geometry and load effects.
 ///
@@ -14,6 +59,19 @@ use crate::validation::{validate_field, ValidationError};
 /// * `effective_width` - Effective width of the foundation after load effects (m).
 /// * `base_tilt_angle` - Foundation inclination angle (degrees).
 /// * `slope_angle` - Slope angle of the ground (degrees).
+/// * `opposite_slope_angle` - Slope angle of the ground on the opposite side (degrees).
+/// * `berm_width` - Horizontal distance from the foundation edge to the slope crest (m).
+/// * `opposite_berm_width` - Distance from the foundation edge to the opposite slope crest (m).
+/// * `slope_height` - Height of the slope below its crest (m), unlimited if not set.
+/// * `opposite_slope_height` - Height of the opposite slope below its crest (m).
+/// * `downslope_embedment` - Soil cover above the base on the downslope side (m), full depth if
+///   not set.
+/// * `shape` - Plan shape of the foundation (rectangular if not set).
+/// * `failure_mode` - Shear failure mode for bearing capacity (general shear if not set).
+/// * `apply_compressibility` - Applies the soil compressibility factors in the Vesic method.
+/// * `geometry_adjustment` - Geometry adjustment of the empirical bearing capacity methods (none
+///   if not set).
+/// * `interface_type` - Base interface, used when `surface_friction_coefficient` is not set.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Foundation {
     /// Depth of the foundation (m).
@@ -28,12 +86,36 @@ pub struct Foundation {
     pub base_tilt_angle: Option<f64>,
     /// Slope angle of the ground (degrees).
     pub slope_angle: Option<f64>,
+    /// Slope angle of the ground on the opposite side of the foundation (degrees).
+    pub opposite_slope_angle: Option<f64>,
+    /// Horizontal distance from the foundation edge to the crest of the slope (m).
+    pub berm_width: Option<f64>,
+    /// Horizontal distance from the foundation edge to the crest of the opposite slope (m).
+    pub opposite_berm_width: Option<f64>,
+    /// Height of the slope below its crest (m).
+    pub slope_height: Option<f64>,
+    /// Height of the opposite slope below its crest (m).
+    pub opposite_slope_height: Option<f64>,
+    /// Soil cover above the base level on the downslope side of the foundation (m), e.g. in a
+    /// sloping fill or next to a cut.
+    pub downslope_embedment: Option<f64>,
     /// Effective length of the foundation after load effects (m).
     pub effective_length: Option<f64>,
     /// Effective width of the foundation after load effects (m).
     pub effective_width: Option<f64>,
-    /// Friction coefficient for horizontal sliding (unitless).
+    /// Friction coefficient for horizontal sliding (unitless), overriding the interface type.
     pub surface_friction_coefficient: Option<f64>,
+    /// Interface between the foundation base and the soil.
+    pub interface_type: Option<InterfaceType>,
+    /// Plan shape of the foundation.
+    #[serde(default)]
+    pub shape: FoundationShape,
+    /// Shear failure mode for bearing capacity.
+    pub failure_mode: Option<ShearFailureMode>,
+    /// Applies the soil compressibility factors of Vesic (1973) in the Vesic method.
+    pub apply_compressibility: Option<bool>,
+    /// Adjustment of the empirical bearing capacity methods for the footing geometry.
+    pub geometry_adjustment: Option<EmpiricalGeometryAdjustment>,
 }
 
 impl Foundation {
@@ -65,11 +147,166 @@ impl Foundation {
             foundation_area: area,
             base_tilt_angle: angle,
             slope_angle: slope,
+            opposite_slope_angle: None,
+            berm_width: None,
+            opposite_berm_width: None,
+            slope_height: None,
+            opposite_slope_height: None,
+            downslope_embedment: None,
             effective_length: None,
             effective_width: None,
             surface_friction_coefficient,
+            interface_type: None,
+            shape: FoundationShape::Rectangular,
+            failure_mode: None,
+            apply_compressibility: None,
+            geometry_adjustment: None,
+        }
+    }
+
+    /// Returns the base friction coefficient for a soil friction angle.
+    ///
+    /// The manual `surface_friction_coefficient` takes precedence; otherwise the coefficient is
+    /// tan(δ) with δ from the friction ratio of the interface type.
+    ///
+    /// # Arguments
+    /// * `phi` - Friction angle of the soil at the base (degrees).
+    ///
+    /// # Returns
+    /// * `Option<f64>` - Friction coefficient, or None if neither input is set.
+    pub fn calc_base_friction_coefficient(&self, phi: f64) -> Option<f64> {
+        self.surface_friction_coefficient.or_else(|| {
+            self.interface_type.map(|interface| {
+                (get_interface_factors(interface).0 * phi)
+                    .to_radians()
+                    .tan()
+            })
+        })
+    }
+
+    /// Returns the embedment of the foundation that can be relied on for passive resistance and
+    /// overburden.
+    ///
+    /// On the downslope side of a footing in a slope or near a cut, the soil cover above the base
+    /// is smaller than the foundation depth. The smaller of `downslope_embedment` and the
+    /// foundation depth is returned; the foundation depth if no downslope embedment is set.
+    pub fn calc_downslope_embedment(&self) -> f64 {
+        let df = self.foundation_depth.unwrap();
+        self.downslope_embedment.map_or(df, |cover| cover.min(df))
+    }
+
+    /// Returns the base adhesion ratio ca/c of the interface type (1 if not set).
+    pub fn get_base_adhesion_factor(&self) -> f64 {
+        self.interface_type
+            .map_or(1.0, |interface| get_interface_factors(interface).1)
+    }
+
+    /// Returns the gross foundation pressure q of a pressure given in `pressure_convention`.
+    ///
+    /// A net pressure is converted with q = q_net + σv(Df). The total overburden includes the
+    /// water above the base, so the buoyant uplift is accounted for as well. The layer depths of
+    /// the soil profile must be calculated.
+    ///
+    /// # Arguments
+    /// * `soil_profile` - The soil profile data.
+    /// * `foundation_pressure` - Foundation pressure (t/m²).
+    /// * `pressure_convention` - Whether `foundation_pressure` is gross or net.
+    pub fn calc_gross_pressure(
+        &self,
+        soil_profile: &SoilProfile,
+        foundation_pressure: f64,
+        pressure_convention: PressureConvention,
+    ) -> f64 {
+        match pressure_convention {
+            PressureConvention::Gross => foundation_pressure,
+            PressureConvention::Net => {
+                foundation_pressure
+                    + soil_profile.calc_normal_stress(self.foundation_depth.unwrap())
+            }
         }
     }
+
+    /// Returns the net foundation pressure q_net = q − σv(Df) of a pressure given in
+    /// `pressure_convention`. The layer depths of the soil profile must be calculated.
+    ///
+    /// Below the groundwater level the base carries the uplift u = γw · (Df − zw). Since
+    /// σv(Df) = σ'v(Df) + u, the net pressure is also (q − u) − σ'v(Df): subtracting the total
+    /// overburden removes the buoyant uplift together with the effective overburden.
+    ///
+    /// # Arguments
+    /// * `soil_profile` - The soil profile data.
+    /// * `foundation_pressure` - Foundation pressure (t/m²).
+    /// * `pressure_convention` - Whether `foundation_pressure` is gross or net.
+    pub fn calc_net_pressure(
+        &self,
+        soil_profile: &SoilProfile,
+        foundation_pressure: f64,
+        pressure_convention: PressureConvention,
+    ) -> f64 {
+        match pressure_convention {
+            PressureConvention::Gross => {
+                foundation_pressure
+                    - soil_profile.calc_normal_stress(self.foundation_depth.unwrap())
+            }
+            PressureConvention::Net => foundation_pressure,
+        }
+    }
+
+    /// Returns the width to length ratio used by the shape factors.
+    ///
+    /// The ratio is 0 for strip footings and 1 for circular and ring footings, which are treated
+    /// like square footings by the bearing capacity shape factors.
+    ///
+    /// # Arguments
+    /// * `width` - Width of the foundation (m).
+    /// * `length` - Length of the foundation (m).
+    pub fn calc_shape_ratio(&self, width: f64, length: f64) -> f64 {
+        match self.shape {
+            FoundationShape::Rectangular => width / length,
+            FoundationShape::Strip => 0.0,
+            FoundationShape::Circular { .. } | FoundationShape::Ring { .. } => 1.0,
+        }
+    }
+
+    /// Sets the width, length and area of the foundation from its shape.
+    ///
+    /// * Circular: B = L = D, A = π · D² / 4
+    /// * Ring: B = L = Do, A = π · (Do² − Di²) / 4
+    /// * Strip: L = 10 · B when the length is not given
+    ///
+    /// Rectangular foundations are left unchanged.
+    pub fn apply_shape(&mut self) -> Result<(), ValidationError> {
+        self.validate(&["shape"])?;
+
+        match self.shape {
+            FoundationShape::Rectangular => {}
+            FoundationShape::Circular { diameter } => {
+                self.foundation_width = Some(diameter);
+                self.foundation_length = Some(diameter);
+                self.foundation_area = Some(std::f64::consts::PI * diameter.powi(2) / 4.0);
+            }
+            FoundationShape::Ring {
+                outer_diameter,
+                inner_diameter,
+            } => {
+                self.foundation_width = Some(outer_diameter);
+                self.foundation_length = Some(outer_diameter);
+                self.foundation_area = Some(
+                    std::f64::consts::PI * (outer_diameter.powi(2) - inner_diameter.powi(2)) / 4.0,
+                );
+            }
+            FoundationShape::Strip => {
+                self.validate(&["foundation_width"])?;
+                if self.foundation_length.is_none() {
+                    self.foundation_length =
+                        Some(STRIP_LENGTH_RATIO * self.foundation_width.unwrap());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Calculates effective lengths based on applied loads.
     ///
     /// # Arguments
@@ -84,6 +321,84 @@ impl Foundation {
         self.effective_length = Some(f64::max(b_, l_).max(0.0));
     }
 
+    /// Calculates the effective area of the foundation for one- or two-way eccentricity and stores
+    /// the effective width and length.
+    ///
+    /// One-way eccentricity uses the equivalent rectangle of Meyerhof (1953), B' = B − 2e. For
+    /// two-way eccentricity, the effective area is the part of the base cut off by a straight line
+    /// whose centroid coincides with the load resultant (Highter & Anders, 1985):
+    ///
+    /// * Case I (eB/B ≥ 1/6, eL/L ≥ 1/6): triangle with B1 = B(1.5 − 3eB/B), L1 = L(1.5 − 3eL/L)
+    /// * Case II/III: trapezoid spanning the full width or length
+    /// * Case IV: rectangle with the corner opposite the resultant cut off
+    ///
+    /// The effective length is the larger plan dimension of the effective area and the effective
+    /// width is B' = A' / L'.
+    ///
+    /// # Arguments
+    /// * `ex` - Eccentricity in x-direction, along the width (m).
+    /// * `ey` - Eccentricity in y-direction, along the length (m).
+    ///
+    /// # Returns
+    /// * `EffectiveArea` - The effective area geometry.
+    pub fn calc_effective_area(&mut self, ex: f64, ey: f64) -> EffectiveArea {
+        let width = self.foundation_width.unwrap();
+        let length = self.foundation_length.unwrap();
+        let u = ex.abs() / width;
+        let v = ey.abs() / length;
+        let outside_kern = u > 1.0 / 6.0 || v > 1.0 / 6.0;
+
+        let (case, b_, l_, area) = if u >= 0.5 || v >= 0.5 {
+            (EccentricityCase::NoContact, 0.0, 0.0, 0.0)
+        } else if u == 0.0 && v == 0.0 {
+            (
+                EccentricityCase::Concentric,
+                width.min(length),
+                width.max(length),
+                width * length,
+            )
+        } else if u == 0.0 || v == 0.0 {
+            let b1 = width * (1.0 - 2.0 * u);
+            let l1 = length * (1.0 - 2.0 * v);
+            (EccentricityCase::OneWay, b1.min(l1), b1.max(l1), b1 * l1)
+        } else if let Some((alpha, beta)) = calc_corner_cut(u, v) {
+            // Case IV: corner triangle with legs α·B and β·L removed
+            let area = width * length * (1.0 - alpha * beta / 2.0);
+            let l_ = width.max(length);
+            (EccentricityCase::Pentagonal, area / l_, l_, area)
+        } else if let Some((h0, h1)) = calc_trapezoid_heights(u, 0.5 - v) {
+            // Case II: parallel sides along the length, spanning the full width
+            let (h0, h1) = (h0 * length, h1 * length);
+            let area = width * (h0 + h1) / 2.0;
+            let l_ = width.max(h1);
+            (EccentricityCase::TrapezoidalLength, area / l_, l_, area)
+        } else if let Some((h0, h1)) = calc_trapezoid_heights(v, 0.5 - u) {
+            // Case III: parallel sides along the width, spanning the full length
+            let (h0, h1) = (h0 * width, h1 * width);
+            let area = length * (h0 + h1) / 2.0;
+            let l_ = length.max(h1);
+            (EccentricityCase::TrapezoidalWidth, area / l_, l_, area)
+        } else {
+            // Case I: triangle at the loaded corner
+            let b1 = width * (1.5 - 3.0 * u);
+            let l1 = length * (1.5 - 3.0 * v);
+            let area = b1 * l1 / 2.0;
+            let l_ = b1.max(l1);
+            (EccentricityCase::Triangular, area / l_, l_, area)
+        };
+
+        self.effective_width = Some(b_);
+        self.effective_length = Some(l_);
+
+        EffectiveArea {
+            effective_width: b_,
+            effective_length: l_,
+            effective_area: area,
+            case,
+            outside_kern,
+        }
+    }
+
     /// Validates specific fields of the Foundation using field names.
     /// This enables context-specific validation like `["foundation_depth", "effective_width"]`
     ///
@@ -143,6 +458,71 @@ impl Foundation {
                     "foundation",
                 ),
 
+                "opposite_slope_angle" => validate_field(
+                    "opposite_slope_angle",
+                    self.opposite_slope_angle,
+                    Some(0.0),
+                    Some(90.0),
+                    "foundation",
+                ),
+
+                "berm_width" => {
+                    validate_field("berm_width", self.berm_width, Some(0.0), None, "foundation")
+                }
+
+                "opposite_berm_width" => validate_field(
+                    "opposite_berm_width",
+                    self.opposite_berm_width,
+                    Some(0.0),
+                    None,
+                    "foundation",
+                ),
+
+                "slope_height" => validate_field(
+                    "slope_height",
+                    self.slope_height,
+                    Some(0.0),
+                    None,
+                    "foundation",
+                ),
+
+                "opposite_slope_height" => validate_field(
+                    "opposite_slope_height",
+                    self.opposite_slope_height,
+                    Some(0.0),
+                    None,
+                    "foundation",
+                ),
+
+                "downslope_embedment" => validate_field(
+                    "downslope_embedment",
+                    self.downslope_embedment,
+                    Some(0.0),
+                    self.foundation_depth,
+                    "foundation",
+                ),
+
+                "geometry_adjustment" => match self.geometry_adjustment {
+                    Some(EmpiricalGeometryAdjustment::Custom {
+                        shape_factor,
+                        depth_factor,
+                    }) => validate_field(
+                        "shape_factor",
+                        Some(shape_factor),
+                        Some(0.0),
+                        None,
+                        "foundation.geometry_adjustment",
+                    )
+                    .and(validate_field(
+                        "depth_factor",
+                        Some(depth_factor),
+                        Some(0.0),
+                        None,
+                        "foundation.geometry_adjustment",
+                    )),
+                    _ => Ok(()),
+                },
+
                 "effective_width" => validate_field(
                     "effective_width",
                     self.effective_width,
@@ -167,6 +547,36 @@ impl Foundation {
                     "foundation",
                 ),
 
+                "shape" => match self.shape {
+                    FoundationShape::Rectangular | FoundationShape::Strip => Ok(()),
+                    FoundationShape::Circular { diameter } => validate_field(
+                        "diameter",
+                        Some(diameter),
+                        Some(0.001),
+                        None,
+                        "foundation.shape",
+                    ),
+                    FoundationShape::Ring {
+                        outer_diameter,
+                        inner_diameter,
+                    } => validate_field(
+                        "outer_diameter",
+                        Some(outer_diameter),
+                        Some(0.001),
+                        None,
+                        "foundation.shape",
+                    )
+                    .and_then(|_| {
+                        validate_field(
+                            "inner_diameter",
+                            Some(inner_diameter),
+                            Some(0.0),
+                            Some(outer_diameter - 0.001),
+                            "foundation.shape",
+                        )
+                    }),
+                },
+
                 unknown => Err(ValidationError {
                     code: "foundation.invalid_field".into(),
                     message: format!("Field '{}' is not valid for Foundation.", unknown),
@@ -179,3 +589,63 @@ impl Foundation {
         Ok(())
     }
 }
+
+/// Solves the corner cut of the Case IV effective area for normalized eccentricities u = eB/B and
+/// v = eL/L.
+///
+/// Removing a triangle with legs α·B and β·L from the corner opposite the resultant moves the
+/// centroid by u · (1 − αβ/2) = αβ(3 − 2α)/12 (and likewise for v, β). With p = αβ this gives
+/// α = 1.5 + 3u − 6u/p and β = 1.5 + 3v − 6v/p, and p is found by bisection.
+///
+/// # Returns
+/// * `Some((α, β))` if the cut stays within the foundation edges, `None` otherwise.
+fn calc_corner_cut(u: f64, v: f64) -> Option<(f64, f64)> {
+    let alpha = |p: f64| 1.5 + 3.0 * u - 6.0 * u / p;
+    let beta = |p: f64| 1.5 + 3.0 * v - 6.0 * v / p;
+    let f = |p: f64| alpha(p) * beta(p) - p;
+
+    let mut low = f64::max(4.0 * u / (1.0 + 2.0 * u), 4.0 * v / (1.0 + 2.0 * v));
+    let mut high = 1.0;
+    if low >= high || f(high) < 0.0 {
+        return None;
+    }
+
+    for _ in 0..100 {
+        let mid = 0.5 * (low + high);
+        if f(mid) < 0.0 {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+
+    let p = 0.5 * (low + high);
+    let (a, b) = (alpha(p), beta(p));
+    (a <= 1.0 + 1e-9 && b <= 1.0 + 1e-9).then_some((a.min(1.0), b.min(1.0)))
+}
+
+/// Calculates the normalized parallel sides of a trapezoidal effective area spanning the full
+/// side of the foundation.
+///
+/// For a trapezoid with sides h0 and h1 (h1 on the loaded side), the centroid is at
+/// (h0 + 2h1) / (3(h0 + h1)) across the spanned side and at (h0² + h0·h1 + h1²) / (3(h0 + h1))
+/// from the loaded edge.
+///
+/// # Arguments
+/// * `e_span` - Normalized eccentricity along the spanned side.
+/// * `distance` - Normalized distance of the resultant from the loaded edge.
+///
+/// # Returns
+/// * `Some((h0, h1))` normalized by the foundation dimension, if the trapezoid fits, `None`
+///   otherwise.
+fn calc_trapezoid_heights(e_span: f64, distance: f64) -> Option<(f64, f64)> {
+    if e_span > 1.0 / 6.0 {
+        return None;
+    }
+
+    let r = 0.5 + e_span;
+    let k = (2.0 - 3.0 * r) / (3.0 * r - 1.0);
+    let h1 = 3.0 * distance * (k + 1.0) / (k * k + k + 1.0);
+
+    (h1 <= 1.0 + 1e-9).then_some((k * h1, h1.min(1.0)))
+}
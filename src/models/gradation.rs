@@ -0,0 +1,184 @@
+use serde::{Deserialize, Serialize};
+
+use crate::validation::{validate_field, ValidationError};
+
+/// Represents the particle size distribution (gradation curve) of a soil.
+///
+/// Interpolation between the sieve sizes is linear in percent passing and logarithmic in
+/// particle size, as the curve is plotted on a semi-log chart.
+///
+/// # Fields
+/// * `sizes` - Particle (sieve) sizes in increasing order (mm).
+/// * `percent_passing` - Percent passing by mass for each size (%).
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct GradationCurve {
+    /// Particle sizes in increasing order (mm).
+    pub sizes: Vec<f64>,
+    /// Percent passing for each size (%).
+    pub percent_passing: Vec<f64>,
+}
+
+impl GradationCurve {
+    /// Creates a new `GradationCurve` instance.
+    ///
+    /// # Arguments
+    /// * `sizes` - Particle sizes in increasing order (mm).
+    /// * `percent_passing` - Percent passing for each size (%).
+    pub fn new(sizes: Vec<f64>, percent_passing: Vec<f64>) -> Self {
+        Self {
+            sizes,
+            percent_passing,
+        }
+    }
+
+    /// Validates the gradation curve.
+    ///
+    /// The curve must have at least two points, positive increasing sizes and non-decreasing
+    /// percent passing values between 0 and 100.
+    ///
+    /// # Returns
+    /// Ok(()) if the curve is valid, or an error if it is invalid.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if self.sizes.len() != self.percent_passing.len() {
+            return Err(ValidationError {
+                code: "gradation.length_mismatch".to_string(),
+                message: "Sizes and percent passing must have the same length.".to_string(),
+            });
+        }
+        if self.sizes.len() < 2 {
+            return Err(ValidationError {
+                code: "gradation.too_few_points".to_string(),
+                message: "Gradation curve must have at least two points.".to_string(),
+            });
+        }
+
+        for (&size, &passing) in self.sizes.iter().zip(self.percent_passing.iter()) {
+            validate_field("size", Some(size), Some(1e-6), None, "gradation")?;
+            validate_field(
+                "percent_passing",
+                Some(passing),
+                Some(0.0),
+                Some(100.0),
+                "gradation",
+            )?;
+        }
+
+        for i in 1..self.sizes.len() {
+            if self.sizes[i] <= self.sizes[i - 1] {
+                return Err(ValidationError {
+                    code: "gradation.sizes.not_increasing".to_string(),
+                    message: "Sizes must be in increasing order.".to_string(),
+                });
+            }
+            if self.percent_passing[i] < self.percent_passing[i - 1] {
+                return Err(ValidationError {
+                    code: "gradation.percent_passing.decreasing".to_string(),
+                    message: "Percent passing must not decrease with size.".to_string(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the percent passing (finer) for a given particle size.
+    ///
+    /// Sizes below the smallest sieve give the first value and sizes above the largest sieve
+    /// give the last value.
+    ///
+    /// # Arguments
+    /// * `size` - Particle size (mm).
+    ///
+    /// # Returns
+    /// * `f64`: Percent finer than the size (%).
+    pub fn percent_finer(&self, size: f64) -> f64 {
+        let n = self.sizes.len();
+        if size <= self.sizes[0] {
+            return self.percent_passing[0];
+        }
+        if size >= self.sizes[n - 1] {
+            return self.percent_passing[n - 1];
+        }
+
+        let i = self.sizes.iter().position(|&s| s >= size).unwrap();
+        let (x0, x1) = (self.sizes[i - 1].log10(), self.sizes[i].log10());
+        let (y0, y1) = (self.percent_passing[i - 1], self.percent_passing[i]);
+        y0 + (y1 - y0) * (size.log10() - x0) / (x1 - x0)
+    }
+
+    /// Returns the particle size for a given percent passing (e.g. D15 for 15%).
+    ///
+    /// # Arguments
+    /// * `percent` - Percent passing (%).
+    ///
+    /// # Returns
+    /// * `Option<f64>`: Particle size (mm), or `None` if the percentage is outside the curve.
+    pub fn diameter_at(&self, percent: f64) -> Option<f64> {
+        let n = self.sizes.len();
+        if percent < self.percent_passing[0] || percent > self.percent_passing[n - 1] {
+            return None;
+        }
+
+        let i = self
+            .percent_passing
+            .iter()
+            .position(|&p| p >= percent)
+            .unwrap();
+        if i == 0 || self.percent_passing[i] == self.percent_passing[i - 1] {
+            return Some(self.sizes[i]);
+        }
+
+        let (x0, x1) = (self.sizes[i - 1].log10(), self.sizes[i].log10());
+        let (y0, y1) = (self.percent_passing[i - 1], self.percent_passing[i]);
+        Some(10f64.powf(x0 + (x1 - x0) * (percent - y0) / (y1 - y0)))
+    }
+
+    /// Returns the effective size D10 (mm).
+    pub fn d10(&self) -> Option<f64> {
+        self.diameter_at(10.0)
+    }
+
+    /// Returns D30 (mm).
+    pub fn d30(&self) -> Option<f64> {
+        self.diameter_at(30.0)
+    }
+
+    /// Returns D60 (mm).
+    pub fn d60(&self) -> Option<f64> {
+        self.diameter_at(60.0)
+    }
+
+    /// Returns the coefficient of uniformity Cu = D60 / D10.
+    pub fn uniformity_coefficient(&self) -> Option<f64> {
+        Some(self.d60()? / self.d10()?)
+    }
+
+    /// Returns the coefficient of curvature Cc = D30² / (D10 · D60).
+    pub fn curvature_coefficient(&self) -> Option<f64> {
+        Some(self.d30()?.powi(2) / (self.d10()? * self.d60()?))
+    }
+
+    /// Returns the percentage of fines passing the No. 200 (0.075 mm) sieve (%).
+    pub fn fines_content(&self) -> f64 {
+        self.percent_finer(0.075)
+    }
+
+    /// Returns whether the coarse fraction is well graded according to USCS.
+    ///
+    /// Gravels (more than half of the coarse fraction retained on the 4.75 mm sieve) require
+    /// Cu ≥ 4, sands require Cu ≥ 6, and both require 1 ≤ Cc ≤ 3.
+    ///
+    /// # Returns
+    /// * `Option<bool>`: `None` if Cu or Cc cannot be read from the curve.
+    pub fn is_well_graded(&self) -> Option<bool> {
+        let cu = self.uniformity_coefficient()?;
+        let cc = self.curvature_coefficient()?;
+
+        let fines = self.fines_content();
+        let sand = self.percent_finer(4.75) - fines;
+        let gravel = 100.0 - self.percent_finer(4.75);
+        let min_cu = if gravel > sand { 4.0 } else { 6.0 };
+
+        Some(cu >= min_cu && (1.0..=3.0).contains(&cc))
+    }
+}
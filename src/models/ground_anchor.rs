@@ -0,0 +1,110 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::{AnchorGroundType, GroutingMethod},
+    validation::{validate_field, ValidationError},
+};
+
+/// Represents a prestressed ground anchor of a retaining wall.
+///
+/// # Fields
+/// * `design_load` - Design load of the anchor (t).
+/// * `drill_hole_diameter` - Diameter of the grouted drill hole (m).
+/// * `inclination` - Inclination of the anchor below the horizontal (degrees).
+/// * `head_depth` - Depth of the anchor head below the top of the wall (m).
+/// * `ground_type` - Ground type around the fixed length.
+/// * `grouting_method` - Grouting method of the fixed length.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroundAnchor {
+    /// Design load of the anchor (t).
+    pub design_load: Option<f64>,
+    /// Diameter of the grouted drill hole (m).
+    pub drill_hole_diameter: Option<f64>,
+    /// Inclination of the anchor below the horizontal (degrees).
+    pub inclination: Option<f64>,
+    /// Depth of the anchor head below the top of the wall (m).
+    pub head_depth: Option<f64>,
+    /// Ground type around the fixed length.
+    pub ground_type: AnchorGroundType,
+    /// Grouting method of the fixed length.
+    pub grouting_method: GroutingMethod,
+}
+
+impl GroundAnchor {
+    /// Creates a new `GroundAnchor` instance.
+    ///
+    /// # Arguments
+    /// * `design_load` - Design load of the anchor (t).
+    /// * `drill_hole_diameter` - Diameter of the grouted drill hole (m).
+    /// * `inclination` - Inclination of the anchor below the horizontal (degrees).
+    /// * `head_depth` - Depth of the anchor head below the top of the wall (m).
+    /// * `ground_type` - Ground type around the fixed length.
+    /// * `grouting_method` - Grouting method of the fixed length.
+    pub fn new(
+        design_load: f64,
+        drill_hole_diameter: f64,
+        inclination: f64,
+        head_depth: f64,
+        ground_type: AnchorGroundType,
+        grouting_method: GroutingMethod,
+    ) -> Self {
+        Self {
+            design_load: Some(design_load),
+            drill_hole_diameter: Some(drill_hole_diameter),
+            inclination: Some(inclination),
+            head_depth: Some(head_depth),
+            ground_type,
+            grouting_method,
+        }
+    }
+
+    /// Validates specific fields of the GroundAnchor using field names.
+    ///
+    /// # Arguments
+    /// * `fields` - A slice of field names to validate.
+    ///
+    /// # Returns
+    /// Ok(()) if all fields are valid, or an error if any field is invalid.
+    pub fn validate(&self, fields: &[&str]) -> Result<(), ValidationError> {
+        for &field in fields {
+            let result = match field {
+                "design_load" => validate_field(
+                    "design_load",
+                    self.design_load,
+                    Some(0.0001),
+                    None,
+                    "ground_anchor",
+                ),
+                "drill_hole_diameter" => validate_field(
+                    "drill_hole_diameter",
+                    self.drill_hole_diameter,
+                    Some(0.0001),
+                    None,
+                    "ground_anchor",
+                ),
+                "inclination" => validate_field(
+                    "inclination",
+                    self.inclination,
+                    Some(0.0),
+                    Some(45.0),
+                    "ground_anchor",
+                ),
+                "head_depth" => validate_field(
+                    "head_depth",
+                    self.head_depth,
+                    Some(0.0),
+                    None,
+                    "ground_anchor",
+                ),
+                unknown => Err(ValidationError {
+                    code: "ground_anchor.invalid_field".into(),
+                    message: format!("Field '{}' is not valid for GroundAnchor.", unknown),
+                }),
+            };
+
+            result?; // propagate error if any field fails
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+
+use crate::validation::{validate_field, ValidationError};
+
+/// Represents a groundwater sample tested for chemical aggressivity.
+///
+/// # Fields
+/// * `depth` - Sampling depth in meters.
+/// * `date` - Sampling date (e.g., "2024-05-17").
+/// * `ph` - pH of the water.
+/// * `sulfate` - Sulfate (SO₄²⁻) content in mg/l.
+/// * `chloride` - Chloride (Cl⁻) content in mg/l.
+/// * `total_dissolved_solids` - Total dissolved solids (TDS) in mg/l.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GroundwaterSample {
+    pub depth: Option<f64>,
+    pub date: Option<String>,
+    pub ph: Option<f64>,
+    pub sulfate: Option<f64>,
+    pub chloride: Option<f64>,
+    pub total_dissolved_solids: Option<f64>,
+}
+
+impl GroundwaterSample {
+    pub fn new(depth: f64, ph: f64, sulfate: f64, chloride: f64) -> Self {
+        Self {
+            depth: Some(depth),
+            ph: Some(ph),
+            sulfate: Some(sulfate),
+            chloride: Some(chloride),
+            ..Default::default()
+        }
+    }
+
+    /// Validates specific fields of the GroundwaterSample using field names.
+    ///
+    /// # Arguments
+    /// * `fields` - A slice of field names to validate.
+    ///
+    /// # Returns
+    /// Ok(()) if all fields are valid, or an error if any field is invalid.
+    pub fn validate(&self, fields: &[&str]) -> Result<(), ValidationError> {
+        for &field in fields {
+            let result = match field {
+                "depth" => validate_field("depth", self.depth, Some(0.0), None, "groundwater"),
+                "ph" => validate_field("ph", self.ph, Some(0.0), Some(14.0), "groundwater"),
+                "sulfate" => {
+                    validate_field("sulfate", self.sulfate, Some(0.0), None, "groundwater")
+                }
+                "chloride" => {
+                    validate_field("chloride", self.chloride, Some(0.0), None, "groundwater")
+                }
+                "total_dissolved_solids" => validate_field(
+                    "total_dissolved_solids",
+                    self.total_dissolved_solids,
+                    Some(0.0),
+                    None,
+                    "groundwater",
+                ),
+                unknown => Err(ValidationError {
+                    code: "groundwater.invalid_field".into(),
+                    message: format!("Field '{}' is not valid for Groundwater Sample.", unknown),
+                }),
+            };
+
+            result?; // propagate error if any field fails
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+
+/// Descriptive log data of a soil layer or sample, as recorded on a borehole log.
+///
+/// # Fields
+/// * `description` - Lithology description (e.g., "silty CLAY with gravel").
+/// * `uscs_symbol` - Unified Soil Classification System group symbol (e.g., "CL").
+/// * `color` - Color of the soil (e.g., "brown").
+/// * `moisture` - Moisture condition (e.g., "moist").
+/// * `consistency` - Consistency or relative density (e.g., "stiff", "medium dense").
+/// * `photos` - References (file paths or URIs) to core box or sample photos.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Lithology {
+    pub description: Option<String>,
+    pub uscs_symbol: Option<String>,
+    pub color: Option<String>,
+    pub moisture: Option<String>,
+    pub consistency: Option<String>,
+    #[serde(default)]
+    pub photos: Vec<String>,
+}
+
+impl Lithology {
+    /// Returns the log description in the usual order of color, moisture, consistency,
+    /// description and group symbol, e.g. "brown, moist, stiff silty CLAY (CL)".
+    ///
+    /// # Returns
+    /// * `Option<String>`: The summary, or `None` if no descriptive field is set.
+    pub fn summary(&self) -> Option<String> {
+        let mut text = [&self.color, &self.moisture, &self.consistency]
+            .iter()
+            .filter_map(|s| s.as_deref())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        if let Some(description) = &self.description {
+            if !text.is_empty() {
+                text.push(' ');
+            }
+            text.push_str(description);
+        }
+        if let Some(symbol) = &self.uscs_symbol {
+            if !text.is_empty() {
+                text.push(' ');
+            }
+            text.push_str(&format!("({})", symbol));
+        }
+
+        (!text.is_empty()).then_some(text)
+    }
+}
@@ -37,6 +37,10 @@ impl Stress {
 /// * `moment_x` - Moment in x-direction in ton.m
 /// * `moment_y` - Moment in y-direction in ton.m
 /// * `vertical_load` - Vertical load in ton
+/// * `flood_height` - Height of flood water above the ground surface in m
+/// * `horizontal_seismic_coefficient` - Horizontal seismic coefficient kh
+/// * `vertical_seismic_coefficient` - Vertical seismic coefficient kv
+/// * `surface_surcharge` - Uniform surcharge on the ground surface around the foundation in ton/m^2
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Loads {
     pub service_load: Option<Stress>,
@@ -47,6 +51,10 @@ pub struct Loads {
     pub moment_x: Option<f64>,
     pub moment_y: Option<f64>,
     pub vertical_load: Option<f64>,
+    pub flood_height: Option<f64>,
+    pub horizontal_seismic_coefficient: Option<f64>,
+    pub vertical_seismic_coefficient: Option<f64>,
+    pub surface_surcharge: Option<f64>,
 }
 
 impl Loads {
@@ -79,25 +87,21 @@ impl Loads {
     }
     /// Calculates the eccentricity of the loading.
     ///
-    /// # Arguments
-    /// * `vertical_load` - Vertical load in ton (or equivalent unit).
-    ///
     /// # Returns
     /// * `(ex, ey)` - Eccentricities in meters (or equivalent unit).
     ///
     /// # Note
+    /// A missing moment gives no eccentricity in its direction, so one-way moments are kept.
     /// If `vertical_load` is zero, it returns `(0.0, 0.0)` to prevent division by zero.
     pub fn calc_eccentricity(&self) -> (f64, f64) {
-        if self.vertical_load.is_none() || self.vertical_load.unwrap() == 0.0 {
+        let vertical_load = self.vertical_load.unwrap_or(0.0);
+        if vertical_load == 0.0 {
             return (0.0, 0.0);
         }
-        if let (Some(mx), Some(my)) = (self.moment_x, self.moment_y) {
-            let ex = mx / self.vertical_load.unwrap();
-            let ey = my / self.vertical_load.unwrap();
-            (ex, ey)
-        } else {
-            (0.0, 0.0)
-        }
+        let ex = self.moment_x.unwrap_or(0.0) / vertical_load;
+        let ey = self.moment_y.unwrap_or(0.0) / vertical_load;
+
+        (ex, ey)
     }
     /// Validates specific fields of the Loads using field names.
     /// This enables context-specific validation like `["vertical_load", "moment_x"]`.
@@ -133,6 +137,30 @@ impl Loads {
                     None,
                     "loads",
                 ),
+                "flood_height" => {
+                    validate_field("flood_height", self.flood_height, Some(0.0), None, "loads")
+                }
+                "horizontal_seismic_coefficient" => validate_field(
+                    "horizontal_seismic_coefficient",
+                    self.horizontal_seismic_coefficient,
+                    Some(0.0),
+                    Some(1.0),
+                    "loads",
+                ),
+                "vertical_seismic_coefficient" => validate_field(
+                    "vertical_seismic_coefficient",
+                    self.vertical_seismic_coefficient,
+                    Some(0.0),
+                    Some(0.99),
+                    "loads",
+                ),
+                "surface_surcharge" => validate_field(
+                    "surface_surcharge",
+                    self.surface_surcharge,
+                    Some(0.0),
+                    None,
+                    "loads",
+                ),
                 "service_load" => {
                     if let Some(service_load) = &self.service_load {
                         service_load.validate()
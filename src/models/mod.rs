@@ -1,7 +1,33 @@
+pub mod adjacent_structure;
+pub mod averaging_window;
+pub mod borehole;
+pub mod buried_pipe;
+pub mod column_load;
+pub mod column_schedule;
 pub mod cpt;
+pub mod deadman;
+pub mod driven_pile;
+pub mod embankment;
+pub mod engineered_fill;
+pub mod excavation;
 pub mod foundation;
+pub mod gradation;
+pub mod ground_anchor;
+pub mod groundwater;
+pub mod lithology;
 pub mod loads;
 pub mod masw;
+pub mod phreatic_surface;
+pub mod pile_section;
+pub mod piled_raft;
+pub mod pocket_penetrometer;
 pub mod point_load_test;
+pub mod pressuremeter;
+pub mod rock_mass;
+pub mod scour;
+pub mod site;
+pub mod skirted_foundation;
 pub mod soil_profile;
 pub mod spt;
+pub mod torvane;
+pub mod vibration;
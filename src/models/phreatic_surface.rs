@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{helper::interp1d, validation::ValidationError};
+
+/// Represents a phreatic surface as a polyline in a vertical cross-section.
+///
+/// Outside the given points the elevation of the nearest end point is used.
+///
+/// # Fields
+/// * `x` - Horizontal coordinates of the polyline points in increasing order (m).
+/// * `elevation` - Elevations of the polyline points (m).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PhreaticSurface {
+    /// Horizontal coordinates of the polyline points (m).
+    pub x: Vec<f64>,
+    /// Elevations of the polyline points (m).
+    pub elevation: Vec<f64>,
+}
+
+impl PhreaticSurface {
+    /// Creates a new `PhreaticSurface` instance.
+    ///
+    /// # Arguments
+    /// * `x` - Horizontal coordinates of the polyline points in increasing order (m).
+    /// * `elevation` - Elevations of the polyline points (m).
+    pub fn new(x: Vec<f64>, elevation: Vec<f64>) -> Self {
+        Self { x, elevation }
+    }
+
+    /// Validates the polyline.
+    ///
+    /// # Returns
+    /// Ok(()) if the polyline has at least two points with increasing x, or an error otherwise.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if self.x.len() != self.elevation.len() {
+            return Err(ValidationError {
+                code: "phreatic_surface.length_mismatch".to_string(),
+                message: "x and elevation must have the same number of points.".to_string(),
+            });
+        }
+        if self.x.len() < 2 {
+            return Err(ValidationError {
+                code: "phreatic_surface.too_few_points".to_string(),
+                message: "Phreatic surface must have at least two points.".to_string(),
+            });
+        }
+        if self.x.windows(2).any(|w| w[1] <= w[0]) {
+            return Err(ValidationError {
+                code: "phreatic_surface.x.not_increasing".to_string(),
+                message: "x coordinates of the phreatic surface must be increasing.".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns the elevation of the phreatic surface at a horizontal coordinate.
+    ///
+    /// # Arguments
+    /// * `x` - Horizontal coordinate (m).
+    pub fn get_elevation(&self, x: f64) -> f64 {
+        interp1d(&self.x, &self.elevation, x)
+    }
+
+    /// Returns the inclination of the phreatic surface at a horizontal coordinate (degrees).
+    ///
+    /// # Arguments
+    /// * `x` - Horizontal coordinate (m).
+    pub fn get_inclination(&self, x: f64) -> f64 {
+        let last = self.x.len() - 1;
+        if x < self.x[0] || x > self.x[last] {
+            return 0.0;
+        }
+
+        let i = self
+            .x
+            .windows(2)
+            .position(|w| x <= w[1])
+            .unwrap_or(last - 1);
+        let slope = (self.elevation[i + 1] - self.elevation[i]) / (self.x[i + 1] - self.x[i]);
+        slope.atan().to_degrees()
+    }
+}
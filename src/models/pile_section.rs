@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+
+use crate::validation::{validate_field, ValidationError};
+
+/// Represents the structural section of a pile.
+///
+/// # Fields
+/// * `diameter` - Outer diameter of the pile (m).
+/// * `length` - Embedded length of the pile (m).
+/// * `elastic_modulus` - Elastic modulus of the pile material Ep (t/m²).
+/// * `moment_of_inertia` - Moment of inertia of the section Ip (m⁴), π · d⁴ / 64 if not given.
+/// * `moment_capacity` - Bending moment capacity of the section (t·m).
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct PileSection {
+    pub diameter: Option<f64>,
+    pub length: Option<f64>,
+    pub elastic_modulus: Option<f64>,
+    pub moment_of_inertia: Option<f64>,
+    pub moment_capacity: Option<f64>,
+}
+
+impl PileSection {
+    /// Returns the moment of inertia of the section (m⁴).
+    ///
+    /// The moment of inertia of a solid circular section is used when none is given.
+    pub fn get_moment_of_inertia(&self) -> f64 {
+        self.moment_of_inertia
+            .unwrap_or_else(|| std::f64::consts::PI * self.diameter.unwrap().powi(4) / 64.0)
+    }
+
+    /// Validates specific fields of the PileSection using field names.
+    ///
+    /// # Arguments
+    /// * `fields` - A slice of field names to validate.
+    ///
+    /// # Returns
+    /// Ok(()) if all fields are valid, or an error if any field is invalid.
+    pub fn validate(&self, fields: &[&str]) -> Result<(), ValidationError> {
+        for &field in fields {
+            let result = match field {
+                "diameter" => validate_field(
+                    "diameter",
+                    self.diameter,
+                    Some(0.0001),
+                    None,
+                    "pile_section",
+                ),
+                "length" => {
+                    validate_field("length", self.length, Some(0.0001), None, "pile_section")
+                }
+                "elastic_modulus" => validate_field(
+                    "elastic_modulus",
+                    self.elastic_modulus,
+                    Some(0.0001),
+                    None,
+                    "pile_section",
+                ),
+                "moment_of_inertia" => validate_field(
+                    "moment_of_inertia",
+                    self.moment_of_inertia,
+                    Some(0.0),
+                    None,
+                    "pile_section",
+                ),
+                "moment_capacity" => validate_field(
+                    "moment_capacity",
+                    self.moment_capacity,
+                    Some(0.0001),
+                    None,
+                    "pile_section",
+                ),
+                unknown => Err(ValidationError {
+                    code: "pile_section.invalid_field".into(),
+                    message: format!("Field '{}' is not valid for PileSection.", unknown),
+                }),
+            };
+
+            result?; // propagate error if any field fails
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+
+use crate::validation::{validate_field, ValidationError};
+
+/// Represents a piled raft foundation.
+///
+/// # Fields
+/// * `raft_width` - Width of the raft (m).
+/// * `raft_length` - Length of the raft (m).
+/// * `pile_count` - Number of piles under the raft.
+/// * `pile_stiffness` - Axial stiffness of a single pile (t/m), e.g. from a pile load test.
+/// * `pile_capacity` - Ultimate axial capacity of a single pile (t).
+/// * `group_exponent` - Exponent e of the pile group stiffness kp = n^(1−e) · k1 (typically 0.3–0.5).
+/// * `interaction_factor` - Raft-pile interaction factor αrp (typically about 0.8).
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct PiledRaft {
+    /// Width of the raft (m).
+    pub raft_width: Option<f64>,
+    /// Length of the raft (m).
+    pub raft_length: Option<f64>,
+    /// Number of piles under the raft.
+    pub pile_count: Option<f64>,
+    /// Axial stiffness of a single pile (t/m).
+    pub pile_stiffness: Option<f64>,
+    /// Ultimate axial capacity of a single pile (t).
+    pub pile_capacity: Option<f64>,
+    /// Exponent e of the pile group stiffness.
+    pub group_exponent: Option<f64>,
+    /// Raft-pile interaction factor αrp.
+    pub interaction_factor: Option<f64>,
+}
+
+impl PiledRaft {
+    /// Validates specific fields of the PiledRaft using field names.
+    ///
+    /// # Arguments
+    /// * `fields` - A slice of field names to validate.
+    ///
+    /// # Returns
+    /// Ok(()) if all fields are valid, or an error if any field is invalid.
+    pub fn validate(&self, fields: &[&str]) -> Result<(), ValidationError> {
+        for &field in fields {
+            let result = match field {
+                "raft_width" => validate_field(
+                    "raft_width",
+                    self.raft_width,
+                    Some(0.0001),
+                    None,
+                    "piled_raft",
+                ),
+                "raft_length" => validate_field(
+                    "raft_length",
+                    self.raft_length,
+                    Some(0.0001),
+                    None,
+                    "piled_raft",
+                ),
+                "pile_count" => {
+                    validate_field("pile_count", self.pile_count, Some(1.0), None, "piled_raft")
+                }
+                "pile_stiffness" => validate_field(
+                    "pile_stiffness",
+                    self.pile_stiffness,
+                    Some(0.0001),
+                    None,
+                    "piled_raft",
+                ),
+                "pile_capacity" => validate_field(
+                    "pile_capacity",
+                    self.pile_capacity,
+                    Some(0.0001),
+                    None,
+                    "piled_raft",
+                ),
+                "group_exponent" => validate_field(
+                    "group_exponent",
+                    self.group_exponent,
+                    Some(0.0),
+                    Some(1.0),
+                    "piled_raft",
+                ),
+                "interaction_factor" => validate_field(
+                    "interaction_factor",
+                    self.interaction_factor,
+                    Some(0.0),
+                    Some(0.99),
+                    "piled_raft",
+                ),
+                unknown => Err(ValidationError {
+                    code: "piled_raft.invalid_field".into(),
+                    message: format!("Field '{}' is not valid for PiledRaft.", unknown),
+                }),
+            };
+
+            result?; // propagate error if any field fails
+        }
+
+        Ok(())
+    }
+}
@@ -8,6 +8,9 @@ use crate::{
     validation::{validate_field, ValidationError},
 };
 
+/// (Is50, d) pair collected per depth while idealizing the boreholes.
+type Is50DiameterPair = (OrderedFloat<f64>, OrderedFloat<f64>);
+
 /// Represents an individual Point Load Test sample for determining rock strength.
 ///
 /// # Fields
@@ -183,10 +186,7 @@ impl PointLoadTest {
 
         let mode = self.idealization_method;
 
-        let mut depth_map: BTreeMap<
-            OrderedFloat<f64>,
-            Vec<(OrderedFloat<f64>, OrderedFloat<f64>)>,
-        > = BTreeMap::new();
+        let mut depth_map: BTreeMap<OrderedFloat<f64>, Vec<Is50DiameterPair>> = BTreeMap::new();
 
         // Collect all unique depths and corresponding (is50, d) values
         for exp in &self.exps {
@@ -0,0 +1,239 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use ordered_float::OrderedFloat;
+
+use crate::{
+    enums::SelectionMethod,
+    validation::{validate_field, ValidationError},
+};
+
+/// (pl, Em) pair collected per depth while idealizing the boreholes.
+type PressureModulusPair = (OrderedFloat<f64>, OrderedFloat<f64>);
+
+/// Represents an individual Menard pressuremeter test point.
+///
+/// # Fields
+/// * `depth` - Depth of the test point in meters.
+/// * `limit_pressure` - Limit pressure pl in ton/m².
+/// * `menard_modulus` - Menard pressuremeter modulus Em in ton/m².
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PressuremeterSample {
+    pub depth: Option<f64>,
+    pub limit_pressure: Option<f64>,
+    pub menard_modulus: Option<f64>,
+}
+
+impl PressuremeterSample {
+    pub fn new(depth: f64, limit_pressure: f64, menard_modulus: f64) -> Self {
+        Self {
+            depth: Some(depth),
+            limit_pressure: Some(limit_pressure),
+            menard_modulus: Some(menard_modulus),
+        }
+    }
+
+    /// Validates specific fields of the PressuremeterSample using field names.
+    ///
+    /// # Arguments
+    /// * `fields` - A slice of field names to validate.
+    ///
+    /// # Returns
+    /// Ok(()) if all fields are valid, or an error if any field is invalid.
+    pub fn validate(&self, fields: &[&str]) -> Result<(), ValidationError> {
+        for &field in fields {
+            let result = match field {
+                "depth" => validate_field("depth", self.depth, Some(0.0), None, "pressuremeter"),
+                "limit_pressure" => validate_field(
+                    "limit_pressure",
+                    self.limit_pressure,
+                    Some(0.00001),
+                    None,
+                    "pressuremeter",
+                ),
+                "menard_modulus" => validate_field(
+                    "menard_modulus",
+                    self.menard_modulus,
+                    Some(0.00001),
+                    None,
+                    "pressuremeter",
+                ),
+                unknown => Err(ValidationError {
+                    code: "pressuremeter.invalid_field".into(),
+                    message: format!("Field '{}' is not valid for Pressuremeter Test.", unknown),
+                }),
+            };
+
+            result?; // propagate error if any field fails
+        }
+
+        Ok(())
+    }
+}
+
+/// Represents a single borehole containing multiple pressuremeter test points.
+///
+/// # Fields
+/// * `borehole_id` - Identifier for the borehole.
+/// * `samples` - Collection of test points of the borehole, sorted by depth.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PressuremeterExp {
+    pub borehole_id: String,
+    pub samples: Vec<PressuremeterSample>,
+}
+
+impl PressuremeterExp {
+    pub fn new(borehole_id: String, samples: Vec<PressuremeterSample>) -> Self {
+        Self {
+            borehole_id,
+            samples,
+        }
+    }
+
+    pub fn add_sample(&mut self, sample: PressuremeterSample) {
+        self.samples.push(sample);
+    }
+
+    /// Retrieves the test point at the specified depth.
+    ///
+    /// This function finds the first test point whose depth is greater than or equal to the given
+    /// `depth`. If no such test point is found, it returns the last test point in the list.
+    ///
+    /// # Arguments
+    ///
+    /// * `depth` - The depth at which to search for a test point.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the matching `PressuremeterSample`.
+    pub fn get_sample_at_depth(&self, depth: f64) -> &PressuremeterSample {
+        self.samples
+            .iter()
+            .find(|exp| exp.depth.unwrap() >= depth)
+            .unwrap_or_else(|| self.samples.last().unwrap())
+    }
+
+    /// Validates specific fields of the PressuremeterExp using field names.
+    ///
+    /// # Arguments
+    /// * `fields` - A slice of field names to validate.
+    ///
+    /// # Returns
+    /// Ok(()) if all fields are valid, or an error if any field is invalid.
+    pub fn validate(&self, fields: &[&str]) -> Result<(), ValidationError> {
+        if self.samples.is_empty() {
+            return Err(ValidationError {
+                code: "pressuremeter.empty_samples".into(),
+                message: "No samples provided for Pressuremeter Experiment.".into(),
+            });
+        }
+        for sample in &self.samples {
+            sample.validate(fields)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Represents the entire pressuremeter test campaign comprising multiple boreholes.
+///
+/// # Fields
+/// * `exps` - Collection of borehole tests included in the overall test campaign.
+/// * `idealization_method` - Method used for idealizing the test results.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PressuremeterTest {
+    pub exps: Vec<PressuremeterExp>,
+    pub idealization_method: SelectionMethod,
+}
+
+impl PressuremeterTest {
+    pub fn new(exps: Vec<PressuremeterExp>, idealization_method: SelectionMethod) -> Self {
+        Self {
+            exps,
+            idealization_method,
+        }
+    }
+
+    pub fn add_borehole(&mut self, exp: PressuremeterExp) {
+        self.exps.push(exp);
+    }
+
+    /// Get the idealized experiment
+    ///
+    /// The limit pressure is selected per depth by the idealization method and the Menard
+    /// modulus of the selected test point is kept (averaged with `SelectionMethod::Avg`).
+    ///
+    /// # Arguments
+    /// * `name` - Name of the idealized experiment
+    ///
+    /// # Returns
+    /// * `PressuremeterExp` - Idealized experiment
+    pub fn get_idealized_exp(&self, name: String) -> PressuremeterExp {
+        if self.exps.is_empty() {
+            return PressuremeterExp::new(name, vec![]);
+        }
+
+        let mode = self.idealization_method;
+
+        let mut depth_map: BTreeMap<OrderedFloat<f64>, Vec<PressureModulusPair>> = BTreeMap::new();
+
+        // Collect all unique depths and corresponding (pl, Em) values
+        for exp in &self.exps {
+            for sample in &exp.samples {
+                depth_map
+                    .entry(OrderedFloat(sample.depth.unwrap()))
+                    .or_default()
+                    .push((
+                        OrderedFloat(sample.limit_pressure.unwrap()),
+                        OrderedFloat(sample.menard_modulus.unwrap()),
+                    ));
+            }
+        }
+
+        // Create a new PressuremeterExp with selected values
+        let mut idealized_samples = Vec::new();
+
+        for (&depth, pairs) in &depth_map {
+            let selected = match mode {
+                SelectionMethod::Min => pairs.iter().min_by_key(|&(pl, _)| pl).unwrap(),
+                SelectionMethod::Max => pairs.iter().max_by_key(|&(pl, _)| pl).unwrap(),
+                SelectionMethod::Avg => {
+                    let sum_pl: f64 = pairs.iter().map(|(pl, _)| pl.into_inner()).sum();
+                    let sum_em: f64 = pairs.iter().map(|(_, em)| em.into_inner()).sum();
+                    let count = pairs.len() as f64;
+                    &(OrderedFloat(sum_pl / count), OrderedFloat(sum_em / count))
+                }
+            };
+
+            // Add to new PressuremeterExp
+            idealized_samples.push(PressuremeterSample::new(
+                depth.into_inner(),
+                selected.0.into_inner(),
+                selected.1.into_inner(),
+            ));
+        }
+
+        PressuremeterExp::new(name, idealized_samples)
+    }
+
+    /// Validates specific fields of the PressuremeterTest using field names.
+    ///
+    /// # Arguments
+    /// * `fields` - A slice of field names to validate.
+    ///
+    /// # Returns
+    /// Ok(()) if all fields are valid, or an error if any field is invalid.
+    pub fn validate(&self, fields: &[&str]) -> Result<(), ValidationError> {
+        if self.exps.is_empty() {
+            return Err(ValidationError {
+                code: "pressuremeter.empty_exps".into(),
+                message: "No experiments provided for Pressuremeter Test.".into(),
+            });
+        }
+        for exp in &self.exps {
+            exp.validate(fields)?;
+        }
+
+        Ok(())
+    }
+}
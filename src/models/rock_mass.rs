@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+
+use crate::validation::{validate_field, ValidationError};
+
+/// Represents a rock mass described by the generalized Hoek-Brown failure criterion.
+///
+/// σ1' = σ3' + σci · (mb · σ3' / σci + s)^a
+///
+/// # Fields
+/// * `gsi` - Geological Strength Index.
+/// * `mi` - Hoek-Brown constant of the intact rock.
+/// * `disturbance_factor` - Disturbance factor D (0 for undisturbed, 1 for very disturbed rock).
+/// * `ucs` - Uniaxial compressive strength of the intact rock σci (MPa).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RockMassHoekBrown {
+    /// Geological Strength Index.
+    pub gsi: Option<f64>,
+    /// Hoek-Brown constant of the intact rock.
+    pub mi: Option<f64>,
+    /// Disturbance factor D.
+    pub disturbance_factor: Option<f64>,
+    /// Uniaxial compressive strength of the intact rock (MPa).
+    pub ucs: Option<f64>,
+}
+
+impl RockMassHoekBrown {
+    pub fn new(gsi: f64, mi: f64, disturbance_factor: f64, ucs: f64) -> Self {
+        Self {
+            gsi: Some(gsi),
+            mi: Some(mi),
+            disturbance_factor: Some(disturbance_factor),
+            ucs: Some(ucs),
+        }
+    }
+
+    /// Calculates the reduced value of the material constant mb = mi · exp((GSI − 100) / (28 − 14D)).
+    pub fn calc_mb(&self) -> f64 {
+        let gsi = self.gsi.unwrap();
+        let d = self.disturbance_factor.unwrap();
+        self.mi.unwrap() * ((gsi - 100.0) / (28.0 - 14.0 * d)).exp()
+    }
+
+    /// Calculates the rock mass constant s = exp((GSI − 100) / (9 − 3D)).
+    pub fn calc_s(&self) -> f64 {
+        let gsi = self.gsi.unwrap();
+        let d = self.disturbance_factor.unwrap();
+        ((gsi - 100.0) / (9.0 - 3.0 * d)).exp()
+    }
+
+    /// Calculates the rock mass constant a = 1/2 + (e^(−GSI/15) − e^(−20/3)) / 6.
+    pub fn calc_a(&self) -> f64 {
+        let gsi = self.gsi.unwrap();
+        0.5 + ((-gsi / 15.0).exp() - (-20.0_f64 / 3.0).exp()) / 6.0
+    }
+
+    /// Validates specific fields of the RockMassHoekBrown using field names.
+    ///
+    /// # Arguments
+    /// * `fields` - A slice of field names to validate.
+    ///
+    /// # Returns
+    /// Ok(()) if all fields are valid, or an error if any field is invalid.
+    pub fn validate(&self, fields: &[&str]) -> Result<(), ValidationError> {
+        for &field in fields {
+            let result = match field {
+                "gsi" => validate_field("gsi", self.gsi, Some(0.0), Some(100.0), "rock_mass"),
+                "mi" => validate_field("mi", self.mi, Some(0.0001), None, "rock_mass"),
+                "disturbance_factor" => validate_field(
+                    "disturbance_factor",
+                    self.disturbance_factor,
+                    Some(0.0),
+                    Some(1.0),
+                    "rock_mass",
+                ),
+                "ucs" => validate_field("ucs", self.ucs, Some(0.0001), None, "rock_mass"),
+                unknown => Err(ValidationError {
+                    code: "rock_mass.invalid_field".into(),
+                    message: format!("Field '{}' is not valid for Rock Mass.", unknown),
+                }),
+            };
+
+            result?; // propagate error if any field fails
+        }
+
+        Ok(())
+    }
+}
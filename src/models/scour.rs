@@ -0,0 +1,144 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::{BedCondition, PierNoseShape},
+    validation::{validate_field, ValidationError},
+};
+
+/// Represents a bridge pier (or any obstruction) founded in a river bed.
+///
+/// # Fields
+/// * `width` - Width of the pier perpendicular to its axis (m).
+/// * `length` - Length of the pier along its axis (m).
+/// * `attack_angle` - Angle between the flow direction and the pier axis (degrees).
+/// * `nose_shape` - Shape of the pier nose.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Pier {
+    /// Width of the pier (m).
+    pub width: Option<f64>,
+    /// Length of the pier (m).
+    pub length: Option<f64>,
+    /// Angle of attack of the flow (degrees).
+    pub attack_angle: Option<f64>,
+    /// Shape of the pier nose.
+    pub nose_shape: PierNoseShape,
+}
+
+impl Pier {
+    /// Validates specific fields of the Pier using field names.
+    ///
+    /// # Arguments
+    /// * `fields` - A slice of field names to validate.
+    ///
+    /// # Returns
+    /// Ok(()) if all fields are valid, or an error if any field is invalid.
+    pub fn validate(&self, fields: &[&str]) -> Result<(), ValidationError> {
+        for &field in fields {
+            let result = match field {
+                "width" => validate_field("width", self.width, Some(0.0001), None, "pier"),
+                "length" => validate_field("length", self.length, self.width, None, "pier"),
+                "attack_angle" => validate_field(
+                    "attack_angle",
+                    self.attack_angle,
+                    Some(0.0),
+                    Some(90.0),
+                    "pier",
+                ),
+                unknown => Err(ValidationError {
+                    code: "pier.invalid_field".into(),
+                    message: format!("Field '{}' is not valid for Pier.", unknown),
+                }),
+            };
+
+            result?; // propagate error if any field fails
+        }
+
+        Ok(())
+    }
+}
+
+/// Represents the design flow conditions for scour calculations.
+///
+/// The contraction fields are optional; contraction scour is neglected when they are not given.
+///
+/// # Fields
+/// * `flow_depth` - Flow depth directly upstream of the pier (m).
+/// * `velocity` - Mean flow velocity directly upstream of the pier (m/s).
+/// * `bed_condition` - Bed condition of the channel.
+/// * `contracted_discharge` - Discharge through the contracted section (m³/s).
+/// * `contracted_width` - Bottom width of the contracted section less pier widths (m).
+/// * `median_grain_size` - Median grain size D50 of the bed material (mm).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlowConditions {
+    /// Flow depth upstream of the pier (m).
+    pub flow_depth: Option<f64>,
+    /// Mean flow velocity upstream of the pier (m/s).
+    pub velocity: Option<f64>,
+    /// Bed condition of the channel.
+    pub bed_condition: BedCondition,
+    /// Discharge through the contracted section (m³/s).
+    pub contracted_discharge: Option<f64>,
+    /// Bottom width of the contracted section (m).
+    pub contracted_width: Option<f64>,
+    /// Median grain size D50 of the bed material (mm).
+    pub median_grain_size: Option<f64>,
+}
+
+impl FlowConditions {
+    /// Validates specific fields of the FlowConditions using field names.
+    ///
+    /// # Arguments
+    /// * `fields` - A slice of field names to validate.
+    ///
+    /// # Returns
+    /// Ok(()) if all fields are valid, or an error if any field is invalid.
+    pub fn validate(&self, fields: &[&str]) -> Result<(), ValidationError> {
+        for &field in fields {
+            let result = match field {
+                "flow_depth" => validate_field(
+                    "flow_depth",
+                    self.flow_depth,
+                    Some(0.0001),
+                    None,
+                    "flow_conditions",
+                ),
+                "velocity" => validate_field(
+                    "velocity",
+                    self.velocity,
+                    Some(0.0),
+                    None,
+                    "flow_conditions",
+                ),
+                "contracted_discharge" => validate_field(
+                    "contracted_discharge",
+                    self.contracted_discharge,
+                    Some(0.0),
+                    None,
+                    "flow_conditions",
+                ),
+                "contracted_width" => validate_field(
+                    "contracted_width",
+                    self.contracted_width,
+                    Some(0.0001),
+                    None,
+                    "flow_conditions",
+                ),
+                "median_grain_size" => validate_field(
+                    "median_grain_size",
+                    self.median_grain_size,
+                    Some(0.0001),
+                    None,
+                    "flow_conditions",
+                ),
+                unknown => Err(ValidationError {
+                    code: "flow_conditions.invalid_field".into(),
+                    message: format!("Field '{}' is not valid for FlowConditions.", unknown),
+                }),
+            };
+
+            result?; // propagate error if any field fails
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+use super::{masw::Masw, soil_profile::SoilProfile, spt::SPT};
+
+/// Investigation data and seismic hazard of a site.
+///
+/// # Fields
+/// * `soil_profile` - The soil profile of the site.
+/// * `spt` - SPT data of the site, if available.
+/// * `masw` - MASW data of the site, if available.
+/// * `pga` - Peak ground acceleration of the design earthquake (g).
+/// * `mw` - Moment magnitude of the design earthquake.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Site {
+    /// The soil profile of the site.
+    pub soil_profile: SoilProfile,
+    /// SPT data of the site.
+    pub spt: Option<SPT>,
+    /// MASW data of the site.
+    pub masw: Option<Masw>,
+    /// Peak ground acceleration (g).
+    pub pga: Option<f64>,
+    /// Moment magnitude.
+    pub mw: Option<f64>,
+}
+
+impl Site {
+    pub fn new(soil_profile: SoilProfile) -> Self {
+        Self {
+            soil_profile,
+            spt: None,
+            masw: None,
+            pga: None,
+            mw: None,
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+
+use crate::validation::{validate_field, ValidationError};
+
+/// Represents a circular skirted (bucket / suction caisson) foundation.
+///
+/// # Fields
+/// * `diameter` - Outer diameter of the caisson (m).
+/// * `skirt_length` - Penetration length of the skirt below the mudline (m).
+/// * `adhesion_factor` - Adhesion factor (α) along the outer skirt wall (unitless).
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SkirtedFoundation {
+    /// Outer diameter of the caisson (m).
+    pub diameter: Option<f64>,
+    /// Penetration length of the skirt below the mudline (m).
+    pub skirt_length: Option<f64>,
+    /// Adhesion factor (α) along the outer skirt wall (unitless).
+    pub adhesion_factor: Option<f64>,
+}
+
+impl SkirtedFoundation {
+    /// Creates a new `SkirtedFoundation` instance.
+    ///
+    /// # Arguments
+    /// * `diameter` - Outer diameter of the caisson (m).
+    /// * `skirt_length` - Penetration length of the skirt below the mudline (m).
+    /// * `adhesion_factor` - Adhesion factor (α) along the outer skirt wall (unitless).
+    pub fn new(diameter: f64, skirt_length: f64, adhesion_factor: f64) -> Self {
+        Self {
+            diameter: Some(diameter),
+            skirt_length: Some(skirt_length),
+            adhesion_factor: Some(adhesion_factor),
+        }
+    }
+
+    /// Returns the base area of the caisson (m²).
+    pub fn area(&self) -> f64 {
+        std::f64::consts::PI * self.diameter.unwrap().powi(2) / 4.0
+    }
+
+    /// Validates specific fields of the SkirtedFoundation using field names.
+    ///
+    /// # Arguments
+    /// * `fields` - A slice of field names to validate.
+    ///
+    /// # Returns
+    /// Ok(()) if all fields are valid, or an error if any field is invalid.
+    pub fn validate(&self, fields: &[&str]) -> Result<(), ValidationError> {
+        for &field in fields {
+            let result = match field {
+                "diameter" => validate_field(
+                    "diameter",
+                    self.diameter,
+                    Some(0.0001),
+                    None,
+                    "skirted_foundation",
+                ),
+                "skirt_length" => validate_field(
+                    "skirt_length",
+                    self.skirt_length,
+                    Some(0.0001),
+                    None,
+                    "skirted_foundation",
+                ),
+                "adhesion_factor" => validate_field(
+                    "adhesion_factor",
+                    self.adhesion_factor,
+                    Some(0.0),
+                    Some(1.0),
+                    "skirted_foundation",
+                ),
+                unknown => Err(ValidationError {
+                    code: "skirted_foundation.invalid_field".into(),
+                    message: format!("Field '{}' is not valid for SkirtedFoundation.", unknown),
+                }),
+            };
+
+            result?; // propagate error if any field fails
+        }
+
+        Ok(())
+    }
+}
@@ -1,6 +1,12 @@
 use serde::{Deserialize, Serialize};
 
-use crate::validation::{validate_field, ValidationError};
+use crate::{
+    constants::Environment,
+    enums::{AnalysisTerm, LoadingRate, MaterialType, WeatheringGrade},
+    helper::calc_thickness_weighted_average,
+    models::{gradation::GradationCurve, groundwater::GroundwaterSample, lithology::Lithology},
+    validation::{validate_field, ValidationError},
+};
 
 /// Represents a single soil layer in a geotechnical engineering model.
 ///
@@ -29,12 +35,21 @@ pub struct SoilLayer {
     pub water_content: Option<f64>,          // percentage
     pub poissons_ratio: Option<f64>,         // Poisson's ratio
     pub elastic_modulus: Option<f64>,        // t/m²
+    pub elastic_modulus_gradient: Option<f64>, // Increase of elastic modulus with depth in t/m²/m
     pub void_ratio: Option<f64>,             // Void ratio
     pub recompression_index: Option<f64>,    // Recompression index
     pub compression_index: Option<f64>,      // Compression index
+    pub secondary_compression_index: Option<f64>, // Secondary compression index Cα
     pub preconsolidation_pressure: Option<f64>, // t/m²
     pub mv: Option<f64>,                     // volume compressibility coefficient in m²/t
+    pub cv: Option<f64>,                     // coefficient of consolidation in m²/year
     pub shear_wave_velocity: Option<f64>,    // m/s
+    pub material_type: Option<MaterialType>, // Soil or Rock, soil if not set
+    pub weathering_grade: Option<WeatheringGrade>, // Weathering grade of rock layers
+    pub rock_mass_modulus: Option<f64>,      // Deformation modulus of the rock mass in t/m²
+    pub gradation: Option<GradationCurve>,   // Particle size distribution
+    pub relative_density: Option<f64>,       // Relative density of granular soil in percentage
+    pub lithology: Option<Lithology>,        // Descriptive log data of the layer
 }
 
 impl SoilLayer {
@@ -44,6 +59,78 @@ impl SoilLayer {
             ..Default::default()
         }
     }
+
+    /// Returns whether the layer behaves as rock.
+    ///
+    /// Completely weathered rock and residual soil (grades V and VI) behave as soil.
+    pub fn is_rock(&self) -> bool {
+        matches!(self.material_type, Some(MaterialType::Rock))
+            && !matches!(
+                self.weathering_grade,
+                Some(WeatheringGrade::CompletelyWeathered) | Some(WeatheringGrade::ResidualSoil)
+            )
+    }
+
+    /// Returns the deformation modulus of the layer (t/m²).
+    ///
+    /// For rock layers the rock mass modulus is used when available.
+    pub fn get_elastic_modulus(&self) -> Option<f64> {
+        if self.is_rock() && self.rock_mass_modulus.is_some() {
+            self.rock_mass_modulus
+        } else {
+            self.elastic_modulus
+        }
+    }
+
+    /// Returns the deformation modulus at a depth below the top of the layer (t/m²).
+    ///
+    /// For soil layers with an elastic modulus gradient k, the modulus increases linearly
+    /// (Gibson soil): E(z) = E0 + k · z, where E0 is the elastic modulus at the top of the layer.
+    ///
+    /// # Arguments
+    /// * `depth_below_top` - Depth below the top of the layer (m).
+    pub fn get_elastic_modulus_at(&self, depth_below_top: f64) -> Option<f64> {
+        let modulus = self.get_elastic_modulus()?;
+        if self.is_rock() && self.rock_mass_modulus.is_some() {
+            return Some(modulus);
+        }
+
+        Some(modulus + self.elastic_modulus_gradient.unwrap_or(0.0) * depth_below_top.max(0.0))
+    }
+
+    /// Returns the fine content of the layer (%).
+    ///
+    /// The gradation curve is used when `fine_content` is not given.
+    pub fn get_fine_content(&self) -> Option<f64> {
+        self.fine_content
+            .or_else(|| self.gradation.as_ref().map(|g| g.fines_content()))
+    }
+
+    /// Returns whether the layer is cohesive (fine grained).
+    ///
+    /// A layer is treated as cohesive when it has a positive plasticity index or at least 50% fines.
+    pub fn is_cohesive(&self) -> bool {
+        self.plasticity_index.is_some_and(|pi| pi > 0.0)
+            || self.get_fine_content().is_some_and(|fc| fc >= 50.0)
+    }
+
+    /// Returns whether the undrained (short term) parameters apply to the layer.
+    ///
+    /// With `AnalysisTerm::Auto`, cohesive soil layers under rapid loading are undrained, while
+    /// granular layers, rock layers and all layers under slow loading are drained.
+    ///
+    /// # Arguments
+    /// * `term` - Analysis term.
+    pub fn is_undrained(&self, term: AnalysisTerm) -> bool {
+        match term {
+            AnalysisTerm::Short => true,
+            AnalysisTerm::Long => false,
+            AnalysisTerm::Auto(rate) => {
+                matches!(rate, LoadingRate::Rapid) && !self.is_rock() && self.is_cohesive()
+            }
+        }
+    }
+
     /// Validate based on a list of required fields by name.
     ///
     /// # Arguments
@@ -152,6 +239,13 @@ impl SoilLayer {
                     None,
                     "soil_profile",
                 ),
+                "elastic_modulus_gradient" => validate_field(
+                    "elastic_modulus_gradient",
+                    self.elastic_modulus_gradient,
+                    Some(0.0),
+                    None,
+                    "soil_profile",
+                ),
                 "void_ratio" => validate_field(
                     "void_ratio",
                     self.void_ratio,
@@ -166,6 +260,13 @@ impl SoilLayer {
                     None,
                     "soil_profile",
                 ),
+                "secondary_compression_index" => validate_field(
+                    "secondary_compression_index",
+                    self.secondary_compression_index,
+                    Some(0.0),
+                    None,
+                    "soil_profile",
+                ),
                 "recompression_index" => validate_field(
                     "recompression_index",
                     self.recompression_index,
@@ -181,6 +282,7 @@ impl SoilLayer {
                     "soil_profile",
                 ),
                 "mv" => validate_field("mv", self.mv, Some(0.0), None, "soil_profile"),
+                "cv" => validate_field("cv", self.cv, Some(0.00001), None, "soil_profile"),
                 "shear_wave_velocity" => validate_field(
                     "shear_wave_velocity",
                     self.shear_wave_velocity,
@@ -188,6 +290,27 @@ impl SoilLayer {
                     None,
                     "soil_profile",
                 ),
+                "relative_density" => validate_field(
+                    "relative_density",
+                    self.relative_density,
+                    Some(0.0),
+                    Some(100.0),
+                    "soil_profile",
+                ),
+                "rock_mass_modulus" => validate_field(
+                    "rock_mass_modulus",
+                    self.rock_mass_modulus,
+                    Some(0.0001),
+                    None,
+                    "soil_profile",
+                ),
+                "gradation" => match &self.gradation {
+                    Some(gradation) => gradation.validate(),
+                    None => Err(ValidationError {
+                        code: "soil_profile.gradation.missing".to_string(),
+                        message: "gradation must be provided.".to_string(),
+                    }),
+                },
                 other => Err(ValidationError {
                     code: "soil_profile.invalid_field".to_string(),
                     message: format!("Field '{}' is not valid for SoilLayer.", other),
@@ -203,12 +326,27 @@ impl SoilLayer {
 
 /// Represents a soil profile consisting of multiple soil layers.
 /// This structure stores soil layers and calculates normal and effective stresses.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct SoilProfile {
     /// A list of soil layers in the profile.
     pub layers: Vec<SoilLayer>,
     /// Depth of the groundwater table (meters).
     pub ground_water_level: Option<f64>, // meters
+    /// Groundwater samples tested for chemical aggressivity.
+    #[serde(default)]
+    pub groundwater_samples: Vec<GroundwaterSample>,
+    /// Total vertical stress at the profile surface from the soil above it (t/m²), set when the
+    /// profile is sliced from a deeper one.
+    #[serde(default)]
+    pub overburden_stress: Option<f64>,
+    /// Pore water pressure at the profile surface (t/m²), set when the profile is sliced from a
+    /// deeper one.
+    #[serde(default)]
+    pub overburden_pore_pressure: Option<f64>,
+    /// Physical constants of the site (e.g. sea water as the groundwater), fresh water if not
+    /// set.
+    #[serde(default)]
+    pub environment: Option<Environment>,
 }
 
 impl SoilProfile {
@@ -228,6 +366,10 @@ impl SoilProfile {
         let mut profile = Self {
             layers,
             ground_water_level: Some(ground_water_level),
+            groundwater_samples: vec![],
+            overburden_stress: None,
+            overburden_pore_pressure: None,
+            environment: None,
         };
         profile.calc_layer_depths();
         profile
@@ -258,10 +400,10 @@ impl SoilProfile {
     /// * The index of the layer containing the specified depth.
     pub fn get_layer_index(&self, depth: f64) -> usize {
         for (i, layer) in self.layers.iter().enumerate() {
-            if let Some(layer_depth) = layer.depth {
-                if layer_depth >= depth {
-                    return i;
-                }
+            if let Some(layer_depth) = layer.depth
+                && layer_depth >= depth
+            {
+                return i;
             }
         }
         self.layers.len() - 1
@@ -279,8 +421,40 @@ impl SoilProfile {
         &self.layers[index]
     }
 
+    /// Calculates the thickness-weighted average deformation modulus between two depths (t/m²).
+    ///
+    /// Layers without a modulus are skipped. For layers with a modulus gradient, the modulus at
+    /// the middle of the part of the layer inside the window is used.
+    ///
+    /// # Arguments
+    /// * `top` - Top depth of the window (m).
+    /// * `bottom` - Bottom depth of the window (m).
+    ///
+    /// # Returns
+    /// * The average modulus, or `None` if no layer with a modulus lies in the window.
+    pub fn calc_average_elastic_modulus(&self, top: f64, bottom: f64) -> Option<f64> {
+        let mut intervals = Vec::new();
+        let mut layer_top = 0.0;
+        for layer in &self.layers {
+            let layer_bottom = layer_top + layer.thickness.unwrap();
+            let segment_top = layer_top.max(top);
+            let segment_bottom = layer_bottom.min(bottom);
+            if segment_bottom > segment_top
+                && let Some(modulus) =
+                    layer.get_elastic_modulus_at(0.5 * (segment_top + segment_bottom) - layer_top)
+            {
+                intervals.push((segment_top, segment_bottom, modulus));
+            }
+            layer_top = layer_bottom;
+        }
+
+        calc_thickness_weighted_average(&intervals, top, bottom)
+    }
+
     /// Calculates the total (normal) stress at a given depth.
     ///
+    /// The overburden stress of a sliced profile is added to the weight of its layers.
+    ///
     /// # Arguments
     /// * `depth` - The depth at which to calculate total stress.
     ///
@@ -289,7 +463,7 @@ impl SoilProfile {
     pub fn calc_normal_stress(&self, depth: f64) -> f64 {
         let layer_index = self.get_layer_index(depth);
 
-        let mut total_stress = 0.0;
+        let mut total_stress = self.overburden_stress.unwrap_or(0.0);
         let mut previous_depth = 0.0;
         let gwt = self.ground_water_level.unwrap();
 
@@ -324,7 +498,13 @@ impl SoilProfile {
         total_stress
     }
 
-    /// Calculates the effective stress at a given depth.
+    /// Returns the physical constants of the site, fresh water if not set.
+    pub fn get_environment(&self) -> Environment {
+        self.environment.unwrap_or_default()
+    }
+
+    /// Calculates the effective stress at a given depth with the groundwater of the profile
+    /// environment.
     ///
     /// # Arguments
     /// * `depth` - The depth at which to calculate effective stress.
@@ -332,16 +512,119 @@ impl SoilProfile {
     /// # Returns
     /// * The effective stress (t/m²) at the specified depth.
     pub fn calc_effective_stress(&self, depth: f64) -> f64 {
+        self.calc_effective_stress_with_environment(depth, &self.get_environment())
+    }
+
+    /// Calculates the effective stress at a given depth with the groundwater of the given
+    /// environment (e.g. sea water).
+    ///
+    /// # Arguments
+    /// * `depth` - The depth at which to calculate effective stress.
+    /// * `environment` - Physical constants with the unit weight of the groundwater.
+    ///
+    /// # Returns
+    /// * The effective stress (t/m²) at the specified depth.
+    pub fn calc_effective_stress_with_environment(
+        &self,
+        depth: f64,
+        environment: &Environment,
+    ) -> f64 {
         let normal_stress = self.calc_normal_stress(depth);
+        let overburden_pore_pressure = self.overburden_pore_pressure.unwrap_or(0.0);
 
         if self.ground_water_level.unwrap() >= depth {
-            normal_stress // Effective stress equals total stress above water table
+            // No hydrostatic pressure above the water table, only the pore pressure of the overburden
+            normal_stress - overburden_pore_pressure
         } else {
-            let pore_pressure = (depth - self.ground_water_level.unwrap()) * 0.981; // t/m³ for water
+            let pore_pressure = environment
+                .hydrostatic_pressure(depth - self.ground_water_level.unwrap())
+                + overburden_pore_pressure;
             normal_stress - pore_pressure
         }
     }
 
+    /// Returns the total depth of the soil profile.
+    pub fn get_total_depth(&self) -> f64 {
+        self.layers
+            .iter()
+            .map(|layer| layer.thickness.unwrap())
+            .sum()
+    }
+
+    /// Extracts the sub-profile between two depths.
+    ///
+    /// Layers outside the range are removed and the layers cut by the boundaries are thinned.
+    /// Depths in the new profile are measured from `top`, and the groundwater level is shifted by
+    /// the same amount (a groundwater level above `top` is placed at the new surface). The total
+    /// stress and the pore pressure at `top` are kept as the overburden of the new profile, so the
+    /// stresses at a physical depth do not change. A `bottom` deeper than the profile is limited
+    /// to the profile depth. Groundwater samples within the range are kept with their depths
+    /// shifted.
+    ///
+    /// # Arguments
+    /// * `top` - Top depth of the sub-profile (m).
+    /// * `bottom` - Bottom depth of the sub-profile (m).
+    ///
+    /// # Returns
+    /// * `SoilProfile`: The sub-profile with re-baselined depths.
+    pub fn slice(&self, top: f64, bottom: f64) -> Result<SoilProfile, ValidationError> {
+        self.validate(&["thickness", "dry_unit_weight", "saturated_unit_weight"])?;
+        validate_field("top", Some(top), Some(0.0), None, "soil_profile.slice")?;
+
+        let bottom = bottom.min(self.get_total_depth());
+        if bottom <= top + 1e-9 {
+            return Err(ValidationError {
+                code: "soil_profile.slice.empty".to_string(),
+                message: "Slice bottom must be deeper than its top and the profile surface."
+                    .to_string(),
+            });
+        }
+
+        let mut layers = Vec::new();
+        let mut layer_top = 0.0;
+        for layer in self.layers.iter() {
+            let layer_bottom = layer_top + layer.thickness.unwrap();
+            let thickness = layer_bottom.min(bottom) - layer_top.max(top);
+            if thickness > 1e-9 {
+                let mut remaining = layer.clone();
+                remaining.thickness = Some(thickness);
+                layers.push(remaining);
+            }
+            layer_top = layer_bottom;
+        }
+
+        let ground_water_level = self.ground_water_level.unwrap_or(0.0);
+
+        let mut profile = SoilProfile::new(layers, (ground_water_level - top).max(0.0));
+        profile.environment = self.environment;
+        let overburden_stress = self.calc_normal_stress(top);
+        profile.overburden_stress = Some(overburden_stress);
+        profile.overburden_pore_pressure =
+            Some(overburden_stress - self.calc_effective_stress(top));
+        profile.groundwater_samples = self
+            .groundwater_samples
+            .iter()
+            .filter(|sample| sample.depth.is_some_and(|d| d >= top && d <= bottom))
+            .map(|sample| GroundwaterSample {
+                depth: sample.depth.map(|d| d - top),
+                ..sample.clone()
+            })
+            .collect();
+
+        Ok(profile)
+    }
+
+    /// Extracts the sub-profile below a given depth (e.g. below an excavation or pile tips).
+    ///
+    /// # Arguments
+    /// * `depth` - Top depth of the sub-profile (m).
+    ///
+    /// # Returns
+    /// * `SoilProfile`: The sub-profile with depths measured from `depth`.
+    pub fn slice_below(&self, depth: f64) -> Result<SoilProfile, ValidationError> {
+        self.slice(depth, f64::INFINITY)
+    }
+
     /// Validates the soil profile and its layers.
     ///
     /// # Arguments
@@ -368,6 +651,24 @@ impl SoilProfile {
             None,
             "soil_profile",
         )?;
+        if let Some(environment) = &self.environment {
+            environment.validate()?;
+        }
+
+        Ok(())
+    }
+
+    /// Validates the given fields for soil layers only, skipping rock layers.
+    ///
+    /// # Arguments
+    /// * `fields` - A slice of field names to validate.
+    ///
+    /// # Returns
+    /// * `Ok(())` if all soil layers are valid.
+    pub fn validate_soil_layers(&self, fields: &[&str]) -> Result<(), ValidationError> {
+        for layer in self.layers.iter().filter(|layer| !layer.is_rock()) {
+            layer.validate_fields(fields)?;
+        }
 
         Ok(())
     }
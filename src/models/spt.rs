@@ -1,3 +1,4 @@
+use crate::constants::GRAVITY;
 use crate::enums::SelectionMethod;
 use crate::validation::{validate_field, ValidationError};
 use ordered_float::OrderedFloat;
@@ -5,7 +6,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::fmt;
 
-use super::soil_profile::SoilProfile;
+use super::{lithology::Lithology, soil_profile::SoilProfile};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum NValue {
@@ -94,6 +95,74 @@ impl Ord for NValue {
     }
 }
 // -------------------------------------------------------------------------------------------
+/// A user-defined correction step applied to each blow during `apply_corrections`.
+///
+/// The step returns a factor multiplied into N1,60 together with the sampler and borehole
+/// corrections, before the fines content correction. At the time it is called, the blow has its
+/// N60, CN, CR, α and β values set. Closures of the form `Fn(&SPTBlow, &SoilProfile) -> f64`
+/// implement this trait.
+pub trait BlowCorrection {
+    /// Returns the correction factor of the blow.
+    ///
+    /// # Arguments
+    /// * `blow` - The blow being corrected
+    /// * `soil_profile` - Soil profile
+    fn factor(&self, blow: &SPTBlow, soil_profile: &SoilProfile) -> f64;
+}
+
+impl<F> BlowCorrection for F
+where
+    F: Fn(&SPTBlow, &SoilProfile) -> f64,
+{
+    fn factor(&self, blow: &SPTBlow, soil_profile: &SoilProfile) -> f64 {
+        self(blow, soil_profile)
+    }
+}
+
+/// An ordered list of user-defined correction steps for SPT blows.
+#[derive(Default)]
+pub struct CorrectionPipeline {
+    steps: Vec<Box<dyn BlowCorrection>>,
+}
+
+impl CorrectionPipeline {
+    /// Create an empty pipeline
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a correction step, applied after the previously registered ones
+    ///
+    /// # Arguments
+    /// * `step` - Correction step
+    pub fn register(&mut self, step: impl BlowCorrection + 'static) -> &mut Self {
+        self.steps.push(Box::new(step));
+        self
+    }
+
+    /// Number of registered steps
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Whether no step is registered
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Calculate the product of the factors of all steps for a blow
+    ///
+    /// # Arguments
+    /// * `blow` - The blow being corrected
+    /// * `soil_profile` - Soil profile
+    pub fn calc_factor(&self, blow: &SPTBlow, soil_profile: &SoilProfile) -> f64 {
+        self.steps
+            .iter()
+            .map(|step| step.factor(blow, soil_profile))
+            .product()
+    }
+}
+// -------------------------------------------------------------------------------------------
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SPTBlow {
     pub thickness: Option<f64>,
@@ -107,6 +176,9 @@ pub struct SPTBlow {
     pub cr: Option<f64>,
     pub alpha: Option<f64>,
     pub beta: Option<f64>,
+    /// Product of the user-defined correction factors applied to N1,60.
+    pub custom_factor: Option<f64>,
+    pub lithology: Option<Lithology>,
 }
 
 impl SPTBlow {
@@ -173,7 +245,7 @@ impl SPTBlow {
     /// * `sigma_effective` - Effective overburden pressure in ton
     pub fn set_cn(&mut self, sigma_effective: f64) {
         self.cn = Some(f64::min(
-            f64::sqrt(1. / (9.81 * sigma_effective)) * 9.78,
+            f64::sqrt(1. / (GRAVITY * sigma_effective)) * 9.78,
             1.7,
         ))
     }
@@ -213,6 +285,25 @@ impl SPTBlow {
     /// * `cb` - borehole diameter correction factor
     /// * `ce` - energy correction factor
     pub fn apply_corrections(&mut self, soil_profile: &SoilProfile, cs: f64, cb: f64, ce: f64) {
+        self.apply_corrections_with(soil_profile, cs, cb, ce, &CorrectionPipeline::new());
+    }
+
+    /// Apply corrections, including the user-defined steps of a pipeline
+    ///
+    /// # Arguments
+    /// * `soil_profile` - Soil profile
+    /// * `cs` - sampler correction factor
+    /// * `cb` - borehole diameter correction factor
+    /// * `ce` - energy correction factor
+    /// * `pipeline` - user-defined correction steps
+    pub fn apply_corrections_with(
+        &mut self,
+        soil_profile: &SoilProfile,
+        cs: f64,
+        cb: f64,
+        ce: f64,
+        pipeline: &CorrectionPipeline,
+    ) {
         self.apply_energy_correction(ce);
         self.set_cn(soil_profile.calc_effective_stress(self.depth.unwrap()));
         self.set_cr();
@@ -222,11 +313,13 @@ impl SPTBlow {
                 .fine_content
                 .unwrap_or(0.0),
         );
+        let custom_factor = pipeline.calc_factor(self, soil_profile);
+        self.custom_factor = Some(custom_factor);
 
         if let (Some(n60), Some(cn), Some(cr), Some(alpha), Some(beta)) =
             (self.n60, self.cn, self.cr, self.alpha, self.beta)
         {
-            let n1_60 = n60.mul_by_f64(cn * cr * cs * cb);
+            let n1_60 = n60.mul_by_f64(cn * cr * cs * cb * custom_factor);
             self.n1_60 = Some(n1_60);
             self.n1_60f = Some(n1_60.mul_by_f64(beta).add_f64(alpha));
         }
@@ -259,6 +352,24 @@ impl SPTExp {
         }
     }
 
+    /// Apply depth-specific energy correction from hammer calibration records
+    ///
+    /// # Arguments
+    /// * `calibration` - Energy calibration records
+    /// * `default_factor` - Energy correction factor used if the experiment has no measurement
+    pub fn apply_energy_calibration(
+        &mut self,
+        calibration: &EnergyCalibration,
+        default_factor: f64,
+    ) {
+        for blow in &mut self.blows {
+            let ce = calibration
+                .get_correction_factor(&self.name, blow.depth.unwrap())
+                .unwrap_or(default_factor);
+            blow.apply_energy_correction(ce);
+        }
+    }
+
     /// Add a new blow to the experiment
     ///
     /// # Arguments
@@ -285,9 +396,28 @@ impl SPTExp {
     /// * `cb` - borehole diameter correction factor
     /// * `ce` - energy correction factor
     pub fn apply_corrections(&mut self, soil_profile: &SoilProfile, cs: f64, cb: f64, ce: f64) {
+        self.apply_corrections_with(soil_profile, cs, cb, ce, &CorrectionPipeline::new());
+    }
+
+    /// Apply corrections to all blows, including the user-defined steps of a pipeline
+    ///
+    /// # Arguments
+    /// * `soil_profile` - Soil profile
+    /// * `cs` - sampler correction factor
+    /// * `cb` - borehole diameter correction factor
+    /// * `ce` - energy correction factor
+    /// * `pipeline` - user-defined correction steps
+    pub fn apply_corrections_with(
+        &mut self,
+        soil_profile: &SoilProfile,
+        cs: f64,
+        cb: f64,
+        ce: f64,
+        pipeline: &CorrectionPipeline,
+    ) {
         self.blows
             .iter_mut()
-            .for_each(|blow| blow.apply_corrections(soil_profile, cs, cb, ce));
+            .for_each(|blow| blow.apply_corrections_with(soil_profile, cs, cb, ce, pipeline));
     }
     /// Validates specific fields of the SPTExp using field names.
     ///
@@ -312,6 +442,144 @@ impl SPTExp {
 
 // -------------------------------------------------------------------------------------------
 
+/// N value of a source experiment at the depth of an idealized blow.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SourceValue {
+    pub exp_name: String,
+    pub n: NValue,
+}
+
+/// Records how an idealized blow was selected from the source experiments.
+///
+/// # Fields
+/// * `depth` - Depth of the idealized blow
+/// * `selected` - N value of the idealized blow
+/// * `sources` - N values of all experiments at the depth
+/// * `contributors` - Names of the experiments the selected value comes from
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlowProvenance {
+    pub depth: f64,
+    pub selected: NValue,
+    pub sources: Vec<SourceValue>,
+    pub contributors: Vec<String>,
+}
+
+/// Measured energy transfer ratio of the SPT hammer at a depth of a borehole.
+///
+/// # Fields
+/// * `exp_name` - Name of the experiment (borehole) the measurement belongs to
+/// * `depth` - Depth of the measurement (m)
+/// * `etr` - Measured energy transfer ratio (%)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EnergyMeasurement {
+    pub exp_name: String,
+    pub depth: f64,
+    pub etr: f64,
+}
+
+/// Hammer energy calibration records of an SPT campaign.
+///
+/// The energy correction factor at a blow is CE = ETR / 60, with the ETR interpolated linearly
+/// between the measured depths of the same experiment and held constant above the first and
+/// below the last measurement.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EnergyCalibration {
+    pub measurements: Vec<EnergyMeasurement>,
+}
+
+impl EnergyCalibration {
+    /// Create a new EnergyCalibration
+    ///
+    /// # Arguments
+    /// * `measurements` - Measured energy transfer ratios
+    pub fn new(measurements: Vec<EnergyMeasurement>) -> Self {
+        Self { measurements }
+    }
+
+    /// Add a new measurement to the calibration
+    ///
+    /// # Arguments
+    /// * `exp_name` - Name of the experiment
+    /// * `depth` - Depth of the measurement (m)
+    /// * `etr` - Measured energy transfer ratio (%)
+    pub fn add_measurement(&mut self, exp_name: &str, depth: f64, etr: f64) {
+        self.measurements.push(EnergyMeasurement {
+            exp_name: exp_name.to_string(),
+            depth,
+            etr,
+        });
+    }
+
+    /// Get the energy transfer ratio of an experiment at a depth
+    ///
+    /// # Arguments
+    /// * `exp_name` - Name of the experiment
+    /// * `depth` - Depth of the blow (m)
+    ///
+    /// # Returns
+    /// * `Option<f64>` - Energy transfer ratio (%), or None if the experiment has no measurement
+    pub fn get_energy_ratio(&self, exp_name: &str, depth: f64) -> Option<f64> {
+        let mut points: Vec<(f64, f64)> = self
+            .measurements
+            .iter()
+            .filter(|m| m.exp_name == exp_name)
+            .map(|m| (m.depth, m.etr))
+            .collect();
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let first = *points.first()?;
+        let last = *points.last()?;
+        if depth <= first.0 {
+            return Some(first.1);
+        }
+        if depth >= last.0 {
+            return Some(last.1);
+        }
+
+        points.windows(2).find_map(|pair| {
+            let ((d1, e1), (d2, e2)) = (pair[0], pair[1]);
+            (depth >= d1 && depth <= d2).then(|| e1 + (e2 - e1) * (depth - d1) / (d2 - d1))
+        })
+    }
+
+    /// Get the energy correction factor CE = ETR / 60 of an experiment at a depth
+    ///
+    /// # Arguments
+    /// * `exp_name` - Name of the experiment
+    /// * `depth` - Depth of the blow (m)
+    ///
+    /// # Returns
+    /// * `Option<f64>` - Energy correction factor, or None if the experiment has no measurement
+    pub fn get_correction_factor(&self, exp_name: &str, depth: f64) -> Option<f64> {
+        self.get_energy_ratio(exp_name, depth).map(|etr| etr / 60.0)
+    }
+
+    /// Validates the measurements of the calibration.
+    ///
+    /// # Returns
+    /// Ok(()) if all measurements are valid, or an error if any measurement is invalid.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        for measurement in &self.measurements {
+            validate_field(
+                "depth",
+                Some(measurement.depth),
+                Some(0.0),
+                None,
+                "energy_calibration",
+            )?;
+            validate_field(
+                "etr",
+                Some(measurement.etr),
+                Some(0.001),
+                Some(100.0),
+                "energy_calibration",
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SPT {
     pub exps: Vec<SPTExp>,
@@ -353,6 +621,42 @@ impl SPT {
         }
     }
 
+    /// Returns a copy of the SPT with its N values converted to N60 using depth-specific energy
+    /// correction factors.
+    ///
+    /// Each experiment is corrected with its own calibration records, falling back to the
+    /// global `energy_correction_factor` where it has none. The energy correction factor of the
+    /// copy is set to 1, so the analyses that apply the global factor use the calibrated values.
+    ///
+    /// # Arguments
+    /// * `calibration` - Energy calibration records
+    pub fn with_energy_calibration(
+        &self,
+        calibration: &EnergyCalibration,
+    ) -> Result<SPT, ValidationError> {
+        calibration.validate()?;
+        validate_field(
+            "energy_correction_factor",
+            self.energy_correction_factor,
+            Some(0.001),
+            None,
+            "spt",
+        )?;
+
+        let mut calibrated = self.clone();
+        for exp in &mut calibrated.exps {
+            exp.apply_energy_calibration(calibration, self.energy_correction_factor.unwrap());
+            for blow in &mut exp.blows {
+                blow.n = blow.n60;
+                blow.n60 = None;
+                blow.n90 = None;
+            }
+        }
+        calibrated.energy_correction_factor = Some(1.0);
+
+        Ok(calibrated)
+    }
+
     /// Add a new experiment to the SPT
     ///
     /// # Arguments
@@ -369,8 +673,20 @@ impl SPT {
     /// # Returns
     /// * `SPTExp` - Idealized experiment
     pub fn get_idealized_exp(&self, name: String) -> SPTExp {
+        self.get_idealized_exp_with_provenance(name).0
+    }
+
+    /// Get the idealized experiment together with the source values of each idealized blow
+    ///
+    /// # Arguments
+    /// * `name` - Name of the idealized experiment
+    ///
+    /// # Returns
+    /// * `SPTExp` - Idealized experiment
+    /// * `Vec<BlowProvenance>` - Provenance of each idealized blow, in the order of the blows
+    pub fn get_idealized_exp_with_provenance(&self, name: String) -> (SPTExp, Vec<BlowProvenance>) {
         let mode = self.idealization_method;
-        let mut depth_map: BTreeMap<OrderedFloat<f64>, Vec<NValue>> = BTreeMap::new();
+        let mut depth_map: BTreeMap<OrderedFloat<f64>, Vec<SourceValue>> = BTreeMap::new();
 
         // Collect all unique depths and corresponding `n` values
         for exp in &self.exps {
@@ -378,37 +694,54 @@ impl SPT {
                 depth_map
                     .entry(OrderedFloat(blow.depth.unwrap()))
                     .or_default()
-                    .push(blow.n.unwrap());
+                    .push(SourceValue {
+                        exp_name: exp.name.clone(),
+                        n: blow.n.unwrap(),
+                    });
             }
         }
 
         // Create a new SPTExp with selected values
         let mut idealized_blows = Vec::new();
+        let mut provenance = Vec::new();
 
-        for (&depth, n_values) in &depth_map {
+        for (&depth, sources) in &depth_map {
+            let n_values = sources.iter().map(|source| source.n);
             let selected_n = match mode {
-                SelectionMethod::Min => *n_values.iter().min().unwrap(), // Refusal is best
-                SelectionMethod::Max => *n_values.iter().max().unwrap(), // Refusal is best
+                SelectionMethod::Min => n_values.min().unwrap(), // Refusal is best
+                SelectionMethod::Max => n_values.max().unwrap(), // Refusal is best
                 SelectionMethod::Avg => {
                     let sum: f64 = n_values
-                        .iter()
-                        .filter_map(|&n| n.to_option().map(|v| v as f64))
+                        .filter_map(|n| n.to_option().map(|v| v as f64))
                         .sum();
-                    let count = n_values.len();
+                    let count = sources.len();
 
                     NValue::from_i32((sum / count as f64).round() as i32)
                 }
             };
 
+            // Min and Max take the value of the matching experiments, Avg uses all of them
+            let contributors = sources
+                .iter()
+                .filter(|source| matches!(mode, SelectionMethod::Avg) || source.n == selected_n)
+                .map(|source| source.exp_name.clone())
+                .collect();
+
             // Add to new SPTExp
             idealized_blows.push(SPTBlow {
                 depth: Some(depth.into_inner()),
                 n: Some(selected_n),
                 ..Default::default()
             });
+            provenance.push(BlowProvenance {
+                depth: depth.into_inner(),
+                selected: selected_n,
+                sources: sources.clone(),
+                contributors,
+            });
         }
 
-        SPTExp::new(idealized_blows, name)
+        (SPTExp::new(idealized_blows, name), provenance)
     }
     /// Validates specific fields of the SPT using field names.
     ///
@@ -0,0 +1,233 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use ordered_float::OrderedFloat;
+
+use crate::{
+    enums::SelectionMethod,
+    validation::{validate_field, ValidationError},
+};
+
+/// Represents an individual torvane (hand vane) reading.
+///
+/// Torvanes are usually graduated in kg/cm², which is 10 t/m².
+///
+/// # Fields
+/// * `depth` - Depth of the reading in meters.
+/// * `su` - Undrained shear strength in t/m².
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TorvaneSample {
+    pub depth: Option<f64>,
+    pub su: Option<f64>,
+}
+
+impl TorvaneSample {
+    pub fn new(depth: f64, su: f64) -> Self {
+        Self {
+            depth: Some(depth),
+            su: Some(su),
+        }
+    }
+
+    /// Returns the undrained shear strength of the reading.
+    ///
+    /// # Returns
+    /// * `f64`: Undrained shear strength in t/m².
+    pub fn calc_undrained_strength(&self) -> f64 {
+        self.su.unwrap()
+    }
+
+    /// Validates specific fields of the TorvaneSample using field names.
+    ///
+    /// # Arguments
+    /// * `fields` - A slice of field names to validate.
+    ///
+    /// # Returns
+    /// Ok(()) if all fields are valid, or an error if any field is invalid.
+    pub fn validate(&self, fields: &[&str]) -> Result<(), ValidationError> {
+        for &field in fields {
+            let result = match field {
+                "depth" => validate_field("depth", self.depth, Some(0.0), None, "torvane"),
+                "su" => validate_field("su", self.su, Some(0.0), None, "torvane"),
+                unknown => Err(ValidationError {
+                    code: "torvane.invalid_field".into(),
+                    message: format!("Field '{}' is not valid for Torvane Test.", unknown),
+                }),
+            };
+
+            result?; // propagate error if any field fails
+        }
+
+        Ok(())
+    }
+}
+
+/// Represents a single borehole or test pit containing multiple torvane readings.
+///
+/// # Fields
+/// * `borehole_id` - Identifier for the borehole.
+/// * `samples` - Collection of readings of the borehole, sorted by depth.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TorvaneExp {
+    pub borehole_id: String,
+    pub samples: Vec<TorvaneSample>,
+}
+
+impl TorvaneExp {
+    pub fn new(borehole_id: String, samples: Vec<TorvaneSample>) -> Self {
+        Self {
+            borehole_id,
+            samples,
+        }
+    }
+
+    pub fn add_sample(&mut self, sample: TorvaneSample) {
+        self.samples.push(sample);
+    }
+
+    /// Retrieves the reading at the specified depth.
+    ///
+    /// This function finds the first reading whose depth is greater than or equal to the given
+    /// `depth`. If no such reading is found, it returns the last reading in the list.
+    ///
+    /// # Arguments
+    ///
+    /// * `depth` - The depth at which to search for a reading.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the matching `TorvaneSample`.
+    pub fn get_sample_at_depth(&self, depth: f64) -> &TorvaneSample {
+        self.samples
+            .iter()
+            .find(|exp| exp.depth.unwrap() >= depth)
+            .unwrap_or_else(|| self.samples.last().unwrap())
+    }
+
+    /// Returns the (depth, su) pairs of the readings.
+    pub fn get_undrained_strengths(&self) -> Vec<(f64, f64)> {
+        self.samples
+            .iter()
+            .map(|sample| (sample.depth.unwrap(), sample.calc_undrained_strength()))
+            .collect()
+    }
+
+    /// Validates specific fields of the TorvaneExp using field names.
+    ///
+    /// # Arguments
+    /// * `fields` - A slice of field names to validate.
+    ///
+    /// # Returns
+    /// Ok(()) if all fields are valid, or an error if any field is invalid.
+    pub fn validate(&self, fields: &[&str]) -> Result<(), ValidationError> {
+        if self.samples.is_empty() {
+            return Err(ValidationError {
+                code: "torvane.empty_samples".into(),
+                message: "No samples provided for Torvane Experiment.".into(),
+            });
+        }
+        for sample in &self.samples {
+            sample.validate(fields)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Represents the torvane readings of all boreholes of a site.
+///
+/// # Fields
+/// * `exps` - Collection of borehole readings included in the overall test.
+/// * `idealization_method` - Method used for idealizing the test results.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TorvaneTest {
+    pub exps: Vec<TorvaneExp>,
+    pub idealization_method: SelectionMethod,
+}
+
+impl TorvaneTest {
+    pub fn new(exps: Vec<TorvaneExp>, idealization_method: SelectionMethod) -> Self {
+        Self {
+            exps,
+            idealization_method,
+        }
+    }
+
+    pub fn add_borehole(&mut self, exp: TorvaneExp) {
+        self.exps.push(exp);
+    }
+
+    /// Get the idealized experiment
+    ///
+    /// # Arguments
+    /// * `name` - Name of the idealized experiment
+    ///
+    /// # Returns
+    /// * `TorvaneExp` - Idealized experiment
+    pub fn get_idealized_exp(&self, name: String) -> TorvaneExp {
+        if self.exps.is_empty() {
+            return TorvaneExp::new(name, vec![]);
+        }
+
+        let mode = self.idealization_method;
+
+        let mut depth_map: BTreeMap<OrderedFloat<f64>, Vec<OrderedFloat<f64>>> = BTreeMap::new();
+
+        // Collect all unique depths and corresponding su values
+        for exp in &self.exps {
+            for sample in &exp.samples {
+                depth_map
+                    .entry(OrderedFloat(sample.depth.unwrap()))
+                    .or_default()
+                    .push(OrderedFloat(sample.su.unwrap()));
+            }
+        }
+
+        // Create a new TorvaneExp with selected values
+        let mut idealized_samples = Vec::new();
+
+        for (&depth, values) in &depth_map {
+            let selected_su = match mode {
+                SelectionMethod::Min => values.iter().min().unwrap().into_inner(),
+                SelectionMethod::Max => values.iter().max().unwrap().into_inner(),
+                SelectionMethod::Avg => {
+                    let sum: f64 = values.iter().map(|su| su.into_inner()).sum();
+                    sum / values.len() as f64
+                }
+            };
+
+            idealized_samples.push(TorvaneSample::new(depth.into_inner(), selected_su));
+        }
+
+        TorvaneExp::new(name, idealized_samples)
+    }
+
+    /// Returns the (depth, su) pairs of the readings of all boreholes.
+    pub fn get_undrained_strengths(&self) -> Vec<(f64, f64)> {
+        self.exps
+            .iter()
+            .flat_map(|exp| exp.get_undrained_strengths())
+            .collect()
+    }
+
+    /// Validates specific fields of the TorvaneTest using field names.
+    ///
+    /// # Arguments
+    /// * `fields` - A slice of field names to validate.
+    ///
+    /// # Returns
+    /// Ok(()) if all fields are valid, or an error if any field is invalid.
+    pub fn validate(&self, fields: &[&str]) -> Result<(), ValidationError> {
+        if self.exps.is_empty() {
+            return Err(ValidationError {
+                code: "torvane.empty_exps".into(),
+                message: "No experiments provided for Torvane Test.".into(),
+            });
+        }
+        for exp in &self.exps {
+            exp.validate(fields)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::{IsolationType, WaveType},
+    validation::{validate_field, ValidationError},
+};
+
+/// Represents a construction vibration source (pile driving, vibratory compaction, blasting etc.).
+///
+/// # Fields
+/// * `peak_particle_velocity` - Peak particle velocity measured or estimated at the reference distance (mm/s).
+/// * `reference_distance` - Distance from the source at which the reference velocity applies (m).
+/// * `frequency` - Dominant frequency of the vibration (Hz).
+/// * `wave_type` - Wave type governing the geometric attenuation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VibrationSource {
+    /// Peak particle velocity at the reference distance (mm/s).
+    pub peak_particle_velocity: Option<f64>,
+    /// Reference distance from the source (m).
+    pub reference_distance: Option<f64>,
+    /// Dominant frequency (Hz).
+    pub frequency: Option<f64>,
+    /// Wave type governing the geometric attenuation.
+    pub wave_type: WaveType,
+}
+
+impl VibrationSource {
+    /// Validates specific fields of the VibrationSource using field names.
+    ///
+    /// # Arguments
+    /// * `fields` - A slice of field names to validate.
+    ///
+    /// # Returns
+    /// Ok(()) if all fields are valid, or an error if any field is invalid.
+    pub fn validate(&self, fields: &[&str]) -> Result<(), ValidationError> {
+        for &field in fields {
+            let result = match field {
+                "peak_particle_velocity" => validate_field(
+                    "peak_particle_velocity",
+                    self.peak_particle_velocity,
+                    Some(0.0),
+                    None,
+                    "vibration_source",
+                ),
+                "reference_distance" => validate_field(
+                    "reference_distance",
+                    self.reference_distance,
+                    Some(0.0001),
+                    None,
+                    "vibration_source",
+                ),
+                "frequency" => validate_field(
+                    "frequency",
+                    self.frequency,
+                    Some(0.0001),
+                    None,
+                    "vibration_source",
+                ),
+                unknown => Err(ValidationError {
+                    code: "vibration_source.invalid_field".into(),
+                    message: format!("Field '{}' is not valid for VibrationSource.", unknown),
+                }),
+            };
+
+            result?; // propagate error if any field fails
+        }
+
+        Ok(())
+    }
+}
+
+/// Represents an open screening trench used to reduce ground-borne vibrations.
+///
+/// # Fields
+/// * `depth` - Depth of the trench (m).
+/// * `isolation_type` - Position of the trench relative to the source.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScreeningTrench {
+    /// Depth of the trench (m).
+    pub depth: Option<f64>,
+    /// Position of the trench relative to the source.
+    pub isolation_type: IsolationType,
+}
+
+impl ScreeningTrench {
+    /// Validates specific fields of the ScreeningTrench using field names.
+    ///
+    /// # Arguments
+    /// * `fields` - A slice of field names to validate.
+    ///
+    /// # Returns
+    /// Ok(()) if all fields are valid, or an error if any field is invalid.
+    pub fn validate(&self, fields: &[&str]) -> Result<(), ValidationError> {
+        for &field in fields {
+            let result = match field {
+                "depth" => validate_field("depth", self.depth, Some(0.0), None, "screening_trench"),
+                unknown => Err(ValidationError {
+                    code: "screening_trench.invalid_field".into(),
+                    message: format!("Field '{}' is not valid for ScreeningTrench.", unknown),
+                }),
+            };
+
+            result?; // propagate error if any field fails
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,331 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    constants::{KPA_TO_TON, MPA_TO_TON},
+    enums::{DesignParameter, MeasurementSource, SelectionRule},
+    helper::interp1d,
+    liquefaction::spt::seed_idriss::prepare_spt_exp,
+    models::{cpt::CPTExp, soil_profile::SoilProfile, spt::SPT},
+    validation::{validate_field, ValidationError},
+};
+
+/// Cone factor Nkt used to derive cu from cone resistance.
+const CONE_FACTOR: f64 = 15.0;
+
+/// Stroud (1974) factor f1 relating cu to N60 (4.5 kPa in t/m²).
+const STROUD_FACTOR: f64 = 4.5 * KPA_TO_TON;
+
+/// Single measurement of a design parameter.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Measurement {
+    /// Depth of the measurement (m).
+    pub depth: f64,
+    /// Measured or derived value of the parameter.
+    pub value: f64,
+    /// Source of the measurement.
+    pub source: MeasurementSource,
+    /// Test or correlation the value comes from.
+    pub method: String,
+}
+
+impl Measurement {
+    /// Creates a new laboratory measurement.
+    ///
+    /// # Arguments
+    /// * `depth` - Depth of the sample (m).
+    /// * `value` - Measured value of the parameter.
+    /// * `method` - Name of the laboratory test (e.g. "UU triaxial").
+    pub fn lab(depth: f64, value: f64, method: &str) -> Self {
+        Self {
+            depth,
+            value,
+            source: MeasurementSource::Lab,
+            method: method.to_string(),
+        }
+    }
+}
+
+/// Selected design value of a single soil layer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParameterSelectionRow {
+    /// Index of the soil layer.
+    pub layer_index: usize,
+    /// Top depth of the layer (m).
+    pub top_depth: f64,
+    /// Bottom depth of the layer (m).
+    pub bottom_depth: f64,
+    /// Measurements used for the selection.
+    pub measurements: Vec<Measurement>,
+    /// Mean of the measurements.
+    pub mean: f64,
+    /// Sample standard deviation of the measurements.
+    pub std_dev: f64,
+    /// Coefficient of variation of the measurements.
+    pub cov: f64,
+    /// Selected design value.
+    pub selected_value: f64,
+}
+
+/// Parameter selection table of a soil profile.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParameterSelectionTable {
+    /// Selected parameter.
+    pub parameter: DesignParameter,
+    /// Rule used for the selection.
+    pub rule: SelectionRule,
+    /// Selection of each layer with at least one measurement.
+    pub rows: Vec<ParameterSelectionRow>,
+}
+
+/// Validates the input data for parameter selection.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `lab_measurements` - Laboratory measurements of the parameter.
+/// * `spt` - Optional SPT data.
+/// * `cpt` - Optional CPT data.
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(
+    soil_profile: &SoilProfile,
+    lab_measurements: &[Measurement],
+    spt: Option<&SPT>,
+    cpt: Option<&CPTExp>,
+) -> Result<(), ValidationError> {
+    soil_profile.validate(&["thickness", "dry_unit_weight", "saturated_unit_weight"])?;
+
+    for measurement in lab_measurements {
+        validate_field(
+            "depth",
+            Some(measurement.depth),
+            Some(0.0),
+            None,
+            "parameter_selection",
+        )?;
+    }
+    if let Some(spt) = spt {
+        spt.validate(&["n", "depth"])?;
+    }
+    if let Some(cpt) = cpt {
+        for layer in &cpt.layers {
+            layer.validate(&["depth", "cone_resistance"])?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the one-sided 95% Student-t value for the given degrees of freedom.
+fn get_student_t(degrees_of_freedom: usize) -> f64 {
+    let dof = [
+        1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 20.0, 30.0, 60.0, 120.0,
+    ];
+    let t = [
+        6.314, 2.920, 2.353, 2.132, 2.015, 1.943, 1.895, 1.860, 1.833, 1.812, 1.725, 1.697, 1.671,
+        1.658,
+    ];
+    interp1d(&dof, &t, degrees_of_freedom as f64)
+}
+
+/// Derives measurements of a parameter from SPT blow counts.
+///
+/// * cu = f1 · N60 with f1 = 4.5 kPa (Stroud, 1974) in cohesive layers
+/// * φ' = √(20 · N1,60) + 20 (Hatanaka & Uchida, 1996) in granular layers
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `spt` - SPT data.
+/// * `parameter` - The parameter to derive.
+///
+/// # Returns
+/// * `Vec<Measurement>`: SPT-derived measurements.
+pub fn derive_from_spt(
+    soil_profile: &SoilProfile,
+    spt: &mut SPT,
+    parameter: DesignParameter,
+) -> Vec<Measurement> {
+    let spt_exp = prepare_spt_exp(spt, soil_profile);
+
+    spt_exp
+        .blows
+        .iter()
+        .filter_map(|blow| {
+            let depth = blow.depth.unwrap();
+            let cohesive = soil_profile.get_layer_at_depth(depth).is_cohesive();
+            let (value, method) = match parameter {
+                DesignParameter::UndrainedShearStrength if cohesive => {
+                    (STROUD_FACTOR * blow.n60?.to_i32() as f64, "Stroud (1974)")
+                }
+                DesignParameter::FrictionAngle if !cohesive => (
+                    (20.0 * blow.n1_60?.to_i32() as f64).sqrt() + 20.0,
+                    "Hatanaka & Uchida (1996)",
+                ),
+                _ => return None,
+            };
+            Some(Measurement {
+                depth,
+                value,
+                source: MeasurementSource::Spt,
+                method: method.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Derives measurements of a parameter from CPT cone resistance.
+///
+/// * cu = (qc − σv) / Nkt with Nkt = 15 in cohesive layers
+/// * tan φ' = 0.1 + 0.38 · log10(qc / σ'v) (Robertson & Campanella, 1983) in granular layers
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `cpt` - CPT data (qc in MPa).
+/// * `parameter` - The parameter to derive.
+///
+/// # Returns
+/// * `Vec<Measurement>`: CPT-derived measurements.
+pub fn derive_from_cpt(
+    soil_profile: &SoilProfile,
+    cpt: &CPTExp,
+    parameter: DesignParameter,
+) -> Vec<Measurement> {
+    cpt.layers
+        .iter()
+        .filter_map(|layer| {
+            let depth = layer.depth.unwrap();
+            let qc = layer.cone_resistance.unwrap() * MPA_TO_TON;
+            let cohesive = soil_profile.get_layer_at_depth(depth).is_cohesive();
+            let (value, method) = match parameter {
+                DesignParameter::UndrainedShearStrength if cohesive => {
+                    let normal_stress = soil_profile.calc_normal_stress(depth);
+                    ((qc - normal_stress) / CONE_FACTOR, "Nkt = 15")
+                }
+                DesignParameter::FrictionAngle if !cohesive => {
+                    let effective_stress = soil_profile.calc_effective_stress(depth);
+                    if effective_stress <= 0.0 {
+                        return None;
+                    }
+                    let tan_phi = 0.1 + 0.38 * (qc / effective_stress).log10();
+                    (tan_phi.atan().to_degrees(), "Robertson & Campanella (1983)")
+                }
+                _ => return None,
+            };
+            Some(Measurement {
+                depth,
+                value,
+                source: MeasurementSource::Cpt,
+                method: method.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Applies a selection rule to a set of values.
+///
+/// The characteristic value is the 95% confidence lower bound of the mean,
+/// x̄ − t(n−1) · s / √n (EC7). With a single measurement, the measured value is used for every
+/// rule.
+///
+/// # Arguments
+/// * `values` - Measured values (at least one).
+/// * `rule` - Selection rule.
+///
+/// # Returns
+/// * `(mean, std_dev, selected_value)`
+pub fn apply_selection_rule(values: &[f64], rule: SelectionRule) -> (f64, f64, f64) {
+    let n = values.len();
+    let mean = values.iter().sum::<f64>() / n as f64;
+    if n < 2 {
+        return (mean, 0.0, mean);
+    }
+
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1) as f64;
+    let std_dev = variance.sqrt();
+
+    let selected_value = match rule {
+        SelectionRule::Mean => mean,
+        SelectionRule::CautiousMean => mean - 0.5 * std_dev,
+        SelectionRule::Characteristic => mean - get_student_t(n - 1) * std_dev / (n as f64).sqrt(),
+        SelectionRule::Minimum => values.iter().cloned().fold(f64::INFINITY, f64::min),
+        SelectionRule::Median => {
+            let mut sorted = values.to_vec();
+            sorted.sort_by(|a, b| a.total_cmp(b));
+            if n.is_multiple_of(2) {
+                (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0
+            } else {
+                sorted[n / 2]
+            }
+        }
+    };
+
+    (mean, std_dev, selected_value)
+}
+
+/// Selects the design value of a parameter for each soil layer.
+///
+/// Laboratory measurements are combined with values derived from SPT and CPT data, grouped by
+/// layer, and reduced to a single design value with the given rule. Each row keeps the
+/// measurements used so the selection can be traced back to its sources.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `parameter` - The parameter to select.
+/// * `lab_measurements` - Laboratory measurements of the parameter.
+/// * `spt` - Optional SPT data.
+/// * `cpt` - Optional CPT data (qc in MPa).
+/// * `rule` - Selection rule.
+///
+/// # Returns
+/// * `ParameterSelectionTable` with a row for each layer that has measurements.
+pub fn select_design_parameter(
+    soil_profile: &SoilProfile,
+    parameter: DesignParameter,
+    lab_measurements: &[Measurement],
+    spt: Option<&mut SPT>,
+    cpt: Option<&CPTExp>,
+    rule: SelectionRule,
+) -> Result<ParameterSelectionTable, ValidationError> {
+    validate_input(soil_profile, lab_measurements, spt.as_deref(), cpt)?;
+
+    let mut measurements = lab_measurements.to_vec();
+    if let Some(spt) = spt {
+        measurements.extend(derive_from_spt(soil_profile, spt, parameter));
+    }
+    if let Some(cpt) = cpt {
+        measurements.extend(derive_from_cpt(soil_profile, cpt, parameter));
+    }
+
+    let mut grouped: Vec<Vec<Measurement>> = vec![Vec::new(); soil_profile.layers.len()];
+    for measurement in measurements {
+        let index = soil_profile.get_layer_index(measurement.depth);
+        grouped[index].push(measurement);
+    }
+
+    let mut rows = Vec::new();
+    let mut top_depth = 0.0;
+    for (layer_index, measurements) in grouped.into_iter().enumerate() {
+        let bottom_depth = soil_profile.layers[layer_index].depth.unwrap();
+        if !measurements.is_empty() {
+            let values: Vec<f64> = measurements.iter().map(|m| m.value).collect();
+            let (mean, std_dev, selected_value) = apply_selection_rule(&values, rule);
+            rows.push(ParameterSelectionRow {
+                layer_index,
+                top_depth,
+                bottom_depth,
+                measurements,
+                mean,
+                std_dev,
+                cov: if mean != 0.0 { std_dev / mean } else { 0.0 },
+                selected_value,
+            });
+        }
+        top_depth = bottom_depth;
+    }
+
+    Ok(ParameterSelectionTable {
+        parameter,
+        rule,
+        rows,
+    })
+}
@@ -0,0 +1,28 @@
+use crate::{models::gradation::GradationCurve, validation::ValidationError};
+
+/// Estimates the hydraulic conductivity of a clean sand with the Hazen (1911) formula.
+///
+/// k = C · D10², with k in cm/s and D10 in cm. The formula is intended for clean, uniform sands
+/// with 0.1 mm ≤ D10 ≤ 3 mm and Cu < 5; C typically ranges from 40 to 150 and 100 is a common
+/// default.
+///
+/// # Arguments
+/// * `gradation` - The gradation curve of the soil.
+/// * `hazen_coefficient` - Empirical Hazen coefficient C.
+///
+/// # Returns
+/// * `f64`: Hydraulic conductivity (m/s).
+pub fn calc_hazen_permeability(
+    gradation: &GradationCurve,
+    hazen_coefficient: f64,
+) -> Result<f64, ValidationError> {
+    gradation.validate()?;
+
+    let d10 = gradation.d10().ok_or(ValidationError {
+        code: "permeability.gradation.d10_unavailable".to_string(),
+        message: "D10 cannot be read from the gradation curve.".to_string(),
+    })?;
+
+    let d10_cm = d10 / 10.0;
+    Ok(hazen_coefficient * d10_cm.powi(2) / 100.0)
+}
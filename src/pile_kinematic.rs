@@ -0,0 +1,197 @@
+use serde::Serialize;
+
+use crate::{
+    liquefaction::helper_functions::calc_rd,
+    models::{pile_section::PileSection, soil_profile::SoilProfile},
+    validation::{validate_field, ValidationError},
+};
+
+/// Kinematic bending of a pile at an interface between a soft and a stiff layer.
+#[derive(Debug, Clone, Serialize)]
+pub struct KinematicInterfaceResult {
+    /// Depth of the interface (m).
+    pub depth: f64,
+    /// Shear wave velocity of the upper layer (m/s).
+    pub upper_shear_wave_velocity: f64,
+    /// Shear wave velocity of the lower layer (m/s).
+    pub lower_shear_wave_velocity: f64,
+    /// Peak shear strain of the upper layer at the interface.
+    pub shear_strain: f64,
+    /// Stiffness contrast c = (G2 / G1)^(1/4).
+    pub stiffness_contrast: f64,
+    /// Curvature of the pile at the interface (1/m).
+    pub curvature: f64,
+    /// Kinematic bending moment at the interface (t·m).
+    pub bending_moment: f64,
+    /// Ratio of the bending moment to the moment capacity of the section.
+    pub utilization: f64,
+    /// Indicates whether the bending moment exceeds the moment capacity.
+    pub exceeds_capacity: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct KinematicBendingResult {
+    /// Results of the interfaces crossed by the pile with a stiffer layer below.
+    pub interfaces: Vec<KinematicInterfaceResult>,
+    /// Largest kinematic bending moment (t·m).
+    pub max_bending_moment: f64,
+    /// Largest utilization of the moment capacity.
+    pub max_utilization: f64,
+    /// Indicates whether the moment capacity is sufficient at all interfaces.
+    pub is_safe: bool,
+}
+
+/// Validates the input data for the kinematic bending check.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `pile` - The pile section.
+/// * `pga` - Peak ground acceleration (g).
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(
+    soil_profile: &SoilProfile,
+    pile: &PileSection,
+    pga: f64,
+) -> Result<(), ValidationError> {
+    soil_profile.validate(&[
+        "thickness",
+        "dry_unit_weight",
+        "saturated_unit_weight",
+        "shear_wave_velocity",
+    ])?;
+    pile.validate(&["diameter", "length", "elastic_modulus", "moment_capacity"])?;
+    if pile.moment_of_inertia.is_some() {
+        pile.validate(&["moment_of_inertia"])?;
+    }
+    validate_field("pga", Some(pga), Some(0.0), None, "pile_kinematic")?;
+
+    Ok(())
+}
+
+/// Calculates the kinematic bending moment of a pile at a stiffness contrast.
+///
+/// The pile is a beam on Winkler springs k = 3 · G across the interface, with the soil
+/// displacement of the upper layer at the shear strain γ1 and of the lower layer at
+/// γ2 = γ1 · G1 / G2 (Dobry & O'Rourke, 1983):
+///
+/// * M = 2 · (3/4)^(1/4) · (Ep · Ip)^(3/4) · G1^(1/4) · γ1 · F ≈ 1.86 · (Ep · Ip)^(3/4) · G1^(1/4) · γ1 · F
+/// * F = (c − 1) · (c² − c + 1) / c³, with c = (G2 / G1)^(1/4)
+///
+/// # Arguments
+/// * `bending_stiffness` - Bending stiffness of the pile Ep · Ip (t·m²).
+/// * `upper_shear_modulus` - Shear modulus of the upper layer G1 (t/m²).
+/// * `lower_shear_modulus` - Shear modulus of the lower layer G2 (t/m²).
+/// * `shear_strain` - Shear strain of the upper layer γ1.
+///
+/// # Returns
+/// * `f64`: Bending moment at the interface (t·m), zero if the lower layer is not stiffer.
+pub fn calc_interface_moment(
+    bending_stiffness: f64,
+    upper_shear_modulus: f64,
+    lower_shear_modulus: f64,
+    shear_strain: f64,
+) -> f64 {
+    let c = (lower_shear_modulus / upper_shear_modulus).powf(0.25);
+    if c <= 1.0 {
+        return 0.0;
+    }
+    let f = (c - 1.0) * (c * c - c + 1.0) / c.powi(3);
+
+    2.0 * 0.75_f64.powf(0.25)
+        * bending_stiffness.powf(0.75)
+        * upper_shear_modulus.powf(0.25)
+        * shear_strain
+        * f
+}
+
+/// Checks a pile for kinematic bending at the stiffness contrasts it crosses.
+///
+/// At each layer interface above the pile tip with a higher shear wave velocity below, the peak
+/// shear strain of the upper layer comes from the simplified site response
+/// γ1 = amax · σv · rd / G1, with the small-strain shear modulus G = γ / g · Vs². The bending
+/// moment from `calc_interface_moment` gives the curvature M / (Ep · Ip) and is compared with
+/// the moment capacity of the section.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile with the shear wave velocities.
+/// * `pile` - The pile section.
+/// * `pga` - Peak ground acceleration (g).
+///
+/// # Returns
+/// * `KinematicBendingResult` with the bending demand at each interface.
+pub fn calc_kinematic_bending(
+    soil_profile: &SoilProfile,
+    pile: &PileSection,
+    pga: f64,
+) -> Result<KinematicBendingResult, ValidationError> {
+    validate_input(soil_profile, pile, pga)?;
+
+    let mut profile = soil_profile.clone();
+    profile.calc_layer_depths();
+    let gwt = profile.ground_water_level.unwrap();
+    let bending_stiffness = pile.elastic_modulus.unwrap() * pile.get_moment_of_inertia();
+    let moment_capacity = pile.moment_capacity.unwrap();
+    let pile_length = pile.length.unwrap();
+    let environment = profile.get_environment();
+
+    let calc_shear_modulus = |index: usize, depth: f64| {
+        let layer = &profile.layers[index];
+        let unit_weight = if depth <= gwt {
+            layer.dry_unit_weight.unwrap()
+        } else {
+            layer.saturated_unit_weight.unwrap()
+        };
+        unit_weight / environment.gravity * layer.shear_wave_velocity.unwrap().powi(2)
+    };
+
+    let mut interfaces = Vec::new();
+
+    for (i, pair) in profile.layers.windows(2).enumerate() {
+        let depth = pair[0].depth.unwrap();
+        let upper_vs = pair[0].shear_wave_velocity.unwrap();
+        let lower_vs = pair[1].shear_wave_velocity.unwrap();
+        if depth >= pile_length || lower_vs <= upper_vs {
+            continue;
+        }
+
+        let upper_shear_modulus = calc_shear_modulus(i, depth);
+        let lower_shear_modulus = calc_shear_modulus(i + 1, depth);
+        let shear_stress = pga * profile.calc_normal_stress(depth) * calc_rd(depth);
+        let shear_strain = shear_stress / upper_shear_modulus;
+
+        let bending_moment = calc_interface_moment(
+            bending_stiffness,
+            upper_shear_modulus,
+            lower_shear_modulus,
+            shear_strain,
+        );
+        let utilization = bending_moment / moment_capacity;
+
+        interfaces.push(KinematicInterfaceResult {
+            depth,
+            upper_shear_wave_velocity: upper_vs,
+            lower_shear_wave_velocity: lower_vs,
+            shear_strain,
+            stiffness_contrast: (lower_shear_modulus / upper_shear_modulus).powf(0.25),
+            curvature: bending_moment / bending_stiffness,
+            bending_moment,
+            utilization,
+            exceeds_capacity: utilization > 1.0,
+        });
+    }
+
+    let max_bending_moment = interfaces
+        .iter()
+        .map(|r| r.bending_moment)
+        .fold(0.0, f64::max);
+    let max_utilization = interfaces.iter().map(|r| r.utilization).fold(0.0, f64::max);
+
+    Ok(KinematicBendingResult {
+        interfaces,
+        max_bending_moment,
+        max_utilization,
+        is_safe: max_utilization <= 1.0,
+    })
+}
@@ -0,0 +1,160 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    models::piled_raft::PiledRaft,
+    validation::{validate_field, ValidationError},
+};
+
+/// Load sharing and settlement of a piled raft.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PiledRaftResult {
+    /// Stiffness of the raft alone, kr = ks · A (t/m).
+    pub raft_stiffness: f64,
+    /// Stiffness of the pile group alone, kp (t/m).
+    pub pile_group_stiffness: f64,
+    /// Stiffness of the combined piled raft, kpr (t/m).
+    pub piled_raft_stiffness: f64,
+    /// Proportion of the load carried by the raft in the elastic range, Pr / Pt.
+    pub raft_load_ratio: f64,
+    /// Total load at which the piles reach their ultimate capacity (t).
+    pub pile_yield_load: f64,
+    /// Load carried by the raft (t).
+    pub raft_load: f64,
+    /// Load carried by the piles (t).
+    pub pile_load: f64,
+    /// Indicates whether the piles are fully mobilized.
+    pub piles_at_capacity: bool,
+    /// Settlement of the piled raft (cm).
+    pub settlement: f64,
+}
+
+/// Validates the input data for piled raft calculations.
+///
+/// # Arguments
+/// * `piled_raft` - The piled raft data.
+/// * `subgrade_modulus` - Modulus of subgrade reaction of the raft (t/m³).
+/// * `total_load` - Total vertical load (t).
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(
+    piled_raft: &PiledRaft,
+    subgrade_modulus: f64,
+    total_load: f64,
+) -> Result<(), ValidationError> {
+    piled_raft.validate(&[
+        "raft_width",
+        "raft_length",
+        "pile_count",
+        "pile_stiffness",
+        "pile_capacity",
+        "group_exponent",
+        "interaction_factor",
+    ])?;
+    validate_field(
+        "subgrade_modulus",
+        Some(subgrade_modulus),
+        Some(0.0001),
+        None,
+        "piled_raft",
+    )?;
+    validate_field(
+        "total_load",
+        Some(total_load),
+        Some(0.0),
+        None,
+        "piled_raft",
+    )?;
+
+    Ok(())
+}
+
+/// Calculates load sharing and settlement of a piled raft with the Poulos–Davis–Randolph (PDR) method.
+///
+/// The raft stiffness is obtained from the modulus of subgrade reaction (see `soil_coefficient`),
+/// and the pile group stiffness from the single pile stiffness as kp = n^(1−e) · k1. The single
+/// pile stiffness k1 is taken as given in `pile_stiffness`, e.g. from a pile load test; it is not
+/// derived from the soil profile. Following Randolph (1994):
+///
+/// * kpr = (kp + kr · (1 − 2αrp)) / (1 − αrp² · kr / kp)
+/// * Pr / Pt = kr · (1 − αrp) / (kp + kr · (1 − 2αrp))
+///
+/// Once the piles reach their ultimate capacity (Poulos, 2001), additional load is carried by
+/// the raft alone with stiffness kr, giving a tri-linear load-settlement curve.
+///
+/// Combinations for which Pr / Pt falls outside [0, 1) give a negative raft or pile load and are
+/// rejected.
+///
+/// # Arguments
+/// * `piled_raft` - The piled raft data.
+/// * `subgrade_modulus` - Modulus of subgrade reaction of the raft (t/m³).
+/// * `total_load` - Total vertical load (t).
+///
+/// # Returns
+/// * `PiledRaftResult` with load sharing and settlement.
+pub fn calc_piled_raft(
+    piled_raft: &PiledRaft,
+    subgrade_modulus: f64,
+    total_load: f64,
+) -> Result<PiledRaftResult, ValidationError> {
+    validate_input(piled_raft, subgrade_modulus, total_load)?;
+
+    let width = piled_raft.raft_width.unwrap();
+    let length = piled_raft.raft_length.unwrap();
+    let n = piled_raft.pile_count.unwrap();
+    let k1 = piled_raft.pile_stiffness.unwrap();
+    let pile_capacity = piled_raft.pile_capacity.unwrap();
+    let e = piled_raft.group_exponent.unwrap();
+    let alpha = piled_raft.interaction_factor.unwrap();
+
+    let kr = subgrade_modulus * width * length;
+    let kp = k1 * n.powf(1.0 - e);
+
+    if alpha.powi(2) * kr / kp >= 1.0 {
+        return Err(ValidationError {
+            code: "piled_raft.stiffness_ratio.out_of_range".to_string(),
+            message: "Raft stiffness is too large relative to the pile group stiffness."
+                .to_string(),
+        });
+    }
+
+    let kpr = (kp + kr * (1.0 - 2.0 * alpha)) / (1.0 - alpha.powi(2) * kr / kp);
+    let raft_load_ratio = kr * (1.0 - alpha) / (kp + kr * (1.0 - 2.0 * alpha));
+    if !(0.0..1.0).contains(&raft_load_ratio) {
+        return Err(ValidationError {
+            code: "piled_raft.raft_load_ratio.out_of_range".to_string(),
+            message: "Raft load ratio must be between 0 and 1.".to_string(),
+        });
+    }
+
+    let group_capacity = n * pile_capacity;
+    let pile_yield_load = group_capacity / (1.0 - raft_load_ratio);
+
+    let (raft_load, pile_load, settlement, piles_at_capacity) = if total_load <= pile_yield_load {
+        (
+            raft_load_ratio * total_load,
+            (1.0 - raft_load_ratio) * total_load,
+            total_load / kpr,
+            false,
+        )
+    } else {
+        (
+            total_load - group_capacity,
+            group_capacity,
+            pile_yield_load / kpr + (total_load - pile_yield_load) / kr,
+            true,
+        )
+    };
+
+    Ok(PiledRaftResult {
+        raft_stiffness: kr,
+        pile_group_stiffness: kp,
+        piled_raft_stiffness: kpr,
+        raft_load_ratio,
+        pile_yield_load,
+        raft_load,
+        pile_load,
+        piles_at_capacity,
+        settlement: settlement * 100.0,
+    })
+}
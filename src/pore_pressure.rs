@@ -0,0 +1,58 @@
+use crate::{
+    constants::Environment,
+    models::phreatic_surface::PhreaticSurface,
+    validation::{validate_field, ValidationError},
+};
+
+/// Calculates the pore pressure on a slice base from a phreatic surface.
+///
+/// The pressure head is the vertical distance from the base to the phreatic surface, corrected
+/// for the inclination θ of the phreatic surface, since the equipotentials are perpendicular to
+/// it: u = γw · hw · cos²θ.
+///
+/// # Arguments
+/// * `phreatic_surface` - The phreatic surface.
+/// * `x` - Horizontal coordinate of the slice base center (m).
+/// * `base_elevation` - Elevation of the slice base center (m).
+/// * `environment` - Physical constants with the unit weight of the groundwater.
+///
+/// # Returns
+/// * `f64`: Pore pressure (t/m²). Zero above the phreatic surface.
+pub fn calc_pore_pressure_from_phreatic_surface(
+    phreatic_surface: &PhreaticSurface,
+    x: f64,
+    base_elevation: f64,
+    environment: &Environment,
+) -> Result<f64, ValidationError> {
+    phreatic_surface.validate()?;
+    environment.validate()?;
+
+    let head = phreatic_surface.get_elevation(x) - base_elevation;
+    if head <= 0.0 {
+        return Ok(0.0);
+    }
+
+    let theta = phreatic_surface.get_inclination(x).to_radians();
+    Ok(environment.hydrostatic_pressure(head) * theta.cos().powi(2))
+}
+
+/// Calculates the pore pressure on a slice base from the pore pressure ratio ru = u / σv.
+///
+/// # Arguments
+/// * `ru` - Pore pressure ratio.
+/// * `vertical_stress` - Total vertical stress at the slice base, γ · h (t/m²).
+///
+/// # Returns
+/// * `f64`: Pore pressure (t/m²).
+pub fn calc_pore_pressure_from_ru(ru: f64, vertical_stress: f64) -> Result<f64, ValidationError> {
+    validate_field("ru", Some(ru), Some(0.0), Some(1.0), "pore_pressure")?;
+    validate_field(
+        "vertical_stress",
+        Some(vertical_stress),
+        Some(0.0),
+        None,
+        "pore_pressure",
+    )?;
+
+    Ok(ru * vertical_stress)
+}
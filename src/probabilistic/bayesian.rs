@@ -0,0 +1,175 @@
+use serde::Serialize;
+
+use crate::{
+    enums::Distribution,
+    validation::{validate_field, ValidationError},
+};
+
+use super::distribution::ParameterDistribution;
+
+/// Result of a Bayesian update of a soil parameter.
+#[derive(Debug, Clone, Serialize)]
+pub struct BayesianUpdateResult {
+    /// Prior distribution of the parameter mean.
+    pub prior: ParameterDistribution,
+    /// Posterior distribution of the parameter mean.
+    pub posterior: ParameterDistribution,
+    /// Posterior predictive distribution of a single new measurement.
+    pub predictive: ParameterDistribution,
+    /// Number of site measurements used.
+    pub n_measurements: usize,
+    /// Weight of the site data in the posterior mean (0: prior only, 1: data only).
+    pub data_weight: f64,
+}
+
+/// Validates the input data for Bayesian updating.
+///
+/// # Arguments
+/// * `prior` - Prior distribution of the parameter.
+/// * `measurements` - Site specific test results.
+/// * `measurement_std` - Standard deviation of a single measurement, if known.
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(
+    prior: &ParameterDistribution,
+    measurements: &[f64],
+    measurement_std: Option<f64>,
+) -> Result<(), ValidationError> {
+    prior.validate()?;
+    validate_field(
+        "std_dev",
+        Some(prior.std_dev),
+        Some(1e-12),
+        None,
+        "bayesian.prior",
+    )?;
+
+    if measurements.is_empty() {
+        return Err(ValidationError {
+            code: "bayesian.measurements.empty".to_string(),
+            message: "At least one measurement is required.".to_string(),
+        });
+    }
+
+    match measurement_std {
+        Some(std) => {
+            validate_field("measurement_std", Some(std), Some(1e-12), None, "bayesian")?;
+        }
+        None if measurements.len() < 2 => {
+            return Err(ValidationError {
+                code: "bayesian.measurement_std.missing".to_string(),
+                message: "Measurement standard deviation is required for a single measurement."
+                    .to_string(),
+            });
+        }
+        None => {}
+    }
+
+    if let Distribution::Lognormal = prior.distribution
+        && measurements.iter().any(|&x| x <= 0.0)
+    {
+        return Err(ValidationError {
+            code: "bayesian.measurements.non_positive".to_string(),
+            message: "Measurements must be positive for a lognormal parameter.".to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Calculates the sample mean and standard deviation of a set of values.
+fn sample_statistics(values: &[f64]) -> (f64, f64) {
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let var = if values.len() > 1 {
+        values.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)
+    } else {
+        0.0
+    };
+    (mean, var.sqrt())
+}
+
+/// Updates a prior parameter distribution with site specific test results.
+///
+/// The normal–normal conjugate model is used for the mean of the parameter:
+///
+/// * 1/σ²post = 1/σ²prior + n/σ²m
+/// * μpost = σ²post · (μprior/σ²prior + Σx/σ²m)
+///
+/// For lognormal parameters the update is carried out on ln(X). When the measurement standard
+/// deviation is not given, it is estimated from the scatter of the measurements.
+///
+/// # Arguments
+/// * `prior` - Prior distribution of the parameter, e.g. from a correlation.
+/// * `measurements` - Site specific test results.
+/// * `measurement_std` - Standard deviation of a single measurement (parameter units), if known.
+///
+/// # Returns
+/// * `BayesianUpdateResult` with posterior and predictive distributions.
+pub fn update_parameter(
+    prior: &ParameterDistribution,
+    measurements: &[f64],
+    measurement_std: Option<f64>,
+) -> Result<BayesianUpdateResult, ValidationError> {
+    validate_input(prior, measurements, measurement_std)?;
+
+    let n = measurements.len();
+
+    let (prior_mean, prior_std, data): (f64, f64, Vec<f64>) = match prior.distribution {
+        Distribution::Normal => (prior.mean, prior.std_dev, measurements.to_vec()),
+        Distribution::Lognormal => {
+            let (mu_ln, sigma_ln) = prior.log_params();
+            (
+                mu_ln,
+                sigma_ln,
+                measurements.iter().map(|x| x.ln()).collect(),
+            )
+        }
+    };
+
+    let (data_mean, data_std) = sample_statistics(&data);
+    let sigma_m = match (measurement_std, prior.distribution) {
+        (Some(std), Distribution::Normal) => std,
+        (Some(std), Distribution::Lognormal) => {
+            let (mean, _) = sample_statistics(measurements);
+            (1.0 + (std / mean).powi(2)).ln().sqrt()
+        }
+        (None, _) => data_std,
+    };
+
+    let prior_precision = 1.0 / prior_std.powi(2);
+    let data_precision = if sigma_m > 0.0 {
+        n as f64 / sigma_m.powi(2)
+    } else {
+        f64::INFINITY
+    };
+
+    let (post_mean, post_std, data_weight) = if data_precision.is_infinite() {
+        (data_mean, 0.0, 1.0)
+    } else {
+        let post_var = 1.0 / (prior_precision + data_precision);
+        let post_mean = post_var * (prior_mean * prior_precision + data_mean * data_precision);
+        (post_mean, post_var.sqrt(), data_precision * post_var)
+    };
+    let predictive_std = (post_std.powi(2) + sigma_m.powi(2)).sqrt();
+
+    let (posterior, predictive) = match prior.distribution {
+        Distribution::Normal => (
+            ParameterDistribution::new(post_mean, post_std, Distribution::Normal),
+            ParameterDistribution::new(post_mean, predictive_std, Distribution::Normal),
+        ),
+        Distribution::Lognormal => (
+            ParameterDistribution::from_log_params(post_mean, post_std),
+            ParameterDistribution::from_log_params(post_mean, predictive_std),
+        ),
+    };
+
+    Ok(BayesianUpdateResult {
+        prior: *prior,
+        posterior,
+        predictive,
+        n_measurements: n,
+        data_weight,
+    })
+}
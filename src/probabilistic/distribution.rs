@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::Distribution,
+    validation::{validate_field, ValidationError},
+};
+
+use super::{random::Rng, random_field::transform_to_marginal};
+
+/// Probability distribution of a soil parameter.
+///
+/// # Fields
+/// * `mean` - Mean of the parameter.
+/// * `std_dev` - Standard deviation of the parameter.
+/// * `distribution` - Marginal distribution type.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ParameterDistribution {
+    pub mean: f64,
+    pub std_dev: f64,
+    pub distribution: Distribution,
+}
+
+impl ParameterDistribution {
+    /// Creates a new `ParameterDistribution` instance.
+    pub fn new(mean: f64, std_dev: f64, distribution: Distribution) -> Self {
+        Self {
+            mean,
+            std_dev,
+            distribution,
+        }
+    }
+
+    /// Returns the coefficient of variation of the parameter.
+    pub fn cov(&self) -> f64 {
+        self.std_dev / self.mean
+    }
+
+    /// Returns the mean and standard deviation of ln(X) for a lognormal parameter.
+    pub fn log_params(&self) -> (f64, f64) {
+        let sigma_ln = (1.0 + self.cov().powi(2)).ln().sqrt();
+        let mu_ln = self.mean.ln() - 0.5 * sigma_ln.powi(2);
+        (mu_ln, sigma_ln)
+    }
+
+    /// Creates a lognormal distribution from the mean and standard deviation of ln(X).
+    pub fn from_log_params(mu_ln: f64, sigma_ln: f64) -> Self {
+        let mean = (mu_ln + 0.5 * sigma_ln.powi(2)).exp();
+        let std_dev = mean * (sigma_ln.powi(2).exp() - 1.0).sqrt();
+        Self::new(mean, std_dev, Distribution::Lognormal)
+    }
+
+    /// Draws a random sample of the parameter.
+    pub fn sample(&self, rng: &mut Rng) -> f64 {
+        match self.distribution {
+            Distribution::Normal => rng.normal(self.mean, self.std_dev),
+            Distribution::Lognormal => transform_to_marginal(
+                self.mean,
+                self.cov(),
+                rng.standard_normal(),
+                self.distribution,
+            ),
+        }
+    }
+
+    /// Validates the distribution parameters.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let min_mean = match self.distribution {
+            Distribution::Normal => None,
+            Distribution::Lognormal => Some(0.0001),
+        };
+        validate_field("mean", Some(self.mean), min_mean, None, "distribution")?;
+        validate_field(
+            "std_dev",
+            Some(self.std_dev),
+            Some(0.0),
+            None,
+            "distribution",
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+pub mod bayesian;
+pub mod distribution;
+pub mod monte_carlo;
+pub mod random;
+pub mod random_field;
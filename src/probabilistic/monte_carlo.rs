@@ -0,0 +1,255 @@
+use std::{num::NonZeroUsize, thread};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    progress::ProgressHandle,
+    validation::{validate_field, ValidationError},
+};
+
+use super::random::Rng;
+
+/// Settings of a Monte Carlo simulation.
+///
+/// # Fields
+/// * `iterations` - Number of realizations.
+/// * `seed` - Seed of the analysis; realization i draws from stream i of the seed.
+/// * `threads` - Number of worker threads, 0 for the available parallelism.
+/// * `convergence_interval` - Number of realizations between convergence diagnostics.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct MonteCarloParams {
+    pub iterations: usize,
+    pub seed: u64,
+    pub threads: usize,
+    pub convergence_interval: usize,
+}
+
+impl Default for MonteCarloParams {
+    fn default() -> Self {
+        Self {
+            iterations: 10000,
+            seed: 0,
+            threads: 0,
+            convergence_interval: 100,
+        }
+    }
+}
+
+impl MonteCarloParams {
+    /// Validates the simulation settings.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        validate_field(
+            "iterations",
+            Some(self.iterations as f64),
+            Some(2.0),
+            None,
+            "monte_carlo",
+        )?;
+        validate_field(
+            "convergence_interval",
+            Some(self.convergence_interval as f64),
+            Some(1.0),
+            None,
+            "monte_carlo",
+        )?;
+        Ok(())
+    }
+}
+
+/// Running statistics of the factor of safety after a number of realizations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConvergencePoint {
+    /// Number of realizations included.
+    pub iterations: usize,
+    /// Running mean of the factor of safety.
+    pub mean: f64,
+    /// Running coefficient of variation of the factor of safety.
+    pub cov: f64,
+    /// Running probability of failure (FS < 1).
+    pub probability_of_failure: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonteCarloResult {
+    /// Factor of safety of each realization, in realization order.
+    pub samples: Vec<f64>,
+    /// Mean of the factor of safety.
+    pub mean: f64,
+    /// Standard deviation of the factor of safety.
+    pub std_dev: f64,
+    /// Coefficient of variation of the factor of safety.
+    pub cov: f64,
+    /// Fraction of the realizations with FS < 1.
+    pub probability_of_failure: f64,
+    /// Reliability index β = (μ − 1) / σ of a normally distributed factor of safety.
+    pub reliability_index: f64,
+    /// Running statistics at every convergence interval and at the last realization.
+    pub convergence: Vec<ConvergencePoint>,
+    /// Seed of the analysis.
+    pub seed: u64,
+    /// Number of worker threads used.
+    pub threads: usize,
+}
+
+/// Returns the number of worker threads for the settings.
+fn resolve_threads(params: &MonteCarloParams) -> usize {
+    let threads = if params.threads == 0 {
+        thread::available_parallelism().map_or(1, NonZeroUsize::get)
+    } else {
+        params.threads
+    };
+
+    threads.min(params.iterations).max(1)
+}
+
+/// Runs the realizations in the index range, each with its own random stream.
+///
+/// Cancellation is checked before each realization, which stops the block with the
+/// cancellation error. Completed realizations are reported in blocks of `report_interval`.
+fn run_realizations<F>(
+    model: &F,
+    seed: u64,
+    range: std::ops::Range<usize>,
+    progress: Option<&ProgressHandle>,
+    report_interval: usize,
+) -> Vec<Result<f64, ValidationError>>
+where
+    F: Fn(&mut Rng) -> Result<f64, ValidationError>,
+{
+    let mut results = Vec::with_capacity(range.len());
+    let mut unreported = 0;
+    for i in range {
+        if let Some(progress) = progress
+            && let Err(err) = progress.check_cancelled()
+        {
+            results.push(Err(err));
+            break;
+        }
+
+        results.push(model(&mut Rng::from_stream(seed, i as u64)));
+        unreported += 1;
+        if let Some(progress) = progress
+            && unreported == report_interval
+        {
+            progress.advance(unreported);
+            unreported = 0;
+        }
+    }
+    if let Some(progress) = progress {
+        progress.advance(unreported);
+    }
+
+    results
+}
+
+/// Runs a Monte Carlo simulation of a factor of safety.
+///
+/// Realization i draws its random variables from stream i of the seed (see
+/// `Rng::from_stream`), and the realizations are split into contiguous blocks over the worker
+/// threads. The samples and all statistics are therefore identical for a given seed whatever
+/// the number of threads, so reliability results can be reproduced independently.
+///
+/// The convergence diagnostics record the running mean, coefficient of variation and
+/// probability of failure every `convergence_interval` realizations, in realization order.
+///
+/// With a progress handle, the total is set to the number of realizations and each worker
+/// reports its completed realizations every `convergence_interval` realizations. Cancellation is
+/// checked between realizations and ends the simulation with an `analysis.cancelled` error.
+///
+/// # Arguments
+/// * `model` - Returns the factor of safety of one realization, drawing its random variables
+///   from the given generator.
+/// * `params` - Simulation settings.
+/// * `progress` - Optional handle for progress reporting and cancellation.
+///
+/// # Returns
+/// * `MonteCarloResult` with the samples, statistics and convergence diagnostics, or the error
+///   of the first failing realization.
+pub fn run_monte_carlo<F>(
+    model: F,
+    params: &MonteCarloParams,
+    progress: Option<&ProgressHandle>,
+) -> Result<MonteCarloResult, ValidationError>
+where
+    F: Fn(&mut Rng) -> Result<f64, ValidationError> + Sync,
+{
+    params.validate()?;
+    if let Some(progress) = progress {
+        progress.set_total(params.iterations);
+    }
+    let report_interval = params.convergence_interval;
+
+    let threads = resolve_threads(params);
+    let block = params.iterations.div_ceil(threads);
+
+    let results: Vec<Result<f64, ValidationError>> = if threads == 1 {
+        run_realizations(
+            &model,
+            params.seed,
+            0..params.iterations,
+            progress,
+            report_interval,
+        )
+    } else {
+        thread::scope(|scope| {
+            let handles: Vec<_> = (0..threads)
+                .map(|t| {
+                    let start = (t * block).min(params.iterations);
+                    let end = ((t + 1) * block).min(params.iterations);
+                    let model = &model;
+                    scope.spawn(move || {
+                        run_realizations(model, params.seed, start..end, progress, report_interval)
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().expect("Monte Carlo worker panicked"))
+                .collect()
+        })
+    };
+    let samples = results.into_iter().collect::<Result<Vec<f64>, _>>()?;
+
+    let mut convergence = Vec::new();
+    let (mut mean, mut m2, mut failures) = (0.0, 0.0, 0usize);
+    for (i, &fs) in samples.iter().enumerate() {
+        // Welford's running mean and variance
+        let n = (i + 1) as f64;
+        let delta = fs - mean;
+        mean += delta / n;
+        m2 += delta * (fs - mean);
+        if fs < 1.0 {
+            failures += 1;
+        }
+
+        let count = i + 1;
+        if count % params.convergence_interval == 0 || count == samples.len() {
+            let std_dev = if count > 1 {
+                (m2 / (n - 1.0)).sqrt()
+            } else {
+                0.0
+            };
+            convergence.push(ConvergencePoint {
+                iterations: count,
+                mean,
+                cov: std_dev / mean,
+                probability_of_failure: failures as f64 / n,
+            });
+        }
+    }
+
+    let std_dev = (m2 / (samples.len() - 1) as f64).sqrt();
+
+    Ok(MonteCarloResult {
+        mean,
+        std_dev,
+        cov: std_dev / mean,
+        probability_of_failure: failures as f64 / samples.len() as f64,
+        reliability_index: (mean - 1.0) / std_dev,
+        samples,
+        convergence,
+        seed: params.seed,
+        threads,
+    })
+}
@@ -0,0 +1,72 @@
+/// A small, seedable pseudo random number generator (SplitMix64).
+///
+/// It is deterministic for a given seed so that probabilistic analyses are reproducible.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+    spare_normal: Option<f64>,
+}
+
+impl Rng {
+    /// Creates a new generator from a seed.
+    ///
+    /// # Arguments
+    /// * `seed` - Seed of the generator.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: seed,
+            spare_normal: None,
+        }
+    }
+
+    /// Creates the generator of an independent stream of a seed.
+    ///
+    /// The stream index is scrambled with the SplitMix64 output function, so that every Monte
+    /// Carlo realization can have its own generator, independent of the thread that runs it.
+    ///
+    /// # Arguments
+    /// * `seed` - Seed of the analysis.
+    /// * `stream` - Index of the stream.
+    pub fn from_stream(seed: u64, stream: u64) -> Self {
+        let mut scrambler = Self::new(seed ^ stream.wrapping_mul(0xD1B5_4A32_D192_ED03));
+        Self::new(scrambler.next_u64())
+    }
+
+    /// Returns the next random 64-bit integer.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a uniformly distributed number in the open interval (0, 1).
+    pub fn next_f64(&mut self) -> f64 {
+        ((self.next_u64() >> 11) as f64 + 0.5) / (1u64 << 53) as f64
+    }
+
+    /// Returns a standard normal variate using the Box–Muller transform.
+    pub fn standard_normal(&mut self) -> f64 {
+        if let Some(z) = self.spare_normal.take() {
+            return z;
+        }
+
+        let u1 = self.next_f64();
+        let u2 = self.next_f64();
+        let r = (-2.0 * u1.ln()).sqrt();
+        let theta = 2.0 * std::f64::consts::PI * u2;
+
+        self.spare_normal = Some(r * theta.sin());
+        r * theta.cos()
+    }
+
+    /// Returns a normal variate with the given mean and standard deviation.
+    ///
+    /// # Arguments
+    /// * `mean` - Mean of the distribution.
+    /// * `std_dev` - Standard deviation of the distribution.
+    pub fn normal(&mut self, mean: f64, std_dev: f64) -> f64 {
+        mean + std_dev * self.standard_normal()
+    }
+}
@@ -0,0 +1,199 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::Distribution,
+    models::{
+        soil_profile::SoilProfile,
+        spt::{NValue, SPTExp},
+    },
+    validation::{validate_field, ValidationError},
+};
+
+use super::random::Rng;
+
+/// Parameters of a one-dimensional random field over depth.
+///
+/// The mean of the field is taken from the deterministic value of each layer (or test),
+/// so only the variability and spatial correlation are described here.
+///
+/// # Fields
+/// * `cov` - Coefficient of variation of the parameter (unitless).
+/// * `correlation_length` - Vertical scale of fluctuation θ (m).
+/// * `distribution` - Marginal distribution of the parameter.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct RandomFieldParams {
+    pub cov: f64,
+    pub correlation_length: f64,
+    pub distribution: Distribution,
+}
+
+impl RandomFieldParams {
+    /// Validates the random field parameters.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        validate_field("cov", Some(self.cov), Some(0.0), None, "random_field")?;
+        validate_field(
+            "correlation_length",
+            Some(self.correlation_length),
+            Some(0.0001),
+            None,
+            "random_field",
+        )?;
+        Ok(())
+    }
+}
+
+/// Generates a correlated standard normal field at the given depths.
+///
+/// A Markov (exponential) correlation ρ(τ) = exp(−2τ/θ) is used, which is generated exactly
+/// as a first order autoregressive process even for irregular depth spacing.
+///
+/// # Arguments
+/// * `depths` - Depths in increasing order (m).
+/// * `correlation_length` - Vertical scale of fluctuation θ (m).
+/// * `rng` - Random number generator.
+///
+/// # Returns
+/// * `Vec<f64>`: Standard normal values at each depth.
+pub fn generate_standard_field(depths: &[f64], correlation_length: f64, rng: &mut Rng) -> Vec<f64> {
+    let mut field = Vec::with_capacity(depths.len());
+    let mut previous: Option<(f64, f64)> = None;
+
+    for &depth in depths {
+        let value = match previous {
+            None => rng.standard_normal(),
+            Some((prev_depth, prev_value)) => {
+                let rho = (-2.0 * (depth - prev_depth).abs() / correlation_length).exp();
+                rho * prev_value + (1.0 - rho.powi(2)).sqrt() * rng.standard_normal()
+            }
+        };
+        field.push(value);
+        previous = Some((depth, value));
+    }
+
+    field
+}
+
+/// Transforms a standard normal value to the marginal distribution of a parameter.
+///
+/// # Arguments
+/// * `mean` - Mean of the parameter.
+/// * `cov` - Coefficient of variation of the parameter.
+/// * `z` - Standard normal value.
+/// * `distribution` - Marginal distribution of the parameter.
+///
+/// # Returns
+/// * `f64`: Parameter value.
+pub fn transform_to_marginal(mean: f64, cov: f64, z: f64, distribution: Distribution) -> f64 {
+    match distribution {
+        Distribution::Normal => mean * (1.0 + cov * z),
+        Distribution::Lognormal => {
+            let sigma_ln = (1.0 + cov.powi(2)).ln().sqrt();
+            let mu_ln = mean.ln() - 0.5 * sigma_ln.powi(2);
+            (mu_ln + sigma_ln * z).exp()
+        }
+    }
+}
+
+/// Generates a realization of the undrained shear strength (cu) over depth.
+///
+/// Each layer is split into sublayers not thicker than `sublayer_thickness`, and the `cu` of
+/// each sublayer is sampled from a random field whose mean is the `cu` of the parent layer.
+/// The field is continuous across layer boundaries, so adjacent sublayers are correlated
+/// according to their distance rather than their layer membership.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `params` - Random field parameters.
+/// * `sublayer_thickness` - Maximum thickness of the generated sublayers (m).
+/// * `rng` - Random number generator.
+///
+/// # Returns
+/// * `SoilProfile`: A discretized profile with randomized `cu` values.
+pub fn generate_cu_field(
+    soil_profile: &SoilProfile,
+    params: &RandomFieldParams,
+    sublayer_thickness: f64,
+    rng: &mut Rng,
+) -> Result<SoilProfile, ValidationError> {
+    soil_profile.validate(&["thickness", "cu"])?;
+    params.validate()?;
+    validate_field(
+        "sublayer_thickness",
+        Some(sublayer_thickness),
+        Some(0.0001),
+        None,
+        "random_field",
+    )?;
+
+    let mut sublayers = Vec::new();
+    let mut centers = Vec::new();
+    let mut top = 0.0;
+
+    for layer in soil_profile.layers.iter() {
+        let thickness = layer.thickness.unwrap();
+        let count = (thickness / sublayer_thickness).ceil().max(1.0) as usize;
+        let dz = thickness / count as f64;
+
+        for i in 0..count {
+            let mut sublayer = layer.clone();
+            sublayer.thickness = Some(dz);
+            centers.push(top + (i as f64 + 0.5) * dz);
+            sublayers.push(sublayer);
+        }
+        top += thickness;
+    }
+
+    let field = generate_standard_field(&centers, params.correlation_length, rng);
+
+    for (sublayer, z) in sublayers.iter_mut().zip(field) {
+        let mean = sublayer.cu.unwrap();
+        let value = transform_to_marginal(mean, params.cov, z, params.distribution);
+        sublayer.cu = Some(value.max(0.0));
+    }
+
+    let mut profile = SoilProfile {
+        layers: sublayers,
+        ground_water_level: soil_profile.ground_water_level,
+        groundwater_samples: soil_profile.groundwater_samples.clone(),
+        overburden_stress: soil_profile.overburden_stress,
+        overburden_pore_pressure: soil_profile.overburden_pore_pressure,
+        environment: soil_profile.environment,
+    };
+    profile.calc_layer_depths();
+
+    Ok(profile)
+}
+
+/// Generates a realization of SPT N values over depth.
+///
+/// The mean of the field at each blow is the recorded N value. Refusals are kept unchanged
+/// and generated values are rounded to the nearest integer with a minimum of 1.
+///
+/// # Arguments
+/// * `exp` - The SPT experiment.
+/// * `params` - Random field parameters.
+/// * `rng` - Random number generator.
+///
+/// # Returns
+/// * `SPTExp`: A copy of the experiment with randomized N values.
+pub fn generate_spt_field(
+    exp: &SPTExp,
+    params: &RandomFieldParams,
+    rng: &mut Rng,
+) -> Result<SPTExp, ValidationError> {
+    exp.validate(&["depth", "n"])?;
+    params.validate()?;
+
+    let mut result = exp.clone();
+    let depths: Vec<f64> = result.blows.iter().map(|b| b.depth.unwrap()).collect();
+    let field = generate_standard_field(&depths, params.correlation_length, rng);
+
+    for (blow, z) in result.blows.iter_mut().zip(field) {
+        if let Some(NValue::Value(n)) = blow.n {
+            let value = transform_to_marginal(n as f64, params.cov, z, params.distribution);
+            blow.n = Some(NValue::Value(value.round().max(1.0) as i32));
+        }
+    }
+
+    Ok(result)
+}
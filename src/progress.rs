@@ -0,0 +1,87 @@
+use std::sync::{
+    atomic::{AtomicBool, AtomicUsize, Ordering},
+    Arc,
+};
+
+use crate::validation::ValidationError;
+
+#[derive(Debug, Default)]
+struct ProgressState {
+    completed: AtomicUsize,
+    total: AtomicUsize,
+    cancelled: AtomicBool,
+}
+
+/// A thread-safe handle for reporting progress of, and cancelling, long-running analyses.
+///
+/// Cloning the handle shares the same state, so a host (e.g. a GUI) can keep one clone to read
+/// the progress or request cancellation while the analysis works with another clone.
+#[derive(Debug, Clone, Default)]
+pub struct ProgressHandle {
+    state: Arc<ProgressState>,
+}
+
+impl ProgressHandle {
+    /// Creates a new handle for a job with the given number of steps.
+    ///
+    /// # Arguments
+    /// * `total` - Total number of steps of the job.
+    pub fn new(total: usize) -> Self {
+        let handle = Self::default();
+        handle.set_total(total);
+        handle
+    }
+
+    /// Sets the total number of steps and resets the completed count.
+    pub fn set_total(&self, total: usize) {
+        self.state.total.store(total, Ordering::SeqCst);
+        self.state.completed.store(0, Ordering::SeqCst);
+    }
+
+    /// Marks a number of steps as completed.
+    pub fn advance(&self, steps: usize) {
+        self.state.completed.fetch_add(steps, Ordering::SeqCst);
+    }
+
+    /// Returns the number of completed steps.
+    pub fn completed(&self) -> usize {
+        self.state.completed.load(Ordering::SeqCst)
+    }
+
+    /// Returns the total number of steps.
+    pub fn total(&self) -> usize {
+        self.state.total.load(Ordering::SeqCst)
+    }
+
+    /// Returns the completed fraction of the job between 0 and 1.
+    pub fn fraction(&self) -> f64 {
+        let total = self.total();
+        if total == 0 {
+            return 0.0;
+        }
+        (self.completed() as f64 / total as f64).min(1.0)
+    }
+
+    /// Requests cancellation of the job.
+    pub fn cancel(&self) {
+        self.state.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Returns whether cancellation has been requested.
+    pub fn is_cancelled(&self) -> bool {
+        self.state.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Returns an error if cancellation has been requested.
+    ///
+    /// Long-running analyses call this between steps and propagate the error with `?`.
+    pub fn check_cancelled(&self) -> Result<(), ValidationError> {
+        if self.is_cancelled() {
+            return Err(ValidationError {
+                code: "analysis.cancelled".to_string(),
+                message: "The analysis was cancelled.".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
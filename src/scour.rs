@@ -0,0 +1,302 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bearing_capacity::{model::BearingCapacityResult, vesic},
+    constants::GRAVITY,
+    enums::{AnalysisTerm, BedCondition, PierNoseShape, PressureConvention},
+    horizontal_sliding::{calc_horizontal_sliding, HorizontalSlidingResult},
+    models::{
+        foundation::Foundation,
+        loads::Loads,
+        scour::{FlowConditions, Pier},
+        soil_profile::SoilProfile,
+    },
+    validation::{validate_field, ValidationError},
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScourResult {
+    /// Froude number of the approach flow.
+    pub froude_number: f64,
+    /// Correction factor for pier nose shape K1.
+    pub k1: f64,
+    /// Correction factor for angle of attack K2.
+    pub k2: f64,
+    /// Correction factor for bed condition K3.
+    pub k3: f64,
+    /// Local pier scour depth (m).
+    pub local_scour_depth: f64,
+    /// Contraction scour depth (m).
+    pub contraction_scour_depth: f64,
+    /// Total scour depth (m).
+    pub total_scour_depth: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PostScourResult {
+    /// Soil profile after removing the scoured layers.
+    pub scoured_profile: SoilProfile,
+    /// Foundation with its embedment reduced by the scour depth.
+    pub scoured_foundation: Foundation,
+    /// Bearing capacity (Vesic) on the scoured profile.
+    pub bearing_capacity: BearingCapacityResult,
+    /// Horizontal sliding check on the scoured profile.
+    pub horizontal_sliding: HorizontalSlidingResult,
+}
+
+/// Returns the correction factor for pier nose shape K1.
+///
+/// The factor is taken as 1.0 when the angle of attack is greater than 5°.
+///
+/// # Arguments
+/// * `nose_shape` - Shape of the pier nose.
+/// * `attack_angle` - Angle of attack of the flow (degrees).
+///
+/// # Returns
+/// * `f64`: K1.
+pub fn get_shape_factor(nose_shape: PierNoseShape, attack_angle: f64) -> f64 {
+    if attack_angle > 5.0 {
+        return 1.0;
+    }
+    match nose_shape {
+        PierNoseShape::Square => 1.1,
+        PierNoseShape::Round | PierNoseShape::Circular | PierNoseShape::GroupOfCylinders => 1.0,
+        PierNoseShape::Sharp => 0.9,
+    }
+}
+
+/// Calculates the correction factor for angle of attack K2 = (cos θ + L/a · sin θ)^0.65.
+///
+/// # Arguments
+/// * `width` - Width of the pier (m).
+/// * `length` - Length of the pier (m).
+/// * `attack_angle` - Angle of attack of the flow (degrees).
+///
+/// # Returns
+/// * `f64`: K2.
+pub fn calc_attack_angle_factor(width: f64, length: f64, attack_angle: f64) -> f64 {
+    let theta = attack_angle.to_radians();
+    let length_ratio = (length / width).min(12.0);
+    (theta.cos() + length_ratio * theta.sin()).powf(0.65)
+}
+
+/// Returns the correction factor for bed condition K3.
+///
+/// # Arguments
+/// * `bed_condition` - Bed condition of the channel.
+///
+/// # Returns
+/// * `f64`: K3.
+pub fn get_bed_condition_factor(bed_condition: BedCondition) -> f64 {
+    match bed_condition {
+        BedCondition::ClearWater | BedCondition::PlaneBed | BedCondition::SmallDunes => 1.1,
+        BedCondition::MediumDunes => 1.2,
+        BedCondition::LargeDunes => 1.3,
+    }
+}
+
+/// Calculates the clear-water contraction scour depth (Laursen, 1963).
+///
+/// y2 = (0.025 · Q² / (Dm^(2/3) · W²))^(3/7), with Dm = 1.25 · D50
+///
+/// # Arguments
+/// * `discharge` - Discharge through the contracted section (m³/s).
+/// * `width` - Bottom width of the contracted section (m).
+/// * `median_grain_size` - Median grain size D50 (mm).
+/// * `flow_depth` - Existing flow depth in the contracted section (m).
+///
+/// # Returns
+/// * `f64`: Contraction scour depth (m).
+pub fn calc_contraction_scour(
+    discharge: f64,
+    width: f64,
+    median_grain_size: f64,
+    flow_depth: f64,
+) -> f64 {
+    let dm = 1.25 * median_grain_size / 1000.0;
+    let y2 = (0.025 * discharge.powi(2) / (dm.powf(2.0 / 3.0) * width.powi(2))).powf(3.0 / 7.0);
+    (y2 - flow_depth).max(0.0)
+}
+
+/// Validates the input data for scour calculations.
+///
+/// # Arguments
+/// * `pier` - The pier data.
+/// * `flow` - The flow conditions.
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(pier: &Pier, flow: &FlowConditions) -> Result<(), ValidationError> {
+    pier.validate(&["width", "length", "attack_angle"])?;
+    flow.validate(&["flow_depth", "velocity"])?;
+
+    if flow.contracted_discharge.is_some() {
+        flow.validate(&[
+            "contracted_discharge",
+            "contracted_width",
+            "median_grain_size",
+        ])?;
+    }
+
+    Ok(())
+}
+
+/// Calculates local pier scour and contraction scour depths (HEC-18).
+///
+/// The local scour is obtained from the CSU equation:
+///
+/// ys / a = 2.0 · K1 · K2 · K3 · (y1 / a)^0.35 · Fr^0.43
+///
+/// For round nosed piers aligned with the flow, the local scour depth is limited to 2.4a for
+/// Fr ≤ 0.8 and 3.0a otherwise.
+///
+/// # Arguments
+/// * `pier` - The pier data.
+/// * `flow` - The flow conditions.
+///
+/// # Returns
+/// * `ScourResult` with local, contraction and total scour depths.
+pub fn calc_scour_depth(
+    pier: &Pier,
+    flow: &FlowConditions,
+) -> Result<ScourResult, ValidationError> {
+    validate_input(pier, flow)?;
+
+    let a = pier.width.unwrap();
+    let attack_angle = pier.attack_angle.unwrap();
+    let y1 = flow.flow_depth.unwrap();
+    let v1 = flow.velocity.unwrap();
+
+    let froude_number = v1 / (GRAVITY * y1).sqrt();
+
+    let k1 = get_shape_factor(pier.nose_shape, attack_angle);
+    let k2 = calc_attack_angle_factor(a, pier.length.unwrap(), attack_angle);
+    let k3 = get_bed_condition_factor(flow.bed_condition);
+
+    let mut local_scour_depth =
+        2.0 * k1 * k2 * k3 * a * (y1 / a).powf(0.35) * froude_number.powf(0.43);
+
+    let is_round = matches!(
+        pier.nose_shape,
+        PierNoseShape::Round | PierNoseShape::Circular
+    );
+    if is_round && attack_angle <= 5.0 {
+        let limit = if froude_number <= 0.8 { 2.4 } else { 3.0 };
+        local_scour_depth = local_scour_depth.min(limit * a);
+    }
+
+    let contraction_scour_depth = match flow.contracted_discharge {
+        Some(discharge) => calc_contraction_scour(
+            discharge,
+            flow.contracted_width.unwrap(),
+            flow.median_grain_size.unwrap(),
+            y1,
+        ),
+        None => 0.0,
+    };
+
+    Ok(ScourResult {
+        froude_number,
+        k1,
+        k2,
+        k3,
+        local_scour_depth,
+        contraction_scour_depth,
+        total_scour_depth: local_scour_depth + contraction_scour_depth,
+    })
+}
+
+/// Generates the soil profile remaining after scour.
+///
+/// Layers entirely within the scour depth are removed, the first remaining layer is thinned and
+/// the ground water level is shifted to the new ground surface. The scoured soil no longer loads
+/// the profile, so no overburden is kept.
+///
+/// # Arguments
+/// * `soil_profile` - The original soil profile.
+/// * `scour_depth` - Total scour depth (m).
+///
+/// # Returns
+/// * `SoilProfile`: The scoured soil profile.
+pub fn generate_scoured_profile(
+    soil_profile: &SoilProfile,
+    scour_depth: f64,
+) -> Result<SoilProfile, ValidationError> {
+    soil_profile.validate(&["thickness"])?;
+    validate_field("scour_depth", Some(scour_depth), Some(0.0), None, "scour")?;
+
+    if scour_depth >= soil_profile.get_total_depth() - 1e-9 {
+        return Err(ValidationError {
+            code: "scour.scour_depth.greater_than_soil_profile_depth".to_string(),
+            message: "Scour depth is greater than the soil profile depth.".to_string(),
+        });
+    }
+
+    let mut profile = soil_profile.slice_below(scour_depth)?;
+    profile.overburden_stress = None;
+    profile.overburden_pore_pressure = None;
+    Ok(profile)
+}
+
+/// Re-runs the bearing capacity (Vesic) and horizontal sliding checks after scour.
+///
+/// The scoured profile is generated from `scour_depth` and the foundation depth is reduced by
+/// the same amount, since the foundation depth is measured from the original ground surface.
+///
+/// # Arguments
+/// * `soil_profile` - The original soil profile.
+/// * `foundation` - The foundation data.
+/// * `loading` - The applied loads.
+/// * `foundation_pressure` - The pressure on the foundation (t/m²).
+/// * `factor_of_safety` - The safety factor of the bearing capacity.
+/// * `term` - Short or long-term condition.
+/// * `scour_depth` - Total scour depth (m).
+///
+/// # Returns
+/// * `PostScourResult` with the scoured profile and the re-run analyses.
+pub fn calc_post_scour_stability(
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    loading: &Loads,
+    foundation_pressure: f64,
+    factor_of_safety: f64,
+    term: AnalysisTerm,
+    scour_depth: f64,
+) -> Result<PostScourResult, ValidationError> {
+    foundation.validate(&["foundation_depth"])?;
+
+    let foundation_depth = foundation.foundation_depth.unwrap();
+    if scour_depth >= foundation_depth {
+        return Err(ValidationError {
+            code: "scour.foundation.undermined".to_string(),
+            message: "Scour depth reaches the foundation base.".to_string(),
+        });
+    }
+
+    let mut scoured_profile = generate_scoured_profile(soil_profile, scour_depth)?;
+    let mut scoured_foundation = foundation.clone();
+    scoured_foundation.foundation_depth = Some(foundation_depth - scour_depth);
+
+    let bearing_capacity = vesic::calc_bearing_capacity(
+        &mut scoured_profile,
+        &mut scoured_foundation,
+        loading,
+        foundation_pressure,
+        PressureConvention::Gross,
+        factor_of_safety,
+        term,
+    )?;
+    let horizontal_sliding = calc_horizontal_sliding(
+        &scoured_profile,
+        &scoured_foundation,
+        loading,
+        foundation_pressure,
+    )?;
+
+    Ok(PostScourResult {
+        scoured_profile,
+        scoured_foundation,
+        bearing_capacity,
+        horizontal_sliding,
+    })
+}
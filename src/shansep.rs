@@ -0,0 +1,195 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    models::soil_profile::SoilProfile,
+    validation::{validate_field, ValidationError},
+};
+
+/// Coefficients of the SHANSEP strength relation su / σ'v = S · OCR^m.
+///
+/// The default values are the typical values of Ladd (1991) for homogeneous clays in direct
+/// simple shear and should be calibrated against laboratory tests where available.
+///
+/// # Fields
+/// * `s` - Normally consolidated strength ratio S.
+/// * `m` - Strength gain exponent m.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ShansepParams {
+    pub s: f64,
+    pub m: f64,
+}
+
+impl Default for ShansepParams {
+    fn default() -> Self {
+        Self { s: 0.22, m: 0.8 }
+    }
+}
+
+/// SHANSEP strength of a single soil layer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShansepLayer {
+    /// Bottom depth of the layer (m).
+    pub depth: f64,
+    /// Vertical effective stress at the layer center, including the consolidated load (t/m²).
+    pub effective_stress: f64,
+    /// Preconsolidation pressure after loading (t/m²).
+    pub preconsolidation_pressure: f64,
+    /// Overconsolidation ratio σ'p / σ'v.
+    pub ocr: f64,
+    /// Undrained shear strength su (t/m²).
+    pub cu: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShansepResult {
+    /// Vertical stress increase applied to the profile (t/m²).
+    pub stress_increase: f64,
+    /// Average degree of consolidation under the stress increase (0 to 1).
+    pub degree_of_consolidation: f64,
+    /// Results of the cohesive layers.
+    pub layers: Vec<ShansepLayer>,
+    /// Copy of the soil profile with the SHANSEP `cu` and updated preconsolidation pressures.
+    pub strengthened_profile: SoilProfile,
+}
+
+/// Calculates the overconsolidation ratio, which is at least 1.
+///
+/// # Arguments
+/// * `effective_stress` - Vertical effective stress σ'v (t/m²).
+/// * `preconsolidation_pressure` - Preconsolidation pressure σ'p (t/m²).
+///
+/// # Returns
+/// * `f64`: OCR = max(σ'p / σ'v, 1).
+pub fn calc_ocr(effective_stress: f64, preconsolidation_pressure: f64) -> f64 {
+    (preconsolidation_pressure / effective_stress).max(1.0)
+}
+
+/// Calculates the undrained shear strength su = S · σ'v · OCR^m (Ladd & Foott, 1974).
+///
+/// # Arguments
+/// * `effective_stress` - Vertical effective stress σ'v (t/m²).
+/// * `ocr` - Overconsolidation ratio.
+/// * `params` - SHANSEP coefficients.
+///
+/// # Returns
+/// * `f64`: Undrained shear strength (t/m²).
+pub fn calc_undrained_strength(effective_stress: f64, ocr: f64, params: &ShansepParams) -> f64 {
+    params.s * effective_stress * ocr.powf(params.m)
+}
+
+/// Validates the input data for SHANSEP strength profiles.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `stress_increase` - Vertical stress increase (t/m²).
+/// * `degree_of_consolidation` - Average degree of consolidation (0 to 1).
+/// * `params` - SHANSEP coefficients.
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(
+    soil_profile: &SoilProfile,
+    stress_increase: f64,
+    degree_of_consolidation: f64,
+    params: &ShansepParams,
+) -> Result<(), ValidationError> {
+    soil_profile.validate(&["thickness", "dry_unit_weight", "saturated_unit_weight"])?;
+    validate_field(
+        "stress_increase",
+        Some(stress_increase),
+        Some(0.0),
+        None,
+        "shansep",
+    )?;
+    validate_field(
+        "degree_of_consolidation",
+        Some(degree_of_consolidation),
+        Some(0.0),
+        Some(1.0),
+        "shansep",
+    )?;
+    validate_field("s", Some(params.s), Some(0.0001), None, "shansep")?;
+    validate_field("m", Some(params.m), Some(0.0), Some(1.0), "shansep")?;
+
+    Ok(())
+}
+
+/// Calculates the SHANSEP undrained shear strength profile after partial consolidation.
+///
+/// At the center of each cohesive layer the vertical effective stress grows by U · Δσ under a
+/// wide load such as an embankment, and the preconsolidation pressure is raised to the new
+/// effective stress when exceeded:
+///
+/// * σ'v = σ'v0 + U · Δσ
+/// * σ'p = max(σ'p0, σ'v)
+/// * su = S · σ'v · (σ'p / σ'v)^m
+///
+/// Layers without a preconsolidation pressure are taken as normally consolidated. Granular and
+/// rock layers are left unchanged. For staged construction, pass the returned profile with the
+/// cumulative stress increase of the next stage, so the stress history of the earlier stages
+/// is kept in the preconsolidation pressures.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `stress_increase` - Vertical stress increase Δσ of the load (t/m²).
+/// * `degree_of_consolidation` - Average degree of consolidation U under the load (0 to 1).
+/// * `params` - SHANSEP coefficients.
+///
+/// # Returns
+/// * `ShansepResult` with per-layer results and the strengthened profile.
+pub fn calc_shansep_profile(
+    soil_profile: &SoilProfile,
+    stress_increase: f64,
+    degree_of_consolidation: f64,
+    params: &ShansepParams,
+) -> Result<ShansepResult, ValidationError> {
+    validate_input(
+        soil_profile,
+        stress_increase,
+        degree_of_consolidation,
+        params,
+    )?;
+
+    let mut strengthened_profile = soil_profile.clone();
+    strengthened_profile.calc_layer_depths();
+
+    let initial_stresses: Vec<f64> = strengthened_profile
+        .layers
+        .iter()
+        .map(|layer| strengthened_profile.calc_effective_stress(layer.center.unwrap()))
+        .collect();
+
+    let mut layers = Vec::new();
+
+    for (layer, initial_stress) in strengthened_profile.layers.iter_mut().zip(initial_stresses) {
+        if layer.is_rock() || !layer.is_cohesive() {
+            continue;
+        }
+
+        let effective_stress = initial_stress + degree_of_consolidation * stress_increase;
+        let preconsolidation_pressure = layer
+            .preconsolidation_pressure
+            .unwrap_or(initial_stress)
+            .max(effective_stress);
+        let ocr = calc_ocr(effective_stress, preconsolidation_pressure);
+        let cu = calc_undrained_strength(effective_stress, ocr, params);
+
+        layer.cu = Some(cu);
+        layer.preconsolidation_pressure = Some(preconsolidation_pressure);
+
+        layers.push(ShansepLayer {
+            depth: layer.depth.unwrap(),
+            effective_stress,
+            preconsolidation_pressure,
+            ocr,
+            cu,
+        });
+    }
+
+    Ok(ShansepResult {
+        stress_increase,
+        degree_of_consolidation,
+        layers,
+        strengthened_profile,
+    })
+}
@@ -1,3 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cyclic_degradation::calc_degradation_index,
+    validation::{validate_field, ValidationError},
+};
+
 /// Calculates the soil coefficient based on settlement and foundation load.
 /// Returns a high value (999_999.0) if settlement is zero or negative to avoid division by zero.
 ///
@@ -27,3 +34,133 @@ pub fn calc_by_settlement(settlement: f64, foundation_pressure: f64) -> f64 {
 pub fn calc_by_bearing_capacity(bearing_capacity: f64) -> f64 {
     400.0 * bearing_capacity // units: t/m³
 }
+
+/// Coefficients of the stiffness degradation of the soil coefficient under repeated loading.
+///
+/// The default values are indicative for crane runways and machine bases on medium stiff soils
+/// and should be calibrated against repeated plate load tests where available.
+///
+/// # Fields
+/// * `t` - Degradation parameter of the cycle reduction N^(−t).
+/// * `reference_strain` - Reference shear strain γr of the hyperbolic strain reduction (%).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct KsDegradationParams {
+    pub t: f64,
+    pub reference_strain: f64,
+}
+
+impl Default for KsDegradationParams {
+    fn default() -> Self {
+        Self {
+            t: 0.08,
+            reference_strain: 0.1,
+        }
+    }
+}
+
+/// Represents the soil coefficient degraded by repeated loading.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KsDegradation {
+    /// Soil coefficient under first loading (t/m³).
+    pub ks: f64,
+    /// Number of load repetitions.
+    pub number_of_cycles: f64,
+    /// Cyclic shear strain amplitude (%).
+    pub shear_strain: f64,
+    /// Reduction factor of the load repetitions N^(−t).
+    pub cycle_factor: f64,
+    /// Reduction factor of the strain level 1 / (1 + γ / γr).
+    pub strain_factor: f64,
+    /// Degraded soil coefficient (t/m³).
+    pub degraded_ks: f64,
+}
+
+/// Calculates the modulus reduction factor of the strain level, 1 / (1 + γ / γr)
+/// (Hardin & Drnevich, 1972).
+///
+/// # Arguments
+/// * `shear_strain` - Cyclic shear strain amplitude γ (%).
+/// * `reference_strain` - Reference shear strain γr (%).
+///
+/// # Returns
+/// * The reduction factor between 0 and 1.
+pub fn calc_strain_reduction_factor(shear_strain: f64, reference_strain: f64) -> f64 {
+    1.0 / (1.0 + shear_strain / reference_strain)
+}
+
+/// Validates the input data for the degradation of the soil coefficient.
+///
+/// # Arguments
+/// * `ks` - Soil coefficient under first loading (t/m³).
+/// * `number_of_cycles` - Number of load repetitions.
+/// * `shear_strain` - Cyclic shear strain amplitude (%).
+/// * `params` - Degradation coefficients.
+pub fn validate_degradation_input(
+    ks: f64,
+    number_of_cycles: f64,
+    shear_strain: f64,
+    params: &KsDegradationParams,
+) -> Result<(), ValidationError> {
+    validate_field("ks", Some(ks), Some(0.0001), None, "soil_coefficient")?;
+    validate_field(
+        "number_of_cycles",
+        Some(number_of_cycles),
+        Some(1.0),
+        None,
+        "soil_coefficient",
+    )?;
+    validate_field(
+        "shear_strain",
+        Some(shear_strain),
+        Some(0.0),
+        None,
+        "soil_coefficient",
+    )?;
+    validate_field("t", Some(params.t), Some(0.0), None, "soil_coefficient")?;
+    validate_field(
+        "reference_strain",
+        Some(params.reference_strain),
+        Some(0.0001),
+        None,
+        "soil_coefficient",
+    )?;
+    Ok(())
+}
+
+/// Calculates the soil coefficient softened by repeated loading, e.g. under crane runways and
+/// machine bases.
+///
+/// The soil coefficient is proportional to the soil modulus, so it is reduced by the degradation
+/// index of the load repetitions (Idriss et al., 1978) and by the hyperbolic modulus reduction of
+/// the strain level:
+///
+/// ks,N = ks · N^(−t) · 1 / (1 + γ / γr)
+///
+/// # Arguments
+/// * `ks` - Soil coefficient under first loading (t/m³).
+/// * `number_of_cycles` - Number of load repetitions (N ≥ 1).
+/// * `shear_strain` - Cyclic shear strain amplitude γ (%).
+/// * `params` - Degradation coefficients.
+///
+/// # Returns
+/// * `KsDegradation` with the reduction factors and the degraded soil coefficient.
+pub fn calc_cyclic_degradation(
+    ks: f64,
+    number_of_cycles: f64,
+    shear_strain: f64,
+    params: &KsDegradationParams,
+) -> Result<KsDegradation, ValidationError> {
+    validate_degradation_input(ks, number_of_cycles, shear_strain, params)?;
+
+    let cycle_factor = calc_degradation_index(number_of_cycles, params.t);
+    let strain_factor = calc_strain_reduction_factor(shear_strain, params.reference_strain);
+
+    Ok(KsDegradation {
+        ks,
+        number_of_cycles,
+        shear_strain,
+        cycle_factor,
+        strain_factor,
+        degraded_ks: ks * cycle_factor * strain_factor,
+    })
+}
@@ -0,0 +1,234 @@
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+use crate::{
+    constants::KPA_TO_TON,
+    diagnostics::{check_limits, Warning},
+    enums::{MethodId, SoilType},
+    validation::{validate_field, ValidationError},
+};
+
+/// Stroud (1974) coefficient relating undrained shear strength to N60 (kPa).
+const STROUD_F1: f64 = 4.5;
+
+/// Adhesion factor on cu for the grout/clay bond of tiebacks.
+const BOND_ADHESION_FACTOR: f64 = 0.5;
+
+/// Bond stress per blow in granular soils (kPa).
+const GRANULAR_BOND_PER_BLOW: f64 = 5.0;
+
+/// Upper limit of the bond stress in granular soils (kPa).
+const MAX_GRANULAR_BOND: f64 = 380.0;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SptAnchorResult {
+    /// Ultimate unit resistance, bond stress for tiebacks or helix bearing for screw anchors (t/m²).
+    pub unit_resistance: f64,
+    /// Ultimate pullout capacity (t).
+    pub ultimate_capacity: f64,
+    /// Allowable pullout capacity (t).
+    pub allowable_capacity: f64,
+    /// Method used for the calculation.
+    pub method: MethodId,
+    /// Warnings for inputs outside the calibration range of the method.
+    pub warnings: Vec<Warning>,
+}
+
+/// Validates the common inputs of the SPT based anchor correlations.
+///
+/// # Arguments
+/// * `n60` - Corrected SPT blow count N60.
+/// * `soil_type` - Soil type around the anchor.
+/// * `factor_of_safety` - Safety factor on the pullout capacity.
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(
+    n60: f64,
+    soil_type: SoilType,
+    factor_of_safety: f64,
+) -> Result<(), ValidationError> {
+    validate_field("n60", Some(n60), Some(0.0), None, "spt_anchor")?;
+    validate_field(
+        "factor_of_safety",
+        Some(factor_of_safety),
+        Some(1.0),
+        None,
+        "spt_anchor",
+    )?;
+
+    if soil_type == SoilType::Rock {
+        return Err(ValidationError {
+            code: "spt_anchor.soil_type.rock".to_string(),
+            message: "SPT based anchor correlations are not applicable to rock.".to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Returns the ultimate grout/ground bond stress of a tieback from the SPT blow count.
+///
+/// * Cohesive: τ = α · cu with α = 0.5 and cu = 4.5 · N60 kPa (Stroud, 1974).
+/// * Granular: τ = 5 · N60 kPa, limited to 380 kPa, within the ranges of FHWA-IF-99-015 for
+///   gravity and low pressure grouted anchors.
+///
+/// # Arguments
+/// * `n60` - Corrected SPT blow count N60.
+/// * `soil_type` - Soil type around the fixed length.
+///
+/// # Returns
+/// * `f64`: Ultimate bond stress (t/m²).
+pub fn get_bond_stress_from_spt(n60: f64, soil_type: SoilType) -> f64 {
+    let bond_stress = match soil_type {
+        SoilType::Cohesive => BOND_ADHESION_FACTOR * STROUD_F1 * n60,
+        _ => (GRANULAR_BOND_PER_BLOW * n60).min(MAX_GRANULAR_BOND),
+    };
+
+    bond_stress * KPA_TO_TON
+}
+
+/// Returns the ultimate bearing pressure of a helix of a screw anchor from the SPT blow count.
+///
+/// * Cohesive: q = 9 · cu with cu = 4.5 · N60 kPa (Stroud, 1974).
+/// * Granular: q = 40 · N60 · H / D ≤ 400 · N60 kPa (Meyerhof, 1976).
+///
+/// # Arguments
+/// * `n60` - Corrected SPT blow count N60.
+/// * `soil_type` - Soil type around the helix.
+/// * `helix_diameter` - Diameter of the helix (m).
+/// * `helix_depth` - Depth of the helix below the ground surface (m).
+///
+/// # Returns
+/// * `f64`: Ultimate helix bearing pressure (t/m²).
+pub fn get_helix_bearing_from_spt(
+    n60: f64,
+    soil_type: SoilType,
+    helix_diameter: f64,
+    helix_depth: f64,
+) -> f64 {
+    let bearing = match soil_type {
+        SoilType::Cohesive => 9.0 * STROUD_F1 * n60,
+        _ => (40.0 * n60 * helix_depth / helix_diameter).min(400.0 * n60),
+    };
+
+    bearing * KPA_TO_TON
+}
+
+/// Estimates the pullout capacity of a grouted tieback from the SPT blow count for preliminary
+/// design.
+///
+/// Qult = π · D · Lb · τ
+///
+/// # Arguments
+/// * `n60` - Corrected SPT blow count N60 along the fixed length.
+/// * `soil_type` - Soil type around the fixed length.
+/// * `drill_hole_diameter` - Diameter of the grouted drill hole (m).
+/// * `fixed_length` - Fixed (bonded) length (m).
+/// * `factor_of_safety` - Safety factor on the pullout capacity.
+///
+/// # Returns
+/// * `SptAnchorResult` with the ultimate and allowable capacities.
+pub fn calc_tieback_capacity(
+    n60: f64,
+    soil_type: SoilType,
+    drill_hole_diameter: f64,
+    fixed_length: f64,
+    factor_of_safety: f64,
+) -> Result<SptAnchorResult, ValidationError> {
+    validate_input(n60, soil_type, factor_of_safety)?;
+    validate_field(
+        "drill_hole_diameter",
+        Some(drill_hole_diameter),
+        Some(0.0001),
+        None,
+        "spt_anchor",
+    )?;
+    validate_field(
+        "fixed_length",
+        Some(fixed_length),
+        Some(0.0001),
+        None,
+        "spt_anchor",
+    )?;
+
+    let unit_resistance = get_bond_stress_from_spt(n60, soil_type);
+    let ultimate_capacity = PI * drill_hole_diameter * fixed_length * unit_resistance;
+
+    Ok(SptAnchorResult {
+        unit_resistance,
+        ultimate_capacity,
+        allowable_capacity: ultimate_capacity / factor_of_safety,
+        method: MethodId::SptAnchor,
+        warnings: check_limits(MethodId::SptAnchor, &[("n60", n60)]),
+    })
+}
+
+/// Estimates the pullout capacity of a screw (helical) anchor from the SPT blow count for
+/// preliminary design.
+///
+/// The individual bearing method is used: each helix carries q · π · D² / 4, with the helices
+/// spaced at least three diameters apart and embedded deep enough for a deep failure mode.
+/// The shaft resistance is neglected.
+///
+/// # Arguments
+/// * `n60` - Corrected SPT blow count N60 around the helices.
+/// * `soil_type` - Soil type around the helices.
+/// * `helix_diameters` - Diameters of the helices (m).
+/// * `helix_depth` - Depth of the shallowest helix below the ground surface (m).
+/// * `factor_of_safety` - Safety factor on the pullout capacity.
+///
+/// # Returns
+/// * `SptAnchorResult` with the ultimate and allowable capacities. The unit resistance is that
+///   of the first helix.
+pub fn calc_screw_anchor_capacity(
+    n60: f64,
+    soil_type: SoilType,
+    helix_diameters: &[f64],
+    helix_depth: f64,
+    factor_of_safety: f64,
+) -> Result<SptAnchorResult, ValidationError> {
+    validate_input(n60, soil_type, factor_of_safety)?;
+    validate_field(
+        "helix_depth",
+        Some(helix_depth),
+        Some(0.0001),
+        None,
+        "spt_anchor",
+    )?;
+    if helix_diameters.is_empty() {
+        return Err(ValidationError {
+            code: "spt_anchor.helix_diameters.empty".to_string(),
+            message: "At least one helix diameter must be provided.".to_string(),
+        });
+    }
+    for &diameter in helix_diameters {
+        validate_field(
+            "helix_diameter",
+            Some(diameter),
+            Some(0.0001),
+            None,
+            "spt_anchor",
+        )?;
+    }
+
+    let unit_resistance =
+        get_helix_bearing_from_spt(n60, soil_type, helix_diameters[0], helix_depth);
+    let ultimate_capacity: f64 = helix_diameters
+        .iter()
+        .map(|&diameter| {
+            get_helix_bearing_from_spt(n60, soil_type, diameter, helix_depth)
+                * PI
+                * diameter.powi(2)
+                / 4.0
+        })
+        .sum();
+
+    Ok(SptAnchorResult {
+        unit_resistance,
+        ultimate_capacity,
+        allowable_capacity: ultimate_capacity / factor_of_safety,
+        method: MethodId::SptAnchor,
+        warnings: check_limits(MethodId::SptAnchor, &[("n60", n60)]),
+    })
+}
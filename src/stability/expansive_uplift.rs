@@ -0,0 +1,266 @@
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+use crate::{
+    enums::PressureConvention,
+    models::{foundation::Foundation, soil_profile::SoilProfile},
+    swelling_potential::calc_swelling_potential,
+    validation::{validate_field, ValidationError},
+};
+
+/// Parameters of the uplift check of footings on expansive soil.
+///
+/// The friction factors are the typical values of Nelson & Miller (1992) for drilled piers and
+/// should be replaced by local experience where available.
+///
+/// # Fields
+/// * `active_zone_depth` - Depth of the zone of seasonal moisture change (m).
+/// * `pier_diameter` - Diameter of the drilled pier considered to anchor a footing (m).
+/// * `uplift_friction_factor` - Ratio of the uplift side friction to the swelling pressure.
+/// * `adhesion_factor` - Ratio of the anchorage side friction to cu below the active zone.
+/// * `required_factor_of_safety` - Required safety factor against heave.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ExpansiveUpliftParams {
+    pub active_zone_depth: f64,
+    pub pier_diameter: f64,
+    pub uplift_friction_factor: f64,
+    pub adhesion_factor: f64,
+    pub required_factor_of_safety: f64,
+}
+
+impl Default for ExpansiveUpliftParams {
+    fn default() -> Self {
+        Self {
+            active_zone_depth: 3.0,
+            pier_diameter: 0.3,
+            uplift_friction_factor: 0.15,
+            adhesion_factor: 0.45,
+            required_factor_of_safety: 1.5,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExpansiveUpliftResult {
+    /// Index of the footing in the input.
+    pub footing_index: usize,
+    /// Dead load on the footing (t).
+    pub dead_load: f64,
+    /// Net swelling pressure lifting the footing base (t/m²).
+    pub uplift_pressure: f64,
+    /// Uplift force on the footing base (t).
+    pub uplift_force: f64,
+    /// Safety factor of the dead load against the uplift force.
+    pub factor_of_safety: f64,
+    /// Dead load needed to reach the required safety factor (t).
+    pub required_dead_load: f64,
+    /// Uplift friction on a pier through the active zone (t).
+    pub pier_uplift_force: f64,
+    /// Pier depth needed to anchor the footing below the active zone (m), `None` if the soil
+    /// profile is not deep enough.
+    pub required_pier_depth: Option<f64>,
+    /// Indicates whether the dead load alone resists the heave.
+    pub is_adequate: bool,
+}
+
+/// Validates the input data for the uplift check of footings on expansive soil.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `footings` - The footings to check.
+/// * `dead_loads` - Dead load on each footing (t).
+/// * `params` - Uplift check parameters.
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(
+    soil_profile: &SoilProfile,
+    footings: &[Foundation],
+    dead_loads: &[f64],
+    params: &ExpansiveUpliftParams,
+) -> Result<(), ValidationError> {
+    if footings.len() != dead_loads.len() {
+        return Err(ValidationError {
+            code: "expansive_uplift.dead_loads.invalid_length".into(),
+            message: "A dead load must be given for each footing.".into(),
+        });
+    }
+    for (footing, &dead_load) in footings.iter().zip(dead_loads) {
+        footing.validate(&["foundation_depth", "foundation_width", "foundation_length"])?;
+        validate_field(
+            "dead_load",
+            Some(dead_load),
+            Some(0.0),
+            None,
+            "expansive_uplift",
+        )?;
+        validate_field(
+            "active_zone_depth",
+            Some(params.active_zone_depth),
+            footing.foundation_depth,
+            None,
+            "expansive_uplift",
+        )?;
+    }
+    soil_profile.validate(&["thickness"])?;
+    validate_field(
+        "pier_diameter",
+        Some(params.pier_diameter),
+        Some(0.0001),
+        None,
+        "expansive_uplift",
+    )?;
+    validate_field(
+        "uplift_friction_factor",
+        Some(params.uplift_friction_factor),
+        Some(0.0),
+        Some(1.0),
+        "expansive_uplift",
+    )?;
+    validate_field(
+        "adhesion_factor",
+        Some(params.adhesion_factor),
+        Some(0.0),
+        Some(1.0),
+        "expansive_uplift",
+    )?;
+    validate_field(
+        "required_factor_of_safety",
+        Some(params.required_factor_of_safety),
+        Some(1.0),
+        None,
+        "expansive_uplift",
+    )?;
+
+    Ok(())
+}
+
+/// Calculates the depth a pier must reach to anchor a required force below the active zone.
+///
+/// The anchorage is the side adhesion α · cu · π · d of the layers below the active zone;
+/// layers without `cu` give no anchorage.
+fn calc_pier_depth(
+    soil_profile: &SoilProfile,
+    params: &ExpansiveUpliftParams,
+    required_anchorage: f64,
+) -> Option<f64> {
+    let za = params.active_zone_depth;
+    if required_anchorage <= 0.0 {
+        return Some(za);
+    }
+
+    let perimeter = PI * params.pier_diameter;
+    let mut remaining = required_anchorage;
+    let mut top: f64 = 0.0;
+    for layer in &soil_profile.layers {
+        let bottom = top + layer.thickness.unwrap();
+        let start = top.max(za);
+        if bottom > start {
+            let unit_anchorage = params.adhesion_factor * layer.cu.unwrap_or(0.0) * perimeter;
+            if unit_anchorage > 0.0 && unit_anchorage * (bottom - start) >= remaining {
+                return Some(start + remaining / unit_anchorage);
+            }
+            remaining -= unit_anchorage * (bottom - start);
+        }
+        top = bottom;
+    }
+
+    None
+}
+
+/// Checks lightly loaded footings on expansive soil against heave.
+///
+/// The swelling pressure of each layer is found with the Kayabalı & Yaldız (2014) method (see
+/// `calc_swelling_potential`). Within the active zone below the footing base, the part of the
+/// swelling pressure not balanced by the effective weight of the soil between the base and the
+/// layer lifts the footing:
+///
+/// * pu = max(ps − (σ'v(z) − σ'v(Df)))
+/// * FS = P / (pu · B · L)
+///
+/// As an alternative, the footing can be carried on a drilled pier with a void below the cap.
+/// The swelling soil then grips the shaft with the uplift force U = αu · π · d · Σ ps · h over
+/// the active zone, which the dead load and the adhesion below the active zone must resist with
+/// the required safety factor.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile with the swelling parameters of the layers.
+/// * `footings` - The footings to check.
+/// * `dead_loads` - Dead load on each footing (t).
+/// * `params` - Uplift check parameters.
+///
+/// # Returns
+/// * `Vec<ExpansiveUpliftResult>`: One result per footing, in the input order.
+pub fn calc_expansive_uplift(
+    soil_profile: &SoilProfile,
+    footings: &[Foundation],
+    dead_loads: &[f64],
+    params: &ExpansiveUpliftParams,
+) -> Result<Vec<ExpansiveUpliftResult>, ValidationError> {
+    validate_input(soil_profile, footings, dead_loads, params)?;
+
+    let mut profile = soil_profile.clone();
+    profile.calc_layer_depths();
+    let za = params.active_zone_depth;
+    let fs_required = params.required_factor_of_safety;
+
+    let mut results = Vec::new();
+
+    for (footing_index, (footing, &dead_load)) in footings.iter().zip(dead_loads).enumerate() {
+        let df = footing.foundation_depth.unwrap();
+        let area = footing.foundation_width.unwrap() * footing.foundation_length.unwrap();
+        let swelling = calc_swelling_potential(
+            &mut profile.clone(),
+            footing,
+            dead_load / area,
+            PressureConvention::Gross,
+        )?;
+        let base_stress = profile.calc_effective_stress(df);
+
+        let mut uplift_pressure: f64 = 0.0;
+        let mut shaft_pressure = 0.0;
+        let mut top: f64 = 0.0;
+        for (layer, data) in profile.layers.iter().zip(&swelling.data) {
+            let bottom = layer.depth.unwrap();
+            let (start, end) = (top.max(df), bottom.min(za));
+            top = bottom;
+            if end <= start {
+                continue;
+            }
+
+            let swelling_pressure = data.swelling_pressure.max(0.0);
+            let overburden = profile.calc_effective_stress((start + end) / 2.0) - base_stress;
+            uplift_pressure = uplift_pressure.max(swelling_pressure - overburden);
+            shaft_pressure += swelling_pressure * (end - start);
+        }
+
+        let uplift_force = uplift_pressure * area;
+        let factor_of_safety = if uplift_force > 0.0 {
+            dead_load / uplift_force
+        } else {
+            f64::INFINITY
+        };
+
+        let pier_uplift_force =
+            params.uplift_friction_factor * PI * params.pier_diameter * shaft_pressure;
+        let required_pier_depth = calc_pier_depth(
+            &profile,
+            params,
+            fs_required * pier_uplift_force - dead_load,
+        );
+
+        results.push(ExpansiveUpliftResult {
+            footing_index,
+            dead_load,
+            uplift_pressure,
+            uplift_force,
+            factor_of_safety,
+            required_dead_load: fs_required * uplift_force,
+            pier_uplift_force,
+            required_pier_depth,
+            is_adequate: factor_of_safety >= fs_required,
+        });
+    }
+
+    Ok(results)
+}
@@ -0,0 +1,4 @@
+pub mod expansive_uplift;
+pub mod overturning;
+pub mod rocking;
+pub mod uplift;
@@ -0,0 +1,129 @@
+use serde::Serialize;
+
+use crate::{
+    models::{foundation::Foundation, loads::Loads},
+    validation::{validate_field, ValidationError},
+};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OverturningResult {
+    /// Overturning moment about the footing edge parallel to the length (t·m).
+    pub overturning_moment_x: f64,
+    /// Resisting moment about the footing edge parallel to the length (t·m).
+    pub resisting_moment_x: f64,
+    /// Safety factor against overturning in the x-direction (along the width).
+    pub factor_of_safety_x: f64,
+    /// Overturning moment about the footing edge parallel to the width (t·m).
+    pub overturning_moment_y: f64,
+    /// Resisting moment about the footing edge parallel to the width (t·m).
+    pub resisting_moment_y: f64,
+    /// Safety factor against overturning in the y-direction (along the length).
+    pub factor_of_safety_y: f64,
+    /// Required safety factor against overturning.
+    pub required_factor_of_safety: f64,
+    /// Indicates whether the x-direction safety factor is at least the required value.
+    pub is_safe_x: bool,
+    /// Indicates whether the y-direction safety factor is at least the required value.
+    pub is_safe_y: bool,
+}
+
+/// Validates the input data for overturning checks.
+///
+/// # Arguments
+/// * `foundation` - The foundation data.
+/// * `loading` - The applied loads.
+/// * `required_factor_of_safety` - Required safety factor against overturning.
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(
+    foundation: &Foundation,
+    loading: &Loads,
+    required_factor_of_safety: f64,
+) -> Result<(), ValidationError> {
+    foundation.validate(&["foundation_depth", "foundation_width", "foundation_length"])?;
+    loading.validate(&["vertical_load"])?;
+    let optional_fields = [
+        ("moment_x", loading.moment_x),
+        ("moment_y", loading.moment_y),
+        ("horizontal_load_x", loading.horizontal_load_x),
+        ("horizontal_load_y", loading.horizontal_load_y),
+    ];
+    for (field, value) in optional_fields {
+        if value.is_some() {
+            loading.validate(&[field])?;
+        }
+    }
+    validate_field(
+        "required_factor_of_safety",
+        Some(required_factor_of_safety),
+        Some(1.0),
+        None,
+        "overturning",
+    )?;
+
+    Ok(())
+}
+
+/// Returns the ratio of the resisting to the overturning moment.
+fn calc_factor_of_safety(resisting_moment: f64, overturning_moment: f64) -> f64 {
+    if overturning_moment > 0.0 {
+        resisting_moment / overturning_moment
+    } else {
+        f64::INFINITY
+    }
+}
+
+/// Checks a spread footing against overturning about its edges.
+///
+/// In each direction the overturning moment is the applied moment plus the horizontal load
+/// acting at the ground surface, Df above the base, and the resisting moment is the vertical
+/// load acting at the centre of the footing:
+///
+/// * Mo,x = Mx + Hx · Df, Mr,x = V · B / 2
+/// * Mo,y = My + Hy · Df, Mr,y = V · L / 2
+///
+/// The vertical load should include the weight of the footing and the backfill above it.
+/// Missing moments and horizontal loads are taken as zero.
+///
+/// # Arguments
+/// * `foundation` - The foundation data.
+/// * `loading` - The applied loads.
+/// * `required_factor_of_safety` - Required safety factor against overturning.
+///
+/// # Returns
+/// * `OverturningResult` with the moments and safety factors in both directions.
+pub fn calc_overturning(
+    foundation: &Foundation,
+    loading: &Loads,
+    required_factor_of_safety: f64,
+) -> Result<OverturningResult, ValidationError> {
+    validate_input(foundation, loading, required_factor_of_safety)?;
+
+    let df = foundation.foundation_depth.unwrap();
+    let b = foundation.foundation_width.unwrap();
+    let l = foundation.foundation_length.unwrap();
+    let vertical_load = loading.vertical_load.unwrap();
+
+    let overturning_moment_x =
+        loading.moment_x.unwrap_or(0.0) + loading.horizontal_load_x.unwrap_or(0.0) * df;
+    let overturning_moment_y =
+        loading.moment_y.unwrap_or(0.0) + loading.horizontal_load_y.unwrap_or(0.0) * df;
+    let resisting_moment_x = vertical_load * b / 2.0;
+    let resisting_moment_y = vertical_load * l / 2.0;
+
+    let factor_of_safety_x = calc_factor_of_safety(resisting_moment_x, overturning_moment_x);
+    let factor_of_safety_y = calc_factor_of_safety(resisting_moment_y, overturning_moment_y);
+
+    Ok(OverturningResult {
+        overturning_moment_x,
+        resisting_moment_x,
+        factor_of_safety_x,
+        overturning_moment_y,
+        resisting_moment_y,
+        factor_of_safety_y,
+        required_factor_of_safety,
+        is_safe_x: factor_of_safety_x >= required_factor_of_safety,
+        is_safe_y: factor_of_safety_y >= required_factor_of_safety,
+    })
+}
@@ -0,0 +1,164 @@
+use serde::Serialize;
+
+use crate::{
+    bearing_capacity::vhm_envelope::{calc_utilization, VhmEnvelopeParams, VhmUtilizationResult},
+    models::{foundation::Foundation, loads::Loads},
+    validation::{validate_field, ValidationError},
+};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RockingResult {
+    /// Expected moment demand on the footing (t·m).
+    pub expected_moment: f64,
+    /// Rocking moment capacity (t·m).
+    pub moment_capacity: f64,
+    /// Ratio of the expected moment to the moment capacity.
+    pub moment_demand_capacity_ratio: f64,
+    /// Width of the footing in contact with the soil at the moment capacity (m).
+    pub critical_contact_width: f64,
+    /// Fraction of the footing width lifted off the soil under the expected moment.
+    pub uplift_ratio: f64,
+    /// Expected horizontal load on the footing (t).
+    pub expected_horizontal_load: f64,
+    /// Sliding capacity of the footing base (t).
+    pub sliding_capacity: f64,
+    /// Ratio of the expected horizontal load to the sliding capacity.
+    pub sliding_demand_capacity_ratio: f64,
+    /// Utilization of the combined load with respect to the V-H-M envelope.
+    pub vhm: VhmUtilizationResult,
+    /// Indicates whether the expected moment reaches the moment capacity, i.e. the footing rocks.
+    pub is_rocking: bool,
+    /// Indicates whether the expected horizontal load does not exceed the sliding capacity.
+    pub is_sliding_safe: bool,
+}
+
+/// Validates the input data for seismic rocking checks.
+///
+/// # Arguments
+/// * `vertical_capacity` - Expected ultimate vertical capacity, V0 (t).
+/// * `foundation` - The foundation data.
+/// * `loading` - The expected loads.
+/// * `phi` - Friction angle of the soil at the base (degrees).
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(
+    vertical_capacity: f64,
+    foundation: &Foundation,
+    loading: &Loads,
+    phi: f64,
+) -> Result<(), ValidationError> {
+    foundation.validate(&["foundation_width", "foundation_length"])?;
+    loading.validate(&["vertical_load", "horizontal_load_x", "moment_x"])?;
+    validate_field("phi", Some(phi), Some(0.0), Some(50.0), "rocking")?;
+    validate_field(
+        "vertical_load",
+        loading.vertical_load,
+        None,
+        Some(vertical_capacity),
+        "rocking",
+    )?;
+
+    Ok(())
+}
+
+/// Calculates the fraction of the footing width lifted off the soil under an eccentric load.
+///
+/// The soil pressure is linear and takes no tension, so the footing is in full contact while
+/// e = M / V ≤ B / 6 and the contact width is 3 · (B / 2 − e) beyond that.
+///
+/// # Arguments
+/// * `vertical_load` - Vertical load on the footing (t).
+/// * `moment` - Moment on the footing (t·m).
+/// * `width` - Footing width in the rocking direction (m).
+///
+/// # Returns
+/// * `f64`: Uplift ratio between 0 (full contact) and 1 (overturned).
+pub fn calc_uplift_ratio(vertical_load: f64, moment: f64, width: f64) -> f64 {
+    if vertical_load <= 0.0 {
+        return 1.0;
+    }
+    let e = moment.abs() / vertical_load;
+    if e <= width / 6.0 {
+        0.0
+    } else {
+        (1.0 - 3.0 * (width / 2.0 - e) / width).min(1.0)
+    }
+}
+
+/// Returns the ratio of a demand to a capacity.
+fn calc_demand_capacity_ratio(demand: f64, capacity: f64) -> f64 {
+    if capacity > 0.0 {
+        demand / capacity
+    } else if demand > 0.0 {
+        f64::INFINITY
+    } else {
+        0.0
+    }
+}
+
+/// Checks a shallow footing for rocking and sliding under seismic loads (ASCE 41-17, 8.4.2.3).
+///
+/// The footing is treated as rigid on a rigid-plastic soil. With the expected bearing pressure
+/// qc = V0 / (B · L), the rocking moment capacity and the critical contact width are
+///
+/// * Mc = B · P / 2 · (1 − q / qc), with q = P / (B · L)
+/// * Bc = P / (qc · L)
+///
+/// The sliding capacity is μ · P with μ from the base interface of the foundation (tan φ when
+/// neither a friction coefficient nor an interface type is set). The combined load is also
+/// checked against the V-H-M envelope of the footing.
+///
+/// # Arguments
+/// * `vertical_capacity` - Expected ultimate vertical capacity under pure vertical load, V0 (t).
+/// * `foundation` - The foundation data, with the width in the rocking direction.
+/// * `loading` - The expected loads (`vertical_load`, `horizontal_load_x`, `moment_x`).
+/// * `phi` - Friction angle of the soil at the base (degrees).
+/// * `params` - V-H-M envelope shape parameters.
+///
+/// # Returns
+/// * `RockingResult` with the rocking, uplift, sliding and envelope checks.
+pub fn calc_rocking(
+    vertical_capacity: f64,
+    foundation: &Foundation,
+    loading: &Loads,
+    phi: f64,
+    params: &VhmEnvelopeParams,
+) -> Result<RockingResult, ValidationError> {
+    validate_input(vertical_capacity, foundation, loading, phi)?;
+    let vhm = calc_utilization(vertical_capacity, foundation, loading, params)?;
+
+    let b = foundation.foundation_width.unwrap();
+    let l = foundation.foundation_length.unwrap();
+    let vertical_load = loading.vertical_load.unwrap();
+    let expected_moment = loading.moment_x.unwrap();
+    let expected_horizontal_load = loading.horizontal_load_x.unwrap();
+
+    let expected_pressure = vertical_capacity / (b * l);
+    let moment_capacity = b * vertical_load / 2.0 * (1.0 - vertical_load / vertical_capacity);
+    let critical_contact_width = vertical_load / (expected_pressure * l);
+    let uplift_ratio = calc_uplift_ratio(vertical_load, expected_moment, b);
+
+    let friction_coefficient = foundation
+        .calc_base_friction_coefficient(phi)
+        .unwrap_or(phi.to_radians().tan());
+    let sliding_capacity = friction_coefficient * vertical_load;
+
+    let moment_demand_capacity_ratio = calc_demand_capacity_ratio(expected_moment, moment_capacity);
+    let sliding_demand_capacity_ratio =
+        calc_demand_capacity_ratio(expected_horizontal_load, sliding_capacity);
+
+    Ok(RockingResult {
+        expected_moment,
+        moment_capacity,
+        moment_demand_capacity_ratio,
+        critical_contact_width,
+        uplift_ratio,
+        expected_horizontal_load,
+        sliding_capacity,
+        sliding_demand_capacity_ratio,
+        vhm,
+        is_rocking: moment_demand_capacity_ratio >= 1.0,
+        is_sliding_safe: sliding_demand_capacity_ratio <= 1.0,
+    })
+}
@@ -0,0 +1,172 @@
+use serde::Serialize;
+
+use crate::{
+    models::{foundation::Foundation, soil_profile::SoilProfile},
+    validation::{validate_field, ValidationError},
+};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UpliftResult {
+    /// Hydrostatic uplift pressure on the foundation base (t/m²).
+    pub uplift_pressure: f64,
+    /// Total uplift force on the foundation base (t).
+    pub uplift_force: f64,
+    /// Dead weight of the structure resisting the uplift (t).
+    pub dead_weight: f64,
+    /// Friction on the embedded side walls resisting the uplift (t).
+    pub side_friction: f64,
+    /// Safety factor against flotation from the dead weight and side friction.
+    pub factor_of_safety: f64,
+    /// Safety factor against flotation from the dead weight only.
+    pub factor_of_safety_without_friction: f64,
+    /// Required safety factor against flotation.
+    pub required_factor_of_safety: f64,
+    /// Indicates whether the safety factor is at least the required value.
+    pub is_safe: bool,
+}
+
+/// Validates the input data for uplift checks.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `foundation` - The foundation data.
+/// * `dead_weight` - Dead weight of the structure (t).
+/// * `required_factor_of_safety` - Required safety factor against flotation.
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    dead_weight: f64,
+    required_factor_of_safety: f64,
+) -> Result<(), ValidationError> {
+    soil_profile.validate(&[
+        "thickness",
+        "dry_unit_weight",
+        "saturated_unit_weight",
+        "phi_prime",
+    ])?;
+    foundation.validate(&["foundation_depth", "foundation_width", "foundation_length"])?;
+    validate_field("dead_weight", Some(dead_weight), Some(0.0), None, "uplift")?;
+    validate_field(
+        "required_factor_of_safety",
+        Some(required_factor_of_safety),
+        Some(1.0),
+        None,
+        "uplift",
+    )?;
+
+    Ok(())
+}
+
+/// Calculates the drained friction on the side walls of a buried foundation.
+///
+/// The unit friction K0 · σv' · tan(δ) with K0 = 1 − sin(φ') is integrated from the ground
+/// surface to the foundation base over the perimeter 2 · (B + L). The wall friction coefficient
+/// tan(δ) comes from the foundation interface, or δ = 2/3 · φ' when none is given. Since σv' is
+/// linear between layer boundaries and the groundwater level, the trapezoidal rule is exact.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `foundation` - The foundation data.
+///
+/// # Returns
+/// * `f64`: Side friction force (t).
+pub fn calc_side_friction(soil_profile: &SoilProfile, foundation: &Foundation) -> f64 {
+    let df = foundation.foundation_depth.unwrap();
+    let perimeter =
+        2.0 * (foundation.foundation_width.unwrap() + foundation.foundation_length.unwrap());
+    let gwt = soil_profile.ground_water_level.unwrap();
+
+    let mut breakpoints = vec![0.0, df];
+    let mut layer_bottom = 0.0;
+    for layer in &soil_profile.layers {
+        layer_bottom += layer.thickness.unwrap();
+        breakpoints.push(layer_bottom);
+    }
+    breakpoints.push(gwt);
+    breakpoints.retain(|&z| z >= 0.0 && z <= df);
+    breakpoints.sort_by(|a, b| a.total_cmp(b));
+    breakpoints.dedup();
+
+    let mut side_friction = 0.0;
+    for pair in breakpoints.windows(2) {
+        let (top, bottom) = (pair[0], pair[1]);
+        let phi = soil_profile
+            .get_layer_at_depth((top + bottom) / 2.0)
+            .phi_prime
+            .unwrap();
+        let k0 = 1.0 - phi.to_radians().sin();
+        let friction_coefficient = foundation
+            .calc_base_friction_coefficient(phi)
+            .unwrap_or_else(|| (2.0 / 3.0 * phi).to_radians().tan());
+        let mean_stress = (soil_profile.calc_effective_stress(top)
+            + soil_profile.calc_effective_stress(bottom))
+            / 2.0;
+
+        side_friction += k0 * friction_coefficient * mean_stress * (bottom - top);
+    }
+
+    side_friction * perimeter
+}
+
+/// Checks a basement or buried foundation against flotation from the groundwater level.
+///
+/// The hydrostatic uplift γw · (Df − GWT) · A on the base is resisted by the dead weight of the
+/// structure and the friction on the embedded side walls. The base area is the foundation area
+/// if given, B · L otherwise.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data with the groundwater level.
+/// * `foundation` - The foundation data.
+/// * `dead_weight` - Dead weight of the structure (t).
+/// * `required_factor_of_safety` - Required safety factor against flotation.
+///
+/// # Returns
+/// * `UpliftResult` with the uplift force and safety factors.
+pub fn calc_uplift(
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    dead_weight: f64,
+    required_factor_of_safety: f64,
+) -> Result<UpliftResult, ValidationError> {
+    validate_input(
+        soil_profile,
+        foundation,
+        dead_weight,
+        required_factor_of_safety,
+    )?;
+
+    let df = foundation.foundation_depth.unwrap();
+    let gwt = soil_profile.ground_water_level.unwrap();
+    let area = foundation
+        .foundation_area
+        .unwrap_or(foundation.foundation_width.unwrap() * foundation.foundation_length.unwrap());
+
+    let uplift_pressure = soil_profile
+        .get_environment()
+        .hydrostatic_pressure(df - gwt);
+    let uplift_force = uplift_pressure * area;
+    let side_friction = calc_side_friction(soil_profile, foundation);
+
+    let (factor_of_safety, factor_of_safety_without_friction) = if uplift_force > 0.0 {
+        (
+            (dead_weight + side_friction) / uplift_force,
+            dead_weight / uplift_force,
+        )
+    } else {
+        (f64::INFINITY, f64::INFINITY)
+    };
+
+    Ok(UpliftResult {
+        uplift_pressure,
+        uplift_force,
+        dead_weight,
+        side_friction,
+        factor_of_safety,
+        factor_of_safety_without_friction,
+        required_factor_of_safety,
+        is_safe: factor_of_safety >= required_factor_of_safety,
+    })
+}
@@ -0,0 +1,242 @@
+use std::f64::consts::PI;
+
+use serde::{Deserialize, Serialize};
+
+use crate::validation::{validate_field, ValidationError};
+
+/// Number of Simpson intervals used to integrate each polygon edge over its angle.
+const INTEGRATION_STEPS: usize = 64;
+
+/// Uniformly loaded area of arbitrary polygonal shape in plan.
+///
+/// # Fields
+/// * `vertices` - Corner coordinates (x, y) of the polygon in order, without repeating the first
+///   vertex (m).
+/// * `pressure` - Uniform pressure on the area (t/m²).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LoadedArea {
+    pub vertices: Vec<(f64, f64)>,
+    pub pressure: f64,
+}
+
+impl LoadedArea {
+    pub fn new(vertices: Vec<(f64, f64)>, pressure: f64) -> Self {
+        Self { vertices, pressure }
+    }
+
+    /// Creates a rectangular loaded area centered on (x, y).
+    ///
+    /// # Arguments
+    /// * `x`, `y` - Center of the rectangle (m).
+    /// * `width` - Extent along x (m).
+    /// * `length` - Extent along y (m).
+    /// * `pressure` - Uniform pressure on the area (t/m²).
+    pub fn rectangle(x: f64, y: f64, width: f64, length: f64, pressure: f64) -> Self {
+        let (dx, dy) = (width / 2.0, length / 2.0);
+        Self::new(
+            vec![
+                (x - dx, y - dy),
+                (x + dx, y - dy),
+                (x + dx, y + dy),
+                (x - dx, y + dy),
+            ],
+            pressure,
+        )
+    }
+
+    /// Returns the plan area of the polygon (m²).
+    pub fn calc_area(&self) -> f64 {
+        let n = self.vertices.len();
+        let twice_area: f64 = (0..n)
+            .map(|i| {
+                let (x1, y1) = self.vertices[i];
+                let (x2, y2) = self.vertices[(i + 1) % n];
+                x1 * y2 - x2 * y1
+            })
+            .sum();
+
+        twice_area.abs() / 2.0
+    }
+
+    /// Validates the loaded area.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if self.vertices.len() < 3 {
+            return Err(ValidationError {
+                code: "loaded_area.vertices.too_few".into(),
+                message: "A loaded area must have at least three vertices.".into(),
+            });
+        }
+        if self.calc_area() <= 0.0 {
+            return Err(ValidationError {
+                code: "loaded_area.vertices.zero_area".into(),
+                message: "The vertices of a loaded area must enclose a non-zero area.".into(),
+            });
+        }
+        validate_field(
+            "pressure",
+            Some(self.pressure),
+            Some(0.0),
+            None,
+            "loaded_area",
+        )?;
+
+        Ok(())
+    }
+}
+
+/// Calculates the vertical stress increase below a point load (Boussinesq, 1885).
+///
+/// # Arguments
+/// * `load` - Point load P (t).
+/// * `radius` - Horizontal distance from the load (m).
+/// * `depth` - Depth below the load (m).
+///
+/// # Returns
+/// * Vertical stress Δσ = 3P / (2π z²) · (1 + (r / z)²)^(−5/2) (t/m²).
+pub fn calc_point_load_stress(load: f64, radius: f64, depth: f64) -> f64 {
+    3.0 * load / (2.0 * PI * depth.powi(2)) * (1.0 + (radius / depth).powi(2)).powf(-2.5)
+}
+
+/// Integrates the Boussinesq point solution over the triangle between the origin and an edge.
+///
+/// Integrating over the radius gives q / (2π) · [1 − (1 + (r / z)²)^(−3/2)] per unit angle, with
+/// r the distance to the edge along the ray. The angle is integrated with Simpson's rule and is
+/// signed, so triangles of edges running clockwise around the origin are subtracted.
+fn calc_edge_stress(start: (f64, f64), end: (f64, f64), depth: f64) -> f64 {
+    let cross = start.0 * end.1 - start.1 * end.0;
+    if cross.abs() < 1e-12 {
+        return 0.0;
+    }
+
+    let (ex, ey) = (end.0 - start.0, end.1 - start.1);
+    let theta_start = start.1.atan2(start.0);
+    let sweep = cross.atan2(start.0 * end.0 + start.1 * end.1);
+
+    let integrand = |theta: f64| {
+        let (ux, uy) = (theta.cos(), theta.sin());
+        let radius = cross / (ux * ey - uy * ex);
+        1.0 - (1.0 + (radius / depth).powi(2)).powf(-1.5)
+    };
+
+    let h = sweep / INTEGRATION_STEPS as f64;
+    let mut sum = integrand(theta_start) + integrand(theta_start + sweep);
+    for i in 1..INTEGRATION_STEPS {
+        let weight = if i % 2 == 1 { 4.0 } else { 2.0 };
+        sum += weight * integrand(theta_start + i as f64 * h);
+    }
+
+    sum * h / 3.0 / (2.0 * PI)
+}
+
+/// Calculates the vertical stress increase at a point below a uniformly loaded polygon.
+///
+/// The polygon is split into triangles between the point and each edge, and the Boussinesq point
+/// solution is integrated over each triangle (Newmark's influence chart in numerical form). The
+/// signed triangles add up to the polygon for both convex and concave shapes, such as L-shaped
+/// mats, and for points inside or outside the loaded area.
+///
+/// # Arguments
+/// * `area` - The loaded area.
+/// * `x`, `y` - Plan coordinates of the point (m).
+/// * `depth` - Depth of the point below the loaded surface (m).
+///
+/// # Returns
+/// * Vertical stress increase (t/m²).
+pub fn calc_area_stress(area: &LoadedArea, x: f64, y: f64, depth: f64) -> f64 {
+    let n = area.vertices.len();
+    let influence: f64 = (0..n)
+        .map(|i| {
+            let (x1, y1) = area.vertices[i];
+            let (x2, y2) = area.vertices[(i + 1) % n];
+            calc_edge_stress((x1 - x, y1 - y), (x2 - x, y2 - y), depth)
+        })
+        .sum();
+
+    // The vertex order sets the sign of the sum
+    area.pressure * influence.abs()
+}
+
+/// Validates the input data for stress distribution calculations.
+///
+/// # Arguments
+/// * `areas` - The loaded areas.
+/// * `depths` - Depths of the points below the loaded surface (m).
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(areas: &[LoadedArea], depths: &[f64]) -> Result<(), ValidationError> {
+    if areas.is_empty() {
+        return Err(ValidationError {
+            code: "stress_distribution.empty_areas".into(),
+            message: "At least one loaded area must be provided.".into(),
+        });
+    }
+    for area in areas {
+        area.validate()?;
+    }
+    for &depth in depths {
+        validate_field(
+            "depth",
+            Some(depth),
+            Some(0.001),
+            None,
+            "stress_distribution",
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Calculates the vertical stress increase at a point below one or more loaded areas.
+///
+/// The stresses of the areas are superposed, so a trapezoidal embankment can be modeled as
+/// stacked areas with the pressure of each fill lift.
+///
+/// # Arguments
+/// * `areas` - The loaded areas.
+/// * `x`, `y` - Plan coordinates of the point (m).
+/// * `depth` - Depth of the point below the loaded surface (m).
+///
+/// # Returns
+/// * Vertical stress increase (t/m²).
+pub fn calc_vertical_stress(
+    areas: &[LoadedArea],
+    x: f64,
+    y: f64,
+    depth: f64,
+) -> Result<f64, ValidationError> {
+    validate_input(areas, &[depth])?;
+
+    Ok(areas
+        .iter()
+        .map(|area| calc_area_stress(area, x, y, depth))
+        .sum())
+}
+
+/// Calculates the vertical stress increase at several depths below a point.
+///
+/// # Arguments
+/// * `areas` - The loaded areas.
+/// * `x`, `y` - Plan coordinates of the point (m).
+/// * `depths` - Depths below the loaded surface (m).
+///
+/// # Returns
+/// * Vertical stress increase at each depth (t/m²).
+pub fn calc_stress_profile(
+    areas: &[LoadedArea],
+    x: f64,
+    y: f64,
+    depths: &[f64],
+) -> Result<Vec<f64>, ValidationError> {
+    validate_input(areas, depths)?;
+
+    Ok(depths
+        .iter()
+        .map(|&depth| {
+            areas
+                .iter()
+                .map(|area| calc_area_stress(area, x, y, depth))
+                .sum()
+        })
+        .collect())
+}
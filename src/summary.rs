@@ -0,0 +1,269 @@
+use serde::Serialize;
+
+use crate::{
+    bearing_capacity::depth_profile::get_bearing_capacity_fn,
+    elastic_settlement::boussinesq::{calc_elastic_settlement, calc_equivalent_rectangle},
+    enums::{
+        AnalysisTerm, ChemicalExposureClass, LoadCase, MethodId, PressureConvention,
+        SelectionMethod,
+    },
+    groundwater_aggressivity::calc_groundwater_aggressivity,
+    horizontal_sliding::calc_horizontal_sliding_by_term,
+    liquefaction::{models::CommonLiquefactionLayerResult, spt::seed_idriss, vs::andrus_stokoe},
+    local_soil_class::{by_cu::calc_lsc_by_cu, by_spt::calc_lsc_by_spt, by_vs::calc_lsc_by_vs},
+    models::{foundation::Foundation, loads::Loads, site::Site},
+    validation::ValidationError,
+};
+
+/// Factor of safety applied to the bearing capacity in the summary.
+pub const SUMMARY_FACTOR_OF_SAFETY: f64 = 3.0;
+
+/// Key results of a foundation design, as tabulated in geotechnical reports.
+///
+/// Results whose input data is not available for the site are `None`.
+///
+/// # Fields
+/// * `foundation_pressure` - Foundation pressure under service loads (t/m²).
+/// * `allowable_bearing_capacity` - Governing (lowest) allowable bearing capacity (t/m²).
+/// * `bearing_capacity_method` - Method giving the governing bearing capacity.
+/// * `bearing_capacity_term` - Analysis term giving the governing bearing capacity.
+/// * `total_settlement` - Elastic settlement at the foundation center (cm).
+/// * `differential_settlement` - Settlement difference between the center and a corner (cm).
+/// * `min_liquefaction_safety_factor` - Lowest liquefaction safety factor.
+/// * `max_liquefaction_safety_factor` - Highest liquefaction safety factor.
+/// * `soil_class` - Local soil class.
+/// * `sliding_utilization` - Ratio of the horizontal load to the sliding resistance.
+/// * `groundwater_exposure_class` - Governing chemical exposure class of the groundwater.
+/// * `is_groundwater_corrosive` - Whether the groundwater is corrosive to steel.
+#[derive(Debug, Serialize)]
+pub struct DesignSummary {
+    pub foundation_pressure: f64,
+    pub allowable_bearing_capacity: f64,
+    pub bearing_capacity_method: MethodId,
+    pub bearing_capacity_term: AnalysisTerm,
+    pub total_settlement: Option<f64>,
+    pub differential_settlement: Option<f64>,
+    pub min_liquefaction_safety_factor: Option<f64>,
+    pub max_liquefaction_safety_factor: Option<f64>,
+    pub soil_class: Option<String>,
+    pub sliding_utilization: Option<f64>,
+    pub groundwater_exposure_class: Option<ChemicalExposureClass>,
+    pub is_groundwater_corrosive: Option<bool>,
+}
+
+/// Returns the foundation pressure from the maximum service load stress, or from the vertical
+/// load over the foundation area.
+fn get_foundation_pressure(foundation: &Foundation, loads: &Loads) -> Result<f64, ValidationError> {
+    if loads.service_load.is_some_and(|s| s.max.is_some()) {
+        return Ok(loads.get_vertical_stress(LoadCase::ServiceLoad, SelectionMethod::Max));
+    }
+
+    match (
+        loads.vertical_load,
+        foundation.foundation_width,
+        foundation.foundation_length,
+    ) {
+        (Some(load), Some(width), Some(length)) if width * length > 0.0 => {
+            Ok(load / (width * length))
+        }
+        _ => Err(ValidationError {
+            code: "summary.foundation_pressure.missing".to_string(),
+            message: "A service load or a vertical load and foundation dimensions are required."
+                .to_string(),
+        }),
+    }
+}
+
+/// Calculates the governing bearing capacity over the general methods and analysis terms.
+fn calc_governing_bearing_capacity(
+    site: &Site,
+    foundation: &Foundation,
+    loads: &Loads,
+    foundation_pressure: f64,
+) -> Result<(f64, MethodId, AnalysisTerm), ValidationError> {
+    let mut governing: Option<(f64, MethodId, AnalysisTerm)> = None;
+    let mut last_error = None;
+
+    for method in [MethodId::Vesic, MethodId::Hansen, MethodId::Meyerhof] {
+        let calc = get_bearing_capacity_fn(method, "summary")?;
+        for term in [AnalysisTerm::Short, AnalysisTerm::Long] {
+            let result = calc(
+                &mut site.soil_profile.clone(),
+                &mut foundation.clone(),
+                loads,
+                foundation_pressure,
+                PressureConvention::Gross,
+                SUMMARY_FACTOR_OF_SAFETY,
+                term,
+            );
+            match result {
+                Ok(result) => {
+                    let qa = result.allowable_bearing_capacity;
+                    if governing.is_none_or(|(min, _, _)| qa < min) {
+                        governing = Some((qa, method, term));
+                    }
+                }
+                Err(err) => last_error = Some(err),
+            }
+        }
+    }
+
+    governing.ok_or_else(|| last_error.unwrap())
+}
+
+/// Calculates the center and corner elastic settlements of a flexible foundation.
+///
+/// The corner settlement is a quarter of the center settlement of a foundation twice as wide and
+/// long, by superposition.
+fn calc_settlements(
+    site: &Site,
+    foundation: &Foundation,
+    foundation_pressure: f64,
+) -> Option<(f64, f64)> {
+    let foundation = calc_equivalent_rectangle(foundation).ok()?;
+    let center = calc_elastic_settlement(
+        &mut site.soil_profile.clone(),
+        &foundation,
+        foundation_pressure,
+        PressureConvention::Gross,
+    )
+    .ok()?
+    .total_settlement;
+
+    let mut doubled = foundation.clone();
+    doubled.foundation_width = Some(2.0 * foundation.foundation_width.unwrap());
+    doubled.foundation_length = Some(2.0 * foundation.foundation_length.unwrap());
+    let corner = calc_elastic_settlement(
+        &mut site.soil_profile.clone(),
+        &doubled,
+        foundation_pressure,
+        PressureConvention::Gross,
+    )
+    .ok()?
+    .total_settlement
+        / 4.0;
+
+    Some((center, (center - corner).max(0.0)))
+}
+
+/// Returns the range of the liquefaction safety factors, from SPT data if available or else
+/// from MASW data.
+fn calc_liquefaction_range(site: &Site) -> Option<(f64, f64)> {
+    let (pga, mw) = (site.pga?, site.mw?);
+
+    let layers: Vec<CommonLiquefactionLayerResult> = if let Some(spt) = &site.spt {
+        seed_idriss::calc_liquefacion(&site.soil_profile, &mut spt.clone(), pga, mw)
+            .ok()?
+            .layers
+    } else {
+        andrus_stokoe::calc_liquefacion(
+            &mut site.soil_profile.clone(),
+            &mut site.masw.clone()?,
+            pga,
+            mw,
+        )
+        .ok()?
+        .layers
+    };
+
+    let safety_factors: Vec<f64> = layers.iter().filter_map(|l| l.safety_factor).collect();
+    if safety_factors.is_empty() {
+        return None;
+    }
+
+    Some((
+        safety_factors.iter().cloned().fold(f64::INFINITY, f64::min),
+        safety_factors
+            .iter()
+            .cloned()
+            .fold(f64::NEG_INFINITY, f64::max),
+    ))
+}
+
+/// Returns the local soil class from MASW, SPT or undrained shear strength data, in that order.
+fn calc_soil_class(site: &Site) -> Option<String> {
+    let by_vs = site
+        .masw
+        .as_ref()
+        .and_then(|masw| calc_lsc_by_vs(&mut masw.clone()).ok())
+        .map(|result| result.soil_class);
+    let by_spt = || {
+        site.spt
+            .as_ref()
+            .and_then(|spt| calc_lsc_by_spt(&mut spt.clone()).ok())
+            .map(|result| result.soil_class)
+    };
+    let by_cu = || {
+        calc_lsc_by_cu(&mut site.soil_profile.clone())
+            .ok()
+            .map(|result| result.soil_class)
+    };
+
+    by_vs.or_else(by_spt).or_else(by_cu)
+}
+
+/// Calculates the governing sliding utilization over both horizontal directions and analysis
+/// terms.
+fn calc_sliding_utilization(
+    site: &Site,
+    foundation: &Foundation,
+    loads: &Loads,
+    foundation_pressure: f64,
+) -> Option<f64> {
+    [AnalysisTerm::Short, AnalysisTerm::Long]
+        .into_iter()
+        .filter_map(|term| {
+            calc_horizontal_sliding_by_term(
+                &site.soil_profile,
+                foundation,
+                loads,
+                foundation_pressure,
+                term,
+            )
+            .ok()
+        })
+        .map(|result| f64::max(result.vth_x / result.sum_x, result.vth_y / result.sum_y))
+        .reduce(f64::max)
+}
+
+/// Summarizes the key design results of a foundation on a site.
+///
+/// The governing bearing capacity is the lowest allowable value of the Vesic, Hansen and
+/// Meyerhof methods in the short and long term, with a factor of safety of 3. The other results
+/// are calculated when the site has the data they need and are `None` otherwise.
+///
+/// # Arguments
+/// * `site` - The site investigation data.
+/// * `foundation` - The foundation data.
+/// * `loads` - The applied loads.
+///
+/// # Returns
+/// * `DesignSummary` with the key design results.
+pub fn summarize(
+    site: &Site,
+    foundation: &Foundation,
+    loads: &Loads,
+) -> Result<DesignSummary, ValidationError> {
+    let foundation_pressure = get_foundation_pressure(foundation, loads)?;
+    let (allowable_bearing_capacity, bearing_capacity_method, bearing_capacity_term) =
+        calc_governing_bearing_capacity(site, foundation, loads, foundation_pressure)?;
+
+    let settlements = calc_settlements(site, foundation, foundation_pressure);
+    let liquefaction = calc_liquefaction_range(site);
+    let aggressivity = calc_groundwater_aggressivity(&site.soil_profile).ok();
+
+    Ok(DesignSummary {
+        foundation_pressure,
+        allowable_bearing_capacity,
+        bearing_capacity_method,
+        bearing_capacity_term,
+        total_settlement: settlements.map(|s| s.0),
+        differential_settlement: settlements.map(|s| s.1),
+        min_liquefaction_safety_factor: liquefaction.map(|l| l.0),
+        max_liquefaction_safety_factor: liquefaction.map(|l| l.1),
+        soil_class: calc_soil_class(site),
+        sliding_utilization: calc_sliding_utilization(site, foundation, loads, foundation_pressure),
+        groundwater_exposure_class: aggressivity.as_ref().map(|a| a.exposure_class),
+        is_groundwater_corrosive: aggressivity.map(|a| a.is_corrosive),
+    })
+}
@@ -1,4 +1,7 @@
 use crate::{
+    consolidation_settlement::helper_functions::calc_delta_stress,
+    diagnostics::{check_limits, Warning},
+    enums::{MethodId, PressureConvention, StressDistribution},
     models::{foundation::Foundation, soil_profile::SoilProfile},
     validation::{validate_field, ValidationError},
 };
@@ -25,6 +28,12 @@ pub struct SwellingPotentialResult {
     pub data: Vec<SwellingPotentialData>,
     /// The net foundation pressure in ton/m2.
     pub net_foundation_pressure: f64,
+    /// Method used for the analysis.
+    pub method: MethodId,
+    /// Warnings for inputs outside the calibration range of the method.
+    pub warnings: Vec<Warning>,
+    /// Convention of the foundation pressure given to the method.
+    pub pressure_convention: PressureConvention,
 }
 
 /// Validates the input data for swelling potential calculations.
@@ -69,6 +78,7 @@ pub fn validate_input(
 /// * `soil_profile`: The soil profile containing the layers of soil.
 /// * `foundation`: The foundation parameters including depth, width, and length.
 /// * `foundation_pressure`: The foundation pressure applied to the soil in ton/m2.
+/// * `pressure_convention`: Whether `foundation_pressure` is gross or net.
 ///
 /// # Returns
 /// A `SwellingPotentialResult` containing the swelling potential data for each layer and the net foundation pressure.
@@ -76,6 +86,35 @@ pub fn calc_swelling_potential(
     soil_profile: &mut SoilProfile,
     foundation: &Foundation,
     foundation_pressure: f64,
+    pressure_convention: PressureConvention,
+) -> Result<SwellingPotentialResult, ValidationError> {
+    calc_swelling_potential_with_distribution(
+        soil_profile,
+        foundation,
+        foundation_pressure,
+        pressure_convention,
+        StressDistribution::default(),
+    )
+}
+
+/// Calculates the swelling potential of a soil profile with the stress increase of each layer
+/// from the given stress distribution method.
+///
+/// # Arguments
+/// * `soil_profile`: The soil profile containing the layers of soil.
+/// * `foundation`: The foundation parameters including depth, width, and length.
+/// * `foundation_pressure`: The foundation pressure applied to the soil in ton/m2.
+/// * `pressure_convention`: Whether `foundation_pressure` is gross or net.
+/// * `distribution`: Stress distribution method below the foundation center.
+///
+/// # Returns
+/// A `SwellingPotentialResult` containing the swelling potential data for each layer and the net foundation pressure.
+pub fn calc_swelling_potential_with_distribution(
+    soil_profile: &mut SoilProfile,
+    foundation: &Foundation,
+    foundation_pressure: f64,
+    pressure_convention: PressureConvention,
+    distribution: StressDistribution,
 ) -> Result<SwellingPotentialResult, ValidationError> {
     validate_input(soil_profile, foundation, foundation_pressure)?;
     soil_profile.calc_layer_depths();
@@ -83,11 +122,11 @@ pub fn calc_swelling_potential(
     let width = foundation.foundation_width.unwrap();
     let length = foundation.foundation_length.unwrap();
 
-    let net_foundation_pressure = foundation_pressure - soil_profile.calc_normal_stress(df);
-
-    let vertical_load = net_foundation_pressure * width * length;
+    let net_foundation_pressure =
+        foundation.calc_net_pressure(soil_profile, foundation_pressure, pressure_convention);
 
     let mut data = Vec::new();
+    let mut warnings = Vec::new();
 
     for layer in soil_profile.layers.iter() {
         let z = layer.center.unwrap();
@@ -95,13 +134,23 @@ pub fn calc_swelling_potential(
         let mut delta_stress = 0.;
         if z >= df {
             effective_stress = soil_profile.calc_effective_stress(z);
-            delta_stress = vertical_load / ((width + z - df) * (length + z - df));
+            delta_stress =
+                calc_delta_stress(net_foundation_pressure, width, length, z - df, distribution);
         }
 
         let swelling_pressure = if let Some(plastic_limit) = layer.plastic_limit {
             let water_content = layer.water_content.unwrap();
             let liquid_limit = layer.liquid_limit.unwrap();
             let dry_unit_weight = layer.dry_unit_weight.unwrap();
+            warnings.extend(check_limits(
+                MethodId::KayabaliYaldiz,
+                &[
+                    ("water_content", water_content),
+                    ("dry_unit_weight", dry_unit_weight),
+                    ("liquid_limit", liquid_limit),
+                    ("plastic_limit", plastic_limit),
+                ],
+            ));
             -3.08 * water_content
                 + 102.5 * dry_unit_weight
                 + 0.635 * liquid_limit
@@ -125,5 +174,8 @@ pub fn calc_swelling_potential(
     Ok(SwellingPotentialResult {
         data,
         net_foundation_pressure,
+        method: MethodId::KayabaliYaldiz,
+        warnings,
+        pressure_convention,
     })
 }
@@ -0,0 +1,224 @@
+use crate::{
+    constants::TON_TO_KN,
+    enums::{MaterialType, ProfileStyle, WeatheringGrade},
+    models::soil_profile::{SoilLayer, SoilProfile},
+    probabilistic::random::Rng,
+};
+
+/// Specific gravity of soil solids.
+const SPECIFIC_GRAVITY: f64 = 2.7;
+
+/// Coefficient of variation of the random scatter applied to strength parameters.
+const SCATTER_COV: f64 = 0.1;
+
+/// Returns a uniformly distributed number between `min` and `max`.
+fn uniform(rng: &mut Rng, min: f64, max: f64) -> f64 {
+    min + (max - min) * rng.next_f64()
+}
+
+/// Returns a random multiplier with a mean of 1 and a coefficient of variation of 10%.
+fn scatter(rng: &mut Rng) -> f64 {
+    rng.normal(1.0, SCATTER_COV).max(0.5)
+}
+
+/// Generates a clay layer whose parameters are derived from its undrained shear strength and
+/// liquidity index.
+///
+/// * e0 = w · Gs (saturated), γsat = (Gs + e0) / (1 + e0), γdry = Gs / (1 + e0)
+/// * Cc = 0.009 · (LL − 10) (Terzaghi & Peck, 1967), Cr = Cc / 5–10
+/// * σp' = cu / 0.22 (Mesri, 1975)
+/// * sin φ' = 0.8 − 0.094 · ln(PI) (Kenney, 1959)
+/// * E = 300 · cu, mv = 0.434 · Cc / ((1 + e0) · σp')
+/// * Vs = 40 · cu^0.4 with cu in kPa
+fn generate_clay_layer(rng: &mut Rng, thickness: f64, cu: f64, liquidity_index: f64) -> SoilLayer {
+    let cu = (cu * scatter(rng)).max(1.0);
+    let liquid_limit = uniform(rng, 35.0, 70.0);
+    let plastic_limit = uniform(rng, 18.0, 28.0);
+    let plasticity_index = liquid_limit - plastic_limit;
+    let liquidity_index = (liquidity_index + rng.normal(0.0, 0.05)).clamp(0.0, 1.0);
+    let water_content = plastic_limit + liquidity_index * plasticity_index;
+
+    let void_ratio = water_content / 100.0 * SPECIFIC_GRAVITY;
+    let saturated_unit_weight = (SPECIFIC_GRAVITY + void_ratio) / (1.0 + void_ratio);
+    let dry_unit_weight = SPECIFIC_GRAVITY / (1.0 + void_ratio);
+
+    let compression_index = 0.009 * (liquid_limit - 10.0);
+    let recompression_index = compression_index / uniform(rng, 5.0, 10.0);
+    let preconsolidation_pressure = cu / 0.22;
+    let phi_prime = (0.8 - 0.094 * plasticity_index.ln()).asin().to_degrees();
+
+    SoilLayer {
+        soil_classification: Some("CLAY".to_string()),
+        thickness: Some(thickness),
+        natural_unit_weight: Some(saturated_unit_weight),
+        dry_unit_weight: Some(dry_unit_weight),
+        saturated_unit_weight: Some(saturated_unit_weight),
+        damping_ratio: Some(5.0),
+        fine_content: Some(uniform(rng, 70.0, 95.0)),
+        liquid_limit: Some(liquid_limit),
+        plastic_limit: Some(plastic_limit),
+        plasticity_index: Some(plasticity_index),
+        cu: Some(cu),
+        c_prime: Some(uniform(rng, 0.5, 1.5)),
+        phi_u: Some(0.0),
+        phi_prime: Some(phi_prime),
+        water_content: Some(water_content),
+        poissons_ratio: Some(0.45),
+        elastic_modulus: Some(300.0 * cu),
+        void_ratio: Some(void_ratio),
+        recompression_index: Some(recompression_index),
+        compression_index: Some(compression_index),
+        preconsolidation_pressure: Some(preconsolidation_pressure),
+        mv: Some(0.434 * compression_index / ((1.0 + void_ratio) * preconsolidation_pressure)),
+        shear_wave_velocity: Some(40.0 * (cu * TON_TO_KN).powf(0.4)),
+        material_type: Some(MaterialType::Soil),
+        ..Default::default()
+    }
+}
+
+/// Generates a sand layer whose parameters are derived from its SPT blow count.
+///
+/// * φ' = √(20 · N) + 20 (Hatanaka & Uchida, 1996)
+/// * e0 = 0.9 − 0.01 · N ≥ 0.45
+/// * E = 500 · (N + 15) kPa (Bowles, 1996)
+/// * Vs = 97 · N^0.314 (Imai & Tonouchi, 1982)
+fn generate_sand_layer(rng: &mut Rng, thickness: f64, n60: f64) -> SoilLayer {
+    let n60 = (n60 * scatter(rng)).max(2.0);
+    let void_ratio = (0.9 - 0.01 * n60).max(0.45);
+    let saturated_unit_weight = (SPECIFIC_GRAVITY + void_ratio) / (1.0 + void_ratio);
+    let dry_unit_weight = SPECIFIC_GRAVITY / (1.0 + void_ratio);
+    let phi_prime = (20.0 * n60).sqrt() + 20.0;
+    let elastic_modulus = 500.0 * (n60 + 15.0) / TON_TO_KN;
+    let poissons_ratio = 0.3;
+    let oedometer_modulus = elastic_modulus * (1.0 - poissons_ratio)
+        / ((1.0 + poissons_ratio) * (1.0 - 2.0 * poissons_ratio));
+
+    SoilLayer {
+        soil_classification: Some("SAND".to_string()),
+        thickness: Some(thickness),
+        natural_unit_weight: Some(saturated_unit_weight),
+        dry_unit_weight: Some(dry_unit_weight),
+        saturated_unit_weight: Some(saturated_unit_weight),
+        damping_ratio: Some(5.0),
+        fine_content: Some(uniform(rng, 3.0, 15.0)),
+        plasticity_index: Some(0.0),
+        cu: Some(0.0),
+        c_prime: Some(0.0),
+        phi_u: Some(phi_prime),
+        phi_prime: Some(phi_prime),
+        water_content: Some(void_ratio / SPECIFIC_GRAVITY * 100.0),
+        poissons_ratio: Some(poissons_ratio),
+        elastic_modulus: Some(elastic_modulus),
+        void_ratio: Some(void_ratio),
+        recompression_index: Some(0.002),
+        compression_index: Some(0.01),
+        preconsolidation_pressure: Some(100.0),
+        mv: Some(1.0 / oedometer_modulus),
+        shear_wave_velocity: Some(97.0 * n60.powf(0.314)),
+        material_type: Some(MaterialType::Soil),
+        ..Default::default()
+    }
+}
+
+/// Generates a moderately weathered rock layer.
+fn generate_rock_layer(rng: &mut Rng, thickness: f64) -> SoilLayer {
+    let unit_weight = uniform(rng, 2.4, 2.6);
+    let modulus = uniform(rng, 50000.0, 200000.0);
+
+    SoilLayer {
+        soil_classification: Some("ROCK".to_string()),
+        thickness: Some(thickness),
+        natural_unit_weight: Some(unit_weight),
+        dry_unit_weight: Some(unit_weight),
+        saturated_unit_weight: Some(unit_weight),
+        damping_ratio: Some(2.0),
+        poissons_ratio: Some(0.25),
+        elastic_modulus: Some(modulus),
+        shear_wave_velocity: Some(uniform(rng, 760.0, 1200.0)),
+        material_type: Some(MaterialType::Rock),
+        weathering_grade: Some(WeatheringGrade::ModeratelyWeathered),
+        rock_mass_modulus: Some(modulus),
+        ..Default::default()
+    }
+}
+
+/// Generates a realistic layered soil profile for examples, fuzzing, benchmarks and tutorials.
+///
+/// The layer thicknesses and groundwater level are drawn randomly within the ranges of the
+/// style, and the parameters of each layer are derived from a single strength parameter (cu for
+/// clays, N60 for sands) by common correlations, so that they are consistent with each other.
+/// The same seed always gives the same profile.
+///
+/// # Arguments
+/// * `seed` - Seed of the random number generator.
+/// * `style` - Style of the profile.
+///
+/// # Returns
+/// * `SoilProfile`: The generated profile.
+pub fn generate_profile(seed: u64, style: ProfileStyle) -> SoilProfile {
+    let mut rng = Rng::new(seed);
+    let mut layers = vec![];
+    let mut depth = 0.0;
+
+    let ground_water_level = match style {
+        ProfileStyle::SoftClayOverSand => {
+            let thickness = uniform(&mut rng, 4.0, 10.0);
+            let cu = 1.5 + 0.15 * thickness / 2.0;
+            layers.push(generate_clay_layer(&mut rng, thickness, cu, 0.8));
+            let n60 = uniform(&mut rng, 15.0, 30.0);
+            let sand_thickness = uniform(&mut rng, 10.0, 20.0);
+            layers.push(generate_sand_layer(&mut rng, sand_thickness, n60));
+            uniform(&mut rng, 0.5, 2.0)
+        }
+        ProfileStyle::Alluvium => {
+            let layer_count = 4 + (rng.next_u64() % 3) as usize;
+            let mut is_sand = rng.next_f64() < 0.5;
+            for _ in 0..layer_count {
+                let thickness = uniform(&mut rng, 1.5, 4.0);
+                let center = depth + thickness / 2.0;
+                let layer = if is_sand {
+                    let n60 = uniform(&mut rng, 8.0, 25.0);
+                    generate_sand_layer(&mut rng, thickness, n60)
+                } else {
+                    generate_clay_layer(&mut rng, thickness, 2.5 + 0.2 * center, 0.6)
+                };
+                layers.push(layer);
+                depth += thickness;
+                is_sand = !is_sand;
+            }
+            uniform(&mut rng, 0.5, 1.5)
+        }
+        ProfileStyle::StiffClay => {
+            for _ in 0..2 {
+                let thickness = uniform(&mut rng, 5.0, 10.0);
+                let center = depth + thickness / 2.0;
+                layers.push(generate_clay_layer(
+                    &mut rng,
+                    thickness,
+                    8.0 + 0.5 * center,
+                    0.15,
+                ));
+                depth += thickness;
+            }
+            uniform(&mut rng, 2.0, 6.0)
+        }
+        ProfileStyle::DenseSand => {
+            let mut n60 = uniform(&mut rng, 20.0, 30.0);
+            for _ in 0..3 {
+                let thickness = uniform(&mut rng, 3.0, 8.0);
+                layers.push(generate_sand_layer(&mut rng, thickness, n60));
+                n60 = (n60 + uniform(&mut rng, 3.0, 10.0)).min(50.0);
+            }
+            uniform(&mut rng, 3.0, 8.0)
+        }
+        ProfileStyle::ClayOverRock => {
+            let thickness = uniform(&mut rng, 3.0, 8.0);
+            let cu = 5.0 + 0.3 * thickness / 2.0;
+            layers.push(generate_clay_layer(&mut rng, thickness, cu, 0.3));
+            layers.push(generate_rock_layer(&mut rng, 10.0));
+            uniform(&mut rng, 1.0, 4.0)
+        }
+    };
+
+    SoilProfile::new(layers, ground_water_level)
+}
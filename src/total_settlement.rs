@@ -0,0 +1,261 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    allowable_pressure::select_consolidation_method,
+    consolidation_settlement::{
+        by_compression_index, by_mv, helper_functions::get_center_and_thickness,
+        model::ConsolidationOptions,
+    },
+    diagnostics::Warning,
+    elastic_settlement::boussinesq::calc_elastic_settlement,
+    enums::{MethodId, PressureConvention, StressDistribution},
+    models::{foundation::Foundation, soil_profile::SoilProfile},
+    validation::{validate_field, ValidationError},
+};
+
+/// Time span of the secondary compression.
+///
+/// # Fields
+/// * `end_of_primary` - Time at the end of primary consolidation (years).
+/// * `design_life` - Time at which the settlement is evaluated (years).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct SecondaryCompression {
+    pub end_of_primary: f64,
+    pub design_life: f64,
+}
+
+/// Settings of the total settlement calculation.
+///
+/// # Fields
+/// * `consolidation_method` - Primary consolidation method (`CompressionIndex` or
+///   `VolumeCompressibility`), selected from the layer parameters if not set.
+/// * `distribution` - Stress distribution method of the primary consolidation.
+/// * `secondary` - Time span of the secondary compression, not calculated if not set.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct TotalSettlementParams {
+    pub consolidation_method: Option<MethodId>,
+    pub distribution: StressDistribution,
+    pub secondary: Option<SecondaryCompression>,
+}
+
+/// Settlement components of a single layer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TotalSettlementLayer {
+    /// Depth of the layer bottom (m).
+    pub depth: f64,
+    /// Thickness of the layer (m).
+    pub thickness: f64,
+    /// Indicates whether the layer is cohesive and consolidates.
+    pub is_cohesive: bool,
+    /// Immediate (elastic) settlement (cm).
+    pub immediate_settlement: f64,
+    /// Primary consolidation settlement (cm).
+    pub consolidation_settlement: f64,
+    /// Secondary compression settlement (cm).
+    pub secondary_settlement: f64,
+    /// Sum of the components (cm).
+    pub total_settlement: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TotalSettlementResult {
+    /// Settlement components of each layer.
+    pub layers: Vec<TotalSettlementLayer>,
+    /// Immediate settlement of all layers (cm).
+    pub immediate_settlement: f64,
+    /// Primary consolidation settlement of all layers (cm).
+    pub consolidation_settlement: f64,
+    /// Secondary compression settlement of all layers (cm).
+    pub secondary_settlement: f64,
+    /// Sum of all components (cm).
+    pub total_settlement: f64,
+    /// Net foundation pressure (t/m²).
+    pub qnet: f64,
+    /// Primary consolidation method used, `None` if the profile has no consolidation data.
+    pub consolidation_method: Option<MethodId>,
+    /// Applicability warnings of the methods.
+    pub warnings: Vec<Warning>,
+    /// Convention of the foundation pressure given to the calculation; `qnet` is always net.
+    pub pressure_convention: PressureConvention,
+}
+
+/// Validates the input data for total settlement calculations.
+///
+/// # Arguments
+/// * `params` - Settings of the calculation.
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(params: &TotalSettlementParams) -> Result<(), ValidationError> {
+    if let Some(method) = params.consolidation_method
+        && !matches!(
+            method,
+            MethodId::CompressionIndex | MethodId::VolumeCompressibility
+        )
+    {
+        return Err(ValidationError {
+            code: "total_settlement.consolidation_method.unsupported".into(),
+            message: format!("{method:?} is not a primary consolidation method."),
+        });
+    }
+    if let Some(secondary) = params.secondary {
+        validate_field(
+            "end_of_primary",
+            Some(secondary.end_of_primary),
+            Some(0.001),
+            None,
+            "total_settlement",
+        )?;
+        validate_field(
+            "design_life",
+            Some(secondary.design_life),
+            Some(secondary.end_of_primary),
+            None,
+            "total_settlement",
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Calculates the secondary compression settlement of a layer.
+///
+/// # Arguments
+/// * `c_alpha` - Secondary compression index Cα.
+/// * `thickness` - Thickness of the layer [m].
+/// * `e0` - Void ratio of the layer.
+/// * `end_of_primary` - Time at the end of primary consolidation (years).
+/// * `design_life` - Time at which the settlement is evaluated (years).
+///
+/// # Returns
+/// * Settlement Ss = Cα · H / (1 + e0) · log10(t / tp) [cm].
+pub fn calc_secondary_settlement(
+    c_alpha: f64,
+    thickness: f64,
+    e0: f64,
+    end_of_primary: f64,
+    design_life: f64,
+) -> f64 {
+    c_alpha * thickness / (1.0 + e0) * (design_life / end_of_primary).log10() * 100.0
+}
+
+/// Calculates the total settlement of a foundation as the sum of its immediate, primary
+/// consolidation and secondary compression components.
+///
+/// The immediate settlement is the Boussinesq elastic settlement of each layer, so cohesive
+/// layers must be given their undrained modulus. Granular and rock layers settle immediately
+/// only, while cohesive layers also consolidate: the primary consolidation settlement of the
+/// selected method is added to the cohesive layers alone, and the secondary compression
+/// settlement to the cohesive layers that consolidate. The void ratio at the end of primary
+/// consolidation is taken as the initial void ratio.
+///
+/// # Arguments
+/// * `soil_profile` - The soil profile data.
+/// * `foundation` - The foundation data.
+/// * `foundation_pressure` - The foundation pressure (q) [t/m²].
+/// * `pressure_convention` - Whether `foundation_pressure` is gross or net.
+/// * `params` - Settings of the calculation.
+///
+/// # Returns
+/// * `TotalSettlementResult` with the settlement components of each layer.
+pub fn calc_total_settlement(
+    soil_profile: &SoilProfile,
+    foundation: &Foundation,
+    foundation_pressure: f64,
+    pressure_convention: PressureConvention,
+    params: &TotalSettlementParams,
+) -> Result<TotalSettlementResult, ValidationError> {
+    validate_input(params)?;
+
+    let mut soil_profile = soil_profile.clone();
+    let elastic = calc_elastic_settlement(
+        &mut soil_profile,
+        foundation,
+        foundation_pressure,
+        pressure_convention,
+    )?;
+
+    let consolidation_method = params
+        .consolidation_method
+        .or_else(|| select_consolidation_method(&soil_profile, foundation));
+    let options = ConsolidationOptions {
+        distribution: params.distribution,
+        ..Default::default()
+    };
+    let consolidation = match consolidation_method {
+        Some(MethodId::CompressionIndex) => {
+            Some(by_compression_index::calc_settlement_with_options(
+                &mut soil_profile,
+                foundation,
+                foundation_pressure,
+                pressure_convention,
+                &options,
+            )?)
+        }
+        Some(MethodId::VolumeCompressibility) => Some(by_mv::calc_settlement_with_options(
+            &mut soil_profile,
+            foundation,
+            foundation_pressure,
+            pressure_convention,
+            &options,
+        )?),
+        _ => None,
+    };
+
+    let df = foundation.foundation_depth.unwrap();
+    let mut layers = Vec::new();
+    for (i, layer) in soil_profile.layers.iter().enumerate() {
+        let is_cohesive = !layer.is_rock() && layer.is_cohesive();
+        let immediate_settlement = elastic.settlement_per_layer[i];
+        let consolidation_settlement = match &consolidation {
+            Some(result) if is_cohesive => result.settlement_per_layer[i],
+            _ => 0.0,
+        };
+
+        let secondary_settlement = match params.secondary {
+            Some(secondary) if consolidation_settlement > 0.0 => {
+                layer.validate_fields(&["secondary_compression_index", "void_ratio"])?;
+                let (_, thickness) = get_center_and_thickness(&soil_profile, df, i);
+                calc_secondary_settlement(
+                    layer.secondary_compression_index.unwrap(),
+                    thickness,
+                    layer.void_ratio.unwrap(),
+                    secondary.end_of_primary,
+                    secondary.design_life,
+                )
+            }
+            _ => 0.0,
+        };
+
+        layers.push(TotalSettlementLayer {
+            depth: layer.depth.unwrap(),
+            thickness: layer.thickness.unwrap(),
+            is_cohesive,
+            immediate_settlement,
+            consolidation_settlement,
+            secondary_settlement,
+            total_settlement: immediate_settlement
+                + consolidation_settlement
+                + secondary_settlement,
+        });
+    }
+
+    let immediate_settlement = layers.iter().map(|l| l.immediate_settlement).sum::<f64>();
+    let consolidation_settlement = layers
+        .iter()
+        .map(|l| l.consolidation_settlement)
+        .sum::<f64>();
+    let secondary_settlement = layers.iter().map(|l| l.secondary_settlement).sum::<f64>();
+
+    Ok(TotalSettlementResult {
+        layers,
+        immediate_settlement,
+        consolidation_settlement,
+        secondary_settlement,
+        total_settlement: immediate_settlement + consolidation_settlement + secondary_settlement,
+        qnet: elastic.qnet,
+        consolidation_method: consolidation.as_ref().map(|result| result.method),
+        warnings: elastic.warnings,
+        pressure_convention,
+    })
+}
@@ -43,25 +43,25 @@ where
         message: format!("{} must be provided.", field_name),
     })?;
 
-    if let Some(min_val) = min {
-        if val < min_val {
-            return Err(ValidationError {
-                code: format!("{}.{}.too_small.{}", error_code_prefix, field_name, min_val),
-                message: format!(
-                    "{} must be greater than or equal to {}.",
-                    field_name, min_val
-                ),
-            });
-        }
+    if let Some(min_val) = min
+        && val < min_val
+    {
+        return Err(ValidationError {
+            code: format!("{}.{}.too_small.{}", error_code_prefix, field_name, min_val),
+            message: format!(
+                "{} must be greater than or equal to {}.",
+                field_name, min_val
+            ),
+        });
     }
 
-    if let Some(max_val) = max {
-        if val > max_val {
-            return Err(ValidationError {
-                code: format!("{}.{}.too_large.{}", error_code_prefix, field_name, max_val),
-                message: format!("{} must be less than or equal to {}.", field_name, max_val),
-            });
-        }
+    if let Some(max_val) = max
+        && val > max_val
+    {
+        return Err(ValidationError {
+            code: format!("{}.{}.too_large.{}", error_code_prefix, field_name, max_val),
+            message: format!("{} must be less than or equal to {}.", field_name, max_val),
+        });
     }
 
     Ok(())
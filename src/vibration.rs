@@ -0,0 +1,256 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::{IsolationType, StructureCategory, WaveType},
+    helper::interp1d,
+    models::{
+        soil_profile::SoilProfile,
+        vibration::{ScreeningTrench, VibrationSource},
+    },
+    validation::{validate_field, ValidationError},
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VibrationResult {
+    /// Average shear wave velocity within one wavelength from the surface (m/s).
+    pub shear_wave_velocity: f64,
+    /// Average damping ratio within one wavelength from the surface (unitless).
+    pub damping_ratio: f64,
+    /// Wavelength of the vibration, λ = Vs / f (m).
+    pub wavelength: f64,
+    /// Material attenuation coefficient α (1/m).
+    pub attenuation_coefficient: f64,
+    /// Peak particle velocity at the receiver without screening (mm/s).
+    pub peak_particle_velocity: f64,
+    /// Amplitude reduction factor of the screening trench (1.0 if no trench).
+    pub amplitude_reduction_factor: f64,
+    /// Peak particle velocity at the receiver after screening (mm/s).
+    pub screened_peak_particle_velocity: f64,
+    /// Allowable peak particle velocity at the foundation (mm/s).
+    pub allowable_peak_particle_velocity: f64,
+    /// Indicates whether the vibration is below the damage threshold.
+    pub is_safe: bool,
+}
+
+/// Returns the geometric attenuation exponent n of the Bornitz equation.
+///
+/// # Arguments
+/// * `wave_type` - Wave type governing the geometric attenuation.
+///
+/// # Returns
+/// * `f64`: Geometric attenuation exponent.
+pub fn get_geometric_exponent(wave_type: WaveType) -> f64 {
+    match wave_type {
+        WaveType::Surface => 0.5,
+        WaveType::Body => 2.0,
+        WaveType::BodyAtDepth => 1.0,
+    }
+}
+
+/// Calculates the material attenuation coefficient α = 2π · f · D / Vs.
+///
+/// # Arguments
+/// * `frequency` - Dominant frequency (Hz).
+/// * `damping_ratio` - Damping ratio of the soil (unitless).
+/// * `shear_wave_velocity` - Shear wave velocity of the soil (m/s).
+///
+/// # Returns
+/// * `f64`: Attenuation coefficient (1/m).
+pub fn calc_attenuation_coefficient(
+    frequency: f64,
+    damping_ratio: f64,
+    shear_wave_velocity: f64,
+) -> f64 {
+    2.0 * std::f64::consts::PI * frequency * damping_ratio / shear_wave_velocity
+}
+
+/// Calculates the peak particle velocity at a distance with the Bornitz equation.
+///
+/// v2 = v1 · (r1 / r2)^n · e^(−α · (r2 − r1))
+///
+/// # Arguments
+/// * `reference_velocity` - Peak particle velocity at the reference distance (mm/s).
+/// * `reference_distance` - Reference distance r1 (m).
+/// * `distance` - Distance of the receiver r2 (m).
+/// * `geometric_exponent` - Geometric attenuation exponent n.
+/// * `attenuation_coefficient` - Material attenuation coefficient α (1/m).
+///
+/// # Returns
+/// * `f64`: Peak particle velocity at the receiver (mm/s).
+pub fn calc_bornitz_velocity(
+    reference_velocity: f64,
+    reference_distance: f64,
+    distance: f64,
+    geometric_exponent: f64,
+    attenuation_coefficient: f64,
+) -> f64 {
+    reference_velocity
+        * (reference_distance / distance).powf(geometric_exponent)
+        * (-attenuation_coefficient * (distance - reference_distance)).exp()
+}
+
+/// Returns the DIN 4150-3 guideline value of the peak particle velocity at the foundation.
+///
+/// # Arguments
+/// * `structure_category` - Structure category.
+/// * `frequency` - Dominant frequency (Hz).
+///
+/// # Returns
+/// * `f64`: Allowable peak particle velocity (mm/s).
+pub fn get_allowable_velocity(structure_category: StructureCategory, frequency: f64) -> f64 {
+    let frequencies = [10.0, 50.0, 100.0];
+    let velocities = match structure_category {
+        StructureCategory::Commercial => [20.0, 40.0, 50.0],
+        StructureCategory::Residential => [5.0, 15.0, 20.0],
+        StructureCategory::Sensitive => [3.0, 8.0, 10.0],
+    };
+
+    interp1d(&frequencies, &velocities, frequency)
+}
+
+/// Estimates the amplitude reduction factor of an open screening trench (Woods, 1968).
+///
+/// Woods found an amplitude reduction factor of 0.25 or less once the normalized depth of the
+/// trench D / λ reaches 0.6 for active isolation and 1.33 for passive isolation. Shallower
+/// trenches are interpolated linearly down from no reduction.
+///
+/// # Arguments
+/// * `trench_depth` - Depth of the trench (m).
+/// * `wavelength` - Rayleigh wavelength (m).
+/// * `isolation_type` - Position of the trench relative to the source.
+///
+/// # Returns
+/// * `f64`: Amplitude reduction factor between 0.25 and 1.0.
+pub fn calc_trench_reduction_factor(
+    trench_depth: f64,
+    wavelength: f64,
+    isolation_type: IsolationType,
+) -> f64 {
+    let required_depth_ratio = match isolation_type {
+        IsolationType::Active => 0.6,
+        IsolationType::Passive => 1.33,
+    };
+
+    interp1d(
+        &[0.0, required_depth_ratio],
+        &[1.0, 0.25],
+        trench_depth / wavelength,
+    )
+}
+
+/// Calculates the thickness-weighted shear wave velocity and damping ratio down to a depth.
+fn get_average_properties(soil_profile: &SoilProfile, depth: f64) -> (f64, f64) {
+    let mut top = 0.0;
+    let mut vs_sum = 0.0;
+    let mut damping_sum = 0.0;
+
+    for layer in soil_profile.layers.iter() {
+        if top >= depth {
+            break;
+        }
+        let bottom = layer.depth.unwrap().min(depth);
+        let thickness = bottom - top;
+        vs_sum += layer.shear_wave_velocity.unwrap() * thickness;
+        damping_sum += layer.damping_ratio.unwrap() / 100.0 * thickness;
+        top = bottom;
+    }
+
+    (vs_sum / top, damping_sum / top)
+}
+
+/// Validates the input data for vibration calculations.
+///
+/// # Arguments
+/// * `source` - The vibration source.
+/// * `soil_profile` - The soil profile data.
+/// * `distance` - Distance of the receiver from the source (m).
+/// * `trench` - Optional screening trench.
+///
+/// # Returns
+/// * `Result<(), ValidationError>`: Ok if valid, Err if invalid.
+pub fn validate_input(
+    source: &VibrationSource,
+    soil_profile: &SoilProfile,
+    distance: f64,
+    trench: Option<&ScreeningTrench>,
+) -> Result<(), ValidationError> {
+    source.validate(&["peak_particle_velocity", "reference_distance", "frequency"])?;
+    soil_profile.validate(&["thickness", "shear_wave_velocity", "damping_ratio"])?;
+    validate_field(
+        "distance",
+        Some(distance),
+        source.reference_distance,
+        None,
+        "vibration",
+    )?;
+    if let Some(trench) = trench {
+        trench.validate(&["depth"])?;
+    }
+
+    Ok(())
+}
+
+/// Calculates ground-borne vibration at a structure and checks it against DIN 4150-3.
+///
+/// The peak particle velocity is attenuated from the reference distance with the Bornitz
+/// equation. The shear wave velocity and damping ratio are averaged over one wavelength from the
+/// surface, where the wavelength is first estimated from the surface layer.
+///
+/// # Arguments
+/// * `source` - The vibration source.
+/// * `soil_profile` - The soil profile data.
+/// * `distance` - Distance of the receiver from the source (m).
+/// * `structure_category` - Structure category for the damage threshold.
+/// * `trench` - Optional screening trench between the source and the receiver.
+///
+/// # Returns
+/// * `VibrationResult` with the attenuated velocity and the damage check.
+pub fn calc_vibration(
+    source: &VibrationSource,
+    soil_profile: &mut SoilProfile,
+    distance: f64,
+    structure_category: StructureCategory,
+    trench: Option<&ScreeningTrench>,
+) -> Result<VibrationResult, ValidationError> {
+    soil_profile.calc_layer_depths();
+    validate_input(source, soil_profile, distance, trench)?;
+
+    let frequency = source.frequency.unwrap();
+    let profile_depth = soil_profile.layers.last().unwrap().depth.unwrap();
+
+    let surface_wavelength = soil_profile.layers[0].shear_wave_velocity.unwrap() / frequency;
+    let (shear_wave_velocity, damping_ratio) =
+        get_average_properties(soil_profile, surface_wavelength.min(profile_depth));
+    let wavelength = shear_wave_velocity / frequency;
+
+    let attenuation_coefficient =
+        calc_attenuation_coefficient(frequency, damping_ratio, shear_wave_velocity);
+    let peak_particle_velocity = calc_bornitz_velocity(
+        source.peak_particle_velocity.unwrap(),
+        source.reference_distance.unwrap(),
+        distance,
+        get_geometric_exponent(source.wave_type),
+        attenuation_coefficient,
+    );
+
+    let amplitude_reduction_factor = match trench {
+        Some(trench) => {
+            calc_trench_reduction_factor(trench.depth.unwrap(), wavelength, trench.isolation_type)
+        }
+        None => 1.0,
+    };
+    let screened_peak_particle_velocity = peak_particle_velocity * amplitude_reduction_factor;
+    let allowable_peak_particle_velocity = get_allowable_velocity(structure_category, frequency);
+
+    Ok(VibrationResult {
+        shear_wave_velocity,
+        damping_ratio,
+        wavelength,
+        attenuation_coefficient,
+        peak_particle_velocity,
+        amplitude_reduction_factor,
+        screened_peak_particle_velocity,
+        allowable_peak_particle_velocity,
+        is_safe: screened_peak_particle_velocity <= allowable_peak_particle_velocity,
+    })
+}
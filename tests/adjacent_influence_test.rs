@@ -0,0 +1,140 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    adjacent_influence::{calc_adjacent_influence, AllowableIncrement},
+    enums::PressureConvention,
+    models::{
+        adjacent_structure::AdjacentStructure,
+        foundation::Foundation,
+        soil_profile::{SoilLayer, SoilProfile},
+    },
+};
+
+fn create_profile() -> SoilProfile {
+    SoilProfile::new(
+        vec![SoilLayer {
+            thickness: Some(10.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(1.9),
+            mv: Some(0.01),
+            ..Default::default()
+        }],
+        20.0,
+    )
+}
+
+fn create_foundation() -> Foundation {
+    Foundation {
+        foundation_depth: Some(0.0),
+        foundation_width: Some(2.0),
+        foundation_length: Some(2.0),
+        ..Default::default()
+    }
+}
+
+fn create_adjacent(offset_x: f64) -> AdjacentStructure {
+    AdjacentStructure {
+        width: Some(2.0),
+        length: Some(2.0),
+        depth: Some(0.0),
+        offset_x: Some(offset_x),
+        offset_y: Some(0.0),
+        foundation_pressure: Some(15.0),
+    }
+}
+
+#[test]
+fn test_calc_adjacent_influence() {
+    let result = calc_adjacent_influence(
+        &create_profile(),
+        &create_foundation(),
+        10.0,
+        PressureConvention::Gross,
+        &create_adjacent(3.0),
+        &AllowableIncrement::default(),
+    )
+    .unwrap();
+
+    assert_eq!(result.layers.len(), 1);
+    assert_abs_diff_eq!(
+        result.layers[0].center_stress_increase,
+        0.3524,
+        epsilon = 1e-4
+    );
+    assert_abs_diff_eq!(
+        result.layers[0].edge_stress_increase,
+        0.5114,
+        epsilon = 1e-4
+    );
+    assert_abs_diff_eq!(result.center_settlement, 3.524, epsilon = 1e-3);
+    assert_abs_diff_eq!(result.edge_settlement, 5.114, epsilon = 1e-3);
+    assert_abs_diff_eq!(result.stress_increment_ratio, 0.5114 / 15.0, epsilon = 1e-4);
+    assert_abs_diff_eq!(result.tilt, 0.0159, epsilon = 1e-4);
+    assert!(!result.is_safe);
+}
+
+#[test]
+fn test_calc_adjacent_influence_distant_structure() {
+    let near = calc_adjacent_influence(
+        &create_profile(),
+        &create_foundation(),
+        10.0,
+        PressureConvention::Gross,
+        &create_adjacent(3.0),
+        &AllowableIncrement::default(),
+    )
+    .unwrap();
+    let far = calc_adjacent_influence(
+        &create_profile(),
+        &create_foundation(),
+        10.0,
+        PressureConvention::Gross,
+        &create_adjacent(30.0),
+        &AllowableIncrement::default(),
+    )
+    .unwrap();
+
+    assert!(far.max_stress_increase < near.max_stress_increase);
+    assert!(far.center_settlement < 0.1);
+    assert!(far.is_safe);
+}
+
+#[test]
+fn test_calc_adjacent_influence_deeper_neighbour() {
+    let adjacent = AdjacentStructure {
+        depth: Some(4.0),
+        ..create_adjacent(3.0)
+    };
+
+    let result = calc_adjacent_influence(
+        &create_profile(),
+        &create_foundation(),
+        10.0,
+        PressureConvention::Gross,
+        &adjacent,
+        &AllowableIncrement::default(),
+    )
+    .unwrap();
+
+    assert_abs_diff_eq!(result.layers[0].top, 4.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.layers[0].bottom, 10.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_calc_adjacent_influence_invalid_input() {
+    let adjacent = AdjacentStructure {
+        foundation_pressure: None,
+        ..create_adjacent(3.0)
+    };
+
+    let err = calc_adjacent_influence(
+        &create_profile(),
+        &create_foundation(),
+        10.0,
+        PressureConvention::Gross,
+        &adjacent,
+        &AllowableIncrement::default(),
+    )
+    .unwrap_err();
+
+    assert_eq!(err.code, "adjacent_structure.foundation_pressure.missing");
+}
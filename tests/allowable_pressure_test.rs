@@ -0,0 +1,104 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    allowable_pressure::calc_allowable_pressure_for_settlement,
+    elastic_settlement::boussinesq::calc_elastic_settlement,
+    enums::{GoverningCriterion, MethodId, PressureConvention},
+    models::{
+        foundation::Foundation,
+        soil_profile::{SoilLayer, SoilProfile},
+    },
+};
+
+fn create_soil_profile(elastic_modulus: f64) -> SoilProfile {
+    SoilProfile::new(
+        vec![SoilLayer {
+            thickness: Some(20.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(1.9),
+            c_prime: Some(0.0),
+            phi_prime: Some(32.0),
+            cu: Some(0.0),
+            phi_u: Some(32.0),
+            poissons_ratio: Some(0.3),
+            elastic_modulus: Some(elastic_modulus),
+            ..Default::default()
+        }],
+        10.0,
+    )
+}
+
+fn create_foundation() -> Foundation {
+    Foundation {
+        foundation_depth: Some(1.0),
+        foundation_width: Some(2.0),
+        foundation_length: Some(2.0),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_settlement_governs() {
+    let profile = create_soil_profile(300.0);
+    let foundation = create_foundation();
+
+    let result =
+        calc_allowable_pressure_for_settlement(&profile, &foundation, 2.5, MethodId::Vesic)
+            .unwrap();
+
+    assert_eq!(result.governing, GoverningCriterion::Settlement);
+    assert!(result.consolidation_method.is_none());
+    assert!(result.allowable_pressure < result.allowable_bearing_capacity);
+    assert_eq!(result.settlement_pressure, Some(result.allowable_pressure));
+    assert_abs_diff_eq!(result.settlement, 2.5, epsilon = 1e-3);
+
+    // The governing pressure reproduces the settlement limit
+    let settlement = calc_elastic_settlement(
+        &mut profile.clone(),
+        &foundation,
+        result.allowable_pressure,
+        PressureConvention::Gross,
+    )
+    .unwrap();
+    assert_abs_diff_eq!(settlement.total_settlement, 2.5, epsilon = 1e-3);
+}
+
+#[test]
+fn test_capacity_governs() {
+    let result = calc_allowable_pressure_for_settlement(
+        &create_soil_profile(30000.0),
+        &create_foundation(),
+        2.5,
+        MethodId::Vesic,
+    )
+    .unwrap();
+
+    assert_eq!(result.governing, GoverningCriterion::BearingCapacity);
+    assert!(result.settlement_pressure.is_none());
+    assert_abs_diff_eq!(
+        result.allowable_pressure,
+        result.allowable_bearing_capacity,
+        epsilon = 1e-9
+    );
+    assert!(result.settlement <= 2.5);
+}
+
+#[test]
+fn test_invalid_input() {
+    let err = calc_allowable_pressure_for_settlement(
+        &create_soil_profile(300.0),
+        &create_foundation(),
+        0.0,
+        MethodId::Vesic,
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "allowable_pressure.limit_cm.too_small.0.0001");
+
+    let err = calc_allowable_pressure_for_settlement(
+        &create_soil_profile(300.0),
+        &create_foundation(),
+        2.5,
+        MethodId::TezcanOzdemir,
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "allowable_pressure.method.unsupported");
+}
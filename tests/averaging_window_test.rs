@@ -0,0 +1,171 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    bearing_capacity::spt::{calc_bearing_capacity, calc_bearing_capacity_with_window},
+    enums::{PressureConvention, SelectionMethod},
+    helper::{calc_thickness_weighted_average, calc_window_average},
+    models::{
+        averaging_window::AveragingWindow,
+        cpt::{CPTExp, CPTLayer},
+        foundation::Foundation,
+        soil_profile::{SoilLayer, SoilProfile},
+        spt::{NValue, SPTExp, SPT},
+    },
+};
+
+#[test]
+fn test_calc_bounds() {
+    let (top, bottom) = AveragingWindow::shallow_foundation().calc_bounds(1.0, 2.0);
+    assert_abs_diff_eq!(top, 1.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(bottom, 3.0, epsilon = 1e-9);
+
+    let (top, bottom) = AveragingWindow::pile_tip().calc_bounds(10.0, 0.6);
+    assert_abs_diff_eq!(top, 9.7, epsilon = 1e-9);
+    assert_abs_diff_eq!(bottom, 10.9, epsilon = 1e-9);
+
+    // The window is limited to the ground surface
+    let (top, _) = AveragingWindow::new(2.0, 1.0).calc_bounds(1.0, 2.0);
+    assert_abs_diff_eq!(top, 0.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_validate() {
+    assert!(AveragingWindow::default().validate().is_ok());
+
+    let err = AveragingWindow::new(-0.5, 1.0).validate().unwrap_err();
+    assert_eq!(err.code, "averaging_window.above.too_small.0");
+
+    let err = AveragingWindow::new(0.0, 0.0).validate().unwrap_err();
+    assert_eq!(err.code, "averaging_window.empty");
+}
+
+#[test]
+fn test_calc_window_average() {
+    let samples = [(1.0, 10.0), (2.0, 20.0), (3.0, 30.0)];
+
+    assert_eq!(calc_window_average(&samples, 1.5, 3.0), Some(25.0));
+    // No sample in the window: the first sample below the top is used
+    assert_eq!(calc_window_average(&samples, 1.2, 1.8), Some(20.0));
+    // The data ends above the window: the deepest sample is used
+    assert_eq!(calc_window_average(&samples, 5.0, 6.0), Some(30.0));
+    assert_eq!(calc_window_average(&[], 0.0, 1.0), None);
+}
+
+#[test]
+fn test_calc_thickness_weighted_average() {
+    let intervals = [(0.0, 2.0, 100.0), (2.0, 5.0, 400.0)];
+
+    // 1 m of 100 and 2 m of 400
+    assert_abs_diff_eq!(
+        calc_thickness_weighted_average(&intervals, 1.0, 4.0).unwrap(),
+        300.0,
+        epsilon = 1e-9
+    );
+    assert!(calc_thickness_weighted_average(&intervals, 6.0, 7.0).is_none());
+}
+
+#[test]
+fn test_calc_average_elastic_modulus() {
+    let profile = SoilProfile::new(
+        vec![
+            SoilLayer {
+                thickness: Some(2.0),
+                elastic_modulus: Some(1000.0),
+                ..Default::default()
+            },
+            SoilLayer {
+                thickness: Some(10.0),
+                elastic_modulus: Some(2000.0),
+                elastic_modulus_gradient: Some(100.0),
+                ..Default::default()
+            },
+        ],
+        10.0,
+    );
+
+    // 1 m of 1000 and 2 m with the modulus at 1 m below the top of the second layer (2100)
+    let (top, bottom) = AveragingWindow::new(0.0, 1.5).calc_bounds(1.0, 2.0);
+    assert_abs_diff_eq!(
+        profile.calc_average_elastic_modulus(top, bottom).unwrap(),
+        (1000.0 + 2.0 * 2100.0) / 3.0,
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_calc_window_cone_resistance() {
+    let exp = CPTExp::new(
+        vec![
+            CPTLayer::new(9.0, 5.0, 0.1, None),
+            CPTLayer::new(9.8, 8.0, 0.1, None),
+            CPTLayer::new(10.4, 12.0, 0.1, None),
+            CPTLayer::new(11.5, 20.0, 0.1, None),
+        ],
+        "cpt1".to_string(),
+    );
+
+    // 0.5B above to 1.5B below a 0.6 m pile tip at 10 m: 9.7 m to 10.9 m
+    assert_abs_diff_eq!(
+        exp.calc_window_cone_resistance(&AveragingWindow::pile_tip(), 10.0, 0.6),
+        10.0,
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_spt_bearing_capacity_with_window() {
+    let mut exp = SPTExp::new(vec![], "exp1".to_string());
+    exp.add_blow(1.5, NValue::Value(18));
+    exp.add_blow(2.5, NValue::Value(22));
+    exp.add_blow(3.5, NValue::Value(30));
+    let mut spt = SPT::new(1.0, 1.0, 1.0, SelectionMethod::Min);
+    spt.add_exp(exp);
+
+    let foundation = Foundation {
+        foundation_depth: Some(1.0),
+        foundation_width: Some(2.0),
+        foundation_length: Some(2.0),
+        ..Default::default()
+    };
+
+    // The default window averages N60 from Df to Df + B
+    let default = calc_bearing_capacity(
+        &spt,
+        &SoilProfile::default(),
+        &foundation,
+        10.0,
+        PressureConvention::Gross,
+        2.5,
+    )
+    .unwrap();
+    assert_abs_diff_eq!(default.n60, 20.0, epsilon = 1e-9);
+    assert_eq!(
+        default.averaging_window,
+        AveragingWindow::shallow_foundation()
+    );
+
+    // Df to Df + 1.5B
+    let result = calc_bearing_capacity_with_window(
+        &spt,
+        &SoilProfile::default(),
+        &foundation,
+        10.0,
+        PressureConvention::Gross,
+        2.5,
+        AveragingWindow::new(0.0, 1.5),
+    )
+    .unwrap();
+    assert_abs_diff_eq!(result.n60, 70.0 / 3.0, epsilon = 1e-9);
+    assert!(result.allowable_bearing_capacity > default.allowable_bearing_capacity);
+
+    let err = calc_bearing_capacity_with_window(
+        &spt,
+        &SoilProfile::default(),
+        &foundation,
+        10.0,
+        PressureConvention::Gross,
+        2.5,
+        AveragingWindow::new(0.0, -1.0),
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "averaging_window.below.too_small.0");
+}
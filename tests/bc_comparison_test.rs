@@ -0,0 +1,157 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    bearing_capacity::{comparison::*, vesic},
+    enums::{AnalysisTerm, BearingCapacityMethod, PressureConvention, SelectionMethod},
+    models::{
+        foundation::Foundation,
+        loads::Loads,
+        masw::{Masw, MaswExp, MaswLayer},
+        soil_profile::{SoilLayer, SoilProfile},
+    },
+};
+
+fn create_soil_profile() -> SoilProfile {
+    SoilProfile::new(
+        vec![SoilLayer {
+            thickness: Some(20.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(2.0),
+            c_prime: Some(0.0),
+            phi_prime: Some(30.0),
+            ..Default::default()
+        }],
+        10.0,
+    )
+}
+
+fn create_masw() -> Masw {
+    Masw {
+        exps: vec![MaswExp {
+            layers: vec![MaswLayer {
+                thickness: Some(20.0),
+                depth: Some(20.0),
+                vs: Some(300.0),
+                vp: Some(0.0),
+            }],
+            name: "MASW-1".to_string(),
+        }],
+        idealization_method: SelectionMethod::Min,
+    }
+}
+
+fn create_foundation() -> Foundation {
+    Foundation {
+        foundation_depth: Some(1.0),
+        foundation_width: Some(2.0),
+        foundation_length: Some(2.0),
+        ..Default::default()
+    }
+}
+
+fn create_loads() -> Loads {
+    Loads {
+        vertical_load: Some(100.0),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_compare_methods() {
+    let data = BearingCapacityData {
+        soil_profile: Some(create_soil_profile()),
+        masw: Some(create_masw()),
+        ..Default::default()
+    };
+
+    let result = compare_methods(
+        &data,
+        &create_foundation(),
+        &create_loads(),
+        25.0,
+        3.0,
+        AnalysisTerm::Long,
+    )
+    .unwrap();
+
+    let methods: Vec<_> = result.rows.iter().map(|row| row.method).collect();
+    assert_eq!(
+        methods,
+        vec![
+            BearingCapacityMethod::Vesic,
+            BearingCapacityMethod::Meyerhof,
+            BearingCapacityMethod::Hansen,
+            BearingCapacityMethod::TezcanOzdemir,
+        ]
+    );
+    assert_eq!(result.skipped.len(), 2);
+    assert_eq!(result.skipped[0].method, BearingCapacityMethod::PointLoad);
+    assert_eq!(
+        result.skipped[0].reason,
+        "bearing_capacity_data.point_load_test.missing"
+    );
+    assert_eq!(result.skipped[1].method, BearingCapacityMethod::SptBased);
+
+    // Exactly one governing row, holding the minimum allowable capacity
+    let governing: Vec<_> = result.rows.iter().filter(|row| row.is_governing).collect();
+    assert_eq!(governing.len(), 1);
+    assert_eq!(governing[0].method, result.governing_method);
+    for row in &result.rows {
+        assert!(row.allowable_bearing_capacity >= result.governing_bearing_capacity);
+    }
+
+    // Rows match the individual methods
+    let single = vesic::calc_bearing_capacity(
+        &mut create_soil_profile(),
+        &mut create_foundation(),
+        &create_loads(),
+        25.0,
+        PressureConvention::Gross,
+        3.0,
+        AnalysisTerm::Long,
+    )
+    .unwrap();
+    assert_abs_diff_eq!(
+        result.rows[0].ultimate_bearing_capacity.unwrap(),
+        single.ultimate_bearing_capacity,
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_compare_methods_without_data() {
+    let err = compare_methods(
+        &BearingCapacityData::default(),
+        &create_foundation(),
+        &create_loads(),
+        25.0,
+        3.0,
+        AnalysisTerm::Long,
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "method_comparison.no_applicable_method");
+}
+
+#[test]
+fn test_unsupported_method() {
+    let data = BearingCapacityData {
+        soil_profile: Some(create_soil_profile()),
+        ..Default::default()
+    };
+
+    for method in [
+        BearingCapacityMethod::Terzaghi,
+        BearingCapacityMethod::CptBased,
+    ] {
+        let err = calc_bearing_capacity(
+            method,
+            &data,
+            &create_foundation(),
+            &create_loads(),
+            25.0,
+            3.0,
+            AnalysisTerm::Long,
+        )
+        .unwrap_err();
+        assert_eq!(err.code, "bearing_capacity_data.method.unsupported");
+    }
+}
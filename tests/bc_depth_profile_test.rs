@@ -0,0 +1,108 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    bearing_capacity::{depth_profile::*, hansen},
+    enums::{AnalysisTerm, MethodId, PressureConvention},
+    models::{
+        foundation::Foundation,
+        loads::Loads,
+        soil_profile::{SoilLayer, SoilProfile},
+    },
+};
+
+fn create_soil_profile() -> SoilProfile {
+    SoilProfile::new(
+        vec![SoilLayer {
+            thickness: Some(20.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(2.0),
+            c_prime: Some(0.0),
+            phi_prime: Some(30.0),
+            ..Default::default()
+        }],
+        10.0,
+    )
+}
+
+fn create_foundation() -> Foundation {
+    Foundation {
+        foundation_depth: Some(1.0),
+        foundation_width: Some(2.0),
+        foundation_length: Some(2.0),
+        ..Default::default()
+    }
+}
+
+fn create_loads() -> Loads {
+    Loads {
+        vertical_load: Some(100.0),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_generate_candidate_depths() {
+    let depths = generate_candidate_depths(0.5, 5.0, 0.5).unwrap();
+    assert_eq!(depths.len(), 10);
+    assert_abs_diff_eq!(depths[0], 0.5, epsilon = 1e-9);
+    assert_abs_diff_eq!(depths[9], 5.0, epsilon = 1e-9);
+
+    let err = generate_candidate_depths(0.5, 5.0, 0.0).unwrap_err();
+    assert_eq!(err.code, "bearing_capacity_profile.step.too_small.0.0001");
+}
+
+#[test]
+fn test_calc_bearing_capacity_profile() {
+    let depths = generate_candidate_depths(0.5, 5.0, 0.5).unwrap();
+
+    let result = calc_bearing_capacity_profile(
+        &create_soil_profile(),
+        &create_foundation(),
+        &create_loads(),
+        &depths,
+        3.0,
+        AnalysisTerm::Long,
+        MethodId::Hansen,
+    )
+    .unwrap();
+
+    assert_eq!(result.points.len(), depths.len());
+    assert_eq!(result.method, MethodId::Hansen);
+
+    // Capacity increases with embedment in a uniform sand above the groundwater level
+    for pair in result.points.windows(2) {
+        assert!(pair[1].allowable_bearing_capacity > pair[0].allowable_bearing_capacity);
+    }
+
+    // Each point matches a single analysis at the same depth
+    let single = hansen::calc_bearing_capacity(
+        &mut create_soil_profile(),
+        &mut create_foundation(),
+        &create_loads(),
+        0.0,
+        PressureConvention::Gross,
+        3.0,
+        AnalysisTerm::Long,
+    )
+    .unwrap();
+    assert_abs_diff_eq!(result.points[1].foundation_depth, 1.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(
+        result.points[1].allowable_bearing_capacity,
+        single.allowable_bearing_capacity,
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_unsupported_method() {
+    let err = calc_bearing_capacity_profile(
+        &create_soil_profile(),
+        &create_foundation(),
+        &create_loads(),
+        &[1.0],
+        3.0,
+        AnalysisTerm::Long,
+        MethodId::TezcanOzdemir,
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "bearing_capacity_profile.method.unsupported");
+}
@@ -0,0 +1,102 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    bearing_capacity::engineered_fill::calc_bearing_capacity,
+    enums::PressureConvention,
+    models::{
+        engineered_fill::EngineeredFill,
+        foundation::Foundation,
+        soil_profile::{SoilLayer, SoilProfile},
+    },
+};
+
+fn create_soil_profile() -> SoilProfile {
+    SoilProfile::new(
+        vec![SoilLayer {
+            thickness: Some(10.0),
+            cu: Some(3.0),
+            ..Default::default()
+        }],
+        5.0,
+    )
+}
+
+fn create_foundation() -> Foundation {
+    Foundation {
+        foundation_depth: Some(0.5),
+        foundation_width: Some(2.0),
+        foundation_length: Some(2.0),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_punching_governs() {
+    let fill = EngineeredFill::new(2.0, 2.0, 35.0, 0.0, 1.5, 1.0, 30.0);
+
+    let result = calc_bearing_capacity(
+        &create_soil_profile(),
+        &fill,
+        &create_foundation(),
+        20.0,
+        PressureConvention::Gross,
+        3.0,
+    )
+    .unwrap();
+
+    assert_abs_diff_eq!(result.fill_thickness_below_base, 1.5, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.spread_width, 3.73205, epsilon = 1e-5);
+    assert_abs_diff_eq!(result.interface_stress, 5.45655, epsilon = 1e-5);
+    assert_abs_diff_eq!(result.interface_capacity, 18.504, epsilon = 1e-5);
+    assert!(result.is_interface_safe);
+
+    assert_abs_diff_eq!(result.fill_base_width, 10.0, epsilon = 1e-9);
+    assert!(result.is_footprint_sufficient);
+
+    assert_abs_diff_eq!(result.punching_shear_coefficient, 0.42642, epsilon = 1e-5);
+    assert_abs_diff_eq!(result.punching_capacity, 21.74339, epsilon = 1e-4);
+    assert!(result.fill_capacity > result.punching_capacity);
+    assert_abs_diff_eq!(
+        result.ultimate_bearing_capacity,
+        result.punching_capacity,
+        epsilon = 1e-9
+    );
+    assert!(!result.is_safe);
+}
+
+#[test]
+fn test_narrow_fill_footprint() {
+    let mut fill = EngineeredFill::new(2.0, 2.0, 35.0, 0.0, 0.0, 0.0, 30.0);
+    fill.punching_shear_coefficient = Some(4.0);
+
+    let result = calc_bearing_capacity(
+        &create_soil_profile(),
+        &fill,
+        &create_foundation(),
+        5.0,
+        PressureConvention::Gross,
+        3.0,
+    )
+    .unwrap();
+
+    assert!(!result.is_footprint_sufficient);
+    assert!(!result.is_safe);
+}
+
+#[test]
+fn test_foundation_below_fill() {
+    let fill = EngineeredFill::new(0.5, 2.0, 35.0, 0.0, 1.5, 1.0, 30.0);
+
+    let result = calc_bearing_capacity(
+        &create_soil_profile(),
+        &fill,
+        &create_foundation(),
+        20.0,
+        PressureConvention::Gross,
+        3.0,
+    );
+
+    assert_eq!(
+        result.unwrap_err().code,
+        "engineered_fill.thickness.smaller_than_foundation_depth"
+    );
+}
@@ -0,0 +1,168 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    bearing_capacity::{eurocode::*, vesic},
+    enums::{AnalysisTerm, DesignApproach, MethodId, PressureConvention},
+    models::{
+        foundation::Foundation,
+        loads::Loads,
+        soil_profile::{SoilLayer, SoilProfile},
+    },
+};
+
+fn create_soil_profile() -> SoilProfile {
+    SoilProfile::new(
+        vec![SoilLayer {
+            thickness: Some(20.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(2.0),
+            c_prime: Some(1.0),
+            phi_prime: Some(30.0),
+            ..Default::default()
+        }],
+        10.0,
+    )
+}
+
+fn create_foundation() -> Foundation {
+    Foundation {
+        foundation_depth: Some(1.0),
+        foundation_width: Some(2.0),
+        foundation_length: Some(2.0),
+        ..Default::default()
+    }
+}
+
+fn create_loads() -> Loads {
+    Loads {
+        vertical_load: Some(100.0),
+        ..Default::default()
+    }
+}
+
+fn create_actions() -> CharacteristicActions {
+    CharacteristicActions {
+        permanent: 15.0,
+        variable: 10.0,
+    }
+}
+
+#[test]
+fn test_partial_factors() {
+    let da1_1 = PartialFactors::for_approach(DesignApproach::Da1Combination1);
+    assert_abs_diff_eq!(da1_1.permanent_action, 1.35, epsilon = 1e-9);
+    assert_abs_diff_eq!(da1_1.friction_angle, 1.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(da1_1.resistance, 1.0, epsilon = 1e-9);
+
+    let da1_2 = PartialFactors::for_approach(DesignApproach::Da1Combination2);
+    assert_abs_diff_eq!(da1_2.permanent_action, 1.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(da1_2.variable_action, 1.3, epsilon = 1e-9);
+    assert_abs_diff_eq!(da1_2.undrained_strength, 1.4, epsilon = 1e-9);
+
+    let da2 = PartialFactors::for_approach(DesignApproach::Da2);
+    assert_abs_diff_eq!(da2.resistance, 1.4, epsilon = 1e-9);
+
+    let da3 = PartialFactors::for_approach(DesignApproach::Da3);
+    assert_abs_diff_eq!(da3.variable_action, 1.5, epsilon = 1e-9);
+    assert_abs_diff_eq!(da3.effective_cohesion, 1.25, epsilon = 1e-9);
+}
+
+#[test]
+fn test_apply_material_factors() {
+    let factors = PartialFactors::for_approach(DesignApproach::Da3);
+    let design = apply_material_factors(&create_soil_profile(), &factors);
+
+    // tan φd = tan 30° / 1.25
+    assert_abs_diff_eq!(design.layers[0].phi_prime.unwrap(), 24.7913, epsilon = 1e-4);
+    assert_abs_diff_eq!(design.layers[0].c_prime.unwrap(), 0.8, epsilon = 1e-9);
+}
+
+#[test]
+fn test_calc_bearing_capacity_da2() {
+    let result = calc_bearing_capacity(
+        &create_soil_profile(),
+        &create_foundation(),
+        &create_loads(),
+        &create_actions(),
+        DesignApproach::Da2,
+        AnalysisTerm::Long,
+        MethodId::Vesic,
+    )
+    .unwrap();
+
+    // Ed = 1.35 · 15 + 1.5 · 10
+    assert_abs_diff_eq!(result.design_pressure, 35.25, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.design_load, 141.0, epsilon = 1e-9);
+
+    // M1 leaves the soil strength unchanged, R2 divides the resistance by 1.4
+    let characteristic = vesic::calc_bearing_capacity(
+        &mut create_soil_profile(),
+        &mut create_foundation(),
+        &Loads {
+            vertical_load: Some(141.0),
+            ..Default::default()
+        },
+        0.0,
+        PressureConvention::Gross,
+        1.0,
+        AnalysisTerm::Long,
+    )
+    .unwrap();
+    assert_abs_diff_eq!(
+        result.design_resistance_pressure,
+        characteristic.ultimate_bearing_capacity / 1.4,
+        epsilon = 1e-9
+    );
+    assert_abs_diff_eq!(
+        result.design_resistance,
+        result.design_resistance_pressure * 4.0,
+        epsilon = 1e-9
+    );
+    assert_abs_diff_eq!(
+        result.utilization,
+        result.design_load / result.design_resistance,
+        epsilon = 1e-9
+    );
+    assert!(result.is_safe);
+}
+
+#[test]
+fn test_design_approaches_order() {
+    let calc = |approach| {
+        calc_bearing_capacity(
+            &create_soil_profile(),
+            &create_foundation(),
+            &create_loads(),
+            &create_actions(),
+            approach,
+            AnalysisTerm::Long,
+            MethodId::Vesic,
+        )
+        .unwrap()
+    };
+
+    // Factored strength governs for a drained footing on sand
+    let da1_1 = calc(DesignApproach::Da1Combination1);
+    let da1_2 = calc(DesignApproach::Da1Combination2);
+    let da3 = calc(DesignApproach::Da3);
+    assert!(da1_2.design_resistance < da1_1.design_resistance);
+    assert!(da3.utilization > da1_2.utilization);
+    assert_abs_diff_eq!(da1_2.design_pressure, 28.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_invalid_input() {
+    let err = calc_bearing_capacity(
+        &create_soil_profile(),
+        &create_foundation(),
+        &create_loads(),
+        &CharacteristicActions {
+            permanent: -1.0,
+            variable: 0.0,
+        },
+        DesignApproach::Da2,
+        AnalysisTerm::Long,
+        MethodId::Vesic,
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "ec7.permanent.too_small.0");
+}
@@ -0,0 +1,160 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    bearing_capacity::{hansen::*, vesic},
+    enums::{AnalysisTerm, MethodId, PressureConvention},
+    models::{
+        foundation::Foundation,
+        loads::Loads,
+        soil_profile::{SoilLayer, SoilProfile},
+    },
+};
+
+#[test]
+fn test_calc_bearing_capacity_factors() {
+    let result = calc_bearing_capacity_factors(0.0);
+    assert_abs_diff_eq!(result.nc, 5.14, epsilon = 1e-3);
+    assert_abs_diff_eq!(result.nq, 1.0, epsilon = 1e-3);
+    assert_abs_diff_eq!(result.ng, 0.0, epsilon = 1e-3);
+
+    let result = calc_bearing_capacity_factors(30.0);
+    assert_abs_diff_eq!(result.nq, 18.401, epsilon = 1e-3);
+    assert_abs_diff_eq!(result.ng, 15.070, epsilon = 1e-3);
+}
+
+#[test]
+fn test_calc_depth_factors() {
+    let mut foundation = Foundation {
+        foundation_depth: Some(1.0),
+        foundation_width: Some(2.0),
+        ..Foundation::default()
+    };
+    let result = calc_depth_factors(&foundation, 30.0);
+    assert_abs_diff_eq!(result.dc, 1.2, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.dq, 1.1443, epsilon = 1e-4);
+
+    // Df/B > 1: k = atan(Df/B)
+    foundation.foundation_depth = Some(4.0);
+    let result = calc_depth_factors(&foundation, 30.0);
+    assert_abs_diff_eq!(result.dc, 1.44286, epsilon = 1e-5);
+    assert_abs_diff_eq!(result.dq, 1.31961, epsilon = 1e-5);
+    assert_abs_diff_eq!(result.dg, 1.0, epsilon = 1e-9);
+
+    let result = calc_depth_factors(&foundation, 0.0);
+    assert_abs_diff_eq!(result.dc, 0.44286, epsilon = 1e-5);
+}
+
+#[test]
+fn test_calc_ground_and_base_factors() {
+    let ground = calc_ground_factors(10.0, 30.0);
+    assert_abs_diff_eq!(ground.gc, 0.93197, epsilon = 1e-5);
+    assert_abs_diff_eq!(ground.gq, 0.63035, epsilon = 1e-5);
+
+    let foundation = Foundation {
+        base_tilt_angle: Some(10.0),
+        ..Foundation::default()
+    };
+    let base = calc_base_factors(30.0, &foundation);
+    assert_abs_diff_eq!(base.bc, 0.93197, epsilon = 1e-5);
+    assert_abs_diff_eq!(base.bq, 0.81747, epsilon = 1e-5);
+    assert_abs_diff_eq!(base.bg, 0.76180, epsilon = 1e-5);
+}
+
+#[test]
+fn test_calc_inclination_factors() {
+    let foundation = Foundation {
+        effective_width: Some(2.0),
+        effective_length: Some(2.0),
+        ..Foundation::default()
+    };
+    let loads = Loads {
+        vertical_load: Some(100.0),
+        horizontal_load_x: Some(10.0),
+        ..Default::default()
+    };
+    let factors = calc_bearing_capacity_factors(30.0);
+
+    let result = calc_inclination_factors(30.0, 0.0, factors, &foundation, &loads);
+    assert_abs_diff_eq!(result.iq, 0.95_f64.powi(5), epsilon = 1e-9);
+    assert_abs_diff_eq!(result.ig, 0.93_f64.powi(5), epsilon = 1e-9);
+    assert_abs_diff_eq!(
+        result.ic,
+        result.iq - (1.0 - result.iq) / (factors.nq - 1.0),
+        epsilon = 1e-9
+    );
+
+    // φ = 0: i'c = 0.5 − 0.5 · √(1 − H / (A' · ca)), ca = 0.75 · 10
+    let result = calc_inclination_factors(0.0, 10.0, factors, &foundation, &loads);
+    assert_abs_diff_eq!(
+        result.ic,
+        0.5 - 0.5 * (1.0 - 10.0 / 30.0_f64).sqrt(),
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_calc_bearing_capacity() {
+    let create_soil_profile = || {
+        SoilProfile::new(
+            vec![SoilLayer {
+                thickness: Some(20.0),
+                dry_unit_weight: Some(1.8),
+                saturated_unit_weight: Some(2.0),
+                c_prime: Some(0.0),
+                phi_prime: Some(30.0),
+                ..Default::default()
+            }],
+            20.0,
+        )
+    };
+    let create_foundation = || Foundation {
+        foundation_depth: Some(1.0),
+        foundation_width: Some(2.0),
+        foundation_length: Some(2.0),
+        ..Foundation::default()
+    };
+    let loads = Loads {
+        vertical_load: Some(100.0),
+        ..Default::default()
+    };
+
+    let result = calc_bearing_capacity(
+        &mut create_soil_profile(),
+        &mut create_foundation(),
+        &loads,
+        25.0,
+        PressureConvention::Gross,
+        3.0,
+        AnalysisTerm::Long,
+    )
+    .unwrap();
+
+    let sq = 1.5;
+    let dq = 1.1443;
+    let expected = 1.8 * 18.401 * sq * dq + 0.5 * 1.8 * 2.0 * 15.070 * 0.6;
+    assert_abs_diff_eq!(result.ultimate_bearing_capacity, expected, epsilon = 1e-1);
+    assert_abs_diff_eq!(result.terms.part_2, 1.8 * 18.401 * sq * dq, epsilon = 1e-1);
+    assert_abs_diff_eq!(
+        result.terms.part_3,
+        0.5 * 1.8 * 2.0 * 15.070 * 0.6,
+        epsilon = 1e-1
+    );
+    assert_abs_diff_eq!(
+        result.terms.part_1 + result.terms.part_2 + result.terms.part_3,
+        result.ultimate_bearing_capacity,
+        epsilon = 1e-9
+    );
+    assert_eq!(result.method, MethodId::Hansen);
+
+    // Hansen's Nγ is lower than Vesic's
+    let vesic_result = vesic::calc_bearing_capacity(
+        &mut create_soil_profile(),
+        &mut create_foundation(),
+        &loads,
+        25.0,
+        PressureConvention::Gross,
+        3.0,
+        AnalysisTerm::Long,
+    )
+    .unwrap();
+    assert!(result.bearing_capacity_factors.ng < vesic_result.bearing_capacity_factors.ng);
+}
@@ -1,6 +1,8 @@
 use approx::assert_abs_diff_eq;
 use soilrust::bearing_capacity::helper_functions::*;
-use soilrust::enums::AnalysisTerm;
+use soilrust::enums::{
+    AnalysisTerm, EmpiricalGeometryAdjustment, GroundwaterCase, ShearFailureMode,
+};
 use soilrust::models::foundation::Foundation;
 use soilrust::models::soil_profile::{SoilLayer, SoilProfile};
 // ------------------------------------------------------------------------------------------------
@@ -16,6 +18,7 @@ fn test_compute_equivalent_unit_weights_1() {
             depth: Some(5.0),
             ..Default::default()
         }],
+        ..Default::default()
     };
     let (gamma_1, gamma_2) = compute_equivalent_unit_weights(&profile, 5.0);
     assert_abs_diff_eq!(gamma_1, 1.8, epsilon = 1e-3);
@@ -43,6 +46,7 @@ fn test_compute_equivalent_unit_weights_2() {
                 ..Default::default()
             },
         ],
+        ..Default::default()
     };
     let (gamma_1, gamma_2) = compute_equivalent_unit_weights(&profile, 5.0);
     assert!((gamma_1 - 1.78).abs() < 1e-3);
@@ -77,6 +81,7 @@ fn test_compute_equivalent_unit_weights_3() {
                 ..Default::default()
             },
         ],
+        ..Default::default()
     };
     let (gamma_1, gamma_2) = compute_equivalent_unit_weights(&profile, 7.0);
     assert!((gamma_1 - 1.8).abs() < 1e-3);
@@ -104,6 +109,7 @@ fn test_compute_equivalent_unit_weights_4() {
                 ..Default::default()
             },
         ],
+        ..Default::default()
     };
     let (gamma_1, gamma_2) = compute_equivalent_unit_weights(&profile, 3.0);
     assert!((gamma_1 - 1.7).abs() < 1e-3);
@@ -131,6 +137,7 @@ fn test_compute_equivalent_unit_weights_5() {
                 ..Default::default()
             },
         ],
+        ..Default::default()
     };
     let (gamma_1, gamma_2) = compute_equivalent_unit_weights(&profile, 4.0);
     assert!((gamma_1 - 1.725).abs() < 1e-3);
@@ -158,6 +165,7 @@ fn test_compute_equivalent_unit_weights_6() {
                 ..Default::default()
             },
         ],
+        ..Default::default()
     };
     let (gamma_1, gamma_2) = compute_equivalent_unit_weights(&profile, 10.0);
     assert!((gamma_1 - 1.75).abs() < 1e-3);
@@ -176,6 +184,7 @@ fn test_calc_effective_surcharge_1() {
             depth: Some(5.0),
             ..Default::default()
         }],
+        ..Default::default()
     };
     let building = Foundation {
         foundation_depth: Some(3.0),
@@ -202,6 +211,7 @@ fn test_calc_effective_surcharge_2() {
             depth: Some(5.0),
             ..Default::default()
         }],
+        ..Default::default()
     };
     let building = Foundation {
         foundation_depth: Some(5.0),
@@ -228,6 +238,7 @@ fn test_calc_effective_surcharge_3() {
             depth: Some(5.0),
             ..Default::default()
         }],
+        ..Default::default()
     };
     let building = Foundation {
         foundation_depth: Some(7.0),
@@ -254,6 +265,7 @@ fn test_calc_effective_surcharge_4() {
             depth: Some(5.0),
             ..Default::default()
         }],
+        ..Default::default()
     };
     let building = Foundation {
         foundation_depth: Some(7.0),
@@ -267,6 +279,42 @@ fn test_calc_effective_surcharge_4() {
         pressure
     );
 }
+/// Reduced soil cover on the downslope side of the foundation
+#[test]
+fn test_calc_effective_surcharge_downslope() {
+    let profile = SoilProfile {
+        ground_water_level: Some(2.0),
+        layers: vec![SoilLayer {
+            thickness: Some(5.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(2.0),
+            depth: Some(5.0),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    let building = Foundation {
+        foundation_depth: Some(5.0),
+        effective_width: Some(2.0),
+        downslope_embedment: Some(4.0),
+        ..Default::default()
+    };
+    // The downslope surface is 1 m lower, so 1 m of dry and 3 m of submerged cover remain
+    let pressure = calc_effective_surcharge(&profile, &building, AnalysisTerm::Short);
+    assert_abs_diff_eq!(pressure, 1.8 + 3.0 * 1.019, epsilon = 1e-3);
+
+    let building = Foundation {
+        foundation_depth: Some(3.0),
+        downslope_embedment: Some(1.0),
+        ..building
+    };
+    let profile = SoilProfile {
+        ground_water_level: Some(10.0),
+        ..profile
+    };
+    let pressure = calc_effective_surcharge(&profile, &building, AnalysisTerm::Short);
+    assert_abs_diff_eq!(pressure, 1.8, epsilon = 1e-3);
+}
 // ------------------------------------------------------------------------------------------------
 /// Case 1: Entire foundation is below groundwater level (gwt <= Df)
 #[test]
@@ -280,6 +328,7 @@ fn test_calc_effective_unit_weight_1() {
             depth: Some(5.0),
             ..Default::default()
         }],
+        ..Default::default()
     };
 
     let foundation = Foundation {
@@ -308,6 +357,7 @@ fn test_calc_effective_unit_weight_2() {
             depth: Some(4.0),
             ..Default::default()
         }],
+        ..Default::default()
     };
 
     let foundation = Foundation {
@@ -336,6 +386,7 @@ fn test_calc_effective_unit_weight_3() {
             depth: Some(4.0),
             ..Default::default()
         }],
+        ..Default::default()
     };
 
     let foundation = Foundation {
@@ -360,6 +411,7 @@ fn test_calc_effective_unit_weight_4() {
             depth: Some(4.0),
             ..Default::default()
         }],
+        ..Default::default()
     };
 
     let foundation = Foundation {
@@ -371,6 +423,73 @@ fn test_calc_effective_unit_weight_4() {
     let gamma = calc_effective_unit_weight(&profile, &foundation, AnalysisTerm::Long);
     assert!((gamma - 1.7).abs() < 1e-3, "Expected 1.7, got {}", gamma);
 }
+/// Case II per Das: γ̄ = γ' + d / B · (γ − γ') with d measured below the foundation base
+#[test]
+fn test_calc_effective_unit_weight_5() {
+    let profile = SoilProfile {
+        ground_water_level: Some(5.5),
+        layers: vec![SoilLayer {
+            thickness: Some(10.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(2.0),
+            depth: Some(10.0),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    let foundation = Foundation {
+        foundation_depth: Some(5.0),
+        effective_width: Some(2.0),
+        ..Default::default()
+    };
+
+    // γ' = 2.0 − 0.981 = 1.019, d = 0.5 m
+    let gamma = calc_effective_unit_weight(&profile, &foundation, AnalysisTerm::Short);
+    assert!(
+        (gamma - (1.019 + 0.25 * (1.8 - 1.019))).abs() < 1e-3,
+        "Expected 1.214, got {}",
+        gamma
+    );
+}
+
+#[test]
+fn test_classify_groundwater_case() {
+    let create_profile = |gwt: f64| SoilProfile {
+        ground_water_level: Some(gwt),
+        layers: vec![SoilLayer {
+            thickness: Some(10.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(2.0),
+            depth: Some(10.0),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    let foundation = Foundation {
+        foundation_depth: Some(2.0),
+        effective_width: Some(2.0),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        classify_groundwater_case(&create_profile(1.0), &foundation, AnalysisTerm::Short),
+        GroundwaterCase::CaseI
+    );
+    assert_eq!(
+        classify_groundwater_case(&create_profile(3.0), &foundation, AnalysisTerm::Short),
+        GroundwaterCase::CaseII
+    );
+    assert_eq!(
+        classify_groundwater_case(&create_profile(5.0), &foundation, AnalysisTerm::Short),
+        GroundwaterCase::CaseIII
+    );
+    // Drained conditions place the groundwater below the influence zone
+    assert_eq!(
+        classify_groundwater_case(&create_profile(1.0), &foundation, AnalysisTerm::Long),
+        GroundwaterCase::CaseIII
+    );
+}
 // ------------------------------------------------------------------------------------------------
 /// Case 1: Short-term loading — returns undrained cohesion and undrained friction angle
 #[test]
@@ -388,6 +507,7 @@ fn test_get_soil_params_1() {
             saturated_unit_weight: Some(2.0),
             ..Default::default()
         }],
+        ..Default::default()
     };
 
     let foundation = Foundation {
@@ -423,6 +543,7 @@ fn test_get_soil_params_2() {
             saturated_unit_weight: Some(2.1),
             ..Default::default()
         }],
+        ..Default::default()
     };
 
     let foundation = Foundation {
@@ -441,3 +562,128 @@ fn test_get_soil_params_2() {
         params.unit_weight
     );
 }
+
+/// Case 3: Local shear failure — Terzaghi reduced parameters
+#[test]
+fn test_get_soil_params_local_shear() {
+    let profile = SoilProfile {
+        ground_water_level: Some(10.0),
+        layers: vec![SoilLayer {
+            thickness: Some(5.0),
+            depth: Some(5.0),
+            c_prime: Some(3.0),
+            phi_prime: Some(30.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(2.0),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    let foundation = Foundation {
+        foundation_depth: Some(1.0),
+        effective_width: Some(2.0),
+        failure_mode: Some(ShearFailureMode::Local),
+        ..Default::default()
+    };
+
+    let params = get_soil_params(&profile, &foundation, AnalysisTerm::Long);
+
+    // tan φ* = 2/3 · tan 30°
+    assert_abs_diff_eq!(params.friction_angle, 21.052, epsilon = 1e-3);
+    assert_abs_diff_eq!(params.cohesion, 2.0, epsilon = 1e-9);
+    assert_eq!(params.failure_mode, ShearFailureMode::Local);
+}
+
+#[test]
+fn test_classify_failure_mode() {
+    let sand = |dr: f64| SoilLayer {
+        relative_density: Some(dr),
+        ..Default::default()
+    };
+    assert_eq!(
+        classify_failure_mode(&sand(80.0), AnalysisTerm::Long),
+        ShearFailureMode::General
+    );
+    assert_eq!(
+        classify_failure_mode(&sand(50.0), AnalysisTerm::Long),
+        ShearFailureMode::Local
+    );
+    assert_eq!(
+        classify_failure_mode(&sand(20.0), AnalysisTerm::Long),
+        ShearFailureMode::Punching
+    );
+
+    let clay = SoilLayer {
+        plasticity_index: Some(20.0),
+        cu: Some(2.0),
+        ..Default::default()
+    };
+    assert_eq!(
+        classify_failure_mode(&clay, AnalysisTerm::Short),
+        ShearFailureMode::Punching
+    );
+
+    // Unknown state defaults to general shear
+    assert_eq!(
+        classify_failure_mode(&SoilLayer::default(), AnalysisTerm::Long),
+        ShearFailureMode::General
+    );
+}
+// ------------------------------------------------------------------------------------------------
+#[test]
+fn test_calc_empirical_shape_factor() {
+    let strip = Foundation {
+        foundation_width: Some(1.0),
+        foundation_length: Some(100.0),
+        ..Default::default()
+    };
+    assert_abs_diff_eq!(calc_empirical_shape_factor(&strip), 1.002, epsilon = 1e-9);
+
+    let square = Foundation {
+        foundation_width: Some(2.0),
+        foundation_length: Some(2.0),
+        ..Default::default()
+    };
+    assert_abs_diff_eq!(calc_empirical_shape_factor(&square), 1.2, epsilon = 1e-9);
+    assert_abs_diff_eq!(
+        calc_empirical_shape_factor(&Foundation::default()),
+        1.0,
+        epsilon = 1e-9
+    );
+}
+// ------------------------------------------------------------------------------------------------
+#[test]
+fn test_calc_empirical_geometry_factors() {
+    let mut foundation = Foundation {
+        foundation_depth: Some(5.0),
+        foundation_width: Some(2.0),
+        foundation_length: Some(4.0),
+        ..Default::default()
+    };
+    assert_eq!(calc_empirical_geometry_factors(&foundation), (1.0, 1.0));
+
+    // 1 + 0.3 · 2 / 4
+    foundation.geometry_adjustment = Some(EmpiricalGeometryAdjustment::Terzaghi);
+    let (shape_factor, depth_factor) = calc_empirical_geometry_factors(&foundation);
+    assert_abs_diff_eq!(shape_factor, 1.15, epsilon = 1e-9);
+    assert_abs_diff_eq!(depth_factor, 1.0, epsilon = 1e-9);
+
+    foundation.geometry_adjustment = Some(EmpiricalGeometryAdjustment::Custom {
+        shape_factor: 1.1,
+        depth_factor: 1.2,
+    });
+    assert_eq!(calc_empirical_geometry_factors(&foundation), (1.1, 1.2));
+
+    foundation.geometry_adjustment = Some(EmpiricalGeometryAdjustment::Custom {
+        shape_factor: -1.0,
+        depth_factor: 1.0,
+    });
+    assert_eq!(
+        foundation
+            .validate(&["geometry_adjustment"])
+            .unwrap_err()
+            .code,
+        "foundation.geometry_adjustment.shape_factor.too_small.0"
+    );
+}
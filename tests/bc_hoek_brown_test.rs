@@ -0,0 +1,65 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    bearing_capacity::hoek_brown::*,
+    enums::{MethodId, PressureConvention},
+    models::{foundation::Foundation, rock_mass::RockMassHoekBrown, soil_profile::SoilProfile},
+};
+
+#[test]
+fn test_hoek_brown_constants() {
+    let rock_mass = RockMassHoekBrown::new(50.0, 10.0, 0.0, 50.0);
+
+    assert_abs_diff_eq!(rock_mass.calc_mb(), 1.67677, epsilon = 1e-5);
+    assert_abs_diff_eq!(rock_mass.calc_s(), 0.0038659, epsilon = 1e-7);
+    assert_abs_diff_eq!(rock_mass.calc_a(), 0.50573, epsilon = 1e-5);
+
+    // Intact rock
+    let intact = RockMassHoekBrown::new(100.0, 10.0, 0.0, 50.0);
+    assert_abs_diff_eq!(intact.calc_mb(), 10.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(intact.calc_s(), 1.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(intact.calc_a(), 0.5, epsilon = 1e-9);
+}
+
+#[test]
+fn test_calc_bearing_capacity() {
+    let rock_mass = RockMassHoekBrown::new(50.0, 10.0, 0.0, 50.0);
+    let foundation = Foundation {
+        foundation_depth: Some(1.0),
+        foundation_width: Some(2.0),
+        foundation_length: Some(2.0),
+        ..Default::default()
+    };
+
+    let result = calc_bearing_capacity(
+        &rock_mass,
+        &SoilProfile::default(),
+        &foundation,
+        100.0,
+        PressureConvention::Gross,
+        3.0,
+    )
+    .unwrap();
+
+    assert_abs_diff_eq!(result.shape_factor, 1.2, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.ultimate_bearing_capacity, 2324.205, epsilon = 1e-3);
+    assert_abs_diff_eq!(result.allowable_bearing_capacity, 774.735, epsilon = 1e-3);
+    assert!(result.is_safe);
+    assert_eq!(result.method, MethodId::HoekBrown);
+    assert!(result.warnings.is_empty());
+}
+
+#[test]
+fn test_invalid_disturbance_factor() {
+    let rock_mass = RockMassHoekBrown::new(50.0, 10.0, 1.5, 50.0);
+
+    let err = calc_bearing_capacity(
+        &rock_mass,
+        &SoilProfile::default(),
+        &Foundation::default(),
+        100.0,
+        PressureConvention::Gross,
+        3.0,
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "rock_mass.disturbance_factor.too_large.1");
+}
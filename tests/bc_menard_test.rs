@@ -0,0 +1,172 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    bearing_capacity::menard::*,
+    enums::{MethodId, PmtSoilCategory, PressureConvention, SelectionMethod},
+    models::{
+        foundation::Foundation,
+        pressuremeter::{PressuremeterExp, PressuremeterSample, PressuremeterTest},
+        soil_profile::{SoilLayer, SoilProfile},
+    },
+};
+
+fn create_soil_profile() -> SoilProfile {
+    SoilProfile::new(
+        vec![SoilLayer {
+            thickness: Some(20.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(2.0),
+            ..Default::default()
+        }],
+        10.0,
+    )
+}
+
+fn create_pressuremeter_test() -> PressuremeterTest {
+    let exp1 = PressuremeterExp::new(
+        "BH-1".to_string(),
+        vec![
+            PressuremeterSample::new(1.0, 50.0, 500.0),
+            PressuremeterSample::new(2.0, 80.0, 800.0),
+            PressuremeterSample::new(3.0, 100.0, 1000.0),
+            PressuremeterSample::new(4.0, 120.0, 1200.0),
+        ],
+    );
+    let exp2 = PressuremeterExp::new(
+        "BH-2".to_string(),
+        vec![
+            PressuremeterSample::new(2.0, 90.0, 900.0),
+            PressuremeterSample::new(3.0, 110.0, 1100.0),
+        ],
+    );
+
+    PressuremeterTest::new(vec![exp1, exp2], SelectionMethod::Min)
+}
+
+fn create_foundation() -> Foundation {
+    Foundation {
+        foundation_depth: Some(1.5),
+        foundation_width: Some(2.0),
+        foundation_length: Some(2.0),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_get_idealized_exp() {
+    let mut pmt = create_pressuremeter_test();
+
+    let exp = pmt.get_idealized_exp("idealized".to_string());
+    assert_eq!(exp.samples.len(), 4);
+    assert_abs_diff_eq!(exp.samples[1].limit_pressure.unwrap(), 80.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(
+        exp.samples[1].menard_modulus.unwrap(),
+        800.0,
+        epsilon = 1e-9
+    );
+
+    pmt.idealization_method = SelectionMethod::Avg;
+    let exp = pmt.get_idealized_exp("idealized".to_string());
+    assert_abs_diff_eq!(exp.samples[1].limit_pressure.unwrap(), 85.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(
+        exp.samples[1].menard_modulus.unwrap(),
+        850.0,
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_calc_net_limit_pressure() {
+    let mut profile = create_soil_profile();
+    profile.calc_layer_depths();
+
+    // p0 = 0.5 · 1.8 · 2 = 1.8
+    assert_abs_diff_eq!(
+        calc_net_limit_pressure(&profile, 2.0, 80.0),
+        78.2,
+        epsilon = 1e-9
+    );
+    // p0 = 0.5 · (1.8 · 10 + 1.019 · 2) + 0.981 · 2 = 11.98
+    assert_abs_diff_eq!(
+        calc_net_limit_pressure(&profile, 12.0, 80.0),
+        68.019,
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_calc_bearing_factor() {
+    let foundation = create_foundation();
+
+    assert_abs_diff_eq!(
+        calc_bearing_factor(PmtSoilCategory::SandB, &foundation, 1.0),
+        1.25,
+        epsilon = 1e-9
+    );
+    assert_abs_diff_eq!(
+        calc_bearing_factor(PmtSoilCategory::ClayA, &foundation, 0.0),
+        0.8,
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_calc_bearing_capacity() {
+    let result = calc_bearing_capacity(
+        &create_pressuremeter_test(),
+        &mut create_soil_profile(),
+        &create_foundation(),
+        PmtSoilCategory::SandB,
+        30.0,
+        PressureConvention::Gross,
+        3.0,
+    )
+    .unwrap();
+
+    assert_abs_diff_eq!(
+        result.equivalent_net_limit_pressure,
+        96.0338,
+        epsilon = 1e-4
+    );
+    assert_abs_diff_eq!(result.equivalent_embedment, 0.91843, epsilon = 1e-5);
+    assert_abs_diff_eq!(result.bearing_factor, 1.22961, epsilon = 1e-5);
+    assert_abs_diff_eq!(result.overburden_pressure, 2.7, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.ultimate_bearing_capacity, 120.7838, epsilon = 1e-4);
+    assert_abs_diff_eq!(result.allowable_bearing_capacity, 42.0613, epsilon = 1e-4);
+    assert!(result.is_safe);
+    assert_eq!(result.method, MethodId::Menard);
+}
+
+#[test]
+fn test_net_pressure() {
+    // σv(1.5 m) = 2.7 t/m², so a net pressure of 27.3 t/m² is checked as 30 t/m² gross
+    let result = calc_bearing_capacity(
+        &create_pressuremeter_test(),
+        &mut create_soil_profile(),
+        &create_foundation(),
+        PmtSoilCategory::SandB,
+        27.3,
+        PressureConvention::Net,
+        3.0,
+    )
+    .unwrap();
+
+    assert_abs_diff_eq!(result.qmax, 30.0, epsilon = 1e-9);
+    assert_eq!(result.pressure_convention, PressureConvention::Net);
+}
+
+#[test]
+fn test_empty_pressuremeter_test() {
+    let pmt = PressuremeterTest::new(vec![], SelectionMethod::Min);
+
+    let err = calc_bearing_capacity(
+        &pmt,
+        &mut create_soil_profile(),
+        &create_foundation(),
+        PmtSoilCategory::SandB,
+        30.0,
+        PressureConvention::Gross,
+        3.0,
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "pressuremeter.empty_exps");
+}
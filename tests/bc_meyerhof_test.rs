@@ -0,0 +1,135 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    bearing_capacity::meyerhof::*,
+    enums::{AnalysisTerm, MethodId, PressureConvention},
+    models::{
+        foundation::Foundation,
+        loads::Loads,
+        soil_profile::{SoilLayer, SoilProfile},
+    },
+};
+
+#[test]
+fn test_calc_bearing_capacity_factors() {
+    let result = calc_bearing_capacity_factors(0.0);
+    assert_abs_diff_eq!(result.nc, 5.14, epsilon = 1e-3);
+    assert_abs_diff_eq!(result.nq, 1.0, epsilon = 1e-3);
+    assert_abs_diff_eq!(result.ng, 0.0, epsilon = 1e-3);
+
+    let result = calc_bearing_capacity_factors(30.0);
+    assert_abs_diff_eq!(result.nc, 30.14, epsilon = 1e-2);
+    assert_abs_diff_eq!(result.nq, 18.401, epsilon = 1e-3);
+    assert_abs_diff_eq!(result.ng, 15.668, epsilon = 1e-3);
+}
+
+#[test]
+fn test_calc_shape_and_depth_factors() {
+    let foundation = Foundation {
+        foundation_depth: Some(1.0),
+        foundation_width: Some(1.0),
+        foundation_length: Some(2.0),
+        ..Foundation::default()
+    };
+
+    let shape = calc_shape_factors(&foundation, 30.0);
+    assert_abs_diff_eq!(shape.sc, 1.3, epsilon = 1e-9);
+    assert_abs_diff_eq!(shape.sq, 1.15, epsilon = 1e-9);
+    assert_abs_diff_eq!(shape.sg, 1.15, epsilon = 1e-9);
+
+    let depth = calc_depth_factors(&foundation, 30.0);
+    assert_abs_diff_eq!(depth.dc, 1.3464, epsilon = 1e-4);
+    assert_abs_diff_eq!(depth.dq, 1.1732, epsilon = 1e-4);
+
+    // φ < 10°: only the cohesion term is modified
+    let shape = calc_shape_factors(&foundation, 0.0);
+    assert_abs_diff_eq!(shape.sc, 1.1, epsilon = 1e-9);
+    assert_abs_diff_eq!(shape.sq, 1.0, epsilon = 1e-9);
+    let depth = calc_depth_factors(&foundation, 0.0);
+    assert_abs_diff_eq!(depth.dc, 1.2, epsilon = 1e-9);
+    assert_abs_diff_eq!(depth.dq, 1.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_calc_inclination_factors() {
+    let loads = Loads {
+        vertical_load: Some(100.0),
+        horizontal_load_x: Some(10.0),
+        ..Default::default()
+    };
+
+    let result = calc_inclination_factors(30.0, &loads);
+    assert_abs_diff_eq!(result.ic, 0.87712, epsilon = 1e-5);
+    assert_abs_diff_eq!(result.iq, 0.87712, epsilon = 1e-5);
+    assert_abs_diff_eq!(result.ig, 0.65553, epsilon = 1e-5);
+
+    // Load inclination greater than φ
+    let result = calc_inclination_factors(5.0, &loads);
+    assert_abs_diff_eq!(result.ig, 0.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_calc_bearing_capacity() {
+    let mut soil_profile = SoilProfile::new(
+        vec![SoilLayer {
+            thickness: Some(20.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(2.0),
+            c_prime: Some(0.0),
+            phi_prime: Some(30.0),
+            ..Default::default()
+        }],
+        20.0,
+    );
+    let mut foundation = Foundation {
+        foundation_depth: Some(1.0),
+        foundation_width: Some(2.0),
+        foundation_length: Some(2.0),
+        ..Foundation::default()
+    };
+    let loads = Loads {
+        vertical_load: Some(100.0),
+        ..Default::default()
+    };
+
+    let result = calc_bearing_capacity(
+        &mut soil_profile,
+        &mut foundation,
+        &loads,
+        25.0,
+        PressureConvention::Gross,
+        3.0,
+        AnalysisTerm::Long,
+    )
+    .unwrap();
+
+    let dq = 1.0 + 0.1 * 3.0_f64.sqrt() * 0.5;
+    let expected = 1.8 * 18.401 * 1.3 * dq + 0.5 * 1.8 * 2.0 * 15.668 * 1.3 * dq;
+    assert_abs_diff_eq!(result.ultimate_bearing_capacity, expected, epsilon = 1e-2);
+
+    // Term-by-term breakdown
+    let terms = result.terms;
+    assert_abs_diff_eq!(terms.part_1, 0.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(terms.part_2, 1.8 * 18.401 * 1.3 * dq, epsilon = 1e-2);
+    assert_abs_diff_eq!(
+        terms.part_3,
+        0.5 * 1.8 * 2.0 * 15.668 * 1.3 * dq,
+        epsilon = 1e-2
+    );
+    assert_abs_diff_eq!(
+        terms.part_1 + terms.part_2 + terms.part_3,
+        result.ultimate_bearing_capacity,
+        epsilon = 1e-9
+    );
+    assert_abs_diff_eq!(terms.friction_angle, 30.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(terms.cohesion, 0.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(terms.unit_weight, 1.8, epsilon = 1e-9);
+    assert_abs_diff_eq!(terms.surcharge, 1.8, epsilon = 1e-9);
+    assert_abs_diff_eq!(terms.effective_width, 2.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(
+        result.allowable_bearing_capacity,
+        expected / 3.0,
+        epsilon = 1e-2
+    );
+    assert!(result.is_safe);
+    assert_eq!(result.method, MethodId::Meyerhof);
+}
@@ -1,10 +1,13 @@
 use approx::assert_abs_diff_eq;
 use soilrust::{
-    bearing_capacity::point_load_test::{calc_bearing_capacity, get_generalized_c_value},
-    enums::SelectionMethod,
+    bearing_capacity::point_load_test::{
+        calc_bearing_capacity, calc_zone_bearing_capacity, get_generalized_c_value,
+    },
+    enums::{EmpiricalGeometryAdjustment, PressureConvention, SelectionMethod},
     models::{
         foundation::Foundation,
         point_load_test::{PointLoadExp, PointLoadSample, PointLoadTest},
+        soil_profile::SoilProfile,
     },
 };
 
@@ -38,13 +41,184 @@ fn test_calc_bearing_capacity() {
         ..Default::default()
     };
 
-    let result = calc_bearing_capacity(pt, foundation, foundation_pressure, safety_factor).unwrap();
+    let result = calc_bearing_capacity(
+        pt,
+        &SoilProfile::default(),
+        foundation,
+        foundation_pressure,
+        PressureConvention::Gross,
+        safety_factor,
+    )
+    .unwrap();
 
     assert_eq!(result.c, 23.0);
-    assert_abs_diff_eq!(result.ucs, 4690.69452, epsilon = 1e-5);
+    assert_abs_diff_eq!(result.ucs, 4690.69458, epsilon = 1e-5);
+    assert_abs_diff_eq!(
+        result.allowable_bearing_capacity,
+        2345.34729,
+        epsilon = 1e-5
+    );
+}
+
+#[test]
+fn test_calc_bearing_capacity_with_geometry_adjustment() {
+    let exp = PointLoadExp::new("Test".to_string(), vec![PointLoadSample::new(20., 2., 50.)]);
+    let pt = PointLoadTest {
+        exps: vec![exp],
+        idealization_method: SelectionMethod::Min,
+    };
+
+    let mut foundation = Foundation {
+        foundation_depth: Some(20.),
+        foundation_width: Some(2.),
+        foundation_length: Some(2.),
+        ..Default::default()
+    };
+
+    // No adjustment unless requested
+    let result = calc_bearing_capacity(
+        pt.clone(),
+        &SoilProfile::default(),
+        foundation.clone(),
+        100.0,
+        PressureConvention::Gross,
+        2.0,
+    )
+    .unwrap();
+    assert_abs_diff_eq!(result.shape_factor, 1.0, epsilon = 1e-9);
     assert_abs_diff_eq!(
         result.allowable_bearing_capacity,
-        2345.34726,
+        2345.34729,
         epsilon = 1e-5
     );
+
+    foundation.geometry_adjustment = Some(EmpiricalGeometryAdjustment::Terzaghi);
+    let result = calc_bearing_capacity(
+        pt.clone(),
+        &SoilProfile::default(),
+        foundation.clone(),
+        100.0,
+        PressureConvention::Gross,
+        2.0,
+    )
+    .unwrap();
+    assert_abs_diff_eq!(result.shape_factor, 1.3, epsilon = 1e-9);
+    assert_abs_diff_eq!(
+        result.allowable_bearing_capacity,
+        2345.34729 * 1.3,
+        epsilon = 1e-5
+    );
+
+    foundation.geometry_adjustment = Some(EmpiricalGeometryAdjustment::Custom {
+        shape_factor: 1.0,
+        depth_factor: 1.5,
+    });
+    let result = calc_bearing_capacity(
+        pt,
+        &SoilProfile::default(),
+        foundation,
+        100.0,
+        PressureConvention::Gross,
+        2.0,
+    )
+    .unwrap();
+    assert_abs_diff_eq!(result.depth_factor, 1.5, epsilon = 1e-9);
+    assert_abs_diff_eq!(
+        result.allowable_bearing_capacity,
+        2345.34729 * 1.5,
+        epsilon = 1e-5
+    );
+}
+
+#[test]
+fn test_calc_zone_bearing_capacity() {
+    let bh1 = PointLoadExp::new(
+        "BH-1".to_string(),
+        vec![
+            PointLoadSample::new(2., 1., 50.),
+            PointLoadSample::new(4., 3., 50.),
+            PointLoadSample::new(8., 0.5, 50.),
+        ],
+    );
+    let bh2 = PointLoadExp::new("BH-2".to_string(), vec![PointLoadSample::new(3., 2., 50.)]);
+    let mut pt = PointLoadTest::new(vec![bh1, bh2], SelectionMethod::Min);
+
+    let foundation = Foundation {
+        foundation_depth: Some(2.),
+        foundation_width: Some(1.),
+        foundation_length: Some(1.),
+        ..Default::default()
+    };
+
+    let result = calc_zone_bearing_capacity(
+        &pt,
+        &SoilProfile::default(),
+        &foundation,
+        100.0,
+        PressureConvention::Gross,
+        2.0,
+    )
+    .unwrap();
+    let stats = &result.statistics;
+
+    // The weak core at 8 m lies below Df + 2B and is excluded
+    assert_eq!(stats.sample_count, 3);
+    assert!(!stats.is_fallback);
+    assert_abs_diff_eq!(stats.zone_bottom, 4.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(stats.min_is50, 1.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(stats.max_is50, 3.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(stats.mean_is50, 2.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(stats.std_dev_is50, 1.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(stats.coefficient_of_variation, 0.5, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.result.is50, 1.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(
+        result.result.allowable_bearing_capacity,
+        1172.67364,
+        epsilon = 1e-5
+    );
+
+    pt.idealization_method = SelectionMethod::Avg;
+    let result = calc_zone_bearing_capacity(
+        &pt,
+        &SoilProfile::default(),
+        &foundation,
+        100.0,
+        PressureConvention::Gross,
+        2.0,
+    )
+    .unwrap();
+    assert_abs_diff_eq!(result.result.is50, 2.0, epsilon = 1e-9);
+
+    // Falls back to the sample at Df when the zone has no samples
+    let deep = Foundation {
+        foundation_depth: Some(5.),
+        ..foundation.clone()
+    };
+    let result = calc_zone_bearing_capacity(
+        &pt,
+        &SoilProfile::default(),
+        &deep,
+        100.0,
+        PressureConvention::Gross,
+        2.0,
+    )
+    .unwrap();
+    assert!(result.statistics.is_fallback);
+    assert_eq!(result.statistics.sample_count, 1);
+    assert_abs_diff_eq!(result.result.is50, 0.5, epsilon = 1e-9);
+
+    let no_width = Foundation {
+        foundation_depth: Some(2.),
+        ..Default::default()
+    };
+    let err = calc_zone_bearing_capacity(
+        &pt,
+        &SoilProfile::default(),
+        &no_width,
+        100.0,
+        PressureConvention::Gross,
+        2.0,
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "foundation.foundation_width.missing");
 }
@@ -0,0 +1,151 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    bearing_capacity::{seismic::*, vesic},
+    enums::{AnalysisTerm, MethodId, PressureConvention},
+    models::{
+        foundation::Foundation,
+        loads::Loads,
+        soil_profile::{SoilLayer, SoilProfile},
+    },
+};
+
+fn create_soil_profile() -> SoilProfile {
+    SoilProfile::new(
+        vec![SoilLayer {
+            thickness: Some(20.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(2.0),
+            c_prime: Some(0.0),
+            phi_prime: Some(30.0),
+            ..Default::default()
+        }],
+        10.0,
+    )
+}
+
+fn create_foundation() -> Foundation {
+    Foundation {
+        foundation_depth: Some(1.0),
+        foundation_width: Some(2.0),
+        foundation_length: Some(2.0),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_calc_seismic_factors() {
+    let result = calc_seismic_factors(0.1, 0.0, 0.0, 1.8, 1.0);
+    assert_abs_diff_eq!(result.ec, 0.65051, epsilon = 1e-5);
+    assert_abs_diff_eq!(result.eq, 0.71576, epsilon = 1e-5);
+    assert_abs_diff_eq!(result.eg, 0.56674, epsilon = 1e-5);
+
+    let result = calc_seismic_factors(0.1, 0.05, 2.0, 1.8, 1.0);
+    assert_abs_diff_eq!(result.ec, 0.96725, epsilon = 1e-5);
+    assert_abs_diff_eq!(result.eq, 0.66811, epsilon = 1e-5);
+    assert_abs_diff_eq!(result.eg, 0.53171, epsilon = 1e-5);
+
+    let result = calc_seismic_factors(0.0, 0.0, 2.0, 1.8, 1.0);
+    assert_abs_diff_eq!(result.ec, 1.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.eq, 1.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.eg, 1.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_calc_bearing_capacity() {
+    let loads = Loads {
+        vertical_load: Some(100.0),
+        horizontal_seismic_coefficient: Some(0.1),
+        ..Default::default()
+    };
+
+    let result = calc_bearing_capacity(
+        &mut create_soil_profile(),
+        &mut create_foundation(),
+        &loads,
+        20.0,
+        PressureConvention::Gross,
+        3.0,
+        AnalysisTerm::Long,
+    )
+    .unwrap();
+    let static_result = vesic::calc_bearing_capacity(
+        &mut create_soil_profile(),
+        &mut create_foundation(),
+        &loads,
+        20.0,
+        PressureConvention::Gross,
+        3.0,
+        AnalysisTerm::Long,
+    )
+    .unwrap();
+
+    assert_abs_diff_eq!(
+        result.ultimate_bearing_capacity,
+        static_result.ultimate_bearing_capacity,
+        epsilon = 1e-9
+    );
+    assert_eq!(result.method, MethodId::Vesic);
+
+    let seismic = result.seismic.unwrap();
+    assert_eq!(seismic.method, MethodId::BudhuAlKarni);
+    assert_abs_diff_eq!(seismic.vertical_coefficient, 0.0, epsilon = 1e-9);
+    assert!(seismic.ultimate_bearing_capacity < result.ultimate_bearing_capacity);
+    assert!(
+        seismic.ultimate_bearing_capacity
+            > seismic.seismic_factors.eg * result.ultimate_bearing_capacity
+    );
+    assert_abs_diff_eq!(
+        seismic.allowable_bearing_capacity,
+        seismic.ultimate_bearing_capacity / 3.0,
+        epsilon = 1e-9
+    );
+    assert!(seismic.warnings.is_empty());
+}
+
+#[test]
+fn test_zero_seismic_coefficient_matches_static() {
+    let loads = Loads {
+        vertical_load: Some(100.0),
+        horizontal_seismic_coefficient: Some(0.0),
+        vertical_seismic_coefficient: Some(0.0),
+        ..Default::default()
+    };
+
+    let result = calc_bearing_capacity(
+        &mut create_soil_profile(),
+        &mut create_foundation(),
+        &loads,
+        20.0,
+        PressureConvention::Gross,
+        3.0,
+        AnalysisTerm::Long,
+    )
+    .unwrap();
+
+    let seismic = result.seismic.unwrap();
+    assert_abs_diff_eq!(
+        seismic.ultimate_bearing_capacity,
+        result.ultimate_bearing_capacity,
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_missing_seismic_coefficient() {
+    let loads = Loads {
+        vertical_load: Some(100.0),
+        ..Default::default()
+    };
+
+    let err = calc_bearing_capacity(
+        &mut create_soil_profile(),
+        &mut create_foundation(),
+        &loads,
+        20.0,
+        PressureConvention::Gross,
+        3.0,
+        AnalysisTerm::Long,
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "loads.horizontal_seismic_coefficient.missing");
+}
@@ -0,0 +1,95 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    bearing_capacity::skirted_foundation::{calc_bearing_capacity, calc_interaction_envelope},
+    models::{
+        loads::Loads,
+        skirted_foundation::SkirtedFoundation,
+        soil_profile::{SoilLayer, SoilProfile},
+    },
+};
+
+fn create_soil_profile() -> SoilProfile {
+    SoilProfile::new(
+        vec![
+            SoilLayer {
+                thickness: Some(3.0),
+                dry_unit_weight: Some(1.7),
+                saturated_unit_weight: Some(1.7),
+                cu: Some(2.0),
+                ..Default::default()
+            },
+            SoilLayer {
+                thickness: Some(10.0),
+                dry_unit_weight: Some(1.7),
+                saturated_unit_weight: Some(1.7),
+                cu: Some(4.0),
+                ..Default::default()
+            },
+        ],
+        0.0,
+    )
+}
+
+#[test]
+fn test_calc_bearing_capacity() {
+    let mut soil_profile = create_soil_profile();
+    let caisson = SkirtedFoundation::new(10.0, 5.0, 0.5);
+    let loads = Loads {
+        vertical_load: Some(1000.0),
+        horizontal_load_x: Some(200.0),
+        moment_x: Some(1000.0),
+        ..Default::default()
+    };
+
+    let result = calc_bearing_capacity(&mut soil_profile, &caisson, &loads, 2.0).unwrap();
+
+    assert_abs_diff_eq!(result.su_tip, 4.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.su_avg, 2.8, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.nc, 7.17737, epsilon = 1e-5);
+    assert_abs_diff_eq!(result.skirt_friction, 219.91149, epsilon = 1e-4);
+    assert_abs_diff_eq!(result.vertical_capacity, 2757.10, epsilon = 1e-1);
+    assert_abs_diff_eq!(result.horizontal_capacity, 1260.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.moment_capacity, 5254.87, epsilon = 1e-2);
+
+    // The scaled load point must lie on the envelope
+    let h = 400.0 / result.horizontal_capacity / result.hm_utilization;
+    let m = 2000.0 / result.moment_capacity / result.hm_utilization;
+    let envelope = h.powf(result.exponent_h) + m.powf(result.exponent_m);
+    assert_abs_diff_eq!(envelope, 1.0, epsilon = 1e-9);
+    assert!(result.is_safe);
+}
+
+#[test]
+fn test_calc_interaction_envelope() {
+    let points = calc_interaction_envelope(1000.0, 4000.0, 0.5, 11);
+
+    assert_eq!(points.len(), 11);
+    assert_abs_diff_eq!(points[0].0, 0.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(points[0].1, 4000.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(points[10].0, 1000.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(points[10].1, 0.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_invalid_input() {
+    let caisson = SkirtedFoundation::new(10.0, 5.0, 0.5);
+    let loads = Loads {
+        vertical_load: Some(1000.0),
+        horizontal_load_x: Some(200.0),
+        moment_x: Some(1000.0),
+        ..Default::default()
+    };
+
+    // A missing thickness is reported before the layer depths are calculated
+    let mut soil_profile = create_soil_profile();
+    soil_profile.layers[1].thickness = None;
+    let err = calc_bearing_capacity(&mut soil_profile, &caisson, &loads, 2.0).unwrap_err();
+    assert_eq!(err.code, "soil_profile.thickness.missing");
+
+    let caisson = SkirtedFoundation::new(10.0, 20.0, 0.5);
+    let err = calc_bearing_capacity(&mut create_soil_profile(), &caisson, &loads, 2.0).unwrap_err();
+    assert_eq!(
+        err.code,
+        "skirted_foundation.skirt_length.greater_than_soil_profile_depth"
+    );
+}
@@ -0,0 +1,131 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    bearing_capacity::{slope::calc_bearing_capacity, vesic},
+    enums::{AnalysisTerm, PressureConvention},
+    models::{
+        foundation::Foundation,
+        loads::Loads,
+        soil_profile::{SoilLayer, SoilProfile},
+    },
+};
+
+fn create_soil_profile() -> SoilProfile {
+    SoilProfile::new(
+        vec![SoilLayer {
+            thickness: Some(20.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(2.0),
+            c_prime: Some(0.0),
+            phi_prime: Some(30.0),
+            ..Default::default()
+        }],
+        10.0,
+    )
+}
+
+fn create_foundation() -> Foundation {
+    Foundation {
+        foundation_depth: Some(1.0),
+        foundation_width: Some(2.0),
+        foundation_length: Some(10.0),
+        slope_angle: Some(20.0),
+        ..Default::default()
+    }
+}
+
+fn create_loads() -> Loads {
+    Loads {
+        vertical_load: Some(100.0),
+        ..Default::default()
+    }
+}
+
+fn calc_ultimate(foundation: &Foundation) -> f64 {
+    vesic::calc_bearing_capacity(
+        &mut create_soil_profile(),
+        &mut foundation.clone(),
+        &create_loads(),
+        10.0,
+        PressureConvention::Gross,
+        3.0,
+        AnalysisTerm::Long,
+    )
+    .unwrap()
+    .ultimate_bearing_capacity
+}
+
+#[test]
+fn test_calc_bearing_capacity_near_slope() {
+    let mut foundation = Foundation {
+        berm_width: Some(1.0),
+        slope_height: Some(2.0),
+        ..create_foundation()
+    };
+
+    let result = calc_bearing_capacity(
+        &mut create_soil_profile(),
+        &mut foundation,
+        &create_loads(),
+        10.0,
+        PressureConvention::Gross,
+        3.0,
+        AnalysisTerm::Long,
+    )
+    .unwrap();
+
+    assert_abs_diff_eq!(result.setback_ratio, 0.5, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.height_ratio.unwrap(), 1.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.slope_influence, 0.375, epsilon = 1e-9);
+
+    let level = Foundation {
+        slope_angle: None,
+        ..create_foundation()
+    };
+    let level_capacity = calc_ultimate(&level);
+    assert_abs_diff_eq!(
+        result.level_ground_bearing_capacity,
+        level_capacity,
+        epsilon = 1e-9
+    );
+    assert!(result.reduction_ratio < 1.0);
+    assert_abs_diff_eq!(
+        result.reduction_ratio,
+        result.bearing_capacity.ultimate_bearing_capacity / level_capacity,
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_slope_reduction_decreases_with_setback_and_height() {
+    let at_crest = calc_ultimate(&create_foundation());
+    let set_back = calc_ultimate(&Foundation {
+        berm_width: Some(2.0),
+        ..create_foundation()
+    });
+    let low_slope = calc_ultimate(&Foundation {
+        slope_height: Some(1.0),
+        ..create_foundation()
+    });
+    let far = calc_ultimate(&Foundation {
+        berm_width: Some(4.0),
+        ..create_foundation()
+    });
+
+    assert!(at_crest < set_back && set_back < far);
+    assert!(at_crest < low_slope);
+
+    let err = calc_bearing_capacity(
+        &mut create_soil_profile(),
+        &mut Foundation {
+            slope_height: Some(-1.0),
+            ..create_foundation()
+        },
+        &create_loads(),
+        10.0,
+        PressureConvention::Gross,
+        3.0,
+        AnalysisTerm::Long,
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "foundation.slope_height.too_small.0");
+}
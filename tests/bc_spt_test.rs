@@ -0,0 +1,122 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    bearing_capacity::spt::*,
+    enums::{MethodId, PressureConvention, SelectionMethod},
+    models::{
+        foundation::Foundation,
+        soil_profile::SoilProfile,
+        spt::{NValue, SPTExp, SPT},
+    },
+};
+
+fn create_spt() -> SPT {
+    let mut exp = SPTExp::new(vec![], "exp1".to_string());
+    exp.add_blow(1.5, NValue::Value(18));
+    exp.add_blow(2.5, NValue::Value(22));
+    exp.add_blow(3.5, NValue::Value(30));
+
+    let mut spt = SPT::new(1.0, 1.0, 1.0, SelectionMethod::Min);
+    spt.add_exp(exp);
+    spt
+}
+
+fn create_foundation() -> Foundation {
+    Foundation {
+        foundation_depth: Some(1.0),
+        foundation_width: Some(2.0),
+        foundation_length: Some(2.0),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_calc_depth_factor() {
+    assert_abs_diff_eq!(
+        calc_depth_factor(&create_foundation()),
+        1.165,
+        epsilon = 1e-9
+    );
+
+    let deep_foundation = Foundation {
+        foundation_depth: Some(5.0),
+        ..create_foundation()
+    };
+    assert_abs_diff_eq!(calc_depth_factor(&deep_foundation), 1.33, epsilon = 1e-9);
+}
+
+#[test]
+fn test_calc_allowable_pressure() {
+    // Narrow footing, 1 cm settlement
+    assert_abs_diff_eq!(
+        calc_allowable_pressure(20.0, 1.0, 1.33, 1.0),
+        20.7882,
+        epsilon = 1e-4
+    );
+    // Wide footing, 2.5 cm settlement
+    assert_abs_diff_eq!(
+        calc_allowable_pressure(20.0, 2.0, 1.165, 2.5),
+        37.8031,
+        epsilon = 1e-4
+    );
+}
+
+#[test]
+fn test_calc_bearing_capacity() {
+    let result = calc_bearing_capacity(
+        &create_spt(),
+        &SoilProfile::default(),
+        &create_foundation(),
+        50.0,
+        PressureConvention::Gross,
+        4.0,
+    )
+    .unwrap();
+
+    assert_abs_diff_eq!(result.n60, 20.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.depth_factor, 1.165, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.allowable_bearing_capacity, 37.8031, epsilon = 1e-4);
+    assert_abs_diff_eq!(
+        result.settlement_limited_bearing_capacity,
+        60.4850,
+        epsilon = 1e-4
+    );
+    assert!(result.is_safe);
+    assert_eq!(result.method, MethodId::MeyerhofSpt);
+    assert!(result.warnings.is_empty());
+}
+
+#[test]
+fn test_calc_bearing_capacity_below_last_blow() {
+    let foundation = Foundation {
+        foundation_depth: Some(5.0),
+        ..create_foundation()
+    };
+
+    let result = calc_bearing_capacity(
+        &create_spt(),
+        &SoilProfile::default(),
+        &foundation,
+        10.0,
+        PressureConvention::Gross,
+        2.5,
+    )
+    .unwrap();
+    assert_abs_diff_eq!(result.n60, 30.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_invalid_settlement() {
+    let err = calc_bearing_capacity(
+        &create_spt(),
+        &SoilProfile::default(),
+        &create_foundation(),
+        50.0,
+        PressureConvention::Gross,
+        0.0,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.code,
+        "spt_bearing.allowable_settlement.too_small.0.0001"
+    );
+}
@@ -0,0 +1,161 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    bearing_capacity::{sweep::*, vesic},
+    enums::{AnalysisTerm, MethodId, PressureConvention},
+    models::{
+        foundation::Foundation,
+        loads::Loads,
+        soil_profile::{SoilLayer, SoilProfile},
+    },
+    progress::ProgressHandle,
+};
+
+fn create_soil_profile() -> SoilProfile {
+    SoilProfile::new(
+        vec![SoilLayer {
+            thickness: Some(20.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(2.0),
+            c_prime: Some(0.0),
+            phi_prime: Some(30.0),
+            ..Default::default()
+        }],
+        10.0,
+    )
+}
+
+fn create_foundation() -> Foundation {
+    Foundation {
+        foundation_depth: Some(1.0),
+        foundation_width: Some(2.0),
+        foundation_length: Some(4.0),
+        ..Default::default()
+    }
+}
+
+fn create_loads() -> Loads {
+    Loads {
+        vertical_load: Some(100.0),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_calc_bearing_capacity_sweep() {
+    let grid = SweepGrid {
+        widths: vec![1.0, 2.0, 3.0, 4.0, 5.0],
+        depths: vec![1.0, 2.0, 3.0],
+    };
+
+    let result = calc_bearing_capacity_sweep(
+        &create_soil_profile(),
+        &create_foundation(),
+        &create_loads(),
+        &grid,
+        3.0,
+        AnalysisTerm::Long,
+        MethodId::Vesic,
+        None,
+    )
+    .unwrap();
+
+    assert_eq!(result.allowable_bearing_capacity.len(), 3);
+    assert_eq!(result.allowable_bearing_capacity[0].len(), 5);
+    assert_eq!(result.method, MethodId::Vesic);
+
+    // Capacity increases with depth in a uniform sand above the groundwater level
+    for column in 0..5 {
+        assert!(
+            result.allowable_bearing_capacity[2][column]
+                > result.allowable_bearing_capacity[0][column]
+        );
+    }
+
+    // A grid point matches a single analysis with the same L/B ratio
+    let single = vesic::calc_bearing_capacity(
+        &mut create_soil_profile(),
+        &mut create_foundation(),
+        &create_loads(),
+        0.0,
+        PressureConvention::Gross,
+        3.0,
+        AnalysisTerm::Long,
+    )
+    .unwrap();
+    assert_abs_diff_eq!(
+        result.allowable_bearing_capacity[0][1],
+        single.allowable_bearing_capacity,
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_invalid_sweep() {
+    let err = calc_bearing_capacity_sweep(
+        &create_soil_profile(),
+        &create_foundation(),
+        &create_loads(),
+        &SweepGrid::default(),
+        3.0,
+        AnalysisTerm::Long,
+        MethodId::Vesic,
+        None,
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "bearing_capacity_sweep.grid.empty");
+
+    let grid = SweepGrid {
+        widths: vec![1.0],
+        depths: vec![1.0],
+    };
+    let err = calc_bearing_capacity_sweep(
+        &create_soil_profile(),
+        &create_foundation(),
+        &create_loads(),
+        &grid,
+        3.0,
+        AnalysisTerm::Long,
+        MethodId::MeyerhofSpt,
+        None,
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "bearing_capacity_sweep.method.unsupported");
+}
+
+#[test]
+fn test_sweep_progress() {
+    let grid = SweepGrid {
+        widths: vec![1.0, 2.0],
+        depths: vec![1.0, 2.0, 3.0],
+    };
+    let progress = ProgressHandle::default();
+
+    calc_bearing_capacity_sweep(
+        &create_soil_profile(),
+        &create_foundation(),
+        &create_loads(),
+        &grid,
+        3.0,
+        AnalysisTerm::Long,
+        MethodId::Vesic,
+        Some(&progress),
+    )
+    .unwrap();
+    assert_eq!(progress.total(), 6);
+    assert_eq!(progress.completed(), 6);
+
+    progress.cancel();
+    let err = calc_bearing_capacity_sweep(
+        &create_soil_profile(),
+        &create_foundation(),
+        &create_loads(),
+        &grid,
+        3.0,
+        AnalysisTerm::Long,
+        MethodId::Vesic,
+        Some(&progress),
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "analysis.cancelled");
+    assert_eq!(progress.completed(), 0);
+}
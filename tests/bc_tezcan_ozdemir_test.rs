@@ -1,7 +1,11 @@
 use approx::assert_abs_diff_eq;
 use soilrust::{
-    enums::SelectionMethod,
+    bearing_capacity::tezcan_ozdemir::{
+        calc_averaged_bearing_capacity, calc_bearing_capacity, calc_bearing_capacity_with_method,
+    },
+    enums::{MethodId, PressureConvention, SelectionMethod, VsBearingMethod},
     models::{
+        foundation::Foundation,
         masw::{Masw, MaswExp, MaswLayer},
         soil_profile::{SoilLayer, SoilProfile},
     },
@@ -17,6 +21,7 @@ fn create_soil_profile() -> SoilProfile {
             depth: Some(5.0),
             ..Default::default()
         }],
+        ..Default::default()
     }
 }
 
@@ -57,6 +62,7 @@ fn test_bc_tezcan_ozdemir_1() {
         &mut masw_exp,
         foundation,
         foundation_pressure,
+        PressureConvention::Gross,
     )
     .unwrap();
 
@@ -87,6 +93,7 @@ fn test_bc_tezcan_ozdemir_2() {
         &mut masw_exp,
         foundation,
         foundation_pressure,
+        PressureConvention::Gross,
     )
     .unwrap();
 
@@ -117,6 +124,7 @@ fn test_bc_tezcan_ozdemir_3() {
         &mut masw_exp,
         foundation,
         foundation_pressure,
+        PressureConvention::Gross,
     )
     .unwrap();
 
@@ -124,3 +132,140 @@ fn test_bc_tezcan_ozdemir_3() {
     assert_abs_diff_eq!(result.allowable_bearing_capacity, 20., epsilon = 1e-5);
     assert_abs_diff_eq!(result.safety_factor, 4., epsilon = 1e-5);
 }
+
+// Test for VS below the calibration range
+#[test]
+fn test_bc_tezcan_ozdemir_low_vs_warning() {
+    let soil_profile: SoilProfile = create_soil_profile();
+    let mut masw_exp = create_masw_exp(100.0);
+    let foundation = soilrust::models::foundation::Foundation::new(
+        Some(5.0),
+        Some(1.0),
+        Some(1.0),
+        None,
+        None,
+        None,
+        None,
+    );
+
+    let result = soilrust::bearing_capacity::tezcan_ozdemir::calc_bearing_capacity(
+        soil_profile,
+        &mut masw_exp,
+        foundation,
+        100.0,
+        PressureConvention::Gross,
+    )
+    .unwrap();
+
+    assert_eq!(result.warnings.len(), 1);
+    assert_eq!(result.warnings[0].code, "applicability.vs.out_of_range");
+}
+
+#[test]
+fn test_bc_tezcan_ozdemir_averaged() {
+    let soil_profile = SoilProfile::new(
+        vec![SoilLayer {
+            thickness: Some(10.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(2.0),
+            ..Default::default()
+        }],
+        4.0,
+    );
+    let mut masw = Masw {
+        exps: vec![MaswExp {
+            layers: vec![
+                MaswLayer::new(3.0, 300.0, 600.0),
+                MaswLayer::new(7.0, 600.0, 1200.0),
+            ],
+            name: "Test".to_string(),
+        }],
+        idealization_method: SelectionMethod::Min,
+    };
+    let foundation = Foundation {
+        foundation_depth: Some(2.0),
+        foundation_width: Some(2.0),
+        foundation_length: Some(2.0),
+        ..Default::default()
+    };
+
+    let result = calc_averaged_bearing_capacity(
+        soil_profile,
+        &mut masw,
+        foundation,
+        10.0,
+        PressureConvention::Gross,
+    )
+    .unwrap();
+
+    assert_abs_diff_eq!(result.zone_top, 2.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.zone_bottom, 6.0, epsilon = 1e-9);
+
+    // Point values at Df
+    assert_abs_diff_eq!(result.point.vs, 300.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.point.unit_weight, 1.8, epsilon = 1e-9);
+
+    // 1 m at 300 m/s and 3 m at 600 m/s; 2 m dry and 2 m saturated
+    assert_abs_diff_eq!(result.averaged.vs, 525.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.averaged.unit_weight, 1.9, epsilon = 1e-9);
+    assert_abs_diff_eq!(
+        result.averaged.allowable_bearing_capacity / result.point.allowable_bearing_capacity,
+        1.9 * 525.0 / (1.8 * 300.0),
+        epsilon = 1e-9
+    );
+
+    let err = calc_averaged_bearing_capacity(
+        create_soil_profile(),
+        &mut create_masw_exp(300.0),
+        Foundation {
+            foundation_depth: Some(1.0),
+            ..Default::default()
+        },
+        10.0,
+        PressureConvention::Gross,
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "foundation.foundation_width.missing");
+}
+
+#[test]
+fn test_bc_keceli() {
+    let foundation = Foundation {
+        foundation_depth: Some(2.0),
+        foundation_width: Some(2.0),
+        foundation_length: Some(2.0),
+        ..Default::default()
+    };
+
+    let result = calc_bearing_capacity_with_method(
+        create_soil_profile(),
+        &mut create_masw_exp(1000.0),
+        foundation.clone(),
+        10.0,
+        PressureConvention::Gross,
+        VsBearingMethod::Keceli,
+    )
+    .unwrap();
+    let factors = result.shape_factor * result.depth_factor;
+
+    assert_eq!(result.method, MethodId::Keceli);
+    assert_abs_diff_eq!(result.safety_factor, 4.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(
+        result.allowable_bearing_capacity / factors,
+        50.0,
+        epsilon = 1e-9
+    );
+
+    // Tezcan & Ozdemir reduces the safety factor above 750 m/s
+    let tezcan = calc_bearing_capacity(
+        create_soil_profile(),
+        &mut create_masw_exp(1000.0),
+        foundation,
+        10.0,
+        PressureConvention::Gross,
+    )
+    .unwrap();
+    assert_eq!(tezcan.method, MethodId::TezcanOzdemir);
+    assert_abs_diff_eq!(tezcan.safety_factor, 3.8, epsilon = 1e-9);
+    assert!(tezcan.allowable_bearing_capacity > result.allowable_bearing_capacity);
+}
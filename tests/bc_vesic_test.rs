@@ -1,7 +1,12 @@
 use approx::assert_abs_diff_eq;
 use soilrust::{
     bearing_capacity::{model::BearingCapacityFactors, vesic::*},
-    models::{foundation::Foundation, loads::Loads},
+    enums::{AnalysisTerm, EccentricityCase, FoundationShape, PressureConvention},
+    models::{
+        foundation::Foundation,
+        loads::Loads,
+        soil_profile::{SoilLayer, SoilProfile},
+    },
 };
 
 /// Case 1: φ = 0°, pure cohesive soil — should return Nc = 5.14, Nq = 1.0, Ng = 0.0
@@ -82,6 +87,37 @@ fn test_calc_shape_factors_2() {
     assert_abs_diff_eq!(result.sq, 1.333, epsilon = 1e-3);
     assert_abs_diff_eq!(result.sg, 0.733, epsilon = 1e-3);
 }
+#[test]
+fn test_calc_shape_factors_by_shape() {
+    let bc_factors = BearingCapacityFactors {
+        nc: 30.140,
+        nq: 18.401,
+        ng: 20.093,
+    };
+
+    let mut strip = Foundation {
+        foundation_depth: Some(1.0),
+        foundation_width: Some(1.0),
+        shape: FoundationShape::Strip,
+        ..Foundation::default()
+    };
+    strip.apply_shape().unwrap();
+    let result = calc_shape_factors(&strip, bc_factors, 30.0);
+    assert_abs_diff_eq!(result.sc, 1.0, epsilon = 1e-3);
+    assert_abs_diff_eq!(result.sq, 1.0, epsilon = 1e-3);
+    assert_abs_diff_eq!(result.sg, 1.0, epsilon = 1e-3);
+
+    let mut circular = Foundation {
+        foundation_depth: Some(1.0),
+        shape: FoundationShape::Circular { diameter: 2.0 },
+        ..Foundation::default()
+    };
+    circular.apply_shape().unwrap();
+    let result = calc_shape_factors(&circular, bc_factors, 30.0);
+    assert_abs_diff_eq!(result.sc, 1.611, epsilon = 1e-3);
+    assert_abs_diff_eq!(result.sq, 1.5, epsilon = 1e-3);
+    assert_abs_diff_eq!(result.sg, 0.6, epsilon = 1e-3);
+}
 // --------------------------------------------------------------
 /// Case 1: φ = 0°, c = 10, HL = 0, HB = 0, V = 200
 #[test]
@@ -454,3 +490,458 @@ fn test_calc_ground_factors_4() {
     assert_abs_diff_eq!(result.gq, 0.833, epsilon = 1e-3);
     assert_abs_diff_eq!(result.gg, 0.833, epsilon = 1e-3);
 }
+
+// --------------------------------------------------------------
+/// Single slope without berm matches the uniform slope ground factors
+#[test]
+fn test_calc_benched_ground_factors_1() {
+    let foundation = Foundation {
+        foundation_width: Some(2.0),
+        slope_angle: Some(20.0),
+        ..Default::default()
+    };
+    let result = calc_benched_ground_factors(1.0, &foundation, 30.0);
+    let expected = calc_ground_factors(1.0, 20.0, 30.0);
+
+    assert_abs_diff_eq!(result.gq, expected.gq, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.gc, expected.gc, epsilon = 1e-9);
+}
+
+/// Berm of B halves the slope reduction, far opposite slope has no effect
+#[test]
+fn test_calc_benched_ground_factors_2() {
+    let foundation = Foundation {
+        foundation_width: Some(2.0),
+        slope_angle: Some(20.0),
+        berm_width: Some(2.0),
+        opposite_slope_angle: Some(30.0),
+        opposite_berm_width: Some(4.0),
+        ..Default::default()
+    };
+    let result = calc_benched_ground_factors(1.0, &foundation, 30.0);
+
+    assert_abs_diff_eq!(result.gq, 0.702267, epsilon = 1e-6);
+    assert_abs_diff_eq!(result.gg, 0.702267, epsilon = 1e-6);
+}
+
+/// The side with the larger reduction governs
+#[test]
+fn test_calc_benched_ground_factors_3() {
+    let foundation = Foundation {
+        foundation_width: Some(2.0),
+        slope_angle: Some(20.0),
+        berm_width: Some(2.0),
+        opposite_slope_angle: Some(10.0),
+        opposite_berm_width: Some(0.0),
+        ..Default::default()
+    };
+    let result = calc_benched_ground_factors(1.0, &foundation, 30.0);
+
+    assert_abs_diff_eq!(result.gq, 0.678437, epsilon = 1e-6);
+}
+
+/// A slope of height B carries half of the slope reduction
+#[test]
+fn test_calc_benched_ground_factors_with_slope_height() {
+    let foundation = Foundation {
+        foundation_width: Some(2.0),
+        slope_angle: Some(20.0),
+        slope_height: Some(2.0),
+        ..Default::default()
+    };
+    let result = calc_benched_ground_factors(1.0, &foundation, 30.0);
+
+    assert_abs_diff_eq!(result.gq, 0.702267, epsilon = 1e-6);
+}
+
+#[test]
+fn test_calc_slope_influence() {
+    assert_abs_diff_eq!(calc_slope_influence(2.0, None, None), 1.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(
+        calc_slope_influence(2.0, Some(1.0), Some(2.0)),
+        0.375,
+        epsilon = 1e-9
+    );
+    assert_abs_diff_eq!(
+        calc_slope_influence(2.0, Some(1.0), Some(10.0)),
+        0.75,
+        epsilon = 1e-9
+    );
+    assert_abs_diff_eq!(
+        calc_slope_influence(2.0, Some(5.0), Some(10.0)),
+        0.0,
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_calc_bearing_capacity_with_surface_surcharge() {
+    let create_soil_profile = || {
+        SoilProfile::new(
+            vec![SoilLayer {
+                thickness: Some(20.0),
+                dry_unit_weight: Some(1.8),
+                saturated_unit_weight: Some(2.0),
+                c_prime: Some(0.0),
+                phi_prime: Some(30.0),
+                ..Default::default()
+            }],
+            10.0,
+        )
+    };
+    let create_foundation = || Foundation {
+        foundation_depth: Some(1.0),
+        foundation_width: Some(2.0),
+        foundation_length: Some(2.0),
+        ..Default::default()
+    };
+    let loads = Loads {
+        vertical_load: Some(100.0),
+        ..Default::default()
+    };
+    let surcharged_loads = Loads {
+        surface_surcharge: Some(2.0),
+        ..loads.clone()
+    };
+
+    let result = calc_bearing_capacity(
+        &mut create_soil_profile(),
+        &mut create_foundation(),
+        &loads,
+        20.0,
+        PressureConvention::Gross,
+        3.0,
+        AnalysisTerm::Long,
+    )
+    .unwrap();
+    let surcharged = calc_bearing_capacity(
+        &mut create_soil_profile(),
+        &mut create_foundation(),
+        &surcharged_loads,
+        20.0,
+        PressureConvention::Gross,
+        3.0,
+        AnalysisTerm::Long,
+    )
+    .unwrap();
+
+    // The surcharge adds q · Nq · sq · dq · bq · gq · iq to the ultimate bearing capacity
+    let expected_increase = 2.0
+        * result.bearing_capacity_factors.nq
+        * result.shape_factors.sq
+        * result.depth_factors.dq
+        * result.base_factors.bq
+        * result.ground_factors.gq
+        * result.load_inclination_factors.iq;
+    assert_abs_diff_eq!(
+        surcharged.ultimate_bearing_capacity - result.ultimate_bearing_capacity,
+        expected_increase,
+        epsilon = 1e-9
+    );
+
+    let invalid_loads = Loads {
+        surface_surcharge: Some(-1.0),
+        ..loads
+    };
+    let err = calc_bearing_capacity(
+        &mut create_soil_profile(),
+        &mut create_foundation(),
+        &invalid_loads,
+        20.0,
+        PressureConvention::Gross,
+        3.0,
+        AnalysisTerm::Long,
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "loads.surface_surcharge.too_small.0");
+}
+
+#[test]
+fn test_calc_bearing_capacity_two_way_eccentricity() {
+    let mut soil_profile = SoilProfile::new(
+        vec![SoilLayer {
+            thickness: Some(20.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(2.0),
+            c_prime: Some(0.0),
+            phi_prime: Some(30.0),
+            ..Default::default()
+        }],
+        10.0,
+    );
+    let mut foundation = Foundation {
+        foundation_depth: Some(1.0),
+        foundation_width: Some(2.0),
+        foundation_length: Some(2.0),
+        ..Default::default()
+    };
+    // ex = ey = 40 / 100 = 0.4 m > B/6
+    let loads = Loads {
+        vertical_load: Some(100.0),
+        moment_x: Some(40.0),
+        moment_y: Some(40.0),
+        ..Default::default()
+    };
+
+    let result = calc_bearing_capacity(
+        &mut soil_profile,
+        &mut foundation,
+        &loads,
+        20.0,
+        PressureConvention::Gross,
+        3.0,
+        AnalysisTerm::Long,
+    )
+    .unwrap();
+
+    assert_eq!(result.effective_area.case, EccentricityCase::Triangular);
+    assert!(result.effective_area.outside_kern);
+    assert_abs_diff_eq!(result.effective_area.effective_area, 1.62, epsilon = 1e-9);
+    assert_abs_diff_eq!(foundation.effective_width.unwrap(), 0.9, epsilon = 1e-9);
+}
+
+#[test]
+fn test_calc_bearing_capacity_one_way_eccentricity() {
+    let mut soil_profile = SoilProfile::new(
+        vec![SoilLayer {
+            thickness: Some(20.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(2.0),
+            c_prime: Some(0.0),
+            phi_prime: Some(30.0),
+            ..Default::default()
+        }],
+        10.0,
+    );
+    let mut foundation = Foundation {
+        foundation_depth: Some(1.0),
+        foundation_width: Some(2.0),
+        foundation_length: Some(2.0),
+        ..Default::default()
+    };
+    // ex = 20 / 100 = 0.2 m, no moment about the other axis
+    let loads = Loads {
+        vertical_load: Some(100.0),
+        moment_x: Some(20.0),
+        ..Default::default()
+    };
+
+    calc_bearing_capacity(
+        &mut soil_profile,
+        &mut foundation,
+        &loads,
+        20.0,
+        PressureConvention::Gross,
+        3.0,
+        AnalysisTerm::Long,
+    )
+    .unwrap();
+
+    // B' = 2.0 - 2 · 0.2
+    assert_abs_diff_eq!(foundation.effective_width.unwrap(), 1.6, epsilon = 1e-9);
+    assert_abs_diff_eq!(foundation.effective_length.unwrap(), 2.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_calc_bearing_capacity_terms_undrained() {
+    let mut soil_profile = SoilProfile::new(
+        vec![SoilLayer {
+            thickness: Some(20.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(2.0),
+            cu: Some(5.0),
+            phi_u: Some(0.0),
+            ..Default::default()
+        }],
+        10.0,
+    );
+    let mut foundation = Foundation {
+        foundation_depth: Some(1.0),
+        foundation_width: Some(2.0),
+        foundation_length: Some(2.0),
+        ..Default::default()
+    };
+    let loads = Loads {
+        vertical_load: Some(100.0),
+        ..Default::default()
+    };
+
+    let result = calc_bearing_capacity(
+        &mut soil_profile,
+        &mut foundation,
+        &loads,
+        20.0,
+        PressureConvention::Gross,
+        3.0,
+        AnalysisTerm::Short,
+    )
+    .unwrap();
+
+    // φ = 0: the cohesion term carries the modification factors, the surcharge term is q
+    let terms = result.terms;
+    assert_abs_diff_eq!(terms.cohesion, 5.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(terms.friction_angle, 0.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(terms.part_2, terms.surcharge, epsilon = 1e-9);
+    assert_abs_diff_eq!(terms.surcharge, 1.8, epsilon = 1e-9);
+    assert_abs_diff_eq!(terms.part_3, 0.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(
+        terms.part_1 + terms.part_2,
+        result.ultimate_bearing_capacity,
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_calc_compressibility_factors() {
+    assert_abs_diff_eq!(
+        calc_critical_rigidity_index(30.0, 1.0),
+        69.6301,
+        epsilon = 1e-4
+    );
+    assert_abs_diff_eq!(
+        calc_rigidity_index(1000.0, 0.0, 10.0, 45.0),
+        100.0,
+        epsilon = 1e-9
+    );
+
+    // Ir < Ir,cr: loose sand
+    let nq = calc_bearing_capacity_factors(30.0).nq;
+    let factors = calc_compressibility_factors(50.0, 30.0, 1.0, nq);
+    assert_abs_diff_eq!(factors.zq, 0.863066, epsilon = 1e-6);
+    assert_abs_diff_eq!(factors.zg, factors.zq, epsilon = 1e-9);
+    assert_abs_diff_eq!(factors.zc, 0.850177, epsilon = 1e-5);
+
+    // φ = 0: ζc = 0.32 + 0.12 · B/L + 0.60 · log10(Ir)
+    let factors = calc_compressibility_factors(5.0, 0.0, 1.0, 1.0);
+    assert_abs_diff_eq!(factors.critical_rigidity_index, 8.6439, epsilon = 1e-4);
+    assert_abs_diff_eq!(factors.zc, 0.859382, epsilon = 1e-6);
+    assert_abs_diff_eq!(factors.zq, 1.0, epsilon = 1e-9);
+
+    // Ir ≥ Ir,cr: incompressible soil
+    let factors = calc_compressibility_factors(100.0, 30.0, 1.0, nq);
+    assert_abs_diff_eq!(factors.zc, 1.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(factors.zq, 1.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_calc_bearing_capacity_with_compressibility() {
+    let create_soil_profile = || {
+        SoilProfile::new(
+            vec![SoilLayer {
+                thickness: Some(20.0),
+                dry_unit_weight: Some(1.8),
+                saturated_unit_weight: Some(2.0),
+                c_prime: Some(0.0),
+                phi_prime: Some(30.0),
+                elastic_modulus: Some(100.0),
+                poissons_ratio: Some(0.3),
+                ..Default::default()
+            }],
+            10.0,
+        )
+    };
+    let create_foundation = |apply_compressibility| Foundation {
+        foundation_depth: Some(1.0),
+        foundation_width: Some(2.0),
+        foundation_length: Some(2.0),
+        apply_compressibility,
+        ..Default::default()
+    };
+    let loads = Loads {
+        vertical_load: Some(100.0),
+        ..Default::default()
+    };
+
+    let calc = |apply_compressibility| {
+        calc_bearing_capacity(
+            &mut create_soil_profile(),
+            &mut create_foundation(apply_compressibility),
+            &loads,
+            20.0,
+            PressureConvention::Gross,
+            3.0,
+            AnalysisTerm::Long,
+        )
+        .unwrap()
+    };
+    let standard = calc(None);
+    let reduced = calc(Some(true));
+
+    assert!(standard.compressibility_factors.is_none());
+    let factors = reduced.compressibility_factors.unwrap();
+
+    // G = 100 / 2.6, q' = 1.8 · 2 at Df + B/2
+    assert_abs_diff_eq!(
+        factors.rigidity_index,
+        100.0 / 2.6 / (3.6 * 30.0_f64.to_radians().tan()),
+        epsilon = 1e-6
+    );
+    assert!(factors.zq < 1.0);
+    assert_abs_diff_eq!(
+        reduced.terms.part_2,
+        standard.terms.part_2 * factors.zq,
+        epsilon = 1e-9
+    );
+    assert_abs_diff_eq!(
+        reduced.terms.part_3,
+        standard.terms.part_3 * factors.zg,
+        epsilon = 1e-9
+    );
+
+    // The modulus is required when the factors are applied
+    let mut soil_profile = create_soil_profile();
+    soil_profile.layers[0].elastic_modulus = None;
+    let err = calc_bearing_capacity(
+        &mut soil_profile,
+        &mut create_foundation(Some(true)),
+        &loads,
+        20.0,
+        PressureConvention::Gross,
+        3.0,
+        AnalysisTerm::Long,
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "soil_profile.elastic_modulus.missing");
+}
+
+#[test]
+fn test_calc_bearing_capacity_with_net_pressure() {
+    let create_soil_profile = || {
+        SoilProfile::new(
+            vec![SoilLayer {
+                thickness: Some(20.0),
+                dry_unit_weight: Some(1.8),
+                saturated_unit_weight: Some(2.0),
+                c_prime: Some(0.0),
+                phi_prime: Some(30.0),
+                ..Default::default()
+            }],
+            10.0,
+        )
+    };
+    let loads = Loads {
+        vertical_load: Some(100.0),
+        ..Default::default()
+    };
+    let mut foundation = Foundation {
+        foundation_depth: Some(1.0),
+        foundation_width: Some(2.0),
+        foundation_length: Some(2.0),
+        ..Default::default()
+    };
+
+    let result = calc_bearing_capacity(
+        &mut create_soil_profile(),
+        &mut foundation,
+        &loads,
+        20.0,
+        PressureConvention::Net,
+        3.0,
+        AnalysisTerm::Long,
+    )
+    .unwrap();
+
+    // The net pressure is checked as the gross pressure 20 + 1.8 · 1.0
+    assert_abs_diff_eq!(result.qmax, 21.8, epsilon = 1e-9);
+    assert_eq!(result.pressure_convention, PressureConvention::Net);
+}
@@ -0,0 +1,73 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    bearing_capacity::vhm_envelope::{
+        calc_envelope_surface, calc_utilization, calc_yield_function, VhmEnvelopeParams,
+    },
+    models::{foundation::Foundation, loads::Loads},
+};
+
+fn create_foundation() -> Foundation {
+    Foundation {
+        foundation_width: Some(4.0),
+        foundation_length: Some(4.0),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_envelope_surface_points_on_yield_surface() {
+    let params = VhmEnvelopeParams::default();
+    let points = calc_envelope_surface(1000.0, 4.0, &params, 5, 8);
+
+    assert_eq!(points.len(), 40);
+    for p in points {
+        let f = calc_yield_function(p.v / 1000.0, p.h / 1000.0, p.m / 4000.0, &params);
+        assert_abs_diff_eq!(f, 0.0, epsilon = 1e-9);
+    }
+}
+
+#[test]
+fn test_pure_vertical_utilization() {
+    let loads = Loads {
+        vertical_load: Some(400.0),
+        horizontal_load_x: Some(0.0),
+        moment_x: Some(0.0),
+        ..Default::default()
+    };
+
+    let result = calc_utilization(
+        1000.0,
+        &create_foundation(),
+        &loads,
+        &VhmEnvelopeParams::default(),
+    )
+    .unwrap();
+
+    assert_abs_diff_eq!(result.utilization, 0.4, epsilon = 1e-9);
+    assert!(result.is_safe);
+}
+
+#[test]
+fn test_combined_utilization() {
+    let params = VhmEnvelopeParams::default();
+    let loads = Loads {
+        vertical_load: Some(400.0),
+        horizontal_load_x: Some(100.0),
+        moment_x: Some(200.0),
+        ..Default::default()
+    };
+
+    let result = calc_utilization(1000.0, &create_foundation(), &loads, &params).unwrap();
+
+    // The scaled load point must lie on the envelope
+    let scale = 1.0 / result.utilization;
+    let f = calc_yield_function(
+        scale * result.v,
+        scale * result.h,
+        scale * result.m,
+        &params,
+    );
+    assert_abs_diff_eq!(f, 0.0, epsilon = 1e-9);
+    assert!(result.yield_function > 0.0);
+    assert!(!result.is_safe);
+}
@@ -0,0 +1,86 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    constants::{
+        Environment, KPA_TO_TON, MPA_TO_TON, SEA_WATER_UNIT_WEIGHT, TON_TO_KN, WATER_UNIT_WEIGHT,
+    },
+    models::soil_profile::{SoilLayer, SoilProfile},
+};
+
+fn create_soil_profile() -> SoilProfile {
+    SoilProfile::new(
+        vec![SoilLayer {
+            thickness: Some(10.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(2.0),
+            ..Default::default()
+        }],
+        2.0,
+    )
+}
+
+#[test]
+fn test_default_environment() {
+    let environment = Environment::default();
+
+    assert_abs_diff_eq!(environment.gravity, 9.81, epsilon = 1e-9);
+    assert_abs_diff_eq!(
+        environment.water_unit_weight,
+        WATER_UNIT_WEIGHT,
+        epsilon = 1e-9
+    );
+    assert_abs_diff_eq!(
+        environment.submerged_unit_weight(2.0),
+        1.019,
+        epsilon = 1e-9
+    );
+    assert_abs_diff_eq!(environment.hydrostatic_pressure(-1.0), 0.0, epsilon = 1e-9);
+    assert!(environment.validate().is_ok());
+}
+
+#[test]
+fn test_sea_water_effective_stress() {
+    let profile = create_soil_profile();
+    let environment = Environment::sea_water();
+
+    assert_abs_diff_eq!(SEA_WATER_UNIT_WEIGHT, 1.005525, epsilon = 1e-6);
+
+    // σ = 1.8 · 2 + 2.0 · 3 = 9.6 t/m², u = γw · 3
+    assert_abs_diff_eq!(profile.calc_effective_stress(5.0), 6.657, epsilon = 1e-6);
+    assert_abs_diff_eq!(
+        profile.calc_effective_stress_with_environment(5.0, &environment),
+        6.583425,
+        epsilon = 1e-6
+    );
+
+    // The environment of the profile is used by the analyses
+    let profile = SoilProfile {
+        environment: Some(environment),
+        ..create_soil_profile()
+    };
+    assert_abs_diff_eq!(profile.calc_effective_stress(5.0), 6.583425, epsilon = 1e-6);
+}
+
+#[test]
+fn test_invalid_environment() {
+    let environment = Environment {
+        water_unit_weight: 0.0,
+        ..Default::default()
+    };
+
+    let err = environment.validate().unwrap_err();
+    assert_eq!(err.code, "environment.water_unit_weight.too_small.0.0001");
+
+    let profile = SoilProfile {
+        environment: Some(environment),
+        ..create_soil_profile()
+    };
+    let err = profile.validate(&["thickness"]).unwrap_err();
+    assert_eq!(err.code, "environment.water_unit_weight.too_small.0.0001");
+}
+
+#[test]
+fn test_unit_conversions() {
+    assert_abs_diff_eq!(KPA_TO_TON * TON_TO_KN, 1.0, epsilon = 1e-12);
+    assert_abs_diff_eq!(MPA_TO_TON, 1000.0 * KPA_TO_TON, epsilon = 1e-9);
+    assert_abs_diff_eq!(MPA_TO_TON, 101.97162, epsilon = 1e-5);
+}
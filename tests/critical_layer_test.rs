@@ -0,0 +1,170 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    consolidation_settlement::model::SettlementResult,
+    critical_layer::{
+        find_critical_bearing_layer, find_critical_liquefaction_layer,
+        find_critical_settlement_layer,
+    },
+    enums::{AnalysisTerm, CriticalLayerCriterion, MethodId, PressureConvention},
+    interpretation::{interpret_critical_layers, Finding},
+    liquefaction::models::CommonLiquefactionLayerResult,
+    models::{
+        foundation::Foundation,
+        soil_profile::{SoilLayer, SoilProfile},
+    },
+};
+
+fn create_layer(thickness: f64, c_prime: f64, phi_prime: f64) -> SoilLayer {
+    SoilLayer {
+        thickness: Some(thickness),
+        dry_unit_weight: Some(1.8),
+        saturated_unit_weight: Some(1.9),
+        c_prime: Some(c_prime),
+        phi_prime: Some(phi_prime),
+        ..Default::default()
+    }
+}
+
+fn create_profile() -> SoilProfile {
+    SoilProfile::new(
+        vec![
+            create_layer(3.0, 0.0, 32.0),
+            create_layer(2.0, 1.0, 20.0),
+            create_layer(5.0, 0.0, 18.0),
+        ],
+        2.0,
+    )
+}
+
+#[test]
+fn test_find_critical_settlement_layer() {
+    let profile = create_profile();
+    let settlement = SettlementResult {
+        settlement_per_layer: vec![1.0, 3.0, 2.0],
+        total_settlement: 6.0,
+        qnet: 10.0,
+        method: MethodId::Boussinesq,
+        warnings: vec![],
+        fully_compensated: false,
+        pressure_convention: PressureConvention::Gross,
+    };
+
+    let critical = find_critical_settlement_layer(&profile, &settlement)
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(critical.criterion, CriticalLayerCriterion::Settlement);
+    assert_eq!(critical.layer_index, 1);
+    assert_abs_diff_eq!(critical.top, 3.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(critical.bottom, 5.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(critical.value, 3.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(critical.share.unwrap(), 0.5, epsilon = 1e-9);
+}
+
+#[test]
+fn test_find_critical_settlement_layer_without_settlement() {
+    let profile = create_profile();
+    let settlement = SettlementResult {
+        settlement_per_layer: vec![0.0, 0.0, 0.0],
+        total_settlement: 0.0,
+        qnet: 0.0,
+        method: MethodId::Boussinesq,
+        warnings: vec![],
+        fully_compensated: true,
+        pressure_convention: PressureConvention::Gross,
+    };
+
+    let critical = find_critical_settlement_layer(&profile, &settlement).unwrap();
+
+    assert!(critical.is_none());
+}
+
+#[test]
+fn test_find_critical_bearing_layer() {
+    let profile = create_profile();
+    let foundation = Foundation {
+        foundation_depth: Some(1.0),
+        foundation_width: Some(2.0),
+        ..Default::default()
+    };
+
+    let critical = find_critical_bearing_layer(&profile, &foundation, AnalysisTerm::Long)
+        .unwrap()
+        .unwrap();
+
+    // Clay layer: 1.0 · 14.83 + 0.5 · (1.9 − 0.981) · 2.0 · 3.93.
+    // The softer layer below 5 m lies outside the influence zone.
+    assert_eq!(critical.criterion, CriticalLayerCriterion::BearingCapacity);
+    assert_eq!(critical.layer_index, 1);
+    assert_abs_diff_eq!(critical.top, 3.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(critical.bottom, 5.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(critical.value, 18.45, epsilon = 1e-2);
+    assert!(critical.share.is_none());
+}
+
+#[test]
+fn test_find_critical_bearing_layer_deeper_zone() {
+    let profile = create_profile();
+    let foundation = Foundation {
+        foundation_depth: Some(1.0),
+        foundation_width: Some(3.0),
+        ..Default::default()
+    };
+
+    let critical = find_critical_bearing_layer(&profile, &foundation, AnalysisTerm::Long)
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(critical.layer_index, 2);
+}
+
+#[test]
+fn test_find_critical_liquefaction_layer() {
+    let layers: Vec<CommonLiquefactionLayerResult> =
+        [(1.5, Some(2.0)), (3.0, Some(0.8)), (4.5, None)]
+            .into_iter()
+            .map(|(depth, safety_factor)| CommonLiquefactionLayerResult {
+                depth,
+                safety_factor,
+                ..Default::default()
+            })
+            .collect();
+
+    let critical = find_critical_liquefaction_layer(&layers).unwrap();
+
+    assert_eq!(critical.criterion, CriticalLayerCriterion::Liquefaction);
+    assert_eq!(critical.layer_index, 1);
+    assert_abs_diff_eq!(critical.top, 1.5, epsilon = 1e-9);
+    assert_abs_diff_eq!(critical.bottom, 3.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(critical.value, 0.8, epsilon = 1e-9);
+
+    assert!(find_critical_liquefaction_layer(&[]).is_none());
+}
+
+#[test]
+fn test_interpret_critical_layers() {
+    let layers = vec![CommonLiquefactionLayerResult {
+        depth: 3.0,
+        safety_factor: Some(0.8),
+        ..Default::default()
+    }];
+    let critical = find_critical_liquefaction_layer(&layers).unwrap();
+
+    let findings = interpret_critical_layers(&[critical]);
+
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].code(), "critical_layer");
+    assert_eq!(
+        findings[0],
+        Finding::CriticalLayer {
+            criterion: CriticalLayerCriterion::Liquefaction,
+            top: 0.0,
+            bottom: 3.0,
+            value: 0.8,
+        }
+    );
+    assert_eq!(
+        findings[0].message(),
+        "Layer between 0.0–3.0 m governs liquefaction with a safety factor of 0.80."
+    );
+}
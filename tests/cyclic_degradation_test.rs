@@ -0,0 +1,68 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    cyclic_degradation::{
+        calc_cyclic_degradation, calc_degradation_index, CyclicDegradationParams,
+    },
+    models::soil_profile::{SoilLayer, SoilProfile},
+};
+
+fn create_soil_profile() -> SoilProfile {
+    SoilProfile::new(
+        vec![
+            SoilLayer {
+                thickness: Some(2.0),
+                cu: Some(3.0),
+                ..Default::default()
+            },
+            SoilLayer {
+                thickness: Some(5.0),
+                cu: Some(6.0),
+                ..Default::default()
+            },
+        ],
+        1.0,
+    )
+}
+
+#[test]
+fn test_calc_degradation_index() {
+    assert_abs_diff_eq!(calc_degradation_index(10.0, 0.0), 1.0, epsilon = 1e-12);
+    assert_abs_diff_eq!(calc_degradation_index(10.0, 0.1), 0.794328, epsilon = 1e-6);
+}
+
+#[test]
+fn test_calc_cyclic_degradation() {
+    let soil_profile = create_soil_profile();
+    let params = CyclicDegradationParams::default();
+
+    let result = calc_cyclic_degradation(&soil_profile, &[0.15, 0.5], 10.0, &params).unwrap();
+
+    // Below threshold: no degradation
+    assert_abs_diff_eq!(result.layers[0].degradation_index, 1.0, epsilon = 1e-12);
+    assert_abs_diff_eq!(result.degraded_profile.layers[0].cu.unwrap(), 3.0);
+
+    // t = 0.3 * (0.5 - 0.2) = 0.09, δ = 10^-0.09
+    assert_abs_diff_eq!(result.layers[1].t, 0.09, epsilon = 1e-12);
+    assert_abs_diff_eq!(result.layers[1].degradation_index, 0.812831, epsilon = 1e-6);
+    assert_abs_diff_eq!(
+        result.degraded_profile.layers[1].cu.unwrap(),
+        4.876984,
+        epsilon = 1e-5
+    );
+}
+
+#[test]
+fn test_length_mismatch() {
+    let soil_profile = create_soil_profile();
+    let result = calc_cyclic_degradation(
+        &soil_profile,
+        &[0.5],
+        10.0,
+        &CyclicDegradationParams::default(),
+    );
+
+    assert_eq!(
+        result.unwrap_err().code,
+        "cyclic_degradation.cyclic_stress_ratios.length_mismatch"
+    );
+}
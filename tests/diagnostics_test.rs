@@ -0,0 +1,30 @@
+use soilrust::{
+    diagnostics::{check_limit, check_limits},
+    enums::MethodId,
+};
+
+#[test]
+fn test_check_limit() {
+    assert!(check_limit(MethodId::TezcanOzdemir, "vs", 300.0).is_none());
+
+    let warning = check_limit(MethodId::TezcanOzdemir, "vs", 100.0).unwrap();
+    assert_eq!(warning.code, "applicability.vs.out_of_range");
+    assert_eq!(warning.method, MethodId::TezcanOzdemir);
+    assert_eq!(warning.value, 100.0);
+}
+
+#[test]
+fn test_check_limit_without_range() {
+    assert!(check_limit(MethodId::Vesic, "vs", 100.0).is_none());
+}
+
+#[test]
+fn test_check_limits() {
+    let warnings = check_limits(
+        MethodId::Boussinesq,
+        &[("depth_ratio", 3.0), ("length_ratio", 2.0)],
+    );
+
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].parameter, "depth_ratio");
+}
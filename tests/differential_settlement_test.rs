@@ -0,0 +1,191 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    differential_settlement::*,
+    elastic_settlement::boussinesq::{calc_corner_settlement, calc_elastic_settlement},
+    enums::PressureConvention,
+    models::{
+        foundation::Foundation,
+        soil_profile::{SoilLayer, SoilProfile},
+    },
+};
+
+fn create_soil_profile() -> SoilProfile {
+    SoilProfile::new(
+        vec![SoilLayer {
+            thickness: Some(20.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(1.9),
+            poissons_ratio: Some(0.3),
+            elastic_modulus: Some(1500.0),
+            ..Default::default()
+        }],
+        10.0,
+    )
+}
+
+fn create_foundation(width: f64) -> Foundation {
+    Foundation {
+        foundation_depth: Some(1.0),
+        foundation_width: Some(width),
+        foundation_length: Some(width),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_calc_differential_settlements() {
+    let locations = vec![
+        LocationSettlement::new("A", 0.0, 0.0, 2.0),
+        LocationSettlement::new("B", 6.0, 0.0, 3.0),
+        LocationSettlement::new("C", 6.0, 8.0, 5.0),
+    ];
+
+    let result = calc_differential_settlements(locations, &DistortionLimits::default()).unwrap();
+
+    assert_eq!(result.pairs.len(), 3);
+    let ab = &result.pairs[0];
+    assert_eq!((ab.first.as_str(), ab.second.as_str()), ("A", "B"));
+    assert_abs_diff_eq!(ab.distance, 6.0);
+    assert_abs_diff_eq!(ab.differential_settlement, 1.0);
+    assert_abs_diff_eq!(ab.angular_distortion, 0.01 / 6.0, epsilon = 1e-12);
+    assert!(ab.is_safe);
+
+    // A-C: 3 cm over 10 m = 1/333
+    let ac = &result.pairs[1];
+    assert_abs_diff_eq!(ac.distance, 10.0);
+    assert_abs_diff_eq!(ac.angular_distortion, 0.003, epsilon = 1e-12);
+    assert!(!ac.is_safe);
+
+    assert_abs_diff_eq!(result.max_differential_settlement, 3.0);
+    assert_abs_diff_eq!(result.max_angular_distortion, 0.003, epsilon = 1e-12);
+    assert!(!result.is_safe);
+}
+
+#[test]
+fn test_differential_settlement_limit() {
+    let locations = vec![
+        LocationSettlement::new("A", 0.0, 0.0, 2.0),
+        LocationSettlement::new("B", 10.0, 0.0, 3.5),
+    ];
+    let limits = DistortionLimits {
+        angular_distortion: 1.0 / 300.0,
+        differential_settlement: Some(1.0),
+    };
+
+    let result = calc_differential_settlements(locations, &limits).unwrap();
+
+    // 1/667 is within the distortion limit, but 1.5 cm exceeds 1 cm
+    assert!(result.pairs[0].angular_distortion < limits.angular_distortion);
+    assert!(!result.is_safe);
+}
+
+#[test]
+fn test_calc_footing_differential_settlements() {
+    let soil_profile = create_soil_profile();
+    let footings = vec![
+        LocatedFoundation {
+            id: "S1".into(),
+            x: 0.0,
+            y: 0.0,
+            foundation: create_foundation(2.0),
+            foundation_pressure: 20.0,
+            pressure_convention: PressureConvention::Gross,
+        },
+        LocatedFoundation {
+            id: "S2".into(),
+            x: 5.0,
+            y: 0.0,
+            foundation: create_foundation(3.0),
+            foundation_pressure: 20.0,
+            pressure_convention: PressureConvention::Gross,
+        },
+    ];
+
+    let result =
+        calc_footing_differential_settlements(&soil_profile, &footings, &Default::default())
+            .unwrap();
+
+    let s1 = calc_elastic_settlement(
+        &mut soil_profile.clone(),
+        &create_foundation(2.0),
+        20.0,
+        PressureConvention::Gross,
+    )
+    .unwrap()
+    .total_settlement;
+    let s2 = calc_elastic_settlement(
+        &mut soil_profile.clone(),
+        &create_foundation(3.0),
+        20.0,
+        PressureConvention::Gross,
+    )
+    .unwrap()
+    .total_settlement;
+    assert_abs_diff_eq!(result.locations[0].settlement, s1, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.locations[1].settlement, s2, epsilon = 1e-9);
+    assert_abs_diff_eq!(
+        result.pairs[0].angular_distortion,
+        (s2 - s1).abs() / 100.0 / 5.0,
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_calc_mat_differential_settlements() {
+    let soil_profile = create_soil_profile();
+    let foundation = Foundation {
+        foundation_width: Some(12.0),
+        foundation_length: Some(16.0),
+        ..create_foundation(12.0)
+    };
+
+    let result = calc_mat_differential_settlements(
+        &soil_profile,
+        &foundation,
+        15.0,
+        PressureConvention::Gross,
+        &Default::default(),
+    )
+    .unwrap();
+
+    assert_eq!(result.locations.len(), 5);
+    assert_eq!(result.pairs.len(), 10);
+    let corner = calc_corner_settlement(
+        &mut soil_profile.clone(),
+        &foundation,
+        15.0,
+        PressureConvention::Gross,
+    )
+    .unwrap()
+    .total_settlement;
+    assert_abs_diff_eq!(result.locations[1].settlement, corner, epsilon = 1e-9);
+    // Center to corner over half the diagonal of 20 m
+    let center_corner = &result.pairs[0];
+    assert_abs_diff_eq!(center_corner.distance, 10.0, epsilon = 1e-9);
+    assert!(result.locations[0].settlement > corner);
+    assert_abs_diff_eq!(
+        result.max_differential_settlement,
+        result.locations[0].settlement - corner,
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_invalid_locations() {
+    let err = calc_differential_settlements(
+        vec![LocationSettlement::new("A", 0.0, 0.0, 2.0)],
+        &DistortionLimits::default(),
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "differential_settlement.too_few_locations");
+
+    let err = calc_differential_settlements(
+        vec![
+            LocationSettlement::new("A", 1.0, 1.0, 2.0),
+            LocationSettlement::new("B", 1.0, 1.0, 3.0),
+        ],
+        &DistortionLimits::default(),
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "differential_settlement.coincident_locations");
+}
@@ -0,0 +1,111 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    drivability::{calc_drivability, calc_enr_blow_count, get_setup_factor},
+    models::{
+        driven_pile::DrivenPile,
+        soil_profile::{SoilLayer, SoilProfile},
+    },
+};
+
+fn create_soil_profile() -> SoilProfile {
+    SoilProfile::new(
+        vec![
+            SoilLayer {
+                thickness: Some(5.0),
+                cu: Some(4.0),
+                dry_unit_weight: Some(1.8),
+                saturated_unit_weight: Some(1.9),
+                ..Default::default()
+            },
+            SoilLayer {
+                thickness: Some(15.0),
+                phi_prime: Some(32.0),
+                dry_unit_weight: Some(1.9),
+                saturated_unit_weight: Some(2.0),
+                ..Default::default()
+            },
+        ],
+        2.0,
+    )
+}
+
+fn create_pile() -> DrivenPile {
+    DrivenPile {
+        diameter: Some(0.4),
+        length: Some(12.0),
+        hammer_energy: Some(3.0),
+        hammer_efficiency: Some(0.8),
+        refusal_blow_count: Some(100.0),
+    }
+}
+
+#[test]
+fn test_setup_factor() {
+    let clay = SoilLayer {
+        cu: Some(5.0),
+        ..Default::default()
+    };
+    let silty_sand = SoilLayer {
+        phi_prime: Some(30.0),
+        fine_content: Some(20.0),
+        ..Default::default()
+    };
+    let sand = SoilLayer {
+        phi_prime: Some(30.0),
+        ..Default::default()
+    };
+
+    assert_eq!(get_setup_factor(&clay), 2.0);
+    assert_eq!(get_setup_factor(&silty_sand), 1.2);
+    assert_eq!(get_setup_factor(&sand), 1.0);
+}
+
+#[test]
+fn test_enr_blow_count_refusal() {
+    assert!(calc_enr_blow_count(1000.0, 2.0, 0.8).is_infinite());
+    assert_abs_diff_eq!(calc_enr_blow_count(100.0, 5.0, 0.8), 6.6738, epsilon = 1e-4);
+}
+
+#[test]
+fn test_drivability_profile() {
+    let result = calc_drivability(&create_pile(), &mut create_soil_profile(), 1.0).unwrap();
+
+    assert_eq!(result.points.len(), 12);
+    assert!(result.refusal_depth.is_none());
+
+    let clay_bottom = &result.points[4];
+    assert_abs_diff_eq!(clay_bottom.shaft_resistance, 12.39651, epsilon = 1e-4);
+    assert_abs_diff_eq!(clay_bottom.driving_resistance, 10.72215, epsilon = 1e-4);
+
+    let toe = result.points.last().unwrap();
+    assert_abs_diff_eq!(toe.depth, 12.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(toe.shaft_resistance, 56.87383, epsilon = 1e-4);
+    assert_abs_diff_eq!(toe.toe_resistance, 39.28935, epsilon = 1e-4);
+    assert_abs_diff_eq!(toe.driving_resistance, 89.96493, epsilon = 1e-4);
+    assert_abs_diff_eq!(toe.static_capacity, 96.16318, epsilon = 1e-4);
+    assert_abs_diff_eq!(toe.enr_blow_count, 10.3575, epsilon = 1e-3);
+    assert_abs_diff_eq!(toe.gates_blow_count, 19.55507, epsilon = 1e-4);
+}
+
+#[test]
+fn test_drivability_refusal_depth() {
+    let mut pile = create_pile();
+    pile.refusal_blow_count = Some(5.0);
+
+    let result = calc_drivability(&pile, &mut create_soil_profile(), 1.0).unwrap();
+    assert_eq!(result.refusal_depth, Some(6.0));
+    assert!(!result.points[4].is_refusal);
+    assert!(result.points[5].is_refusal);
+}
+
+#[test]
+fn test_pile_longer_than_profile() {
+    let mut pile = create_pile();
+    pile.length = Some(25.0);
+
+    let err = calc_drivability(&pile, &mut create_soil_profile(), 1.0).unwrap_err();
+    assert_eq!(
+        err.code,
+        "driven_pile.length.greater_than_soil_profile_depth"
+    );
+}
@@ -1,6 +1,7 @@
 use approx::assert_abs_diff_eq;
 use soilrust::{
     effective_depth::calc_effective_depth,
+    enums::PressureConvention,
     models::{
         foundation::Foundation,
         soil_profile::{SoilLayer, SoilProfile},
@@ -33,6 +34,7 @@ fn create_soil_profile() -> SoilProfile {
                 ..Default::default()
             },
         ],
+        ..Default::default()
     }
 }
 fn create_foundation_data() -> Foundation {
@@ -50,8 +52,28 @@ fn test_effective_depth() {
     let foundation_data = create_foundation_data();
     let foundation_pressure = 50.;
 
-    let effective_depth =
-        calc_effective_depth(&soil_profile, &foundation_data, foundation_pressure).unwrap();
+    let effective_depth = calc_effective_depth(
+        &soil_profile,
+        &foundation_data,
+        foundation_pressure,
+        PressureConvention::Gross,
+    )
+    .unwrap();
     let expected_depth = 34.41;
     assert_abs_diff_eq!(effective_depth, expected_depth, epsilon = 1e-2);
 }
+
+#[test]
+fn test_effective_depth_compensated() {
+    let soil_profile = create_soil_profile();
+    let foundation_data = create_foundation_data();
+
+    let effective_depth = calc_effective_depth(
+        &soil_profile,
+        &foundation_data,
+        1.0,
+        PressureConvention::Gross,
+    )
+    .unwrap();
+    assert_abs_diff_eq!(effective_depth, 2.0, epsilon = 1e-9);
+}
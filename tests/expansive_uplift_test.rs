@@ -0,0 +1,122 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    models::{
+        foundation::Foundation,
+        soil_profile::{SoilLayer, SoilProfile},
+    },
+    stability::expansive_uplift::*,
+};
+
+fn create_soil_profile() -> SoilProfile {
+    SoilProfile::new(
+        vec![
+            // Swelling pressure 8.89 t/m²
+            SoilLayer {
+                dry_unit_weight: Some(1.8),
+                saturated_unit_weight: Some(1.9),
+                liquid_limit: Some(43.9),
+                plastic_limit: Some(21.3),
+                water_content: Some(23.7),
+                ..SoilLayer::new(3.0)
+            },
+            // Not swelling
+            SoilLayer {
+                dry_unit_weight: Some(1.5),
+                saturated_unit_weight: Some(1.9),
+                liquid_limit: Some(40.0),
+                plastic_limit: Some(20.0),
+                water_content: Some(40.0),
+                cu: Some(10.0),
+                ..SoilLayer::new(10.0)
+            },
+        ],
+        20.0,
+    )
+}
+
+fn create_footing() -> Foundation {
+    Foundation {
+        foundation_depth: Some(1.0),
+        foundation_width: Some(1.0),
+        foundation_length: Some(1.0),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_calc_expansive_uplift() {
+    let params = ExpansiveUpliftParams::default();
+
+    let results = calc_expansive_uplift(
+        &create_soil_profile(),
+        &[create_footing(), create_footing()],
+        &[5.0, 12.0],
+        &params,
+    )
+    .unwrap();
+
+    assert_eq!(results.len(), 2);
+    // pu = 8.8925 - 1.8 * (2 - 1)
+    assert_abs_diff_eq!(results[0].uplift_pressure, 7.0925, epsilon = 1e-3);
+    assert_abs_diff_eq!(results[0].uplift_force, 7.0925, epsilon = 1e-3);
+    assert_abs_diff_eq!(results[0].factor_of_safety, 0.705, epsilon = 1e-3);
+    assert_abs_diff_eq!(results[0].required_dead_load, 10.639, epsilon = 1e-3);
+    assert!(!results[0].is_adequate);
+
+    assert_eq!(results[1].footing_index, 1);
+    assert!(results[1].is_adequate);
+}
+
+#[test]
+fn test_required_pier_depth() {
+    let params = ExpansiveUpliftParams::default();
+
+    let results = calc_expansive_uplift(
+        &create_soil_profile(),
+        &[create_footing(), create_footing()],
+        &[5.0, 1.0],
+        &params,
+    )
+    .unwrap();
+
+    // U = 0.15 * π * 0.3 * 8.8925 * 2
+    assert_abs_diff_eq!(results[0].pier_uplift_force, 2.5143, epsilon = 1e-3);
+    // The dead load resists the shaft uplift, so the pier only passes the active zone
+    assert_abs_diff_eq!(results[0].required_pier_depth.unwrap(), 3.0);
+    // (1.5 * 2.5143 - 1) / (0.45 * 10 * π * 0.3) below the active zone
+    assert_abs_diff_eq!(
+        results[1].required_pier_depth.unwrap(),
+        3.6535,
+        epsilon = 1e-3
+    );
+}
+
+#[test]
+fn test_pier_depth_beyond_profile() {
+    let params = ExpansiveUpliftParams {
+        adhesion_factor: 0.01,
+        ..Default::default()
+    };
+
+    let results =
+        calc_expansive_uplift(&create_soil_profile(), &[create_footing()], &[0.0], &params)
+            .unwrap();
+
+    assert!(results[0].required_pier_depth.is_none());
+}
+
+#[test]
+fn test_validation_errors() {
+    let params = ExpansiveUpliftParams::default();
+    let profile = create_soil_profile();
+
+    let err = calc_expansive_uplift(&profile, &[create_footing()], &[], &params).unwrap_err();
+    assert_eq!(err.code, "expansive_uplift.dead_loads.invalid_length");
+
+    let shallow = ExpansiveUpliftParams {
+        active_zone_depth: 0.5,
+        ..Default::default()
+    };
+    let err = calc_expansive_uplift(&profile, &[create_footing()], &[5.0], &shallow).unwrap_err();
+    assert_eq!(err.code, "expansive_uplift.active_zone_depth.too_small.1");
+}
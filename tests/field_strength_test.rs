@@ -0,0 +1,153 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    enums::SelectionMethod,
+    field_strength::{calc_layer_strength_ranges, populate_layer_cu},
+    models::{
+        pocket_penetrometer::{
+            PocketPenetrometerExp, PocketPenetrometerSample, PocketPenetrometerTest,
+        },
+        soil_profile::{SoilLayer, SoilProfile},
+        torvane::{TorvaneExp, TorvaneSample, TorvaneTest},
+    },
+};
+
+fn create_pocket_penetrometer_test(mode: SelectionMethod) -> PocketPenetrometerTest {
+    let sk1 = PocketPenetrometerExp::new(
+        "Borehole1".to_string(),
+        vec![
+            PocketPenetrometerSample::new(1.0, 10.0),
+            PocketPenetrometerSample::new(2.0, 14.0),
+            PocketPenetrometerSample::new(4.0, 20.0),
+        ],
+    );
+    let sk2 = PocketPenetrometerExp::new(
+        "Borehole2".to_string(),
+        vec![
+            PocketPenetrometerSample::new(1.0, 12.0),
+            PocketPenetrometerSample::new(4.0, 24.0),
+        ],
+    );
+
+    PocketPenetrometerTest::new(vec![sk1, sk2], mode)
+}
+
+fn create_soil_profile() -> SoilProfile {
+    SoilProfile::new(
+        vec![
+            SoilLayer {
+                cu: Some(1.0),
+                ..SoilLayer::new(3.0)
+            },
+            SoilLayer::new(3.0),
+            SoilLayer::new(4.0),
+        ],
+        10.0,
+    )
+}
+/// -----------------------------------------------------------------------------------
+#[test]
+fn test_pocket_penetrometer_idealized_exp() {
+    let min = create_pocket_penetrometer_test(SelectionMethod::Min).get_idealized_exp("Min".into());
+    let avg = create_pocket_penetrometer_test(SelectionMethod::Avg).get_idealized_exp("Avg".into());
+    let max = create_pocket_penetrometer_test(SelectionMethod::Max).get_idealized_exp("Max".into());
+
+    assert_eq!(min.samples.len(), 3);
+    assert_abs_diff_eq!(min.samples[0].qu.unwrap(), 10.0);
+    assert_abs_diff_eq!(avg.samples[0].qu.unwrap(), 11.0);
+    assert_abs_diff_eq!(max.samples[0].qu.unwrap(), 12.0);
+    assert_abs_diff_eq!(avg.samples[2].qu.unwrap(), 22.0);
+}
+
+#[test]
+fn test_pocket_penetrometer_undrained_strengths() {
+    let test = create_pocket_penetrometer_test(SelectionMethod::Avg);
+    let readings = test.get_undrained_strengths();
+
+    assert_eq!(readings.len(), 5);
+    assert_abs_diff_eq!(readings[0].0, 1.0);
+    assert_abs_diff_eq!(readings[0].1, 5.0);
+
+    let sample = test.exps[0].get_sample_at_depth(1.5);
+    assert_abs_diff_eq!(sample.calc_undrained_strength(), 7.0);
+}
+
+#[test]
+fn test_torvane_idealized_exp() {
+    let sk1 = TorvaneExp::new(
+        "Borehole1".to_string(),
+        vec![TorvaneSample::new(1.0, 4.0), TorvaneSample::new(2.0, 6.0)],
+    );
+    let sk2 = TorvaneExp::new("Borehole2".to_string(), vec![TorvaneSample::new(1.0, 5.0)]);
+    let test = TorvaneTest::new(vec![sk1, sk2], SelectionMethod::Min);
+
+    let exp = test.get_idealized_exp("Idealized".into());
+    assert_eq!(exp.samples.len(), 2);
+    assert_abs_diff_eq!(exp.samples[0].su.unwrap(), 4.0);
+    assert_abs_diff_eq!(exp.samples[1].calc_undrained_strength(), 6.0);
+    assert_eq!(test.get_undrained_strengths().len(), 3);
+}
+
+#[test]
+fn test_validation_errors() {
+    let test = TorvaneTest::new(vec![], SelectionMethod::Avg);
+    assert_eq!(
+        test.validate(&["depth", "su"]).unwrap_err().code,
+        "torvane.empty_exps"
+    );
+
+    let exp = PocketPenetrometerExp::new("Borehole1".to_string(), vec![]);
+    assert_eq!(
+        exp.validate(&["depth", "qu"]).unwrap_err().code,
+        "pocket_penetrometer.empty_samples"
+    );
+
+    let sample = PocketPenetrometerSample::new(1.0, -1.0);
+    assert_eq!(
+        sample.validate(&["qu"]).unwrap_err().code,
+        "pocket_penetrometer.qu.too_small.0"
+    );
+}
+/// -----------------------------------------------------------------------------------
+#[test]
+fn test_calc_layer_strength_ranges() {
+    let profile = create_soil_profile();
+    let readings = create_pocket_penetrometer_test(SelectionMethod::Avg).get_undrained_strengths();
+
+    let ranges = calc_layer_strength_ranges(&profile, &readings, SelectionMethod::Min);
+
+    assert_eq!(ranges.len(), 2);
+    assert_eq!(ranges[0].layer_index, 0);
+    assert_eq!(ranges[0].count, 3);
+    assert_abs_diff_eq!(ranges[0].min, 5.0);
+    assert_abs_diff_eq!(ranges[0].average, 6.0);
+    assert_abs_diff_eq!(ranges[0].max, 7.0);
+    assert_abs_diff_eq!(ranges[0].selected, 5.0);
+
+    assert_eq!(ranges[1].layer_index, 1);
+    assert_abs_diff_eq!(ranges[1].top, 3.0);
+    assert_abs_diff_eq!(ranges[1].bottom, 6.0);
+    assert_abs_diff_eq!(ranges[1].min, 10.0);
+    assert_abs_diff_eq!(ranges[1].max, 12.0);
+}
+
+#[test]
+fn test_populate_layer_cu() {
+    let profile = create_soil_profile();
+    let readings = create_pocket_penetrometer_test(SelectionMethod::Avg).get_undrained_strengths();
+
+    let result = populate_layer_cu(&profile, &readings, SelectionMethod::Avg).unwrap();
+    let layers = &result.populated_profile.layers;
+
+    assert_abs_diff_eq!(layers[0].cu.unwrap(), 6.0);
+    assert_abs_diff_eq!(layers[1].cu.unwrap(), 11.0);
+    assert!(layers[2].cu.is_none());
+    assert_abs_diff_eq!(profile.layers[0].cu.unwrap(), 1.0);
+}
+
+#[test]
+fn test_populate_layer_cu_empty_readings() {
+    let profile = create_soil_profile();
+
+    let result = populate_layer_cu(&profile, &[], SelectionMethod::Avg);
+    assert_eq!(result.unwrap_err().code, "field_strength.empty_readings");
+}
@@ -0,0 +1,90 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    enums::FilterCriterion,
+    filter_design::{check_filter, check_filter_candidates},
+    models::gradation::GradationCurve,
+};
+
+fn create_base() -> GradationCurve {
+    GradationCurve::new(
+        vec![0.01, 0.075, 0.15, 0.3, 0.6, 1.18],
+        vec![0.0, 10.0, 30.0, 70.0, 95.0, 100.0],
+    )
+}
+
+fn create_sand_filter() -> GradationCurve {
+    GradationCurve::new(
+        vec![0.075, 0.3, 0.6, 1.18, 2.36, 4.75, 9.5],
+        vec![2.0, 5.0, 15.0, 35.0, 60.0, 85.0, 100.0],
+    )
+}
+
+fn create_gravel_filter() -> GradationCurve {
+    GradationCurve::new(
+        vec![0.075, 0.6, 1.18, 2.36, 4.75, 9.5, 19.0],
+        vec![1.0, 3.0, 8.0, 15.0, 40.0, 80.0, 100.0],
+    )
+}
+
+#[test]
+fn test_compatible_filter() {
+    let result = check_filter(&create_base(), &create_sand_filter()).unwrap();
+
+    assert_eq!(result.checks.len(), 6);
+    assert!(result.is_compatible);
+
+    let retention = &result.checks[0];
+    assert_eq!(retention.criterion, FilterCriterion::TerzaghiRetention);
+    assert_abs_diff_eq!(retention.value, 1.319508, epsilon = 1e-6);
+
+    let permeability = &result.checks[1];
+    assert_eq!(
+        permeability.criterion,
+        FilterCriterion::TerzaghiPermeability
+    );
+    assert_abs_diff_eq!(permeability.value, 6.727171, epsilon = 1e-6);
+
+    assert_abs_diff_eq!(result.checks[3].value, 8.431285, epsilon = 1e-6);
+    assert_abs_diff_eq!(result.checks[5].value, 2.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_too_coarse_filter() {
+    let result = check_filter(&create_base(), &create_gravel_filter()).unwrap();
+
+    assert!(!result.is_compatible);
+    let failed: Vec<FilterCriterion> = result
+        .checks
+        .iter()
+        .filter(|c| !c.is_satisfied)
+        .map(|c| c.criterion)
+        .collect();
+    assert_eq!(
+        failed,
+        vec![
+            FilterCriterion::TerzaghiRetention,
+            FilterCriterion::UsaceRetention,
+            FilterCriterion::UsaceD50Ratio,
+        ]
+    );
+}
+
+#[test]
+fn test_filter_candidates() {
+    let results = check_filter_candidates(
+        &create_base(),
+        &[create_sand_filter(), create_gravel_filter()],
+    )
+    .unwrap();
+
+    assert!(results[0].is_compatible);
+    assert!(!results[1].is_compatible);
+}
+
+#[test]
+fn test_unavailable_diameter() {
+    let filter = GradationCurve::new(vec![0.075, 4.75], vec![20.0, 100.0]);
+
+    let err = check_filter(&create_base(), &filter).unwrap_err();
+    assert_eq!(err.code, "filter_design.filter.d15_unavailable");
+}
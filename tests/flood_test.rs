@@ -0,0 +1,141 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    bearing_capacity::vesic,
+    constants::Environment,
+    enums::{AnalysisTerm, PressureConvention},
+    flood::{calc_flood_stability, calc_flotation, calc_uplift_pressure},
+    models::{
+        foundation::Foundation,
+        loads::Loads,
+        soil_profile::{SoilLayer, SoilProfile},
+    },
+};
+
+fn create_layer(thickness: f64, phi: f64, c: f64) -> SoilLayer {
+    SoilLayer {
+        thickness: Some(thickness),
+        dry_unit_weight: Some(1.8),
+        saturated_unit_weight: Some(1.9),
+        c_prime: Some(c),
+        phi_prime: Some(phi),
+        cu: Some(0.0),
+        phi_u: Some(phi),
+        ..Default::default()
+    }
+}
+
+fn create_soil_profile() -> SoilProfile {
+    SoilProfile::new(
+        vec![create_layer(3.0, 28.0, 0.5), create_layer(20.0, 32.0, 0.0)],
+        5.0,
+    )
+}
+
+fn create_foundation() -> Foundation {
+    Foundation {
+        foundation_width: Some(4.0),
+        foundation_length: Some(8.0),
+        foundation_depth: Some(2.0),
+        surface_friction_coefficient: Some(0.6),
+        ..Default::default()
+    }
+}
+
+fn create_loads(flood_height: f64) -> Loads {
+    Loads {
+        vertical_load: Some(500.0),
+        horizontal_load_x: Some(20.0),
+        horizontal_load_y: Some(10.0),
+        flood_height: Some(flood_height),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_uplift_pressure() {
+    assert_abs_diff_eq!(
+        calc_uplift_pressure(2.0, 1.5, &Environment::default()),
+        3.4335,
+        epsilon = 1e-9
+    );
+    // Sea water, 1.025 · 0.981 · 3.5
+    assert_abs_diff_eq!(
+        calc_uplift_pressure(2.0, 1.5, &Environment::sea_water()),
+        3.5193375,
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_flotation() {
+    let result = calc_flotation(
+        &create_foundation(),
+        &create_loads(1.5),
+        &Environment::default(),
+    )
+    .unwrap();
+
+    assert_abs_diff_eq!(result.uplift_force, 109.872, epsilon = 1e-6);
+    assert_abs_diff_eq!(result.factor_of_safety, 500.0 / 109.872, epsilon = 1e-6);
+    assert!(result.is_safe);
+
+    let result = calc_flotation(
+        &create_foundation(),
+        &create_loads(14.0),
+        &Environment::default(),
+    )
+    .unwrap();
+    assert!(!result.is_safe);
+}
+
+#[test]
+fn test_flood_stability() {
+    let result = calc_flood_stability(
+        &create_soil_profile(),
+        &create_foundation(),
+        &create_loads(1.5),
+        15.0,
+        3.0,
+        AnalysisTerm::Long,
+    )
+    .unwrap();
+
+    assert_eq!(result.flooded_profile.ground_water_level, Some(0.0));
+    assert_abs_diff_eq!(
+        result.effective_foundation_pressure,
+        15.0 - 3.4335,
+        epsilon = 1e-9
+    );
+
+    let mut expected_profile = SoilProfile::new(
+        vec![create_layer(3.0, 28.0, 0.5), create_layer(20.0, 32.0, 0.0)],
+        0.0,
+    );
+    let expected = vesic::calc_bearing_capacity(
+        &mut expected_profile,
+        &mut create_foundation(),
+        &create_loads(1.5),
+        15.0 - 3.4335,
+        PressureConvention::Gross,
+        3.0,
+        AnalysisTerm::Long,
+    )
+    .unwrap();
+    assert_abs_diff_eq!(
+        result.bearing_capacity.ultimate_bearing_capacity,
+        expected.ultimate_bearing_capacity,
+        epsilon = 1e-9
+    );
+    assert!(result.horizontal_sliding.ptv < 15.0 * 4.0 * 8.0);
+}
+
+#[test]
+fn test_flood_height_validation() {
+    let err = calc_flotation(
+        &create_foundation(),
+        &create_loads(-1.0),
+        &Environment::default(),
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "loads.flood_height.too_small.0");
+}
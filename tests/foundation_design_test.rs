@@ -0,0 +1,318 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    enums::LoadCase,
+    foundation_design::{
+        calc_combined_footing_pressure, calc_footing_pressures, calc_mat_pressure,
+        calc_schedule_mat_pressure, calc_strap_footing_pressure,
+    },
+    models::{
+        column_load::{ColumnLoad, MatColumnLoad},
+        column_schedule::{ColumnLoadCase, ColumnSchedule, ScheduledColumn},
+        foundation::Foundation,
+    },
+};
+
+fn create_foundation(width: f64, length: f64) -> Foundation {
+    Foundation {
+        foundation_width: Some(width),
+        foundation_length: Some(length),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_calc_combined_footing_pressure() {
+    let columns = [
+        ColumnLoad::new(1.0, 60.0, 0.0),
+        ColumnLoad::new(5.0, 90.0, 0.0),
+    ];
+
+    let result =
+        calc_combined_footing_pressure(&create_foundation(2.0, 6.0), &columns, 20.0).unwrap();
+
+    // xR = (60 · 1 + 90 · 5) / 150 = 3.4, e = 0.4, q = 12.5 · (1 ± 6 · 0.4 / 6)
+    assert_abs_diff_eq!(result.total_load, 150.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.resultant_position, 3.4, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.eccentricity, 0.4, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.max_pressure, 17.5, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.min_pressure, 7.5, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.left_edge_pressure, 7.5, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.right_edge_pressure, 17.5, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.contact_length, 6.0, epsilon = 1e-9);
+    assert!(!result.has_tension);
+    assert!(result.is_safe);
+
+    // A column moment of −60 t·m brings the resultant back to the centre
+    let columns = [
+        ColumnLoad::new(1.0, 60.0, -60.0),
+        ColumnLoad::new(5.0, 90.0, 0.0),
+    ];
+    let result =
+        calc_combined_footing_pressure(&create_foundation(2.0, 6.0), &columns, 10.0).unwrap();
+    assert_abs_diff_eq!(result.eccentricity, 0.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.max_pressure, 12.5, epsilon = 1e-9);
+    assert!(!result.is_safe);
+}
+
+#[test]
+fn test_calc_combined_footing_pressure_with_tension() {
+    let columns = [
+        ColumnLoad::new(0.5, 10.0, 0.0),
+        ColumnLoad::new(5.5, 100.0, 0.0),
+    ];
+
+    let result =
+        calc_combined_footing_pressure(&create_foundation(2.0, 6.0), &columns, 50.0).unwrap();
+
+    let e = 555.0 / 110.0 - 3.0;
+    assert_abs_diff_eq!(result.eccentricity, e, epsilon = 1e-9);
+    assert!(result.has_tension);
+    assert_abs_diff_eq!(result.contact_length, 3.0 * (3.0 - e), epsilon = 1e-9);
+    assert_abs_diff_eq!(
+        result.max_pressure,
+        2.0 * 110.0 / (3.0 * 2.0 * (3.0 - e)),
+        epsilon = 1e-9
+    );
+    assert_abs_diff_eq!(result.left_edge_pressure, 0.0, epsilon = 1e-9);
+    assert!(result.is_safe);
+
+    let columns = [
+        ColumnLoad::new(7.0, 10.0, 0.0),
+        ColumnLoad::new(5.5, 100.0, 0.0),
+    ];
+    let err =
+        calc_combined_footing_pressure(&create_foundation(2.0, 6.0), &columns, 50.0).unwrap_err();
+    assert_eq!(err.code, "column_load.position.too_large.6");
+}
+
+#[test]
+fn test_calc_strap_footing_pressure() {
+    let columns = [
+        ColumnLoad::new(0.25, 80.0, 0.0),
+        ColumnLoad::new(5.25, 120.0, 0.0),
+    ];
+
+    let result = calc_strap_footing_pressure(
+        &create_foundation(1.5, 2.0),
+        &create_foundation(2.0, 2.0),
+        &columns,
+        30.0,
+    )
+    .unwrap();
+
+    // xR = 3.25, R1 = 200 · (5.25 − 3.25) / (5.25 − 0.75)
+    let r1 = 400.0 / 4.5;
+    assert_abs_diff_eq!(result.exterior_reaction, r1, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.interior_reaction, 200.0 - r1, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.exterior_pressure, r1 / 3.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.interior_pressure, (200.0 - r1) / 4.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.strap_shear, r1 - 80.0, epsilon = 1e-9);
+    assert!(!result.has_tension);
+    assert!(result.is_safe);
+
+    // A light interior column cannot hold the strap down
+    let columns = [
+        ColumnLoad::new(0.25, 80.0, 0.0),
+        ColumnLoad::new(1.25, 5.0, 0.0),
+    ];
+    let result = calc_strap_footing_pressure(
+        &create_foundation(1.5, 2.0),
+        &create_foundation(2.0, 2.0),
+        &columns,
+        30.0,
+    )
+    .unwrap();
+    assert!(result.interior_reaction < 0.0);
+    assert!(result.has_tension);
+}
+
+fn create_mat_columns() -> Vec<MatColumnLoad> {
+    vec![
+        MatColumnLoad::new(2.5, 5.0, 100.0),
+        MatColumnLoad::new(7.5, 5.0, 100.0),
+        MatColumnLoad::new(2.5, 15.0, 100.0),
+        MatColumnLoad::new(7.5, 15.0, 100.0),
+    ]
+}
+
+#[test]
+fn test_calc_mat_pressure() {
+    let subgrade_moduli = vec![vec![1000.0, 1000.0], vec![1000.0, 2000.0]];
+
+    // Symmetric columns give a uniform pressure of 400 / 200
+    let result = calc_mat_pressure(
+        &create_foundation(10.0, 20.0),
+        &create_mat_columns(),
+        &subgrade_moduli,
+        5.0,
+    )
+    .unwrap();
+    assert_abs_diff_eq!(result.eccentricity_x, 0.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.eccentricity_y, 0.0, epsilon = 1e-9);
+    for &q in result.zone_pressures.iter().flatten() {
+        assert_abs_diff_eq!(q, 2.0, epsilon = 1e-9);
+    }
+    assert_abs_diff_eq!(result.zone_settlements[0][0], 0.2, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.zone_settlements[1][1], 0.1, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.max_utilization, 0.4, epsilon = 1e-9);
+    assert!(result.is_safe);
+
+    // A corner column of 200 t: ex = 5/3, ey = 10/3
+    let mut columns = create_mat_columns();
+    columns.push(MatColumnLoad::new(10.0, 20.0, 200.0));
+    let result = calc_mat_pressure(
+        &create_foundation(10.0, 20.0),
+        &columns,
+        &subgrade_moduli,
+        5.0,
+    )
+    .unwrap();
+
+    assert_abs_diff_eq!(result.total_load, 600.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.eccentricity_x, 5.0 / 3.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.eccentricity_y, 10.0 / 3.0, epsilon = 1e-9);
+    // q = 3 ± 1.5 ± 1.5 at the zone centres (±2.5, ±5)
+    assert_abs_diff_eq!(result.zone_pressures[0][0], 0.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.zone_pressures[0][1], 3.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.zone_pressures[1][0], 3.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.zone_pressures[1][1], 6.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.zone_utilizations[1][1], 1.2, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.zone_settlements[1][1], 0.3, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.min_pressure, 0.0, epsilon = 1e-9);
+    assert!(!result.is_safe);
+
+    // A heavier corner column lifts the opposite corner
+    columns[4].load = Some(400.0);
+    let result = calc_mat_pressure(
+        &create_foundation(10.0, 20.0),
+        &columns,
+        &subgrade_moduli,
+        5.0,
+    )
+    .unwrap();
+    assert_abs_diff_eq!(result.zone_pressures[0][0], -2.0, epsilon = 1e-9);
+    assert!(result.has_tension);
+}
+
+#[test]
+fn test_calc_mat_pressure_invalid_grid() {
+    let err = calc_mat_pressure(
+        &create_foundation(10.0, 20.0),
+        &create_mat_columns(),
+        &[vec![1000.0, 1000.0], vec![1000.0]],
+        5.0,
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "foundation_design.subgrade_moduli.invalid_grid");
+}
+
+fn create_schedule() -> ColumnSchedule {
+    ColumnSchedule::new(vec![
+        ScheduledColumn::new(
+            "S1".to_string(),
+            10.0,
+            20.0,
+            vec![
+                ColumnLoadCase::new(LoadCase::ServiceLoad, 100.0, 10.0, 0.0),
+                ColumnLoadCase::new(LoadCase::UltimateLoad, 150.0, 15.0, 0.0),
+            ],
+        ),
+        ScheduledColumn::new(
+            "S2".to_string(),
+            10.0,
+            26.0,
+            vec![ColumnLoadCase::new(LoadCase::ServiceLoad, 50.0, 0.0, 0.0)],
+        ),
+    ])
+}
+
+#[test]
+fn test_column_schedule_get_loads() {
+    let schedule = create_schedule();
+    let column = schedule.get_column("S1").unwrap();
+
+    let loads = column.get_loads(LoadCase::UltimateLoad).unwrap();
+    assert_abs_diff_eq!(loads.vertical_load.unwrap(), 150.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(loads.moment_x.unwrap(), 15.0, epsilon = 1e-9);
+
+    assert!(schedule.get_column("S3").is_none());
+    assert!(schedule
+        .get_column("S2")
+        .unwrap()
+        .get_loads(LoadCase::UltimateLoad)
+        .is_none());
+}
+
+#[test]
+fn test_calc_footing_pressures() {
+    let schedule = create_schedule();
+    let footings = [create_foundation(2.0, 2.5), create_foundation(2.0, 2.0)];
+
+    let results = calc_footing_pressures(&schedule, &footings, LoadCase::ServiceLoad).unwrap();
+
+    // S1: q = 100 / 5 · (1 ± 6 · 0.1 / 2), A' = 2 · (1 − 2 · 0.05) · 2.5
+    assert_eq!(results[0].column_id, "S1");
+    assert_abs_diff_eq!(results[0].eccentricity_x, 0.1, epsilon = 1e-9);
+    assert_abs_diff_eq!(results[0].average_pressure, 20.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(results[0].max_pressure, 26.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(results[0].min_pressure, 14.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(results[0].effective_pressure, 22.222, epsilon = 1e-3);
+    assert!(!results[0].has_tension);
+
+    assert_abs_diff_eq!(results[1].max_pressure, 12.5, epsilon = 1e-9);
+    assert_abs_diff_eq!(results[1].effective_pressure, 12.5, epsilon = 1e-9);
+}
+
+#[test]
+fn test_calc_footing_pressures_invalid_input() {
+    let schedule = create_schedule();
+
+    let err = calc_footing_pressures(
+        &schedule,
+        &[create_foundation(2.0, 2.0)],
+        LoadCase::ServiceLoad,
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "foundation_design.footings.count_mismatch");
+
+    let footings = [create_foundation(2.0, 2.0), create_foundation(2.0, 2.0)];
+    let err = calc_footing_pressures(&schedule, &footings, LoadCase::UltimateLoad).unwrap_err();
+    assert_eq!(err.code, "column_schedule.missing_load_case");
+}
+
+#[test]
+fn test_calc_schedule_mat_pressure() {
+    let schedule = create_schedule();
+    let foundation = create_foundation(4.0, 12.0);
+    let subgrade_moduli = vec![vec![1000.0, 1000.0]; 3];
+
+    let result = calc_schedule_mat_pressure(
+        &schedule,
+        LoadCase::ServiceLoad,
+        &foundation,
+        (8.0, 18.0),
+        &subgrade_moduli,
+        10.0,
+    )
+    .unwrap();
+    let expected = calc_mat_pressure(
+        &foundation,
+        &[
+            MatColumnLoad {
+                moment_x: Some(10.0),
+                ..MatColumnLoad::new(2.0, 2.0, 100.0)
+            },
+            MatColumnLoad::new(2.0, 8.0, 50.0),
+        ],
+        &subgrade_moduli,
+        10.0,
+    )
+    .unwrap();
+
+    assert_abs_diff_eq!(result.total_load, 150.0, epsilon = 1e-9);
+    // The 10 t·m moment of S1 shifts the resultant by 10 / 150 along x
+    assert_abs_diff_eq!(result.eccentricity_x, 10.0 / 150.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.eccentricity_y, -2.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.max_pressure, expected.max_pressure, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.min_pressure, expected.min_pressure, epsilon = 1e-9);
+}
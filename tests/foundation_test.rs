@@ -1,4 +1,7 @@
-use soilrust::models::foundation::Foundation;
+use soilrust::{
+    enums::{EccentricityCase, FoundationShape},
+    models::foundation::Foundation,
+};
 
 #[test]
 fn test_calc_effective_lengths() {
@@ -54,3 +57,200 @@ fn test_calc_effective_lengths_negative_effective_size() {
     assert_eq!(foundation.effective_width, Some(0.0));
     assert_eq!(foundation.effective_length, Some(2.0)); // The remaining length
 }
+
+#[test]
+fn test_apply_shape_circular() {
+    let mut foundation = Foundation {
+        shape: FoundationShape::Circular { diameter: 2.0 },
+        ..Default::default()
+    };
+
+    foundation.apply_shape().unwrap();
+
+    assert_eq!(foundation.foundation_width, Some(2.0));
+    assert_eq!(foundation.foundation_length, Some(2.0));
+    assert!((foundation.foundation_area.unwrap() - std::f64::consts::PI).abs() < 1e-9);
+    assert_eq!(foundation.calc_shape_ratio(2.0, 2.0), 1.0);
+}
+
+#[test]
+fn test_apply_shape_ring() {
+    let mut foundation = Foundation {
+        shape: FoundationShape::Ring {
+            outer_diameter: 4.0,
+            inner_diameter: 2.0,
+        },
+        ..Default::default()
+    };
+
+    foundation.apply_shape().unwrap();
+
+    // A = π · (4² − 2²) / 4 = 3π
+    assert_eq!(foundation.foundation_width, Some(4.0));
+    assert!((foundation.foundation_area.unwrap() - 3.0 * std::f64::consts::PI).abs() < 1e-9);
+
+    foundation.shape = FoundationShape::Ring {
+        outer_diameter: 2.0,
+        inner_diameter: 2.0,
+    };
+    let err = foundation.apply_shape().unwrap_err();
+    assert_eq!(err.code, "foundation.shape.inner_diameter.too_large.1.999");
+}
+
+#[test]
+fn test_apply_shape_strip() {
+    let mut foundation = Foundation {
+        foundation_width: Some(1.5),
+        shape: FoundationShape::Strip,
+        ..Default::default()
+    };
+
+    foundation.apply_shape().unwrap();
+
+    assert_eq!(foundation.foundation_length, Some(15.0));
+    assert_eq!(foundation.calc_shape_ratio(1.5, 15.0), 0.0);
+
+    foundation.foundation_width = None;
+    let err = foundation.apply_shape().unwrap_err();
+    assert_eq!(err.code, "foundation.foundation_width.missing");
+}
+
+#[test]
+fn test_invalid_circular_diameter() {
+    let foundation = Foundation {
+        shape: FoundationShape::Circular { diameter: 0.0 },
+        ..Default::default()
+    };
+
+    let err = foundation.validate(&["shape"]).unwrap_err();
+    assert_eq!(err.code, "foundation.shape.diameter.too_small.0.001");
+}
+
+#[test]
+fn test_calc_effective_area_one_way() {
+    let mut foundation = Foundation {
+        foundation_length: Some(10.0),
+        foundation_width: Some(5.0),
+        ..Default::default()
+    };
+
+    // e = 1.0 > B/6, outside the kern
+    let area = foundation.calc_effective_area(1.0, 0.0);
+
+    assert_eq!(area.case, EccentricityCase::OneWay);
+    assert!(area.outside_kern);
+    assert_eq!(area.effective_width, 3.0);
+    assert_eq!(area.effective_length, 10.0);
+    assert_eq!(area.effective_area, 30.0);
+    assert_eq!(foundation.effective_width, Some(3.0));
+}
+
+#[test]
+fn test_calc_effective_area_two_way() {
+    let mut foundation = Foundation {
+        foundation_length: Some(2.0),
+        foundation_width: Some(2.0),
+        ..Default::default()
+    };
+
+    // Case I: B1 = L1 = 2 · (1.5 − 3 · 0.2) = 1.8
+    let area = foundation.calc_effective_area(0.4, 0.4);
+    assert_eq!(area.case, EccentricityCase::Triangular);
+    assert!((area.effective_area - 1.62).abs() < 1e-9);
+    assert!((area.effective_length - 1.8).abs() < 1e-9);
+    assert!((area.effective_width - 0.9).abs() < 1e-9);
+
+    // Case II: eB/B = 0.05, eL/L = 0.3
+    let area = foundation.calc_effective_area(0.1, 0.6);
+    assert_eq!(area.case, EccentricityCase::TrapezoidalLength);
+    assert!((area.effective_area - 1.55340).abs() < 1e-5);
+
+    // Case III is the mirror of case II
+    let area = foundation.calc_effective_area(0.6, 0.1);
+    assert_eq!(area.case, EccentricityCase::TrapezoidalWidth);
+    assert!((area.effective_area - 1.55340).abs() < 1e-5);
+
+    // Case IV: within the kern in both directions
+    let area = foundation.calc_effective_area(0.2, 0.2);
+    assert_eq!(area.case, EccentricityCase::Pentagonal);
+    assert!(!area.outside_kern);
+    assert!((area.effective_area - 2.84693).abs() < 1e-5);
+    assert_eq!(area.effective_length, 2.0);
+}
+
+#[test]
+fn test_calc_effective_area_no_contact() {
+    let mut foundation = Foundation {
+        foundation_length: Some(6.0),
+        foundation_width: Some(3.0),
+        ..Default::default()
+    };
+
+    let area = foundation.calc_effective_area(1.5, 0.5);
+
+    assert_eq!(area.case, EccentricityCase::NoContact);
+    assert!(area.outside_kern);
+    assert_eq!(area.effective_area, 0.0);
+    assert_eq!(foundation.effective_width, Some(0.0));
+}
+
+#[test]
+fn test_calc_gross_and_net_pressure() {
+    use soilrust::{
+        enums::PressureConvention,
+        models::soil_profile::{SoilLayer, SoilProfile},
+    };
+
+    let mut soil_profile = SoilProfile::new(
+        vec![SoilLayer {
+            thickness: Some(10.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(2.0),
+            ..Default::default()
+        }],
+        1.0,
+    );
+    soil_profile.calc_layer_depths();
+    let foundation = Foundation {
+        foundation_depth: Some(2.0),
+        ..Default::default()
+    };
+
+    // σv(2 m) = 1.8 · 1 + 2.0 · 1 = 3.8 t/m²
+    let (gross, net) = (PressureConvention::Gross, PressureConvention::Net);
+    assert_eq!(
+        foundation.calc_gross_pressure(&soil_profile, 20.0, gross),
+        20.0
+    );
+    assert!((foundation.calc_net_pressure(&soil_profile, 20.0, gross) - 16.2).abs() < 1e-9);
+    assert!((foundation.calc_gross_pressure(&soil_profile, 16.2, net) - 20.0).abs() < 1e-9);
+    assert_eq!(foundation.calc_net_pressure(&soil_profile, 16.2, net), 16.2);
+
+    // The base is 1 m below the groundwater level, so the net pressure also equals the pressure
+    // less the uplift, less the effective overburden
+    let uplift = soil_profile.calc_normal_stress(2.0) - soil_profile.calc_effective_stress(2.0);
+    assert!((uplift - 0.981).abs() < 1e-9);
+    assert!(
+        (foundation.calc_net_pressure(&soil_profile, 20.0, gross)
+            - (20.0 - uplift - soil_profile.calc_effective_stress(2.0)))
+        .abs()
+            < 1e-9
+    );
+}
+
+#[test]
+fn test_calc_downslope_embedment() {
+    let mut foundation = Foundation {
+        foundation_depth: Some(2.0),
+        ..Default::default()
+    };
+    assert_eq!(foundation.calc_downslope_embedment(), 2.0);
+
+    foundation.downslope_embedment = Some(0.5);
+    assert_eq!(foundation.calc_downslope_embedment(), 0.5);
+    foundation.validate(&["downslope_embedment"]).unwrap();
+
+    foundation.downslope_embedment = Some(3.0);
+    let err = foundation.validate(&["downslope_embedment"]).unwrap_err();
+    assert_eq!(err.code, "foundation.downslope_embedment.too_large.2");
+}
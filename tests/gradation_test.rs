@@ -0,0 +1,103 @@
+use approx::assert_abs_diff_eq;
+use soilrust::models::{gradation::GradationCurve, soil_profile::SoilLayer};
+
+fn create_gradation() -> GradationCurve {
+    GradationCurve::new(
+        vec![0.075, 0.15, 0.3, 0.6, 1.18, 2.36, 4.75, 9.5, 19.0],
+        vec![0.0, 10.0, 20.0, 22.0, 23.0, 25.0, 40.0, 80.0, 100.0],
+    )
+}
+
+#[test]
+fn test_percent_finer() {
+    let gradation = create_gradation();
+
+    assert_abs_diff_eq!(gradation.percent_finer(0.3), 20.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(gradation.percent_finer(1.2), 23.048495, epsilon = 1e-5);
+    assert_abs_diff_eq!(gradation.percent_finer(0.01), 0.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(gradation.percent_finer(50.0), 100.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_diameter_at() {
+    let gradation = create_gradation();
+
+    assert_abs_diff_eq!(gradation.diameter_at(10.0).unwrap(), 0.15, epsilon = 1e-9);
+    assert_abs_diff_eq!(
+        gradation.diameter_at(15.0).unwrap(),
+        0.212132,
+        epsilon = 1e-6
+    );
+    assert!(gradation.diameter_at(101.0).is_none());
+}
+
+#[test]
+fn test_invalid_gradation() {
+    let gradation = GradationCurve::new(vec![0.075, 0.15], vec![10.0, 5.0]);
+    assert_eq!(
+        gradation.validate().unwrap_err().code,
+        "gradation.percent_passing.decreasing"
+    );
+
+    let gradation = GradationCurve::new(vec![0.15, 0.075], vec![5.0, 10.0]);
+    assert_eq!(
+        gradation.validate().unwrap_err().code,
+        "gradation.sizes.not_increasing"
+    );
+
+    let layer = SoilLayer::new(2.0);
+    assert_eq!(
+        layer.validate_fields(&["gradation"]).unwrap_err().code,
+        "soil_profile.gradation.missing"
+    );
+}
+
+fn create_well_graded() -> GradationCurve {
+    GradationCurve::new(
+        vec![0.01, 0.075, 0.15, 0.3, 0.6, 1.18, 2.36, 4.75, 9.5, 19.0],
+        vec![0.0, 3.0, 5.0, 9.0, 15.0, 24.0, 37.0, 55.0, 76.0, 100.0],
+    )
+}
+
+#[test]
+fn test_characteristic_diameters() {
+    let gradation = create_well_graded();
+
+    assert_abs_diff_eq!(gradation.d10().unwrap(), 0.336739, epsilon = 1e-6);
+    assert_abs_diff_eq!(gradation.d30().unwrap(), 1.624871, epsilon = 1e-6);
+    assert_abs_diff_eq!(gradation.d60().unwrap(), 5.602314, epsilon = 1e-6);
+    assert_abs_diff_eq!(
+        gradation.uniformity_coefficient().unwrap(),
+        16.636980,
+        epsilon = 1e-5
+    );
+    assert_abs_diff_eq!(
+        gradation.curvature_coefficient().unwrap(),
+        1.399515,
+        epsilon = 1e-5
+    );
+    assert_abs_diff_eq!(gradation.fines_content(), 3.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_is_well_graded() {
+    assert_eq!(create_well_graded().is_well_graded(), Some(true));
+
+    let uniform = GradationCurve::new(vec![0.1, 0.2, 0.4, 0.8], vec![0.0, 10.0, 60.0, 100.0]);
+    assert_eq!(uniform.is_well_graded(), Some(false));
+
+    let no_d10 = GradationCurve::new(vec![0.075, 4.75], vec![20.0, 100.0]);
+    assert_eq!(no_d10.is_well_graded(), None);
+}
+
+#[test]
+fn test_layer_fine_content_from_gradation() {
+    let mut layer = SoilLayer {
+        gradation: Some(create_well_graded()),
+        ..Default::default()
+    };
+    assert_abs_diff_eq!(layer.get_fine_content().unwrap(), 3.0, epsilon = 1e-9);
+
+    layer.fine_content = Some(8.0);
+    assert_abs_diff_eq!(layer.get_fine_content().unwrap(), 8.0, epsilon = 1e-9);
+}
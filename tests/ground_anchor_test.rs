@@ -0,0 +1,128 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    enums::{AnchorGroundType, GroutingMethod},
+    ground_anchor::{calc_anchor_design, get_bond_stress},
+    models::{
+        ground_anchor::GroundAnchor,
+        soil_profile::{SoilLayer, SoilProfile},
+    },
+};
+
+fn create_soil_profile() -> SoilProfile {
+    SoilProfile::new(
+        vec![
+            SoilLayer {
+                thickness: Some(4.0),
+                phi_prime: Some(28.0),
+                ..Default::default()
+            },
+            SoilLayer {
+                thickness: Some(10.0),
+                phi_prime: Some(32.0),
+                ..Default::default()
+            },
+        ],
+        20.0,
+    )
+}
+
+#[test]
+fn test_get_bond_stress() {
+    let gravity = get_bond_stress(AnchorGroundType::FineMediumSand, GroutingMethod::Gravity);
+    let low = get_bond_stress(
+        AnchorGroundType::FineMediumSand,
+        GroutingMethod::LowPressure,
+    );
+    let high = get_bond_stress(
+        AnchorGroundType::FineMediumSand,
+        GroutingMethod::HighPressure,
+    );
+
+    assert_abs_diff_eq!(gravity, 8.15773, epsilon = 1e-5);
+    assert_abs_diff_eq!(low, 23.45347, epsilon = 1e-5);
+    assert_abs_diff_eq!(high, 38.74922, epsilon = 1e-5);
+}
+
+#[test]
+fn test_calc_anchor_design() {
+    let anchor = GroundAnchor::new(
+        40.0,
+        0.15,
+        15.0,
+        2.0,
+        AnchorGroundType::FineMediumSand,
+        GroutingMethod::LowPressure,
+    );
+    let mut soil_profile = create_soil_profile();
+
+    let result = calc_anchor_design(&anchor, &mut soil_profile, 8.0, 2.0).unwrap();
+
+    assert_abs_diff_eq!(result.friction_angle, 30.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.required_fixed_length, 7.23838, epsilon = 1e-4);
+    assert_abs_diff_eq!(
+        result.fixed_length,
+        result.required_fixed_length,
+        epsilon = 1e-9
+    );
+    assert!(!result.exceeds_max_fixed_length);
+    assert_abs_diff_eq!(result.wedge_length, 3.10582, epsilon = 1e-4);
+    assert_abs_diff_eq!(result.free_length, 4.70582, epsilon = 1e-4);
+    assert_abs_diff_eq!(result.lock_off_load_min, 30.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.proof_test_load, 53.2, epsilon = 1e-9);
+}
+
+#[test]
+fn test_head_below_excavation() {
+    let anchor = GroundAnchor::new(
+        40.0,
+        0.15,
+        15.0,
+        9.0,
+        AnchorGroundType::FineMediumSand,
+        GroutingMethod::LowPressure,
+    );
+    let mut soil_profile = create_soil_profile();
+
+    let result = calc_anchor_design(&anchor, &mut soil_profile, 8.0, 2.0);
+
+    assert_eq!(
+        result.unwrap_err().code,
+        "ground_anchor.head_depth.greater_than_wall_height"
+    );
+}
+
+#[test]
+fn test_calc_deadman_capacity() {
+    use soilrust::{ground_anchor::calc_deadman_capacity, models::deadman::Deadman};
+
+    let mut soil_profile = SoilProfile::new(
+        vec![SoilLayer {
+            thickness: Some(15.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(2.0),
+            phi_prime: Some(30.0),
+            ..Default::default()
+        }],
+        20.0,
+    );
+    let deadman = Deadman::new(1.2, 1.8, 3.0, 12.0);
+
+    let result = calc_deadman_capacity(&deadman, &mut soil_profile, 8.0, 2.0).unwrap();
+
+    // σ'v = 1.8 · 1.8 = 3.24 t/m², Kp = 3, Ka = 1/3
+    assert_abs_diff_eq!(result.passive_force, 8.748, epsilon = 1e-3);
+    assert_abs_diff_eq!(result.active_force, 0.972, epsilon = 1e-3);
+    // Teng's end effect as given by Das: (1/3) · K0 · (√Kp + √Ka) · γ · H³ · tan φ
+    // = (1/3) · 0.5 · (√3 + √(1/3)) · 1.8 · 1.8³ · tan 30° = 2.3328
+    assert_abs_diff_eq!(result.end_resistance, 2.3328, epsilon = 1e-4);
+    assert_abs_diff_eq!(result.ultimate_capacity, 25.6608, epsilon = 1e-4);
+    assert_abs_diff_eq!(result.allowable_capacity, 12.8304, epsilon = 1e-4);
+    assert!(result.is_near_surface);
+    // 8 · tan 30° + 1.8 · tan 60° = 7.7365 m
+    assert_abs_diff_eq!(result.required_distance, 7.7365, epsilon = 1e-3);
+    assert!(result.is_beyond_failure_plane);
+
+    let close = Deadman::new(1.2, 1.8, 3.0, 5.0);
+    let result = calc_deadman_capacity(&close, &mut soil_profile, 8.0, 2.0).unwrap();
+    assert!(!result.is_beyond_failure_plane);
+}
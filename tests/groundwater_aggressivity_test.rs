@@ -0,0 +1,103 @@
+use soilrust::{
+    enums::ChemicalExposureClass,
+    groundwater_aggressivity::*,
+    models::{
+        groundwater::GroundwaterSample,
+        soil_profile::{SoilLayer, SoilProfile},
+    },
+};
+
+fn create_soil_profile(samples: Vec<GroundwaterSample>) -> SoilProfile {
+    let mut profile = SoilProfile::new(vec![SoilLayer::new(10.0)], 2.0);
+    profile.groundwater_samples = samples;
+    profile
+}
+
+#[test]
+fn test_classify_sulfate() {
+    assert_eq!(classify_sulfate(150.0), ChemicalExposureClass::None);
+    assert_eq!(classify_sulfate(200.0), ChemicalExposureClass::Xa1);
+    assert_eq!(classify_sulfate(600.0), ChemicalExposureClass::Xa1);
+    assert_eq!(classify_sulfate(601.0), ChemicalExposureClass::Xa2);
+    assert_eq!(classify_sulfate(3500.0), ChemicalExposureClass::Xa3);
+}
+
+#[test]
+fn test_classify_ph() {
+    assert_eq!(classify_ph(7.2), ChemicalExposureClass::None);
+    assert_eq!(classify_ph(6.5), ChemicalExposureClass::Xa1);
+    assert_eq!(classify_ph(5.0), ChemicalExposureClass::Xa2);
+    assert_eq!(classify_ph(4.2), ChemicalExposureClass::Xa3);
+}
+
+#[test]
+fn test_is_corrosive() {
+    assert!(!is_corrosive(&GroundwaterSample::new(
+        7.0, 7.0, 300.0, 100.0
+    )));
+    assert!(is_corrosive(&GroundwaterSample::new(
+        7.0, 7.0, 300.0, 500.0
+    )));
+    assert!(is_corrosive(&GroundwaterSample::new(
+        7.0, 7.0, 1500.0, 100.0
+    )));
+    assert!(is_corrosive(&GroundwaterSample::new(
+        7.0, 5.5, 300.0, 100.0
+    )));
+}
+
+#[test]
+fn test_calc_groundwater_aggressivity() {
+    let profile = create_soil_profile(vec![
+        GroundwaterSample {
+            date: Some("2024-05-17".into()),
+            total_dissolved_solids: Some(900.0),
+            ..GroundwaterSample::new(3.0, 6.2, 400.0, 120.0)
+        },
+        GroundwaterSample {
+            total_dissolved_solids: Some(1400.0),
+            ..GroundwaterSample::new(6.0, 7.1, 900.0, 650.0)
+        },
+    ]);
+
+    let result = calc_groundwater_aggressivity(&profile).unwrap();
+
+    assert_eq!(result.samples.len(), 2);
+    assert_eq!(result.samples[0].date.as_deref(), Some("2024-05-17"));
+    assert_eq!(result.samples[0].sulfate_class, ChemicalExposureClass::Xa1);
+    assert_eq!(result.samples[0].ph_class, ChemicalExposureClass::Xa1);
+    assert!(!result.samples[0].is_corrosive);
+    assert_eq!(result.samples[1].exposure_class, ChemicalExposureClass::Xa2);
+    assert!(result.samples[1].is_corrosive);
+
+    assert_eq!(result.exposure_class, ChemicalExposureClass::Xa2);
+    assert!(result.is_corrosive);
+    assert!(!result.requires_special_study);
+    assert_eq!(result.max_total_dissolved_solids, Some(1400.0));
+}
+
+#[test]
+fn test_special_study() {
+    let profile = create_soil_profile(vec![GroundwaterSample::new(3.0, 3.8, 200.0, 10.0)]);
+
+    let result = calc_groundwater_aggressivity(&profile).unwrap();
+
+    assert_eq!(result.exposure_class, ChemicalExposureClass::Xa3);
+    assert!(result.requires_special_study);
+    assert!(result.max_total_dissolved_solids.is_none());
+}
+
+#[test]
+fn test_validation_errors() {
+    let profile = create_soil_profile(vec![]);
+    assert_eq!(
+        calc_groundwater_aggressivity(&profile).unwrap_err().code,
+        "groundwater.empty_samples"
+    );
+
+    let profile = create_soil_profile(vec![GroundwaterSample::new(3.0, 15.0, 200.0, 10.0)]);
+    assert_eq!(
+        calc_groundwater_aggressivity(&profile).unwrap_err().code,
+        "groundwater.ph.too_large.14"
+    );
+}
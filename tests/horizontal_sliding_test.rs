@@ -1,6 +1,7 @@
 use approx::assert_abs_diff_eq;
 use soilrust::{
-    horizontal_sliding::calc_horizontal_sliding,
+    enums::{AnalysisTerm, InterfaceType, LoadingRate},
+    horizontal_sliding::{calc_horizontal_sliding, calc_horizontal_sliding_by_term},
     models::{
         foundation::Foundation,
         loads::Loads,
@@ -46,6 +47,7 @@ fn create_soil_profile() -> SoilProfile {
                 ..Default::default()
             },
         ],
+        ..Default::default()
     }
 }
 fn create_foundation_data() -> Foundation {
@@ -88,3 +90,116 @@ fn test_horizontal_sliding() {
     assert_abs_diff_eq!(result.sum_x, 5470.88, epsilon = 1e-2);
     assert_abs_diff_eq!(result.sum_y, 5487.21, epsilon = 1e-2);
 }
+
+#[test]
+fn test_horizontal_sliding_by_term() {
+    let mut soil_profile = create_soil_profile();
+    soil_profile.layers[0].plasticity_index = Some(25.);
+    let foundation_data = create_foundation_data();
+    let load_data = create_load_data();
+
+    let result = calc_horizontal_sliding_by_term(
+        &soil_profile,
+        &foundation_data,
+        &load_data,
+        50.,
+        AnalysisTerm::Auto(LoadingRate::Rapid),
+    )
+    .unwrap();
+    assert_abs_diff_eq!(result.rth, 545.45, epsilon = 1e-2);
+    assert_abs_diff_eq!(result.rpk_x, 36.0, epsilon = 1e-9);
+
+    let result = calc_horizontal_sliding_by_term(
+        &soil_profile,
+        &foundation_data,
+        &load_data,
+        50.,
+        AnalysisTerm::Auto(LoadingRate::Slow),
+    )
+    .unwrap();
+    assert_abs_diff_eq!(result.rth, 5454.55, epsilon = 1e-2);
+    assert_abs_diff_eq!(result.rpk_x, 76.21, epsilon = 1e-2);
+}
+
+#[test]
+fn test_horizontal_sliding_with_surface_surcharge() {
+    let soil_profile = create_soil_profile();
+    let foundation_data = create_foundation_data();
+    let load_data = Loads {
+        surface_surcharge: Some(1.0),
+        ..create_load_data()
+    };
+
+    let result = calc_horizontal_sliding(&soil_profile, &foundation_data, &load_data, 50.).unwrap();
+    // (0.5 · γ · Df² + q · Df) · Kp · B
+    assert_abs_diff_eq!(result.rpk_x, 118.55, epsilon = 1e-2);
+    assert_abs_diff_eq!(result.rpk_y, 237.10, epsilon = 1e-2);
+}
+
+#[test]
+fn test_horizontal_sliding_downslope_embedment() {
+    let soil_profile = create_soil_profile();
+    let load_data = create_load_data();
+    let full =
+        calc_horizontal_sliding(&soil_profile, &create_foundation_data(), &load_data, 50.).unwrap();
+
+    let foundation_data = Foundation {
+        downslope_embedment: Some(1.0),
+        ..create_foundation_data()
+    };
+    let result = calc_horizontal_sliding(&soil_profile, &foundation_data, &load_data, 50.).unwrap();
+    // Passive resistance of half the embedment is a quarter of the full one
+    assert_abs_diff_eq!(result.rpk_x, full.rpk_x / 4.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.rpk_y, full.rpk_y / 4.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.rth, full.rth, epsilon = 1e-9);
+
+    let foundation_data = Foundation {
+        downslope_embedment: Some(-1.0),
+        ..create_foundation_data()
+    };
+    let err =
+        calc_horizontal_sliding(&soil_profile, &foundation_data, &load_data, 50.).unwrap_err();
+    assert_eq!(err.code, "foundation.downslope_embedment.too_small.0");
+}
+
+#[test]
+fn test_horizontal_sliding_interface_type() {
+    let soil_profile = create_soil_profile();
+    let load_data = create_load_data();
+    let mut foundation_data = Foundation {
+        surface_friction_coefficient: None,
+        interface_type: Some(InterfaceType::PrecastConcreteOnSand),
+        ..create_foundation_data()
+    };
+
+    // δ = 2/3 · 21° = 14°
+    let result = calc_horizontal_sliding(&soil_profile, &foundation_data, &load_data, 50.).unwrap();
+    assert_abs_diff_eq!(result.friction_coefficient, 0.249328, epsilon = 1e-6);
+    assert_abs_diff_eq!(result.rth, 2266.62, epsilon = 1e-2);
+
+    // The manual coefficient overrides the interface type
+    foundation_data.surface_friction_coefficient = Some(0.6);
+    let result = calc_horizontal_sliding(&soil_profile, &foundation_data, &load_data, 50.).unwrap();
+    assert_abs_diff_eq!(result.rth, 5454.55, epsilon = 1e-2);
+
+    // Undrained adhesion is reduced by ca/c
+    let mut clay_profile = create_soil_profile();
+    clay_profile.layers[0].plasticity_index = Some(25.);
+    foundation_data.interface_type = Some(InterfaceType::LeanConcrete);
+    let result = calc_horizontal_sliding_by_term(
+        &clay_profile,
+        &foundation_data,
+        &load_data,
+        50.,
+        AnalysisTerm::Auto(LoadingRate::Rapid),
+    )
+    .unwrap();
+    assert_abs_diff_eq!(result.adhesion_factor, 0.8, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.rth, 436.36, epsilon = 1e-2);
+
+    foundation_data.interface_type = None;
+    foundation_data.surface_friction_coefficient = None;
+    let err =
+        calc_horizontal_sliding(&soil_profile, &foundation_data, &load_data, 50.).unwrap_err();
+    assert_eq!(err.code, "foundation.surface_friction_coefficient.missing");
+}
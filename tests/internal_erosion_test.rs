@@ -0,0 +1,75 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    internal_erosion::{calc_internal_stability, calc_profile_internal_stability},
+    models::{
+        gradation::GradationCurve,
+        soil_profile::{SoilLayer, SoilProfile},
+    },
+};
+
+fn create_gap_graded() -> GradationCurve {
+    GradationCurve::new(
+        vec![0.075, 0.15, 0.3, 0.6, 1.18, 2.36, 4.75, 9.5, 19.0],
+        vec![0.0, 10.0, 20.0, 22.0, 23.0, 25.0, 40.0, 80.0, 100.0],
+    )
+}
+
+fn create_well_graded() -> GradationCurve {
+    GradationCurve::new(
+        vec![0.01, 0.075, 0.15, 0.3, 0.6, 1.18, 2.36, 4.75, 9.5, 19.0],
+        vec![0.0, 3.0, 5.0, 9.0, 15.0, 24.0, 37.0, 55.0, 76.0, 100.0],
+    )
+}
+
+#[test]
+fn test_gap_graded_is_unstable() {
+    let result = calc_internal_stability(&create_gap_graded()).unwrap();
+
+    assert_abs_diff_eq!(
+        result.uniformity_coefficient.unwrap(),
+        44.783429,
+        epsilon = 1e-5
+    );
+    assert_abs_diff_eq!(result.f_limit, 20.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.min_ratio, 0.164510, epsilon = 1e-5);
+    assert!(!result.is_stable);
+}
+
+#[test]
+fn test_well_graded_is_stable() {
+    let result = calc_internal_stability(&create_well_graded()).unwrap();
+
+    assert_abs_diff_eq!(result.min_ratio, 1.364393, epsilon = 1e-5);
+    assert!(result.is_stable);
+    assert!(result.points.iter().all(|p| p.f <= 20.0));
+}
+
+#[test]
+fn test_insufficient_range() {
+    let gradation = GradationCurve::new(vec![2.0, 4.75, 9.5], vec![50.0, 80.0, 100.0]);
+
+    let err = calc_internal_stability(&gradation).unwrap_err();
+    assert_eq!(err.code, "internal_erosion.gradation.insufficient_range");
+}
+
+#[test]
+fn test_profile_internal_stability() {
+    let profile = SoilProfile::new(
+        vec![
+            SoilLayer {
+                thickness: Some(2.0),
+                ..Default::default()
+            },
+            SoilLayer {
+                thickness: Some(5.0),
+                gradation: Some(create_gap_graded()),
+                ..Default::default()
+            },
+        ],
+        10.0,
+    );
+
+    let results = calc_profile_internal_stability(&profile).unwrap();
+    assert!(results[0].is_none());
+    assert!(!results[1].as_ref().unwrap().is_stable);
+}
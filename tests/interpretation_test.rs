@@ -0,0 +1,192 @@
+use soilrust::{
+    bearing_capacity::vesic,
+    consolidation_settlement::model::SettlementResult,
+    enums::{AnalysisTerm, MethodId, PressureConvention, SoilClassBasis},
+    interpretation::{
+        interpret_foundation, interpret_liquefaction, interpret_lithology,
+        interpret_soil_class_by_vs, Finding,
+    },
+    liquefaction::models::CommonLiquefactionLayerResult,
+    local_soil_class::by_vs::VsSoilClassificationResult,
+    models::{
+        foundation::Foundation,
+        lithology::Lithology,
+        loads::Loads,
+        soil_profile::{SoilLayer, SoilProfile},
+    },
+};
+
+fn create_settlement(total_settlement: f64) -> SettlementResult {
+    SettlementResult {
+        settlement_per_layer: vec![total_settlement],
+        total_settlement,
+        qnet: 10.0,
+        method: MethodId::Boussinesq,
+        warnings: vec![],
+        fully_compensated: false,
+        pressure_convention: PressureConvention::Gross,
+    }
+}
+
+fn create_liquefaction_layer(depth: f64, safety_factor: f64) -> CommonLiquefactionLayerResult {
+    CommonLiquefactionLayerResult {
+        depth,
+        safety_factor: Some(safety_factor),
+        is_safe: safety_factor >= 1.1,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_interpret_foundation() {
+    let mut profile = SoilProfile::new(
+        vec![SoilLayer {
+            thickness: Some(10.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(1.9),
+            c_prime: Some(0.0),
+            phi_prime: Some(30.0),
+            ..Default::default()
+        }],
+        10.0,
+    );
+    let mut foundation = Foundation {
+        foundation_width: Some(2.0),
+        foundation_length: Some(2.0),
+        foundation_depth: Some(1.0),
+        ..Default::default()
+    };
+    let loads = Loads {
+        vertical_load: Some(80.0),
+        ..Default::default()
+    };
+    let bearing_capacity = vesic::calc_bearing_capacity(
+        &mut profile,
+        &mut foundation,
+        &loads,
+        20.0,
+        PressureConvention::Gross,
+        3.0,
+        AnalysisTerm::Long,
+    )
+    .unwrap();
+
+    // 20 t/m² gives 5 cm, so 2.5 cm is reached at 10 t/m²
+    let findings = interpret_foundation(&bearing_capacity, &create_settlement(5.0), 2.5);
+    assert_eq!(findings[0].code(), "settlement_exceeded");
+    assert_eq!(
+        findings[1],
+        Finding::SettlementGoverns {
+            settlement_pressure: 10.0,
+            allowable_bearing_capacity: bearing_capacity.allowable_bearing_capacity,
+        }
+    );
+    assert!(findings[1]
+        .message()
+        .starts_with("Settlement governs over bearing"));
+
+    let findings = interpret_foundation(&bearing_capacity, &create_settlement(0.1), 2.5);
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].code(), "bearing_governs");
+}
+
+#[test]
+fn test_interpret_liquefaction() {
+    let layers = vec![
+        create_liquefaction_layer(1.5, 2.0),
+        create_liquefaction_layer(3.0, 0.8),
+        create_liquefaction_layer(4.5, 0.6),
+        create_liquefaction_layer(6.0, 0.9),
+        create_liquefaction_layer(7.5, 1.5),
+        create_liquefaction_layer(9.0, 1.0),
+    ];
+
+    let findings = interpret_liquefaction(&layers);
+
+    assert_eq!(findings.len(), 2);
+    assert_eq!(
+        findings[0],
+        Finding::LiquefiableZone {
+            top: 3.0,
+            bottom: 6.0,
+            min_safety_factor: 0.6,
+        }
+    );
+    assert_eq!(
+        findings[0].message(),
+        "Liquefiable layer between 3.0–6.0 m (minimum safety factor 0.60)."
+    );
+    assert_eq!(findings[1].code(), "liquefiable_zone");
+
+    let findings = interpret_liquefaction(&[create_liquefaction_layer(3.0, 2.0)]);
+    assert_eq!(findings, vec![Finding::NoLiquefaction]);
+}
+
+#[test]
+fn test_interpret_soil_class() {
+    let result = VsSoilClassificationResult {
+        layers: vec![],
+        sum_h_over_vs: 30.0 / 310.0,
+        vs_30: 310.0,
+        soil_class: "ZD".to_string(),
+        method: MethodId::LocalSoilClassByVs,
+    };
+
+    let finding = interpret_soil_class_by_vs(&result);
+
+    assert_eq!(
+        finding,
+        Finding::SoilClass {
+            soil_class: "ZD".to_string(),
+            basis: SoilClassBasis::Vs30,
+            value: 310.0,
+        }
+    );
+    assert_eq!(finding.message(), "Soil class ZD due to Vs30=310 m/s.");
+}
+
+#[test]
+fn test_interpret_lithology() {
+    let lithology = Lithology {
+        description: Some("silty CLAY".to_string()),
+        uscs_symbol: Some("CL".to_string()),
+        color: Some("brown".to_string()),
+        moisture: Some("moist".to_string()),
+        consistency: Some("stiff".to_string()),
+        photos: vec!["BH-1/box-1.jpg".to_string()],
+    };
+    assert_eq!(
+        Lithology {
+            uscs_symbol: Some("SM".to_string()),
+            ..Default::default()
+        }
+        .summary(),
+        Some("(SM)".to_string())
+    );
+    assert_eq!(Lithology::default().summary(), None);
+
+    let profile = SoilProfile::new(
+        vec![
+            SoilLayer {
+                thickness: Some(2.0),
+                ..Default::default()
+            },
+            SoilLayer {
+                thickness: Some(3.0),
+                lithology: Some(lithology.clone()),
+                ..Default::default()
+            },
+        ],
+        10.0,
+    );
+
+    let findings = interpret_lithology(&profile);
+
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].code(), "lithology");
+    assert_eq!(
+        findings[0].message(),
+        "2.0–5.0 m: brown, moist, stiff silty CLAY (CL)."
+    );
+    assert_eq!(profile.layers[1].lithology, Some(lithology));
+}
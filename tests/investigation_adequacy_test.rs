@@ -0,0 +1,105 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    investigation_adequacy::*,
+    models::{
+        borehole::Borehole,
+        foundation::Foundation,
+        soil_profile::{SoilLayer, SoilProfile},
+    },
+};
+
+fn create_soil_profile() -> SoilProfile {
+    SoilProfile::new(
+        vec![SoilLayer {
+            thickness: Some(50.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(2.0),
+            ..Default::default()
+        }],
+        5.0,
+    )
+}
+
+fn create_foundation() -> Foundation {
+    Foundation {
+        foundation_depth: Some(2.0),
+        foundation_width: Some(20.0),
+        foundation_length: Some(20.0),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_adequate_investigation() {
+    let boreholes = vec![
+        Borehole::new("BH-1", -10.0, -10.0, 40.0),
+        Borehole::new("BH-2", 10.0, -10.0, 40.0),
+        Borehole::new("BH-3", -10.0, 10.0, 40.0),
+        Borehole::new("BH-4", 10.0, 10.0, 40.0),
+    ];
+
+    let result = calc_investigation_adequacy(
+        &create_soil_profile(),
+        &create_foundation(),
+        20.0,
+        &boreholes,
+    )
+    .unwrap();
+
+    assert!(result.is_adequate);
+    assert_eq!(result.borehole_count, 4);
+    assert_eq!(result.required_count, 3);
+    assert_abs_diff_eq!(result.spacing.unwrap(), 20.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.score, 100.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_insufficient_investigation() {
+    let boreholes = vec![
+        Borehole::new("BH-1", 0.0, 0.0, 10.0),
+        Borehole::new("BH-2", 5.0, 5.0, 40.0),
+        // Too far from the footprint to be counted
+        Borehole::new("BH-3", 100.0, 0.0, 40.0),
+    ];
+
+    let result = calc_investigation_adequacy(
+        &create_soil_profile(),
+        &create_foundation(),
+        20.0,
+        &boreholes,
+    )
+    .unwrap();
+
+    assert!(!result.is_adequate);
+    assert_eq!(result.borehole_count, 2);
+    assert!(result
+        .flags
+        .contains(&InvestigationFlag::InsufficientCount {
+            count: 2,
+            required_count: 3
+        }));
+    assert!(matches!(
+        &result.flags[0],
+        InvestigationFlag::InsufficientDepth { borehole_id, .. } if borehole_id == "BH-1"
+    ));
+    assert_abs_diff_eq!(result.score, 78.298, epsilon = 1e-3);
+}
+
+#[test]
+fn test_invalid_borehole() {
+    let boreholes = vec![Borehole {
+        borehole_id: "BH-1".to_string(),
+        x: Some(0.0),
+        y: Some(0.0),
+        depth: None,
+    }];
+
+    let err = calc_investigation_adequacy(
+        &create_soil_profile(),
+        &create_foundation(),
+        20.0,
+        &boreholes,
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "borehole.depth.missing");
+}
@@ -0,0 +1,44 @@
+use approx::assert_abs_diff_eq;
+use soilrust::liquefaction::{
+    helper_functions::{calc_csr, calc_rd},
+    models::{CyclicStressDemand, ShearStressProfile},
+};
+
+#[test]
+fn test_simplified_demand() {
+    let demand = CyclicStressDemand::Simplified;
+    assert_abs_diff_eq!(demand.calc_rd(5.0, 0.3, 9.0), calc_rd(5.0), epsilon = 1e-9);
+}
+
+#[test]
+fn test_site_response_demand() {
+    let profile = ShearStressProfile::new(vec![0.0, 10.0], vec![0.0, 4.0]);
+    let demand = CyclicStressDemand::SiteResponse(profile);
+    demand.validate().unwrap();
+
+    // τmax(5 m) = 2.0 t/m², σv = 9.0 t/m², PGA = 0.3 g
+    let rd = demand.calc_rd(5.0, 0.3, 9.0);
+    assert_abs_diff_eq!(rd, 2.0 / 2.7, epsilon = 1e-9);
+    assert_abs_diff_eq!(calc_csr(0.3, 9.0, rd), 0.65 * 2.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_invalid_shear_stress_profile() {
+    let mismatch = CyclicStressDemand::SiteResponse(ShearStressProfile::new(vec![0.0], vec![]));
+    assert_eq!(
+        mismatch.validate().unwrap_err().code,
+        "shear_stress_profile.length_mismatch"
+    );
+
+    let unsorted = ShearStressProfile::new(vec![5.0, 1.0], vec![1.0, 2.0]);
+    assert_eq!(
+        unsorted.validate().unwrap_err().code,
+        "shear_stress_profile.depths.not_sorted"
+    );
+
+    let negative = ShearStressProfile::new(vec![1.0, 2.0], vec![1.0, -2.0]);
+    assert_eq!(
+        negative.validate().unwrap_err().code,
+        "shear_stress_profile.max_shear_stress.too_small.0"
+    );
+}
@@ -0,0 +1,106 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    liquefaction::{
+        helper_functions::calc_msf,
+        mitigation::*,
+        models::CyclicStressDemand,
+        spt::seed_idriss,
+        vs::andrus_stokoe::{self, calc_vs1c},
+    },
+    models::soil_profile::{SoilLayer, SoilProfile},
+};
+
+fn create_profile() -> SoilProfile {
+    let sand = SoilLayer {
+        dry_unit_weight: Some(1.8),
+        saturated_unit_weight: Some(2.0),
+        plasticity_index: Some(0.0),
+        fine_content: Some(5.0),
+        ..SoilLayer::new(10.0)
+    };
+    let clay = SoilLayer {
+        plasticity_index: Some(25.0),
+        fine_content: Some(80.0),
+        ..sand.clone()
+    };
+    SoilProfile::new(vec![sand, clay], 2.0)
+}
+
+#[test]
+fn test_calc_cpt_crr75() {
+    assert_abs_diff_eq!(calc_cpt_crr75(0.0), 0.05, epsilon = 1e-9);
+    assert_abs_diff_eq!(calc_cpt_crr75(100.0), 0.173, epsilon = 1e-9);
+}
+
+#[test]
+fn test_calc_required_values() {
+    let qc1ncs = calc_required_qc1ncs(0.2).unwrap();
+    assert_abs_diff_eq!(calc_cpt_crr75(qc1ncs), 0.2, epsilon = 1e-9);
+    let qc1ncs = calc_required_qc1ncs(0.07).unwrap();
+    assert_abs_diff_eq!(calc_cpt_crr75(qc1ncs), 0.07, epsilon = 1e-9);
+    assert!(calc_required_qc1ncs(0.6).is_none());
+
+    let n = calc_required_n1_60cs(0.2).unwrap();
+    assert!(seed_idriss::calc_crr75(n, 1.0) >= 0.2);
+    assert!(seed_idriss::calc_crr75(n - 1, 1.0) < 0.2);
+    assert!(calc_required_n1_60cs(2.0).is_none());
+
+    let vs1 = calc_required_vs1(0.2, 5.0);
+    let crr75 = andrus_stokoe::calc_crr75(vs1, calc_vs1c(5.0), 1.0);
+    assert_abs_diff_eq!(crr75, 0.2, epsilon = 1e-9);
+}
+
+#[test]
+fn test_calc_mitigation_targets() {
+    let profile = create_profile();
+    let demand = CyclicStressDemand::Simplified;
+
+    let result =
+        calc_mitigation_targets(&profile, &[1.0, 6.0, 15.0], 0.3, 7.5, 1.2, &demand).unwrap();
+
+    assert_eq!(result.targets.len(), 3);
+    assert_abs_diff_eq!(result.msf, calc_msf(7.5), epsilon = 1e-9);
+
+    // Above the groundwater level and in the clay layer
+    assert!(!result.targets[0].is_susceptible);
+    assert!(result.targets[0].required_crr75.is_none());
+    assert!(!result.targets[2].is_susceptible);
+
+    let target = &result.targets[1];
+    assert!(target.is_susceptible);
+    // σv = 2 · 1.8 + 4 · 2.0, σ'v = σv − 4 · 0.981, rd = 1 − 0.00765 · 6
+    let normal_stress = 11.6;
+    let effective_stress = normal_stress - 4.0 * 0.981;
+    let csr = 0.65 * 0.3 * normal_stress / effective_stress * (1.0 - 0.00765 * 6.0);
+    assert_abs_diff_eq!(target.csr.unwrap(), csr, epsilon = 1e-6);
+    let crr75 = 1.2 * csr / result.msf;
+    assert_abs_diff_eq!(target.required_crr75.unwrap(), crr75, epsilon = 1e-6);
+
+    // Reaching the targets gives at least the target factor of safety
+    let n = target.required_n1_60cs.unwrap();
+    assert!(seed_idriss::calc_crr75(n, 1.0) * result.msf / csr >= 1.2);
+    let qc1ncs = target.required_qc1ncs.unwrap();
+    assert_abs_diff_eq!(
+        calc_cpt_crr75(qc1ncs) * result.msf / csr,
+        1.2,
+        epsilon = 1e-6
+    );
+    let vs1 = target.required_vs1.unwrap();
+    let crr75 = andrus_stokoe::calc_crr75(vs1, calc_vs1c(5.0), 1.0);
+    assert_abs_diff_eq!(crr75 * result.msf / csr, 1.2, epsilon = 1e-6);
+}
+
+#[test]
+fn test_invalid_input() {
+    let profile = create_profile();
+    let demand = CyclicStressDemand::Simplified;
+
+    let err = calc_mitigation_targets(&profile, &[], 0.3, 7.5, 1.2, &demand).unwrap_err();
+    assert_eq!(err.code, "liquefaction_mitigation.empty_depths");
+
+    let err = calc_mitigation_targets(&profile, &[5.0], 0.3, 7.5, 0.9, &demand).unwrap_err();
+    assert_eq!(
+        err.code,
+        "liquefaction_mitigation.target_factor_of_safety.too_small.1"
+    );
+}
@@ -0,0 +1,195 @@
+use soilrust::{
+    enums::{DepositType, GeologicAge, LiquefactionSusceptibility, SelectionMethod},
+    liquefaction::{models::CyclicStressDemand, screening::*, spt::seed_idriss, vs::andrus_stokoe},
+    models::{
+        masw::{Masw, MaswExp, MaswLayer},
+        soil_profile::{SoilLayer, SoilProfile},
+        spt::{NValue, SPTExp, SPT},
+    },
+};
+
+fn create_soil_profile(ground_water_level: f64) -> SoilProfile {
+    SoilProfile::new(
+        vec![SoilLayer {
+            thickness: Some(10.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(1.9),
+            plasticity_index: Some(0.0),
+            fine_content: Some(5.0),
+            ..Default::default()
+        }],
+        ground_water_level,
+    )
+}
+
+fn create_spt() -> SPT {
+    let mut exp = SPTExp::new(vec![], "exp1".to_string());
+    exp.add_blow(1.5, NValue::Value(10));
+    exp.add_blow(3.0, NValue::Value(12));
+    exp.add_blow(4.5, NValue::Value(15));
+
+    let mut spt = SPT::new(1.0, 1.0, 1.0, SelectionMethod::Min);
+    spt.add_exp(exp);
+    spt
+}
+
+fn create_masw() -> Masw {
+    let exp = MaswExp::new(vec![MaswLayer::new(10.0, 150.0, 300.0)], "exp1".to_string());
+    Masw::new(vec![exp], SelectionMethod::Min)
+}
+
+#[test]
+fn test_calc_geologic_susceptibility() {
+    use LiquefactionSusceptibility::*;
+
+    let river = DepositType::RiverChannel;
+    assert_eq!(
+        calc_geologic_susceptibility(river, GeologicAge::LatestHolocene),
+        VeryHigh
+    );
+    assert_eq!(
+        calc_geologic_susceptibility(river, GeologicAge::Holocene),
+        High
+    );
+    assert_eq!(
+        calc_geologic_susceptibility(river, GeologicAge::Pleistocene),
+        Low
+    );
+    assert_eq!(
+        calc_geologic_susceptibility(river, GeologicAge::PrePleistocene),
+        VeryLow
+    );
+    assert_eq!(
+        calc_geologic_susceptibility(DepositType::Loess, GeologicAge::PrePleistocene),
+        High
+    );
+    assert_eq!(
+        calc_geologic_susceptibility(DepositType::CompactedFill, GeologicAge::LatestHolocene),
+        Low
+    );
+}
+
+#[test]
+fn test_reduce_for_groundwater() {
+    use LiquefactionSusceptibility::*;
+
+    assert_eq!(reduce_for_groundwater(VeryHigh, 2.0), VeryHigh);
+    assert_eq!(reduce_for_groundwater(VeryHigh, 5.0), High);
+    assert_eq!(reduce_for_groundwater(VeryHigh, 12.0), Moderate);
+    assert_eq!(reduce_for_groundwater(VeryHigh, 20.0), VeryLow);
+    assert_eq!(reduce_for_groundwater(Low, 12.0), VeryLow);
+}
+
+#[test]
+fn test_screen_susceptibility() {
+    let screening =
+        screen_susceptibility(DepositType::FloodPlain, GeologicAge::Holocene, 2.0).unwrap();
+    assert_eq!(
+        screening.geologic_susceptibility,
+        LiquefactionSusceptibility::Moderate
+    );
+    assert!(screening.requires_analysis);
+
+    let screening =
+        screen_susceptibility(DepositType::FloodPlain, GeologicAge::Holocene, 5.0).unwrap();
+    assert_eq!(screening.susceptibility, LiquefactionSusceptibility::Low);
+    assert!(!screening.requires_analysis);
+
+    let err =
+        screen_susceptibility(DepositType::FloodPlain, GeologicAge::Holocene, -1.0).unwrap_err();
+    assert_eq!(
+        err.code,
+        "liquefaction_screening.groundwater_depth.too_small.0"
+    );
+}
+
+#[test]
+fn test_spt_screened_analysis() {
+    let demand = CyclicStressDemand::Simplified;
+    let profile = create_soil_profile(1.0);
+
+    // A young river channel deposit is analyzed as usual
+    let result = calc_spt_liquefaction_screened(
+        &profile,
+        &mut create_spt(),
+        0.4,
+        7.5,
+        &demand,
+        DepositType::RiverChannel,
+        GeologicAge::Holocene,
+    )
+    .unwrap();
+    let expected = seed_idriss::calc_liquefacion(&profile, &mut create_spt(), 0.4, 7.5).unwrap();
+    assert!(result.screening.unwrap().requires_analysis);
+    assert_eq!(result.total_settlement, expected.total_settlement);
+    assert!(result
+        .layers
+        .iter()
+        .any(|layer| layer.safety_factor.is_some()));
+
+    // A Pleistocene glacial till is screened out
+    let result = calc_spt_liquefaction_screened(
+        &profile,
+        &mut create_spt(),
+        0.4,
+        7.5,
+        &demand,
+        DepositType::GlacialTill,
+        GeologicAge::Pleistocene,
+    )
+    .unwrap();
+    let screening = result.screening.unwrap();
+    assert!(!screening.requires_analysis);
+    assert_eq!(
+        screening.susceptibility,
+        LiquefactionSusceptibility::VeryLow
+    );
+    assert_eq!(result.layers.len(), expected.layers.len());
+    assert_eq!(result.total_settlement, 0.0);
+    assert!(result
+        .layers
+        .iter()
+        .all(|layer| layer.is_safe && layer.safety_factor.is_none()));
+}
+
+#[test]
+fn test_vs_screened_analysis() {
+    let demand = CyclicStressDemand::Simplified;
+
+    let mut profile = create_soil_profile(1.0);
+    let result = calc_vs_liquefaction_screened(
+        &mut profile,
+        &mut create_masw(),
+        0.4,
+        7.5,
+        &demand,
+        DepositType::UncompactedFill,
+        GeologicAge::LatestHolocene,
+    )
+    .unwrap();
+    let expected = andrus_stokoe::calc_liquefacion(
+        &mut create_soil_profile(1.0),
+        &mut create_masw(),
+        0.4,
+        7.5,
+    )
+    .unwrap();
+    assert!(result.screening.unwrap().requires_analysis);
+    assert_eq!(result.total_settlement, expected.total_settlement);
+
+    // The deep groundwater table screens out the dune deposit
+    let mut profile = create_soil_profile(8.0);
+    let result = calc_vs_liquefaction_screened(
+        &mut profile,
+        &mut create_masw(),
+        0.4,
+        7.5,
+        &demand,
+        DepositType::Dunes,
+        GeologicAge::Holocene,
+    )
+    .unwrap();
+    assert!(!result.screening.unwrap().requires_analysis);
+    assert!(result.vs_layers.is_empty());
+    assert_eq!(result.total_settlement, 0.0);
+}
@@ -0,0 +1,80 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    enums::SelectionMethod,
+    liquefaction::spt::tokimatsu_seed::{
+        calc_dry_sand_settlement, calc_seismic_settlement, calc_volumetric_strain,
+    },
+    models::{
+        soil_profile::{SoilLayer, SoilProfile},
+        spt::{NValue, SPTExp, SPT},
+    },
+};
+
+fn create_soil_profile() -> SoilProfile {
+    SoilProfile::new(
+        vec![SoilLayer {
+            thickness: Some(10.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(1.9),
+            plasticity_index: Some(0.0),
+            fine_content: Some(5.0),
+            ..Default::default()
+        }],
+        3.0,
+    )
+}
+
+fn create_spt() -> SPT {
+    let mut exp = SPTExp::new(vec![], "exp1".to_string());
+    exp.add_blow(1.5, NValue::Value(10));
+    exp.add_blow(3.0, NValue::Value(12));
+    exp.add_blow(4.5, NValue::Value(15));
+
+    let mut spt = SPT::new(1.0, 1.0, 1.0, SelectionMethod::Min);
+    spt.add_exp(exp);
+    spt
+}
+
+#[test]
+fn test_volumetric_strain_magnitude_scaling() {
+    let strain = calc_volumetric_strain(0.001, 20.0, 7.5);
+    assert_abs_diff_eq!(strain, 0.00100471, epsilon = 1e-8);
+}
+
+#[test]
+fn test_dry_sand_settlement() {
+    let result =
+        calc_dry_sand_settlement(&create_soil_profile(), &mut create_spt(), 0.3, 7.5).unwrap();
+
+    assert_eq!(result.layers.len(), 3);
+
+    let first = &result.layers[0];
+    assert_eq!(first.n1_60, 13);
+    assert_abs_diff_eq!(first.max_shear_modulus, 4532.632, epsilon = 1e-3);
+    assert_abs_diff_eq!(first.shear_strain, 2.096463e-4, epsilon = 1e-9);
+    assert_abs_diff_eq!(first.settlement, 0.105963, epsilon = 1e-6);
+
+    assert_abs_diff_eq!(result.layers[1].settlement, 0.139147, epsilon = 1e-6);
+    assert_abs_diff_eq!(result.layers[2].settlement, 0.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.total_settlement, 0.245110, epsilon = 1e-6);
+}
+
+#[test]
+fn test_total_seismic_settlement() {
+    let result =
+        calc_seismic_settlement(&create_soil_profile(), &mut create_spt(), 0.3, 7.5).unwrap();
+
+    assert_eq!(result.layers.len(), 3);
+    assert_abs_diff_eq!(
+        result.total_settlement,
+        result.liquefaction.total_settlement + result.dry_sand.total_settlement,
+        epsilon = 1e-9
+    );
+    assert_abs_diff_eq!(
+        result.layers[0].liquefaction_settlement,
+        0.0,
+        epsilon = 1e-9
+    );
+    assert_abs_diff_eq!(result.layers[2].dry_sand_settlement, 0.0, epsilon = 1e-9);
+    assert!(result.layers[2].liquefaction_settlement > 0.0);
+}
@@ -0,0 +1,91 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    enums::AnalysisTerm,
+    load_settlement::{
+        calc_hyperbolic_settlement, calc_load_settlement_curve, generate_hyperbolic_curve,
+    },
+    models::{
+        foundation::Foundation,
+        loads::Loads,
+        soil_profile::{SoilLayer, SoilProfile},
+    },
+};
+
+fn create_soil_profile() -> SoilProfile {
+    SoilProfile::new(
+        vec![SoilLayer {
+            thickness: Some(20.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(1.9),
+            c_prime: Some(0.0),
+            phi_prime: Some(32.0),
+            cu: Some(0.0),
+            phi_u: Some(32.0),
+            poissons_ratio: Some(0.3),
+            elastic_modulus: Some(3000.0),
+            ..Default::default()
+        }],
+        10.0,
+    )
+}
+
+#[test]
+fn test_hyperbolic_settlement() {
+    // s = 50 / (10 · (1 − 50 / 100)) = 10 cm
+    assert_abs_diff_eq!(
+        calc_hyperbolic_settlement(50.0, 100.0, 10.0),
+        10.0,
+        epsilon = 1e-9
+    );
+    assert!(calc_hyperbolic_settlement(100.0, 100.0, 10.0).is_infinite());
+}
+
+#[test]
+fn test_generate_hyperbolic_curve() {
+    let points = generate_hyperbolic_curve(100.0, 10.0, 19).unwrap();
+
+    assert_eq!(points.len(), 20);
+    assert_eq!(points[0], (0.0, 0.0));
+    assert_abs_diff_eq!(points[10].0, 50.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(points[10].1, 10.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(points[19].0, 95.0, epsilon = 1e-9);
+    assert!(points.windows(2).all(|w| w[1].1 > w[0].1));
+
+    let err = generate_hyperbolic_curve(100.0, 0.0, 10).unwrap_err();
+    assert_eq!(
+        err.code,
+        "load_settlement.initial_stiffness.too_small.0.0001"
+    );
+}
+
+#[test]
+fn test_load_settlement_curve() {
+    let foundation = Foundation {
+        foundation_width: Some(2.0),
+        foundation_length: Some(2.0),
+        foundation_depth: Some(1.5),
+        ..Default::default()
+    };
+    let loads = Loads {
+        vertical_load: Some(80.0),
+        ..Default::default()
+    };
+
+    let curve = calc_load_settlement_curve(
+        &create_soil_profile(),
+        &foundation,
+        &loads,
+        20.0,
+        AnalysisTerm::Long,
+        10,
+    )
+    .unwrap();
+
+    assert!(curve.ultimate_bearing_capacity > 20.0);
+    assert_eq!(curve.points.len(), 11);
+    // The initial slope of the curve equals the initial stiffness.
+    let (q1, s1) = curve.points[1];
+    let tangent = q1 / s1;
+    assert!(tangent < curve.initial_stiffness);
+    assert!(tangent > 0.85 * curve.initial_stiffness);
+}
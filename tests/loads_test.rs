@@ -19,6 +19,20 @@ fn test_calc_eccentricity() {
     assert_abs_diff_eq!(ey, 1.5, epsilon = 1e-6);
 }
 
+#[test]
+fn test_calc_eccentricity_one_way() {
+    let loading = Loads {
+        vertical_load: Some(10.0),
+        moment_y: Some(15.0),
+        ..Default::default()
+    };
+
+    let (ex, ey) = loading.calc_eccentricity();
+
+    assert_eq!(ex, 0.0);
+    assert_abs_diff_eq!(ey, 1.5, epsilon = 1e-6);
+}
+
 #[test]
 fn test_calc_eccentricity_zero_load() {
     let loading = Loads {
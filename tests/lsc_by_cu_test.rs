@@ -18,6 +18,7 @@ fn test_case_1() {
     let profile = SoilProfile {
         ground_water_level: Some(0.0),
         layers: vec![create_layer(5.0, 10.0), create_layer(10.0, 15.0)], // total depth = 15
+        ..Default::default()
     };
 
     let result = calc_lsc_by_cu(&mut profile.clone()).unwrap();
@@ -36,6 +37,7 @@ fn test_case_2() {
             create_layer(10.0, 0.0), // should be skipped
             create_layer(10.0, 30.0),
         ],
+        ..Default::default()
     };
 
     let result = calc_lsc_by_cu(&mut profile.clone()).unwrap();
@@ -55,6 +57,7 @@ fn test_case_3() {
             create_layer(10.0, 20.0),
             create_layer(20.0, 40.0), // only 10 m of this will be used
         ],
+        ..Default::default()
     };
 
     let result = calc_lsc_by_cu(&mut profile.clone()).unwrap();
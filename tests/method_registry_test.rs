@@ -0,0 +1,78 @@
+use soilrust::{
+    bearing_capacity::vesic,
+    enums::{AnalysisTerm, MethodId, PressureConvention, SoilType},
+    method_registry::{find_methods, get_method_info, list_methods},
+    models::{
+        foundation::Foundation,
+        loads::Loads,
+        soil_profile::{SoilLayer, SoilProfile},
+    },
+};
+
+#[test]
+fn test_get_method_info() {
+    let info = get_method_info(MethodId::Vesic);
+
+    assert_eq!(info.id, MethodId::Vesic);
+    assert!(info.citation.contains("Vesic"));
+    assert_eq!(info.version, "1.0.0");
+
+    let limit = info.get_limit("phi").unwrap();
+    assert!(limit.contains(30.0));
+    assert!(!limit.contains(55.0));
+    assert!(info.get_limit("vs").is_none());
+}
+
+#[test]
+fn test_list_methods() {
+    let methods = list_methods();
+
+    assert!(methods.iter().any(|m| m.id == MethodId::AndrusStokoe));
+    assert!(methods.iter().all(|m| !m.citation.is_empty()));
+}
+
+#[test]
+fn test_find_methods() {
+    let rock_methods = find_methods(SoilType::Rock);
+
+    assert!(rock_methods.iter().any(|m| m.id == MethodId::PointLoadTest));
+    assert!(!rock_methods.iter().any(|m| m.id == MethodId::KenneyLau));
+}
+
+#[test]
+fn test_result_records_method() {
+    let mut profile = SoilProfile::new(
+        vec![SoilLayer {
+            thickness: Some(10.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(1.9),
+            c_prime: Some(0.0),
+            phi_prime: Some(30.0),
+            ..Default::default()
+        }],
+        5.0,
+    );
+    let mut foundation = Foundation {
+        foundation_width: Some(2.0),
+        foundation_length: Some(2.0),
+        foundation_depth: Some(1.0),
+        ..Default::default()
+    };
+    let loads = Loads {
+        vertical_load: Some(100.0),
+        ..Default::default()
+    };
+
+    let result = vesic::calc_bearing_capacity(
+        &mut profile,
+        &mut foundation,
+        &loads,
+        20.0,
+        PressureConvention::Gross,
+        3.0,
+        AnalysisTerm::Long,
+    )
+    .unwrap();
+
+    assert_eq!(result.method, MethodId::Vesic);
+}
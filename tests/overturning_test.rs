@@ -0,0 +1,63 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    models::{foundation::Foundation, loads::Loads},
+    stability::overturning::calc_overturning,
+};
+
+fn create_foundation() -> Foundation {
+    Foundation {
+        foundation_depth: Some(1.5),
+        foundation_width: Some(2.0),
+        foundation_length: Some(3.0),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_calc_overturning() {
+    let loading = Loads {
+        vertical_load: Some(100.0),
+        moment_x: Some(20.0),
+        horizontal_load_x: Some(10.0),
+        moment_y: Some(60.0),
+        ..Default::default()
+    };
+
+    let result = calc_overturning(&create_foundation(), &loading, 1.5).unwrap();
+
+    // Mo,x = 20 + 10 · 1.5, Mr,x = 100 · 2 / 2
+    assert_abs_diff_eq!(result.overturning_moment_x, 35.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.resisting_moment_x, 100.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.factor_of_safety_x, 100.0 / 35.0, epsilon = 1e-9);
+    assert!(result.is_safe_x);
+
+    // Mo,y = 60, Mr,y = 100 · 3 / 2
+    assert_abs_diff_eq!(result.overturning_moment_y, 60.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.resisting_moment_y, 150.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.factor_of_safety_y, 2.5, epsilon = 1e-9);
+    assert!(result.is_safe_y);
+
+    // A stricter requirement fails the y-direction only
+    let result = calc_overturning(&create_foundation(), &loading, 2.6).unwrap();
+    assert_abs_diff_eq!(result.required_factor_of_safety, 2.6, epsilon = 1e-9);
+    assert!(result.is_safe_x);
+    assert!(!result.is_safe_y);
+}
+
+#[test]
+fn test_calc_overturning_without_lateral_loads() {
+    let loading = Loads {
+        vertical_load: Some(100.0),
+        ..Default::default()
+    };
+
+    let result = calc_overturning(&create_foundation(), &loading, 1.5).unwrap();
+    assert!(result.factor_of_safety_x.is_infinite());
+    assert!(result.is_safe_x && result.is_safe_y);
+
+    let err = calc_overturning(&create_foundation(), &loading, 0.5).unwrap_err();
+    assert_eq!(
+        err.code,
+        "overturning.required_factor_of_safety.too_small.1"
+    );
+}
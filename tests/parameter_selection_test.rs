@@ -0,0 +1,147 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    enums::{DesignParameter, MeasurementSource, SelectionMethod, SelectionRule},
+    models::{
+        cpt::{CPTExp, CPTLayer},
+        soil_profile::{SoilLayer, SoilProfile},
+        spt::{NValue, SPTExp, SPT},
+    },
+    parameter_selection::{apply_selection_rule, select_design_parameter, Measurement},
+};
+
+fn create_soil_profile() -> SoilProfile {
+    SoilProfile::new(
+        vec![
+            SoilLayer {
+                thickness: Some(5.0),
+                dry_unit_weight: Some(1.8),
+                saturated_unit_weight: Some(1.9),
+                plasticity_index: Some(20.0),
+                ..Default::default()
+            },
+            SoilLayer {
+                thickness: Some(10.0),
+                dry_unit_weight: Some(1.8),
+                saturated_unit_weight: Some(2.0),
+                fine_content: Some(5.0),
+                ..Default::default()
+            },
+        ],
+        2.0,
+    )
+}
+
+fn create_spt() -> SPT {
+    let mut exp = SPTExp::new(vec![], "exp1".to_string());
+    exp.add_blow(3.0, NValue::Value(10));
+    exp.add_blow(7.5, NValue::Value(20));
+
+    let mut spt = SPT::new(1.0, 1.0, 1.0, SelectionMethod::Min);
+    spt.add_exp(exp);
+    spt
+}
+
+fn create_cpt() -> CPTExp {
+    CPTExp::new(
+        vec![
+            CPTLayer::new(3.5, 1.0, 0.05, None),
+            CPTLayer::new(10.0, 15.0, 0.1, None),
+        ],
+        "cpt1".to_string(),
+    )
+}
+
+#[test]
+fn test_selection_rules() {
+    let values = [10.0, 12.0, 14.0, 16.0];
+
+    let (mean, std_dev, selected) = apply_selection_rule(&values, SelectionRule::Mean);
+    assert_abs_diff_eq!(mean, 13.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(std_dev, 2.581989, epsilon = 1e-6);
+    assert_abs_diff_eq!(selected, 13.0, epsilon = 1e-9);
+
+    let (_, _, selected) = apply_selection_rule(&values, SelectionRule::CautiousMean);
+    assert_abs_diff_eq!(selected, 11.709006, epsilon = 1e-6);
+
+    let (_, _, selected) = apply_selection_rule(&values, SelectionRule::Characteristic);
+    assert_abs_diff_eq!(selected, 9.962290, epsilon = 1e-6);
+
+    let (_, _, selected) = apply_selection_rule(&values, SelectionRule::Minimum);
+    assert_abs_diff_eq!(selected, 10.0, epsilon = 1e-9);
+
+    let (_, _, selected) = apply_selection_rule(&[3.0, 1.0, 2.0], SelectionRule::Median);
+    assert_abs_diff_eq!(selected, 2.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_single_measurement() {
+    let (mean, std_dev, selected) = apply_selection_rule(&[5.0], SelectionRule::Characteristic);
+    assert_abs_diff_eq!(mean, 5.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(std_dev, 0.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(selected, 5.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_select_undrained_shear_strength() {
+    let lab = vec![
+        Measurement::lab(2.0, 5.0, "UU triaxial"),
+        Measurement::lab(4.0, 6.0, "UU triaxial"),
+    ];
+    let mut spt = create_spt();
+    let cpt = create_cpt();
+
+    let table = select_design_parameter(
+        &create_soil_profile(),
+        DesignParameter::UndrainedShearStrength,
+        &lab,
+        Some(&mut spt),
+        Some(&cpt),
+        SelectionRule::Mean,
+    )
+    .unwrap();
+
+    assert_eq!(table.rows.len(), 1);
+    let row = &table.rows[0];
+    assert_eq!(row.layer_index, 0);
+    assert_abs_diff_eq!(row.bottom_depth, 5.0, epsilon = 1e-9);
+    assert_eq!(row.measurements.len(), 4);
+
+    let spt_value = row
+        .measurements
+        .iter()
+        .find(|m| m.source == MeasurementSource::Spt)
+        .unwrap();
+    assert_abs_diff_eq!(spt_value.value, 4.588723, epsilon = 1e-6);
+
+    let cpt_value = row
+        .measurements
+        .iter()
+        .find(|m| m.source == MeasurementSource::Cpt)
+        .unwrap();
+    assert_abs_diff_eq!(cpt_value.value, 6.368108, epsilon = 1e-6);
+
+    assert_abs_diff_eq!(row.mean, 5.489208, epsilon = 1e-6);
+}
+
+#[test]
+fn test_select_friction_angle() {
+    let mut spt = create_spt();
+    let cpt = create_cpt();
+
+    let table = select_design_parameter(
+        &create_soil_profile(),
+        DesignParameter::FrictionAngle,
+        &[],
+        Some(&mut spt),
+        Some(&cpt),
+        SelectionRule::Minimum,
+    )
+    .unwrap();
+
+    assert_eq!(table.rows.len(), 1);
+    let row = &table.rows[0];
+    assert_eq!(row.layer_index, 1);
+    assert_abs_diff_eq!(row.top_depth, 5.0, epsilon = 1e-9);
+    assert_eq!(row.measurements.len(), 2);
+    assert!(row.selected_value <= row.mean);
+}
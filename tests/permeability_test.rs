@@ -0,0 +1,18 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{models::gradation::GradationCurve, permeability::calc_hazen_permeability};
+
+#[test]
+fn test_hazen_permeability() {
+    let gradation = GradationCurve::new(vec![0.1, 0.2, 0.4, 0.8], vec![0.0, 10.0, 60.0, 100.0]);
+
+    let k = calc_hazen_permeability(&gradation, 100.0).unwrap();
+    assert_abs_diff_eq!(k, 4e-4, epsilon = 1e-12);
+}
+
+#[test]
+fn test_hazen_permeability_without_d10() {
+    let gradation = GradationCurve::new(vec![0.075, 4.75], vec![20.0, 100.0]);
+
+    let err = calc_hazen_permeability(&gradation, 100.0).unwrap_err();
+    assert_eq!(err.code, "permeability.gradation.d10_unavailable");
+}
@@ -0,0 +1,113 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    models::{
+        pile_section::PileSection,
+        soil_profile::{SoilLayer, SoilProfile},
+    },
+    pile_kinematic::*,
+};
+
+fn create_soil_profile() -> SoilProfile {
+    SoilProfile::new(
+        vec![
+            SoilLayer {
+                dry_unit_weight: Some(1.8),
+                saturated_unit_weight: Some(1.9),
+                shear_wave_velocity: Some(150.0),
+                ..SoilLayer::new(5.0)
+            },
+            SoilLayer {
+                dry_unit_weight: Some(2.0),
+                saturated_unit_weight: Some(2.1),
+                shear_wave_velocity: Some(400.0),
+                ..SoilLayer::new(10.0)
+            },
+            SoilLayer {
+                dry_unit_weight: Some(2.0),
+                saturated_unit_weight: Some(2.1),
+                shear_wave_velocity: Some(300.0),
+                ..SoilLayer::new(10.0)
+            },
+        ],
+        10.0,
+    )
+}
+
+fn create_pile() -> PileSection {
+    PileSection {
+        diameter: Some(0.6),
+        length: Some(20.0),
+        elastic_modulus: Some(3.0e6),
+        moment_capacity: Some(30.0),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_calc_interface_moment() {
+    // No contrast, no kinematic moment
+    assert_abs_diff_eq!(calc_interface_moment(1.0e4, 5000.0, 5000.0, 1e-3), 0.0);
+    assert_abs_diff_eq!(calc_interface_moment(1.0e4, 5000.0, 4000.0, 1e-3), 0.0);
+
+    // F tends to 1 for a rigid lower layer
+    let rigid = calc_interface_moment(1.0e4, 5000.0, 5000.0 * 1e12, 1e-3);
+    let expected = 1.861 * 1.0e4_f64.powf(0.75) * 5000.0_f64.powf(0.25) * 1e-3;
+    assert_abs_diff_eq!(rigid / expected, 1.0, epsilon = 1e-2);
+}
+
+#[test]
+fn test_calc_kinematic_bending() {
+    let result = calc_kinematic_bending(&create_soil_profile(), &create_pile(), 0.3).unwrap();
+
+    // The softer layer below 15 m is not a stiffness contrast
+    assert_eq!(result.interfaces.len(), 1);
+    let interface = &result.interfaces[0];
+    assert_abs_diff_eq!(interface.depth, 5.0);
+    assert_abs_diff_eq!(interface.shear_strain, 6.2898e-4, epsilon = 1e-7);
+    assert_abs_diff_eq!(interface.stiffness_contrast, 1.6766, epsilon = 1e-4);
+    assert_abs_diff_eq!(interface.bending_moment, 4.6689, epsilon = 1e-3);
+    assert_abs_diff_eq!(interface.curvature, 2.4463e-4, epsilon = 1e-7);
+    assert_abs_diff_eq!(interface.utilization, 4.6689 / 30.0, epsilon = 1e-4);
+    assert!(!interface.exceeds_capacity);
+    assert!(result.is_safe);
+}
+
+#[test]
+fn test_capacity_exceeded() {
+    let pile = PileSection {
+        moment_capacity: Some(3.0),
+        ..create_pile()
+    };
+
+    let result = calc_kinematic_bending(&create_soil_profile(), &pile, 0.3).unwrap();
+
+    assert!(result.interfaces[0].exceeds_capacity);
+    assert!(!result.is_safe);
+    assert_abs_diff_eq!(result.max_bending_moment, 4.6689, epsilon = 1e-3);
+}
+
+#[test]
+fn test_short_pile() {
+    let pile = PileSection {
+        length: Some(4.0),
+        ..create_pile()
+    };
+
+    let result = calc_kinematic_bending(&create_soil_profile(), &pile, 0.3).unwrap();
+
+    assert!(result.interfaces.is_empty());
+    assert!(result.is_safe);
+}
+
+#[test]
+fn test_validation_errors() {
+    let pile = PileSection {
+        moment_capacity: None,
+        ..create_pile()
+    };
+    let err = calc_kinematic_bending(&create_soil_profile(), &pile, 0.3).unwrap_err();
+    assert_eq!(err.code, "pile_section.moment_capacity.missing");
+
+    let err = calc_kinematic_bending(&create_soil_profile(), &create_pile(), -0.1).unwrap_err();
+    assert_eq!(err.code, "pile_kinematic.pga.too_small.0");
+}
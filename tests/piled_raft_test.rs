@@ -0,0 +1,65 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{models::piled_raft::PiledRaft, piled_raft::calc_piled_raft};
+
+fn create_piled_raft() -> PiledRaft {
+    PiledRaft {
+        raft_width: Some(10.0),
+        raft_length: Some(10.0),
+        pile_count: Some(16.0),
+        pile_stiffness: Some(20000.0),
+        pile_capacity: Some(50.0),
+        group_exponent: Some(0.5),
+        interaction_factor: Some(0.8),
+    }
+}
+
+#[test]
+fn test_elastic_range() {
+    let result = calc_piled_raft(&create_piled_raft(), 300.0, 600.0).unwrap();
+
+    assert_abs_diff_eq!(result.raft_stiffness, 30000.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.pile_group_stiffness, 80000.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.piled_raft_stiffness, 81578.947, epsilon = 1e-3);
+    assert_abs_diff_eq!(result.raft_load_ratio, 0.096774, epsilon = 1e-6);
+    assert_abs_diff_eq!(result.pile_yield_load, 885.714, epsilon = 1e-3);
+    assert!(!result.piles_at_capacity);
+    assert_abs_diff_eq!(result.settlement, 0.735484, epsilon = 1e-6);
+}
+
+#[test]
+fn test_piles_at_capacity() {
+    let result = calc_piled_raft(&create_piled_raft(), 300.0, 1200.0).unwrap();
+
+    assert!(result.piles_at_capacity);
+    assert_abs_diff_eq!(result.pile_load, 800.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.raft_load, 400.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.settlement, 2.133333, epsilon = 1e-6);
+}
+
+#[test]
+fn test_stiff_raft_out_of_range() {
+    let result = calc_piled_raft(&create_piled_raft(), 2000.0, 600.0);
+
+    assert_eq!(
+        result.unwrap_err().code,
+        "piled_raft.stiffness_ratio.out_of_range"
+    );
+}
+
+#[test]
+fn test_raft_load_ratio_out_of_range() {
+    // kr = 100, kp = 70, αrp = 0.8 gives Pr / Pt = 20 / 10 = 2
+    let piled_raft = PiledRaft {
+        raft_width: Some(1.0),
+        raft_length: Some(1.0),
+        pile_count: Some(1.0),
+        pile_stiffness: Some(70.0),
+        ..create_piled_raft()
+    };
+    let result = calc_piled_raft(&piled_raft, 100.0, 600.0);
+
+    assert_eq!(
+        result.unwrap_err().code,
+        "piled_raft.raft_load_ratio.out_of_range"
+    );
+}
@@ -0,0 +1,58 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    constants::Environment,
+    models::phreatic_surface::PhreaticSurface,
+    pore_pressure::{calc_pore_pressure_from_phreatic_surface, calc_pore_pressure_from_ru},
+};
+
+fn create_phreatic_surface() -> PhreaticSurface {
+    PhreaticSurface::new(vec![0.0, 10.0, 20.0], vec![8.0, 8.0, 3.0])
+}
+
+#[test]
+fn test_phreatic_surface_geometry() {
+    let surface = create_phreatic_surface();
+
+    assert_abs_diff_eq!(surface.get_elevation(15.0), 5.5, epsilon = 1e-9);
+    assert_abs_diff_eq!(surface.get_elevation(30.0), 3.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(surface.get_inclination(5.0), 0.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(surface.get_inclination(15.0), -26.565051, epsilon = 1e-6);
+}
+
+#[test]
+fn test_pore_pressure_from_phreatic_surface() {
+    let surface = create_phreatic_surface();
+
+    // Horizontal phreatic line: u = γw · hw
+    let u = calc_pore_pressure_from_phreatic_surface(&surface, 5.0, 4.0, &Environment::default())
+        .unwrap();
+    assert_abs_diff_eq!(u, 3.924, epsilon = 1e-9);
+
+    // Inclined phreatic line: u = γw · hw · cos²θ, cos²θ = 0.8
+    let u = calc_pore_pressure_from_phreatic_surface(&surface, 15.0, 1.5, &Environment::default())
+        .unwrap();
+    assert_abs_diff_eq!(u, 0.981 * 4.0 * 0.8, epsilon = 1e-9);
+
+    // Above the phreatic surface
+    let u = calc_pore_pressure_from_phreatic_surface(&surface, 15.0, 6.0, &Environment::default())
+        .unwrap();
+    assert_eq!(u, 0.0);
+
+    let invalid = PhreaticSurface::new(vec![0.0, 0.0], vec![1.0, 2.0]);
+    let err = calc_pore_pressure_from_phreatic_surface(&invalid, 0.0, 0.0, &Environment::default())
+        .unwrap_err();
+    assert_eq!(err.code, "phreatic_surface.x.not_increasing");
+}
+
+#[test]
+fn test_pore_pressure_from_ru() {
+    assert_abs_diff_eq!(
+        calc_pore_pressure_from_ru(0.3, 10.0).unwrap(),
+        3.0,
+        epsilon = 1e-9
+    );
+    assert_eq!(
+        calc_pore_pressure_from_ru(1.2, 10.0).unwrap_err().code,
+        "pore_pressure.ru.too_large.1"
+    );
+}
@@ -0,0 +1,37 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    enums::Distribution,
+    probabilistic::{bayesian::update_parameter, distribution::ParameterDistribution},
+};
+
+#[test]
+fn test_normal_update() {
+    let prior = ParameterDistribution::new(20.0, 5.0, Distribution::Normal);
+
+    let result = update_parameter(&prior, &[30.0, 28.0, 32.0], Some(4.0)).unwrap();
+
+    assert_abs_diff_eq!(result.posterior.mean, 28.24176, epsilon = 1e-5);
+    assert_abs_diff_eq!(result.posterior.std_dev, 2.09657, epsilon = 1e-5);
+    assert_abs_diff_eq!(result.predictive.std_dev, 4.51615, epsilon = 1e-5);
+    assert_abs_diff_eq!(result.data_weight, 0.82418, epsilon = 1e-5);
+}
+
+#[test]
+fn test_lognormal_update_moves_towards_data() {
+    let prior = ParameterDistribution::new(5.0, 2.0, Distribution::Lognormal);
+
+    let result = update_parameter(&prior, &[8.0, 9.0, 7.5, 8.5], None).unwrap();
+
+    assert!(result.posterior.mean > prior.mean);
+    assert!(result.posterior.mean < 9.0);
+    assert!(result.posterior.std_dev < prior.std_dev);
+}
+
+#[test]
+fn test_single_measurement_requires_std() {
+    let prior = ParameterDistribution::new(20.0, 5.0, Distribution::Normal);
+
+    let result = update_parameter(&prior, &[30.0], None);
+
+    assert_eq!(result.unwrap_err().code, "bayesian.measurement_std.missing");
+}
@@ -0,0 +1,159 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    enums::Distribution,
+    probabilistic::{
+        distribution::ParameterDistribution,
+        monte_carlo::{run_monte_carlo, MonteCarloParams},
+        random::Rng,
+    },
+    progress::ProgressHandle,
+    validation::ValidationError,
+};
+
+fn factor_of_safety(rng: &mut Rng) -> Result<f64, ValidationError> {
+    let resistance = ParameterDistribution::new(1.5, 0.3, Distribution::Normal);
+    Ok(resistance.sample(rng))
+}
+
+#[test]
+fn test_rng_streams() {
+    let mut a = Rng::from_stream(42, 3);
+    let mut b = Rng::from_stream(42, 3);
+    let mut c = Rng::from_stream(42, 4);
+
+    let first = a.next_u64();
+    assert_eq!(first, b.next_u64());
+    assert_ne!(first, c.next_u64());
+}
+
+#[test]
+fn test_monte_carlo_statistics() {
+    let params = MonteCarloParams {
+        iterations: 20000,
+        seed: 11,
+        ..Default::default()
+    };
+
+    let result = run_monte_carlo(factor_of_safety, &params, None).unwrap();
+
+    assert_eq!(result.samples.len(), 20000);
+    assert_abs_diff_eq!(result.mean, 1.5, epsilon = 0.01);
+    assert_abs_diff_eq!(result.cov, 0.2, epsilon = 0.005);
+    // P(FS < 1) = Φ(−1.667) ≈ 0.048
+    assert_abs_diff_eq!(result.probability_of_failure, 0.048, epsilon = 0.005);
+    assert_abs_diff_eq!(result.reliability_index, 1.667, epsilon = 0.05);
+}
+
+#[test]
+fn test_monte_carlo_is_reproducible_across_threads() {
+    let params = |threads| MonteCarloParams {
+        iterations: 1001,
+        seed: 5,
+        threads,
+        convergence_interval: 100,
+    };
+
+    let single = run_monte_carlo(factor_of_safety, &params(1), None).unwrap();
+    let parallel = run_monte_carlo(factor_of_safety, &params(4), None).unwrap();
+
+    assert_eq!(single.threads, 1);
+    assert_eq!(parallel.threads, 4);
+    assert_eq!(single.samples, parallel.samples);
+    assert_eq!(single.mean, parallel.mean);
+    assert_eq!(single.std_dev, parallel.std_dev);
+
+    let other_seed = run_monte_carlo(
+        factor_of_safety,
+        &MonteCarloParams {
+            seed: 6,
+            ..params(4)
+        },
+        None,
+    )
+    .unwrap();
+    assert_ne!(single.samples, other_seed.samples);
+}
+
+#[test]
+fn test_monte_carlo_convergence() {
+    let params = MonteCarloParams {
+        iterations: 1050,
+        seed: 3,
+        threads: 2,
+        convergence_interval: 100,
+    };
+
+    let result = run_monte_carlo(factor_of_safety, &params, None).unwrap();
+
+    // Every 100 realizations and the last one
+    assert_eq!(result.convergence.len(), 11);
+    assert_eq!(result.convergence[0].iterations, 100);
+    let last = result.convergence.last().unwrap();
+    assert_eq!(last.iterations, 1050);
+    assert_abs_diff_eq!(last.mean, result.mean, epsilon = 1e-12);
+    assert_abs_diff_eq!(last.cov, result.cov, epsilon = 1e-12);
+    assert_abs_diff_eq!(
+        last.probability_of_failure,
+        result.probability_of_failure,
+        epsilon = 1e-12
+    );
+
+    let first_mean = result.samples[..100].iter().sum::<f64>() / 100.0;
+    assert_abs_diff_eq!(result.convergence[0].mean, first_mean, epsilon = 1e-12);
+}
+
+#[test]
+fn test_monte_carlo_errors() {
+    let params = MonteCarloParams {
+        iterations: 1,
+        ..Default::default()
+    };
+    let err = run_monte_carlo(factor_of_safety, &params, None).unwrap_err();
+    assert_eq!(err.code, "monte_carlo.iterations.too_small.2");
+
+    let failing = |_: &mut Rng| -> Result<f64, ValidationError> {
+        Err(ValidationError {
+            code: "model.failed".into(),
+            message: "The model failed.".into(),
+        })
+    };
+    let params = MonteCarloParams {
+        iterations: 10,
+        threads: 2,
+        ..Default::default()
+    };
+    assert_eq!(
+        run_monte_carlo(failing, &params, None).unwrap_err().code,
+        "model.failed"
+    );
+}
+
+#[test]
+fn test_monte_carlo_progress() {
+    let params = MonteCarloParams {
+        iterations: 1050,
+        seed: 3,
+        threads: 2,
+        convergence_interval: 100,
+    };
+    let progress = ProgressHandle::default();
+
+    run_monte_carlo(factor_of_safety, &params, Some(&progress)).unwrap();
+    assert_eq!(progress.total(), 1050);
+    assert_eq!(progress.completed(), 1050);
+
+    // Cancelled by the host after some realizations
+    let cancelling = |rng: &mut Rng| {
+        if progress.completed() >= 200 {
+            progress.cancel();
+        }
+        factor_of_safety(rng)
+    };
+    let params = MonteCarloParams {
+        threads: 1,
+        ..params
+    };
+    let err = run_monte_carlo(cancelling, &params, Some(&progress)).unwrap_err();
+    assert_eq!(err.code, "analysis.cancelled");
+    assert!(progress.completed() < 1050);
+}
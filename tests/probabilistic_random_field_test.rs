@@ -0,0 +1,122 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    enums::Distribution,
+    models::{
+        soil_profile::{SoilLayer, SoilProfile},
+        spt::{NValue, SPTBlow, SPTExp},
+    },
+    probabilistic::{
+        random::Rng,
+        random_field::{
+            generate_cu_field, generate_spt_field, generate_standard_field, transform_to_marginal,
+            RandomFieldParams,
+        },
+    },
+};
+
+#[test]
+fn test_rng_is_reproducible() {
+    let mut a = Rng::new(42);
+    let mut b = Rng::new(42);
+
+    for _ in 0..100 {
+        assert_eq!(a.next_u64(), b.next_u64());
+    }
+}
+
+#[test]
+fn test_standard_field_statistics() {
+    let depths: Vec<f64> = (0..20000).map(|i| i as f64 * 0.1).collect();
+    let mut rng = Rng::new(7);
+    let field = generate_standard_field(&depths, 2.0, &mut rng);
+
+    let n = field.len() as f64;
+    let mean = field.iter().sum::<f64>() / n;
+    let var = field.iter().map(|z| (z - mean).powi(2)).sum::<f64>() / n;
+    let lag1 = field
+        .windows(2)
+        .map(|w| (w[0] - mean) * (w[1] - mean))
+        .sum::<f64>()
+        / (n - 1.0)
+        / var;
+
+    assert_abs_diff_eq!(mean, 0.0, epsilon = 0.1);
+    assert_abs_diff_eq!(var, 1.0, epsilon = 0.1);
+    assert_abs_diff_eq!(lag1, (-0.1f64).exp(), epsilon = 0.02);
+}
+
+#[test]
+fn test_transform_to_marginal() {
+    assert_abs_diff_eq!(
+        transform_to_marginal(10.0, 0.3, 1.0, Distribution::Normal),
+        13.0,
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        transform_to_marginal(10.0, 0.3, 0.0, Distribution::Lognormal),
+        10.0 / (1.09f64).sqrt(),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_generate_cu_field() {
+    let soil_profile = SoilProfile::new(
+        vec![
+            SoilLayer {
+                thickness: Some(2.0),
+                cu: Some(3.0),
+                ..Default::default()
+            },
+            SoilLayer {
+                thickness: Some(3.0),
+                cu: Some(6.0),
+                ..Default::default()
+            },
+        ],
+        1.0,
+    );
+    let params = RandomFieldParams {
+        cov: 0.3,
+        correlation_length: 2.0,
+        distribution: Distribution::Lognormal,
+    };
+
+    let mut rng = Rng::new(1);
+    let field = generate_cu_field(&soil_profile, &params, 0.5, &mut rng).unwrap();
+
+    assert_eq!(field.layers.len(), 10);
+    assert_abs_diff_eq!(
+        field.layers.last().unwrap().depth.unwrap(),
+        5.0,
+        epsilon = 1e-9
+    );
+    assert!(field.layers.iter().all(|l| l.cu.unwrap() > 0.0));
+
+    let mut rng = Rng::new(1);
+    let again = generate_cu_field(&soil_profile, &params, 0.5, &mut rng).unwrap();
+    assert_eq!(field.layers[3].cu, again.layers[3].cu);
+}
+
+#[test]
+fn test_generate_spt_field_keeps_refusals() {
+    let exp = SPTExp::new(
+        vec![
+            SPTBlow::new(1.5, NValue::Value(10)),
+            SPTBlow::new(3.0, NValue::Value(15)),
+            SPTBlow::new(4.5, NValue::Refusal),
+        ],
+        "SK-1".to_string(),
+    );
+    let params = RandomFieldParams {
+        cov: 0.4,
+        correlation_length: 3.0,
+        distribution: Distribution::Lognormal,
+    };
+
+    let mut rng = Rng::new(3);
+    let field = generate_spt_field(&exp, &params, &mut rng).unwrap();
+
+    assert_eq!(field.blows[2].n, Some(NValue::Refusal));
+    assert!(field.blows[0].n.unwrap().to_i32() >= 1);
+}
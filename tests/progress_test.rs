@@ -0,0 +1,35 @@
+use approx::assert_abs_diff_eq;
+use soilrust::progress::ProgressHandle;
+
+#[test]
+fn test_progress_fraction() {
+    let handle = ProgressHandle::new(4);
+    let worker = handle.clone();
+
+    worker.advance(1);
+    assert_abs_diff_eq!(handle.fraction(), 0.25, epsilon = 1e-12);
+
+    worker.advance(3);
+    assert_abs_diff_eq!(handle.fraction(), 1.0, epsilon = 1e-12);
+}
+
+#[test]
+fn test_cancellation_across_threads() {
+    let handle = ProgressHandle::new(1000);
+    let worker = handle.clone();
+
+    handle.cancel();
+
+    let result = std::thread::spawn(move || {
+        for _ in 0..1000 {
+            worker.check_cancelled()?;
+            worker.advance(1);
+        }
+        Ok::<(), soilrust::validation::ValidationError>(())
+    })
+    .join()
+    .unwrap();
+
+    assert_eq!(result.unwrap_err().code, "analysis.cancelled");
+    assert_eq!(handle.completed(), 0);
+}
@@ -0,0 +1,104 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    bearing_capacity::vhm_envelope::VhmEnvelopeParams,
+    enums::InterfaceType,
+    models::{foundation::Foundation, loads::Loads},
+    stability::rocking::{calc_rocking, calc_uplift_ratio},
+};
+
+fn create_foundation() -> Foundation {
+    Foundation {
+        foundation_width: Some(4.0),
+        foundation_length: Some(4.0),
+        ..Default::default()
+    }
+}
+
+fn create_loads(moment: f64) -> Loads {
+    Loads {
+        vertical_load: Some(400.0),
+        horizontal_load_x: Some(50.0),
+        moment_x: Some(moment),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_calc_rocking() {
+    let params = VhmEnvelopeParams::default();
+    let result = calc_rocking(
+        1000.0,
+        &create_foundation(),
+        &create_loads(200.0),
+        30.0,
+        &params,
+    )
+    .unwrap();
+
+    // Mc = 4 · 400 / 2 · (1 − 400 / 1000), Bc = 400 / (62.5 · 4)
+    assert_abs_diff_eq!(result.moment_capacity, 480.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(
+        result.moment_demand_capacity_ratio,
+        200.0 / 480.0,
+        epsilon = 1e-9
+    );
+    assert_abs_diff_eq!(result.critical_contact_width, 1.6, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.uplift_ratio, 0.0, epsilon = 1e-9);
+    assert!(!result.is_rocking);
+
+    // μ = tan 30°
+    let sliding_capacity = 400.0 * 30f64.to_radians().tan();
+    assert_abs_diff_eq!(result.sliding_capacity, sliding_capacity, epsilon = 1e-9);
+    assert!(result.is_sliding_safe);
+    assert!(result.vhm.utilization > 0.4);
+
+    // e = 1.25 > B / 6: contact width 3 · (2 − 1.25) = 2.25
+    let result = calc_rocking(
+        1000.0,
+        &create_foundation(),
+        &create_loads(500.0),
+        30.0,
+        &params,
+    )
+    .unwrap();
+    assert_abs_diff_eq!(result.uplift_ratio, 1.0 - 2.25 / 4.0, epsilon = 1e-9);
+    assert!(result.is_rocking);
+}
+
+#[test]
+fn test_calc_rocking_with_interface() {
+    let foundation = Foundation {
+        interface_type: Some(InterfaceType::PrecastConcreteOnSand),
+        ..create_foundation()
+    };
+    let result = calc_rocking(
+        1000.0,
+        &foundation,
+        &create_loads(200.0),
+        30.0,
+        &VhmEnvelopeParams::default(),
+    )
+    .unwrap();
+
+    // δ = 2/3 · 30° = 20°
+    let sliding_capacity = 400.0 * 20f64.to_radians().tan();
+    assert_abs_diff_eq!(result.sliding_capacity, sliding_capacity, epsilon = 1e-9);
+
+    let err = calc_rocking(
+        300.0,
+        &foundation,
+        &create_loads(200.0),
+        30.0,
+        &VhmEnvelopeParams::default(),
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "rocking.vertical_load.too_large.300");
+}
+
+#[test]
+fn test_calc_uplift_ratio() {
+    assert_abs_diff_eq!(calc_uplift_ratio(100.0, 50.0, 3.0), 0.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(calc_uplift_ratio(100.0, 100.0, 3.0), 0.5, epsilon = 1e-9);
+    assert_abs_diff_eq!(calc_uplift_ratio(100.0, 120.0, 3.0), 0.7, epsilon = 1e-9);
+    assert_abs_diff_eq!(calc_uplift_ratio(100.0, 500.0, 3.0), 1.0, epsilon = 1e-9);
+}
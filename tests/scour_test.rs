@@ -0,0 +1,209 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    bearing_capacity::vesic,
+    enums::{AnalysisTerm, BedCondition, PierNoseShape, PressureConvention},
+    models::{
+        foundation::Foundation,
+        loads::Loads,
+        scour::{FlowConditions, Pier},
+        soil_profile::{SoilLayer, SoilProfile},
+    },
+    scour::{
+        calc_attack_angle_factor, calc_post_scour_stability, calc_scour_depth,
+        generate_scoured_profile,
+    },
+};
+
+fn create_pier() -> Pier {
+    Pier {
+        width: Some(1.5),
+        length: Some(1.5),
+        attack_angle: Some(0.0),
+        nose_shape: PierNoseShape::Circular,
+    }
+}
+
+fn create_flow() -> FlowConditions {
+    FlowConditions {
+        flow_depth: Some(3.0),
+        velocity: Some(2.0),
+        bed_condition: BedCondition::ClearWater,
+        contracted_discharge: None,
+        contracted_width: None,
+        median_grain_size: None,
+    }
+}
+
+fn create_layer(thickness: f64, phi: f64, c: f64) -> SoilLayer {
+    SoilLayer {
+        thickness: Some(thickness),
+        dry_unit_weight: Some(1.8),
+        saturated_unit_weight: Some(1.9),
+        c_prime: Some(c),
+        phi_prime: Some(phi),
+        cu: Some(0.0),
+        phi_u: Some(phi),
+        ..Default::default()
+    }
+}
+
+fn create_soil_profile() -> SoilProfile {
+    SoilProfile::new(
+        vec![create_layer(3.0, 28.0, 0.5), create_layer(20.0, 32.0, 0.0)],
+        5.0,
+    )
+}
+
+fn create_foundation() -> Foundation {
+    Foundation {
+        foundation_width: Some(4.0),
+        foundation_length: Some(8.0),
+        foundation_depth: Some(2.0),
+        surface_friction_coefficient: Some(0.6),
+        ..Default::default()
+    }
+}
+
+fn create_loads() -> Loads {
+    Loads {
+        vertical_load: Some(500.0),
+        horizontal_load_x: Some(20.0),
+        horizontal_load_y: Some(10.0),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_attack_angle_factor() {
+    assert_abs_diff_eq!(calc_attack_angle_factor(1.5, 6.0, 0.0), 1.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(
+        calc_attack_angle_factor(1.5, 6.0, 15.0),
+        1.569781,
+        epsilon = 1e-6
+    );
+}
+
+#[test]
+fn test_local_scour() {
+    let result = calc_scour_depth(&create_pier(), &create_flow()).unwrap();
+
+    assert_abs_diff_eq!(result.froude_number, 0.368668, epsilon = 1e-6);
+    assert_abs_diff_eq!(result.k3, 1.1, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.local_scour_depth, 2.738593, epsilon = 1e-6);
+    assert_abs_diff_eq!(result.contraction_scour_depth, 0.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_local_scour_limit() {
+    let mut flow = create_flow();
+    flow.flow_depth = Some(20.0);
+    flow.velocity = Some(4.0);
+
+    let result = calc_scour_depth(&create_pier(), &flow).unwrap();
+    assert_abs_diff_eq!(result.local_scour_depth, 3.6, epsilon = 1e-9);
+}
+
+#[test]
+fn test_contraction_scour() {
+    let mut flow = create_flow();
+    flow.contracted_discharge = Some(300.0);
+    flow.contracted_width = Some(50.0);
+    flow.median_grain_size = Some(2.0);
+
+    let result = calc_scour_depth(&create_pier(), &flow).unwrap();
+    assert_abs_diff_eq!(result.contraction_scour_depth, 2.294627, epsilon = 1e-6);
+    assert_abs_diff_eq!(result.total_scour_depth, 5.033220, epsilon = 1e-6);
+}
+
+#[test]
+fn test_contraction_scour_missing_fields() {
+    let mut flow = create_flow();
+    flow.contracted_discharge = Some(300.0);
+
+    assert!(calc_scour_depth(&create_pier(), &flow).is_err());
+}
+
+#[test]
+fn test_scoured_profile() {
+    let profile = generate_scoured_profile(&create_soil_profile(), 4.0).unwrap();
+
+    assert_eq!(profile.layers.len(), 1);
+    assert_abs_diff_eq!(profile.layers[0].thickness.unwrap(), 19.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(profile.ground_water_level.unwrap(), 1.0, epsilon = 1e-9);
+
+    let profile = generate_scoured_profile(&create_soil_profile(), 1.0).unwrap();
+    assert_eq!(profile.layers.len(), 2);
+    assert_abs_diff_eq!(profile.layers[0].thickness.unwrap(), 2.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(profile.layers[1].depth.unwrap(), 22.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_post_scour_stability() {
+    let result = calc_post_scour_stability(
+        &create_soil_profile(),
+        &create_foundation(),
+        &create_loads(),
+        15.0,
+        3.0,
+        AnalysisTerm::Long,
+        1.0,
+    )
+    .unwrap();
+
+    assert_abs_diff_eq!(
+        result.scoured_foundation.foundation_depth.unwrap(),
+        1.0,
+        epsilon = 1e-9
+    );
+
+    let mut expected_profile = SoilProfile::new(
+        vec![create_layer(2.0, 28.0, 0.5), create_layer(20.0, 32.0, 0.0)],
+        4.0,
+    );
+    let mut expected_foundation = create_foundation();
+    expected_foundation.foundation_depth = Some(1.0);
+    let expected = vesic::calc_bearing_capacity(
+        &mut expected_profile,
+        &mut expected_foundation,
+        &create_loads(),
+        15.0,
+        PressureConvention::Gross,
+        3.0,
+        AnalysisTerm::Long,
+    )
+    .unwrap();
+
+    assert_abs_diff_eq!(
+        result.bearing_capacity.ultimate_bearing_capacity,
+        expected.ultimate_bearing_capacity,
+        epsilon = 1e-9
+    );
+
+    let original = vesic::calc_bearing_capacity(
+        &mut create_soil_profile(),
+        &mut create_foundation(),
+        &create_loads(),
+        15.0,
+        PressureConvention::Gross,
+        3.0,
+        AnalysisTerm::Long,
+    )
+    .unwrap();
+    assert!(result.bearing_capacity.ultimate_bearing_capacity < original.ultimate_bearing_capacity);
+}
+
+#[test]
+fn test_foundation_undermined() {
+    let err = calc_post_scour_stability(
+        &create_soil_profile(),
+        &create_foundation(),
+        &create_loads(),
+        15.0,
+        3.0,
+        AnalysisTerm::Long,
+        2.5,
+    )
+    .unwrap_err();
+
+    assert_eq!(err.code, "scour.foundation.undermined");
+}
@@ -1,6 +1,7 @@
 use approx::assert_abs_diff_eq;
 use soilrust::{
     elastic_settlement::boussinesq::*,
+    enums::{FoundationShape, PressureConvention, SettlementPoint},
     models::{
         foundation::Foundation,
         soil_profile::{SoilLayer, SoilProfile},
@@ -39,6 +40,7 @@ fn create_soil_profile() -> SoilProfile {
                 ..Default::default()
             },
         ],
+        ..Default::default()
     }
 }
 fn create_foundation_data() -> Foundation {
@@ -84,8 +86,13 @@ fn test_calc_elastic_settlement() {
     let foundation_data = create_foundation_data();
     let foundation_pressure = 50.;
 
-    let result =
-        calc_elastic_settlement(&mut soil_profile, &foundation_data, foundation_pressure).unwrap();
+    let result = calc_elastic_settlement(
+        &mut soil_profile,
+        &foundation_data,
+        foundation_pressure,
+        PressureConvention::Gross,
+    )
+    .unwrap();
     let expected_settlements = &[1.058, 2.195, 4.613];
 
     for (settlement, expected) in result
@@ -96,3 +103,203 @@ fn test_calc_elastic_settlement() {
         assert_abs_diff_eq!(settlement, expected, epsilon = 1e-3);
     }
 }
+
+#[test]
+fn test_corner_layer_settlement() {
+    // The corner of B x L is a quarter of the center of 2B x 2L by superposition
+    let corner = corner_layer_settlement(2.0, 0.4, 6000.0, 20.0, 10.0, 6.0, 88.3);
+    let center = single_layer_settlement(2.0, 0.4, 6000.0, 40.0, 20.0, 12.0, 88.3);
+
+    assert_abs_diff_eq!(corner, center / 4.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_calc_point_settlement() {
+    let foundation_data = create_foundation_data();
+
+    let result = calc_point_settlement(
+        &mut create_soil_profile(),
+        &foundation_data,
+        50.,
+        PressureConvention::Gross,
+        SettlementPoint::Rigid,
+    )
+    .unwrap();
+    let center = calc_elastic_settlement(
+        &mut create_soil_profile(),
+        &foundation_data,
+        50.,
+        PressureConvention::Gross,
+    )
+    .unwrap()
+    .total_settlement;
+
+    assert_abs_diff_eq!(result.center_flexible, center, epsilon = 1e-9);
+    assert!(result.corner_flexible < center / 2.0);
+    assert_abs_diff_eq!(result.average_flexible, 0.85 * center, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.rigid, 0.93 * center, epsilon = 1e-9);
+    assert_eq!(result.point, SettlementPoint::Rigid);
+    assert_abs_diff_eq!(
+        result.settlement.total_settlement,
+        result.rigid,
+        epsilon = 1e-9
+    );
+    assert_abs_diff_eq!(
+        result.settlement.settlement_per_layer.iter().sum::<f64>(),
+        result.rigid,
+        epsilon = 1e-9
+    );
+
+    let corner = calc_point_settlement(
+        &mut create_soil_profile(),
+        &foundation_data,
+        50.,
+        PressureConvention::Gross,
+        SettlementPoint::CornerFlexible,
+    )
+    .unwrap();
+    assert_abs_diff_eq!(
+        corner.settlement.total_settlement,
+        result.corner_flexible,
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_calc_elastic_settlement_compensated() {
+    let mut soil_profile = create_soil_profile();
+    let foundation_data = create_foundation_data();
+
+    let result = calc_elastic_settlement(
+        &mut soil_profile,
+        &foundation_data,
+        1.0,
+        PressureConvention::Gross,
+    )
+    .unwrap();
+
+    assert!(result.qnet < 0.0);
+    assert!(result.fully_compensated);
+    assert_eq!(result.settlement_per_layer, vec![0.0; 3]);
+    assert_eq!(result.total_settlement, 0.0);
+}
+
+fn create_gibson_profile(gradient: f64) -> SoilProfile {
+    SoilProfile::new(
+        vec![SoilLayer {
+            thickness: Some(20.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(1.9),
+            elastic_modulus: Some(1000.),
+            elastic_modulus_gradient: Some(gradient),
+            poissons_ratio: Some(0.3),
+            ..Default::default()
+        }],
+        10.0,
+    )
+}
+
+#[test]
+fn test_gibson_layer_settlement_uniform_modulus() {
+    let profile = create_gibson_profile(0.0);
+    let foundation = create_foundation_data();
+
+    // With a zero gradient the sublayer settlements add up to the single layer settlement
+    let result = gibson_layer_settlement(&profile.layers[0], 0.0, &foundation, 10.0);
+    let expected = single_layer_settlement(18.0, 0.3, 1000.0, 20.0, 10.0, 2.0, 10.0);
+    assert_abs_diff_eq!(result, expected, epsilon = 1e-9);
+}
+
+#[test]
+fn test_calc_elastic_settlement_gibson_soil() {
+    let foundation = create_foundation_data();
+
+    let uniform = calc_elastic_settlement(
+        &mut create_gibson_profile(0.0),
+        &foundation,
+        15.0,
+        PressureConvention::Gross,
+    )
+    .unwrap()
+    .total_settlement;
+    let gibson = calc_elastic_settlement(
+        &mut create_gibson_profile(100.0),
+        &foundation,
+        15.0,
+        PressureConvention::Gross,
+    )
+    .unwrap()
+    .total_settlement;
+
+    // E increases from 1000 t/m² at the surface to 3000 t/m² at 20 m
+    let mut stiff_profile = create_gibson_profile(0.0);
+    stiff_profile.layers[0].elastic_modulus = Some(3000.);
+    let stiff = calc_elastic_settlement(
+        &mut stiff_profile,
+        &foundation,
+        15.0,
+        PressureConvention::Gross,
+    )
+    .unwrap()
+    .total_settlement;
+
+    assert!(gibson < uniform);
+    assert!(gibson > stiff);
+}
+
+#[test]
+fn test_invalid_modulus_gradient() {
+    let err = calc_elastic_settlement(
+        &mut create_gibson_profile(-1.0),
+        &create_foundation_data(),
+        15.0,
+        PressureConvention::Gross,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.code,
+        "soil_profile.elastic_modulus_gradient.too_small.0"
+    );
+}
+
+#[test]
+fn test_calc_elastic_settlement_circular() {
+    let diameter = 10.0;
+    let circular = Foundation {
+        foundation_depth: Some(2.0),
+        shape: FoundationShape::Circular { diameter },
+        ..Default::default()
+    };
+
+    // A circle is analysed as the square of equal area
+    let side = (std::f64::consts::PI * diameter.powi(2) / 4.0).sqrt();
+    let square = Foundation {
+        foundation_width: Some(side),
+        foundation_length: Some(side),
+        foundation_depth: Some(2.0),
+        ..Default::default()
+    };
+
+    let equivalent = calc_equivalent_rectangle(&circular).unwrap();
+    assert_abs_diff_eq!(equivalent.foundation_width.unwrap(), side, epsilon = 1e-9);
+
+    let circular_result = calc_elastic_settlement(
+        &mut create_soil_profile(),
+        &circular,
+        50.,
+        PressureConvention::Gross,
+    )
+    .unwrap();
+    let square_result = calc_elastic_settlement(
+        &mut create_soil_profile(),
+        &square,
+        50.,
+        PressureConvention::Gross,
+    )
+    .unwrap();
+    assert_abs_diff_eq!(
+        circular_result.total_settlement,
+        square_result.total_settlement,
+        epsilon = 1e-9
+    );
+}
@@ -12,3 +12,413 @@ fn test_settlement_by_mv() {
 
     assert_eq!(settlement, expected_settlement);
 }
+
+#[test]
+fn test_settlement_by_mv_skips_rock() {
+    use soilrust::{
+        consolidation_settlement::by_mv::calc_settlement,
+        enums::{MaterialType, PressureConvention},
+        models::{
+            foundation::Foundation,
+            soil_profile::{SoilLayer, SoilProfile},
+        },
+    };
+
+    let mut soil_profile = SoilProfile::new(
+        vec![
+            SoilLayer {
+                thickness: Some(4.0),
+                dry_unit_weight: Some(1.8),
+                saturated_unit_weight: Some(1.9),
+                mv: Some(0.004),
+                ..Default::default()
+            },
+            SoilLayer {
+                thickness: Some(10.0),
+                dry_unit_weight: Some(2.5),
+                saturated_unit_weight: Some(2.5),
+                material_type: Some(MaterialType::Rock),
+                ..Default::default()
+            },
+        ],
+        0.0,
+    );
+    let foundation = Foundation {
+        foundation_depth: Some(1.0),
+        foundation_width: Some(2.0),
+        foundation_length: Some(2.0),
+        ..Default::default()
+    };
+
+    let result = calc_settlement(
+        &mut soil_profile,
+        &foundation,
+        20.0,
+        PressureConvention::Gross,
+    )
+    .unwrap();
+
+    assert!(result.settlement_per_layer[0] > 0.0);
+    assert_eq!(result.settlement_per_layer[1], 0.0);
+}
+
+#[test]
+fn test_corner_influence_factor() {
+    use approx::assert_abs_diff_eq;
+    use soilrust::consolidation_settlement::helper_functions::{
+        calc_corner_influence_factor, calc_rectangle_stress,
+    };
+
+    assert_abs_diff_eq!(
+        calc_corner_influence_factor(1.0, 1.0, 1.0),
+        0.1752,
+        epsilon = 1e-4
+    );
+    assert_abs_diff_eq!(
+        calc_corner_influence_factor(0.5, 2.0, 1.0),
+        0.1350,
+        epsilon = 1e-4
+    );
+
+    // Point at the center of a 2 x 2 m rectangle: four 1 x 1 m corners.
+    assert_abs_diff_eq!(
+        calc_rectangle_stress(10.0, -1.0, 1.0, -1.0, 1.0, 1.0),
+        4.0 * 1.752,
+        epsilon = 1e-3
+    );
+    // Point outside the rectangle.
+    let outside = calc_rectangle_stress(10.0, 2.0, 4.0, -1.0, 1.0, 1.0);
+    assert!(outside > 0.0 && outside < 1.0);
+}
+
+#[test]
+fn test_settlement_by_mv_with_excavation() {
+    use soilrust::{
+        consolidation_settlement::{
+            by_mv::{calc_settlement, calc_settlement_with_options},
+            model::ConsolidationOptions,
+        },
+        enums::PressureConvention,
+        models::{
+            excavation::Excavation,
+            foundation::Foundation,
+            soil_profile::{SoilLayer, SoilProfile},
+        },
+    };
+
+    let create_profile = || {
+        SoilProfile::new(
+            vec![SoilLayer {
+                thickness: Some(10.0),
+                dry_unit_weight: Some(1.8),
+                saturated_unit_weight: Some(1.9),
+                mv: Some(0.004),
+                ..Default::default()
+            }],
+            0.0,
+        )
+    };
+    let foundation = Foundation {
+        foundation_depth: Some(1.0),
+        foundation_width: Some(2.0),
+        foundation_length: Some(2.0),
+        ..Default::default()
+    };
+    let excavation = |offset_x: f64| Excavation {
+        width: Some(4.0),
+        length: Some(10.0),
+        depth: Some(3.0),
+        offset_x: Some(offset_x),
+        offset_y: Some(0.0),
+    };
+
+    let base = calc_settlement(
+        &mut create_profile(),
+        &foundation,
+        20.0,
+        PressureConvention::Gross,
+    )
+    .unwrap();
+    let options = |offset_x: f64| ConsolidationOptions {
+        excavation: Some(excavation(offset_x)),
+        ..Default::default()
+    };
+    let near = calc_settlement_with_options(
+        &mut create_profile(),
+        &foundation,
+        20.0,
+        PressureConvention::Gross,
+        &options(3.5),
+    )
+    .unwrap();
+    let far = calc_settlement_with_options(
+        &mut create_profile(),
+        &foundation,
+        20.0,
+        PressureConvention::Gross,
+        &options(500.0),
+    )
+    .unwrap();
+
+    assert!(near.total_settlement < base.total_settlement);
+    assert!((far.total_settlement - base.total_settlement).abs() < 1e-6);
+}
+
+#[test]
+fn test_settlement_by_mv_compensated() {
+    use soilrust::{
+        consolidation_settlement::by_mv::calc_settlement,
+        enums::PressureConvention,
+        models::{
+            foundation::Foundation,
+            soil_profile::{SoilLayer, SoilProfile},
+        },
+    };
+
+    let mut soil_profile = SoilProfile::new(
+        vec![SoilLayer {
+            thickness: Some(10.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(1.9),
+            mv: Some(0.004),
+            ..Default::default()
+        }],
+        0.0,
+    );
+    let foundation = Foundation {
+        foundation_depth: Some(3.0),
+        foundation_width: Some(2.0),
+        foundation_length: Some(2.0),
+        ..Default::default()
+    };
+
+    let result = calc_settlement(
+        &mut soil_profile,
+        &foundation,
+        4.0,
+        PressureConvention::Gross,
+    )
+    .unwrap();
+
+    assert!(result.fully_compensated);
+    assert_eq!(result.total_settlement, 0.0);
+
+    let result = calc_settlement(
+        &mut soil_profile,
+        &foundation,
+        20.0,
+        PressureConvention::Gross,
+    )
+    .unwrap();
+    assert!(!result.fully_compensated);
+}
+
+#[test]
+fn test_settlement_by_mv_net_pressure() {
+    use soilrust::{
+        consolidation_settlement::by_mv::calc_settlement,
+        enums::PressureConvention,
+        models::{
+            foundation::Foundation,
+            soil_profile::{SoilLayer, SoilProfile},
+        },
+    };
+
+    let mut soil_profile = SoilProfile::new(
+        vec![SoilLayer {
+            thickness: Some(10.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(1.9),
+            mv: Some(0.004),
+            ..Default::default()
+        }],
+        0.0,
+    );
+    let foundation = Foundation {
+        foundation_depth: Some(1.0),
+        foundation_width: Some(2.0),
+        foundation_length: Some(2.0),
+        ..Default::default()
+    };
+
+    // σv(1 m) = 1.9 t/m²
+    let gross_result = calc_settlement(
+        &mut soil_profile,
+        &foundation,
+        20.0,
+        PressureConvention::Gross,
+    )
+    .unwrap();
+    let net_result = calc_settlement(
+        &mut soil_profile,
+        &foundation,
+        18.1,
+        PressureConvention::Net,
+    )
+    .unwrap();
+
+    assert!((gross_result.qnet - net_result.qnet).abs() < 1e-9);
+    assert!((gross_result.total_settlement - net_result.total_settlement).abs() < 1e-9);
+    assert_eq!(gross_result.pressure_convention, PressureConvention::Gross);
+    assert_eq!(net_result.pressure_convention, PressureConvention::Net);
+}
+
+#[test]
+fn test_calc_delta_stress_distributions() {
+    use approx::assert_abs_diff_eq;
+    use soilrust::{
+        consolidation_settlement::helper_functions::{
+            calc_delta_stress, calc_westergaard_corner_influence_factor,
+        },
+        enums::StressDistribution,
+    };
+
+    // 2 x 2 m foundation, 2 m below the base
+    let two_to_one = calc_delta_stress(10.0, 2.0, 2.0, 2.0, StressDistribution::TwoToOne);
+    assert_abs_diff_eq!(two_to_one, 2.5, epsilon = 1e-9);
+    // 10 · 2 · 4 / ((2 + 2) · (4 + 2)) on a 2 x 4 m foundation
+    let two_to_one = calc_delta_stress(10.0, 2.0, 4.0, 2.0, StressDistribution::TwoToOne);
+    assert_abs_diff_eq!(two_to_one, 80.0 / 24.0, epsilon = 1e-9);
+    let boussinesq = calc_delta_stress(
+        10.0,
+        2.0,
+        2.0,
+        2.0,
+        StressDistribution::BoussinesqRectangular,
+    );
+    assert_abs_diff_eq!(boussinesq, 3.361, epsilon = 1e-3);
+    let westergaard = calc_delta_stress(10.0, 2.0, 2.0, 2.0, StressDistribution::Westergaard);
+    assert_abs_diff_eq!(westergaard, 2.163, epsilon = 1e-3);
+
+    // The full pressure acts at the base
+    for distribution in [
+        StressDistribution::TwoToOne,
+        StressDistribution::BoussinesqRectangular,
+        StressDistribution::Westergaard,
+    ] {
+        assert_abs_diff_eq!(
+            calc_delta_stress(10.0, 2.0, 2.0, 0.0, distribution),
+            10.0,
+            epsilon = 1e-9
+        );
+    }
+    assert_abs_diff_eq!(
+        calc_westergaard_corner_influence_factor(1e6, 1e6, 1.0),
+        0.25,
+        epsilon = 1e-6
+    );
+}
+
+#[test]
+fn test_settlement_by_mv_with_distribution() {
+    use approx::assert_abs_diff_eq;
+    use soilrust::{
+        consolidation_settlement::{
+            by_mv::{calc_settlement, calc_settlement_with_options},
+            helper_functions::calc_delta_stress,
+            model::ConsolidationOptions,
+        },
+        enums::{PressureConvention, StressDistribution},
+        models::{
+            foundation::Foundation,
+            soil_profile::{SoilLayer, SoilProfile},
+        },
+    };
+
+    let create_profile = || {
+        SoilProfile::new(
+            vec![SoilLayer {
+                thickness: Some(10.0),
+                dry_unit_weight: Some(1.8),
+                saturated_unit_weight: Some(1.9),
+                mv: Some(0.004),
+                ..Default::default()
+            }],
+            0.0,
+        )
+    };
+    let foundation = Foundation {
+        foundation_depth: Some(1.0),
+        foundation_width: Some(2.0),
+        foundation_length: Some(2.0),
+        ..Default::default()
+    };
+    let settlement = |distribution| {
+        calc_settlement_with_options(
+            &mut create_profile(),
+            &foundation,
+            20.0,
+            PressureConvention::Gross,
+            &ConsolidationOptions {
+                distribution,
+                ..Default::default()
+            },
+        )
+        .unwrap()
+        .total_settlement
+    };
+
+    let default = calc_settlement(
+        &mut create_profile(),
+        &foundation,
+        20.0,
+        PressureConvention::Gross,
+    )
+    .unwrap();
+    assert_abs_diff_eq!(
+        settlement(StressDistribution::TwoToOne),
+        default.total_settlement,
+        epsilon = 1e-9
+    );
+    // One 9 m thick layer with its center 4.5 m below the base
+    for distribution in [
+        StressDistribution::BoussinesqRectangular,
+        StressDistribution::Westergaard,
+    ] {
+        let delta_stress = calc_delta_stress(default.qnet, 2.0, 2.0, 4.5, distribution);
+        assert_abs_diff_eq!(
+            settlement(distribution),
+            0.004 * 9.0 * delta_stress * 100.0,
+            epsilon = 1e-9
+        );
+    }
+}
+
+#[test]
+fn test_settlement_by_mv_two_to_one_hand_calculation() {
+    use approx::assert_abs_diff_eq;
+    use soilrust::{
+        consolidation_settlement::by_mv::calc_settlement,
+        enums::PressureConvention,
+        models::{
+            foundation::Foundation,
+            soil_profile::{SoilLayer, SoilProfile},
+        },
+    };
+
+    let mut profile = SoilProfile::new(
+        vec![SoilLayer {
+            thickness: Some(10.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(1.9),
+            mv: Some(0.004),
+            ..Default::default()
+        }],
+        0.0,
+    );
+    let foundation = Foundation {
+        foundation_depth: Some(1.0),
+        foundation_width: Some(2.0),
+        foundation_length: Some(2.0),
+        ..Default::default()
+    };
+
+    let result =
+        calc_settlement(&mut profile, &foundation, 20.0, PressureConvention::Gross).unwrap();
+
+    // qnet = 20 − 1.9 · 1 = 18.1 t/m², the 9 m of clay below the base has its center at z = 4.5 m
+    // Δσ = 18.1 · 2 · 2 / ((2 + 4.5) · (2 + 4.5)) = 1.71361 t/m²
+    // S = 0.004 · 9 · 1.71361 · 100 = 6.16899 cm
+    assert_abs_diff_eq!(result.qnet, 18.1, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.total_settlement, 6.16899, epsilon = 1e-5);
+}
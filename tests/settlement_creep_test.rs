@@ -0,0 +1,57 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    consolidation_settlement::model::SettlementResult, elastic_settlement::creep::*,
+    enums::{MethodId, PressureConvention},
+};
+
+#[test]
+fn test_calc_creep_factor() {
+    assert_abs_diff_eq!(
+        calc_creep_factor(0.05, SCHMERTMANN_CREEP_COEFFICIENT),
+        1.0,
+        epsilon = 1e-9
+    );
+    assert_abs_diff_eq!(
+        calc_creep_factor(1.0, SCHMERTMANN_CREEP_COEFFICIENT),
+        1.2,
+        epsilon = 1e-9
+    );
+    // 50 years: 1 + 0.2 · log10(500)
+    assert_abs_diff_eq!(
+        calc_creep_factor(50.0, SCHMERTMANN_CREEP_COEFFICIENT),
+        1.539794,
+        epsilon = 1e-6
+    );
+}
+
+#[test]
+fn test_calc_creep_settlement() {
+    let result = calc_creep_settlement(2.0, 10.0, SCHMERTMANN_CREEP_COEFFICIENT).unwrap();
+
+    assert_abs_diff_eq!(result.creep_factor, 1.4, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.creep_settlement, 0.8, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.total_settlement, 2.8, epsilon = 1e-9);
+
+    let err = calc_creep_settlement(2.0, -1.0, SCHMERTMANN_CREEP_COEFFICIENT).unwrap_err();
+    assert_eq!(err.code, "creep.time.too_small.0");
+}
+
+#[test]
+fn test_apply_creep() {
+    let immediate = SettlementResult {
+        settlement_per_layer: vec![1.0, 0.5],
+        total_settlement: 1.5,
+        qnet: 10.0,
+        method: MethodId::Boussinesq,
+        warnings: vec![],
+        fully_compensated: false,
+        pressure_convention: PressureConvention::Gross,
+    };
+
+    let result = apply_creep(&immediate, 1.0, SCHMERTMANN_CREEP_COEFFICIENT).unwrap();
+
+    assert_abs_diff_eq!(result.settlement_per_layer[0], 1.2, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.settlement_per_layer[1], 0.6, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.total_settlement, 1.8, epsilon = 1e-9);
+    assert_eq!(result.method, MethodId::Boussinesq);
+}
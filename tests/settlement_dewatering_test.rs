@@ -0,0 +1,53 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    consolidation_settlement::{
+        by_compression_index::calc_single_layer_settlement, dewatering::calc_settlement,
+    },
+    models::soil_profile::{SoilLayer, SoilProfile},
+};
+
+fn create_layer(thickness: f64) -> SoilLayer {
+    SoilLayer {
+        thickness: Some(thickness),
+        dry_unit_weight: Some(1.7),
+        saturated_unit_weight: Some(1.9),
+        compression_index: Some(0.3),
+        recompression_index: Some(0.05),
+        void_ratio: Some(1.0),
+        preconsolidation_pressure: Some(5.0),
+        ..Default::default()
+    }
+}
+
+fn create_soil_profile() -> SoilProfile {
+    SoilProfile::new(vec![create_layer(2.0), create_layer(6.0)], 2.0)
+}
+
+#[test]
+fn test_dewatering_settlement() {
+    let result = calc_settlement(&mut create_soil_profile(), 4.0).unwrap();
+
+    assert_abs_diff_eq!(result.drawdown, 2.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.settlement_per_layer[0], 0.0, epsilon = 1e-9);
+
+    // Center of the second layer at 5 m: σ'0 = 3.4 + 3 · 0.919 = 6.157 t/m²,
+    // σ'1 = 3.4 + 2 · 1.7 + 1 · 0.919 = 7.719 t/m² after lowering the water to 4 m.
+    assert_abs_diff_eq!(result.delta_stress_per_layer[1], 1.562, epsilon = 1e-9);
+    let expected = calc_single_layer_settlement(6.0, 0.3, 0.05, 1.0, 5.0, 6.157, 1.562);
+    assert_abs_diff_eq!(result.settlement_per_layer[1], expected, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.total_settlement, expected, epsilon = 1e-9);
+}
+
+#[test]
+fn test_dewatering_no_drawdown() {
+    let result = calc_settlement(&mut create_soil_profile(), 2.0).unwrap();
+
+    assert_abs_diff_eq!(result.total_settlement, 0.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_dewatering_raised_water_level() {
+    let err = calc_settlement(&mut create_soil_profile(), 1.0).unwrap_err();
+
+    assert_eq!(err.code, "dewatering.lowered_water_level.too_small.2");
+}
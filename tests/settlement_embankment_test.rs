@@ -0,0 +1,103 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    consolidation_settlement::{
+        by_compression_index::calc_single_layer_settlement, embankment::calc_settlement,
+    },
+    models::{
+        embankment::{calc_osterberg_influence_factor, Embankment},
+        soil_profile::{SoilLayer, SoilProfile},
+    },
+};
+
+fn create_layer(thickness: f64) -> SoilLayer {
+    SoilLayer {
+        thickness: Some(thickness),
+        dry_unit_weight: Some(1.7),
+        saturated_unit_weight: Some(1.9),
+        compression_index: Some(0.3),
+        recompression_index: Some(0.05),
+        void_ratio: Some(1.0),
+        preconsolidation_pressure: Some(5.0),
+        ..Default::default()
+    }
+}
+
+fn create_embankment() -> Embankment {
+    Embankment {
+        height: Some(4.0),
+        crest_width: Some(10.0),
+        side_slope: Some(2.0),
+        unit_weight: Some(2.0),
+    }
+}
+
+#[test]
+fn test_osterberg_influence_factor() {
+    // a / z = b / z = 1
+    assert_abs_diff_eq!(
+        calc_osterberg_influence_factor(5.0, 5.0, 5.0),
+        0.4548,
+        epsilon = 1e-4
+    );
+    assert_abs_diff_eq!(calc_osterberg_influence_factor(5.0, 5.0, 0.0), 0.5);
+    assert_abs_diff_eq!(
+        calc_osterberg_influence_factor(5.0, 1e6, 5.0),
+        0.5,
+        epsilon = 1e-5
+    );
+    // Vertical sides are the limit of steep slopes
+    assert_abs_diff_eq!(
+        calc_osterberg_influence_factor(0.0, 5.0, 5.0),
+        calc_osterberg_influence_factor(1e-6, 5.0, 5.0),
+        epsilon = 1e-6
+    );
+}
+
+#[test]
+fn test_embankment_geometry() {
+    let embankment = create_embankment();
+
+    assert_abs_diff_eq!(embankment.calc_pressure(), 8.0);
+    assert_abs_diff_eq!(embankment.calc_slope_width(), 8.0);
+    assert_abs_diff_eq!(embankment.calc_base_width(), 26.0);
+    assert_abs_diff_eq!(embankment.calc_center_stress(0.0), 8.0);
+    assert_abs_diff_eq!(
+        embankment.calc_center_stress(5.0),
+        2.0 * 8.0 * calc_osterberg_influence_factor(8.0, 5.0, 5.0),
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_embankment_settlement() {
+    let mut soil_profile = SoilProfile::new(vec![create_layer(2.0), create_layer(6.0)], 2.0);
+    let embankment = create_embankment();
+
+    let result = calc_settlement(&mut soil_profile, &embankment).unwrap();
+
+    assert_abs_diff_eq!(result.embankment_pressure, 8.0);
+    assert_abs_diff_eq!(result.settlement_per_layer[0], 0.0);
+
+    // Center of the second layer at 5 m: σ'0 = 3.4 + 3 · 0.919 = 6.157 t/m²
+    let delta_stress = embankment.calc_center_stress(5.0);
+    assert_abs_diff_eq!(
+        result.delta_stress_per_layer[1],
+        delta_stress,
+        epsilon = 1e-9
+    );
+    let expected = calc_single_layer_settlement(6.0, 0.3, 0.05, 1.0, 5.0, 6.157, delta_stress);
+    assert_abs_diff_eq!(result.total_settlement, expected, epsilon = 1e-9);
+}
+
+#[test]
+fn test_invalid_embankment() {
+    let mut soil_profile = SoilProfile::new(vec![create_layer(8.0)], 0.0);
+    let embankment = Embankment {
+        side_slope: Some(-1.0),
+        ..create_embankment()
+    };
+
+    let err = calc_settlement(&mut soil_profile, &embankment).unwrap_err();
+
+    assert_eq!(err.code, "embankment.side_slope.too_small.0");
+}
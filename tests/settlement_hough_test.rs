@@ -0,0 +1,179 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    elastic_settlement::hough::*,
+    enums::{HoughSoilType, MethodId, PressureConvention, SelectionMethod},
+    models::{
+        foundation::Foundation,
+        soil_profile::{SoilLayer, SoilProfile},
+        spt::{NValue, SPTExp, SPT},
+    },
+};
+
+fn create_spt() -> SPT {
+    let mut exp = SPTExp::new(vec![], "exp1".to_string());
+    for (depth, n) in [(1.5, 12), (3.0, 15), (4.5, 20), (6.0, 25)] {
+        exp.add_blow(depth, NValue::Value(n));
+    }
+
+    let mut spt = SPT::new(1.0, 1.0, 1.0, SelectionMethod::Min);
+    spt.add_exp(exp);
+    spt
+}
+
+fn create_layer(thickness: f64, fine_content: f64, plasticity_index: Option<f64>) -> SoilLayer {
+    SoilLayer {
+        thickness: Some(thickness),
+        dry_unit_weight: Some(1.8),
+        saturated_unit_weight: Some(2.0),
+        fine_content: Some(fine_content),
+        plasticity_index,
+        ..Default::default()
+    }
+}
+
+fn create_foundation() -> Foundation {
+    Foundation {
+        foundation_depth: Some(1.5),
+        foundation_width: Some(2.0),
+        foundation_length: Some(2.0),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_calc_bearing_capacity_index() {
+    assert_abs_diff_eq!(
+        calc_bearing_capacity_index(15.0, HoughSoilType::SiltySand),
+        43.5,
+        epsilon = 1e-9
+    );
+    assert_abs_diff_eq!(
+        calc_bearing_capacity_index(30.0, HoughSoilType::WellGradedSand),
+        120.0,
+        epsilon = 1e-9
+    );
+    // Clamped to the chart
+    assert_abs_diff_eq!(
+        calc_bearing_capacity_index(80.0, HoughSoilType::InorganicSilt),
+        100.0,
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_classify_soil_type() {
+    assert_eq!(
+        classify_soil_type(&create_layer(1.0, 60.0, None)),
+        HoughSoilType::InorganicSilt
+    );
+    assert_eq!(
+        classify_soil_type(&create_layer(1.0, 20.0, None)),
+        HoughSoilType::SiltySand
+    );
+    assert_eq!(
+        classify_soil_type(&create_layer(1.0, 5.0, None)),
+        HoughSoilType::UniformFineSand
+    );
+    assert_eq!(
+        classify_soil_type(&SoilLayer::default()),
+        HoughSoilType::SiltySand
+    );
+}
+
+#[test]
+fn test_calc_layer_settlement() {
+    // 100 · 2 / 50 · log10((10 + 10) / 10)
+    assert_abs_diff_eq!(
+        calc_layer_settlement(2.0, 50.0, 10.0, 10.0),
+        1.20412,
+        epsilon = 1e-5
+    );
+    assert_abs_diff_eq!(
+        calc_layer_settlement(2.0, 50.0, 10.0, 0.0),
+        0.0,
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_calc_settlement() {
+    let profile = SoilProfile::new(vec![create_layer(10.0, 5.0, None)], 10.0);
+
+    let result = calc_settlement(
+        &create_spt(),
+        &profile,
+        &create_foundation(),
+        20.0,
+        PressureConvention::Gross,
+    )
+    .unwrap();
+
+    assert_eq!(result.method, MethodId::Hough);
+    assert_abs_diff_eq!(result.net_pressure, 17.3, epsilon = 1e-9);
+    assert!(!result.fully_compensated);
+    assert_eq!(result.layers.len(), 3);
+    assert_abs_diff_eq!(result.layers[0].top, 1.5, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.layers[0].bottom, 3.0, epsilon = 1e-9);
+    assert_eq!(result.layers[0].soil_type, HoughSoilType::UniformFineSand);
+
+    for layer in &result.layers {
+        assert_abs_diff_eq!(
+            layer.settlement,
+            calc_layer_settlement(
+                layer.bottom - layer.top,
+                layer.bearing_capacity_index,
+                layer.effective_stress,
+                layer.delta_stress
+            ),
+            epsilon = 1e-9
+        );
+    }
+    assert!(result.layers[0].delta_stress > result.layers[2].delta_stress);
+    assert!(result.total_settlement > 0.0);
+    assert_abs_diff_eq!(
+        result.total_settlement,
+        result.layers.iter().map(|l| l.settlement).sum::<f64>(),
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_calc_settlement_compensated() {
+    let profile = SoilProfile::new(vec![create_layer(10.0, 5.0, None)], 10.0);
+
+    let result = calc_settlement(
+        &create_spt(),
+        &profile,
+        &create_foundation(),
+        1.0,
+        PressureConvention::Gross,
+    )
+    .unwrap();
+
+    assert!(result.fully_compensated);
+    assert_abs_diff_eq!(result.total_settlement, 0.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_calc_settlement_skips_plastic_layers() {
+    let profile = SoilProfile::new(
+        vec![
+            create_layer(3.0, 5.0, None),
+            create_layer(1.5, 80.0, Some(25.0)),
+            create_layer(5.5, 5.0, None),
+        ],
+        10.0,
+    );
+
+    let result = calc_settlement(
+        &create_spt(),
+        &profile,
+        &create_foundation(),
+        20.0,
+        PressureConvention::Gross,
+    )
+    .unwrap();
+
+    assert_eq!(result.layers.len(), 2);
+    assert_abs_diff_eq!(result.layers[1].top, 4.5, epsilon = 1e-9);
+}
@@ -0,0 +1,131 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    elastic_settlement::janbu::*,
+    enums::{FoundationShape, MethodId, PressureConvention},
+    models::{
+        foundation::Foundation,
+        soil_profile::{SoilLayer, SoilProfile},
+    },
+};
+
+fn create_layer(thickness: f64) -> SoilLayer {
+    SoilLayer {
+        dry_unit_weight: Some(1.8),
+        saturated_unit_weight: Some(1.9),
+        elastic_modulus: Some(3000.0),
+        ..SoilLayer::new(thickness)
+    }
+}
+
+fn create_foundation() -> Foundation {
+    Foundation {
+        foundation_depth: Some(2.0),
+        foundation_width: Some(2.0),
+        foundation_length: Some(4.0),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_interpolate_mu0() {
+    assert_abs_diff_eq!(interpolate_mu0(0.0), 1.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(interpolate_mu0(1.0), 0.95, epsilon = 1e-9);
+    assert_abs_diff_eq!(interpolate_mu0(5.0), 0.8775, epsilon = 1e-9);
+    assert_abs_diff_eq!(interpolate_mu0(50.0), 0.855, epsilon = 1e-9);
+}
+
+#[test]
+fn test_interpolate_mu1() {
+    assert_abs_diff_eq!(interpolate_mu1(4.0, 0.5, false), 0.82, epsilon = 1e-9);
+    assert_abs_diff_eq!(interpolate_mu1(4.0, 1.0, false), 0.63, epsilon = 1e-9);
+    assert_abs_diff_eq!(interpolate_mu1(10.0, 0.0, false), 1.42, epsilon = 1e-9);
+    assert_abs_diff_eq!(interpolate_mu1(4.0, 1.0, true), 0.58, epsilon = 1e-9);
+    // H/B = 5 between 4 and 6, B/L = 0.75 between 0.5 and 1
+    assert_abs_diff_eq!(
+        interpolate_mu1(5.0, 0.75, false),
+        (0.85 + 0.65) / 2.0,
+        epsilon = 1e-9
+    );
+    assert_abs_diff_eq!(interpolate_mu1(0.0, 0.5, false), 0.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_calc_settlement() {
+    let mut profile = SoilProfile::new(vec![create_layer(2.0), create_layer(8.0)], 20.0);
+
+    let result = calc_settlement(
+        &mut profile,
+        &create_foundation(),
+        20.0,
+        PressureConvention::Gross,
+    )
+    .unwrap();
+
+    assert_eq!(result.method, MethodId::Janbu);
+    assert_abs_diff_eq!(result.settlement_per_layer[0], 0.0);
+    let expected = 100.0 * 0.95 * 0.82 * result.qnet * 2.0 / 3000.0;
+    assert_abs_diff_eq!(result.total_settlement, expected, epsilon = 1e-9);
+    assert!(result.warnings.is_empty());
+}
+
+#[test]
+fn test_calc_settlement_layered() {
+    let mut single = SoilProfile::new(vec![create_layer(2.0), create_layer(8.0)], 20.0);
+    let mut layered = SoilProfile::new(
+        vec![create_layer(2.0), create_layer(4.0), create_layer(4.0)],
+        20.0,
+    );
+
+    let single = calc_settlement(
+        &mut single,
+        &create_foundation(),
+        20.0,
+        PressureConvention::Gross,
+    )
+    .unwrap();
+    let layered = calc_settlement(
+        &mut layered,
+        &create_foundation(),
+        20.0,
+        PressureConvention::Gross,
+    )
+    .unwrap();
+
+    assert_abs_diff_eq!(
+        layered.total_settlement,
+        single.total_settlement,
+        epsilon = 1e-9
+    );
+    assert!(layered.settlement_per_layer[1] > layered.settlement_per_layer[2]);
+}
+
+#[test]
+fn test_calc_settlement_circular() {
+    let mut profile = SoilProfile::new(vec![create_layer(2.0), create_layer(8.0)], 20.0);
+    let foundation = Foundation {
+        shape: FoundationShape::Circular { diameter: 2.0 },
+        ..create_foundation()
+    };
+
+    let result =
+        calc_settlement(&mut profile, &foundation, 20.0, PressureConvention::Gross).unwrap();
+
+    let expected = 100.0 * 0.95 * 0.58 * result.qnet * 2.0 / 3000.0;
+    assert_abs_diff_eq!(result.total_settlement, expected, epsilon = 1e-9);
+}
+
+#[test]
+fn test_calc_settlement_compensated() {
+    let mut profile = SoilProfile::new(vec![create_layer(2.0), create_layer(8.0)], 20.0);
+
+    let result = calc_settlement(
+        &mut profile,
+        &create_foundation(),
+        1.0,
+        PressureConvention::Gross,
+    )
+    .unwrap();
+
+    assert!(result.fully_compensated);
+    assert_abs_diff_eq!(result.total_settlement, 0.0);
+}
@@ -0,0 +1,93 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    elastic_settlement::meyerhof_spt::*,
+    enums::{MethodId, PressureConvention, SelectionMethod},
+    models::{
+        foundation::Foundation,
+        soil_profile::{SoilLayer, SoilProfile},
+        spt::{NValue, SPTExp, SPT},
+    },
+};
+
+fn create_spt() -> SPT {
+    let mut exp = SPTExp::new(vec![], "exp1".to_string());
+    exp.add_blow(1.5, NValue::Value(18));
+    exp.add_blow(2.5, NValue::Value(22));
+    exp.add_blow(3.5, NValue::Value(30));
+
+    let mut spt = SPT::new(1.0, 1.0, 1.0, SelectionMethod::Min);
+    spt.add_exp(exp);
+    spt
+}
+
+fn create_profile() -> SoilProfile {
+    SoilProfile::new(
+        vec![SoilLayer {
+            thickness: Some(10.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(2.0),
+            ..Default::default()
+        }],
+        10.0,
+    )
+}
+
+fn create_foundation(width: f64) -> Foundation {
+    Foundation {
+        foundation_depth: Some(1.0),
+        foundation_width: Some(width),
+        foundation_length: Some(width),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_calc_settlement_wide_footing() {
+    // Net pressure 39.6031 − 1.8 = 37.8031 t/m² is the allowable pressure of N60 = 20 for 25 mm.
+    let result = calc_settlement(
+        &create_spt(),
+        &create_profile(),
+        &create_foundation(2.0),
+        39.6031,
+        PressureConvention::Gross,
+    )
+    .unwrap();
+
+    assert_abs_diff_eq!(result.n60, 20.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.depth_factor, 1.165, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.net_pressure, 37.8031, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.settlement, 2.5, epsilon = 1e-4);
+    assert_eq!(result.method, MethodId::MeyerhofSpt);
+}
+
+#[test]
+fn test_calc_settlement_narrow_footing() {
+    // S = 25 · q / (19.16 · N60 · Kd) mm with q = 10 t/m² = 98.0665 kPa and Kd = 1.33.
+    let result = calc_settlement(
+        &create_spt(),
+        &create_profile(),
+        &create_foundation(1.0),
+        11.8,
+        PressureConvention::Gross,
+    )
+    .unwrap();
+
+    assert_abs_diff_eq!(result.depth_factor, 1.33, epsilon = 1e-9);
+    let expected = 2.5 * 98.0665 / (19.16 * result.n60 * 1.33);
+    assert_abs_diff_eq!(result.settlement, expected, epsilon = 1e-4);
+}
+
+#[test]
+fn test_calc_settlement_compensated() {
+    let result = calc_settlement(
+        &create_spt(),
+        &create_profile(),
+        &create_foundation(2.0),
+        1.0,
+        PressureConvention::Gross,
+    )
+    .unwrap();
+
+    assert!(result.fully_compensated);
+    assert_abs_diff_eq!(result.settlement, 0.0, epsilon = 1e-9);
+}
@@ -0,0 +1,230 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    elastic_settlement::schmertmann::*,
+    enums::{PressureConvention, SoilType},
+    models::{
+        cpt::{CPTExp, CPTLayer},
+        foundation::Foundation,
+        soil_profile::{SoilLayer, SoilProfile},
+    },
+};
+
+fn create_cpt(depths: &[f64], qc: f64, fs: f64) -> CPTExp {
+    CPTExp::new(
+        depths
+            .iter()
+            .map(|&depth| CPTLayer::new(depth, qc, fs, None))
+            .collect(),
+        "CPT-1".to_string(),
+    )
+}
+
+fn create_profile() -> SoilProfile {
+    SoilProfile::new(
+        vec![SoilLayer {
+            thickness: Some(20.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(2.0),
+            ..Default::default()
+        }],
+        10.0,
+    )
+}
+
+fn create_foundation(width: f64, length: f64) -> Foundation {
+    Foundation {
+        foundation_depth: Some(1.0),
+        foundation_width: Some(width),
+        foundation_length: Some(length),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_calc_strain_influence_diagram() {
+    let square = calc_strain_influence_diagram(2.0, 2.0, 18.0, |_| 2.0);
+    assert_abs_diff_eq!(square.base_influence_factor, 0.1, epsilon = 1e-9);
+    assert_abs_diff_eq!(square.peak_influence_factor, 0.8, epsilon = 1e-9);
+    assert_abs_diff_eq!(square.peak_depth, 1.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(square.influence_depth, 4.0, epsilon = 1e-9);
+
+    let strip = calc_strain_influence_diagram(2.0, 30.0, 18.0, |_| 2.0);
+    assert_abs_diff_eq!(strip.base_influence_factor, 0.2, epsilon = 1e-9);
+    assert_abs_diff_eq!(strip.peak_depth, 2.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(strip.influence_depth, 8.0, epsilon = 1e-9);
+
+    // L / B = 5.5 lies halfway between the square and strip diagrams.
+    let rectangle = calc_strain_influence_diagram(2.0, 11.0, 18.0, |_| 2.0);
+    assert_abs_diff_eq!(rectangle.base_influence_factor, 0.15, epsilon = 1e-9);
+    assert_abs_diff_eq!(rectangle.influence_depth, 6.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_calc_influence_factor() {
+    let diagram = calc_strain_influence_diagram(2.0, 2.0, 18.0, |_| 2.0);
+
+    assert_abs_diff_eq!(diagram.calc_influence_factor(0.0), 0.1, epsilon = 1e-9);
+    assert_abs_diff_eq!(diagram.calc_influence_factor(0.5), 0.45, epsilon = 1e-9);
+    assert_abs_diff_eq!(diagram.calc_influence_factor(1.0), 0.8, epsilon = 1e-9);
+    assert_abs_diff_eq!(diagram.calc_influence_factor(2.5), 0.4, epsilon = 1e-9);
+    assert_abs_diff_eq!(diagram.calc_influence_factor(4.5), 0.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_calc_depth_factor() {
+    assert_abs_diff_eq!(calc_depth_factor(2.0, 20.0), 0.95, epsilon = 1e-9);
+    assert_abs_diff_eq!(calc_depth_factor(20.0, 10.0), 0.5, epsilon = 1e-9);
+}
+
+#[test]
+fn test_classify_soil_type_and_modulus() {
+    let sand = CPTLayer::new(2.0, 10.0, 0.05, None);
+    let clay = CPTLayer::new(2.0, 1.0, 0.04, None);
+
+    assert_eq!(classify_soil_type(&sand), SoilType::Granular);
+    assert_eq!(classify_soil_type(&clay), SoilType::Cohesive);
+
+    assert_abs_diff_eq!(
+        calc_elastic_modulus(10.0, SoilType::Granular, 2.0, 2.0),
+        2549.29,
+        epsilon = 1e-2
+    );
+    assert_abs_diff_eq!(
+        calc_elastic_modulus(10.0, SoilType::Granular, 2.0, 20.0),
+        3569.01,
+        epsilon = 1e-2
+    );
+    assert_abs_diff_eq!(
+        calc_elastic_modulus(1.0, SoilType::Cohesive, 2.0, 2.0),
+        509.86,
+        epsilon = 1e-2
+    );
+}
+
+#[test]
+fn test_calc_settlement() {
+    let cpt = create_cpt(&[2.0, 3.0, 4.0, 5.0, 6.0], 5.0, 0.025);
+    let profile = create_profile();
+    let foundation = create_foundation(2.0, 2.0);
+
+    let result = calc_settlement(
+        &cpt,
+        &profile,
+        &foundation,
+        20.0,
+        PressureConvention::Gross,
+        0.1,
+    )
+    .unwrap();
+
+    // Δp = 20 − 1.8, C1 = 1 − 0.5 · 1.8 / 18.2, Izp = 0.5 + 0.1 · √(18.2 / 3.6)
+    assert_abs_diff_eq!(result.net_pressure, 18.2, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.depth_factor, 0.950549, epsilon = 1e-6);
+    assert_abs_diff_eq!(result.creep_factor, 1.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(
+        result.diagram.peak_influence_factor,
+        0.724846,
+        epsilon = 1e-6
+    );
+
+    assert_eq!(result.sublayers.len(), 4);
+    assert_abs_diff_eq!(result.sublayers[0].top, 1.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.sublayers[3].bottom, 5.0, epsilon = 1e-9);
+    assert_eq!(result.sublayers[0].soil_type, SoilType::Granular);
+    assert_abs_diff_eq!(result.total_settlement, 2.0354, epsilon = 1e-4);
+    assert_abs_diff_eq!(
+        result.total_settlement,
+        result.sublayers.iter().map(|s| s.settlement).sum::<f64>(),
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_calc_settlement_with_creep() {
+    let cpt = create_cpt(&[2.0, 3.0, 4.0, 5.0, 6.0], 5.0, 0.025);
+    let profile = create_profile();
+    let foundation = create_foundation(2.0, 2.0);
+
+    let result = calc_settlement(
+        &cpt,
+        &profile,
+        &foundation,
+        20.0,
+        PressureConvention::Gross,
+        10.0,
+    )
+    .unwrap();
+
+    assert_abs_diff_eq!(result.creep_factor, 1.4, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.total_settlement, 2.8496, epsilon = 1e-4);
+}
+
+#[test]
+fn test_calc_settlement_short_sounding() {
+    // The deepest reading continues to the bottom of the influence zone.
+    let short = create_cpt(&[2.0, 3.0], 5.0, 0.025);
+    let long = create_cpt(&[2.0, 3.0, 4.0, 5.0], 5.0, 0.025);
+    let profile = create_profile();
+    let foundation = create_foundation(2.0, 2.0);
+
+    let short_result = calc_settlement(
+        &short,
+        &profile,
+        &foundation,
+        20.0,
+        PressureConvention::Gross,
+        0.1,
+    )
+    .unwrap();
+    let long_result = calc_settlement(
+        &long,
+        &profile,
+        &foundation,
+        20.0,
+        PressureConvention::Gross,
+        0.1,
+    )
+    .unwrap();
+
+    assert_abs_diff_eq!(short_result.sublayers[1].bottom, 5.0, epsilon = 1e-9);
+    assert!(short_result.total_settlement > 0.9 * long_result.total_settlement);
+}
+
+#[test]
+fn test_calc_settlement_compensated() {
+    let cpt = create_cpt(&[2.0, 3.0, 4.0, 5.0], 5.0, 0.025);
+    let profile = create_profile();
+    let foundation = create_foundation(2.0, 2.0);
+
+    let result = calc_settlement(
+        &cpt,
+        &profile,
+        &foundation,
+        1.0,
+        PressureConvention::Gross,
+        0.1,
+    )
+    .unwrap();
+
+    assert!(result.fully_compensated);
+    assert_abs_diff_eq!(result.total_settlement, 0.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_calc_settlement_invalid_cone_resistance() {
+    let cpt = create_cpt(&[2.0, 3.0], 0.0, 0.0);
+    let profile = create_profile();
+    let foundation = create_foundation(2.0, 2.0);
+
+    let err = calc_settlement(
+        &cpt,
+        &profile,
+        &foundation,
+        20.0,
+        PressureConvention::Gross,
+        0.1,
+    )
+    .unwrap_err();
+
+    assert_eq!(err.code, "schmertmann.cone_resistance.too_small.0.001");
+}
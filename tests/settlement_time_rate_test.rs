@@ -0,0 +1,132 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    consolidation_settlement::time_rate::*,
+    enums::DrainageCondition,
+    models::soil_profile::{SoilLayer, SoilProfile},
+};
+
+fn create_soil_profile() -> SoilProfile {
+    SoilProfile::new(
+        vec![
+            SoilLayer::new(2.0),
+            SoilLayer {
+                cv: Some(2.0),
+                ..SoilLayer::new(4.0)
+            },
+            SoilLayer {
+                cv: Some(0.25),
+                ..SoilLayer::new(2.0)
+            },
+        ],
+        1.0,
+    )
+}
+
+#[test]
+fn test_calc_degree_of_consolidation() {
+    assert_abs_diff_eq!(calc_degree_of_consolidation(0.0), 0.0);
+    assert_abs_diff_eq!(calc_degree_of_consolidation(0.05), 0.2523, epsilon = 1e-3);
+    assert_abs_diff_eq!(calc_degree_of_consolidation(0.197), 0.5, epsilon = 1e-3);
+    assert_abs_diff_eq!(calc_degree_of_consolidation(T90), 0.9, epsilon = 1e-3);
+    assert_abs_diff_eq!(calc_degree_of_consolidation(T95), 0.95, epsilon = 1e-3);
+    assert_abs_diff_eq!(calc_degree_of_consolidation(10.0), 1.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_calc_required_time_factor() {
+    assert_abs_diff_eq!(calc_required_time_factor(0.5), 0.19635, epsilon = 1e-5);
+    assert_abs_diff_eq!(calc_required_time_factor(0.9), T90, epsilon = 1e-9);
+    assert_abs_diff_eq!(calc_required_time_factor(0.95), 1.1289, epsilon = 1e-4);
+    assert!(calc_required_time_factor(1.0).is_infinite());
+}
+
+#[test]
+fn test_calc_drainage_path_and_time_factor() {
+    assert_abs_diff_eq!(calc_drainage_path(4.0, DrainageCondition::Single), 4.0);
+    assert_abs_diff_eq!(calc_drainage_path(4.0, DrainageCondition::Double), 2.0);
+    assert_abs_diff_eq!(calc_time_factor(2.0, 1.0, 2.0), 0.5);
+}
+
+#[test]
+fn test_calc_time_rate_single_layer() {
+    let profile = create_soil_profile();
+
+    let result = calc_time_rate(
+        &profile,
+        &[0.0, 10.0, 0.0],
+        DrainageCondition::Double,
+        &[0.0, 0.3938, 1.0],
+    )
+    .unwrap();
+
+    assert_eq!(result.layers.len(), 1);
+    assert_eq!(result.layers[0].layer_index, 1);
+    assert_abs_diff_eq!(result.layers[0].drainage_path, 2.0);
+    assert_abs_diff_eq!(result.layers[0].t90, 1.696, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.total_settlement, 10.0);
+
+    assert_abs_diff_eq!(result.points[0].settlement, 0.0);
+    // Tv = 2 * 0.3938 / 4 = 0.197 gives U = 50%
+    assert_abs_diff_eq!(
+        result.points[1].degree_of_consolidation,
+        0.5,
+        epsilon = 1e-3
+    );
+    assert_abs_diff_eq!(result.points[1].settlement, 5.0, epsilon = 1e-2);
+
+    assert_abs_diff_eq!(result.t90, 1.696, epsilon = 1e-3);
+    assert_abs_diff_eq!(result.t95, 2.258, epsilon = 2e-3);
+}
+
+#[test]
+fn test_calc_time_rate_layered() {
+    let profile = create_soil_profile();
+
+    let single = calc_time_rate(
+        &profile,
+        &[0.0, 10.0, 5.0],
+        DrainageCondition::Single,
+        &[1.0],
+    )
+    .unwrap();
+    let double = calc_time_rate(
+        &profile,
+        &[0.0, 10.0, 5.0],
+        DrainageCondition::Double,
+        &[1.0],
+    )
+    .unwrap();
+
+    assert_eq!(single.layers.len(), 2);
+    assert_abs_diff_eq!(single.total_settlement, 15.0);
+    assert!(single.points[0].settlement < double.points[0].settlement);
+    // The slower layer governs the profile
+    assert!(single.t90 > single.layers[0].t90 && single.t90 < single.layers[1].t90);
+    assert_abs_diff_eq!(double.t90 * 4.0, single.t90, epsilon = 1e-3);
+}
+
+#[test]
+fn test_calc_time_rate_validation() {
+    let profile = create_soil_profile();
+
+    let err = calc_time_rate(
+        &profile,
+        &[1.0, 10.0, 0.0],
+        DrainageCondition::Double,
+        &[1.0],
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "soil_profile.cv.missing");
+
+    let err = calc_time_rate(&profile, &[10.0], DrainageCondition::Double, &[1.0]).unwrap_err();
+    assert_eq!(err.code, "time_rate.settlement_per_layer.invalid_length");
+
+    let err = calc_time_rate(
+        &profile,
+        &[0.0, 10.0, 0.0],
+        DrainageCondition::Double,
+        &[-1.0],
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "time_rate.time.too_small.0");
+}
@@ -0,0 +1,94 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    models::soil_profile::{SoilLayer, SoilProfile},
+    shansep::*,
+};
+
+fn create_soil_profile() -> SoilProfile {
+    SoilProfile::new(
+        vec![
+            SoilLayer {
+                thickness: Some(4.0),
+                dry_unit_weight: Some(1.8),
+                saturated_unit_weight: Some(2.0),
+                plasticity_index: Some(25.0),
+                preconsolidation_pressure: Some(8.0),
+                ..Default::default()
+            },
+            SoilLayer {
+                thickness: Some(4.0),
+                dry_unit_weight: Some(1.8),
+                saturated_unit_weight: Some(2.0),
+                ..Default::default()
+            },
+        ],
+        0.0,
+    )
+}
+
+#[test]
+fn test_calc_undrained_strength() {
+    let params = ShansepParams::default();
+
+    assert_abs_diff_eq!(calc_ocr(5.0, 10.0), 2.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(calc_ocr(10.0, 5.0), 1.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(
+        calc_undrained_strength(10.0, 1.0, &params),
+        2.2,
+        epsilon = 1e-9
+    );
+    assert_abs_diff_eq!(
+        calc_undrained_strength(10.0, 2.0, &params),
+        2.2 * 2f64.powf(0.8),
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_calc_shansep_profile() {
+    let params = ShansepParams::default();
+    let mut soil_profile = create_soil_profile();
+    soil_profile.calc_layer_depths();
+    let initial_stress = soil_profile.calc_effective_stress(2.0);
+
+    // Before consolidation the clay keeps its initial stress history
+    let result = calc_shansep_profile(&soil_profile, 10.0, 0.0, &params).unwrap();
+    assert_eq!(result.layers.len(), 1);
+    let layer = &result.layers[0];
+    assert_abs_diff_eq!(layer.effective_stress, initial_stress, epsilon = 1e-9);
+    assert_abs_diff_eq!(layer.ocr, 8.0 / initial_stress, epsilon = 1e-9);
+    assert_abs_diff_eq!(
+        result.strengthened_profile.layers[0].cu.unwrap(),
+        layer.cu,
+        epsilon = 1e-9
+    );
+    assert!(result.strengthened_profile.layers[1].cu.is_none());
+
+    // Full consolidation under 10 t/m² makes the clay normally consolidated
+    let consolidated = calc_shansep_profile(&soil_profile, 10.0, 1.0, &params).unwrap();
+    let layer = &consolidated.layers[0];
+    assert_abs_diff_eq!(
+        layer.effective_stress,
+        initial_stress + 10.0,
+        epsilon = 1e-9
+    );
+    assert_abs_diff_eq!(layer.ocr, 1.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(layer.cu, 0.22 * (initial_stress + 10.0), epsilon = 1e-9);
+    assert!(layer.cu > result.layers[0].cu);
+
+    // The next stage keeps the raised preconsolidation pressure
+    let unloaded =
+        calc_shansep_profile(&consolidated.strengthened_profile, 0.0, 0.0, &params).unwrap();
+    assert_abs_diff_eq!(
+        unloaded.layers[0].preconsolidation_pressure,
+        initial_stress + 10.0,
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_calc_shansep_profile_invalid_input() {
+    let err = calc_shansep_profile(&create_soil_profile(), 10.0, 1.5, &ShansepParams::default())
+        .unwrap_err();
+    assert_eq!(err.code, "shansep.degree_of_consolidation.too_large.1");
+}
@@ -1,4 +1,7 @@
-use soilrust::soil_coefficient::{calc_by_bearing_capacity, calc_by_settlement};
+use soilrust::soil_coefficient::{
+    calc_by_bearing_capacity, calc_by_settlement, calc_cyclic_degradation,
+    calc_strain_reduction_factor, KsDegradationParams,
+};
 
 #[test]
 fn test_calc_soil_coefficient_by_settlement_positive() {
@@ -22,3 +25,34 @@ fn test_calc_soil_coefficient_by_bearing_capacity() {
     let result = calc_by_bearing_capacity(bearing_capacity);
     assert!((result - 100_000.0).abs() < 1e-6);
 }
+
+#[test]
+fn test_calc_strain_reduction_factor() {
+    assert!((calc_strain_reduction_factor(0.0, 0.1) - 1.0).abs() < 1e-9);
+    assert!((calc_strain_reduction_factor(0.1, 0.1) - 0.5).abs() < 1e-9);
+}
+
+#[test]
+fn test_calc_cyclic_degradation() {
+    let params = KsDegradationParams {
+        t: 0.1,
+        reference_strain: 0.1,
+    };
+    let result = calc_cyclic_degradation(5000.0, 1000.0, 0.05, &params).unwrap();
+
+    // 1000^(-0.1) = 0.5012, 1 / (1 + 0.05 / 0.1) = 0.6667
+    assert!((result.cycle_factor - 0.501187).abs() < 1e-6);
+    assert!((result.strain_factor - 2.0 / 3.0).abs() < 1e-9);
+    assert!((result.degraded_ks - 1670.62).abs() < 1e-2);
+}
+
+#[test]
+fn test_calc_cyclic_degradation_single_cycle() {
+    let result =
+        calc_cyclic_degradation(5000.0, 1.0, 0.0, &KsDegradationParams::default()).unwrap();
+    assert!((result.degraded_ks - 5000.0).abs() < 1e-9);
+
+    let err =
+        calc_cyclic_degradation(5000.0, 0.5, 0.0, &KsDegradationParams::default()).unwrap_err();
+    assert_eq!(err.code, "soil_coefficient.number_of_cycles.too_small.1");
+}
@@ -1,3 +1,4 @@
+use soilrust::models::groundwater::GroundwaterSample;
 use soilrust::models::soil_profile::{SoilLayer, SoilProfile};
 
 /// Creates a reusable soil profile for testing.
@@ -55,3 +56,126 @@ fn test_calc_effective_stress() {
     assert!((profile.calc_effective_stress(2.0) - 3.6).abs() < 1e-3);
     assert!((profile.calc_effective_stress(3.0) - 4.8595).abs() < 1e-3);
 }
+
+#[test]
+fn test_rock_layer() {
+    use soilrust::enums::{MaterialType, WeatheringGrade};
+
+    let mut layer = SoilLayer {
+        thickness: Some(2.0),
+        elastic_modulus: Some(1000.0),
+        rock_mass_modulus: Some(50000.0),
+        material_type: Some(MaterialType::Rock),
+        weathering_grade: Some(WeatheringGrade::SlightlyWeathered),
+        ..Default::default()
+    };
+    assert!(layer.is_rock());
+    assert_eq!(layer.get_elastic_modulus(), Some(50000.0));
+
+    // Completely weathered rock behaves as soil
+    layer.weathering_grade = Some(WeatheringGrade::CompletelyWeathered);
+    assert!(!layer.is_rock());
+    assert_eq!(layer.get_elastic_modulus(), Some(1000.0));
+
+    let soil = SoilLayer::new(1.0);
+    assert!(!soil.is_rock());
+}
+
+#[test]
+fn test_slice() {
+    let profile = setup_soil_profile();
+    let sliced = profile.slice(1.0, 4.0).unwrap();
+
+    assert_eq!(sliced.layers.len(), 2);
+    assert!((sliced.layers[0].thickness.unwrap() - 1.0).abs() < 1e-9);
+    assert!((sliced.layers[1].thickness.unwrap() - 2.0).abs() < 1e-9);
+    assert!((sliced.layers[1].depth.unwrap() - 3.0).abs() < 1e-9);
+    assert!((sliced.ground_water_level.unwrap() - 1.5).abs() < 1e-9);
+}
+
+#[test]
+fn test_slice_groundwater_samples() {
+    let mut profile = setup_soil_profile();
+    profile.groundwater_samples = vec![
+        GroundwaterSample::new(0.5, 7.0, 100.0, 50.0),
+        GroundwaterSample::new(3.0, 6.0, 400.0, 50.0),
+    ];
+    let sliced = profile.slice(1.0, 4.0).unwrap();
+
+    assert_eq!(sliced.groundwater_samples.len(), 1);
+    assert!((sliced.groundwater_samples[0].depth.unwrap() - 2.0).abs() < 1e-9);
+    assert_eq!(sliced.groundwater_samples[0].sulfate, Some(400.0));
+}
+
+#[test]
+fn test_slice_below() {
+    let profile = setup_soil_profile();
+    let sliced = profile.slice_below(3.0).unwrap();
+
+    assert_eq!(sliced.layers.len(), 1);
+    assert!((sliced.layers[0].thickness.unwrap() - 2.0).abs() < 1e-9);
+    assert_eq!(sliced.ground_water_level.unwrap(), 0.0);
+    // 1.8 · 2 + 1.6 · 0.5 + 1.9 · 0.5 of overburden and 1.9 · 1 of the remaining layer
+    assert!((sliced.overburden_stress.unwrap() - 5.35).abs() < 1e-9);
+    assert!((sliced.calc_normal_stress(1.0) - 7.25).abs() < 1e-9);
+}
+
+#[test]
+fn test_slice_keeps_stresses() {
+    let profile = setup_soil_profile();
+
+    // Groundwater level below and above the top of the slice
+    for top in [1.0, 3.0] {
+        let sliced = profile.slice_below(top).unwrap();
+        assert!(
+            (sliced.calc_effective_stress(0.0) - profile.calc_effective_stress(top)).abs() < 1e-9
+        );
+        for depth in [3.5, 4.0, 5.0] {
+            assert!(
+                (sliced.calc_normal_stress(depth - top) - profile.calc_normal_stress(depth)).abs()
+                    < 1e-9
+            );
+            assert!(
+                (sliced.calc_effective_stress(depth - top) - profile.calc_effective_stress(depth))
+                    .abs()
+                    < 1e-9
+            );
+        }
+    }
+}
+
+#[test]
+fn test_slice_out_of_range() {
+    let profile = setup_soil_profile();
+
+    assert_eq!(
+        profile.slice(5.0, 8.0).unwrap_err().code,
+        "soil_profile.slice.empty"
+    );
+    assert!(profile.slice(-1.0, 2.0).is_err());
+}
+
+#[test]
+fn test_is_undrained() {
+    use soilrust::enums::{AnalysisTerm, LoadingRate};
+
+    let clay = SoilLayer {
+        thickness: Some(2.0),
+        plasticity_index: Some(20.0),
+        ..Default::default()
+    };
+    let sand = SoilLayer {
+        thickness: Some(2.0),
+        fine_content: Some(10.0),
+        ..Default::default()
+    };
+
+    assert!(clay.is_cohesive());
+    assert!(!sand.is_cohesive());
+
+    assert!(clay.is_undrained(AnalysisTerm::Auto(LoadingRate::Rapid)));
+    assert!(!clay.is_undrained(AnalysisTerm::Auto(LoadingRate::Slow)));
+    assert!(!sand.is_undrained(AnalysisTerm::Auto(LoadingRate::Rapid)));
+    assert!(sand.is_undrained(AnalysisTerm::Short));
+    assert!(!clay.is_undrained(AnalysisTerm::Long));
+}
@@ -0,0 +1,82 @@
+use approx::assert_abs_diff_eq;
+use std::f64::consts::PI;
+
+use soilrust::{
+    enums::SoilType,
+    spt_anchor::{
+        calc_screw_anchor_capacity, calc_tieback_capacity, get_bond_stress_from_spt,
+        get_helix_bearing_from_spt,
+    },
+};
+
+#[test]
+fn test_bond_stress_from_spt() {
+    // 0.5 · 4.5 · 20 = 45 kPa
+    assert_abs_diff_eq!(
+        get_bond_stress_from_spt(20.0, SoilType::Cohesive),
+        4.5887,
+        epsilon = 1e-4
+    );
+    // 5 · 30 = 150 kPa
+    assert_abs_diff_eq!(
+        get_bond_stress_from_spt(30.0, SoilType::Granular),
+        15.2957,
+        epsilon = 1e-4
+    );
+    // Limited to 380 kPa
+    assert_abs_diff_eq!(
+        get_bond_stress_from_spt(100.0, SoilType::Granular),
+        38.7492,
+        epsilon = 1e-4
+    );
+}
+
+#[test]
+fn test_helix_bearing_from_spt() {
+    // 9 · 4.5 · 10 = 405 kPa
+    assert_abs_diff_eq!(
+        get_helix_bearing_from_spt(10.0, SoilType::Cohesive, 0.3, 5.0),
+        41.2985,
+        epsilon = 1e-4
+    );
+    // 40 · 20 · 3 / 0.3 = 8000 kPa, limited to 400 · 20 = 8000 kPa
+    assert_abs_diff_eq!(
+        get_helix_bearing_from_spt(20.0, SoilType::Granular, 0.3, 3.0),
+        815.7730,
+        epsilon = 1e-3
+    );
+    // 40 · 20 · 1.5 / 0.3 = 4000 kPa
+    assert_abs_diff_eq!(
+        get_helix_bearing_from_spt(20.0, SoilType::Granular, 0.3, 1.5),
+        407.8865,
+        epsilon = 1e-3
+    );
+}
+
+#[test]
+fn test_tieback_capacity() {
+    let result = calc_tieback_capacity(30.0, SoilType::Granular, 0.15, 8.0, 2.0).unwrap();
+
+    let expected = PI * 0.15 * 8.0 * 15.2957;
+    assert_abs_diff_eq!(result.ultimate_capacity, expected, epsilon = 1e-3);
+    assert_abs_diff_eq!(result.allowable_capacity, expected / 2.0, epsilon = 1e-3);
+    assert!(result.warnings.is_empty());
+
+    let result = calc_tieback_capacity(60.0, SoilType::Granular, 0.15, 8.0, 2.0).unwrap();
+    assert_eq!(result.warnings[0].code, "applicability.n60.out_of_range");
+}
+
+#[test]
+fn test_screw_anchor_capacity() {
+    let result =
+        calc_screw_anchor_capacity(10.0, SoilType::Cohesive, &[0.3, 0.35], 5.0, 2.0).unwrap();
+
+    let expected = 41.2985 * PI * (0.3_f64.powi(2) + 0.35_f64.powi(2)) / 4.0;
+    assert_abs_diff_eq!(result.ultimate_capacity, expected, epsilon = 1e-3);
+
+    let err = calc_screw_anchor_capacity(10.0, SoilType::Rock, &[0.3], 5.0, 2.0).unwrap_err();
+    assert_eq!(err.code, "spt_anchor.soil_type.rock");
+
+    let err = calc_screw_anchor_capacity(10.0, SoilType::Cohesive, &[], 5.0, 2.0).unwrap_err();
+    assert_eq!(err.code, "spt_anchor.helix_diameters.empty");
+}
@@ -135,6 +135,7 @@ fn test_apply_corrections() {
             ..Default::default()
         }],
         ground_water_level: Some(10.0),
+        ..Default::default()
     };
     let cs = 0.9;
     let cb = 1.05;
@@ -150,6 +151,60 @@ fn test_apply_corrections() {
     assert_eq!(spt.n1_60.unwrap().to_i32(), 20);
     assert_eq!(spt.n1_60f.unwrap().to_i32(), 22);
 }
+
+#[test]
+fn test_apply_corrections_with_pipeline() {
+    let soil_profile = SoilProfile {
+        layers: vec![soil_profile::SoilLayer {
+            thickness: Some(10.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(2.0),
+            fine_content: Some(10.0),
+            ..Default::default()
+        }],
+        ground_water_level: Some(10.0),
+        ..Default::default()
+    };
+
+    // Without steps the result matches the standard corrections
+    let mut standard = SPTBlow::new(10.0, NValue::from_i32(25));
+    standard.apply_corrections(&soil_profile, 0.9, 1.05, 1.2);
+    let mut empty = SPTBlow::new(10.0, NValue::from_i32(25));
+    empty.apply_corrections_with(&soil_profile, 0.9, 1.05, 1.2, &CorrectionPipeline::new());
+    assert_eq!(empty.n1_60, standard.n1_60);
+    assert_eq!(empty.custom_factor, Some(1.0));
+
+    // An ageing factor and a depth-dependent regional factor
+    struct AgeingFactor(f64);
+    impl BlowCorrection for AgeingFactor {
+        fn factor(&self, _blow: &SPTBlow, _soil_profile: &SoilProfile) -> f64 {
+            self.0
+        }
+    }
+    let mut pipeline = CorrectionPipeline::new();
+    pipeline
+        .register(AgeingFactor(1.2))
+        .register(
+            |blow: &SPTBlow, _: &SoilProfile| {
+                if blow.depth.unwrap() > 5.0 {
+                    1.1
+                } else {
+                    1.0
+                }
+            },
+        );
+    assert_eq!(pipeline.len(), 2);
+
+    let mut exp = SPTExp::new(vec![], "exp1".to_string());
+    exp.add_blow(2.0, NValue::from_i32(25));
+    exp.add_blow(10.0, NValue::from_i32(25));
+    exp.apply_corrections_with(&soil_profile, 0.9, 1.05, 1.2, &pipeline);
+
+    assert!((exp.blows[0].custom_factor.unwrap() - 1.2).abs() < 1e-9);
+    assert!((exp.blows[1].custom_factor.unwrap() - 1.32).abs() < 1e-9);
+    // N1,60 = ceil(30 · 0.735 · 0.95 · 0.9 · 1.05 · 1.32)
+    assert_eq!(exp.blows[1].n1_60.unwrap().to_i32(), 27);
+}
 // -------------------------------------------------------------------------------------------
 
 // Test SPT
@@ -211,3 +266,80 @@ fn test_get_idealized_exp() {
     assert_eq!(idealized_exp_max.blows[1].n, Some(NValue::Value(20)));
     assert_eq!(idealized_exp_max.blows[2].n, Some(NValue::Refusal));
 }
+
+#[test]
+fn test_get_idealized_exp_with_provenance() {
+    let mut exp1 = SPTExp::new(vec![], "BH-1".to_string());
+    exp1.add_blow(1.5, NValue::Value(10));
+    exp1.add_blow(3., NValue::Refusal);
+
+    let mut exp2 = SPTExp::new(vec![], "BH-2".to_string());
+    exp2.add_blow(1.5, NValue::Value(15));
+    exp2.add_blow(3., NValue::Value(14));
+
+    let mut spt = SPT::new(1.0, 1.0, 1.0, SelectionMethod::Min);
+    spt.add_exp(exp1);
+    spt.add_exp(exp2);
+
+    let (exp, provenance) = spt.get_idealized_exp_with_provenance("idealized".to_string());
+
+    assert_eq!(provenance.len(), exp.blows.len());
+    assert_eq!(provenance[0].depth, 1.5);
+    assert_eq!(provenance[0].selected, NValue::Value(10));
+    assert_eq!(
+        provenance[0].sources,
+        vec![
+            SourceValue {
+                exp_name: "BH-1".to_string(),
+                n: NValue::Value(10),
+            },
+            SourceValue {
+                exp_name: "BH-2".to_string(),
+                n: NValue::Value(15),
+            },
+        ]
+    );
+    assert_eq!(provenance[0].contributors, vec!["BH-1".to_string()]);
+    assert_eq!(provenance[1].contributors, vec!["BH-2".to_string()]);
+
+    spt.idealization_method = SelectionMethod::Avg;
+    let (_, provenance) = spt.get_idealized_exp_with_provenance("idealized".to_string());
+    assert_eq!(provenance[0].selected, NValue::Value(13));
+    assert_eq!(provenance[0].contributors.len(), 2);
+}
+
+#[test]
+fn test_energy_calibration() {
+    let mut calibration = EnergyCalibration::default();
+    calibration.add_measurement("BH-1", 5.0, 90.0);
+    calibration.add_measurement("BH-1", 1.0, 60.0);
+
+    assert_eq!(calibration.get_energy_ratio("BH-1", 0.5), Some(60.0));
+    assert_eq!(calibration.get_energy_ratio("BH-1", 3.0), Some(75.0));
+    assert_eq!(calibration.get_correction_factor("BH-1", 6.0), Some(1.5));
+    assert_eq!(calibration.get_energy_ratio("BH-2", 3.0), None);
+
+    let mut exp1 = SPTExp::new(vec![], "BH-1".to_string());
+    exp1.add_blow(3., NValue::Value(10));
+    exp1.add_blow(6., NValue::Value(10));
+    let mut exp2 = SPTExp::new(vec![], "BH-2".to_string());
+    exp2.add_blow(3., NValue::Value(10));
+
+    let mut spt = SPT::new(0.9, 1.0, 1.0, SelectionMethod::Min);
+    spt.add_exp(exp1);
+    spt.add_exp(exp2);
+
+    let calibrated = spt.with_energy_calibration(&calibration).unwrap();
+
+    assert_eq!(calibrated.energy_correction_factor, Some(1.0));
+    assert_eq!(calibrated.exps[0].blows[0].n, Some(NValue::Value(13)));
+    assert_eq!(calibrated.exps[0].blows[1].n, Some(NValue::Value(15)));
+    // No calibration for BH-2, the global factor applies
+    assert_eq!(calibrated.exps[1].blows[0].n, Some(NValue::Value(9)));
+    // Raw blows are left untouched
+    assert_eq!(spt.exps[0].blows[0].n, Some(NValue::Value(10)));
+
+    calibration.add_measurement("BH-2", 1.0, 120.0);
+    let err = spt.with_energy_calibration(&calibration).unwrap_err();
+    assert_eq!(err.code, "energy_calibration.etr.too_large.100");
+}
@@ -0,0 +1,42 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{elastic_settlement::stress_bulb::*, models::foundation::Foundation};
+
+fn create_foundation() -> Foundation {
+    Foundation {
+        foundation_depth: Some(1.0),
+        foundation_width: Some(2.0),
+        foundation_length: Some(2.0),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_calc_stress_bulb() {
+    let result = calc_stress_bulb(&create_foundation(), 10.0, 3.0, 6.0, 0.5).unwrap();
+
+    assert_eq!(result.offsets.len(), 13);
+    assert_eq!(result.depths.len(), 13);
+    assert_eq!(result.delta_stress.len(), 13);
+    assert_eq!(result.delta_stress[0].len(), 13);
+
+    // Full pressure below the base, none outside the footing at the surface
+    assert_abs_diff_eq!(result.delta_stress[0][6], 10.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.delta_stress[0][0], 0.0, epsilon = 1e-9);
+
+    // Below the center of a square at z = B: Δσ / q = 4 · I(0.5, 0.5) = 0.336
+    assert_abs_diff_eq!(result.influence_factors[4][6], 0.336, epsilon = 1e-3);
+
+    // The bulb is symmetric about the center line and decays with depth
+    assert_abs_diff_eq!(
+        result.delta_stress[4][2],
+        result.delta_stress[4][10],
+        epsilon = 1e-9
+    );
+    assert!(result.delta_stress[12][6] < result.delta_stress[4][6]);
+}
+
+#[test]
+fn test_invalid_spacing() {
+    let err = calc_stress_bulb(&create_foundation(), 10.0, 3.0, 6.0, 0.0).unwrap_err();
+    assert_eq!(err.code, "stress_bulb.spacing.too_small.0.0001");
+}
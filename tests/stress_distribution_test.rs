@@ -0,0 +1,104 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    consolidation_settlement::helper_functions::calc_rectangle_stress, stress_distribution::*,
+};
+
+#[test]
+fn test_calc_point_load_stress() {
+    // Directly below the load: 3P / (2π z²)
+    assert_abs_diff_eq!(
+        calc_point_load_stress(10.0, 0.0, 2.0),
+        30.0 / (8.0 * std::f64::consts::PI),
+        epsilon = 1e-9
+    );
+    assert!(calc_point_load_stress(10.0, 2.0, 2.0) < calc_point_load_stress(10.0, 0.0, 2.0));
+}
+
+#[test]
+fn test_rectangle_matches_closed_form() {
+    let area = LoadedArea::rectangle(0.0, 0.0, 4.0, 6.0, 10.0);
+    assert_abs_diff_eq!(area.calc_area(), 24.0, epsilon = 1e-9);
+
+    for (x, y, depth) in [
+        (0.0, 0.0, 3.0),
+        (2.0, 3.0, 1.0),
+        (1.0, -2.5, 0.5),
+        (5.0, 1.0, 4.0),
+        (-3.0, -6.0, 10.0),
+    ] {
+        let expected = calc_rectangle_stress(10.0, -2.0 - x, 2.0 - x, -3.0 - y, 3.0 - y, depth);
+        let stress = calc_area_stress(&area, x, y, depth);
+        assert_abs_diff_eq!(stress, expected, epsilon = 1e-4);
+    }
+}
+
+#[test]
+fn test_l_shaped_area() {
+    // L-shape made of a 4 x 2 m and a 2 x 2 m rectangle, listed clockwise
+    let l_shape = LoadedArea::new(
+        vec![
+            (0.0, 0.0),
+            (0.0, 4.0),
+            (2.0, 4.0),
+            (2.0, 2.0),
+            (4.0, 2.0),
+            (4.0, 0.0),
+        ],
+        8.0,
+    );
+    let parts = [
+        LoadedArea::rectangle(2.0, 1.0, 4.0, 2.0, 8.0),
+        LoadedArea::rectangle(1.0, 3.0, 2.0, 2.0, 8.0),
+    ];
+    assert_abs_diff_eq!(l_shape.calc_area(), 12.0, epsilon = 1e-9);
+
+    // Inside, in the notch and far outside the L-shape
+    for (x, y) in [(1.0, 1.0), (3.0, 3.0), (-5.0, 2.0)] {
+        let stress = calc_area_stress(&l_shape, x, y, 2.0);
+        let expected = calc_vertical_stress(&parts, x, y, 2.0).unwrap();
+        assert_abs_diff_eq!(stress, expected, epsilon = 1e-4);
+    }
+}
+
+#[test]
+fn test_calc_stress_profile() {
+    let trapezoid = LoadedArea::new(
+        vec![(-6.0, 0.0), (6.0, 0.0), (3.0, 20.0), (-3.0, 20.0)],
+        5.0,
+    );
+
+    let profile = calc_stress_profile(&[trapezoid], 0.0, 10.0, &[0.01, 2.0, 5.0, 20.0]).unwrap();
+
+    assert_abs_diff_eq!(profile[0], 5.0, epsilon = 1e-2);
+    assert!(profile.windows(2).all(|w| w[1] < w[0]));
+}
+
+#[test]
+fn test_invalid_input() {
+    let line = LoadedArea::new(vec![(0.0, 0.0), (1.0, 0.0)], 5.0);
+    assert_eq!(
+        calc_vertical_stress(&[line], 0.0, 0.0, 1.0)
+            .unwrap_err()
+            .code,
+        "loaded_area.vertices.too_few"
+    );
+
+    let collinear = LoadedArea::new(vec![(0.0, 0.0), (1.0, 0.0), (2.0, 0.0)], 5.0);
+    assert_eq!(
+        collinear.validate().unwrap_err().code,
+        "loaded_area.vertices.zero_area"
+    );
+
+    assert_eq!(
+        calc_vertical_stress(&[], 0.0, 0.0, 1.0).unwrap_err().code,
+        "stress_distribution.empty_areas"
+    );
+
+    let area = LoadedArea::rectangle(0.0, 0.0, 2.0, 2.0, 5.0);
+    assert_eq!(
+        calc_vertical_stress(&[area], 0.0, 0.0, 0.0)
+            .unwrap_err()
+            .code,
+        "stress_distribution.depth.too_small.0.001"
+    );
+}
@@ -0,0 +1,108 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    bearing_capacity::vesic,
+    enums::{AnalysisTerm, ChemicalExposureClass, MethodId, PressureConvention},
+    models::{
+        foundation::Foundation,
+        groundwater::GroundwaterSample,
+        loads::Loads,
+        site::Site,
+        soil_profile::{SoilLayer, SoilProfile},
+    },
+    summary::*,
+};
+
+fn create_site() -> Site {
+    Site::new(SoilProfile::new(
+        vec![SoilLayer {
+            thickness: Some(20.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(2.0),
+            cu: Some(10.0),
+            phi_u: Some(0.0),
+            c_prime: Some(1.0),
+            phi_prime: Some(28.0),
+            elastic_modulus: Some(2000.0),
+            poissons_ratio: Some(0.3),
+            ..Default::default()
+        }],
+        10.0,
+    ))
+}
+
+fn create_foundation() -> Foundation {
+    Foundation {
+        foundation_depth: Some(1.5),
+        foundation_width: Some(2.0),
+        foundation_length: Some(2.0),
+        surface_friction_coefficient: Some(0.5),
+        ..Default::default()
+    }
+}
+
+fn create_loads() -> Loads {
+    Loads {
+        vertical_load: Some(80.0),
+        horizontal_load_x: Some(5.0),
+        horizontal_load_y: Some(2.0),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_summarize() {
+    let summary = summarize(&create_site(), &create_foundation(), &create_loads()).unwrap();
+
+    assert_abs_diff_eq!(summary.foundation_pressure, 20.0, epsilon = 1e-9);
+
+    // The governing capacity matches the corresponding single analysis
+    let single = vesic::calc_bearing_capacity(
+        &mut create_site().soil_profile,
+        &mut create_foundation(),
+        &create_loads(),
+        20.0,
+        PressureConvention::Gross,
+        SUMMARY_FACTOR_OF_SAFETY,
+        AnalysisTerm::Short,
+    )
+    .unwrap();
+    assert_eq!(summary.bearing_capacity_method, MethodId::Vesic);
+    assert!(matches!(summary.bearing_capacity_term, AnalysisTerm::Short));
+    assert_abs_diff_eq!(
+        summary.allowable_bearing_capacity,
+        single.allowable_bearing_capacity,
+        epsilon = 1e-9
+    );
+
+    let total = summary.total_settlement.unwrap();
+    let differential = summary.differential_settlement.unwrap();
+    assert!(total > 0.0 && differential > 0.0 && differential < total);
+
+    assert_eq!(summary.soil_class.as_deref(), Some("ZD"));
+    assert!(summary.sliding_utilization.unwrap() > 0.0);
+
+    // No SPT or MASW data for a liquefaction analysis
+    assert!(summary.min_liquefaction_safety_factor.is_none());
+    // No groundwater samples for an aggressivity classification
+    assert!(summary.groundwater_exposure_class.is_none());
+}
+
+#[test]
+fn test_summarize_groundwater_aggressivity() {
+    let mut site = create_site();
+    site.soil_profile.groundwater_samples = vec![GroundwaterSample::new(12.0, 6.0, 800.0, 100.0)];
+
+    let summary = summarize(&site, &create_foundation(), &create_loads()).unwrap();
+
+    assert_eq!(
+        summary.groundwater_exposure_class,
+        Some(ChemicalExposureClass::Xa2)
+    );
+    assert_eq!(summary.is_groundwater_corrosive, Some(false));
+}
+
+#[test]
+fn test_summarize_missing_load() {
+    let err = summarize(&create_site(), &create_foundation(), &Loads::default()).unwrap_err();
+    assert_eq!(err.code, "summary.foundation_pressure.missing");
+}
@@ -1,10 +1,11 @@
 use approx::assert_abs_diff_eq;
 use soilrust::{
+    enums::{PressureConvention, StressDistribution},
     models::{
         foundation::Foundation,
         soil_profile::{SoilLayer, SoilProfile},
     },
-    swelling_potential::calc_swelling_potential,
+    swelling_potential::{calc_swelling_potential, calc_swelling_potential_with_distribution},
 };
 
 fn create_soil_profile() -> SoilProfile {
@@ -42,6 +43,7 @@ fn create_soil_profile() -> SoilProfile {
                 ..Default::default()
             },
         ],
+        ..Default::default()
     }
 }
 fn create_foundation_data() -> Foundation {
@@ -59,8 +61,13 @@ fn test_calc_swelling_potential() {
     let foundation_data = create_foundation_data();
     let foundation_pressure = 50.;
 
-    let result =
-        calc_swelling_potential(&mut soil_profile, &foundation_data, foundation_pressure).unwrap();
+    let result = calc_swelling_potential(
+        &mut soil_profile,
+        &foundation_data,
+        foundation_pressure,
+        PressureConvention::Gross,
+    )
+    .unwrap();
     let expected_pressure = 8.89;
     assert_abs_diff_eq!(
         result.data[0].swelling_pressure,
@@ -68,3 +75,60 @@ fn test_calc_swelling_potential() {
         epsilon = 0.01
     );
 }
+
+#[test]
+fn test_swelling_potential_warnings() {
+    let mut soil_profile = create_soil_profile();
+    let foundation_data = create_foundation_data();
+
+    let result = calc_swelling_potential(
+        &mut soil_profile,
+        &foundation_data,
+        50.,
+        PressureConvention::Gross,
+    )
+    .unwrap();
+
+    assert!(result
+        .warnings
+        .iter()
+        .any(|w| w.parameter == "water_content"
+            && w.code == "applicability.water_content.out_of_range"
+            && (w.value - 75.4).abs() < 1e-9));
+    assert!(!result.warnings.iter().any(|w| w.value == 23.7));
+}
+
+#[test]
+fn test_swelling_potential_with_distribution() {
+    let foundation_data = create_foundation_data();
+
+    let two_to_one = calc_swelling_potential(
+        &mut create_soil_profile(),
+        &foundation_data,
+        50.,
+        PressureConvention::Gross,
+    )
+    .unwrap();
+    let boussinesq = calc_swelling_potential_with_distribution(
+        &mut create_soil_profile(),
+        &foundation_data,
+        50.,
+        PressureConvention::Gross,
+        StressDistribution::BoussinesqRectangular,
+    )
+    .unwrap();
+
+    // Layer center at 5.5 m, 3.5 m below the base
+    let q_net = two_to_one.net_foundation_pressure;
+    assert_abs_diff_eq!(
+        two_to_one.data[1].delta_stress,
+        q_net * 200.0 / (13.5 * 23.5),
+        epsilon = 1e-9
+    );
+    assert!(boussinesq.data[1].delta_stress > two_to_one.data[1].delta_stress);
+    assert_abs_diff_eq!(
+        boussinesq.data[1].swelling_pressure,
+        two_to_one.data[1].swelling_pressure,
+        epsilon = 1e-9
+    );
+}
@@ -0,0 +1,103 @@
+use soilrust::{
+    bearing_capacity::vesic,
+    consolidation_settlement::by_compression_index,
+    elastic_settlement::boussinesq,
+    enums::{AnalysisTerm, PressureConvention, ProfileStyle},
+    models::{foundation::Foundation, loads::Loads},
+    testkit::generate_profile,
+};
+
+const STYLES: [ProfileStyle; 5] = [
+    ProfileStyle::SoftClayOverSand,
+    ProfileStyle::Alluvium,
+    ProfileStyle::StiffClay,
+    ProfileStyle::DenseSand,
+    ProfileStyle::ClayOverRock,
+];
+
+#[test]
+fn test_generate_profile_is_deterministic() {
+    for style in STYLES {
+        let a = generate_profile(42, style);
+        let b = generate_profile(42, style);
+        let c = generate_profile(43, style);
+
+        assert_eq!(a.layers.len(), b.layers.len());
+        assert_eq!(a.layers[0].thickness, b.layers[0].thickness);
+        assert_eq!(
+            a.layers[0].shear_wave_velocity,
+            b.layers[0].shear_wave_velocity
+        );
+        assert_ne!(a.ground_water_level, c.ground_water_level);
+    }
+}
+
+#[test]
+fn test_generate_profile_parameters() {
+    for seed in 0..20 {
+        let profile = generate_profile(seed, ProfileStyle::SoftClayOverSand);
+        let clay = &profile.layers[0];
+        let sand = &profile.layers[1];
+
+        assert!(clay.is_cohesive());
+        assert!(!sand.is_cohesive());
+        assert!(clay.saturated_unit_weight.unwrap() > clay.dry_unit_weight.unwrap());
+        assert!(clay.cu.unwrap() < 5.0);
+        assert!(sand.phi_prime.unwrap() > 30.0);
+
+        let profile = generate_profile(seed, ProfileStyle::ClayOverRock);
+        assert!(profile.layers.last().unwrap().is_rock());
+    }
+}
+
+#[test]
+fn test_generated_profiles_run_analyses() {
+    let foundation = Foundation {
+        foundation_depth: Some(1.5),
+        foundation_width: Some(2.0),
+        foundation_length: Some(3.0),
+        ..Default::default()
+    };
+    let loads = Loads {
+        vertical_load: Some(60.0),
+        ..Default::default()
+    };
+
+    for seed in 0..10 {
+        for style in STYLES {
+            let mut profile = generate_profile(seed, style);
+            profile
+                .validate(&["thickness", "dry_unit_weight", "saturated_unit_weight"])
+                .unwrap();
+
+            for term in [AnalysisTerm::Short, AnalysisTerm::Long] {
+                let result = vesic::calc_bearing_capacity(
+                    &mut profile.clone(),
+                    &mut foundation.clone(),
+                    &loads,
+                    10.0,
+                    PressureConvention::Gross,
+                    3.0,
+                    term,
+                )
+                .unwrap();
+                assert!(result.ultimate_bearing_capacity > 0.0);
+            }
+
+            boussinesq::calc_elastic_settlement(
+                &mut profile,
+                &foundation,
+                10.0,
+                PressureConvention::Gross,
+            )
+            .unwrap();
+            by_compression_index::calc_settlement(
+                &mut profile,
+                &foundation,
+                10.0,
+                PressureConvention::Gross,
+            )
+            .unwrap();
+        }
+    }
+}
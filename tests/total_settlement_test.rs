@@ -0,0 +1,216 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    consolidation_settlement::by_mv,
+    elastic_settlement::boussinesq::calc_elastic_settlement,
+    enums::{MethodId, PressureConvention},
+    models::{
+        foundation::Foundation,
+        soil_profile::{SoilLayer, SoilProfile},
+    },
+    total_settlement::*,
+};
+
+fn create_soil_profile() -> SoilProfile {
+    let sand = SoilLayer {
+        thickness: Some(3.0),
+        dry_unit_weight: Some(1.8),
+        saturated_unit_weight: Some(1.9),
+        poissons_ratio: Some(0.3),
+        elastic_modulus: Some(2000.0),
+        mv: Some(0.005),
+        fine_content: Some(5.0),
+        ..Default::default()
+    };
+    let clay = SoilLayer {
+        thickness: Some(10.0),
+        dry_unit_weight: Some(1.7),
+        saturated_unit_weight: Some(1.8),
+        poissons_ratio: Some(0.5),
+        elastic_modulus: Some(1500.0),
+        mv: Some(0.02),
+        plasticity_index: Some(25.0),
+        void_ratio: Some(1.0),
+        secondary_compression_index: Some(0.01),
+        ..Default::default()
+    };
+    SoilProfile::new(vec![sand, clay], 1.0)
+}
+
+fn create_foundation() -> Foundation {
+    Foundation {
+        foundation_depth: Some(1.0),
+        foundation_width: Some(3.0),
+        foundation_length: Some(3.0),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_calc_secondary_settlement() {
+    // 0.01 · 10 / 2 · log10(10 / 1) · 100
+    let settlement = calc_secondary_settlement(0.01, 10.0, 1.0, 1.0, 10.0);
+    assert_abs_diff_eq!(settlement, 5.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_immediate_and_consolidation_partition() {
+    let profile = create_soil_profile();
+    let foundation = create_foundation();
+    let params = TotalSettlementParams::default();
+
+    let result = calc_total_settlement(
+        &profile,
+        &foundation,
+        15.0,
+        PressureConvention::Gross,
+        &params,
+    )
+    .unwrap();
+    let elastic = calc_elastic_settlement(
+        &mut profile.clone(),
+        &foundation,
+        15.0,
+        PressureConvention::Gross,
+    )
+    .unwrap();
+    let consolidation = by_mv::calc_settlement(
+        &mut profile.clone(),
+        &foundation,
+        15.0,
+        PressureConvention::Gross,
+    )
+    .unwrap();
+
+    assert_eq!(
+        result.consolidation_method,
+        Some(MethodId::VolumeCompressibility)
+    );
+    assert_abs_diff_eq!(
+        result.immediate_settlement,
+        elastic.total_settlement,
+        epsilon = 1e-9
+    );
+
+    // The sand settles immediately only, the clay also consolidates
+    let (sand, clay) = (&result.layers[0], &result.layers[1]);
+    assert!(!sand.is_cohesive && clay.is_cohesive);
+    assert!(consolidation.settlement_per_layer[0] > 0.0);
+    assert_eq!(sand.consolidation_settlement, 0.0);
+    assert_abs_diff_eq!(
+        clay.consolidation_settlement,
+        consolidation.settlement_per_layer[1],
+        epsilon = 1e-9
+    );
+    assert_eq!(result.secondary_settlement, 0.0);
+    assert_abs_diff_eq!(
+        result.total_settlement,
+        result.immediate_settlement + clay.consolidation_settlement,
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_net_pressure() {
+    let profile = create_soil_profile();
+    let foundation = create_foundation();
+    let params = TotalSettlementParams::default();
+
+    // σv(1 m) = 1.8 t/m²
+    let gross = calc_total_settlement(
+        &profile,
+        &foundation,
+        15.0,
+        PressureConvention::Gross,
+        &params,
+    )
+    .unwrap();
+    let net = calc_total_settlement(
+        &profile,
+        &foundation,
+        13.2,
+        PressureConvention::Net,
+        &params,
+    )
+    .unwrap();
+
+    assert_abs_diff_eq!(gross.qnet, net.qnet, epsilon = 1e-9);
+    assert_abs_diff_eq!(gross.total_settlement, net.total_settlement, epsilon = 1e-9);
+}
+
+#[test]
+fn test_secondary_settlement() {
+    let profile = create_soil_profile();
+    let foundation = create_foundation();
+    let params = TotalSettlementParams {
+        secondary: Some(SecondaryCompression {
+            end_of_primary: 1.0,
+            design_life: 10.0,
+        }),
+        ..Default::default()
+    };
+
+    let result = calc_total_settlement(
+        &profile,
+        &foundation,
+        15.0,
+        PressureConvention::Gross,
+        &params,
+    )
+    .unwrap();
+
+    assert_eq!(result.layers[0].secondary_settlement, 0.0);
+    assert_abs_diff_eq!(result.layers[1].secondary_settlement, 5.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.secondary_settlement, 5.0, epsilon = 1e-9);
+
+    let mut profile = create_soil_profile();
+    profile.layers[1].secondary_compression_index = None;
+    let err = calc_total_settlement(
+        &profile,
+        &foundation,
+        15.0,
+        PressureConvention::Gross,
+        &params,
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "soil_profile.secondary_compression_index.missing");
+}
+
+#[test]
+fn test_invalid_params() {
+    let profile = create_soil_profile();
+    let foundation = create_foundation();
+
+    let params = TotalSettlementParams {
+        consolidation_method: Some(MethodId::Boussinesq),
+        ..Default::default()
+    };
+    let err = calc_total_settlement(
+        &profile,
+        &foundation,
+        15.0,
+        PressureConvention::Gross,
+        &params,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.code,
+        "total_settlement.consolidation_method.unsupported"
+    );
+
+    let params = TotalSettlementParams {
+        secondary: Some(SecondaryCompression {
+            end_of_primary: 2.0,
+            design_life: 1.0,
+        }),
+        ..Default::default()
+    };
+    let err = calc_total_settlement(
+        &profile,
+        &foundation,
+        15.0,
+        PressureConvention::Gross,
+        &params,
+    )
+    .unwrap_err();
+    assert_eq!(err.code, "total_settlement.design_life.too_small.2");
+}
@@ -0,0 +1,68 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    enums::InterfaceType,
+    models::{
+        foundation::Foundation,
+        soil_profile::{SoilLayer, SoilProfile},
+    },
+    stability::uplift::calc_uplift,
+};
+
+fn create_soil_profile(ground_water_level: f64) -> SoilProfile {
+    SoilProfile::new(
+        vec![SoilLayer {
+            thickness: Some(20.0),
+            dry_unit_weight: Some(1.8),
+            saturated_unit_weight: Some(2.0),
+            phi_prime: Some(30.0),
+            ..Default::default()
+        }],
+        ground_water_level,
+    )
+}
+
+fn create_foundation() -> Foundation {
+    Foundation {
+        foundation_depth: Some(4.0),
+        foundation_width: Some(10.0),
+        foundation_length: Some(10.0),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_calc_uplift() {
+    let result = calc_uplift(&create_soil_profile(1.0), &create_foundation(), 200.0, 1.1).unwrap();
+
+    assert_abs_diff_eq!(result.uplift_pressure, 2.943, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.uplift_force, 294.3, epsilon = 1e-9);
+    // K0 = 0.5, δ = 20°, over a perimeter of 40 m
+    assert_abs_diff_eq!(result.side_friction, 79.2400, epsilon = 1e-4);
+    assert_abs_diff_eq!(result.factor_of_safety, 0.948828, epsilon = 1e-6);
+    assert_abs_diff_eq!(
+        result.factor_of_safety_without_friction,
+        0.679579,
+        epsilon = 1e-6
+    );
+    assert!(!result.is_safe);
+
+    // A rougher interface increases the wall friction
+    let rough = Foundation {
+        interface_type: Some(InterfaceType::CastInPlaceConcrete),
+        ..create_foundation()
+    };
+    let result_rough = calc_uplift(&create_soil_profile(1.0), &rough, 200.0, 1.1).unwrap();
+    assert!(result_rough.side_friction > result.side_friction);
+}
+
+#[test]
+fn test_calc_uplift_above_groundwater() {
+    let result = calc_uplift(&create_soil_profile(6.0), &create_foundation(), 200.0, 1.1).unwrap();
+
+    assert_abs_diff_eq!(result.uplift_force, 0.0, epsilon = 1e-9);
+    assert!(result.factor_of_safety.is_infinite());
+    assert!(result.is_safe);
+
+    let err = calc_uplift(&create_soil_profile(6.0), &create_foundation(), -1.0, 1.1).unwrap_err();
+    assert_eq!(err.code, "uplift.dead_weight.too_small.0");
+}
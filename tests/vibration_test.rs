@@ -0,0 +1,151 @@
+use approx::assert_abs_diff_eq;
+use soilrust::{
+    enums::{IsolationType, StructureCategory, WaveType},
+    models::{
+        soil_profile::{SoilLayer, SoilProfile},
+        vibration::{ScreeningTrench, VibrationSource},
+    },
+    vibration::{
+        calc_bornitz_velocity, calc_trench_reduction_factor, calc_vibration, get_allowable_velocity,
+    },
+};
+
+fn create_soil_profile() -> SoilProfile {
+    SoilProfile::new(
+        vec![
+            SoilLayer {
+                thickness: Some(4.0),
+                shear_wave_velocity: Some(200.0),
+                damping_ratio: Some(5.0),
+                ..Default::default()
+            },
+            SoilLayer {
+                thickness: Some(10.0),
+                shear_wave_velocity: Some(300.0),
+                damping_ratio: Some(3.0),
+                ..Default::default()
+            },
+        ],
+        5.0,
+    )
+}
+
+fn create_source() -> VibrationSource {
+    VibrationSource {
+        peak_particle_velocity: Some(25.0),
+        reference_distance: Some(5.0),
+        frequency: Some(20.0),
+        wave_type: WaveType::Surface,
+    }
+}
+
+#[test]
+fn test_bornitz_geometric_only() {
+    let v = calc_bornitz_velocity(10.0, 5.0, 20.0, 0.5, 0.0);
+    assert_abs_diff_eq!(v, 5.0, epsilon = 1e-9);
+}
+
+#[test]
+fn test_allowable_velocity() {
+    assert_abs_diff_eq!(
+        get_allowable_velocity(StructureCategory::Residential, 5.0),
+        5.0,
+        epsilon = 1e-9
+    );
+    assert_abs_diff_eq!(
+        get_allowable_velocity(StructureCategory::Commercial, 30.0),
+        30.0,
+        epsilon = 1e-9
+    );
+    assert_abs_diff_eq!(
+        get_allowable_velocity(StructureCategory::Sensitive, 200.0),
+        10.0,
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_trench_reduction_factor() {
+    assert_abs_diff_eq!(
+        calc_trench_reduction_factor(6.0, 10.0, IsolationType::Active),
+        0.25,
+        epsilon = 1e-9
+    );
+    assert_abs_diff_eq!(
+        calc_trench_reduction_factor(6.65, 10.0, IsolationType::Passive),
+        0.625,
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_vibration_without_trench() {
+    let result = calc_vibration(
+        &create_source(),
+        &mut create_soil_profile(),
+        30.0,
+        StructureCategory::Residential,
+        None,
+    )
+    .unwrap();
+
+    assert_abs_diff_eq!(result.shear_wave_velocity, 260.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.damping_ratio, 0.038, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.wavelength, 13.0, epsilon = 1e-9);
+    assert_abs_diff_eq!(result.attenuation_coefficient, 0.0183662, epsilon = 1e-7);
+    assert_abs_diff_eq!(result.peak_particle_velocity, 6.448453, epsilon = 1e-6);
+    assert_abs_diff_eq!(result.allowable_peak_particle_velocity, 7.5, epsilon = 1e-9);
+    assert!(result.is_safe);
+}
+
+#[test]
+fn test_vibration_with_trench() {
+    let shallow = ScreeningTrench {
+        depth: Some(3.0),
+        isolation_type: IsolationType::Active,
+    };
+    let result = calc_vibration(
+        &create_source(),
+        &mut create_soil_profile(),
+        30.0,
+        StructureCategory::Sensitive,
+        Some(&shallow),
+    )
+    .unwrap();
+
+    assert_abs_diff_eq!(result.amplitude_reduction_factor, 0.711538, epsilon = 1e-6);
+    assert_abs_diff_eq!(
+        result.screened_peak_particle_velocity,
+        4.588322,
+        epsilon = 1e-6
+    );
+    assert!(!result.is_safe);
+
+    let deep = ScreeningTrench {
+        depth: Some(6.0),
+        isolation_type: IsolationType::Active,
+    };
+    let result = calc_vibration(
+        &create_source(),
+        &mut create_soil_profile(),
+        30.0,
+        StructureCategory::Sensitive,
+        Some(&deep),
+    )
+    .unwrap();
+
+    assert!(result.is_safe);
+}
+
+#[test]
+fn test_receiver_closer_than_reference() {
+    let result = calc_vibration(
+        &create_source(),
+        &mut create_soil_profile(),
+        2.0,
+        StructureCategory::Residential,
+        None,
+    );
+
+    assert!(result.is_err());
+}